@@ -1,6 +1,7 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    plugin::{plugin_name, RegistrationSnapshot},
+    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, PluginBuild, PluginSystems,
+    Plugins, PluginsState, SubApp, SubApps,
 };
 use alloc::{
     boxed::Box,
@@ -10,11 +11,12 @@ use alloc::{
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     component::RequiredComponentsError,
-    event::{event_update_system, EventCursor},
+    event::{event_update_system, EventCursor, EventRetention},
     intern::Interned,
     prelude::*,
-    schedule::{ScheduleBuildSettings, ScheduleLabel},
+    schedule::{DisabledSystemSets, ScheduleBuildSettings, ScheduleLabel},
     system::{IntoObserverSystem, SystemId, SystemInput},
+    world::FromWorldWith,
 };
 use bevy_utils::HashMap;
 use core::{fmt::Debug, num::NonZero, panic::AssertUnwindSafe};
@@ -104,6 +106,8 @@ impl Default for App {
         {
             app.init_resource::<AppTypeRegistry>();
             app.register_type::<Name>();
+            app.register_type::<bevy_ecs::guid::Guid>();
+            app.register_type::<bevy_ecs::entity_disabling::Disabled>();
         }
 
         #[cfg(feature = "reflect_functions")]
@@ -148,6 +152,9 @@ impl App {
             panic!("App::update() was called while a plugin was building.");
         }
 
+        #[cfg(feature = "dynamic_plugins")]
+        crate::dynamic_plugin::reload_watched_plugins(self);
+
         self.sub_apps.update();
     }
 
@@ -359,6 +366,61 @@ impl App {
         self
     }
 
+    /// Initializes `T` event handling like [`add_event`](Self::add_event), but keeps the events
+    /// around according to the given [`EventRetention`] instead of double buffering them.
+    ///
+    /// If the event was already added, only its retention is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{event::EventRetention, prelude::*};
+    /// #
+    /// # #[derive(Event)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// #
+    /// // Readers that only run every few frames still see every `MyEvent`.
+    /// app.add_event_with::<MyEvent>(EventRetention::Frames(5));
+    /// ```
+    pub fn add_event_with<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        self.main_mut().add_event_with::<T>(retention);
+        self
+    }
+
+    /// Sends a [`ComponentChanged<T>`](crate::ComponentChanged) event for every entity whose `T`
+    /// was added or changed, and a [`ComponentRemoved<T>`](crate::ComponentRemoved) event for every
+    /// entity that lost its `T`, once per frame in [`Last`](crate::Last).
+    ///
+    /// This lets any number of consumers, such as networking or UIs that redraw dirty regions,
+    /// react to changes by reading events instead of each iterating a [`Changed<T>`] query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, ComponentChanged};
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Health(f32);
+    /// # let mut app = App::new();
+    /// #
+    /// app.track_changes::<Health>()
+    ///     .add_systems(Update, |mut changes: EventReader<ComponentChanged<Health>>| {
+    ///         for change in changes.read() {
+    ///             println!("{} has a new health", change.entity);
+    ///         }
+    ///     });
+    /// ```
+    pub fn track_changes<T: Component>(&mut self) -> &mut Self {
+        self.main_mut().track_changes::<T>();
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -417,6 +479,44 @@ impl App {
         self
     }
 
+    /// Registers the [`Resource`] to be initialized the first time it is needed, which is when a
+    /// system using it is initialized or when another lazy resource depends on it.
+    ///
+    /// Unlike [`init_resource`](Self::init_resource), the resource can declare other resources
+    /// it needs by implementing [`FromWorldWith`]. Those are created first, regardless of the order
+    /// in which plugins registered them. See [`World::init_resource_lazy`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{prelude::*, world::FromWorldWith};
+    /// #
+    /// #[derive(Resource, Default)]
+    /// struct Settings {
+    ///     capacity: usize,
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct Buffer(Vec<u8>);
+    ///
+    /// impl FromWorldWith for Buffer {
+    ///     type Dependencies = (Settings,);
+    ///
+    ///     fn from_world_with(world: &mut World) -> Self {
+    ///         Buffer(Vec::with_capacity(world.resource::<Settings>().capacity))
+    ///     }
+    /// }
+    ///
+    /// App::new()
+    ///     .init_resource_lazy::<Buffer>()
+    ///     .init_resource_lazy::<Settings>();
+    /// ```
+    pub fn init_resource_lazy<R: Resource + FromWorldWith>(&mut self) -> &mut Self {
+        self.main_mut().init_resource_lazy::<R>();
+        self
+    }
+
     /// Inserts the [`!Send`](Send) resource into the app, overwriting any existing resource
     /// of the same type.
     ///
@@ -471,6 +571,15 @@ impl App {
 
         self.main_mut().plugin_build_depth += 1;
 
+        // Track what the plugin adds, so it can be torn down by `remove_plugin`.
+        let name = plugin_name(&*plugin);
+        let snapshot = RegistrationSnapshot::new(self.world());
+        let main = self.main_mut();
+        let build = PluginBuild(main.plugin_builds);
+        main.plugin_builds += 1;
+        main.building_plugins
+            .push((PluginSystems::new(name.clone()), build));
+
         let f = AssertUnwindSafe(|| plugin.build(self));
 
         #[cfg(feature = "std")]
@@ -483,16 +592,111 @@ impl App {
             .plugin_names
             .insert(plugin.name().to_string());
         self.main_mut().plugin_build_depth -= 1;
+        self.main_mut().building_plugins.pop();
 
         #[cfg(feature = "std")]
         if let Err(payload) = result {
             resume_unwind(payload);
         }
 
+        // What the plugins added by this one registered belongs to them.
+        let mut registrations = snapshot.added(self.world(), build);
+        let main = self.main_mut();
+        for nested in &main.plugin_registry[index + 1..] {
+            if let Some(nested) = main.plugin_registrations.get(&plugin_name(&**nested)) {
+                registrations.subtract(nested);
+            }
+        }
+        main.plugin_registrations
+            .entry(name)
+            .or_default()
+            .extend(registrations);
+
         self.main_mut().plugin_registry[index] = plugin;
         Ok(self)
     }
 
+    /// Removes all added plugins of type `T` at runtime, and returns `true` if any was added.
+    ///
+    /// [`Plugin::remove`] is called on each of them, then what they added to the main app during
+    /// [`Plugin::build`] is torn down:
+    /// - their systems are disabled for good with [`DisabledSystemSets`]. They stay in their
+    ///   schedules but are always skipped, even if a plugin with the same name is added again,
+    ///   which adds its systems anew.
+    /// - the resources they inserted are removed.
+    /// - the schedules they created are removed.
+    /// - the observers they spawned are despawned.
+    ///
+    /// Plugins added by `T` are not removed with it, and neither are the systems, resources and
+    /// schedules added by `T` outside of [`Plugin::build`], or to other sub-apps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// struct ScorePlugin;
+    ///
+    /// impl Plugin for ScorePlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         app.insert_resource(Score(0));
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(ScorePlugin);
+    /// assert!(app.remove_plugin::<ScorePlugin>());
+    /// assert!(!app.world().contains_resource::<Score>());
+    /// ```
+    pub fn remove_plugin<T: Plugin>(&mut self) -> bool {
+        self.remove_plugins_where(|plugin| plugin.downcast_ref::<T>().is_some())
+    }
+
+    /// Removes the added plugins for which `predicate` returns `true`, see
+    /// [`remove_plugin`](Self::remove_plugin).
+    pub(crate) fn remove_plugins_where(
+        &mut self,
+        mut predicate: impl FnMut(&dyn Plugin) -> bool,
+    ) -> bool {
+        let (removed, kept) = core::mem::take(&mut self.main_mut().plugin_registry)
+            .into_iter()
+            .partition::<Vec<_>, _>(|plugin| predicate(&**plugin));
+        self.main_mut().plugin_registry = kept;
+
+        for plugin in &removed {
+            plugin.remove(self);
+
+            let name = plugin_name(&**plugin);
+            let main = self.main_mut();
+            main.plugin_names.remove(&name);
+            let registrations = main.plugin_registrations.remove(&name).unwrap_or_default();
+            let world = self.world_mut();
+            for id in registrations.resources {
+                if world.remove_resource_by_id(id).is_none() {
+                    world.remove_non_send_by_id(id);
+                }
+            }
+            let mut schedules = world.resource_mut::<Schedules>();
+            for label in registrations.schedules {
+                schedules.remove(label);
+            }
+            for observer in registrations.observers {
+                if let Ok(entity) = world.get_entity_mut(observer) {
+                    entity.despawn();
+                }
+            }
+            let mut disabled = world.get_resource_or_init::<DisabledSystemSets>();
+            for build in registrations.builds {
+                disabled.disable(build);
+            }
+        }
+
+        !removed.is_empty()
+    }
+
     /// Returns `true` if the [`Plugin`] has already been added.
     pub fn is_plugin_added<T>(&self) -> bool
     where
@@ -593,6 +797,22 @@ impl App {
         self
     }
 
+    /// Registers the [remote wrapper] `W` in the [`AppTypeRegistry`] resource, so that values of
+    /// its remote type can be reflected through it.
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_remote`] for more information.
+    ///
+    /// [remote wrapper]: bevy_reflect::ReflectRemote
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_remote_type<W>(&mut self) -> &mut Self
+    where
+        W: bevy_reflect::ReflectRemote + bevy_reflect::GetTypeRegistration + bevy_reflect::TypePath,
+        W::Remote: core::any::Any,
+    {
+        self.main_mut().register_remote_type::<W>();
+        self
+    }
+
     /// Associates type data `D` with type `T` in the [`AppTypeRegistry`] resource.
     ///
     /// Most of the time [`register_type`](Self::register_type) can be used instead to register a
@@ -775,6 +995,48 @@ impl App {
         self
     }
 
+    /// Registers the given function or closure into the [`AppFunctionRegistry`] resource as a
+    /// method of `T` with the given name.
+    ///
+    /// The first argument of the function is the receiver, a `T` taken by value, reference, or
+    /// mutable reference.
+    ///
+    /// See [`FunctionRegistry::register_method`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a method has already been registered with the given name for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_app::App;
+    /// use bevy_reflect::Reflect;
+    ///
+    /// #[derive(Reflect)]
+    /// struct Health(f32);
+    ///
+    /// impl Health {
+    ///     fn heal(&mut self, amount: f32) {
+    ///         self.0 += amount;
+    ///     }
+    /// }
+    ///
+    /// App::new().register_method::<Health, _, _>("heal", Health::heal);
+    /// ```
+    ///
+    /// [`FunctionRegistry::register_method`]: bevy_reflect::func::FunctionRegistry::register_method
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_method<T, F, Marker>(&mut self, name: &str, function: F) -> &mut Self
+    where
+        T: bevy_reflect::TypePath,
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + 'static,
+    {
+        self.main_mut()
+            .register_method::<T, F, Marker>(name, function);
+        self
+    }
+
     /// Registers the given component `R` as a [required component] for `T`.
     ///
     /// When `T` is added to an entity, `R` and its own required components will also be added
@@ -1415,7 +1677,8 @@ mod tests {
         change_detection::{DetectChanges, ResMut},
         component::Component,
         entity::Entity,
-        event::{Event, EventWriter, Events},
+        event::{Event, EventRetention, EventWriter, Events},
+        observer::Trigger,
         query::With,
         removal_detection::RemovedComponents,
         schedule::{IntoSystemConfigs, ScheduleLabel},
@@ -1478,6 +1741,68 @@ mod tests {
         App::new().add_plugins((PluginD, PluginD));
     }
 
+    #[test]
+    fn remove_plugin() {
+        #[derive(Resource, Default)]
+        struct Counter(u32);
+
+        #[derive(Resource)]
+        struct Kept;
+
+        #[derive(Resource)]
+        struct Pinged;
+
+        #[derive(Event)]
+        struct Ping;
+
+        #[derive(ScheduleLabel, Hash, Debug, PartialEq, Eq, Clone)]
+        struct Custom;
+
+        struct CounterPlugin;
+        impl Plugin for CounterPlugin {
+            fn build(&self, app: &mut App) {
+                if !app.is_plugin_added::<PluginA>() {
+                    app.add_plugins(PluginA);
+                }
+                app.init_resource::<Counter>()
+                    .init_schedule(Custom)
+                    .add_systems(Update, |mut counter: ResMut<Counter>| counter.0 += 1)
+                    .add_observer(|_: Trigger<Ping>, mut commands: Commands| {
+                        commands.insert_resource(Pinged);
+                    });
+            }
+            fn remove(&self, app: &mut App) {
+                app.insert_resource(Kept);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(CounterPlugin);
+        app.update();
+        assert_eq!(app.world().resource::<Counter>().0, 1);
+
+        assert!(app.remove_plugin::<CounterPlugin>());
+        assert!(!app.remove_plugin::<CounterPlugin>());
+        assert!(!app.is_plugin_added::<CounterPlugin>());
+        assert!(app.is_plugin_added::<PluginA>());
+        assert!(!app.world().contains_resource::<Counter>());
+        assert!(app.world().contains_resource::<Kept>());
+        assert!(app.get_schedule(Custom).is_none());
+        app.world_mut().trigger(Ping);
+        app.world_mut().flush();
+        assert!(!app.world().contains_resource::<Pinged>());
+        // The system is skipped instead of panicking on the missing resource.
+        app.update();
+
+        // Only the systems of the new build run.
+        app.add_plugins(CounterPlugin);
+        app.update();
+        assert_eq!(app.world().resource::<Counter>().0, 1);
+        app.world_mut().trigger(Ping);
+        app.world_mut().flush();
+        assert!(app.world().contains_resource::<Pinged>());
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
@@ -1816,4 +2141,34 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn events_follow_their_retention() {
+        #[derive(Event, Clone)]
+        struct RetainedEvent;
+        #[derive(Event, Clone)]
+        struct ManualEvent;
+
+        let mut app = App::new();
+        app.add_event_with::<RetainedEvent>(EventRetention::Frames(3))
+            .add_event_with::<ManualEvent>(EventRetention::Manual);
+        app.update();
+
+        app.world_mut().send_event(RetainedEvent);
+        app.world_mut().send_event(ManualEvent);
+        for _ in 0..2 {
+            app.update();
+            assert_eq!(app.world().resource::<Events<RetainedEvent>>().len(), 1);
+        }
+
+        // Expires on the third update even though no new events were sent.
+        app.update();
+        assert!(app.world().resource::<Events<RetainedEvent>>().is_empty());
+        assert_eq!(app.world().resource::<Events<ManualEvent>>().len(), 1);
+
+        app.world_mut()
+            .resource_mut::<Events<ManualEvent>>()
+            .clear();
+        assert!(app.world().resource::<Events<ManualEvent>>().is_empty());
+    }
 }
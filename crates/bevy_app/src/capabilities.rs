@@ -0,0 +1,235 @@
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::system::Resource;
+use bevy_utils::HashMap;
+
+/// A capability of the platform the app runs on, like an audio device or a display, detected at
+/// startup.
+///
+/// Members of a [`PluginGroup`](crate::PluginGroup) can [require](crate::PluginGroupBuilder::require)
+/// capabilities, so that one binary runs across different environments: the plugins whose
+/// capabilities are missing are left out of the app. The detections and decisions are logged and
+/// kept in the [`PlatformCapabilities`] resource.
+pub trait Capability: Send + Sync + 'static {
+    /// The name of the capability, which identifies it in [`PlatformCapabilities`].
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(core::any::type_name::<Self>())
+    }
+
+    /// Returns `true` if the platform has the capability.
+    ///
+    /// This is called at most once per app, the result is cached in [`PlatformCapabilities`].
+    fn detect(&self) -> bool;
+}
+
+/// A [`Capability`] detected by a function.
+///
+/// ```
+/// # use bevy_app::{Capability, CapabilityFn};
+/// let gamepads = CapabilityFn::new("gamepads", || std::env::var_os("NO_GAMEPADS").is_none());
+/// assert_eq!(gamepads.name(), "gamepads");
+/// ```
+pub struct CapabilityFn {
+    name: &'static str,
+    detect: fn() -> bool,
+}
+
+impl CapabilityFn {
+    /// Creates a capability named `name`, detected by `detect`.
+    pub const fn new(name: &'static str, detect: fn() -> bool) -> Self {
+        Self { name, detect }
+    }
+}
+
+impl Capability for CapabilityFn {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(self.name)
+    }
+
+    fn detect(&self) -> bool {
+        (self.detect)()
+    }
+}
+
+/// The capability of showing windows.
+///
+/// It's missing when the `BEVY_HEADLESS` environment variable is set, and on Linux and the BSDs
+/// when neither `DISPLAY` nor `WAYLAND_DISPLAY` is set, like on most CI runners.
+pub struct DisplayCapability;
+
+impl Capability for DisplayCapability {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("display")
+    }
+
+    fn detect(&self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            let set = |name| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+            if set("BEVY_HEADLESS") {
+                return false;
+            }
+            if cfg!(all(
+                unix,
+                not(target_os = "macos"),
+                not(target_os = "ios"),
+                not(target_os = "android")
+            )) {
+                return set("DISPLAY") || set("WAYLAND_DISPLAY");
+            }
+        }
+        true
+    }
+}
+
+/// The capabilities detected for the app, and the decisions made from them for the members of its
+/// plugin groups. See [`Capability`].
+#[derive(Resource, Default, Debug, Clone)]
+pub struct PlatformCapabilities {
+    detected: HashMap<String, bool>,
+    decisions: Vec<CapabilityDecision>,
+}
+
+/// Whether a member of a plugin group was added, given the capabilities it requires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilityDecision {
+    /// The name of the plugin group.
+    pub group: String,
+    /// The name of the plugin.
+    pub plugin: String,
+    /// The capabilities the plugin requires that are missing.
+    pub missing: Vec<String>,
+}
+
+impl CapabilityDecision {
+    /// Returns `true` if the plugin was added, as no capability it requires is missing.
+    pub fn is_included(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl PlatformCapabilities {
+    /// Returns whether the capability named `name` is available, or `None` if it wasn't detected.
+    pub fn get(&self, name: &str) -> Option<bool> {
+        self.detected.get(name).copied()
+    }
+
+    /// Returns an iterator over the names of the detected capabilities and whether they are
+    /// available.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.detected
+            .iter()
+            .map(|(name, &available)| (name.as_str(), available))
+    }
+
+    /// Returns the decisions made for the plugins requiring capabilities, in the order they were
+    /// made.
+    pub fn decisions(&self) -> &[CapabilityDecision] {
+        &self.decisions
+    }
+
+    /// Returns the decision made for the plugin named `plugin`, if it required capabilities.
+    pub fn decision(&self, plugin: &str) -> Option<&CapabilityDecision> {
+        self.decisions
+            .iter()
+            .rev()
+            .find(|decision| decision.plugin == plugin)
+    }
+
+    /// Returns whether `capability` is available, detecting it if it wasn't yet.
+    pub fn detect(&mut self, capability: &dyn Capability) -> bool {
+        let name = capability.name();
+        if let Some(&available) = self.detected.get(name.as_ref()) {
+            return available;
+        }
+        let available = capability.detect();
+        log::info!(
+            "Capability `{name}` is {}",
+            if available { "available" } else { "missing" }
+        );
+        self.detected.insert(name.to_string(), available);
+        available
+    }
+
+    pub(crate) fn decide(
+        &mut self,
+        group: &str,
+        plugin: &str,
+        requirements: &[alloc::boxed::Box<dyn Capability>],
+    ) -> bool {
+        let missing: Vec<String> = requirements
+            .iter()
+            .filter(|capability| !self.detect(&***capability))
+            .map(|capability| capability.name().into_owned())
+            .collect();
+        if !missing.is_empty() {
+            log::info!(
+                "Plugin {plugin} of group {group} was left out, as capabilities {missing:?} are missing"
+            );
+        }
+        let decision = CapabilityDecision {
+            group: group.to_string(),
+            plugin: plugin.to_string(),
+            missing,
+        };
+        let included = decision.is_included();
+        self.decisions.push(decision);
+        included
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{App, NoopPluginGroup, Plugin, PluginGroupBuilder};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    struct PluginA;
+    impl Plugin for PluginA {
+        fn build(&self, _: &mut App) {}
+    }
+
+    struct PluginB;
+    impl Plugin for PluginB {
+        fn build(&self, _: &mut App) {}
+    }
+
+    static DETECTIONS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Gpu;
+    impl Capability for Gpu {
+        fn detect(&self) -> bool {
+            DETECTIONS.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+
+    #[test]
+    fn leave_out_plugins_with_missing_capabilities() {
+        let mut app = App::new();
+        app.add_plugins(
+            PluginGroupBuilder::start::<NoopPluginGroup>()
+                .add(PluginA)
+                .add(PluginB)
+                .require::<PluginA>(Gpu)
+                .require::<PluginB>(CapabilityFn::new("available", || true))
+                .require::<PluginB>(Gpu),
+        );
+
+        assert!(!app.is_plugin_added::<PluginA>());
+        assert!(!app.is_plugin_added::<PluginB>());
+        assert_eq!(DETECTIONS.load(Ordering::Relaxed), 1);
+
+        let capabilities = app.world().resource::<PlatformCapabilities>();
+        assert_eq!(capabilities.get(&Gpu.name()), Some(false));
+        assert_eq!(capabilities.get("available"), Some(true));
+        let decision = capabilities
+            .decision(core::any::type_name::<PluginB>())
+            .unwrap();
+        assert!(!decision.is_included());
+        assert_eq!(decision.missing, [Gpu.name()]);
+    }
+}
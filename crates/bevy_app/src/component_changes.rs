@@ -0,0 +1,131 @@
+use bevy_ecs::prelude::*;
+use core::{fmt, marker::PhantomData};
+
+/// Sent every frame for each entity whose component `T` was added or changed, once
+/// [`App::track_changes::<T>`](crate::App::track_changes) was called.
+#[derive(Event)]
+pub struct ComponentChanged<T: Component> {
+    /// The entity whose component changed.
+    pub entity: Entity,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Component> ComponentChanged<T> {
+    /// Creates a [`ComponentChanged`] event for `entity`.
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Sent every frame for each entity that lost its component `T`, including by being despawned,
+/// once [`App::track_changes::<T>`](crate::App::track_changes) was called.
+#[derive(Event)]
+pub struct ComponentRemoved<T: Component> {
+    /// The entity the component was removed from.
+    pub entity: Entity,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Component> ComponentRemoved<T> {
+    /// Creates a [`ComponentRemoved`] event for `entity`.
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            marker: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_change_event_traits {
+    ($event:ident) => {
+        impl<T: Component> Clone for $event<T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T: Component> Copy for $event<T> {}
+
+        impl<T: Component> PartialEq for $event<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.entity == other.entity
+            }
+        }
+
+        impl<T: Component> Eq for $event<T> {}
+
+        impl<T: Component> fmt::Debug for $event<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($event))
+                    .field("entity", &self.entity)
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_change_event_traits!(ComponentChanged);
+impl_change_event_traits!(ComponentRemoved);
+
+/// Sends [`ComponentChanged<T>`] and [`ComponentRemoved<T>`] events for the changes to `T` since
+/// the last time this system ran.
+pub fn send_component_change_events<T: Component>(
+    changed: Query<Entity, Changed<T>>,
+    mut removed: RemovedComponents<T>,
+    mut changed_events: EventWriter<ComponentChanged<T>>,
+    mut removed_events: EventWriter<ComponentRemoved<T>>,
+) {
+    changed_events.send_batch(changed.iter().map(ComponentChanged::new));
+    removed_events.send_batch(removed.read().map(ComponentRemoved::new));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::App;
+    use alloc::vec::Vec;
+    use bevy_ecs::event::Events;
+
+    #[derive(Component)]
+    struct A(u32);
+
+    fn drain<E: Event>(app: &mut App) -> Vec<E> {
+        app.world_mut()
+            .resource_mut::<Events<E>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    fn track_changes() {
+        let mut app = App::new();
+        app.track_changes::<A>();
+
+        let a = app.world_mut().spawn(A(0)).id();
+        let b = app.world_mut().spawn(A(0)).id();
+        app.update();
+        assert_eq!(
+            drain::<ComponentChanged<A>>(&mut app).len(),
+            2,
+            "spawned components count as changed"
+        );
+
+        app.update();
+        assert!(drain::<ComponentChanged<A>>(&mut app).is_empty());
+
+        app.world_mut().get_mut::<A>(a).unwrap().0 += 1;
+        app.world_mut().despawn(b);
+        app.update();
+        assert_eq!(
+            drain::<ComponentChanged<A>>(&mut app),
+            [ComponentChanged::new(a)]
+        );
+        assert_eq!(
+            drain::<ComponentRemoved<A>>(&mut app),
+            [ComponentRemoved::new(b)]
+        );
+    }
+}
@@ -0,0 +1,430 @@
+#![expect(
+    unsafe_code,
+    reason = "Loading a dynamic library and the plugin it exports is unsafe."
+)]
+
+use crate::{App, PlaceholderPlugin, Plugin, PluginsState};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::system::Resource;
+use core::{
+    ffi::{c_char, c_void, CStr},
+    mem,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+use libloading::{Library, Symbol};
+use log::{error, info};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Instant, SystemTime},
+};
+use thiserror::Error;
+
+/// The ABI of the dynamic plugins this version of `bevy_app` loads, as a nul-terminated string.
+///
+/// A dynamic plugin declared with [`dynamic_plugin!`](crate::dynamic_plugin) reports the ABI it
+/// was built for, and is only loaded if it matches. The plugin and the app must also be built with
+/// the same compiler, as plugins are passed as Rust trait objects.
+pub const DYNAMIC_PLUGIN_ABI: &str = concat!("bevy_app ", env!("CARGO_PKG_VERSION"), "\0");
+
+/// Declares the entry points of a dynamic library exporting a [`Plugin`], to be loaded with
+/// [`App::load_dynamic_plugin`].
+///
+/// The library must be built as a `cdylib` or `dylib`, and the expression is evaluated each time
+/// the library is loaded.
+///
+/// ```ignore
+/// # use bevy_app::prelude::*;
+/// #[derive(Default)]
+/// pub struct GameplayPlugin;
+///
+/// impl Plugin for GameplayPlugin {
+///     fn build(&self, app: &mut App) {}
+/// }
+///
+/// bevy_app::dynamic_plugin!(GameplayPlugin::default());
+/// ```
+#[macro_export]
+macro_rules! dynamic_plugin {
+    ($plugin:expr) => {
+        #[no_mangle]
+        pub extern "C" fn _bevy_plugin_abi() -> *const ::core::ffi::c_char {
+            $crate::DYNAMIC_PLUGIN_ABI.as_ptr().cast()
+        }
+
+        #[no_mangle]
+        pub extern "C" fn _bevy_create_plugin() -> *mut ::core::ffi::c_void {
+            let plugin: ::std::boxed::Box<dyn $crate::Plugin> = ::std::boxed::Box::new($plugin);
+            ::std::boxed::Box::into_raw(::std::boxed::Box::new(plugin)).cast()
+        }
+    };
+}
+
+/// An error that occurs when loading a dynamic plugin.
+#[derive(Error, Debug)]
+pub enum DynamicPluginLoadError {
+    /// The library couldn't be copied before being loaded.
+    #[error("failed to copy the library {path:?}: {error}")]
+    Copy {
+        /// The path of the library.
+        path: PathBuf,
+        /// The error that occurred.
+        #[source]
+        error: io::Error,
+    },
+    /// The library couldn't be loaded.
+    #[error("failed to load the library: {0}")]
+    Library(#[source] libloading::Error),
+    /// The library doesn't declare a plugin with [`dynamic_plugin!`](crate::dynamic_plugin).
+    #[error("the library doesn't export a plugin: {0}")]
+    EntryPoint(#[source] libloading::Error),
+    /// The library was built for another version of `bevy_app`.
+    #[error("the library was built for `{found}`, but the app uses `{expected}`")]
+    AbiMismatch {
+        /// The ABI of the app.
+        expected: String,
+        /// The ABI of the library.
+        found: String,
+    },
+}
+
+/// The plugins loaded from dynamic libraries with [`App::load_dynamic_plugin`].
+///
+/// The libraries are never unloaded, as the systems of a removed plugin are kept in the schedules
+/// (see [`App::remove_plugin`]) and the plugins themselves outlive this resource, so each reload
+/// keeps the previous library in memory.
+#[derive(Resource)]
+pub struct DynamicPlugins {
+    plugins: Vec<LoadedPlugin>,
+    watch: bool,
+    check_interval: Duration,
+    last_check: Option<Instant>,
+}
+
+#[derive(Clone)]
+struct LoadedPlugin {
+    path: PathBuf,
+    name: String,
+    modified: Option<SystemTime>,
+}
+
+impl Default for DynamicPlugins {
+    fn default() -> Self {
+        Self {
+            plugins: Vec::new(),
+            watch: true,
+            check_interval: Duration::from_secs(1),
+            last_check: None,
+        }
+    }
+}
+
+impl DynamicPlugins {
+    /// Returns `true` if the libraries are checked for changes during [`App::update`], and
+    /// their plugins reloaded when they changed. This is the default.
+    pub fn watch(&self) -> bool {
+        self.watch
+    }
+
+    /// Sets whether the libraries are checked for changes during [`App::update`].
+    pub fn set_watch(&mut self, watch: bool) {
+        self.watch = watch;
+    }
+
+    /// Returns the minimum time between two checks of the watched libraries. Defaults to one
+    /// second.
+    pub fn check_interval(&self) -> Duration {
+        self.check_interval
+    }
+
+    /// Sets the minimum time between two checks of the watched libraries.
+    pub fn set_check_interval(&mut self, check_interval: Duration) {
+        self.check_interval = check_interval;
+    }
+
+    /// Returns an iterator over the paths of the loaded libraries.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.plugins.iter().map(|plugin| plugin.path.as_path())
+    }
+}
+
+/// Wraps a plugin loaded from a library, under a name unique to this load so that the systems
+/// of the previous loads stay disabled.
+struct DynamicPlugin {
+    plugin: Box<dyn Plugin>,
+    name: String,
+}
+
+impl Plugin for DynamicPlugin {
+    fn build(&self, app: &mut App) {
+        self.plugin.build(app);
+    }
+
+    fn ready(&self, app: &App) -> bool {
+        self.plugin.ready(app)
+    }
+
+    fn finish(&self, app: &mut App) {
+        self.plugin.finish(app);
+    }
+
+    fn cleanup(&self, app: &mut App) {
+        self.plugin.cleanup(app);
+    }
+
+    fn remove(&self, app: &mut App) {
+        self.plugin.remove(app);
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_unique(&self) -> bool {
+        self.plugin.is_unique()
+    }
+}
+
+/// Removes a file when dropped.
+struct RemoveOnDrop<'a>(&'a Path);
+
+impl Drop for RemoveOnDrop<'_> {
+    fn drop(&mut self) {
+        // Windows doesn't allow removing a loaded library, so its copies stay in the temporary
+        // directory.
+        let _ = fs::remove_file(self.0);
+    }
+}
+
+/// Loads the plugin of the library at `path`.
+///
+/// The library is copied to a unique path first, as operating systems return the library that's
+/// already loaded from a path instead of loading it again. The copy is removed once loaded.
+///
+/// The library is leaked, as the plugin and the systems it added are still used after this
+/// returns.
+///
+/// # Safety
+///
+/// See [`App::load_dynamic_plugin`].
+unsafe fn load(path: &Path) -> Result<Box<dyn Plugin>, DynamicPluginLoadError> {
+    static LOADS: AtomicU32 = AtomicU32::new(0);
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let copy = std::env::temp_dir().join(format!(
+        "bevy-{}-{}-{file_name}",
+        std::process::id(),
+        LOADS.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::copy(path, &copy).map_err(|error| DynamicPluginLoadError::Copy {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let _remove_copy = RemoveOnDrop(&copy);
+
+    // SAFETY: the caller guarantees that loading the library is sound.
+    let library = unsafe { Library::new(&copy) }.map_err(DynamicPluginLoadError::Library)?;
+    let plugin = {
+        // SAFETY: the symbols are declared by `dynamic_plugin!` with these signatures.
+        let abi: Symbol<unsafe extern "C" fn() -> *const c_char> =
+            unsafe { library.get(b"_bevy_plugin_abi\0") }
+                .map_err(DynamicPluginLoadError::EntryPoint)?;
+        let create: Symbol<unsafe extern "C" fn() -> *mut c_void> =
+            unsafe { library.get(b"_bevy_create_plugin\0") }
+                .map_err(DynamicPluginLoadError::EntryPoint)?;
+
+        // SAFETY: `_bevy_plugin_abi` returns a nul-terminated static string.
+        let found = unsafe { CStr::from_ptr(abi()) }.to_string_lossy();
+        let expected = DYNAMIC_PLUGIN_ABI.trim_end_matches('\0');
+        if found != expected {
+            return Err(DynamicPluginLoadError::AbiMismatch {
+                expected: expected.to_string(),
+                found: found.into_owned(),
+            });
+        }
+
+        // SAFETY: `_bevy_create_plugin` returns a leaked `Box<Box<dyn Plugin>>`, with the same
+        // layout as in this app since the ABIs match.
+        *unsafe { Box::from_raw(create().cast::<Box<dyn Plugin>>()) }
+    };
+    mem::forget(library);
+    Ok(plugin)
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+impl App {
+    /// Loads the [`Plugin`] exported by the dynamic library at `path` with
+    /// [`dynamic_plugin!`](crate::dynamic_plugin), and adds it to the app.
+    ///
+    /// The library is then watched: when it changes, its plugin is removed with all its
+    /// registrations (see [`remove_plugin`](Self::remove_plugin)) and the new version is added, on
+    /// the next [`update`](Self::update) or call to
+    /// [`reload_dynamic_plugins`](Self::reload_dynamic_plugins). This can be turned off through the
+    /// [`DynamicPlugins`] resource.
+    ///
+    /// The plugin can be loaded after the app is finished, in which case it's finished and
+    /// cleaned up right after it's built.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialization code, and the plugin it exports is trusted to
+    /// match the ABI of the app: the library must be built from a crate using [`dynamic_plugin!`](crate::dynamic_plugin),
+    /// with the same compiler and the same Bevy version as the app. This applies to every version
+    /// of the library that is reloaded.
+    pub unsafe fn load_dynamic_plugin(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<&mut Self, DynamicPluginLoadError> {
+        let path = path.as_ref();
+        let modified = modified(path);
+        // SAFETY: the caller guarantees that loading the library is sound.
+        let plugin = unsafe { load(path) }?;
+        let name = self.add_dynamic_plugin(plugin);
+        self.world_mut()
+            .get_resource_or_init::<DynamicPlugins>()
+            .plugins
+            .push(LoadedPlugin {
+                path: path.to_path_buf(),
+                name,
+                modified,
+            });
+        Ok(self)
+    }
+
+    /// Reloads the dynamic plugins whose libraries changed since they were loaded, and returns how
+    /// many were reloaded. See [`load_dynamic_plugin`](Self::load_dynamic_plugin).
+    ///
+    /// If a library fails to load, its previous version is kept and the error is returned.
+    ///
+    /// # Safety
+    ///
+    /// See [`load_dynamic_plugin`](Self::load_dynamic_plugin).
+    pub unsafe fn reload_dynamic_plugins(&mut self) -> Result<usize, DynamicPluginLoadError> {
+        let Some(dynamic_plugins) = self.world().get_resource::<DynamicPlugins>() else {
+            return Ok(0);
+        };
+        let plugins = dynamic_plugins.plugins.clone();
+
+        let mut reloaded = 0;
+        for (index, loaded) in plugins.into_iter().enumerate() {
+            let modified = modified(&loaded.path);
+            if modified == loaded.modified {
+                continue;
+            }
+            // SAFETY: the caller guarantees that loading the library is sound.
+            let plugin = unsafe { load(&loaded.path) }?;
+            info!("Reloading dynamic plugin {}", plugin.name());
+            self.remove_plugins_where(|plugin| plugin.name() == loaded.name);
+            let name = self.add_dynamic_plugin(plugin);
+            self.world_mut().resource_mut::<DynamicPlugins>().plugins[index] = LoadedPlugin {
+                path: loaded.path,
+                name,
+                modified,
+            };
+            reloaded += 1;
+        }
+        Ok(reloaded)
+    }
+
+    /// Adds a plugin loaded from a library, and returns the name it was added under.
+    fn add_dynamic_plugin(&mut self, plugin: Box<dyn Plugin>) -> String {
+        static GENERATIONS: AtomicU32 = AtomicU32::new(0);
+
+        let name = format!(
+            "{}#{}",
+            plugin.name(),
+            GENERATIONS.fetch_add(1, Ordering::Relaxed)
+        );
+        let state = self.main().plugins_state;
+        let index = self.main().plugin_registry.len();
+        let plugin = Box::new(DynamicPlugin {
+            plugin,
+            name: name.clone(),
+        });
+        if let Err(error) = self.add_boxed_plugin(plugin) {
+            error!("Failed to add dynamic plugin: {error}");
+            return name;
+        }
+
+        if matches!(state, PluginsState::Finished | PluginsState::Cleaned) {
+            let plugin = mem::replace(
+                &mut self.main_mut().plugin_registry[index],
+                Box::new(PlaceholderPlugin),
+            );
+            plugin.finish(self);
+            if state == PluginsState::Cleaned {
+                plugin.cleanup(self);
+            }
+            self.main_mut().plugin_registry[index] = plugin;
+        }
+        name
+    }
+}
+
+/// Reloads the watched dynamic plugins whose libraries changed, at most once per
+/// [check interval](DynamicPlugins::check_interval), logging the errors.
+pub(crate) fn reload_watched_plugins(app: &mut App) {
+    let Some(mut dynamic_plugins) = app.world_mut().get_resource_mut::<DynamicPlugins>() else {
+        return;
+    };
+    if !dynamic_plugins.watch {
+        return;
+    }
+    let now = Instant::now();
+    if dynamic_plugins
+        .last_check
+        .is_some_and(|last_check| now.duration_since(last_check) < dynamic_plugins.check_interval)
+    {
+        return;
+    }
+    dynamic_plugins.last_check = Some(now);
+    // SAFETY: the libraries were loaded with `App::load_dynamic_plugin`, whose caller guarantees
+    // that reloading them is sound.
+    if let Err(error) = unsafe { app.reload_dynamic_plugins() } {
+        error!("Failed to reload a dynamic plugin: {error}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_missing_library() {
+        let mut app = App::new();
+        // SAFETY: the library doesn't exist, so nothing is loaded.
+        let result = unsafe { app.load_dynamic_plugin("does/not/exist.so") };
+        assert!(matches!(result, Err(DynamicPluginLoadError::Copy { .. })));
+        assert!(!app.world().contains_resource::<DynamicPlugins>());
+    }
+
+    #[test]
+    fn throttle_watch_checks() {
+        let mut app = App::new();
+        app.init_resource::<DynamicPlugins>();
+        reload_watched_plugins(&mut app);
+        let last_check = app.world().resource::<DynamicPlugins>().last_check;
+        assert!(last_check.is_some());
+
+        // The libraries aren't checked again before the interval has elapsed.
+        reload_watched_plugins(&mut app);
+        assert_eq!(
+            app.world().resource::<DynamicPlugins>().last_check,
+            last_check
+        );
+    }
+}
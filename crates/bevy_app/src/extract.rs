@@ -0,0 +1,298 @@
+use crate::SubApp;
+use alloc::{boxed::Box, vec::Vec};
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{
+    change_detection::{DetectChanges, Ref},
+    component::{Component, Tick},
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    schedule::ScheduleLabel,
+    system::Resource,
+    world::World,
+};
+
+/// A function mirroring data from the main world into the world of a [`SubApp`], given the change
+/// tick of the main world for this extraction.
+pub(crate) type MirrorFn = Box<dyn FnMut(&mut World, &mut World, Tick) + Send>;
+
+/// The main [`World`] of the [`App`](crate::App), moved into the world of a [`SubApp`] while it
+/// extracts data from it.
+///
+/// This resource is only available during the extract schedule of the [`SubApp`], see
+/// [`SubApp::set_extract_schedule`].
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct MainWorld(World);
+
+impl MainWorld {
+    /// Wraps the main `world` to insert it into the world of a [`SubApp`].
+    pub fn new(world: World) -> Self {
+        Self(world)
+    }
+
+    /// Returns the main world.
+    pub fn into_inner(self) -> World {
+        self.0
+    }
+}
+
+/// A "scratch" world swapped with the main world while it's extracted from, to avoid allocating a
+/// new world every frame.
+#[derive(Resource, Default)]
+struct ScratchMainWorld(World);
+
+/// The entities of the world of a [`SubApp`] that mirror entities of the main world, by main world
+/// entity.
+///
+/// See [`SubApp::mirror_component`].
+#[derive(Resource, Default, Debug)]
+pub struct MirroredEntities(EntityHashMap<Entity>);
+
+impl MirroredEntities {
+    /// Returns the entity mirroring the main world entity `entity`, if any.
+    pub fn get(&self, entity: Entity) -> Option<Entity> {
+        self.0.get(&entity).copied()
+    }
+
+    /// Returns an iterator over the pairs of main world entities and their mirrors.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.0.iter().map(|(&main, &mirror)| (main, mirror))
+    }
+
+    /// Returns the mirror of `entity`, spawning it if it doesn't exist yet.
+    fn get_or_spawn(world: &mut World, entity: Entity) -> Entity {
+        let mirror = world.get_resource_or_init::<MirroredEntities>().get(entity);
+        match mirror {
+            Some(mirror) if world.get_entity(mirror).is_ok() => mirror,
+            _ => {
+                let mirror = world.spawn_empty().id();
+                world
+                    .resource_mut::<MirroredEntities>()
+                    .0
+                    .insert(entity, mirror);
+                mirror
+            }
+        }
+    }
+}
+
+impl SubApp {
+    /// Sets the schedule run by [`extract`](Self::extract), which extracts data from the main world
+    /// into the world of this app, initializing it if it doesn't exist.
+    ///
+    /// While the schedule runs, the main world is moved into the [`MainWorld`] resource of this
+    /// app, so that its systems can read from the main world and write to this one. This replaces
+    /// any function set with [`set_extract`](Self::set_extract).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::{App, AppLabel, MainWorld, SubApp};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// #[derive(Resource, Default)]
+    /// struct Steps(u32);
+    ///
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct Extract;
+    ///
+    /// #[derive(AppLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct SimulationApp;
+    ///
+    /// let mut simulation = SubApp::new();
+    /// simulation
+    ///     .init_resource::<Steps>()
+    ///     .set_extract_schedule(Extract)
+    ///     .add_systems(Extract, |main: Res<MainWorld>, mut steps: ResMut<Steps>| {
+    ///         // Read the main world through `main`.
+    ///         steps.0 += 1;
+    ///     });
+    ///
+    /// let mut app = App::new();
+    /// app.insert_sub_app(SimulationApp, simulation);
+    /// app.update();
+    /// assert_eq!(app.sub_app(SimulationApp).world().resource::<Steps>().0, 1);
+    /// ```
+    pub fn set_extract_schedule(&mut self, label: impl ScheduleLabel) -> &mut Self {
+        let label = label.intern();
+        self.init_schedule(label);
+        self.set_extract(move |main_world, world| {
+            let ScratchMainWorld(scratch) = world.remove_resource().unwrap_or_default();
+            world.insert_resource(MainWorld(core::mem::replace(main_world, scratch)));
+            world.run_schedule(label);
+            let MainWorld(inserted) = world
+                .remove_resource::<MainWorld>()
+                .expect("`MainWorld` was removed during the extract schedule");
+            world.insert_resource(ScratchMainWorld(core::mem::replace(main_world, inserted)));
+        })
+    }
+
+    /// Mirrors the components `C` of the main world into the world of this app on each
+    /// [`extract`](Self::extract), before the extract function runs.
+    ///
+    /// Each main world entity with a `C` gets a mirror entity in this app, which can be found with
+    /// the [`MirroredEntities`] resource. The component is cloned when it's added or changed, and
+    /// removed from the mirror when it's removed from the main world entity. The mirror is
+    /// despawned when the main world entity is.
+    pub fn mirror_component<C: Component + Clone>(&mut self) -> &mut Self {
+        self.world_mut().init_resource::<MirroredEntities>();
+        let mut mirrored = EntityHashSet::default();
+        let mut last_run = Tick::new(0);
+        self.mirrors
+            .push(Box::new(move |main_world, world, this_run| {
+                let despawned: Vec<_> = world
+                    .resource::<MirroredEntities>()
+                    .iter()
+                    .filter(|&(entity, _)| main_world.get_entity(entity).is_err())
+                    .collect();
+                for (entity, mirror) in despawned {
+                    world.resource_mut::<MirroredEntities>().0.remove(&entity);
+                    world.try_despawn(mirror);
+                }
+
+                mirrored.retain(|&entity| {
+                    if main_world.get_entity(entity).is_err() {
+                        return false;
+                    }
+                    if main_world.get::<C>(entity).is_none() {
+                        if let Some(mirror) = world.resource::<MirroredEntities>().get(entity) {
+                            if let Ok(mut mirror) = world.get_entity_mut(mirror) {
+                                mirror.remove::<C>();
+                            }
+                        }
+                        return false;
+                    }
+                    true
+                });
+
+                let mut query = main_world.query::<(Entity, Ref<C>)>();
+                for (entity, component) in query.iter(main_world) {
+                    if mirrored.contains(&entity)
+                        && !component.last_changed().is_newer_than(last_run, this_run)
+                    {
+                        continue;
+                    }
+                    mirrored.insert(entity);
+                    let mirror = MirroredEntities::get_or_spawn(world, entity);
+                    world.entity_mut(mirror).insert(C::clone(&component));
+                }
+                last_run = this_run;
+            }));
+        self
+    }
+
+    /// Mirrors the resource `R` of the main world into the world of this app on each
+    /// [`extract`](Self::extract), before the extract function runs.
+    ///
+    /// The resource is cloned when it's added or changed, and removed from this app when it's
+    /// removed from the main world.
+    pub fn mirror_resource<R: Resource + Clone>(&mut self) -> &mut Self {
+        let mut last_run = Tick::new(0);
+        self.mirrors
+            .push(Box::new(move |main_world, world, this_run| {
+                match main_world.get_resource_ref::<R>() {
+                    Some(resource)
+                        if !world.contains_resource::<R>()
+                            || resource.last_changed().is_newer_than(last_run, this_run) =>
+                    {
+                        world.insert_resource(R::clone(&resource));
+                    }
+                    Some(_) => {}
+                    None => {
+                        world.remove_resource::<R>();
+                    }
+                }
+                last_run = this_run;
+            }));
+        self
+    }
+
+    /// Adds a function mirroring data from the main world into the world of this app, run by
+    /// [`extract`](Self::extract) before the extract function.
+    ///
+    /// The first argument is the main `World`, the second argument is the app `World`.
+    pub fn add_mirror<F>(&mut self, mut mirror: F) -> &mut Self
+    where
+        F: FnMut(&mut World, &mut World) + Send + 'static,
+    {
+        self.mirrors.push(Box::new(move |main_world, world, _| {
+            mirror(main_world, world);
+        }));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_app, App, AppLabel};
+
+    #[derive(AppLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Mirror;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Extract;
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Position(u32);
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct Time(u32);
+
+    #[test]
+    fn mirror_components_and_resources() {
+        let mut app = App::new();
+        app.insert_resource(Time(1));
+        let mut sub_app = SubApp::new();
+        sub_app
+            .mirror_component::<Position>()
+            .mirror_resource::<Time>()
+            .set_extract_schedule(Extract);
+        app.insert_sub_app(Mirror, sub_app);
+
+        let entity = app.world_mut().spawn(Position(1)).id();
+        app.update();
+        let world = app.sub_app_mut(Mirror).world_mut();
+        let mirror = world.resource::<MirroredEntities>().get(entity).unwrap();
+        assert_eq!(world.get::<Position>(mirror), Some(&Position(1)));
+        assert_eq!(world.resource::<Time>(), &Time(1));
+
+        app.world_mut().get_mut::<Position>(entity).unwrap().0 = 2;
+        app.world_mut().resource_mut::<Time>().0 = 2;
+        app.update();
+        let world = app.sub_app(Mirror).world();
+        assert_eq!(world.get::<Position>(mirror), Some(&Position(2)));
+        assert_eq!(world.resource::<Time>(), &Time(2));
+
+        app.world_mut().entity_mut(entity).remove::<Position>();
+        app.world_mut().remove_resource::<Time>();
+        app.update();
+        let world = app.sub_app(Mirror).world();
+        assert!(world.get::<Position>(mirror).is_none());
+        assert!(!world.contains_resource::<Time>());
+
+        app.world_mut().entity_mut(entity).insert(Position(3));
+        app.update();
+        app.world_mut().despawn(entity);
+        app.update();
+        let world = app.sub_app(Mirror).world();
+        assert!(world.get_entity(mirror).is_err());
+        assert!(world.resource::<MirroredEntities>().get(entity).is_none());
+    }
+
+    #[test]
+    fn mirrors_share_one_change_tick() {
+        let mut main_world = World::new();
+        main_world.insert_resource(Time(1));
+        main_world.spawn(Position(1));
+        let mut sub_app = SubApp::new();
+        sub_app
+            .mirror_component::<Position>()
+            .mirror_resource::<Time>()
+            .set_extract_schedule(Extract);
+
+        let tick = main_world.change_tick();
+        sub_app.extract(&mut main_world);
+        assert_eq!(main_world.change_tick().get(), tick.get() + 1);
+        assert_eq!(main_world.resource::<Time>(), &Time(1));
+        assert!(!sub_app.world().contains_resource::<MainWorld>());
+    }
+}
@@ -6,7 +6,7 @@
     )
 )]
 #![cfg_attr(any(docsrs, docsrs_dep), feature(doc_auto_cfg, rustdoc_internals))]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "dynamic_plugins"), forbid(unsafe_code))]
 #![deny(
     clippy::allow_attributes,
     clippy::allow_attributes_without_reason,
@@ -26,28 +26,44 @@ extern crate std;
 extern crate alloc;
 
 mod app;
+mod capabilities;
+mod component_changes;
+#[cfg(feature = "dynamic_plugins")]
+mod dynamic_plugin;
+mod extract;
 mod main_schedule;
 mod panic_handler;
 mod plugin;
 mod plugin_group;
+#[cfg(feature = "std")]
+mod run_report;
 mod schedule_runner;
 mod sub_app;
 #[cfg(feature = "bevy_tasks")]
 mod task_pool_plugin;
 #[cfg(all(any(unix, windows), feature = "std"))]
 mod terminal_ctrl_c_handler;
+mod ticker;
 
 pub use app::*;
+pub use capabilities::*;
+pub use component_changes::*;
+#[cfg(feature = "dynamic_plugins")]
+pub use dynamic_plugin::*;
+pub use extract::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
 pub use plugin_group::*;
+#[cfg(feature = "std")]
+pub use run_report::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
 #[cfg(feature = "bevy_tasks")]
 pub use task_pool_plugin::*;
 #[cfg(all(any(unix, windows), feature = "std"))]
 pub use terminal_ctrl_c_handler::*;
+pub use ticker::*;
 
 /// The app prelude.
 ///
@@ -1,4 +1,18 @@
 use crate::App;
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use bevy_ecs::{
+    archetype::ArchetypeEntity,
+    component::ComponentId,
+    entity::Entity,
+    observer::Observer,
+    schedule::{InternedScheduleLabel, Schedules, SystemSet},
+    world::World,
+};
+use bevy_utils::HashSet;
 use core::any::Any;
 use downcast_rs::{impl_downcast, Downcast};
 
@@ -21,6 +35,10 @@ use downcast_rs::{impl_downcast, Downcast};
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
 ///
+/// When removing a plugin from an [`App`] with [`App::remove_plugin`], the app calls
+/// [`Plugin::remove`], then tears down the systems, resources and schedules the plugin added
+/// during [`Plugin::build`].
+///
 /// ## Defining a plugin.
 ///
 /// Most plugins are simply functions that add configuration to an [`App`].
@@ -78,6 +96,13 @@ pub trait Plugin: Downcast + Any + Send + Sync {
         // do nothing
     }
 
+    /// Runs when the plugin is removed with [`App::remove_plugin`], before the systems, resources,
+    /// schedules and observers added during [`build`](Plugin::build) are torn down. This can be
+    /// used to undo what the app doesn't track, like spawned entities.
+    fn remove(&self, _app: &mut App) {
+        // do nothing
+    }
+
     /// Configures a name for the [`Plugin`] which is primarily used for checking plugin
     /// uniqueness and debugging.
     fn name(&self) -> &str {
@@ -93,6 +118,132 @@ pub trait Plugin: Downcast + Any + Send + Sync {
 
 impl_downcast!(Plugin);
 
+/// The [`SystemSet`] of the systems added by a [`Plugin`] during [`Plugin::build`], identified by
+/// the [name](Plugin::name) of the plugin.
+///
+/// The set can be used to order systems relative to the systems of a plugin.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PluginSystems(String);
+
+impl PluginSystems {
+    /// Returns the set of the systems added by the plugin with the given name.
+    pub fn new(plugin_name: impl Into<String>) -> Self {
+        Self(plugin_name.into())
+    }
+
+    /// Returns the set of the systems added by the plugin `T`, if it uses the default name.
+    pub fn of<T: Plugin>() -> Self {
+        Self::new(core::any::type_name::<T>())
+    }
+}
+
+/// The [`SystemSet`] of the systems added by a single build of a [`Plugin`].
+///
+/// Unlike [`PluginSystems`], which is shared by every build of a plugin, this set is disabled for
+/// good when the plugin is removed, so adding the plugin again doesn't run its systems twice.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct PluginBuild(pub(crate) u32);
+
+/// What was added to the [`World`] of an app while a [`Plugin`] was built.
+#[derive(Default)]
+pub(crate) struct PluginRegistrations {
+    pub(crate) builds: Vec<PluginBuild>,
+    pub(crate) resources: Vec<ComponentId>,
+    pub(crate) schedules: Vec<InternedScheduleLabel>,
+    pub(crate) observers: Vec<Entity>,
+}
+
+impl PluginRegistrations {
+    /// Adds the registrations of another build of the same plugin.
+    pub(crate) fn extend(&mut self, other: PluginRegistrations) {
+        self.builds.extend(other.builds);
+        self.resources.extend(other.resources);
+        self.schedules.extend(other.schedules);
+        self.observers.extend(other.observers);
+    }
+
+    /// Removes the registrations that belong to another plugin.
+    pub(crate) fn subtract(&mut self, other: &PluginRegistrations) {
+        self.resources.retain(|id| !other.resources.contains(id));
+        self.schedules
+            .retain(|label| !other.schedules.contains(label));
+        self.observers
+            .retain(|entity| !other.observers.contains(entity));
+    }
+}
+
+/// The resources, schedules and observers of a [`World`] before a [`Plugin`] is built, to find
+/// what it added.
+pub(crate) struct RegistrationSnapshot {
+    resources: HashSet<ComponentId>,
+    schedules: HashSet<InternedScheduleLabel>,
+    observers: HashSet<Entity>,
+}
+
+impl RegistrationSnapshot {
+    pub(crate) fn new(world: &World) -> Self {
+        Self {
+            resources: resource_ids(world).collect(),
+            schedules: schedule_labels(world).collect(),
+            observers: observer_entities(world).collect(),
+        }
+    }
+
+    /// Returns what was added to `world` by the plugin `build` since the snapshot.
+    pub(crate) fn added(&self, world: &World, build: PluginBuild) -> PluginRegistrations {
+        PluginRegistrations {
+            builds: vec![build],
+            resources: resource_ids(world)
+                .filter(|id| !self.resources.contains(id))
+                .collect(),
+            schedules: schedule_labels(world)
+                .filter(|label| !self.schedules.contains(label))
+                .collect(),
+            observers: observer_entities(world)
+                .filter(|entity| !self.observers.contains(entity))
+                .collect(),
+        }
+    }
+}
+
+fn resource_ids(world: &World) -> impl Iterator<Item = ComponentId> + '_ {
+    let storages = world.storages();
+    storages
+        .resources
+        .iter()
+        .filter(|(_, data)| data.is_present())
+        .map(|(id, _)| id)
+        .chain(
+            storages
+                .non_send_resources
+                .iter()
+                .filter(|(_, data)| data.is_present())
+                .map(|(id, _)| id),
+        )
+}
+
+fn schedule_labels(world: &World) -> impl Iterator<Item = InternedScheduleLabel> + '_ {
+    world
+        .get_resource::<Schedules>()
+        .into_iter()
+        .flat_map(Schedules::iter)
+        .map(|(_, schedule)| schedule.label())
+}
+
+fn observer_entities(world: &World) -> impl Iterator<Item = Entity> + '_ {
+    let observer = world.component_id::<Observer>();
+    world
+        .archetypes()
+        .iter()
+        .filter(move |archetype| observer.is_some_and(|id| archetype.contains(id)))
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+}
+
+/// Returns the name a plugin is tracked under.
+pub(crate) fn plugin_name(plugin: &dyn Plugin) -> String {
+    plugin.name().to_string()
+}
+
 impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     fn build(&self, app: &mut App) {
         self(app);
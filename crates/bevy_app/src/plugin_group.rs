@@ -1,4 +1,4 @@
-use crate::{App, AppError, Plugin};
+use crate::{App, AppError, Capability, PlatformCapabilities, Plugin};
 use alloc::{
     boxed::Box,
     string::{String, ToString},
@@ -216,6 +216,7 @@ pub trait PluginGroup: Sized {
 struct PluginEntry {
     plugin: Box<dyn Plugin>,
     enabled: bool,
+    requirements: Vec<Box<dyn Capability>>,
 }
 
 impl PluginGroup for PluginGroupBuilder {
@@ -272,6 +273,7 @@ impl PluginGroupBuilder {
             PluginEntry {
                 plugin: Box::new(plugin),
                 enabled: true,
+                requirements: Vec::new(),
             },
             added_at_index,
         );
@@ -507,6 +509,40 @@ impl PluginGroupBuilder {
         self
     }
 
+    /// Makes a [`Plugin`] require a [`Capability`] of the platform: when the group is added to an
+    /// [`App`], the plugin is left out if the capability is missing. If there are no plugins of
+    /// type `T` in this group, it will panic.
+    ///
+    /// The capabilities are detected when the group is added, and the decisions are kept in the
+    /// [`PlatformCapabilities`] resource.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, CapabilityFn, PlatformCapabilities, PluginGroupBuilder};
+    /// # #[derive(Default)]
+    /// # struct AudioPlugin;
+    /// # impl Plugin for AudioPlugin { fn build(&self, _: &mut App) {} }
+    /// # struct GamePlugins;
+    /// # impl PluginGroup for GamePlugins {
+    /// #     fn build(self) -> PluginGroupBuilder {
+    /// #         PluginGroupBuilder::start::<Self>().add(AudioPlugin)
+    /// #     }
+    /// # }
+    /// const AUDIO: CapabilityFn = CapabilityFn::new("audio", || false);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(GamePlugins.build().require::<AudioPlugin>(AUDIO));
+    /// assert!(!app.is_plugin_added::<AudioPlugin>());
+    /// assert_eq!(app.world().resource::<PlatformCapabilities>().get("audio"), Some(false));
+    /// ```
+    pub fn require<T: Plugin>(mut self, capability: impl Capability) -> Self {
+        let plugin_entry = self
+            .plugins
+            .get_mut(&TypeId::of::<T>())
+            .expect("Cannot require a capability for a plugin that does not exist.");
+        plugin_entry.requirements.push(Box::new(capability));
+        self
+    }
+
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
     /// in the order specified.
     ///
@@ -517,6 +553,15 @@ impl PluginGroupBuilder {
     pub fn finish(mut self, app: &mut App) {
         for ty in &self.order {
             if let Some(entry) = self.plugins.remove(ty) {
+                if entry.enabled && !entry.requirements.is_empty() {
+                    let included = app
+                        .world_mut()
+                        .get_resource_or_init::<PlatformCapabilities>()
+                        .decide(&self.group_name, entry.plugin.name(), &entry.requirements);
+                    if !included {
+                        continue;
+                    }
+                }
                 if entry.enabled {
                     debug!("added plugin: {}", entry.plugin.name());
                     if let Err(AppError::DuplicatePlugin { plugin_name }) =
@@ -0,0 +1,223 @@
+use crate::{App, AppExit};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use bevy_ecs::{result::DefaultSystemErrorHandler, schedule::ScheduleTimings, world::World};
+use bevy_utils::Instant;
+use core::time::Duration;
+use std::sync::{Mutex, PoisonError};
+
+/// What happened during a bounded run of an [`App`], returned by [`App::update_n`] and
+/// [`App::run_until`].
+#[derive(Debug, Clone, Default)]
+pub struct RunReport {
+    /// The number of frames that ran.
+    pub frames: u32,
+    /// The time the run took.
+    pub elapsed: Duration,
+    /// The time each schedule of the main world took to run.
+    pub schedules: ScheduleTimings,
+    /// The errors returned by systems of the main world and of every sub-app, in the order they
+    /// were returned.
+    pub errors: Vec<ReportedError>,
+    /// The exit the app requested, which ended the run.
+    pub exit: Option<AppExit>,
+}
+
+/// An error returned by a system during a bounded run, see [`RunReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportedError {
+    /// The name of the system that returned the error.
+    pub system: Cow<'static, str>,
+    /// The error, formatted with [`Display`](core::fmt::Display).
+    pub error: String,
+}
+
+impl App {
+    /// Updates the app `frames` times, or until it requests to exit, and returns a [`RunReport`]
+    /// of the run.
+    ///
+    /// This is meant for integration tests and benchmarks. While the run lasts, errors returned by
+    /// systems of the app and its sub-apps are collected in the report instead of being passed to
+    /// the [`DefaultSystemErrorHandler`] of their world. Schedules with their own error handler
+    /// still use it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn fail() -> Result<(), BevyError> {
+    ///     Err("failed".into())
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_systems(Update, fail);
+    /// let report = app.update_n(3);
+    /// assert_eq!(report.frames, 3);
+    /// assert_eq!(report.errors.len(), 3);
+    /// assert_eq!(report.schedules.get(Update).unwrap().runs, 3);
+    /// ```
+    pub fn update_n(&mut self, frames: u32) -> RunReport {
+        self.run_reported(|_, ran| ran >= frames)
+    }
+
+    /// Updates the app until `condition` returns `true` for its world, or until it requests to
+    /// exit, and returns a [`RunReport`] of the run.
+    ///
+    /// `condition` is checked before each frame, so no frame runs if it's already met. See
+    /// [`update_n`](Self::update_n) for how errors are reported.
+    pub fn run_until(&mut self, mut condition: impl FnMut(&World) -> bool) -> RunReport {
+        self.run_reported(|world, _| condition(world))
+    }
+
+    fn run_reported(&mut self, mut done: impl FnMut(&World, u32) -> bool) -> RunReport {
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let error_handlers: Vec<_> = self
+            .sub_apps
+            .iter_mut()
+            .map(|sub_app| {
+                let world = sub_app.world_mut();
+                let error_handler = world.remove_resource::<DefaultSystemErrorHandler>();
+                let errors = errors.clone();
+                world.insert_resource(DefaultSystemErrorHandler::new(move |error, context| {
+                    errors
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .push(ReportedError {
+                            system: context.name,
+                            error: error.to_string(),
+                        });
+                }));
+                error_handler
+            })
+            .collect();
+
+        let world = self.world_mut();
+        let timings = world.remove_resource::<ScheduleTimings>();
+        world.init_resource::<ScheduleTimings>();
+
+        let mut report = RunReport::default();
+        let start = Instant::now();
+        while !done(self.world(), report.frames) {
+            self.update();
+            report.frames += 1;
+            if let Some(exit) = self.should_exit() {
+                report.exit = Some(exit);
+                break;
+            }
+        }
+        report.elapsed = start.elapsed();
+
+        let world = self.world_mut();
+        report.schedules = world
+            .remove_resource::<ScheduleTimings>()
+            .unwrap_or_default();
+        if let Some(timings) = timings {
+            world.insert_resource(timings);
+        }
+        for (sub_app, error_handler) in self.sub_apps.iter_mut().zip(error_handlers) {
+            let world = sub_app.world_mut();
+            world.remove_resource::<DefaultSystemErrorHandler>();
+            if let Some(error_handler) = error_handler {
+                world.insert_resource(error_handler);
+            }
+        }
+        report.errors =
+            core::mem::take(&mut *errors.lock().unwrap_or_else(PoisonError::into_inner));
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_app, AppLabel, Main, SubApp, Update};
+    use bevy_ecs::{
+        event::EventWriter,
+        result::Result,
+        schedule::ScheduleLabel,
+        system::{ResMut, Resource},
+    };
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    fn count(mut counter: ResMut<Counter>) -> Result {
+        counter.0 += 1;
+        if counter.0 == 2 {
+            Err("second frame".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn update_n() {
+        let mut app = App::new();
+        app.init_resource::<Counter>().add_systems(Update, count);
+
+        let report = app.update_n(3);
+        assert_eq!(report.frames, 3);
+        assert!(report.exit.is_none());
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].error, "second frame");
+        assert!(report.errors[0].system.ends_with("count"));
+        assert_eq!(report.schedules.get(Main).unwrap().runs, 3);
+        assert!(!app.world().contains_resource::<ScheduleTimings>());
+        assert!(!app.world().contains_resource::<DefaultSystemErrorHandler>());
+    }
+
+    #[test]
+    fn update_n_collects_sub_app_errors() {
+        #[derive(AppLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        struct Sub;
+
+        fn fail_in_sub_app() -> Result {
+            Err("sub app".into())
+        }
+
+        let mut app = App::new();
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Main.intern());
+        sub_app.add_systems(Main, fail_in_sub_app);
+        app.insert_sub_app(Sub, sub_app);
+        app.world_mut()
+            .insert_resource(DefaultSystemErrorHandler::new(|_, _| {
+                panic!("errors should be reported")
+            }));
+
+        let report = app.update_n(2);
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[0].error, "sub app");
+        assert!(report.errors[0].system.ends_with("fail_in_sub_app"));
+        assert!(app.world().contains_resource::<DefaultSystemErrorHandler>());
+        let sub_world = app.sub_app(Sub).world();
+        assert!(!sub_world.contains_resource::<DefaultSystemErrorHandler>());
+    }
+
+    #[test]
+    fn run_until() {
+        let mut app = App::new();
+        app.init_resource::<Counter>().add_systems(
+            Update,
+            |mut counter: ResMut<Counter>, mut exits: EventWriter<AppExit>| {
+                counter.0 += 1;
+                if counter.0 == 10 {
+                    exits.send(AppExit::Success);
+                }
+            },
+        );
+
+        let report = app.run_until(|world| world.resource::<Counter>().0 == 4);
+        assert_eq!(report.frames, 4);
+        assert!(report.exit.is_none());
+
+        let report = app.run_until(|_| false);
+        assert_eq!(report.frames, 6);
+        assert_eq!(report.exit, Some(AppExit::Success));
+    }
+}
@@ -1,10 +1,15 @@
-use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
+use crate::{
+    extract::MirrorFn, send_component_change_events, App, AppLabel, ComponentChanged,
+    ComponentRemoved, InternedAppLabel, Last, Plugin, PluginBuild, PluginRegistrations,
+    PluginSystems, Plugins, PluginsState,
+};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
-    event::EventRegistry,
+    event::{EventRegistry, EventRetention},
     prelude::*,
     schedule::{InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel},
     system::{SystemId, SystemInput},
+    world::FromWorldWith,
 };
 use bevy_utils::{HashMap, HashSet};
 use core::fmt::Debug;
@@ -66,6 +71,12 @@ pub struct SubApp {
     /// The names of plugins that have been added to this app. (used to track duplicates and
     /// already-registered plugins)
     pub(crate) plugin_names: HashSet<String>,
+    /// The system sets of the plugins being built, the innermost last.
+    pub(crate) building_plugins: Vec<(PluginSystems, PluginBuild)>,
+    /// The number of plugin builds so far, used to give each build its own [`PluginBuild`] set.
+    pub(crate) plugin_builds: u32,
+    /// What each plugin added while it was built, by plugin name.
+    pub(crate) plugin_registrations: HashMap<String, PluginRegistrations>,
     /// Panics if an update is attempted while plugins are building.
     pub(crate) plugin_build_depth: usize,
     pub(crate) plugins_state: PluginsState,
@@ -74,6 +85,8 @@ pub struct SubApp {
     /// A function that gives mutable access to two app worlds. This is primarily
     /// intended for copying data from the main world to secondary worlds.
     extract: Option<ExtractFn>,
+    /// Functions mirroring data from the main world, run before `extract`.
+    pub(crate) mirrors: Vec<MirrorFn>,
 }
 
 impl Debug for SubApp {
@@ -90,10 +103,14 @@ impl Default for SubApp {
             world,
             plugin_registry: Vec::default(),
             plugin_names: HashSet::default(),
+            building_plugins: Vec::default(),
+            plugin_builds: 0,
+            plugin_registrations: HashMap::default(),
             plugin_build_depth: 0,
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
+            mirrors: Vec::new(),
         }
     }
 }
@@ -147,9 +164,19 @@ impl SubApp {
 
     /// Extracts data from `world` into the app's world using the registered extract method.
     ///
-    /// **Note:** There is no default extract method. Calling `extract` does nothing if
-    /// [`set_extract`](Self::set_extract) has not been called.
+    /// The functions added with [`add_mirror`](Self::add_mirror), like the ones of
+    /// [`mirror_component`](Self::mirror_component), run first.
+    ///
+    /// **Note:** There is no default extract method. Calling `extract` only runs the mirror
+    /// functions if [`set_extract`](Self::set_extract) has not been called.
     pub fn extract(&mut self, world: &mut World) {
+        if !self.mirrors.is_empty() {
+            // The mirrors share one tick, so that they don't each advance the main world's.
+            let this_run = world.increment_change_tick();
+            for mirror in &mut self.mirrors {
+                mirror(world, &mut self.world, this_run);
+            }
+        }
         if let Some(f) = self.extract.as_mut() {
             f(world, &mut self.world);
         }
@@ -207,14 +234,26 @@ impl SubApp {
         self
     }
 
+    /// See [`App::init_resource_lazy`].
+    pub fn init_resource_lazy<R: Resource + FromWorldWith>(&mut self) -> &mut Self {
+        self.world.init_resource_lazy::<R>();
+        self
+    }
+
     /// See [`App::add_systems`].
     pub fn add_systems<M>(
         &mut self,
         schedule: impl ScheduleLabel,
         systems: impl IntoSystemConfigs<M>,
     ) -> &mut Self {
+        let sets = self.building_plugins.last().cloned();
         let mut schedules = self.world.resource_mut::<Schedules>();
-        schedules.add_systems(schedule, systems);
+        match sets {
+            Some((plugin, build)) => {
+                schedules.add_systems(schedule, systems.in_set(plugin).in_set(build))
+            }
+            None => schedules.add_systems(schedule, systems),
+        };
 
         self
     }
@@ -347,6 +386,34 @@ impl SubApp {
         self
     }
 
+    /// See [`App::add_event_with`].
+    pub fn add_event_with<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        self.add_event::<T>();
+        self.world
+            .resource_mut::<Events<T>>()
+            .bypass_change_detection()
+            .set_retention(retention);
+
+        self
+    }
+
+    /// See [`App::track_changes`].
+    pub fn track_changes<T: Component>(&mut self) -> &mut Self {
+        if !self
+            .world
+            .contains_resource::<Events<ComponentChanged<T>>>()
+        {
+            self.add_event::<ComponentChanged<T>>()
+                .add_event::<ComponentRemoved<T>>()
+                .add_systems(Last, send_component_change_events::<T>);
+        }
+
+        self
+    }
+
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
         self.run_as_app(|app| plugins.add_to_app(app));
@@ -431,6 +498,18 @@ impl SubApp {
         self
     }
 
+    /// See [`App::register_remote_type`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_remote_type<W>(&mut self) -> &mut Self
+    where
+        W: bevy_reflect::ReflectRemote + bevy_reflect::GetTypeRegistration + bevy_reflect::TypePath,
+        W::Remote: core::any::Any,
+    {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        registry.write().register_remote::<W>();
+        self
+    }
+
     /// See [`App::register_type_data`].
     #[cfg(feature = "bevy_reflect")]
     pub fn register_type_data<
@@ -469,6 +548,21 @@ impl SubApp {
         registry.write().register_with_name(name, function).unwrap();
         self
     }
+
+    /// See [`App::register_method`].
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_method<T, F, Marker>(&mut self, name: &str, function: F) -> &mut Self
+    where
+        T: bevy_reflect::TypePath,
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + 'static,
+    {
+        let registry = self.world.resource_mut::<AppFunctionRegistry>();
+        registry
+            .write()
+            .register_method::<T, F, Marker>(name, function)
+            .unwrap();
+        self
+    }
 }
 
 /// The collection of sub-apps that belong to an [`App`].
@@ -0,0 +1,168 @@
+use crate::{App, AppExit, PluginsState};
+use bevy_ecs::{
+    event::{Event, SendBatchIds},
+    system::Resource,
+    world::World,
+};
+use core::time::Duration;
+
+/// The time elapsed since the previous tick of an [`AppTicker`], inserted before each tick.
+///
+/// `bevy_time` advances its clocks by this duration instead of reading the system clock while
+/// this resource exists.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TickDelta(pub Duration);
+
+/// Drives an [`App`] from the loop of another application, instead of handing control over to
+/// [`App::run`].
+///
+/// This embeds Bevy as a library, for instance in an editor or a tool with its own event loop:
+/// the host calls [`tick`](Self::tick) whenever it wants the app to advance, and feeds its input
+/// to the app as events in between. Created with [`App::make_ticker`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use core::time::Duration;
+/// #[derive(Event)]
+/// struct HostInput(u32);
+///
+/// let mut app = App::new();
+/// app.add_event::<HostInput>();
+/// let mut ticker = app.make_ticker();
+///
+/// // In the loop of the host application:
+/// ticker.send_event(HostInput(1));
+/// if let Some(exit) = ticker.tick(Duration::from_millis(16)) {
+///     // The app requested to exit.
+/// }
+/// ```
+pub struct AppTicker {
+    app: App,
+}
+
+impl App {
+    /// Finishes building the plugins of the app, and returns an [`AppTicker`] to drive it from the
+    /// loop of another application.
+    ///
+    /// The runner of the app is not used. This waits for the plugins to be ready, like the default
+    /// runners do.
+    pub fn make_ticker(mut self) -> AppTicker {
+        if self.plugins_state() != PluginsState::Cleaned {
+            while self.plugins_state() == PluginsState::Adding {
+                #[cfg(all(not(target_arch = "wasm32"), feature = "bevy_tasks"))]
+                bevy_tasks::tick_global_task_pools_on_main_thread();
+            }
+            self.finish();
+            self.cleanup();
+        }
+        AppTicker { app: self }
+    }
+}
+
+impl AppTicker {
+    /// Advances the app by one frame, `delta` after the previous one, and returns the
+    /// [`AppExit`] if the app requested to exit.
+    ///
+    /// `delta` is inserted as the [`TickDelta`] resource before the app is updated.
+    pub fn tick(&mut self, delta: Duration) -> Option<AppExit> {
+        self.app.world_mut().insert_resource(TickDelta(delta));
+        self.app.update();
+        self.app.should_exit()
+    }
+
+    /// Sends an event to the app, to be read during the next ticks.
+    pub fn send_event<E: Event>(&mut self, event: E) -> &mut Self {
+        self.app.world_mut().send_event(event);
+        self
+    }
+
+    /// Sends a batch of events to the app, and returns their ids.
+    pub fn send_event_batch<E: Event>(
+        &mut self,
+        events: impl IntoIterator<Item = E>,
+    ) -> Option<SendBatchIds<E>> {
+        self.app.world_mut().send_event_batch(events)
+    }
+
+    /// Returns a reference to the [`World`] of the app, to read its state between ticks.
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    /// Returns a mutable reference to the [`World`] of the app, to change its state between ticks.
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Returns a reference to the driven [`App`].
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns a mutable reference to the driven [`App`].
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Returns the driven [`App`], without the [`TickDelta`] of the last tick, so that it reads
+    /// the system clock again.
+    pub fn into_app(mut self) -> App {
+        self.app.world_mut().remove_resource::<TickDelta>();
+        self.app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Update;
+    use bevy_ecs::{
+        event::EventReader,
+        system::{Res, ResMut},
+    };
+
+    #[derive(Event)]
+    struct Input(u32);
+
+    #[derive(Resource, Default)]
+    struct Total {
+        input: u32,
+        elapsed: Duration,
+    }
+
+    #[test]
+    fn tick_with_events() {
+        let mut app = App::new();
+        app.add_event::<Input>()
+            .init_resource::<Total>()
+            .add_systems(
+                Update,
+                |mut inputs: EventReader<Input>,
+                 delta: Res<TickDelta>,
+                 mut total: ResMut<Total>| {
+                    total.input += inputs.read().map(|input| input.0).sum::<u32>();
+                    total.elapsed += delta.0;
+                },
+            );
+
+        let mut ticker = app.make_ticker();
+        ticker.send_event(Input(1)).send_event(Input(2));
+        assert!(ticker.tick(Duration::from_millis(10)).is_none());
+        ticker.send_event(Input(3));
+        assert!(ticker.tick(Duration::from_millis(20)).is_none());
+
+        let total = ticker.world().resource::<Total>();
+        assert_eq!(total.input, 6);
+        assert_eq!(total.elapsed, Duration::from_millis(30));
+
+        ticker.world_mut().send_event(AppExit::Success);
+        assert_eq!(ticker.tick(Duration::ZERO), Some(AppExit::Success));
+
+        let app = ticker.into_app();
+        assert!(!app.world().contains_resource::<TickDelta>());
+        assert_eq!(app.world().resource::<Total>().input, 6);
+    }
+}
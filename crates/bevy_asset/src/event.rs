@@ -1,4 +1,4 @@
-use crate::{Asset, AssetId, AssetLoadError, AssetPath, UntypedAssetId};
+use crate::{saver::AssetSaveError, Asset, AssetId, AssetLoadError, AssetPath, UntypedAssetId};
 use bevy_ecs::event::Event;
 use bevy_reflect::Reflect;
 use core::fmt::Debug;
@@ -42,6 +42,74 @@ impl<A: Asset> From<&AssetLoadFailedEvent<A>> for UntypedAssetLoadFailedEvent {
     }
 }
 
+/// An event emitted when an [`Asset`] was saved with [`AssetServer::save`](crate::AssetServer::save).
+#[derive(Event, Clone, Debug)]
+pub struct AssetSavedEvent<A: Asset> {
+    pub id: AssetId<A>,
+    /// The asset path the asset was saved to.
+    pub path: AssetPath<'static>,
+}
+
+/// An event emitted when saving an [`Asset`] with [`AssetServer::save`](crate::AssetServer::save) fails.
+#[derive(Event, Clone, Debug)]
+pub struct AssetSaveFailedEvent<A: Asset> {
+    pub id: AssetId<A>,
+    /// The asset path the asset was saved to.
+    pub path: AssetPath<'static>,
+    /// Why the asset failed to save.
+    pub error: AssetSaveError,
+}
+
+/// What changed in an [`Asset`] when it was reloaded, as reported by its
+/// [`AssetLoader`](crate::AssetLoader).
+///
+/// Loaders record fingerprints of the labeled sub-assets and properties they load with
+/// [`LoadContext::fingerprint_label`](crate::LoadContext::fingerprint_label) and
+/// [`LoadContext::fingerprint_property`](crate::LoadContext::fingerprint_property). The ones whose
+/// fingerprints changed since the previous load are listed here, including the ones that were
+/// added or removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetChange {
+    /// The labeled sub-assets that changed, sorted.
+    pub labels: Vec<String>,
+    /// The properties that changed, sorted.
+    pub properties: Vec<String>,
+}
+
+impl AssetChange {
+    /// Returns `true` if nothing the loader fingerprints changed.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty() && self.properties.is_empty()
+    }
+
+    /// Returns `true` if the labeled sub-asset `label` changed.
+    pub fn is_label_changed(&self, label: &str) -> bool {
+        self.labels
+            .binary_search_by(|l| l.as_str().cmp(label))
+            .is_ok()
+    }
+
+    /// Returns `true` if the property `property` changed.
+    pub fn is_property_changed(&self, property: &str) -> bool {
+        self.properties
+            .binary_search_by(|p| p.as_str().cmp(property))
+            .is_ok()
+    }
+}
+
+/// An event emitted when an [`Asset`] whose loader records fingerprints is reloaded, for instance
+/// by hot reloading, with what changed since its previous load.
+///
+/// This is emitted alongside [`AssetEvent::Modified`], so consumers can skip re-processing the
+/// parts of the asset that didn't change. Assets whose loader doesn't record fingerprints only emit
+/// [`AssetEvent::Modified`], and should be fully re-processed.
+#[derive(Event, Clone, Debug)]
+pub struct AssetReloaded<A: Asset> {
+    pub id: AssetId<A>,
+    /// What changed in the asset.
+    pub change: AssetChange,
+}
+
 /// Events that occur for a specific loaded [`Asset`], such as "value changed" events and "dependency" events.
 #[derive(Event, Reflect)]
 pub enum AssetEvent<A: Asset> {
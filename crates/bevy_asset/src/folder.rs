@@ -1,6 +1,8 @@
 use crate as bevy_asset;
-use crate::{Asset, UntypedHandle};
+use crate::{Asset, AssetServer, Assets, Handle, UntypedAssetId, UntypedHandle};
 use bevy_reflect::TypePath;
+use core::{any::TypeId, marker::PhantomData};
+use std::path::{Path, PathBuf};
 
 /// A "loaded folder" containing handles for all assets stored in a given [`AssetPath`].
 ///
@@ -10,3 +12,219 @@ pub struct LoadedFolder {
     #[dependency]
     pub handles: Vec<UntypedHandle>,
 }
+
+impl LoadedFolder {
+    /// Returns an iterator over the handles of the assets of type `A` in the folder.
+    pub fn typed_handles<A: Asset>(&self) -> impl Iterator<Item = Handle<A>> + '_ {
+        self.handles
+            .iter()
+            .filter(|handle| handle.type_id() == TypeId::of::<A>())
+            .map(|handle| handle.clone().typed::<A>())
+    }
+
+    /// Returns how many of the assets of the folder have finished loading.
+    pub fn progress(&self, asset_server: &AssetServer) -> FolderLoadProgress {
+        FolderLoadProgress::new(asset_server, self.handles.iter().map(UntypedHandle::id))
+    }
+}
+
+/// A handle to a [`LoadedFolder`] of assets of type `A`, returned by
+/// [`AssetServer::load_folder_typed`].
+///
+/// The folder may contain assets of other types, which are skipped.
+pub struct TypedFolderHandle<A: Asset> {
+    handle: Handle<LoadedFolder>,
+    marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Asset> TypedFolderHandle<A> {
+    pub(crate) fn new(handle: Handle<LoadedFolder>) -> Self {
+        Self {
+            handle,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the handle of the [`LoadedFolder`].
+    pub fn handle(&self) -> &Handle<LoadedFolder> {
+        &self.handle
+    }
+
+    /// Returns an iterator over the handles of the assets of the folder, or `None` if the list of
+    /// its files isn't known yet.
+    pub fn get<'a>(
+        &self,
+        folders: &'a Assets<LoadedFolder>,
+    ) -> Option<impl Iterator<Item = Handle<A>> + 'a> {
+        folders.get(&self.handle).map(LoadedFolder::typed_handles)
+    }
+
+    /// Returns how many of the assets of the folder have finished loading, or `None` if the list
+    /// of its files isn't known yet.
+    pub fn progress(
+        &self,
+        asset_server: &AssetServer,
+        folders: &Assets<LoadedFolder>,
+    ) -> Option<FolderLoadProgress> {
+        let folder = folders.get(&self.handle)?;
+        let ids = folder
+            .handles
+            .iter()
+            .map(UntypedHandle::id)
+            .filter(|id| id.type_id() == TypeId::of::<A>());
+        Some(FolderLoadProgress::new(asset_server, ids))
+    }
+}
+
+impl<A: Asset> Clone for TypedFolderHandle<A> {
+    fn clone(&self) -> Self {
+        Self::new(self.handle.clone())
+    }
+}
+
+impl<A: Asset> core::fmt::Debug for TypedFolderHandle<A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypedFolderHandle")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+/// How many of the assets of a [`LoadedFolder`] have finished loading, with their dependencies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FolderLoadProgress {
+    /// The number of assets that were loaded with their dependencies.
+    pub loaded: usize,
+    /// The number of assets that failed to load, or whose dependencies failed to load.
+    pub failed: usize,
+    /// The number of assets in the folder.
+    pub total: usize,
+}
+
+impl FolderLoadProgress {
+    fn new(asset_server: &AssetServer, ids: impl Iterator<Item = UntypedAssetId>) -> Self {
+        let mut progress = Self::default();
+        for id in ids {
+            progress.total += 1;
+            let state = asset_server.recursive_dependency_load_state(id);
+            if state.is_loaded() {
+                progress.loaded += 1;
+            } else if state.is_failed() {
+                progress.failed += 1;
+            }
+        }
+        progress
+    }
+
+    /// Returns `true` if all the assets have either loaded or failed.
+    pub fn is_finished(&self) -> bool {
+        self.loaded + self.failed == self.total
+    }
+
+    /// Returns the fraction of the assets that have either loaded or failed, between 0 and 1.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            (self.loaded + self.failed) as f32 / self.total as f32
+        }
+    }
+}
+
+/// A glob pattern selecting the files of a folder to load, like `textures/**/*.png`.
+///
+/// `*` matches any characters in a file or folder name, `?` matches one character and `**`
+/// matches any number of nested folders.
+pub(crate) struct FolderGlob {
+    segments: Vec<String>,
+}
+
+impl FolderGlob {
+    /// Parses `path` as a glob pattern, or returns `None` if it doesn't contain wildcards.
+    pub(crate) fn parse(path: &Path) -> Option<Self> {
+        let path = path.to_str()?;
+        if !path.contains(['*', '?']) {
+            return None;
+        }
+        Some(Self {
+            segments: path
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(String::from)
+                .collect(),
+        })
+    }
+
+    /// Returns the folder containing all the files matching the pattern: its leading segments
+    /// without wildcards.
+    pub(crate) fn root(&self) -> PathBuf {
+        self.segments
+            .iter()
+            .take_while(|segment| !segment.contains(['*', '?']))
+            .collect()
+    }
+
+    /// Returns `true` if the file at `path` matches the pattern.
+    pub(crate) fn is_match(&self, path: &Path) -> bool {
+        match_segments(&self.segments, &path_segments(path), false)
+    }
+
+    /// Returns `true` if files under the folder at `path` may match the pattern.
+    pub(crate) fn may_contain_matches(&self, path: &Path) -> bool {
+        match_segments(&self.segments, &path_segments(path), true)
+    }
+}
+
+fn path_segments(path: &Path) -> Vec<&str> {
+    path.iter().filter_map(|segment| segment.to_str()).collect()
+}
+
+fn match_segments(pattern: &[String], path: &[&str], prefix: bool) -> bool {
+    match (pattern.split_first(), path.split_first()) {
+        (_, None) => prefix || pattern.iter().all(|segment| segment == "**"),
+        (None, Some(_)) => false,
+        (Some((segment, rest)), Some((_, path_rest))) if segment == "**" => {
+            match_segments(rest, path, prefix) || match_segments(pattern, path_rest, prefix)
+        }
+        (Some((segment, rest)), Some((name, path_rest))) => {
+            let segment: Vec<char> = segment.chars().collect();
+            let name: Vec<char> = name.chars().collect();
+            match_name(&segment, &name) && match_segments(rest, path_rest, prefix)
+        }
+    }
+}
+
+fn match_name(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| match_name(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && match_name(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && match_name(rest, &name[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FolderGlob;
+    use std::path::Path;
+
+    #[test]
+    fn glob_matches() {
+        let glob = FolderGlob::parse(Path::new("textures/**/*.png")).unwrap();
+        assert_eq!(glob.root(), Path::new("textures"));
+        assert!(glob.is_match(Path::new("textures/a.png")));
+        assert!(glob.is_match(Path::new("textures/terrain/far/b.png")));
+        assert!(!glob.is_match(Path::new("textures/a.jpg")));
+        assert!(!glob.is_match(Path::new("models/a.png")));
+        assert!(glob.may_contain_matches(Path::new("textures/terrain")));
+        assert!(!glob.may_contain_matches(Path::new("models")));
+
+        let glob = FolderGlob::parse(Path::new("textures/*/?.png")).unwrap();
+        assert!(glob.is_match(Path::new("textures/terrain/a.png")));
+        assert!(!glob.is_match(Path::new("textures/terrain/ab.png")));
+        assert!(!glob.is_match(Path::new("textures/a.png")));
+        assert!(!glob.may_contain_matches(Path::new("textures/terrain/far")));
+
+        assert!(FolderGlob::parse(Path::new("textures")).is_none());
+    }
+}
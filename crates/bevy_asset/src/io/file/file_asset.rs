@@ -3,7 +3,7 @@ use crate::io::{
     PathStream, Reader, Writer,
 };
 use async_fs::{read_dir, File};
-use futures_io::AsyncSeek;
+use futures_io::{AsyncRead, AsyncSeek};
 use futures_lite::StreamExt;
 
 use core::{pin::Pin, task, task::Poll};
@@ -32,16 +32,50 @@ impl AsyncSeekForward for File {
 
 impl Reader for File {}
 
+/// A [`File`] along with its length, read when it was opened.
+struct FileReader {
+    file: File,
+    len: Option<u64>,
+}
+
+impl AsyncRead for FileReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<futures_io::Result<usize>> {
+        Pin::new(&mut self.file).poll_read(cx, buf)
+    }
+}
+
+impl AsyncSeekForward for FileReader {
+    fn poll_seek_forward(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+        offset: u64,
+    ) -> Poll<futures_io::Result<u64>> {
+        Pin::new(&mut self.file).poll_seek_forward(cx, offset)
+    }
+}
+
+impl Reader for FileReader {
+    fn byte_len(&self) -> Option<u64> {
+        self.len
+    }
+}
+
 impl AssetReader for FileAssetReader {
     async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
         let full_path = self.root_path.join(path);
-        File::open(&full_path).await.map_err(|e| {
+        let file = File::open(&full_path).await.map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 AssetReaderError::NotFound(full_path)
             } else {
                 e.into()
             }
-        })
+        })?;
+        let len = file.metadata().await.ok().map(|metadata| metadata.len());
+        Ok(FileReader { file, len })
     }
 
     async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
@@ -51,6 +51,10 @@ impl Reader for FileReader {
     {
         stackfuture::StackFuture::from(async { self.0.read_to_end(buf) })
     }
+
+    fn byte_len(&self) -> Option<u64> {
+        self.0.metadata().ok().map(|metadata| metadata.len())
+    }
 }
 
 struct FileWriter(File);
@@ -285,6 +285,10 @@ impl Reader for DataReader {
             }
         })
     }
+
+    fn byte_len(&self) -> Option<u64> {
+        Some(self.data.value().len() as u64)
+    }
 }
 
 impl AssetReader for MemoryAssetReader {
@@ -12,6 +12,7 @@ pub mod embedded;
 pub mod file;
 pub mod gated;
 pub mod memory;
+pub mod pak;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
@@ -173,6 +174,12 @@ pub trait Reader: AsyncRead + AsyncSeekForward + Unpin + Send + Sync {
         let future = futures_lite::AsyncReadExt::read_to_end(self, buf);
         StackFuture::from(future)
     }
+
+    /// Returns the total number of bytes of the asset, if known, to report the
+    /// [load progress](crate::AssetServer::load_progress) of the asset.
+    fn byte_len(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl Reader for Box<dyn Reader + '_> {
@@ -182,6 +189,10 @@ impl Reader for Box<dyn Reader + '_> {
     ) -> StackFuture<'a, std::io::Result<usize>, STACK_FUTURE_SIZE> {
         (**self).read_to_end(buf)
     }
+
+    fn byte_len(&self) -> Option<u64> {
+        (**self).byte_len()
+    }
 }
 
 /// A future that returns a value or an [`AssetReaderError`]
@@ -686,6 +697,10 @@ impl Reader for VecReader {
             }
         })
     }
+
+    fn byte_len(&self) -> Option<u64> {
+        Some(self.bytes.len() as u64)
+    }
 }
 
 /// An [`AsyncRead`] implementation capable of reading a [`&[u8]`].
@@ -760,6 +775,10 @@ impl Reader for SliceReader<'_> {
             }
         })
     }
+
+    fn byte_len(&self) -> Option<u64> {
+        Some(self.bytes.len() as u64)
+    }
 }
 
 /// Appends `.meta` to the given path.
@@ -0,0 +1,557 @@
+//! An [`AssetReader`] reading assets from a single packed archive file, a "pak".
+//!
+//! Shipped games can bundle their assets in a pak with [`PakWriter`] instead of exposing the
+//! asset folder, and read them with a [`PakAssetReader`]:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::{AssetSourceBuilder, AssetSourceId, pak::PakAssetReader}};
+//! # let mut app = App::new();
+//! let reader = PakAssetReader::open("assets.pak", None).unwrap();
+//! app.register_asset_source(AssetSourceId::Default, AssetSourceBuilder::pak(reader));
+//! ```
+//!
+//! A pak contains the assets along with their `.meta` files, so packing the processed asset folder
+//! (`imported_assets/Default` by default) gives a pak usable in [`AssetMode::Processed`](crate::AssetMode::Processed).
+//!
+//! Paks can be encrypted with a 32 bytes key. As the key has to ship with the game, this keeps
+//! the assets from being casually extracted, but it's no protection against reverse engineering.
+//! The content of each file is checked against a hash when it's read, with or without encryption.
+
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSourceBuilder, PathStream, Reader, VecReader,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+use bevy_utils::HashMap;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+const MAGIC: &[u8; 8] = b"BEVYPAK\0";
+const VERSION: u32 = 1;
+const FLAG_ENCRYPTED: u32 = 1;
+const HEADER_LEN: usize = 8 + 4 + 4 + 16 + 8 + 32;
+
+/// An error reading a pak, see [`PakAssetReader`].
+#[derive(Error, Debug)]
+pub enum PakError {
+    /// An I/O error occurred while reading the pak.
+    #[error("I/O error while reading the pak: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file isn't a pak.
+    #[error("the file is not a pak")]
+    NotAPak,
+    /// The pak was written by an unsupported version of the format.
+    #[error("unsupported pak version {0}")]
+    UnsupportedVersion(u32),
+    /// The pak is encrypted but no key was provided.
+    #[error("the pak is encrypted but no key was provided")]
+    MissingKey,
+    /// The index of the pak is corrupted, or the key is wrong.
+    #[error("the pak index is corrupted, or the key is wrong")]
+    Corrupted,
+}
+
+/// Builds a pak from files, to read them with a [`PakAssetReader`].
+///
+/// ```no_run
+/// # use bevy_asset::io::pak::PakWriter;
+/// # use std::{fs::File, path::Path};
+/// let mut pak = PakWriter::new().with_key(*b"a very secret key of 32 bytes!!!");
+/// pak.add_directory(Path::new("imported_assets/Default")).unwrap();
+/// pak.write(&mut File::create("assets.pak").unwrap()).unwrap();
+/// ```
+#[derive(Default)]
+pub struct PakWriter {
+    files: BTreeMap<String, Vec<u8>>,
+    key: Option<[u8; 32]>,
+}
+
+impl PakWriter {
+    /// Creates an empty pak.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encrypts the content of the pak with `key`.
+    pub fn with_key(mut self, key: [u8; 32]) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Adds the file at `path` in the pak, with the content `data`.
+    ///
+    /// `path` is relative to the root of the asset source, like an [`AssetPath`](crate::AssetPath).
+    pub fn add(&mut self, path: impl AsRef<Path>, data: Vec<u8>) -> &mut Self {
+        self.files.insert(normalize(path.as_ref()), data);
+        self
+    }
+
+    /// Adds the files in the folder at `root` and its subfolders, including `.meta` files, with
+    /// their paths relative to `root`.
+    pub fn add_directory(&mut self, root: &Path) -> std::io::Result<&mut Self> {
+        let mut folders = vec![root.to_path_buf()];
+        while let Some(folder) = folders.pop() {
+            for entry in std::fs::read_dir(&folder)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    folders.push(path);
+                } else {
+                    let data = std::fs::read(&path)?;
+                    let relative = path.strip_prefix(root).unwrap_or(&path);
+                    self.add(relative, data);
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Writes the pak to `writer`.
+    pub fn write(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let nonce = *uuid::Uuid::new_v4().as_bytes();
+        let index_len: usize = self
+            .files
+            .keys()
+            .map(|path| 4 + path.len() + 8 + 8 + 32)
+            .sum();
+
+        let mut index = Vec::with_capacity(index_len);
+        let mut offset = (HEADER_LEN + index_len) as u64;
+        for (path, data) in &self.files {
+            index.extend_from_slice(&(path.len() as u32).to_le_bytes());
+            index.extend_from_slice(path.as_bytes());
+            index.extend_from_slice(&offset.to_le_bytes());
+            index.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            index.extend_from_slice(&tag(self.key.as_ref(), data));
+            offset += data.len() as u64;
+        }
+        let index_tag = tag(self.key.as_ref(), &index);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        let flags = if self.key.is_some() {
+            FLAG_ENCRYPTED
+        } else {
+            0
+        };
+        writer.write_all(&flags.to_le_bytes())?;
+        writer.write_all(&nonce)?;
+        writer.write_all(&(index_len as u64).to_le_bytes())?;
+        writer.write_all(&index_tag)?;
+        if let Some(key) = &self.key {
+            apply_keystream(key, &nonce, INDEX_DOMAIN, &mut index);
+        }
+        writer.write_all(&index)?;
+        for (path, data) in &self.files {
+            match &self.key {
+                Some(key) => {
+                    let mut data = data.clone();
+                    apply_keystream(key, &nonce, path, &mut data);
+                    writer.write_all(&data)?;
+                }
+                None => writer.write_all(data)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the content of the pak.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes)
+            .expect("writing to a Vec should not fail");
+        bytes
+    }
+}
+
+/// An [`AssetReader`] reading the files of a pak written by a [`PakWriter`].
+///
+/// Cloning the reader is cheap: clones share the index of the pak.
+#[derive(Clone)]
+pub struct PakAssetReader(Arc<Pak>);
+
+struct Pak {
+    data: PakData,
+    key: Option<[u8; 32]>,
+    nonce: [u8; 16],
+    files: HashMap<String, PakEntry>,
+    folders: HashMap<String, BTreeSet<String>>,
+}
+
+enum PakData {
+    Bytes(Arc<[u8]>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+}
+
+struct PakEntry {
+    offset: u64,
+    len: u64,
+    tag: [u8; 32],
+}
+
+impl PakAssetReader {
+    /// Reads the pak in `bytes`, decrypting it with `key` if it's encrypted.
+    pub fn from_bytes(
+        bytes: impl Into<Arc<[u8]>>,
+        key: Option<[u8; 32]>,
+    ) -> Result<Self, PakError> {
+        let bytes: Arc<[u8]> = bytes.into();
+        let header = bytes.get(..HEADER_LEN).ok_or(PakError::NotAPak)?;
+        let index_len = parse_index_len(header, bytes.len() as u64)?;
+        let index = bytes[HEADER_LEN..HEADER_LEN + index_len].to_vec();
+        let len = bytes.len() as u64;
+        Self::new(PakData::Bytes(bytes.clone()), len, header, index, key)
+    }
+
+    /// Opens the pak at `path`, decrypting it with `key` if it's encrypted.
+    ///
+    /// Only the index of the pak is read here: each file is read from the pak when it's loaded,
+    /// with blocking I/O.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl AsRef<Path>, key: Option<[u8; 32]>) -> Result<Self, PakError> {
+        use std::io::Read;
+
+        let path = path.as_ref();
+        let mut file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        let mut header = [0; HEADER_LEN];
+        file.read_exact(&mut header)
+            .map_err(|_| PakError::NotAPak)?;
+        let index_len = parse_index_len(&header, len)?;
+        let mut index = vec![0; index_len];
+        file.read_exact(&mut index)
+            .map_err(|_| PakError::Corrupted)?;
+        Self::new(PakData::File(path.to_path_buf()), len, &header, index, key)
+    }
+
+    /// Parses the pak `data`, which is `len` bytes long.
+    fn new(
+        data: PakData,
+        len: u64,
+        header: &[u8],
+        mut index: Vec<u8>,
+        key: Option<[u8; 32]>,
+    ) -> Result<Self, PakError> {
+        let flags = u32::from_le_bytes(header[12..16].try_into().unwrap());
+        let nonce: [u8; 16] = header[16..32].try_into().unwrap();
+        let index_tag: [u8; 32] = header[40..72].try_into().unwrap();
+        let key = if flags & FLAG_ENCRYPTED != 0 {
+            let key = key.ok_or(PakError::MissingKey)?;
+            apply_keystream(&key, &nonce, INDEX_DOMAIN, &mut index);
+            Some(key)
+        } else {
+            None
+        };
+        if blake3::Hash::from(tag(key.as_ref(), &index)) != blake3::Hash::from(index_tag) {
+            return Err(PakError::Corrupted);
+        }
+
+        let mut files = HashMap::default();
+        let mut folders = HashMap::<String, BTreeSet<String>>::default();
+        folders.insert(String::new(), BTreeSet::new());
+        let mut index = index.as_slice();
+        while !index.is_empty() {
+            let path_len = u32::from_le_bytes(take(&mut index)?) as usize;
+            let path = index.get(..path_len).ok_or(PakError::Corrupted)?;
+            let path = String::from_utf8(path.to_vec()).map_err(|_| PakError::Corrupted)?;
+            index = &index[path_len..];
+            let entry = PakEntry {
+                offset: u64::from_le_bytes(take(&mut index)?),
+                len: u64::from_le_bytes(take(&mut index)?),
+                tag: take(&mut index)?,
+            };
+            if entry
+                .offset
+                .checked_add(entry.len)
+                .is_none_or(|end| end > len)
+            {
+                return Err(PakError::Corrupted);
+            }
+
+            // Meta files are read with their asset, they aren't listed in folders.
+            if !path.ends_with(".meta") {
+                let mut child = path.clone();
+                while let Some((parent, _)) = child.rsplit_once('/') {
+                    let parent = parent.to_string();
+                    let is_new = !folders.contains_key(&parent);
+                    folders
+                        .entry(parent.clone())
+                        .or_default()
+                        .insert(child.clone());
+                    if !is_new {
+                        break;
+                    }
+                    child = parent;
+                }
+                if !child.contains('/') {
+                    folders.get_mut("").unwrap().insert(child);
+                }
+            }
+            files.insert(path, entry);
+        }
+
+        Ok(Self(Arc::new(Pak {
+            data,
+            key,
+            nonce,
+            files,
+            folders,
+        })))
+    }
+
+    /// Returns an iterator over the paths of the files in the pak, including `.meta` files.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.0.files.keys().map(Path::new)
+    }
+
+    fn read_file(&self, path: &Path) -> Result<VecReader, AssetReaderError> {
+        let pak = &self.0;
+        let name = normalize(path);
+        let entry = pak
+            .files
+            .get(&name)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+        let start = usize::try_from(entry.offset).map_err(|_| corrupted(path))?;
+        let len = usize::try_from(entry.len).map_err(|_| corrupted(path))?;
+        let end = start.checked_add(len).ok_or_else(|| corrupted(path))?;
+        let mut data = match &pak.data {
+            PakData::Bytes(bytes) => bytes
+                .get(start..end)
+                .ok_or_else(|| corrupted(path))?
+                .to_vec(),
+            #[cfg(not(target_arch = "wasm32"))]
+            PakData::File(pak_path) => {
+                use std::io::{Read, Seek, SeekFrom};
+                let mut file = std::fs::File::open(pak_path)?;
+                // The pak may have been replaced since its index was read.
+                if file.metadata()?.len() < end as u64 {
+                    return Err(corrupted(path));
+                }
+                file.seek(SeekFrom::Start(entry.offset))?;
+                let mut data = vec![0; len];
+                file.read_exact(&mut data)?;
+                data
+            }
+        };
+        if let Some(key) = &pak.key {
+            apply_keystream(key, &pak.nonce, &name, &mut data);
+        }
+        if blake3::Hash::from(tag(pak.key.as_ref(), &data)) != blake3::Hash::from(entry.tag) {
+            return Err(corrupted(path));
+        }
+        Ok(VecReader::new(data))
+    }
+}
+
+impl AssetReader for PakAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_file(path)
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_file(&get_meta_path(path))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let children = self
+            .0
+            .folders
+            .get(&normalize(path))
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+        let children: Vec<PathBuf> = children.iter().map(PathBuf::from).collect();
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(children));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.0.folders.contains_key(&normalize(path)))
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Returns a builder for an asset source reading its assets from the pak of `reader`, both in
+    /// [`AssetMode::Unprocessed`](crate::AssetMode::Unprocessed) and
+    /// [`AssetMode::Processed`](crate::AssetMode::Processed).
+    pub fn pak(reader: PakAssetReader) -> Self {
+        let processed_reader = reader.clone();
+        Self::default()
+            .with_reader(move || Box::new(reader.clone()))
+            .with_processed_reader(move || Box::new(processed_reader.clone()))
+    }
+}
+
+const INDEX_DOMAIN: &str = "\0index";
+
+/// Joins the components of `path` with `/`, the separator used in paks on all platforms.
+fn normalize(path: &Path) -> String {
+    let components: Vec<_> = path
+        .components()
+        .filter_map(|component| component.as_os_str().to_str())
+        .filter(|component| *component != ".")
+        .collect();
+    components.join("/")
+}
+
+/// Returns the hash checked when reading `data`, keyed if the pak is encrypted.
+fn tag(key: Option<&[u8; 32]>, data: &[u8]) -> [u8; 32] {
+    match key {
+        Some(key) => {
+            let tag_key = blake3::derive_key("bevy_asset pak tag", key);
+            blake3::keyed_hash(&tag_key, data).into()
+        }
+        None => blake3::hash(data).into(),
+    }
+}
+
+/// Encrypts or decrypts `data` by xoring it with a keystream derived from the key, the nonce of the
+/// pak and the `domain` of the data: the path of a file, or [`INDEX_DOMAIN`] for the index.
+fn apply_keystream(key: &[u8; 32], nonce: &[u8; 16], domain: &str, data: &mut [u8]) {
+    let stream_key = blake3::derive_key("bevy_asset pak encryption", key);
+    let mut hasher = blake3::Hasher::new_keyed(&stream_key);
+    hasher.update(nonce);
+    hasher.update(domain.as_bytes());
+    let mut keystream = hasher.finalize_xof();
+    let mut block = [0; 64];
+    for chunk in data.chunks_mut(block.len()) {
+        let block = &mut block[..chunk.len()];
+        keystream.fill(block);
+        for (byte, key_byte) in chunk.iter_mut().zip(block.iter()) {
+            *byte ^= key_byte;
+        }
+    }
+}
+
+/// Returns the length of the index of a pak of `len` bytes, checking that it fits in the pak.
+fn parse_index_len(header: &[u8], len: u64) -> Result<usize, PakError> {
+    if &header[..8] != MAGIC {
+        return Err(PakError::NotAPak);
+    }
+    let version = u32::from_le_bytes(header[8..12].try_into().unwrap());
+    if version != VERSION {
+        return Err(PakError::UnsupportedVersion(version));
+    }
+    let index_len = u64::from_le_bytes(header[32..40].try_into().unwrap());
+    if (HEADER_LEN as u64)
+        .checked_add(index_len)
+        .is_none_or(|end| end > len)
+    {
+        return Err(PakError::Corrupted);
+    }
+    usize::try_from(index_len).map_err(|_| PakError::Corrupted)
+}
+
+fn take<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N], PakError> {
+    let value = bytes
+        .get(..N)
+        .ok_or(PakError::Corrupted)?
+        .try_into()
+        .unwrap();
+    *bytes = &bytes[N..];
+    Ok(value)
+}
+
+fn corrupted(path: &Path) -> AssetReaderError {
+    AssetReaderError::Io(Arc::new(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("{} is corrupted in the pak", path.display()),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future::block_on, StreamExt};
+
+    fn read(reader: &PakAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(async {
+            let mut bytes = Vec::new();
+            reader
+                .read(Path::new(path))
+                .await?
+                .read_to_end(&mut bytes)
+                .await?;
+            Ok(bytes)
+        })
+    }
+
+    #[test]
+    fn read_pak() {
+        let key = [7; 32];
+        let mut pak = PakWriter::new().with_key(key);
+        pak.add("a.txt", b"a".to_vec())
+            .add("a.txt.meta", b"meta".to_vec())
+            .add("textures/terrain/b.png", b"b".to_vec());
+        let bytes = pak.to_bytes();
+        assert!(!bytes.windows(8).any(|window| window == b"textures"));
+
+        assert!(matches!(
+            PakAssetReader::from_bytes(bytes.clone(), None),
+            Err(PakError::MissingKey)
+        ));
+        assert!(matches!(
+            PakAssetReader::from_bytes(bytes.clone(), Some([8; 32])),
+            Err(PakError::Corrupted)
+        ));
+
+        let reader = PakAssetReader::from_bytes(bytes, Some(key)).unwrap();
+        assert_eq!(read(&reader, "a.txt").unwrap(), b"a");
+        assert_eq!(read(&reader, "textures/terrain/b.png").unwrap(), b"b");
+        assert!(read(&reader, "c.txt").is_err());
+        let mut meta = Vec::new();
+        block_on(async {
+            let mut reader = reader.read_meta(Path::new("a.txt")).await.unwrap();
+            reader.read_to_end(&mut meta).await.unwrap();
+        });
+        assert_eq!(meta, b"meta");
+
+        let root: Vec<_> = block_on(async {
+            let stream = reader.read_directory(Path::new("")).await.unwrap();
+            stream.collect().await
+        });
+        assert_eq!(root, [PathBuf::from("a.txt"), PathBuf::from("textures")]);
+        assert!(block_on(reader.is_directory(Path::new("textures/terrain"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("a.txt"))).unwrap());
+    }
+
+    #[test]
+    fn reject_invalid_lengths() {
+        let bytes = PakWriter::new().add("a.txt", b"a".to_vec()).to_bytes();
+        assert!(PakAssetReader::from_bytes(bytes.clone(), None).is_ok());
+
+        // The file runs past the end of the pak.
+        let truncated = bytes[..bytes.len() - 1].to_vec();
+        assert!(matches!(
+            PakAssetReader::from_bytes(truncated, None),
+            Err(PakError::Corrupted)
+        ));
+
+        // The index is longer than the pak.
+        let mut huge_index = bytes.clone();
+        huge_index[32..40].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            PakAssetReader::from_bytes(huge_index, None),
+            Err(PakError::Corrupted)
+        ));
+
+        // The length of the file is forged, with a valid hash of the index.
+        let mut huge_file = bytes;
+        let len_offset = HEADER_LEN + 4 + "a.txt".len() + 8;
+        huge_file[len_offset..len_offset + 8].copy_from_slice(&(u64::MAX - 100).to_le_bytes());
+        let index_len = u64::from_le_bytes(huge_file[32..40].try_into().unwrap()) as usize;
+        let index_tag = tag(None, &huge_file[HEADER_LEN..HEADER_LEN + index_len]);
+        huge_file[40..72].copy_from_slice(&index_tag);
+        assert!(matches!(
+            PakAssetReader::from_bytes(huge_file, None),
+            Err(PakError::Corrupted)
+        ));
+    }
+}
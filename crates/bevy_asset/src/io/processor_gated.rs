@@ -159,4 +159,8 @@ impl Reader for TransactionLockedReader<'_> {
     ) -> stackfuture::StackFuture<'a, std::io::Result<usize>, { super::STACK_FUTURE_SIZE }> {
         self.reader.read_to_end(buf)
     }
+
+    fn byte_len(&self) -> Option<u64> {
+        self.reader.byte_len()
+    }
 }
@@ -28,9 +28,9 @@
 //! This can be done by checking the [`LoadState`] of the asset handle using [`AssetServer::is_loaded_with_dependencies`],
 //! which will be `true` when the asset is ready to use.
 //!
-//! Keep track of what you're waiting on by using a [`HashSet`] of asset handles or similar data structure,
-//! which iterate over and poll in your update loop, and transition to the new scene once all assets are loaded.
-//! Bevy's built-in states system can be very helpful for this!
+//! Keep track of what you're waiting on by spawning a [`LoadingGroup`] of asset handles,
+//! which reports the progress of its assets and sends a [`LoadingGroupFinished`] event once they're all loaded,
+//! to transition to the new scene. Bevy's built-in states system can be very helpful for this!
 //!
 //! # Modifying entities that use assets
 //!
@@ -180,11 +180,16 @@ mod event;
 mod folder;
 mod handle;
 mod id;
+#[cfg(feature = "bevy_diagnostic")]
+mod load_diagnostic;
 mod loader;
 mod loader_builders;
+mod loading_group;
+mod memory;
 mod path;
 mod reflect;
 mod render_asset;
+mod retention;
 mod server;
 
 pub use assets::*;
@@ -195,13 +200,18 @@ pub use folder::*;
 pub use futures_lite::{AsyncReadExt, AsyncWriteExt};
 pub use handle::*;
 pub use id::*;
+#[cfg(feature = "bevy_diagnostic")]
+pub use load_diagnostic::AssetLoadQueueDiagnosticsPlugin;
 pub use loader::*;
 pub use loader_builders::{
     Deferred, DynamicTyped, Immediate, NestedLoader, StaticTyped, UnknownTyped,
 };
+pub use loading_group::{LoadingGroup, LoadingGroupFinished};
+pub use memory::{AssetMemoryDiagnostics, AssetMemoryUsage, AssetTypeMemoryUsage};
 pub use path::*;
 pub use reflect::*;
 pub use render_asset::*;
+pub use retention::{AssetRetentionPolicy, AssetUnloadReason, AssetUnloadedEvent};
 pub use server::*;
 
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy assets.
@@ -209,7 +219,11 @@ pub use ron;
 
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
+    loading_group::track_loading_groups,
+    memory::{track_asset_memory, AssetMemoryUsageFn},
     processor::{AssetProcessor, Process},
+    retention::{apply_asset_retention, AssetRetention},
+    saver::{save_assets, AssetSaver, PendingAssetSaves, RuntimeAssetSaver},
 };
 use alloc::sync::Arc;
 use bevy_app::{App, Last, Plugin, PreUpdate};
@@ -221,7 +235,7 @@ use bevy_ecs::{
 };
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
 use bevy_utils::HashSet;
-use core::any::TypeId;
+use core::{any::TypeId, marker::PhantomData};
 use tracing::error;
 
 #[cfg(all(feature = "file_watcher", not(feature = "multi_threaded")))]
@@ -254,6 +268,9 @@ pub struct AssetPlugin {
     pub mode: AssetMode,
     /// How/If asset meta files should be checked.
     pub meta_check: AssetMetaCheck,
+    /// If `true`, the memory used by the assets is measured in the [`AssetMemoryDiagnostics`]
+    /// resource. This is `false` by default, as it adds a system per asset type.
+    pub memory_diagnostics: bool,
 }
 
 /// Controls whether or not assets are pre-processed before being loaded.
@@ -307,6 +324,7 @@ impl Default for AssetPlugin {
             processed_file_path: Self::DEFAULT_PROCESSED_FILE_PATH.to_string(),
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
+            memory_diagnostics: false,
         }
     }
 }
@@ -381,17 +399,25 @@ impl Plugin for AssetPlugin {
                 }
             }
         }
+        if self.memory_diagnostics {
+            app.init_resource::<AssetMemoryDiagnostics>();
+        }
         app.insert_resource(embedded)
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
             .add_event::<UntypedAssetLoadFailedEvent>()
+            .add_event::<LoadingGroupFinished>()
             .configure_sets(PreUpdate, TrackAssets.after(handle_internal_asset_events))
             // `handle_internal_asset_events` requires the use of `&mut World`,
             // and as a result has ambiguous system ordering with all other systems in `PreUpdate`.
             // This is virtually never a real problem: asset loading is async and so anything that interacts directly with it
             // needs to be robust to stochastic delays anyways.
             .add_systems(PreUpdate, handle_internal_asset_events.ambiguous_with_all())
+            .add_systems(
+                PreUpdate,
+                track_loading_groups.after(handle_internal_asset_events),
+            )
             .register_type::<AssetPath>();
     }
 }
@@ -488,6 +514,13 @@ pub trait AssetApp {
     ) -> &mut Self;
     /// Sets the default asset processor for the given `extension`.
     fn set_default_asset_processor<P: Process>(&mut self, extension: &str) -> &mut Self;
+    /// Sets the default asset processor for the given `extension` within `folder` and its subfolders, taking
+    /// precedence over the one set with [`AssetApp::set_default_asset_processor`].
+    fn set_default_asset_processor_for_folder<'a, P: Process>(
+        &mut self,
+        folder: impl Into<AssetPath<'a>>,
+        extension: &str,
+    ) -> &mut Self;
     /// Initializes the given loader in the [`App`]'s [`AssetServer`].
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self;
     /// Initializes the given [`Asset`] in the [`App`] by:
@@ -508,6 +541,24 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Sets how the memory used by assets of type `A` is measured for the [`AssetMemoryDiagnostics`]
+    /// and the [`AssetRetentionPolicy::Lru`] budget, instead of only counting the size of the asset
+    /// value.
+    fn register_asset_memory_usage<A: Asset>(
+        &mut self,
+        measure: fn(&A) -> AssetMemoryUsage,
+    ) -> &mut Self;
+    /// Sets how long the assets of type `A` loaded by the [`AssetServer`] stay loaded once they're
+    /// unused, and allows unloading them with [`AssetServer::unload`]. By default, they're
+    /// unloaded when their last strong [`Handle`] is dropped.
+    fn set_asset_retention_policy<A: Asset>(&mut self, policy: AssetRetentionPolicy) -> &mut Self;
+    /// Registers the saver used by [`AssetServer::save`] for the assets of type [`AssetSaver::Asset`],
+    /// replacing the previous one.
+    ///
+    /// The saver runs on a clone of the asset, so that it doesn't hold up the frame.
+    fn register_asset_saver<S: AssetSaver>(&mut self, saver: S) -> &mut Self
+    where
+        S::Asset: Clone;
 }
 
 impl AssetApp for App {
@@ -552,6 +603,17 @@ impl AssetApp for App {
         self
     }
 
+    fn set_default_asset_processor_for_folder<'a, P: Process>(
+        &mut self,
+        folder: impl Into<AssetPath<'a>>,
+        extension: &str,
+    ) -> &mut Self {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.set_default_processor_for_folder::<P>(folder, extension);
+        }
+        self
+    }
+
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self {
         let loader = L::from_world(self.world_mut());
         self.register_asset_loader(loader)
@@ -574,10 +636,17 @@ impl AssetApp for App {
                     Arc::new(AssetIndexAllocator::default()),
                 ));
         }
+        if self.world().contains_resource::<AssetMemoryDiagnostics>() {
+            self.add_systems(Last, track_asset_memory::<A>.after(AssetEvents));
+        }
         self.insert_resource(assets)
             .allow_ambiguous_resource::<Assets<A>>()
             .add_event::<AssetEvent<A>>()
             .add_event::<AssetLoadFailedEvent<A>>()
+            .add_event::<AssetReloaded<A>>()
+            .add_event::<AssetUnloadedEvent<A>>()
+            .add_event::<AssetSavedEvent<A>>()
+            .add_event::<AssetSaveFailedEvent<A>>()
             .register_type::<Handle<A>>()
             .add_systems(
                 Last,
@@ -611,6 +680,41 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn register_asset_memory_usage<A: Asset>(
+        &mut self,
+        measure: fn(&A) -> AssetMemoryUsage,
+    ) -> &mut Self {
+        self.insert_resource(AssetMemoryUsageFn {
+            measure,
+            marker: PhantomData,
+        })
+    }
+
+    fn set_asset_retention_policy<A: Asset>(&mut self, policy: AssetRetentionPolicy) -> &mut Self {
+        if let Some(mut retention) = self.world_mut().get_resource_mut::<AssetRetention<A>>() {
+            retention.policy = policy;
+            return self;
+        }
+        self.insert_resource(AssetRetention::<A>::new(policy))
+            .add_systems(Last, apply_asset_retention::<A>.after(AssetEvents))
+    }
+
+    fn register_asset_saver<S: AssetSaver>(&mut self, saver: S) -> &mut Self
+    where
+        S::Asset: Clone,
+    {
+        self.insert_resource(RuntimeAssetSaver::new(saver));
+        if self
+            .world()
+            .resource::<AssetServer>()
+            .register_saver_type::<S::Asset>()
+        {
+            self.init_resource::<PendingAssetSaves<S::Asset>>()
+                .add_systems(Last, save_assets::<S::Asset>);
+        }
+        self
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -627,16 +731,19 @@ pub struct AssetEvents;
 mod tests {
     use crate::{
         self as bevy_asset,
-        folder::LoadedFolder,
+        folder::{LoadedFolder, TypedFolderHandle},
         handle::Handle,
         io::{
             gated::{GateOpener, GatedReader},
             memory::{Dir, MemoryAssetReader},
-            AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
+            AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader, Writer,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets,
+        saver::{AssetSaveError, AssetSaver, SavedAsset},
+        Asset, AssetApp, AssetChange, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent,
+        AssetLoadPriority, AssetMemoryDiagnostics, AssetMemoryUsage, AssetPath, AssetPlugin,
+        AssetReloaded, AssetRetentionPolicy, AssetSaveFailedEvent, AssetServer, AssetUnloadReason,
+        AssetUnloadedEvent, Assets, AsyncWriteExt, LoadingGroup, LoadingGroupFinished,
     };
     use alloc::sync::Arc;
     use bevy_app::{App, TaskPoolPlugin, Update};
@@ -653,7 +760,7 @@ mod tests {
     use std::path::Path;
     use thiserror::Error;
 
-    #[derive(Asset, TypePath, Debug, Default)]
+    #[derive(Asset, TypePath, Clone, Debug, Default)]
     pub struct CoolText {
         pub text: String,
         pub embedded: String,
@@ -705,6 +812,10 @@ mod tests {
             let mut bytes = Vec::new();
             reader.read_to_end(&mut bytes).await?;
             let mut ron: CoolTextRon = ron::de::from_bytes(&bytes)?;
+            load_context.fingerprint_property("text", &ron.text);
+            for text in &ron.sub_texts {
+                load_context.fingerprint_label(text.clone(), text);
+            }
             let mut embedded = String::new();
             for dep in ron.embedded_dependencies {
                 let loaded = load_context
@@ -1387,6 +1498,476 @@ mod tests {
         });
     }
 
+    #[test]
+    fn dependency_graph() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+        "c.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: []
+)"#;
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: [
+        "c.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: []
+)"#;
+        let c_ron = r#"
+(
+    text: "c",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: []
+)"#;
+        dir.insert_asset_text(Path::new("a.cool.ron"), a_ron);
+        dir.insert_asset_text(Path::new("b.cool.ron"), b_ron);
+        dir.insert_asset_text(Path::new("c.cool.ron"), c_ron);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        assert!(asset_server.dependencies_of(&a).is_empty());
+
+        gate_opener.open("a.cool.ron");
+        gate_opener.open("b.cool.ron");
+        gate_opener.open("c.cool.ron");
+        run_app_until(&mut app, |_| {
+            asset_server.is_loaded_with_dependencies(&a).then_some(())
+        });
+
+        let a = a.id().untyped();
+        let b = asset_server.get_path_id("b.cool.ron").unwrap();
+        let c = asset_server.get_path_id("c.cool.ron").unwrap();
+
+        let mut a_dependencies = asset_server.dependencies_of(a);
+        a_dependencies.sort();
+        let mut expected = vec![b, c];
+        expected.sort();
+        assert_eq!(a_dependencies, expected);
+        assert_eq!(asset_server.dependencies_of(b), [c]);
+        assert!(asset_server.dependencies_of(c).is_empty());
+
+        let mut c_dependents = asset_server.dependents_of(c);
+        c_dependents.sort();
+        let mut expected = vec![a, b];
+        expected.sort();
+        assert_eq!(c_dependents, expected);
+        assert!(asset_server.dependents_of(a).is_empty());
+
+        assert_eq!(asset_server.recursive_dependencies_of(a), [c, b, a]);
+        assert_eq!(asset_server.recursive_dependencies_of(b), [c, b]);
+        assert_eq!(asset_server.recursive_dependents_of(c), [c, b, a]);
+        assert_eq!(asset_server.recursive_dependents_of(a), [a]);
+    }
+
+    #[test]
+    fn memory_diagnostics() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                memory_diagnostics: true,
+                ..Default::default()
+            },
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_memory_usage(|text: &CoolText| AssetMemoryUsage {
+            cpu: text.text.len(),
+            gpu: 0,
+        });
+        app.update();
+
+        let mut texts = app.world_mut().resource_mut::<Assets<CoolText>>();
+        let hello = texts.add(CoolText {
+            text: "hello".to_string(),
+            ..Default::default()
+        });
+        let hi = texts.add(CoolText {
+            text: "hi".to_string(),
+            ..Default::default()
+        });
+        let mut sub_texts = app.world_mut().resource_mut::<Assets<SubText>>();
+        let sub_text = sub_texts.add(SubText {
+            text: "sub".to_string(),
+        });
+        app.update();
+
+        let memory = app.world().resource::<AssetMemoryDiagnostics>();
+        assert_eq!(memory.get(&hello).unwrap().cpu, 5);
+        assert_eq!(memory.get(&sub_text).unwrap().cpu, size_of::<SubText>());
+        let texts = memory.get_type::<CoolText>().unwrap();
+        assert_eq!(texts.count, 2);
+        assert_eq!(texts.usage.cpu, 7);
+        assert_eq!(memory.total().cpu, 7 + size_of::<SubText>());
+
+        let mut texts = app.world_mut().resource_mut::<Assets<CoolText>>();
+        texts.get_mut(&hello).unwrap().text = "hello world".to_string();
+        drop(hi);
+        app.update();
+        app.update();
+
+        let memory = app.world().resource::<AssetMemoryDiagnostics>();
+        let texts = memory.get_type::<CoolText>().unwrap();
+        assert_eq!(texts.count, 1);
+        assert_eq!(texts.usage.cpu, 11);
+    }
+
+    #[test]
+    fn save_asset() {
+        struct CoolTextSaver;
+
+        impl AssetSaver for CoolTextSaver {
+            type Asset = CoolText;
+            type Settings = ();
+            type OutputLoader = CoolTextLoader;
+            type Error = std::io::Error;
+
+            async fn save(
+                &self,
+                writer: &mut Writer,
+                asset: SavedAsset<'_, CoolText>,
+                _settings: &(),
+            ) -> Result<(), std::io::Error> {
+                writer.write_all(asset.text.as_bytes()).await
+            }
+        }
+
+        #[derive(Resource, Default)]
+        struct Failed(Vec<AssetSaveError>);
+
+        #[derive(Asset, TypePath)]
+        struct Unregistered;
+
+        fn store_failed(
+            mut reader: EventReader<AssetSaveFailedEvent<CoolText>>,
+            mut failed: ResMut<Failed>,
+        ) {
+            failed
+                .0
+                .extend(reader.read().map(|event| event.error.clone()));
+        }
+
+        let (mut app, _) = test_app(Dir::default());
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<Failed>()
+            .add_systems(Update, store_failed);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .add(CoolText::default());
+
+        // Only registered asset types can be saved.
+        assert!(matches!(
+            asset_server.save(AssetId::<Unregistered>::default(), "a.ron"),
+            Err(AssetSaveError::UnregisteredAssetType(_))
+        ));
+
+        // No saver is registered yet.
+        assert!(matches!(
+            asset_server.save(&handle, "a.cool.ron"),
+            Err(AssetSaveError::MissingAssetSaver(_))
+        ));
+
+        // The test source has no writer.
+        app.register_asset_saver(CoolTextSaver);
+        asset_server.save(&handle, "a.cool.ron").unwrap();
+        run_app_until(&mut app, |world| {
+            let failed = &world.resource::<Failed>().0;
+            failed.first().map(|error| {
+                assert!(matches!(error, AssetSaveError::MissingAssetWriter(_)));
+            })
+        });
+    }
+
+    #[test]
+    fn load_priority() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let paths = ["low.cool.ron", "normal.cool.ron", "high.cool.ron"];
+        for path in paths {
+            dir.insert_asset_text(
+                Path::new(path),
+                r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        asset_server.set_load_budget(Some(Duration::from_millis(1)));
+        let low: Handle<CoolText> =
+            asset_server.load_with_priority("low.cool.ron", AssetLoadPriority::Low);
+        let normal: Handle<CoolText> =
+            asset_server.load_with_priority("normal.cool.ron", AssetLoadPriority::Normal);
+        let high: Handle<CoolText> =
+            asset_server.load_with_priority("high.cool.ron", AssetLoadPriority::High);
+        for priority in AssetLoadPriority::QUEUED {
+            assert_eq!(asset_server.queued_load_count(priority), 1);
+        }
+        assert!(asset_server.load_state(&low).is_loading());
+
+        app.update();
+        assert_eq!(asset_server.queued_load_count(AssetLoadPriority::High), 0);
+        assert_eq!(asset_server.queued_load_count(AssetLoadPriority::Normal), 1);
+        assert_eq!(asset_server.queued_load_count(AssetLoadPriority::Low), 1);
+
+        // Loading a queued asset without priority starts it right away.
+        let _low: Handle<CoolText> = asset_server.load("low.cool.ron");
+        assert_eq!(asset_server.queued_load_count(AssetLoadPriority::Low), 0);
+        assert_eq!(asset_server.queued_load_count(AssetLoadPriority::Normal), 1);
+
+        for path in paths {
+            gate_opener.open(path);
+        }
+        run_app_until(&mut app, |world| {
+            let loaded = [&low, &normal, &high]
+                .into_iter()
+                .all(|handle| get(world, handle.id()).is_some());
+            loaded.then_some(())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "the load budget must not be zero")]
+    fn zero_load_budget() {
+        let (app, _) = test_app(Dir::default());
+        app.world()
+            .resource::<AssetServer>()
+            .set_load_budget(Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn asset_retention() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        #[derive(Resource, Default)]
+        struct Unloaded(Vec<(AssetId<CoolText>, AssetUnloadReason)>);
+
+        fn store_unloaded(
+            mut reader: EventReader<AssetUnloadedEvent<CoolText>>,
+            mut unloaded: ResMut<Unloaded>,
+        ) {
+            unloaded
+                .0
+                .extend(reader.read().map(|event| (event.id, event.reason)));
+        }
+
+        let dir = Dir::default();
+        let paths = ["a.cool.ron", "b.cool.ron"];
+        for path in paths {
+            dir.insert_asset_text(
+                Path::new(path),
+                r#"(text: "text", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .register_asset_memory_usage(|text: &CoolText| AssetMemoryUsage {
+                cpu: text.text.len(),
+                gpu: 0,
+            })
+            .set_asset_retention_policy::<CoolText>(AssetRetentionPolicy::Lru { budget: 6 })
+            .init_resource::<Unloaded>()
+            .add_systems(Update, store_unloaded);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        let (a_id, b_id) = (a.id(), b.id());
+        for path in paths {
+            gate_opener.open(path);
+        }
+        run_app_until(&mut app, |world| {
+            (get(world, a_id).is_some() && get(world, b_id).is_some()).then_some(())
+        });
+
+        // Unused assets are kept within the budget.
+        drop(a);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(get(app.world(), a_id).is_some());
+        assert!(app.world().resource::<Unloaded>().0.is_empty());
+
+        // The least recently used asset is evicted to stay within the budget.
+        drop(b);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(get(app.world(), a_id).is_none());
+        assert!(get(app.world(), b_id).is_some());
+        assert_eq!(
+            app.world().resource::<Unloaded>().0,
+            [(a_id, AssetUnloadReason::Evicted)]
+        );
+
+        // Retained assets can be unloaded explicitly once unused, and loaded again.
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        assert_eq!(b.id(), b_id);
+        assert!(!asset_server.unload(&b));
+        drop(b);
+        assert!(asset_server.unload(b_id));
+        assert!(!asset_server.unload(b_id));
+        app.update();
+        app.update();
+        assert!(get(app.world(), b_id).is_none());
+        assert_eq!(
+            app.world().resource::<Unloaded>().0[1],
+            (b_id, AssetUnloadReason::Requested)
+        );
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        gate_opener.open("b.cool.ron");
+        run_app_until(&mut app, |world| get(world, b.id()).map(|_| ()));
+    }
+
+    #[test]
+    fn asset_retention_without_memory_usage() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let paths = ["a.cool.ron", "b.cool.ron"];
+        for path in paths {
+            dir.insert_asset_text(
+                Path::new(path),
+                r#"(text: "text", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .set_asset_retention_policy::<CoolText>(AssetRetentionPolicy::Lru { budget: 1 });
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        let (a_id, b_id) = (a.id(), b.id());
+        for path in paths {
+            gate_opener.open(path);
+        }
+        run_app_until(&mut app, |world| {
+            (get(world, a_id).is_some() && get(world, b_id).is_some()).then_some(())
+        });
+
+        // Without a memory measure, the budget is the number of unused assets.
+        drop(a);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(get(app.world(), a_id).is_some());
+
+        drop(b);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(get(app.world(), a_id).is_none());
+        assert!(get(app.world(), b_id).is_some());
+    }
+
+    #[test]
+    fn reload_changes() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        #[derive(Resource, Default)]
+        struct Changes(Vec<AssetChange>);
+
+        fn store_changes(
+            mut reader: EventReader<AssetReloaded<CoolText>>,
+            mut changes: ResMut<Changes>,
+        ) {
+            changes
+                .0
+                .extend(reader.read().map(|event| event.change.clone()));
+        }
+
+        let path = "a.cool.ron";
+        let ron = |text: &str, sub_texts: &str| {
+            format!(
+                r#"(
+    text: "{text}",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [{sub_texts}],
+)"#
+            )
+        };
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new(path), &ron("a", r#""x", "y""#));
+
+        let (mut app, gate_opener) = test_app(dir.clone());
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<Changes>()
+            .register_asset_loader(CoolTextLoader)
+            .add_systems(Update, store_changes);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load(path);
+        gate_opener.open(path);
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, handle.id())?;
+            Some(())
+        });
+        app.update();
+        assert!(
+            app.world().resource::<Changes>().0.is_empty(),
+            "the first load isn't a reload"
+        );
+
+        dir.insert_asset_text(Path::new(path), &ron("a", r#""x", "z""#));
+        asset_server.reload(path);
+        gate_opener.open(path);
+        run_app_until(&mut app, |world| {
+            let changes = &world.resource::<Changes>().0;
+            (!changes.is_empty()).then_some(())
+        });
+        let change = app.world_mut().resource_mut::<Changes>().0.remove(0);
+        assert_eq!(change.labels, ["y", "z"]);
+        assert!(change.properties.is_empty());
+        assert!(change.is_label_changed("z"));
+        assert!(!change.is_label_changed("x"));
+
+        dir.insert_asset_text(Path::new(path), &ron("b", r#""x", "z""#));
+        asset_server.reload(path);
+        gate_opener.open(path);
+        run_app_until(&mut app, |world| {
+            let changes = &world.resource::<Changes>().0;
+            (!changes.is_empty()).then_some(())
+        });
+        let change = app.world_mut().resource_mut::<Changes>().0.remove(0);
+        assert!(change.labels.is_empty());
+        assert!(change.is_property_changed("text"));
+    }
+
     const SIMPLE_TEXT: &str = r#"
 (
     text: "dep",
@@ -1530,6 +2111,149 @@ mod tests {
         assert_eq!(events, expected_events);
     }
 
+    #[test]
+    fn labels_for() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let path = "a.cool.ron";
+        dir.insert_asset_text(
+            Path::new(path),
+            r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: ["world", "hello"])"#,
+        );
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load(path);
+        assert!(asset_server.labels_for(path).is_none());
+
+        gate_opener.open(path);
+        run_app_until(&mut app, |world| get(world, handle.id()).map(|_| ()));
+
+        let labels = asset_server.labels_for("a.cool.ron#hello").unwrap();
+        let names: Vec<&str> = labels.iter().map(|labeled| &*labeled.label).collect();
+        assert_eq!(names, ["hello", "world"]);
+        assert_eq!(labels[0].type_name, core::any::type_name::<SubText>());
+        let text = get(app.world(), handle.id()).unwrap();
+        assert!(text
+            .sub_texts
+            .iter()
+            .any(|sub_text| sub_text.id().untyped() == labels[0].id));
+    }
+
+    #[test]
+    fn loading_group() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        dir.insert_asset_text(
+            Path::new(a_path),
+            r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+        );
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load(a_path);
+        let missing: Handle<CoolText> = asset_server.load("missing.cool.ron");
+        let group = app
+            .world_mut()
+            .spawn(LoadingGroup::new().with(a.clone()).with(missing.clone()))
+            .id();
+
+        app.update();
+        assert!(!app
+            .world()
+            .get::<LoadingGroup>(group)
+            .unwrap()
+            .is_finished());
+
+        gate_opener.open(a_path);
+        gate_opener.open("missing.cool.ron");
+        run_app_until(&mut app, |world| {
+            let events = world.resource::<Events<LoadingGroupFinished>>();
+            let mut cursor = events.get_cursor();
+            let event = cursor.read(events).next()?;
+            assert_eq!(event.entity, group);
+            assert_eq!(event.failed, [missing.id().untyped()]);
+            Some(())
+        });
+
+        let loading_group = app.world().get::<LoadingGroup>(group).unwrap();
+        assert!(loading_group.is_finished());
+        assert_eq!(loading_group.progress(), 1.0);
+        assert!(asset_server.is_loaded_with_dependencies(&a));
+    }
+
+    #[test]
+    fn load_folder_glob() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let paths = [
+            "text/a.cool.ron",
+            "text/sub/b.cool.ron",
+            "text/sub/deep/c.cool.ron",
+        ];
+        for path in paths {
+            dir.insert_asset_text(
+                Path::new(path),
+                r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let sub = asset_server.load_folder_typed::<CoolText>("text/sub/*.cool.ron");
+        let all = asset_server.load_folder_typed::<CoolText>("text/**/*.ron");
+        for path in paths {
+            gate_opener.open(path);
+        }
+        // Both folders load `b`, and each load reads it.
+        gate_opener.open(paths[1]);
+
+        run_app_until(&mut app, |world| {
+            let folders = world.resource::<Assets<LoadedFolder>>();
+            let finished = [&sub, &all].into_iter().all(|folder| {
+                folder
+                    .progress(&asset_server, folders)
+                    .is_some_and(|progress| progress.is_finished())
+            });
+            finished.then_some(())
+        });
+
+        let folders = app.world().resource::<Assets<LoadedFolder>>();
+        let paths_of = |folder: &TypedFolderHandle<CoolText>| {
+            let mut paths: Vec<_> = folder
+                .get(folders)
+                .unwrap()
+                .map(|handle| handle.path().unwrap().to_string())
+                .collect();
+            paths.sort();
+            paths
+        };
+        assert_eq!(paths_of(&sub), ["text/sub/b.cool.ron"]);
+        assert_eq!(paths_of(&all), paths);
+        let progress = all.progress(&asset_server, folders).unwrap();
+        assert_eq!(progress.loaded, 3);
+        assert_eq!(progress.fraction(), 1.0);
+    }
+
     #[test]
     fn load_folder() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
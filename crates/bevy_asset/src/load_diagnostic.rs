@@ -0,0 +1,36 @@
+use crate::{AssetLoadPriority, AssetServer};
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::system::Res;
+
+/// Adds the "asset load queue" diagnostics to an App: the number of loads waiting to be started
+/// for each queued [`AssetLoadPriority`], see [`AssetServer::load_with_priority`].
+#[derive(Default)]
+pub struct AssetLoadQueueDiagnosticsPlugin;
+
+impl Plugin for AssetLoadQueueDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for (path, _) in Self::QUEUES {
+            app.register_diagnostic(Diagnostic::new(path));
+        }
+        app.add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl AssetLoadQueueDiagnosticsPlugin {
+    pub const QUEUED_LOW: DiagnosticPath = DiagnosticPath::const_new("asset_load_queue/low");
+    pub const QUEUED_NORMAL: DiagnosticPath = DiagnosticPath::const_new("asset_load_queue/normal");
+    pub const QUEUED_HIGH: DiagnosticPath = DiagnosticPath::const_new("asset_load_queue/high");
+
+    const QUEUES: [(DiagnosticPath, AssetLoadPriority); 3] = [
+        (Self::QUEUED_LOW, AssetLoadPriority::Low),
+        (Self::QUEUED_NORMAL, AssetLoadPriority::Normal),
+        (Self::QUEUED_HIGH, AssetLoadPriority::High),
+    ];
+
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, asset_server: Res<AssetServer>) {
+        for (path, priority) in &Self::QUEUES {
+            diagnostics.add_measurement(path, || asset_server.queued_load_count(*priority) as f64);
+        }
+    }
+}
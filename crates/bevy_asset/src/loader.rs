@@ -3,14 +3,17 @@ use crate::{
     loader_builders::{Deferred, NestedLoader, StaticTyped},
     meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
     path::AssetPath,
-    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
-    UntypedHandle,
+    Asset, AssetChange, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle,
+    UntypedAssetId, UntypedHandle,
 };
 use atomicow::CowArc;
 use bevy_ecs::world::World;
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
-use bevy_utils::{HashMap, HashSet};
-use core::any::{Any, TypeId};
+use bevy_utils::{FixedHasher, HashMap, HashSet};
+use core::{
+    any::{Any, TypeId},
+    hash::{BuildHasher, Hash},
+};
 use downcast_rs::{impl_downcast, Downcast};
 use ron::error::SpannedError;
 use serde::{Deserialize, Serialize};
@@ -148,6 +151,7 @@ pub struct LoadedAsset<A: Asset> {
     pub(crate) dependencies: HashSet<UntypedAssetId>,
     pub(crate) loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
     pub(crate) labeled_assets: HashMap<CowArc<'static, str>, LabeledAsset>,
+    pub(crate) fingerprints: AssetFingerprints,
 }
 
 impl<A: Asset> LoadedAsset<A> {
@@ -162,6 +166,7 @@ impl<A: Asset> LoadedAsset<A> {
             dependencies,
             loader_dependencies: HashMap::default(),
             labeled_assets: HashMap::default(),
+            fingerprints: AssetFingerprints::default(),
         }
     }
 
@@ -187,6 +192,13 @@ impl<A: Asset> LoadedAsset<A> {
     pub fn iter_labels(&self) -> impl Iterator<Item = &str> {
         self.labeled_assets.keys().map(|s| &**s)
     }
+
+    /// Iterate over all "labeled assets" in the loaded asset, with their labels.
+    pub fn iter_labeled(&self) -> impl Iterator<Item = (&str, &ErasedLoadedAsset)> {
+        self.labeled_assets
+            .iter()
+            .map(|(label, labeled)| (&**label, &labeled.asset))
+    }
 }
 
 impl<A: Asset> From<A> for LoadedAsset<A> {
@@ -201,6 +213,7 @@ pub struct ErasedLoadedAsset {
     pub(crate) dependencies: HashSet<UntypedAssetId>,
     pub(crate) loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
     pub(crate) labeled_assets: HashMap<CowArc<'static, str>, LabeledAsset>,
+    pub(crate) fingerprints: AssetFingerprints,
 }
 
 impl<A: Asset> From<LoadedAsset<A>> for ErasedLoadedAsset {
@@ -210,6 +223,7 @@ impl<A: Asset> From<LoadedAsset<A>> for ErasedLoadedAsset {
             dependencies: asset.dependencies,
             loader_dependencies: asset.loader_dependencies,
             labeled_assets: asset.labeled_assets,
+            fingerprints: asset.fingerprints,
         }
     }
 }
@@ -249,6 +263,13 @@ impl ErasedLoadedAsset {
         self.labeled_assets.keys().map(|s| &**s)
     }
 
+    /// Iterate over all "labeled assets" in the loaded asset, with their labels.
+    pub fn iter_labeled(&self) -> impl Iterator<Item = (&str, &ErasedLoadedAsset)> {
+        self.labeled_assets
+            .iter()
+            .map(|(label, labeled)| (&**label, &labeled.asset))
+    }
+
     /// Cast this loaded asset as the given type. If the type does not match,
     /// the original type-erased asset is returned.
     pub fn downcast<A: Asset>(mut self) -> Result<LoadedAsset<A>, ErasedLoadedAsset> {
@@ -258,6 +279,7 @@ impl ErasedLoadedAsset {
                 dependencies: self.dependencies,
                 loader_dependencies: self.loader_dependencies,
                 labeled_assets: self.labeled_assets,
+                fingerprints: self.fingerprints,
             }),
             Err(value) => {
                 self.value = value;
@@ -267,6 +289,45 @@ impl ErasedLoadedAsset {
     }
 }
 
+/// The fingerprints recorded by an [`AssetLoader`] with [`LoadContext::fingerprint_label`] and
+/// [`LoadContext::fingerprint_property`].
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AssetFingerprints {
+    labels: HashMap<String, u64>,
+    properties: HashMap<String, u64>,
+}
+
+impl AssetFingerprints {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.labels.is_empty() && self.properties.is_empty()
+    }
+
+    /// Returns the labels and properties whose fingerprints differ from `previous`, including
+    /// those that were added or removed.
+    pub(crate) fn changes_since(&self, previous: &AssetFingerprints) -> AssetChange {
+        fn changed(current: &HashMap<String, u64>, previous: &HashMap<String, u64>) -> Vec<String> {
+            let mut changed: Vec<String> = current
+                .iter()
+                .filter(|(key, value)| previous.get(*key) != Some(*value))
+                .map(|(key, _)| key.clone())
+                .chain(
+                    previous
+                        .keys()
+                        .filter(|key| !current.contains_key(*key))
+                        .cloned(),
+                )
+                .collect();
+            changed.sort();
+            changed
+        }
+
+        AssetChange {
+            labels: changed(&self.labels, &previous.labels),
+            properties: changed(&self.properties, &previous.properties),
+        }
+    }
+}
+
 /// A type erased container for an [`Asset`] value that is capable of inserting the [`Asset`] into a [`World`]'s [`Assets`] collection.
 pub trait AssetContainer: Downcast + Any + Send + Sync + 'static {
     fn insert(self: Box<Self>, id: UntypedAssetId, world: &mut World);
@@ -318,6 +379,7 @@ pub struct LoadContext<'a> {
     /// Direct dependencies used by this loader.
     pub(crate) loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
     pub(crate) labeled_assets: HashMap<CowArc<'static, str>, LabeledAsset>,
+    fingerprints: AssetFingerprints,
 }
 
 impl<'a> LoadContext<'a> {
@@ -336,6 +398,7 @@ impl<'a> LoadContext<'a> {
             dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
             labeled_assets: HashMap::default(),
+            fingerprints: AssetFingerprints::default(),
         }
     }
 
@@ -451,9 +514,33 @@ impl<'a> LoadContext<'a> {
             dependencies: self.dependencies,
             loader_dependencies: self.loader_dependencies,
             labeled_assets: self.labeled_assets,
+            fingerprints: self.fingerprints,
         }
     }
 
+    /// Records a fingerprint of the labeled sub-asset `label`, such as a hash of the data it's
+    /// loaded from.
+    ///
+    /// When the asset is reloaded, the fingerprints of this load are compared with the previous
+    /// ones, and the labels whose fingerprints changed are reported in the [`AssetChange`] of the
+    /// [`AssetReloaded`](crate::AssetReloaded) event. This lets consumers only re-process the
+    /// sub-assets that changed.
+    pub fn fingerprint_label(&mut self, label: impl Into<String>, value: &impl Hash) {
+        self.fingerprints
+            .labels
+            .insert(label.into(), FixedHasher.hash_one(value));
+    }
+
+    /// Records a fingerprint of the property `property` of the loaded asset, such as
+    /// `"Material0/base_color"`.
+    ///
+    /// See [`LoadContext::fingerprint_label`].
+    pub fn fingerprint_property(&mut self, property: impl Into<String>, value: &impl Hash) {
+        self.fingerprints
+            .properties
+            .insert(property.into(), FixedHasher.hash_one(value));
+    }
+
     /// Gets the source path for this load context.
     pub fn path(&self) -> &Path {
         self.asset_path.path()
@@ -464,6 +551,17 @@ impl<'a> LoadContext<'a> {
         &self.asset_path
     }
 
+    /// Reports the fraction of the decoding of the asset done so far, between 0 and 1, for
+    /// [`AssetServer::load_progress`](crate::AssetServer::load_progress).
+    ///
+    /// Loaders decoding large assets can call this as they go to let apps show accurate loading
+    /// bars. This does nothing when the asset isn't loaded by the [`AssetServer`], like when
+    /// loading it directly from another loader.
+    pub fn report_progress(&self, decoded: f32) {
+        self.asset_server
+            .report_decode_progress(&self.asset_path, decoded);
+    }
+
     /// Reads the asset at the given path and returns its bytes
     pub async fn read_asset_bytes<'b, 'c>(
         &'b mut self,
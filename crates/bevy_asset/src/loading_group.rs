@@ -0,0 +1,168 @@
+use crate::{
+    AssetServer, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+    UntypedHandle,
+};
+use alloc::boxed::Box;
+use bevy_ecs::prelude::*;
+#[cfg(feature = "bevy_state")]
+use bevy_state::{commands::CommandsStatesExt, state::FreelyMutableState};
+
+/// A set of assets loading together, like the assets of a level behind a loading screen, tracked
+/// as one: spawn it on an entity to get its [progress](LoadingGroup::progress) and a
+/// [`LoadingGroupFinished`] event once all of its assets are loaded or failed.
+///
+/// Each asset counts as loaded once its dependencies are loaded too, so adding the handle of a
+/// [`LoadedFolder`](crate::LoadedFolder) tracks all the assets of the folder. With the `bevy_state`
+/// feature, the group can also [set a state](LoadingGroup::with_next_state) once loaded.
+///
+/// ```
+/// # use bevy_asset::{AssetServer, LoadingGroup, LoadingGroupFinished};
+/// # use bevy_ecs::prelude::*;
+/// fn load_level(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn(
+///         LoadingGroup::new()
+///             .with(asset_server.load_folder("levels/forest"))
+///             .with(asset_server.load_untyped("music/forest.ogg")),
+///     );
+/// }
+///
+/// fn start_level(mut finished: EventReader<LoadingGroupFinished>) {
+///     for event in finished.read() {
+///         if event.failed.is_empty() {
+///             // all the assets of the level are ready
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Default)]
+pub struct LoadingGroup {
+    handles: Vec<UntypedHandle>,
+    progress: f32,
+    failed: Vec<UntypedAssetId>,
+    finished: bool,
+    on_loaded: Option<Box<dyn FnOnce(&mut Commands) + Send + Sync>>,
+}
+
+impl LoadingGroup {
+    /// Creates an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the asset of `handle` to the group.
+    pub fn with(mut self, handle: impl Into<UntypedHandle>) -> Self {
+        self.add(handle);
+        self
+    }
+
+    /// Adds the asset of `handle` to the group. Adding assets to a finished group tracks them again
+    /// and sends a new [`LoadingGroupFinished`] event once they're loaded.
+    pub fn add(&mut self, handle: impl Into<UntypedHandle>) {
+        self.handles.push(handle.into());
+        self.finished = false;
+    }
+
+    /// Sets the state `S` to `state` once all the assets of the group are loaded. The state isn't
+    /// set if any of them failed to load.
+    #[cfg(feature = "bevy_state")]
+    pub fn with_next_state<S: FreelyMutableState>(mut self, state: S) -> Self {
+        self.on_loaded = Some(Box::new(move |commands| commands.set_state(state)));
+        self
+    }
+
+    /// Returns the handles of the assets of the group. The group keeps them loaded as long as it
+    /// exists.
+    pub fn handles(&self) -> &[UntypedHandle] {
+        &self.handles
+    }
+
+    /// Returns the fraction of the group loaded, between 0 and 1, as of the last update.
+    ///
+    /// Each asset counts for the same share: half of it while its bytes are read, see
+    /// [`AssetServer::load_progress`], and the other half once it and its dependencies are loaded.
+    /// Failed assets count as done.
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Returns `true` if all the assets of the group are loaded or failed, as of the last update.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the ids of the assets of the group that failed to load, or whose dependencies failed
+    /// to load.
+    pub fn failed(&self) -> &[UntypedAssetId] {
+        &self.failed
+    }
+}
+
+/// An event emitted when all the assets of a [`LoadingGroup`] are loaded or failed.
+#[derive(Event, Clone, Debug)]
+pub struct LoadingGroupFinished {
+    /// The entity of the group.
+    pub entity: Entity,
+    /// The assets of the group that failed to load, or whose dependencies failed to load.
+    pub failed: Vec<UntypedAssetId>,
+}
+
+/// Updates the progress of the [`LoadingGroup`]s and sends [`LoadingGroupFinished`] events.
+pub(crate) fn track_loading_groups(
+    mut commands: Commands,
+    mut groups: Query<(Entity, &mut LoadingGroup)>,
+    mut finished_events: EventWriter<LoadingGroupFinished>,
+    asset_server: Res<AssetServer>,
+) {
+    for (entity, mut group) in &mut groups {
+        if group.finished {
+            continue;
+        }
+        let group = &mut *group;
+
+        let mut done = 0;
+        let mut partial = 0.0;
+        group.failed.clear();
+        for handle in &group.handles {
+            match asset_server.get_load_states(handle.id()) {
+                // Assets added to their `Assets` directly aren't tracked by the server.
+                None
+                | Some((
+                    LoadState::Loaded,
+                    DependencyLoadState::Loaded,
+                    RecursiveDependencyLoadState::Loaded,
+                )) => done += 1,
+                Some((LoadState::Failed(_), ..))
+                | Some((_, DependencyLoadState::Failed(_), _))
+                | Some((_, _, RecursiveDependencyLoadState::Failed(_))) => {
+                    group.failed.push(handle.id());
+                    done += 1;
+                }
+                Some((LoadState::Loaded, ..)) => partial += 0.5,
+                Some(_) => {
+                    partial += asset_server
+                        .load_progress(handle.id())
+                        .and_then(|progress| progress.read_fraction())
+                        .map_or(0.0, |fraction| fraction * 0.5);
+                }
+            }
+        }
+
+        let total = group.handles.len();
+        if done < total {
+            group.progress = (done as f32 + partial) / total as f32;
+            continue;
+        }
+        group.progress = 1.0;
+
+        group.finished = true;
+        if let Some(on_loaded) = group.on_loaded.take() {
+            if group.failed.is_empty() {
+                on_loaded(&mut commands);
+            }
+        }
+        finished_events.send(LoadingGroupFinished {
+            entity,
+            failed: group.failed.clone(),
+        });
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{Asset, AssetEvent, Assets, UntypedAssetId};
+use bevy_ecs::prelude::*;
+use bevy_utils::{HashMap, TypeIdMap};
+use core::{
+    any::TypeId,
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, AddAssign},
+};
+
+/// The approximate memory used by an asset, in bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AssetMemoryUsage {
+    /// The memory used in the main world, on the CPU.
+    pub cpu: usize,
+    /// The memory used by the asset once uploaded to the GPU.
+    pub gpu: usize,
+}
+
+impl AssetMemoryUsage {
+    /// Returns the memory used on the CPU and the GPU.
+    pub fn total(&self) -> usize {
+        self.cpu + self.gpu
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            cpu: self.cpu.saturating_sub(rhs.cpu),
+            gpu: self.gpu.saturating_sub(rhs.gpu),
+        }
+    }
+}
+
+impl Add for AssetMemoryUsage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            cpu: self.cpu + rhs.cpu,
+            gpu: self.gpu + rhs.gpu,
+        }
+    }
+}
+
+impl AddAssign for AssetMemoryUsage {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for AssetMemoryUsage {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// The memory used by the assets of one type, see [`AssetMemoryDiagnostics::get_type`].
+#[derive(Clone, Copy, Debug)]
+pub struct AssetTypeMemoryUsage {
+    /// The [type path](bevy_reflect::TypePath::type_path) of the asset type.
+    pub type_path: &'static str,
+    /// The number of assets of this type in their [`Assets`] collection.
+    pub count: usize,
+    /// The memory used by all the assets of this type.
+    pub usage: AssetMemoryUsage,
+}
+
+/// The approximate memory used by each asset in the [`Assets`] collections, and the totals per
+/// asset type, to implement memory budgets or find assets that are kept alive by mistake.
+///
+/// The usage of an asset is measured when it's added or modified. By default, only the size of the
+/// asset value itself is counted, as the memory it owns can't be known in general: asset types
+/// can measure it with [`AssetApp::register_asset_memory_usage`](crate::AssetApp::register_asset_memory_usage),
+/// which the render assets do with their uploaded size.
+///
+/// This resource only exists when [`AssetPlugin::memory_diagnostics`](crate::AssetPlugin::memory_diagnostics)
+/// is enabled.
+///
+/// ```
+/// # use bevy_asset::{Asset, AssetMemoryDiagnostics};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::TypePath;
+/// # #[derive(Asset, TypePath)]
+/// # struct Texture;
+/// fn log_texture_memory(memory: Res<AssetMemoryDiagnostics>) {
+///     if let Some(textures) = memory.get_type::<Texture>() {
+///         println!("{} textures use {} bytes", textures.count, textures.usage.total());
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct AssetMemoryDiagnostics {
+    assets: HashMap<UntypedAssetId, AssetMemoryUsage>,
+    types: TypeIdMap<AssetTypeMemoryUsage>,
+}
+
+impl AssetMemoryDiagnostics {
+    /// Returns the memory used by the asset `id`, if it's in its [`Assets`] collection.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<AssetMemoryUsage> {
+        self.assets.get(&id.into()).copied()
+    }
+
+    /// Returns the memory used by the assets of type `A`, if any was added.
+    pub fn get_type<A: Asset>(&self) -> Option<&AssetTypeMemoryUsage> {
+        self.types.get(&TypeId::of::<A>())
+    }
+
+    /// Returns an iterator over the memory used by each asset type.
+    pub fn iter_types(&self) -> impl Iterator<Item = &AssetTypeMemoryUsage> {
+        self.types.values()
+    }
+
+    /// Returns an iterator over the memory used by each asset.
+    pub fn iter(&self) -> impl Iterator<Item = (UntypedAssetId, AssetMemoryUsage)> + '_ {
+        self.assets.iter().map(|(&id, &usage)| (id, usage))
+    }
+
+    /// Returns the memory used by all the assets.
+    pub fn total(&self) -> AssetMemoryUsage {
+        self.types.values().map(|memory| memory.usage).sum()
+    }
+
+    fn insert<A: Asset>(&mut self, id: UntypedAssetId, usage: AssetMemoryUsage) {
+        let memory = self
+            .types
+            .entry(TypeId::of::<A>())
+            .or_insert_with(|| AssetTypeMemoryUsage {
+                type_path: A::type_path(),
+                count: 0,
+                usage: AssetMemoryUsage::default(),
+            });
+        match self.assets.insert(id, usage) {
+            Some(previous) => memory.usage = memory.usage.saturating_sub(previous),
+            None => memory.count += 1,
+        }
+        memory.usage += usage;
+    }
+
+    fn remove(&mut self, id: UntypedAssetId) {
+        let Some(usage) = self.assets.remove(&id) else {
+            return;
+        };
+        if let Some(memory) = self.types.get_mut(&id.type_id()) {
+            memory.count -= 1;
+            memory.usage = memory.usage.saturating_sub(usage);
+        }
+    }
+}
+
+/// Measures the memory used by assets of type `A`, see
+/// [`AssetApp::register_asset_memory_usage`](crate::AssetApp::register_asset_memory_usage).
+#[derive(Resource)]
+pub(crate) struct AssetMemoryUsageFn<A: Asset> {
+    pub(crate) measure: fn(&A) -> AssetMemoryUsage,
+    pub(crate) marker: PhantomData<fn() -> A>,
+}
+
+/// Updates the [`AssetMemoryDiagnostics`] of the assets of type `A` that were added, modified or
+/// removed.
+pub(crate) fn track_asset_memory<A: Asset>(
+    mut events: EventReader<AssetEvent<A>>,
+    assets: Res<Assets<A>>,
+    measure: Option<Res<AssetMemoryUsageFn<A>>>,
+    mut diagnostics: ResMut<AssetMemoryDiagnostics>,
+) {
+    let measure: fn(&A) -> AssetMemoryUsage = measure.map_or(
+        |_| AssetMemoryUsage {
+            cpu: size_of::<A>(),
+            gpu: 0,
+        },
+        |measure| measure.measure,
+    );
+    for event in events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                if let Some(asset) = assets.get(id) {
+                    diagnostics.insert::<A>(id.untyped(), measure(asset));
+                }
+            }
+            AssetEvent::Removed { id } => diagnostics.remove(id.untyped()),
+            AssetEvent::Unused { .. } | AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+}
@@ -92,6 +92,13 @@ pub struct AssetProcessor {
     pub(crate) data: Arc<AssetProcessorData>,
 }
 
+/// A default processor for an extension within a folder, see [`AssetProcessor::set_default_processor_for_folder`].
+struct FolderProcessor {
+    folder: AssetPath<'static>,
+    extension: Box<str>,
+    processor: &'static str,
+}
+
 /// Internal data stored inside an [`AssetProcessor`].
 pub struct AssetProcessorData {
     pub(crate) asset_infos: async_lock::RwLock<ProcessorAssetInfos>,
@@ -99,6 +106,8 @@ pub struct AssetProcessorData {
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    /// Default processors for file extensions within folders, which take precedence over `default_processors`
+    folder_processors: RwLock<Vec<FolderProcessor>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -541,6 +550,53 @@ impl AssetProcessor {
         self.data.processors.read().get(key).cloned()
     }
 
+    /// Set the default processor for the given `extension` within `folder` and its subfolders. Make sure `P` is
+    /// registered with [`AssetProcessor::register_processor`].
+    ///
+    /// This takes precedence over the default processor set with [`AssetProcessor::set_default_processor`], and
+    /// the processor of the innermost folder is used when several folders contain an asset. `folder` can name an
+    /// asset source, like `"embedded://textures"`.
+    pub fn set_default_processor_for_folder<'a, P: Process>(
+        &self,
+        folder: impl Into<AssetPath<'a>>,
+        extension: &str,
+    ) {
+        let folder = folder.into().without_label().into_owned();
+        let mut folder_processors = self.data.folder_processors.write();
+        folder_processors.retain(|f| f.folder != folder || &*f.extension != extension);
+        folder_processors.push(FolderProcessor {
+            folder,
+            extension: extension.into(),
+            processor: core::any::type_name::<P>(),
+        });
+    }
+
+    /// Returns the default processor for the asset at `path`, if it exists. This is the processor set for the
+    /// extension of `path` in its innermost folder with [`AssetProcessor::set_default_processor_for_folder`], or
+    /// otherwise the one set for the extension with [`AssetProcessor::set_default_processor`].
+    pub fn get_default_processor_for_path(
+        &self,
+        path: &AssetPath,
+    ) -> Option<Arc<dyn ErasedProcessor>> {
+        let extension = path.get_full_extension()?;
+        let key = self
+            .data
+            .folder_processors
+            .read()
+            .iter()
+            .filter(|f| {
+                *f.extension == extension
+                    && f.folder.source() == path.source()
+                    && path.path().starts_with(f.folder.path())
+            })
+            .max_by_key(|f| f.folder.path().components().count())
+            .map(|f| f.processor);
+        match key {
+            Some(key) => self.data.processors.read().get(key).cloned(),
+            None => self.get_default_processor(&extension),
+        }
+    }
+
     /// Returns the processor with the given `processor_type_name`, if it exists.
     pub fn get_processor(&self, processor_type_name: &str) -> Option<Arc<dyn ErasedProcessor>> {
         let processors = self.data.processors.read();
@@ -780,22 +836,20 @@ impl AssetProcessor {
                 (meta, meta_bytes, processor)
             }
             Err(AssetReaderError::NotFound(_path)) => {
-                let (meta, processor) = if let Some(processor) = asset_path
-                    .get_full_extension()
-                    .and_then(|ext| self.get_default_processor(&ext))
-                {
-                    let meta = processor.default_meta();
-                    (meta, Some(processor))
-                } else {
-                    match server.get_path_asset_loader(asset_path.clone()).await {
-                        Ok(loader) => (loader.default_meta(), None),
-                        Err(MissingAssetLoaderForExtensionError { .. }) => {
-                            let meta: Box<dyn AssetMetaDyn> =
-                                Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
-                            (meta, None)
+                let (meta, processor) =
+                    if let Some(processor) = self.get_default_processor_for_path(asset_path) {
+                        let meta = processor.default_meta();
+                        (meta, Some(processor))
+                    } else {
+                        match server.get_path_asset_loader(asset_path.clone()).await {
+                            Ok(loader) => (loader.default_meta(), None),
+                            Err(MissingAssetLoaderForExtensionError { .. }) => {
+                                let meta: Box<dyn AssetMetaDyn> =
+                                    Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
+                                (meta, None)
+                            }
                         }
-                    }
-                };
+                    };
                 let meta_bytes = meta.serialize();
                 // write meta to source location if it doesn't already exist
                 source
@@ -1026,6 +1080,7 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            folder_processors: Default::default(),
         }
     }
 
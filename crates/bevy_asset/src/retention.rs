@@ -0,0 +1,176 @@
+use crate::{
+    memory::AssetMemoryUsageFn, Asset, AssetEvent, AssetId, AssetPath, AssetServer, Assets, Handle,
+};
+use bevy_ecs::prelude::*;
+use bevy_utils::HashMap;
+
+/// How long the assets of one type stay loaded, set with
+/// [`AssetApp::set_asset_retention_policy`](crate::AssetApp::set_asset_retention_policy).
+///
+/// Policies only apply to the assets loaded by the [`AssetServer`], as the other assets can't be
+/// loaded again once removed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AssetRetentionPolicy {
+    /// Assets are unloaded as soon as their last strong [`Handle`] is dropped.
+    #[default]
+    UnloadWhenUnused,
+    /// Assets stay loaded until they're [unloaded](AssetServer::unload), even if no handle to them
+    /// remains, so loading them again is instant.
+    KeepForever,
+    /// Assets stay loaded when their handles are dropped, until the unused assets of this type
+    /// exceed the `budget`: the least recently used ones are then unloaded.
+    ///
+    /// If the memory used by assets of this type is measured with
+    /// [`AssetApp::register_asset_memory_usage`](crate::AssetApp::register_asset_memory_usage),
+    /// the budget is in bytes. Otherwise, the size of the assets is unknown, and the budget is the
+    /// number of unused assets to keep loaded.
+    Lru {
+        /// The number of bytes of unused assets to keep loaded, or the number of unused assets if
+        /// their memory isn't measured.
+        budget: usize,
+    },
+}
+
+/// Why an asset was unloaded, see [`AssetUnloadedEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetUnloadReason {
+    /// The asset was unloaded with [`AssetServer::unload`].
+    Requested,
+    /// The asset was unused and was evicted to stay within the budget of an
+    /// [`AssetRetentionPolicy::Lru`] policy.
+    Evicted,
+}
+
+/// An event emitted when an [`Asset`] loaded by the [`AssetServer`] is unloaded by
+/// [`AssetServer::unload`] or by its [`AssetRetentionPolicy`].
+///
+/// The asset is removed from its [`Assets`] collection in the same frame when it was
+/// [requested](AssetUnloadReason::Requested), and on the next frame when it was
+/// [evicted](AssetUnloadReason::Evicted), emitting [`AssetEvent::Removed`].
+#[derive(Event, Clone, Debug)]
+pub struct AssetUnloadedEvent<A: Asset> {
+    pub id: AssetId<A>,
+    /// The path the asset was loaded from.
+    pub path: AssetPath<'static>,
+    /// Why the asset was unloaded.
+    pub reason: AssetUnloadReason,
+}
+
+/// The [`AssetRetentionPolicy`] of the assets of type `A`, and the handles keeping them loaded.
+#[derive(Resource)]
+pub(crate) struct AssetRetention<A: Asset> {
+    pub(crate) policy: AssetRetentionPolicy,
+    retained: HashMap<AssetId<A>, RetainedAsset<A>>,
+    frame: u64,
+}
+
+struct RetainedAsset<A: Asset> {
+    handle: Handle<A>,
+    last_used: u64,
+}
+
+impl<A: Asset> AssetRetention<A> {
+    pub(crate) fn new(policy: AssetRetentionPolicy) -> Self {
+        Self {
+            policy,
+            retained: HashMap::default(),
+            frame: 0,
+        }
+    }
+}
+
+/// Applies the [`AssetRetentionPolicy`] of the assets of type `A`: retains the handles of the loaded
+/// assets, evicts the least recently used ones, and removes the assets passed to
+/// [`AssetServer::unload`].
+pub(crate) fn apply_asset_retention<A: Asset>(
+    mut retention: ResMut<AssetRetention<A>>,
+    mut assets: ResMut<Assets<A>>,
+    mut asset_events: EventReader<AssetEvent<A>>,
+    mut unloaded_events: EventWriter<AssetUnloadedEvent<A>>,
+    asset_server: Res<AssetServer>,
+    measure: Option<Res<AssetMemoryUsageFn<A>>>,
+) {
+    let retention = &mut *retention;
+    retention.frame += 1;
+    let frame = retention.frame;
+
+    for id in asset_server.take_unload_requests::<A>() {
+        retention.retained.remove(&id);
+        if assets.remove(id).is_some() {
+            if let Some(path) = asset_server.get_path(id) {
+                unloaded_events.send(AssetUnloadedEvent {
+                    id,
+                    path: path.into_owned(),
+                    reason: AssetUnloadReason::Requested,
+                });
+            }
+        }
+    }
+
+    if retention.policy == AssetRetentionPolicy::UnloadWhenUnused {
+        for (id, _) in retention.retained.drain() {
+            asset_server.set_retained(id, false);
+        }
+        asset_events.clear();
+        return;
+    }
+
+    for event in asset_events.read() {
+        if let AssetEvent::Added { id } = *event {
+            if let Some(handle) = asset_server.get_id_handle(id) {
+                asset_server.set_retained(id, true);
+                retention.retained.insert(
+                    id,
+                    RetainedAsset {
+                        handle,
+                        last_used: frame,
+                    },
+                );
+            }
+        }
+    }
+
+    let AssetRetentionPolicy::Lru { budget } = retention.policy else {
+        return;
+    };
+    // Without a registered measure, only the size of the asset values is known, so the unused
+    // assets are counted instead.
+    let size = |id: AssetId<A>| match &measure {
+        Some(measure) => assets
+            .get(id)
+            .map_or(0, |asset| (measure.measure)(asset).total()),
+        None => 1,
+    };
+    let mut unused = Vec::new();
+    let mut unused_size = 0;
+    for (&id, retained) in &mut retention.retained {
+        // The retained handle is the only one left when the asset isn't used anymore.
+        let is_used = match &retained.handle {
+            Handle::Strong(handle) => alloc::sync::Arc::strong_count(handle) > 1,
+            Handle::Weak(_) => false,
+        };
+        if is_used {
+            retained.last_used = frame;
+        } else {
+            unused_size += size(id);
+            unused.push((retained.last_used, id));
+        }
+    }
+    unused.sort_unstable();
+    for (_, id) in unused {
+        if unused_size <= budget {
+            break;
+        }
+        unused_size -= size(id);
+        // Dropping the last handle unloads the asset on the next frame.
+        retention.retained.remove(&id);
+        asset_server.set_retained(id, false);
+        if let Some(path) = asset_server.get_path(id) {
+            unloaded_events.send(AssetUnloadedEvent {
+                id,
+                path: path.into_owned(),
+                reason: AssetUnloadReason::Evicted,
+            });
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use crate::{
-    io::Writer, meta::Settings, transformer::TransformedAsset, Asset, AssetLoader,
-    ErasedLoadedAsset, Handle, LabeledAsset, UntypedHandle,
+    io::{AssetWriterError, MissingAssetSourceError, MissingAssetWriterError, Writer},
+    meta::{AssetAction, AssetMeta, AssetMetaDyn, Settings},
+    transformer::TransformedAsset,
+    Asset, AssetId, AssetLoader, AssetPath, AssetSaveFailedEvent, AssetSavedEvent, AssetServer,
+    Assets, ErasedLoadedAsset, Handle, LabeledAsset, UntypedHandle,
 };
+use alloc::sync::Arc;
 use atomicow::CowArc;
-use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
+use bevy_ecs::prelude::*;
+use bevy_tasks::{futures::check_ready, BoxedFuture, ConditionalSendFuture, IoTaskPool, Task};
 use bevy_utils::HashMap;
 use core::{borrow::Borrow, hash::Hash, ops::Deref};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Saves an [`Asset`] of a given [`AssetSaver::Asset`] type. [`AssetSaver::OutputLoader`] will then be used to load the saved asset
 /// in the final deployed application. The saver should produce asset bytes in a format that [`AssetSaver::OutputLoader`] can read.
@@ -164,3 +170,155 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         self.labeled_assets.keys().map(|s| &**s)
     }
 }
+
+/// An error that occurs when saving an asset with [`AssetServer::save`].
+#[derive(Error, Debug, Clone)]
+pub enum AssetSaveError {
+    #[error("no AssetSaver is registered for asset type {0}")]
+    MissingAssetSaver(&'static str),
+    #[error("the asset to save does not exist")]
+    MissingAsset,
+    #[error("asset type {0} was not registered with AssetApp::init_asset")]
+    UnregisteredAssetType(&'static str),
+    #[error("the AssetSaver failed: {0}")]
+    AssetSaverError(Arc<dyn core::error::Error + Send + Sync + 'static>),
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    #[error(transparent)]
+    MissingAssetWriter(#[from] MissingAssetWriterError),
+    #[error("failed to write the saved asset: {0}")]
+    AssetWriterError(Arc<AssetWriterError>),
+}
+
+impl From<AssetWriterError> for AssetSaveError {
+    fn from(error: AssetWriterError) -> Self {
+        Self::AssetWriterError(Arc::new(error))
+    }
+}
+
+/// The bytes of an asset and of its meta file, as produced by an [`AssetSaver`].
+struct SavedBytes {
+    asset: Vec<u8>,
+    meta: Vec<u8>,
+}
+
+/// The [`AssetSaver`] used by [`AssetServer::save`] for assets of type `A`, registered with
+/// [`AssetApp::register_asset_saver`](crate::AssetApp::register_asset_saver).
+///
+/// The asset is cloned so that the saver can run on the [`IoTaskPool`].
+#[derive(Resource)]
+pub(crate) struct RuntimeAssetSaver<A: Asset> {
+    save: Box<dyn Fn(&A) -> BoxedFuture<'static, Result<SavedBytes, AssetSaveError>> + Send + Sync>,
+}
+
+impl<A: Asset + Clone> RuntimeAssetSaver<A> {
+    pub(crate) fn new<S: AssetSaver<Asset = A>>(saver: S) -> Self {
+        let saver = Arc::new(saver);
+        Self {
+            save: Box::new(move |asset| {
+                let saver = saver.clone();
+                let asset = asset.clone();
+                Box::pin(async move {
+                    let labeled_assets = HashMap::default();
+                    let saved_asset = SavedAsset {
+                        value: &asset,
+                        labeled_assets: &labeled_assets,
+                    };
+                    let mut bytes = Vec::new();
+                    let settings = saver
+                        .save(&mut bytes, saved_asset, &S::Settings::default())
+                        .await
+                        .map_err(|error| {
+                            let error: Box<dyn core::error::Error + Send + Sync> = error.into();
+                            AssetSaveError::AssetSaverError(error.into())
+                        })?;
+                    let meta = AssetMeta::<S::OutputLoader, ()>::new(AssetAction::Load {
+                        loader: core::any::type_name::<S::OutputLoader>().to_string(),
+                        settings,
+                    });
+                    Ok(SavedBytes {
+                        asset: bytes,
+                        meta: AssetMetaDyn::serialize(&meta),
+                    })
+                })
+            }),
+        }
+    }
+}
+
+/// The saves of assets of type `A` whose bytes are being written.
+#[derive(Resource)]
+pub(crate) struct PendingAssetSaves<A: Asset> {
+    tasks: Vec<(
+        AssetId<A>,
+        AssetPath<'static>,
+        Task<Result<(), AssetSaveError>>,
+    )>,
+}
+
+impl<A: Asset> Default for PendingAssetSaves<A> {
+    fn default() -> Self {
+        Self { tasks: Vec::new() }
+    }
+}
+
+/// Saves the assets of type `A` passed to [`AssetServer::save`], and sends an [`AssetSavedEvent`]
+/// or an [`AssetSaveFailedEvent`] once they're written.
+///
+/// The [`AssetSaver`] and the writes of the bytes it produces run on the [`IoTaskPool`].
+pub(crate) fn save_assets<A: Asset>(
+    assets: Res<Assets<A>>,
+    saver: Res<RuntimeAssetSaver<A>>,
+    asset_server: Res<AssetServer>,
+    mut pending: ResMut<PendingAssetSaves<A>>,
+    mut saved_events: EventWriter<AssetSavedEvent<A>>,
+    mut failed_events: EventWriter<AssetSaveFailedEvent<A>>,
+) {
+    pending.tasks.retain_mut(|(id, path, task)| {
+        let Some(result) = check_ready(task) else {
+            return true;
+        };
+        match result {
+            Ok(()) => {
+                saved_events.send(AssetSavedEvent {
+                    id: *id,
+                    path: path.clone(),
+                });
+            }
+            Err(error) => {
+                failed_events.send(AssetSaveFailedEvent {
+                    id: *id,
+                    path: path.clone(),
+                    error,
+                });
+            }
+        }
+        false
+    });
+
+    for (id, path) in asset_server.take_save_requests::<A>() {
+        let Some(asset) = assets.get(id) else {
+            failed_events.send(AssetSaveFailedEvent {
+                id,
+                path,
+                error: AssetSaveError::MissingAsset,
+            });
+            continue;
+        };
+        let save = (saver.save)(asset);
+        let asset_server = asset_server.clone();
+        let write_path = path.clone();
+        let task = IoTaskPool::get().spawn(async move {
+            let bytes = save.await?;
+            let writer = asset_server
+                .get_source(write_path.source().clone())?
+                .writer()?;
+            writer.write_bytes(write_path.path(), &bytes.asset).await?;
+            writer
+                .write_meta_bytes(write_path.path(), &bytes.meta)
+                .await?;
+            Ok(())
+        });
+        pending.tasks.push((id, path, task));
+    }
+}
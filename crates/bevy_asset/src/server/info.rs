@@ -1,8 +1,9 @@
 use crate::{
+    loader::AssetFingerprints,
     meta::{AssetHash, MetaTransform},
-    Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
-    Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
-    UntypedAssetId, UntypedHandle,
+    Asset, AssetChange, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState,
+    ErasedLoadedAsset, Handle, InternalAssetEvent, LabeledAssetInfo, LoadState,
+    RecursiveDependencyLoadState, StrongHandle, UntypedAssetId, UntypedHandle,
 };
 use alloc::sync::{Arc, Weak};
 use bevy_ecs::world::World;
@@ -27,6 +28,12 @@ pub(crate) struct AssetInfo {
     failed_rec_dependencies: HashSet<UntypedAssetId>,
     dependents_waiting_on_load: HashSet<UntypedAssetId>,
     dependents_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
+    /// The direct dependencies of this asset, as of its last load.
+    dependencies: HashSet<UntypedAssetId>,
+    /// The fingerprints recorded by the loader of this asset, as of its last load.
+    fingerprints: Option<AssetFingerprints>,
+    /// The labeled assets of this asset, as of its last load.
+    pub(crate) labels: Vec<LabeledAssetInfo>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
     /// This is set using the value from [`LoadedAsset`].
     /// This will only be populated if [`AssetInfos::watching_for_changes`] is set to `true` to
@@ -39,6 +46,9 @@ pub(crate) struct AssetInfo {
     handle_drops_to_skip: usize,
     /// List of tasks waiting for this asset to complete loading
     pub(crate) waiting_tasks: Vec<Waker>,
+    /// Whether the [`AssetRetentionPolicy`](crate::AssetRetentionPolicy) of this asset holds a
+    /// handle to it, which [`AssetServer::unload`](crate::AssetServer::unload) may drop.
+    pub(crate) retained: bool,
 }
 
 impl AssetInfo {
@@ -56,10 +66,19 @@ impl AssetInfo {
             loader_dependencies: HashMap::default(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
+            dependencies: HashSet::default(),
+            fingerprints: None,
+            labels: Vec::new(),
             handle_drops_to_skip: 0,
             waiting_tasks: Vec::new(),
+            retained: false,
         }
     }
+
+    /// Returns the number of strong handles to this asset.
+    pub(crate) fn handle_count(&self) -> usize {
+        self.weak_handle.strong_count()
+    }
 }
 
 #[derive(Default)]
@@ -72,6 +91,8 @@ pub(crate) struct AssetInfos {
     /// Tracks assets that depend on the "key" asset path inside their asset loaders ("loader dependencies")
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) loader_dependents: HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
+    /// Tracks the assets that depend on the "key" asset, the reverse of [`AssetInfo::dependencies`].
+    dependents: HashMap<UntypedAssetId, HashSet<UntypedAssetId>>,
     /// Tracks living labeled assets for a given source asset.
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) living_labeled_assets: HashMap<AssetPath<'static>, HashSet<Box<str>>>,
@@ -79,6 +100,7 @@ pub(crate) struct AssetInfos {
     pub(crate) dependency_loaded_event_sender: TypeIdMap<fn(&mut World, UntypedAssetId)>,
     pub(crate) dependency_failed_event_sender:
         TypeIdMap<fn(&mut World, UntypedAssetId, AssetPath<'static>, AssetLoadError)>,
+    pub(crate) reloaded_event_sender: TypeIdMap<fn(&mut World, UntypedAssetId, AssetChange)>,
     pub(crate) pending_tasks: HashMap<UntypedAssetId, Task<()>>,
 }
 
@@ -370,6 +392,7 @@ impl AssetInfos {
             &mut self.infos,
             &mut self.path_to_id,
             &mut self.loader_dependents,
+            &mut self.dependents,
             &mut self.living_labeled_assets,
             &mut self.pending_tasks,
             self.watching_for_changes,
@@ -391,6 +414,8 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
+        self.set_dependencies(loaded_asset_id, loaded_asset.dependencies.clone());
+        self.set_fingerprints(loaded_asset_id, loaded_asset.fingerprints, world);
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
@@ -641,6 +666,110 @@ impl AssetInfos {
         }
     }
 
+    /// Replaces the direct dependencies of the asset `id`, updating the dependents of the
+    /// previous and new dependencies.
+    fn set_dependencies(&mut self, id: UntypedAssetId, dependencies: HashSet<UntypedAssetId>) {
+        let Some(info) = self.infos.get_mut(&id) else {
+            return;
+        };
+        let previous = core::mem::replace(&mut info.dependencies, dependencies);
+        Self::remove_dependent(&mut self.dependents, id, &previous);
+        for dependency in &self.infos[&id].dependencies {
+            self.dependents.entry(*dependency).or_default().insert(id);
+        }
+    }
+
+    /// Replaces the fingerprints of the asset `id`, sending an [`AssetReloaded`](crate::AssetReloaded)
+    /// event with the changes if it was loaded before.
+    fn set_fingerprints(
+        &mut self,
+        id: UntypedAssetId,
+        fingerprints: AssetFingerprints,
+        world: &mut World,
+    ) {
+        let Some(info) = self.infos.get_mut(&id) else {
+            return;
+        };
+        let Some(previous) = info.fingerprints.replace(fingerprints) else {
+            return;
+        };
+        let fingerprints = info.fingerprints.as_ref().unwrap();
+        if previous.is_empty() && fingerprints.is_empty() {
+            return;
+        }
+        let change = fingerprints.changes_since(&previous);
+        if let Some(sender) = self.reloaded_event_sender.get(&id.type_id()) {
+            sender(world, id, change);
+        }
+    }
+
+    fn remove_dependent(
+        dependents: &mut HashMap<UntypedAssetId, HashSet<UntypedAssetId>>,
+        id: UntypedAssetId,
+        dependencies: &HashSet<UntypedAssetId>,
+    ) {
+        for dependency in dependencies {
+            let Entry::Occupied(mut entry) = dependents.entry(*dependency) else {
+                continue;
+            };
+            entry.get_mut().remove(&id);
+            if entry.get().is_empty() {
+                entry.remove();
+            }
+        }
+    }
+
+    /// Returns the direct dependencies of the asset `id`.
+    pub(crate) fn dependencies(
+        &self,
+        id: UntypedAssetId,
+    ) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        self.infos
+            .get(&id)
+            .into_iter()
+            .flat_map(|info| info.dependencies.iter().copied())
+    }
+
+    /// Returns the assets that directly depend on the asset `id`.
+    pub(crate) fn dependents(
+        &self,
+        id: UntypedAssetId,
+    ) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        self.dependents.get(&id).into_iter().flatten().copied()
+    }
+
+    /// Returns `id` and the assets reachable from it through `edges`, each after all the assets
+    /// it reaches. Cycles are broken arbitrarily.
+    pub(crate) fn post_order<I: IntoIterator<Item = UntypedAssetId>>(
+        &self,
+        id: UntypedAssetId,
+        edges: impl Fn(UntypedAssetId) -> I,
+    ) -> Vec<UntypedAssetId> {
+        let mut order = Vec::new();
+        if !self.infos.contains_key(&id) {
+            return order;
+        }
+        let mut visited = <HashSet<_>>::default();
+        let mut stack = vec![(id, false)];
+        while let Some((id, expanded)) = stack.pop() {
+            if expanded {
+                order.push(id);
+                continue;
+            }
+            if !visited.insert(id) {
+                continue;
+            }
+            stack.push((id, true));
+            stack.extend(
+                edges(id)
+                    .into_iter()
+                    .filter(|next| !visited.contains(next))
+                    .map(|next| (next, false)),
+            );
+        }
+        order
+    }
+
     fn remove_dependents_and_labels(
         info: &AssetInfo,
         loader_dependents: &mut HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
@@ -674,6 +803,7 @@ impl AssetInfos {
         infos: &mut HashMap<UntypedAssetId, AssetInfo>,
         path_to_id: &mut HashMap<AssetPath<'static>, TypeIdMap<UntypedAssetId>>,
         loader_dependents: &mut HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
+        dependents: &mut HashMap<UntypedAssetId, HashSet<UntypedAssetId>>,
         living_labeled_assets: &mut HashMap<AssetPath<'static>, HashSet<Box<str>>>,
         pending_tasks: &mut HashMap<UntypedAssetId, Task<()>>,
         watching_for_changes: bool,
@@ -695,6 +825,7 @@ impl AssetInfos {
         let type_id = entry.key().type_id();
 
         let info = entry.remove();
+        Self::remove_dependent(dependents, id, &info.dependencies);
         let Some(path) = &info.path else {
             return true;
         };
@@ -733,6 +864,7 @@ impl AssetInfos {
                         &mut self.infos,
                         &mut self.path_to_id,
                         &mut self.loader_dependents,
+                        &mut self.dependents,
                         &mut self.living_labeled_assets,
                         &mut self.pending_tasks,
                         self.watching_for_changes,
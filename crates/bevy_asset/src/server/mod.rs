@@ -1,8 +1,13 @@
 mod info;
 mod loaders;
+mod progress;
+mod queue;
+
+pub use progress::{AssetLoadProgress, AssetLoadStage};
+pub use queue::AssetLoadPriority;
 
 use crate::{
-    folder::LoadedFolder,
+    folder::{FolderGlob, LoadedFolder, TypedFolderHandle},
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
         ErasedAssetReader, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader,
@@ -13,22 +18,25 @@ use crate::{
         MetaTransform, Settings,
     },
     path::AssetPath,
-    Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
-    DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UntypedAssetId,
-    UntypedAssetLoadFailedEvent, UntypedHandle,
+    saver::AssetSaveError,
+    Asset, AssetChange, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent,
+    AssetMetaCheck, AssetReloaded, Assets, DeserializeMetaError, ErasedLoadedAsset, Handle,
+    LoadedUntypedAsset, UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
 };
 use alloc::sync::Arc;
 use atomicow::CowArc;
 use bevy_ecs::prelude::*;
 use bevy_tasks::IoTaskPool;
-use bevy_utils::HashSet;
-use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
+use bevy_utils::{HashMap, HashSet};
+use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll, time::Duration};
 use crossbeam_channel::{Receiver, Sender};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
 use info::*;
 use loaders::*;
-use parking_lot::{RwLock, RwLockWriteGuard};
+use parking_lot::{Mutex, RwLock, RwLockWriteGuard};
+use progress::{ProgressReader, SharedLoadProgress};
+use queue::{budgeted_load, LoadBudget, LoadQueue};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{error, info};
@@ -61,6 +69,15 @@ pub(crate) struct AssetServerData {
     sources: AssetSources,
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
+    load_queue: Mutex<LoadQueue>,
+    load_budget: LoadBudget,
+    folder_globs: RwLock<HashSet<AssetPath<'static>>>,
+    unload_requests: Mutex<HashSet<UntypedAssetId>>,
+    save_requests: Mutex<Vec<(UntypedAssetId, AssetPath<'static>)>>,
+    /// The asset types with a saver registered with [`AssetApp::register_asset_saver`](crate::AssetApp::register_asset_saver).
+    saver_types: RwLock<HashSet<TypeId>>,
+    /// The progress of the assets being loaded, by path.
+    load_progress: Mutex<HashMap<AssetPath<'static>, SharedLoadProgress>>,
 }
 
 /// The "asset mode" the server is currently in.
@@ -121,6 +138,13 @@ impl AssetServer {
                 asset_event_receiver,
                 loaders,
                 infos: RwLock::new(infos),
+                load_queue: Default::default(),
+                load_budget: Default::default(),
+                folder_globs: Default::default(),
+                unload_requests: Default::default(),
+                save_requests: Default::default(),
+                saver_types: Default::default(),
+                load_progress: Default::default(),
             }),
         }
     }
@@ -166,6 +190,15 @@ impl AssetServer {
                 });
         }
 
+        fn reloaded_sender<A: Asset>(world: &mut World, id: UntypedAssetId, change: AssetChange) {
+            world
+                .resource_mut::<Events<AssetReloaded<A>>>()
+                .send(AssetReloaded {
+                    id: id.typed(),
+                    change,
+                });
+        }
+
         let mut infos = self.data.infos.write();
 
         infos
@@ -175,6 +208,10 @@ impl AssetServer {
         infos
             .dependency_failed_event_sender
             .insert(TypeId::of::<A>(), failed_sender::<A>);
+
+        infos
+            .reloaded_event_sender
+            .insert(TypeId::of::<A>(), reloaded_sender::<A>);
     }
 
     pub(crate) fn register_handle_provider(&self, handle_provider: AssetHandleProvider) {
@@ -362,6 +399,232 @@ impl AssetServer {
         self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), guard)
     }
 
+    /// Begins loading an [`Asset`] of type `A` stored at `path`, after the queued loads with a
+    /// higher [`AssetLoadPriority`].
+    ///
+    /// Unless the priority is [`AssetLoadPriority::Critical`], the load is queued rather than
+    /// started, and the queued loads are started from the highest priority down, as the
+    /// [budget](AssetServer::set_load_budget) allows. The asset is [`LoadState::Loading`] while
+    /// queued. Loading it again with a higher priority moves it up the queue, and loading it with
+    /// [`AssetServer::load`] starts it right away.
+    ///
+    /// ```
+    /// # use bevy_asset::{Asset, AssetLoadPriority, AssetServer, Handle};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::TypePath;
+    /// # #[derive(Asset, TypePath)]
+    /// # struct Model;
+    /// fn stream_terrain(asset_server: Res<AssetServer>) {
+    ///     let player: Handle<Model> = asset_server.load("player.model");
+    ///     let terrain: Handle<Model> =
+    ///         asset_server.load_with_priority("terrain/far.model", AssetLoadPriority::Low);
+    /// }
+    /// ```
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_priority<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        priority: AssetLoadPriority,
+    ) -> Handle<A> {
+        if priority == AssetLoadPriority::Critical {
+            return self.load(path);
+        }
+        let path = path.into().into_owned();
+        let mut infos = self.data.infos.write();
+        let (handle, should_load) =
+            infos.get_or_create_path_handle::<A>(path.clone(), HandleLoadingMode::Request, None);
+        let mut queue = self.data.load_queue.lock();
+        if should_load || queue.remove(handle.id().untyped()).is_some() {
+            queue.push(priority, handle.id().untyped(), path);
+        }
+
+        handle
+    }
+
+    /// Sets how long the queued loads may spend decoding their assets each frame, or removes the
+    /// limit with `None`.
+    ///
+    /// Only the loads started with [`AssetServer::load_with_priority`] below
+    /// [`AssetLoadPriority::Critical`] count against this budget, so that they don't compete
+    /// with critical loads. The time spent in their [`AssetLoader`]s is taken from the budget of
+    /// the frame, and they wait for the next frame once it is used up. Each frame, as many queued
+    /// loads as are expected to fit in the budget are started, from the highest priority down,
+    /// based on the time taken by the previous loads. No queued load is started while the budget
+    /// is used up.
+    ///
+    /// There is no limit by default, and all queued loads are started on the next frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the budget is zero, as no queued load could ever finish.
+    pub fn set_load_budget(&self, per_frame: Option<Duration>) {
+        assert!(
+            per_frame.is_none_or(|per_frame| !per_frame.is_zero()),
+            "the load budget must not be zero"
+        );
+        self.data.load_budget.set_per_frame(per_frame);
+    }
+
+    /// Returns how long the queued loads may spend decoding their assets each frame, see
+    /// [`AssetServer::set_load_budget`].
+    pub fn load_budget(&self) -> Option<Duration> {
+        self.data.load_budget.per_frame()
+    }
+
+    /// Returns the number of loads with this `priority` waiting to be started.
+    pub fn queued_load_count(&self, priority: AssetLoadPriority) -> usize {
+        self.data.load_queue.lock().len(priority)
+    }
+
+    /// Starts a new frame of the load budget, and the queued loads it allows, from the highest
+    /// priority down.
+    pub(crate) fn start_queued_loads(&self) {
+        let limit = self.data.load_budget.refill();
+        let mut started = 0;
+        while started < limit {
+            let infos = self.data.infos.write();
+            let Some((id, path)) = self.data.load_queue.lock().pop() else {
+                break;
+            };
+            // The handles may have been dropped while the load was queued.
+            if let Some(handle) = infos.get_id_handle(id) {
+                self.spawn_load_task(handle, path, infos, ());
+                started += 1;
+            } else {
+                self.data.load_queue.lock().take_started(&path);
+            }
+        }
+    }
+
+    /// Unloads the asset `id` kept loaded by its [`AssetRetentionPolicy`](crate::AssetRetentionPolicy),
+    /// removing it from its [`Assets`] collection at the end of the frame and sending an
+    /// [`AssetUnloadedEvent`](crate::AssetUnloadedEvent).
+    ///
+    /// Its load state goes back to [`LoadState::NotLoaded`], so loading its path again reloads it.
+    /// The labeled sub-assets of the asset are unloaded separately. Returns `false` if the asset
+    /// isn't retained by the policy of its type, or if other strong handles to it remain, as they
+    /// would point to a missing asset.
+    pub fn unload(&self, id: impl Into<UntypedAssetId>) -> bool {
+        let id = id.into();
+        let mut infos = self.data.infos.write();
+        let Some(info) = infos.get_mut(id) else {
+            return false;
+        };
+        // The retention policy holds the only strong handle to the asset.
+        if !info.retained
+            || info.handle_count() > 1
+            || info.path.is_none()
+            || !info.load_state.is_loaded()
+        {
+            return false;
+        }
+        info.retained = false;
+        info.load_state = LoadState::NotLoaded;
+        info.dep_load_state = DependencyLoadState::NotLoaded;
+        info.rec_dep_load_state = RecursiveDependencyLoadState::NotLoaded;
+        self.data.unload_requests.lock().insert(id);
+        true
+    }
+
+    /// Sets whether the [`AssetRetentionPolicy`](crate::AssetRetentionPolicy) of the asset `id`
+    /// holds a handle to it, see [`AssetServer::unload`].
+    pub(crate) fn set_retained(&self, id: impl Into<UntypedAssetId>, retained: bool) {
+        if let Some(info) = self.data.infos.write().get_mut(id.into()) {
+            info.retained = retained;
+        }
+    }
+
+    /// Takes the assets of type `A` passed to [`AssetServer::unload`] since the last call.
+    pub(crate) fn take_unload_requests<A: Asset>(&self) -> Vec<AssetId<A>> {
+        let mut requests = self.data.unload_requests.lock();
+        if requests.is_empty() {
+            return Vec::new();
+        }
+        let mut ids = Vec::new();
+        requests.retain(|id| match id.try_typed::<A>() {
+            Ok(id) => {
+                ids.push(id);
+                false
+            }
+            Err(_) => true,
+        });
+        ids
+    }
+
+    /// Saves the asset `id` to `path` with the [`AssetSaver`](crate::saver::AssetSaver) registered
+    /// for its type with [`AssetApp::register_asset_saver`](crate::AssetApp::register_asset_saver),
+    /// along with a meta file holding the loader settings returned by the saver.
+    ///
+    /// The saver is started at the end of the frame on the [`IoTaskPool`], and its output is
+    /// written to the [`AssetWriter`](crate::io::AssetWriter) of the asset source of `path`. An
+    /// [`AssetSavedEvent`](crate::AssetSavedEvent) or an
+    /// [`AssetSaveFailedEvent`](crate::AssetSaveFailedEvent) is sent once it's done.
+    ///
+    /// Returns an error if the type `A` wasn't registered with
+    /// [`AssetApp::init_asset`](crate::AssetApp::init_asset), or has no registered saver, as
+    /// nothing would save it.
+    ///
+    /// ```
+    /// # use bevy_asset::{AssetServer, Assets, Handle};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::TypePath;
+    /// # #[derive(bevy_asset::Asset, TypePath)]
+    /// # struct Curve;
+    /// # #[derive(Resource)]
+    /// # struct EditedCurve(Handle<Curve>);
+    /// fn save_edited_curve(asset_server: Res<AssetServer>, edited: Res<EditedCurve>) {
+    ///     asset_server
+    ///         .save(&edited.0, "curves/edited.curve.ron")
+    ///         .expect("curves have a registered saver");
+    /// }
+    /// ```
+    pub fn save<'a, A: Asset>(
+        &self,
+        id: impl Into<AssetId<A>>,
+        path: impl Into<AssetPath<'a>>,
+    ) -> Result<(), AssetSaveError> {
+        if !self
+            .data
+            .infos
+            .read()
+            .dependency_loaded_event_sender
+            .contains_key(&TypeId::of::<A>())
+        {
+            return Err(AssetSaveError::UnregisteredAssetType(A::type_path()));
+        }
+        if !self.data.saver_types.read().contains(&TypeId::of::<A>()) {
+            return Err(AssetSaveError::MissingAssetSaver(A::type_path()));
+        }
+        self.data
+            .save_requests
+            .lock()
+            .push((id.into().untyped(), path.into().into_owned()));
+        Ok(())
+    }
+
+    /// Records that assets of type `A` can be saved with [`AssetServer::save`]. Returns `false` if
+    /// a saver was already registered for `A`.
+    pub(crate) fn register_saver_type<A: Asset>(&self) -> bool {
+        self.data.saver_types.write().insert(TypeId::of::<A>())
+    }
+
+    /// Takes the assets of type `A` passed to [`AssetServer::save`] since the last call.
+    pub(crate) fn take_save_requests<A: Asset>(&self) -> Vec<(AssetId<A>, AssetPath<'static>)> {
+        let mut requests = self.data.save_requests.lock();
+        if requests.is_empty() {
+            return Vec::new();
+        }
+        let mut saves = Vec::new();
+        requests.retain(|(id, path)| match id.try_typed::<A>() {
+            Ok(id) => {
+                saves.push((id, path.clone()));
+                false
+            }
+            Err(_) => true,
+        });
+        saves
+    }
+
     pub(crate) fn load_with_meta_transform<'a, A: Asset, G: Send + Sync + 'static>(
         &self,
         path: impl Into<AssetPath<'a>>,
@@ -378,6 +641,12 @@ impl AssetServer {
 
         if should_load {
             self.spawn_load_task(handle.clone().untyped(), path, infos, guard);
+        } else {
+            // A queued load requested without priority starts right away.
+            let queued = self.data.load_queue.lock().remove(handle.id().untyped());
+            if let Some(path) = queued {
+                self.spawn_load_task(handle.clone().untyped(), path, infos, guard);
+            }
         }
 
         handle
@@ -402,6 +671,12 @@ impl AssetServer {
 
         if should_load {
             self.spawn_load_task(handle.clone(), path, infos, guard);
+        } else {
+            // A queued load requested without priority starts right away.
+            let queued = self.data.load_queue.lock().remove(handle.id());
+            if let Some(path) = queued {
+                self.spawn_load_task(handle.clone(), path, infos, guard);
+            }
         }
 
         handle
@@ -551,7 +826,7 @@ impl AssetServer {
 
         let path = path.into_owned();
         let path_clone = path.clone();
-        let (mut meta, loader, mut reader) = self
+        let (mut meta, loader, reader) = self
             .get_meta_loader_and_reader(&path_clone, asset_type_id)
             .await
             .inspect_err(|e| {
@@ -641,17 +916,38 @@ impl AssetServer {
             (handle.clone().unwrap(), path.clone())
         };
 
-        match self
-            .load_with_meta_loader_and_reader(
-                &base_path,
-                meta.as_ref(),
-                &*loader,
-                &mut *reader,
-                true,
-                false,
-            )
-            .await
+        let budgeted = self.data.load_queue.lock().take_started(&path);
+        let mut reader = ProgressReader::new(reader);
+        let progress = reader.progress().clone();
+        self.data
+            .load_progress
+            .lock()
+            .insert(base_path.clone(), progress.clone());
+        let load = self.load_with_meta_loader_and_reader(
+            &base_path,
+            meta.as_ref(),
+            &*loader,
+            &mut reader,
+            true,
+            false,
+        );
+        let result = if budgeted {
+            budgeted_load(load, &self.data.load_budget).await
+        } else {
+            load.await
+        };
         {
+            let mut load_progress = self.data.load_progress.lock();
+            // Another load of the same path may have started in the meantime.
+            if load_progress
+                .get(&base_path)
+                .is_some_and(|current| Arc::ptr_eq(current, &progress))
+            {
+                load_progress.remove(&base_path);
+            }
+        }
+
+        match result {
             Ok(loaded_asset) => {
                 let final_handle = if let Some(label) = path.label_cow() {
                     match loaded_asset.labeled_assets.get(&label) {
@@ -692,6 +988,20 @@ impl AssetServer {
     /// Sends a load event for the given `loaded_asset` and does the same recursively for all
     /// labeled assets.
     fn send_loaded_asset(&self, id: UntypedAssetId, mut loaded_asset: ErasedLoadedAsset) {
+        let mut labels: Vec<_> = loaded_asset
+            .labeled_assets
+            .iter()
+            .map(|(label, labeled_asset)| LabeledAssetInfo {
+                label: label.clone(),
+                id: labeled_asset.handle.id(),
+                type_name: labeled_asset.asset.asset_type_name(),
+            })
+            .collect();
+        sort_labels(&mut labels);
+        if let Some(info) = self.data.infos.write().get_mut(id) {
+            info.labels = labels;
+        }
+
         for (_, labeled_asset) in loaded_asset.labeled_assets.drain() {
             self.send_loaded_asset(labeled_asset.handle.id(), labeled_asset.asset);
         }
@@ -838,9 +1148,17 @@ impl AssetServer {
     /// feature is enabled, [`LoadedFolder`] handles will reload when a file in the folder is
     /// removed, added or moved. This includes files in subdirectories and moving, adding,
     /// or removing complete subdirectories.
+    ///
+    /// The path can also be a glob pattern, to only load the matching files: `*` matches any
+    /// characters in a file or folder name, `?` matches a single character, and `**` matches any
+    /// number of nested folders. For instance `textures/*.png` loads the PNG files of the
+    /// `textures` folder but not of its subfolders, and `textures/**/*.png` loads them all.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
     pub fn load_folder<'a>(&self, path: impl Into<AssetPath<'a>>) -> Handle<LoadedFolder> {
         let path = path.into().into_owned();
+        if FolderGlob::parse(path.path()).is_some() {
+            self.data.folder_globs.write().insert(path.clone());
+        }
         let (handle, should_load) = self
             .data
             .infos
@@ -859,10 +1177,24 @@ impl AssetServer {
         handle
     }
 
+    /// Loads the assets of type `A` in the folder at `path`, which can be a glob pattern, see
+    /// [`AssetServer::load_folder`].
+    ///
+    /// The returned handle gives access to the typed handles of the assets, and to the progress of
+    /// their loading.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_folder_typed<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+    ) -> TypedFolderHandle<A> {
+        TypedFolderHandle::new(self.load_folder(path))
+    }
+
     pub(crate) fn load_folder_internal(&self, id: UntypedAssetId, path: AssetPath) {
         async fn load_folder<'a>(
             source: AssetSourceId<'static>,
             path: &'a Path,
+            glob: Option<&'a FolderGlob>,
             reader: &'a dyn ErasedAssetReader,
             server: &'a AssetServer,
             handles: &'a mut Vec<UntypedHandle>,
@@ -872,14 +1204,20 @@ impl AssetServer {
                 let mut path_stream = reader.read_directory(path.as_ref()).await?;
                 while let Some(child_path) = path_stream.next().await {
                     if reader.is_directory(&child_path).await? {
+                        if glob.is_some_and(|glob| !glob.may_contain_matches(&child_path)) {
+                            continue;
+                        }
                         Box::pin(load_folder(
                             source.clone(),
                             &child_path,
+                            glob,
                             reader,
                             server,
                             handles,
                         ))
                         .await?;
+                    } else if glob.is_some_and(|glob| !glob.is_match(&child_path)) {
+                        continue;
                     } else {
                         let path = child_path.to_str().expect("Path should be a valid string.");
                         let asset_path = AssetPath::parse(path).with_source(source.clone());
@@ -924,8 +1262,10 @@ impl AssetServer {
                     },
                 };
 
+                let glob = FolderGlob::parse(path.path());
+                let folder = glob.as_ref().map_or_else(|| path.path().to_path_buf(), FolderGlob::root);
                 let mut handles = Vec::new();
-                match load_folder(source.id(), path.path(), asset_reader, &server, &mut handles).await {
+                match load_folder(source.id(), &folder, glob.as_ref(), asset_reader, &server, &mut handles).await {
                     Ok(_) => server.send_asset_event(InternalAssetEvent::Loaded {
                         id,
                         loaded_asset: LoadedAsset::new_with_dependencies(
@@ -973,6 +1313,24 @@ impl AssetServer {
             .map(|i| i.load_state.clone())
     }
 
+    /// Retrieves the [`AssetLoadProgress`] of a given asset `id`: the bytes read so far and the
+    /// stage of the load. Returns `None` if the asset isn't being loaded.
+    ///
+    /// The progress of a labeled asset is the progress of the asset it is loaded from.
+    pub fn load_progress(&self, id: impl Into<UntypedAssetId>) -> Option<AssetLoadProgress> {
+        let path = self.get_path(id)?;
+        let load_progress = self.data.load_progress.lock();
+        let progress = *load_progress.get(&path.without_label())?.lock();
+        Some(progress)
+    }
+
+    /// Sets the fraction of the decoding done for the asset loading from the given `path`.
+    pub(crate) fn report_decode_progress(&self, path: &AssetPath, decoded: f32) {
+        if let Some(progress) = self.data.load_progress.lock().get(path) {
+            progress.lock().decoded = Some(decoded.clamp(0.0, 1.0));
+        }
+    }
+
     /// Retrieves the [`DependencyLoadState`] of a given asset `id`'s dependencies.
     ///
     /// Note that this is only the load state of direct dependencies of the root asset. To get
@@ -1060,6 +1418,44 @@ impl AssetServer {
         )
     }
 
+    /// Returns the direct dependencies of the asset `id`: the assets whose handles it held when it
+    /// was last loaded.
+    ///
+    /// The dependencies are known once the asset is loaded, so this is empty before then, and for
+    /// assets that aren't managed by this [`AssetServer`].
+    pub fn dependencies_of(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        self.data.infos.read().dependencies(id.into()).collect()
+    }
+
+    /// Returns the loaded assets that directly depend on the asset `id`.
+    ///
+    /// See [`AssetServer::dependencies_of`].
+    pub fn dependents_of(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        self.data.infos.read().dependents(id.into()).collect()
+    }
+
+    /// Returns the asset `id` and all its recursive dependencies, in topological order: each asset
+    /// comes after all of its dependencies, so `id` comes last.
+    ///
+    /// This is the closure of assets that have to be loaded for `id` to be fully loaded, for
+    /// instance to preload exactly what a level needs. Dependency cycles are broken arbitrarily.
+    pub fn recursive_dependencies_of(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let infos = self.data.infos.read();
+        infos.post_order(id.into(), |id| infos.dependencies(id))
+    }
+
+    /// Returns the asset `id` and all the assets that recursively depend on it, in topological
+    /// order: each asset comes before all of its dependents, so `id` comes first.
+    ///
+    /// These are the assets affected when `id` changes, for instance when it's reloaded.
+    /// Dependency cycles are broken arbitrarily.
+    pub fn recursive_dependents_of(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let infos = self.data.infos.read();
+        let mut order = infos.post_order(id.into(), |id| infos.dependents(id));
+        order.reverse();
+        order
+    }
+
     /// Returns an active handle for the given path, if the asset at the given path has already started loading,
     /// or is still "alive".
     pub fn get_handle<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Option<Handle<A>> {
@@ -1154,6 +1550,41 @@ impl AssetServer {
         Some(info.path.as_ref()?.clone())
     }
 
+    /// Returns the labeled sub-assets of the asset at `path`, sorted by label, or `None` if no asset
+    /// at this path is loaded. The label of `path`, if any, is ignored.
+    ///
+    /// This lists the sub-assets the loader produced the last time the asset was loaded, like the
+    /// animations of a glTF file, so they can be discovered without hardcoding their labels.
+    ///
+    /// ```
+    /// # use bevy_asset::AssetServer;
+    /// # use bevy_ecs::prelude::*;
+    /// fn list_animations(asset_server: Res<AssetServer>) {
+    ///     for labeled in asset_server.labels_for("models/fox.glb").unwrap_or_default() {
+    ///         if labeled.label.starts_with("Animation") {
+    ///             println!("{}: {}", labeled.label, labeled.type_name);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn labels_for<'a>(&self, path: impl Into<AssetPath<'a>>) -> Option<Vec<LabeledAssetInfo>> {
+        let path = path.into();
+        let infos = self.data.infos.read();
+        let mut loaded = false;
+        let mut labels = Vec::new();
+        for id in infos.get_path_ids(&path.without_label()) {
+            let Some(info) = infos.get(id) else {
+                continue;
+            };
+            if info.load_state.is_loaded() {
+                loaded = true;
+                labels.extend(info.labels.iter().cloned());
+            }
+        }
+        sort_labels(&mut labels);
+        loaded.then_some(labels)
+    }
+
     /// Returns the [`AssetServerMode`] this server is currently in.
     pub fn mode(&self) -> AssetServerMode {
         self.data.mode
@@ -1532,7 +1963,21 @@ pub fn handle_internal_asset_events(world: &mut World) {
             }
         }
 
+        let folder_globs = server.data.folder_globs.read();
         let reload_parent_folders = |path: PathBuf, source: &AssetSourceId<'static>| {
+            for glob_path in folder_globs.iter() {
+                let contains_path = glob_path.source() == source
+                    && FolderGlob::parse(glob_path.path())
+                        .is_some_and(|glob| path.starts_with(glob.root()));
+                if !contains_path {
+                    continue;
+                }
+                for folder_handle in infos.get_path_handles(glob_path) {
+                    info!("Reloading folder {glob_path} because the content has changed");
+                    server.load_folder_internal(folder_handle.id(), glob_path.clone());
+                }
+            }
+
             let mut current_folder = path;
             while let Some(parent) = current_folder.parent() {
                 current_folder = parent.to_path_buf();
@@ -1597,6 +2042,9 @@ pub fn handle_internal_asset_events(world: &mut World) {
         infos
             .pending_tasks
             .retain(|_, load_task| !load_task.is_finished());
+
+        drop(infos);
+        server.start_queued_loads();
     });
 }
 
@@ -1616,13 +2064,30 @@ pub(crate) enum InternalAssetEvent {
     },
 }
 
+/// A labeled sub-asset of a loaded asset, see [`AssetServer::labels_for`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabeledAssetInfo {
+    /// The label of the sub-asset.
+    pub label: CowArc<'static, str>,
+    /// The id of the sub-asset. It's only valid while a handle to the sub-asset is alive.
+    pub id: UntypedAssetId,
+    /// The type name of the sub-asset.
+    pub type_name: &'static str,
+}
+
+/// Sorts `labels` by label, so they're listed in a stable order.
+fn sort_labels(labels: &mut [LabeledAssetInfo]) {
+    labels.sort_by_key(|info| info.label.clone());
+}
+
 /// The load state of an asset.
 #[derive(Component, Clone, Debug)]
 pub enum LoadState {
     /// The asset has not started loading yet
     NotLoaded,
 
-    /// The asset is in the process of loading.
+    /// The asset is in the process of loading. See [`AssetServer::load_progress`] for how far along
+    /// it is.
     Loading,
 
     /// The asset has been loaded and has been added to the [`World`]
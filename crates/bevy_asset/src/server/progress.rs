@@ -0,0 +1,162 @@
+use crate::io::{AsyncSeekForward, Reader, StackFuture, STACK_FUTURE_SIZE};
+use alloc::sync::Arc;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::AsyncRead;
+use parking_lot::Mutex;
+
+/// The progress of an asset load, see [`AssetServer::load_progress`](crate::AssetServer::load_progress).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssetLoadProgress {
+    /// The number of bytes of the asset read so far.
+    pub bytes_read: u64,
+    /// The size of the asset in bytes, if the [`AssetReader`](crate::io::AssetReader) knows it,
+    /// see [`Reader::byte_len`].
+    pub total_bytes: Option<u64>,
+    /// The fraction of the decoding done so far, between 0 and 1, if the
+    /// [`AssetLoader`](crate::AssetLoader) reports it with
+    /// [`LoadContext::report_progress`](crate::LoadContext::report_progress).
+    pub decoded: Option<f32>,
+}
+
+/// The stage of an asset load, see [`AssetLoadProgress::stage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetLoadStage {
+    /// The bytes of the asset are being read.
+    Reading,
+    /// The asset is being decoded from its bytes.
+    Decoding,
+}
+
+impl AssetLoadProgress {
+    /// Returns the stage of the load: the asset is decoding once the loader reported decoding
+    /// progress, or once all of its bytes were read.
+    pub fn stage(&self) -> AssetLoadStage {
+        let read_all = self
+            .total_bytes
+            .is_some_and(|total_bytes| self.bytes_read >= total_bytes);
+        if self.decoded.is_some() || read_all {
+            AssetLoadStage::Decoding
+        } else {
+            AssetLoadStage::Reading
+        }
+    }
+
+    /// Returns the fraction of the bytes read so far, between 0 and 1, if the size of the asset is
+    /// known.
+    pub fn read_fraction(&self) -> Option<f32> {
+        self.total_bytes.map(|total_bytes| {
+            if total_bytes == 0 {
+                1.0
+            } else {
+                (self.bytes_read as f64 / total_bytes as f64).min(1.0) as f32
+            }
+        })
+    }
+}
+
+/// The [`AssetLoadProgress`] of a load, shared between its [`ProgressReader`] and the server.
+pub(crate) type SharedLoadProgress = Arc<Mutex<AssetLoadProgress>>;
+
+/// A [`Reader`] counting the bytes read from the reader it wraps in an [`AssetLoadProgress`].
+pub(crate) struct ProgressReader<R> {
+    reader: R,
+    progress: SharedLoadProgress,
+}
+
+impl<R: Reader> ProgressReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        let progress = AssetLoadProgress {
+            bytes_read: 0,
+            total_bytes: reader.byte_len(),
+            decoded: None,
+        };
+        Self {
+            reader,
+            progress: Arc::new(Mutex::new(progress)),
+        }
+    }
+
+    pub(crate) fn progress(&self) -> &SharedLoadProgress {
+        &self.progress
+    }
+}
+
+impl<R: Reader> AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.reader).poll_read(cx, buf);
+        if let Poll::Ready(Ok(read)) = result {
+            this.progress.lock().bytes_read += read as u64;
+        }
+        result
+    }
+}
+
+impl<R: Reader> AsyncSeekForward for ProgressReader<R> {
+    fn poll_seek_forward(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: u64,
+    ) -> Poll<std::io::Result<u64>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.reader).poll_seek_forward(cx, offset);
+        if let Poll::Ready(Ok(position)) = result {
+            this.progress.lock().bytes_read = position;
+        }
+        result
+    }
+}
+
+impl<R: Reader> Reader for ProgressReader<R> {
+    fn read_to_end<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+    ) -> StackFuture<'a, std::io::Result<usize>, STACK_FUTURE_SIZE> {
+        // The wrapped reader may not read through `poll_read`, so count the bytes it returns.
+        StackFuture::from_or_box(async {
+            let read = self.reader.read_to_end(buf).await?;
+            self.progress.lock().bytes_read += read as u64;
+            Ok(read)
+        })
+    }
+
+    fn byte_len(&self) -> Option<u64> {
+        self.reader.byte_len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_tasks::block_on;
+    use futures_lite::AsyncReadExt;
+
+    use crate::io::VecReader;
+
+    use super::*;
+
+    #[test]
+    fn progress_reader() {
+        let mut reader = ProgressReader::new(VecReader::new(vec![7; 100]));
+        let progress = reader.progress().clone();
+        assert_eq!(progress.lock().total_bytes, Some(100));
+        assert_eq!(progress.lock().stage(), AssetLoadStage::Reading);
+
+        let mut buf = [0; 40];
+        block_on(reader.read_exact(&mut buf)).unwrap();
+        assert_eq!(progress.lock().bytes_read, 40);
+        assert_eq!(progress.lock().read_fraction(), Some(0.4));
+
+        let mut rest = Vec::new();
+        block_on(Reader::read_to_end(&mut reader, &mut rest)).unwrap();
+        assert_eq!(rest.len(), 60);
+        assert_eq!(progress.lock().bytes_read, 100);
+        assert_eq!(progress.lock().stage(), AssetLoadStage::Decoding);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{AssetPath, UntypedAssetId};
+use alloc::{collections::BTreeMap, vec::Vec};
+use bevy_utils::{HashMap, HashSet, Instant};
+use core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use parking_lot::Mutex;
+
+/// The priority of a load started with [`AssetServer::load_with_priority`](crate::AssetServer::load_with_priority).
+///
+/// Loads below [`AssetLoadPriority::Critical`] are queued, and each frame the queued loads with the
+/// highest priority are started first, within the [budget](crate::AssetServer::set_load_budget).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AssetLoadPriority {
+    /// Background loads, like distant terrain being streamed in.
+    Low,
+    /// Loads that should start soon, but may wait for more important ones.
+    #[default]
+    Normal,
+    /// Loads that should start before the others in the queue.
+    High,
+    /// Loads that start right away, bypassing the queue and its budget, like [`AssetServer::load`](crate::AssetServer::load).
+    Critical,
+}
+
+impl AssetLoadPriority {
+    /// The priorities whose loads are queued, from the lowest to the highest.
+    pub const QUEUED: [Self; 3] = [Self::Low, Self::Normal, Self::High];
+}
+
+struct QueuedLoad {
+    priority: AssetLoadPriority,
+    order: u64,
+    path: AssetPath<'static>,
+}
+
+/// The loads waiting to be started, by priority.
+#[derive(Default)]
+pub(crate) struct LoadQueue {
+    loads: HashMap<UntypedAssetId, QueuedLoad>,
+    /// The queued assets of each priority, in the order they were queued.
+    queues: [BTreeMap<u64, UntypedAssetId>; 3],
+    next_order: u64,
+    /// The paths of the loads started from the queue, whose loaders run within the budget.
+    started: HashSet<AssetPath<'static>>,
+}
+
+impl LoadQueue {
+    /// Queues the load of the asset `id` from `path`, or raises the priority of its queued load.
+    pub(crate) fn push(
+        &mut self,
+        priority: AssetLoadPriority,
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+    ) {
+        let Some(index) = Self::index(priority) else {
+            return;
+        };
+        if self
+            .loads
+            .get(&id)
+            .is_some_and(|queued| queued.priority >= priority)
+        {
+            return;
+        }
+        self.remove(id);
+        let order = self.next_order;
+        self.next_order += 1;
+        self.queues[index].insert(order, id);
+        self.loads.insert(
+            id,
+            QueuedLoad {
+                priority,
+                order,
+                path,
+            },
+        );
+    }
+
+    /// Removes the queued load of the asset `id`, returning its path.
+    pub(crate) fn remove(&mut self, id: UntypedAssetId) -> Option<AssetPath<'static>> {
+        let queued = self.loads.remove(&id)?;
+        if let Some(index) = Self::index(queued.priority) {
+            self.queues[index].remove(&queued.order);
+        }
+        Some(queued.path)
+    }
+
+    /// Removes the load that was queued first among the ones with the highest priority, and
+    /// marks it as started.
+    pub(crate) fn pop(&mut self) -> Option<(UntypedAssetId, AssetPath<'static>)> {
+        let (_, id) = self.queues.iter_mut().rev().find_map(BTreeMap::pop_first)?;
+        let path = self.loads.remove(&id)?.path;
+        self.started.insert(path.clone());
+        Some((id, path))
+    }
+
+    /// Returns `true` once for each load of `path` started from the queue.
+    pub(crate) fn take_started(&mut self, path: &AssetPath<'static>) -> bool {
+        self.started.remove(path)
+    }
+
+    pub(crate) fn len(&self, priority: AssetLoadPriority) -> usize {
+        Self::index(priority).map_or(0, |index| self.queues[index].len())
+    }
+
+    fn index(priority: AssetLoadPriority) -> Option<usize> {
+        AssetLoadPriority::QUEUED
+            .iter()
+            .position(|&queued| queued == priority)
+    }
+}
+
+/// How long the loads started from the [`LoadQueue`] may spend in their loaders each frame.
+#[derive(Default)]
+pub(crate) struct LoadBudget {
+    state: Mutex<LoadBudgetState>,
+}
+
+#[derive(Default)]
+struct LoadBudgetState {
+    per_frame: Option<Duration>,
+    remaining: Duration,
+    /// The average time spent in the loader by the finished loads, to estimate how many loads fit
+    /// in a frame.
+    average_load: Option<Duration>,
+    waiting: Vec<Waker>,
+}
+
+impl LoadBudget {
+    pub(crate) fn per_frame(&self) -> Option<Duration> {
+        self.state.lock().per_frame
+    }
+
+    pub(crate) fn set_per_frame(&self, per_frame: Option<Duration>) {
+        let mut state = self.state.lock();
+        state.per_frame = per_frame;
+        state.remaining = per_frame.unwrap_or_default();
+        state.wake_all();
+    }
+
+    /// Starts a new frame, returning how many queued loads may start in it: none if the budget of
+    /// the previous frame was used up, and otherwise as many as are expected to fit in the budget.
+    pub(crate) fn refill(&self) -> usize {
+        let mut state = self.state.lock();
+        let Some(per_frame) = state.per_frame else {
+            return usize::MAX;
+        };
+        let used_up = state.remaining.is_zero();
+        state.remaining = per_frame;
+        state.wake_all();
+        if used_up {
+            return 0;
+        }
+        // Until a load finishes, its cost is unknown, so only one load is started.
+        let average_load = state
+            .average_load
+            .unwrap_or(per_frame)
+            .max(Duration::from_micros(1));
+        let count = per_frame.as_nanos() / average_load.as_nanos();
+        usize::try_from(count).unwrap_or(usize::MAX).max(1)
+    }
+
+    /// Returns [`Poll::Ready`] if the budget of this frame isn't used up, and otherwise waits for
+    /// the next frame.
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock();
+        if state.per_frame.is_some() && state.remaining.is_zero() {
+            state.waiting.push(cx.waker().clone());
+            return Poll::Pending;
+        }
+        Poll::Ready(())
+    }
+
+    /// Takes `elapsed` from the budget of this frame.
+    fn spend(&self, elapsed: Duration) {
+        let mut state = self.state.lock();
+        state.remaining = state.remaining.saturating_sub(elapsed);
+    }
+
+    /// Records the time spent by a finished load.
+    fn finish(&self, spent: Duration) {
+        let mut state = self.state.lock();
+        state.average_load = Some(match state.average_load {
+            Some(average) => (average * 3 + spent) / 4,
+            None => spent,
+        });
+    }
+}
+
+impl LoadBudgetState {
+    fn wake_all(&mut self) {
+        for waker in self.waiting.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// Runs `load` within the `budget`: the time spent polling it, which is the work of its loader,
+/// is taken from the budget of the frame, and it waits for the next frame once that is used up.
+pub(crate) async fn budgeted_load<F: Future>(load: F, budget: &LoadBudget) -> F::Output {
+    let mut load = pin!(load);
+    let mut spent = Duration::ZERO;
+    let output = poll_fn(|cx| {
+        if budget.poll_ready(cx).is_pending() {
+            return Poll::Pending;
+        }
+        let start = Instant::now();
+        let poll = load.as_mut().poll(cx);
+        let elapsed = start.elapsed();
+        budget.spend(elapsed);
+        spent += elapsed;
+        poll
+    })
+    .await;
+    budget.finish(spent);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_tasks::block_on;
+    use futures_lite::future::poll_once;
+    use uuid::Uuid;
+
+    use crate::{AssetId, LoadedUntypedAsset};
+
+    use super::*;
+
+    #[test]
+    fn queue_by_priority() {
+        let ids: Vec<UntypedAssetId> = (1..=3)
+            .map(|index| {
+                AssetId::<LoadedUntypedAsset>::Uuid {
+                    uuid: Uuid::from_u128(index),
+                }
+                .untyped()
+            })
+            .collect();
+        let mut queue = LoadQueue::default();
+        queue.push(AssetLoadPriority::Low, ids[0], "a".into());
+        queue.push(AssetLoadPriority::Normal, ids[1], "b".into());
+        queue.push(AssetLoadPriority::Low, ids[2], "c".into());
+        // Raising the priority moves the load up, lowering it doesn't.
+        queue.push(AssetLoadPriority::High, ids[2], "c".into());
+        queue.push(AssetLoadPriority::Low, ids[1], "b".into());
+        assert_eq!(queue.len(AssetLoadPriority::Low), 1);
+
+        assert_eq!(queue.pop().map(|(id, _)| id), Some(ids[2]));
+        assert!(queue.take_started(&"c".into()));
+        assert!(!queue.take_started(&"c".into()));
+        assert_eq!(queue.remove(ids[1]), Some("b".into()));
+        assert_eq!(queue.pop().map(|(id, _)| id), Some(ids[0]));
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn load_budget() {
+        let budget = LoadBudget::default();
+        assert_eq!(budget.refill(), usize::MAX);
+
+        budget.set_per_frame(Some(Duration::from_millis(8)));
+        // A single load starts until the cost of a load is known.
+        assert_eq!(budget.refill(), 1);
+        budget.finish(Duration::from_millis(2));
+        assert_eq!(budget.refill(), 4);
+
+        // Loads wait for the next frame once the budget is used up, and none are started.
+        budget.spend(Duration::from_millis(10));
+        let mut load = pin!(budgeted_load(async { 7 }, &budget));
+        assert!(block_on(poll_once(load.as_mut())).is_none());
+        assert_eq!(budget.refill(), 0);
+        assert_eq!(block_on(load), 7);
+    }
+}
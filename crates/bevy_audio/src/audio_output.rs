@@ -2,6 +2,8 @@ use crate::{
     AudioPlayer, Decodable, DefaultSpatialScale, GlobalVolume, PlaybackMode, PlaybackSettings,
     SpatialAudioSink, SpatialListener,
 };
+use alloc::borrow::Cow;
+use bevy_app::Capability;
 use bevy_asset::{Asset, Assets};
 use bevy_ecs::{prelude::*, system::SystemParam};
 use bevy_hierarchy::DespawnRecursiveExt;
@@ -46,6 +48,27 @@ impl Default for AudioOutput {
     }
 }
 
+/// The [`Capability`] of playing audio, missing when the platform has no default audio output
+/// device.
+///
+/// This can be [required](bevy_app::PluginGroupBuilder::require) by the [`AudioPlugin`](crate::AudioPlugin)
+/// to leave it out on machines without audio, like servers and CI runners.
+pub struct AudioOutputCapability;
+
+impl Capability for AudioOutputCapability {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("audio_output")
+    }
+
+    fn detect(&self) -> bool {
+        use rodio::cpal::traits::HostTrait;
+
+        rodio::cpal::default_host()
+            .default_output_device()
+            .is_some()
+    }
+}
+
 /// Marker for internal use, to despawn entities when playback finishes.
 #[derive(Component, Default)]
 pub struct PlaybackDespawnMarker;
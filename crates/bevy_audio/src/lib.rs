@@ -53,6 +53,7 @@ pub mod prelude {
 }
 
 pub use audio::*;
+pub use audio_output::AudioOutputCapability;
 pub use audio_source::*;
 pub use pitch::*;
 pub use volume::*;
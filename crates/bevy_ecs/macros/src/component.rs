@@ -9,7 +9,8 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Paren},
-    DeriveInput, ExprClosure, ExprPath, Ident, LitStr, Path, Result,
+    Data, DataStruct, DeriveInput, ExprClosure, ExprPath, Fields, Ident, LitStr, Member, Path,
+    Result, Type,
 };
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
@@ -59,11 +60,25 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
 
-    let attrs = match parse_component_attr(&ast) {
+    let mut attrs = match parse_component_attr(&ast) {
         Ok(attrs) => attrs,
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &mut attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &mut attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let map_entities = match map_entities(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
@@ -140,6 +155,10 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     // This puts `register_required` before `register_recursive_requires` to ensure that the constructors of _all_ top
     // level components are initialized first, giving them precedence over recursively defined constructors for the same component type
     TokenStream::from(quote! {
+        #relationship
+
+        #relationship_target
+
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
             type Mutability = #mutable_type;
@@ -172,6 +191,8 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
                     .get_component_clone_handler()
             }
+
+            #map_entities
         }
     })
 }
@@ -215,6 +236,11 @@ pub const ON_REMOVE: &str = "on_remove";
 
 pub const IMMUTABLE: &str = "immutable";
 
+pub const ENTITIES: &str = "entities";
+
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
+
 struct Attrs {
     storage: StorageTy,
     requires: Option<Punctuated<Require, Comma>>,
@@ -223,6 +249,8 @@ struct Attrs {
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
     immutable: bool,
+    relationship: Option<Type>,
+    relationship_target: Option<Type>,
 }
 
 #[derive(Clone, Copy)]
@@ -254,6 +282,8 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         on_remove: None,
         requires: None,
         immutable: false,
+        relationship: None,
+        relationship_target: None,
     };
 
     let mut require_paths = HashSet::new();
@@ -306,6 +336,24 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
             } else {
                 attrs.requires = Some(punctuated);
             }
+        } else if attr.path().is_ident(RELATIONSHIP) {
+            attr.parse_nested_meta(|nested| {
+                if nested.path.is_ident(RELATIONSHIP_TARGET) {
+                    attrs.relationship = Some(nested.value()?.parse::<Type>()?);
+                    Ok(())
+                } else {
+                    Err(nested.error("Unsupported attribute"))
+                }
+            })?;
+        } else if attr.path().is_ident(RELATIONSHIP_TARGET) {
+            attr.parse_nested_meta(|nested| {
+                if nested.path.is_ident(RELATIONSHIP) {
+                    attrs.relationship_target = Some(nested.value()?.parse::<Type>()?);
+                    Ok(())
+                } else {
+                    Err(nested.error("Unsupported attribute"))
+                }
+            })?;
         }
     }
 
@@ -346,3 +394,180 @@ fn hook_register_function_call(
 ) -> Option<TokenStream2> {
     function.map(|meta| quote! { hooks. #hook (#meta); })
 }
+
+/// Generates `Component::map_entities` for the fields marked with `#[entities]`, and the field of
+/// a relationship component.
+fn map_entities(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let mut members = Vec::new();
+    match &ast.data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            for (index, field) in fields.iter().enumerate() {
+                if field
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path().is_ident(ENTITIES))
+                {
+                    members.push(match &field.ident {
+                        Some(ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(index.into()),
+                    });
+                }
+            }
+        }
+        Data::Enum(data) => {
+            for field in data
+                .variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter())
+            {
+                if let Some(attr) = field
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path().is_ident(ENTITIES))
+                {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "`#[entities]` is only supported on struct fields",
+                    ));
+                }
+            }
+        }
+        Data::Union(_) => {}
+    }
+    if attrs.relationship.is_some() {
+        let field = relationship_field(ast, RELATIONSHIP)?;
+        if !members.contains(&field) {
+            members.push(field);
+        }
+    }
+    if members.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(quote! {
+        fn map_entities<M: #bevy_ecs_path::entity::EntityMapper>(this: &mut Self, mapper: &mut M) {
+            #(#bevy_ecs_path::entity::MapEntities::map_entities(&mut this.#members, mapper);)*
+        }
+    }))
+}
+
+/// Returns the member of the single field of a relationship component.
+fn relationship_field(ast: &DeriveInput, attribute: &str) -> Result<Member> {
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("`{attribute}` can only be derived for structs"),
+        ));
+    };
+    match fields {
+        Fields::Named(fields) if fields.named.len() == 1 => {
+            Ok(Member::Named(fields.named[0].ident.clone().unwrap()))
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(Member::Unnamed(0.into())),
+        _ => Err(syn::Error::new(
+            fields.span(),
+            format!("`{attribute}` components must have exactly one field"),
+        )),
+    }
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &mut Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = attrs.relationship.as_ref() else {
+        return Ok(None);
+    };
+    if attrs.on_insert.is_some() || attrs.on_replace.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Custom on_insert and on_replace hooks are not supported as relationships already define them",
+        ));
+    }
+    let field = relationship_field(ast, RELATIONSHIP)?;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let relationship = quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#field
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self { #field: entity }
+            }
+        }
+    };
+
+    attrs.immutable = true;
+    attrs.on_insert = Some(parse_quote! {
+        <Self as #bevy_ecs_path::relationship::Relationship>::on_insert
+    });
+    attrs.on_replace = Some(parse_quote! {
+        <Self as #bevy_ecs_path::relationship::Relationship>::on_replace
+    });
+
+    Ok(Some(relationship))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &mut Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship) = attrs.relationship_target.as_ref() else {
+        return Ok(None);
+    };
+    if attrs.on_replace.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Custom on_replace hooks are not supported as relationship targets already define them",
+        ));
+    }
+    if attrs.immutable {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Relationship targets must be mutable",
+        ));
+    }
+    let field = relationship_field(ast, RELATIONSHIP_TARGET)?;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let relationship_target = quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+
+            #[inline]
+            fn collection(&self) -> &#bevy_ecs_path::__macro_exports::Vec<#bevy_ecs_path::entity::Entity> {
+                &self.#field
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut #bevy_ecs_path::__macro_exports::Vec<#bevy_ecs_path::entity::Entity> {
+                &mut self.#field
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: #bevy_ecs_path::__macro_exports::Vec<#bevy_ecs_path::entity::Entity>) -> Self {
+                Self { #field: collection }
+            }
+        }
+    };
+
+    attrs.on_replace = Some(parse_quote! {
+        <Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace
+    });
+
+    Ok(Some(relationship_target))
+}
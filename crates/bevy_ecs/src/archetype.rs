@@ -20,11 +20,14 @@
 //! [`World::archetypes`]: crate::world::World::archetypes
 
 use crate::{
+    self as bevy_ecs,
     bundle::BundleId,
     component::{ComponentId, Components, RequiredComponentConstructor, StorageType},
     entity::{Entity, EntityLocation},
+    event::Event,
     observer::Observers,
     storage::{ImmutableSparseSet, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow},
+    world::World,
 };
 use alloc::{boxed::Box, vec::Vec};
 use bevy_utils::HashMap;
@@ -110,6 +113,87 @@ impl ArchetypeId {
     }
 }
 
+/// The kind of batch operation reported by an [`ArchetypeBatchEvent`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ArchetypeBatchKind {
+    /// Entities were spawned into the archetype, e.g. by [`World::spawn_batch`].
+    ///
+    /// [`World::spawn_batch`]: crate::world::World::spawn_batch
+    Spawned,
+    /// Entities were moved into (or stayed in) the archetype by inserting a bundle,
+    /// e.g. by [`World::insert_batch`].
+    ///
+    /// [`World::insert_batch`]: crate::world::World::insert_batch
+    Inserted,
+    /// Entities were despawned from the archetype, e.g. by a recursive despawn.
+    Despawned,
+}
+
+/// An [`Event`] triggered once per affected archetype when a batch operation touches many
+/// entities at once.
+///
+/// This lets consumers that mirror the world elsewhere (render extraction, physics,
+/// networking) handle bulk changes with bulk operations instead of reacting to every entity.
+/// Per-entity hooks and observers still run as usual; this event is triggered after them.
+///
+/// ```
+/// # use bevy_ecs::{archetype::{ArchetypeBatchEvent, ArchetypeBatchKind}, prelude::*};
+/// # #[derive(Component)]
+/// # struct A;
+/// #[derive(Resource, Default)]
+/// struct Spawned(usize);
+///
+/// let mut world = World::new();
+/// world.init_resource::<Spawned>();
+/// world.add_observer(|trigger: Trigger<ArchetypeBatchEvent>, mut spawned: ResMut<Spawned>| {
+///     if trigger.kind == ArchetypeBatchKind::Spawned {
+///         spawned.0 += trigger.count;
+///     }
+/// });
+///
+/// world.spawn_batch((0..100).map(|_| A));
+/// world.flush();
+/// assert_eq!(world.resource::<Spawned>().0, 100);
+/// ```
+///
+/// [`Event`]: crate::event::Event
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ArchetypeBatchEvent {
+    /// What the batch did to the entities.
+    pub kind: ArchetypeBatchKind,
+    /// The archetype the entities were spawned into, inserted into, or despawned from.
+    pub archetype: ArchetypeId,
+    /// The number of entities in `archetype` touched by the batch.
+    pub count: usize,
+}
+
+/// Accumulates per-archetype entity counts during a batch operation, so that one
+/// [`ArchetypeBatchEvent`] can be triggered for each archetype once the batch is done.
+#[derive(Debug, Default, Clone)]
+pub struct ArchetypeBatchCounts(Vec<(ArchetypeId, usize)>);
+
+impl ArchetypeBatchCounts {
+    /// Records one more entity touched in `archetype`.
+    #[inline]
+    pub fn add(&mut self, archetype: ArchetypeId) {
+        match self.0.iter_mut().rev().find(|(id, _)| *id == archetype) {
+            Some((_, count)) => *count += 1,
+            None => self.0.push((archetype, 1)),
+        }
+    }
+
+    /// Triggers an [`ArchetypeBatchEvent`] of the given `kind` for every recorded archetype.
+    pub fn trigger(self, world: &mut World, kind: ArchetypeBatchKind) {
+        for (archetype, count) in self.0 {
+            world.trigger(ArchetypeBatchEvent {
+                kind,
+                archetype,
+                count,
+            });
+        }
+    }
+}
+
 /// Used in [`ArchetypeAfterBundleInsert`] to track whether components in the bundle are newly
 /// added or already existed in the entity's archetype.
 #[derive(Copy, Clone, Eq, PartialEq)]
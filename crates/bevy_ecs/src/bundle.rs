@@ -6,8 +6,8 @@ pub use bevy_ecs_macros::Bundle;
 
 use crate::{
     archetype::{
-        Archetype, ArchetypeAfterBundleInsert, ArchetypeId, Archetypes, BundleComponentStatus,
-        ComponentStatus, SpawnBundleStatus,
+        Archetype, ArchetypeAfterBundleInsert, ArchetypeBatchEvent, ArchetypeBatchKind,
+        ArchetypeId, Archetypes, BundleComponentStatus, ComponentStatus, SpawnBundleStatus,
     },
     component::{
         Component, ComponentId, Components, RequiredComponentConstructor, RequiredComponents,
@@ -308,6 +308,32 @@ all_tuples!(
     B
 );
 
+/// A boxed [`Bundle`] whose type has been erased, so that bundles of different types can be
+/// inserted together with a single archetype move.
+pub(crate) trait ErasedBundle: Send + Sync + 'static {
+    /// Registers the bundle and pushes the ids of its components to `ids`, in the order they are
+    /// passed to `func` by [`ErasedBundle::get_components`].
+    fn register_component_ids(&self, world: &mut World, ids: &mut Vec<ComponentId>);
+
+    /// Moves the components out of the bundle, see [`DynamicBundle::get_components`].
+    fn get_components(self: Box<Self>, func: &mut dyn FnMut(StorageType, OwningPtr<'_>));
+}
+
+impl<B: Bundle> ErasedBundle for B {
+    fn register_component_ids(&self, world: &mut World, ids: &mut Vec<ComponentId>) {
+        let bundle_id = world
+            .bundles
+            .register_info::<B>(&mut world.components, &mut world.storages);
+        // SAFETY: We just ensured this bundle exists
+        let bundle_info = unsafe { world.bundles.get_unchecked(bundle_id) };
+        ids.extend_from_slice(bundle_info.explicit_components());
+    }
+
+    fn get_components(self: Box<Self>, func: &mut dyn FnMut(StorageType, OwningPtr<'_>)) {
+        DynamicBundle::get_components(*self, &mut |storage_type, ptr| func(storage_type, ptr));
+    }
+}
+
 /// For a specific [`World`], this stores a unique value identifying a type of a registered [`Bundle`].
 ///
 /// [`World`]: crate::world::World
@@ -1430,6 +1456,22 @@ impl<'w> BundleSpawner<'w> {
         // SAFETY: pointers on self can be invalidated,
         self.world.world_mut().flush();
     }
+
+    /// Triggers an [`ArchetypeBatchEvent`] for `count` entities spawned by this spawner.
+    ///
+    /// # Safety
+    /// - `Self` must be dropped after running this function as it may invalidate internal pointers.
+    #[inline]
+    pub(crate) unsafe fn trigger_batch_spawned(&mut self, count: usize) {
+        // SAFETY: nothing has been flushed yet, so the archetype pointer is still valid
+        let archetype = unsafe { self.archetype.as_ref() }.id();
+        // SAFETY: pointers on self can be invalidated,
+        self.world.world_mut().trigger(ArchetypeBatchEvent {
+            kind: ArchetypeBatchKind::Spawned,
+            archetype,
+            count,
+        });
+    }
 }
 
 /// Metadata for bundles. Stores a [`BundleInfo`] for each type of [`Bundle`] in a given world.
@@ -1641,7 +1683,7 @@ fn sorted_remove<T: Eq + Ord + Copy>(source: &mut Vec<T>, remove: &[T]) {
 mod tests {
     use crate as bevy_ecs;
     use crate::{component::ComponentId, prelude::*, world::DeferredWorld};
-    use alloc::vec;
+    use alloc::{vec, vec::Vec};
 
     #[derive(Component)]
     struct A;
@@ -1755,6 +1797,62 @@ mod tests {
         assert_eq!(2, world.resource::<R>().0);
     }
 
+    #[test]
+    fn component_value_hooks() {
+        #[derive(Component)]
+        #[component(immutable)]
+        struct Buffer(&'static str);
+
+        #[derive(Resource, Default)]
+        struct Dropped(Vec<(&'static str, &'static str)>);
+
+        let mut world = World::new();
+        world.init_resource::<Dropped>();
+        world
+            .register_component_hooks::<Buffer>()
+            .on_replace(|mut world, _, _| {
+                world.resource_mut::<Dropped>().0.push(("replace hook", ""));
+            })
+            .on_replace_value::<Buffer>(|buffer, mut world, _, _| {
+                world
+                    .resource_mut::<Dropped>()
+                    .0
+                    .push(("replace", buffer.0));
+            })
+            .on_remove_value::<Buffer>(|buffer, mut world, _, _| {
+                world.resource_mut::<Dropped>().0.push(("remove", buffer.0));
+            });
+
+        let entity = world.spawn(Buffer("a")).id();
+        world.entity_mut(entity).insert(Buffer("b"));
+        world.entity_mut(entity).remove::<Buffer>();
+        assert_eq!(
+            world.resource::<Dropped>().0,
+            [
+                ("replace hook", ""),
+                ("replace", "a"),
+                ("replace hook", ""),
+                ("replace", "b"),
+                ("remove", "b")
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "A value hook for `Buffer` was registered on the component")]
+    fn component_value_hook_type_mismatch() {
+        #[derive(Component)]
+        #[component(immutable)]
+        struct Buffer;
+
+        let mut world = World::new();
+        world
+            .register_component_hooks::<A>()
+            .on_remove_value::<Buffer>(|_, _, _, _| {});
+        let entity = world.spawn(A).id();
+        world.despawn(entity);
+    }
+
     #[test]
     fn component_hook_order_recursive() {
         let mut world = World::new();
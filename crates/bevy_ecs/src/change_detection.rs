@@ -1,7 +1,9 @@
 //! Types that detect when their internal data mutate.
 
 use crate::{
+    self as bevy_ecs,
     component::{Tick, TickCells},
+    event::Event,
     ptr::PtrMut,
     system::Resource,
 };
@@ -32,6 +34,28 @@ pub const CHECK_TICK_THRESHOLD: u32 = 518_400_000;
 /// Changes stop being detected once they become this old.
 pub const MAX_CHANGE_AGE: u32 = u32::MAX - (2 * CHECK_TICK_THRESHOLD - 1);
 
+/// Triggered by [`World::check_change_ticks`](crate::world::World::check_change_ticks) when it
+/// clamps component or resource change ticks that got older than [`MAX_CHANGE_AGE`].
+///
+/// Changes that old are no longer detected, so this usually means that some values were not
+/// changed for a very long time, which is expected on long-running servers, or that a system
+/// has not run for that long.
+///
+/// ```
+/// # use bevy_ecs::{change_detection::ChangeTicksClamped, prelude::*};
+/// let mut world = World::new();
+/// world.add_observer(|trigger: Trigger<ChangeTicksClamped>| {
+///     println!("Clamped {} change ticks", trigger.count);
+/// });
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeTicksClamped {
+    /// The world change tick at which the ticks were checked.
+    pub change_tick: Tick,
+    /// The number of added and changed ticks that were clamped.
+    pub count: usize,
+}
+
 /// Types that can read change detection information.
 /// This change detection is controlled by [`DetectChangesMut`] types such as [`ResMut`].
 ///
@@ -1253,12 +1277,15 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         change_detection::{
-            Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
+            ChangeTicksClamped, Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD,
+            MAX_CHANGE_AGE,
         },
         component::{Component, ComponentTicks, Tick},
+        observer::Trigger,
         system::{IntoSystem, Single, System},
         world::World,
     };
+    use alloc::vec::Vec;
 
     use super::{DetectChanges, DetectChangesMut, MutUntyped};
 
@@ -1366,6 +1393,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn change_tick_check_period() {
+        #[derive(Resource, Default)]
+        struct Clamped(Vec<ChangeTicksClamped>);
+
+        let mut world = World::new();
+        world.init_resource::<Clamped>();
+        world.add_observer(
+            |trigger: Trigger<ChangeTicksClamped>, mut clamped: ResMut<Clamped>| {
+                clamped.0.push(*trigger.event());
+            },
+        );
+        world.set_change_tick_check_period(10);
+        let entity = world.spawn(C).id();
+
+        // Too soon after the last check.
+        *world.change_tick.get_mut() += 5;
+        world.check_change_ticks();
+        assert_eq!(world.last_check_tick(), Tick::new(0));
+
+        // Nothing is old enough to be clamped.
+        *world.change_tick.get_mut() += 5;
+        world.check_change_ticks();
+        assert_eq!(world.last_check_tick(), world.change_tick());
+        assert!(world.resource::<Clamped>().0.is_empty());
+
+        *world.change_tick.get_mut() += MAX_CHANGE_AGE + 10;
+        let change_tick = world.change_tick();
+        world.check_change_ticks();
+        let clamped = &world.resource::<Clamped>().0;
+        assert_eq!(clamped.len(), 1);
+        assert_eq!(clamped[0].change_tick, change_tick);
+        // At least the added and changed ticks of `C` and of the `Clamped` resource.
+        assert!(clamped[0].count >= 4);
+        let ticks = world.entity(entity).get_change_ticks::<C>().unwrap();
+        assert_eq!(ticks.changed.ticks_since(change_tick), MAX_CHANGE_AGE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn change_tick_check_period_too_large() {
+        World::new().set_change_tick_check_period(CHECK_TICK_THRESHOLD + 1);
+    }
+
     #[test]
     fn mut_from_res_mut() {
         let mut component_ticks = ComponentTicks {
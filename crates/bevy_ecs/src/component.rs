@@ -5,7 +5,7 @@ use crate::{
     archetype::ArchetypeFlags,
     bundle::BundleInfo,
     change_detection::MAX_CHANGE_AGE,
-    entity::{ComponentCloneCtx, Entity},
+    entity::{ComponentCloneCtx, Entity, EntityMapper},
     query::DebugCheckedUnwrap,
     storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
     system::{Local, Resource, SystemParam},
@@ -27,7 +27,7 @@ use core::{
     cell::UnsafeCell,
     fmt::Debug,
     marker::PhantomData,
-    mem::needs_drop,
+    mem::{self, needs_drop},
 };
 use disqualified::ShortName;
 use thiserror::Error;
@@ -329,6 +329,35 @@ pub use bevy_ecs_macros::require;
 /// }
 /// ```
 ///
+/// # Relationships
+///
+/// Components pointing at other entities can be declared as relationships using
+/// `#[relationship(relationship_target = T)]`, with `#[relationship_target(relationship = R)]`
+/// on the other side. The derive then registers the hooks that keep both sides in sync.
+/// See the [`relationship`](crate::relationship) module for more information.
+///
+/// # Entity references
+///
+/// [`Entity`] ids are only valid in the world they come from, so they need to be remapped when a
+/// component is spawned from a scene, or when the entity holding it is cloned. Fields marked with
+/// `#[entities]` are remapped automatically through [`Component::map_entities`]. The field type
+/// must implement [`MapEntities`](crate::entity::MapEntities), which is the case for [`Entity`]
+/// and for collections of entities like `Vec<Entity>` or `Option<Entity>`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Inventory {
+///     #[entities]
+///     items: Vec<Entity>,
+///     #[entities]
+///     equipped: Option<Entity>,
+///     gold: u32,
+/// }
+/// ```
+///
+/// The field of a relationship component is always remapped.
+///
 /// # Implementing the trait for foreign types
 ///
 /// As a consequence of the [orphan rule], it is not possible to separate into two different crates the implementation of `Component` from the definition of a type.
@@ -423,6 +452,14 @@ pub trait Component: Send + Sync + 'static {
     fn get_component_clone_handler() -> ComponentCloneHandler {
         ComponentCloneHandler::default_handler()
     }
+
+    /// Maps the [`Entity`] references stored in this component, using the given `mapper`.
+    ///
+    /// This is generated by the derive for the fields marked with `#[entities]`, see
+    /// [Entity references](Component#entity-references). It is used when spawning scenes through
+    /// [`ReflectComponent`](crate::reflect::ReflectComponent), and when cloning entities, where
+    /// references to the source entity are mapped to the clone.
+    fn map_entities<M: EntityMapper>(_this: &mut Self, _mapper: &mut M) {}
 }
 
 mod private {
@@ -504,6 +541,32 @@ pub enum StorageType {
 /// The type used for [`Component`] lifecycle hooks such as `on_add`, `on_insert` or `on_remove`
 pub type ComponentHook = for<'w> fn(DeferredWorld<'w>, Entity, ComponentId);
 
+/// The type used for [`Component`] lifecycle hooks that receive the value that is about to be
+/// replaced or removed, registered with [`ComponentHooks::on_replace_value`] or
+/// [`ComponentHooks::on_remove_value`].
+pub type ComponentValueHook<T> = for<'w> fn(&'w T, DeferredWorld<'w>, Entity, ComponentId);
+
+/// A [`ComponentValueHook`] with its value type erased, along with the function that runs it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ErasedValueHook {
+    run: for<'w> fn(fn(), DeferredWorld<'w>, Entity, ComponentId),
+    hook: fn(),
+}
+
+impl ErasedValueHook {
+    fn new<T: Component<Mutability = Immutable>>(hook: ComponentValueHook<T>) -> Self {
+        Self {
+            run: run_value_hook::<T>,
+            // SAFETY: `run_value_hook::<T>` transmutes this back into a `ComponentValueHook<T>`.
+            hook: unsafe { mem::transmute::<ComponentValueHook<T>, fn()>(hook) },
+        }
+    }
+
+    pub(crate) fn run(self, world: DeferredWorld, entity: Entity, component_id: ComponentId) {
+        (self.run)(self.hook, world, entity, component_id);
+    }
+}
+
 /// [`World`]-mutating functions that run as part of lifecycle events of a [`Component`].
 ///
 /// Hooks are functions that run when a component is added, overwritten, or removed from an entity.
@@ -563,6 +626,8 @@ pub struct ComponentHooks {
     pub(crate) on_insert: Option<ComponentHook>,
     pub(crate) on_replace: Option<ComponentHook>,
     pub(crate) on_remove: Option<ComponentHook>,
+    pub(crate) on_replace_value: Option<ErasedValueHook>,
+    pub(crate) on_remove_value: Option<ErasedValueHook>,
 }
 
 impl ComponentHooks {
@@ -629,6 +694,80 @@ impl ComponentHooks {
             .expect("Component already has an on_remove hook")
     }
 
+    /// Register a [`ComponentValueHook`] that will be run with the value of this component just
+    /// before it is dropped, when it is replaced (with `.insert`) or removed.
+    ///
+    /// This runs after the `on_replace` hook, if any, and is useful to release resources owned by
+    /// the value, such as GPU buffers, file handles or audio voices, without keeping a copy of
+    /// them in another component.
+    ///
+    /// Only [immutable](Immutable) components can have value hooks: the value is borrowed while
+    /// the hook has access to the [`DeferredWorld`], which could otherwise hand out a mutable
+    /// reference to the same value. Components owning such a resource can be made immutable with
+    /// `#[component(immutable)]`, and updated by inserting a new value, which releases the old
+    /// one through this hook.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct FreedBuffers(Vec<u32>);
+    ///
+    /// #[derive(Component)]
+    /// #[component(immutable)]
+    /// struct GpuBuffer(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<FreedBuffers>();
+    /// world
+    ///     .register_component_hooks::<GpuBuffer>()
+    ///     .on_replace_value::<GpuBuffer>(|buffer, mut world, _entity, _id| {
+    ///         world.resource_mut::<FreedBuffers>().0.push(buffer.0);
+    ///     });
+    ///
+    /// let entity = world.spawn(GpuBuffer(1)).id();
+    /// world.entity_mut(entity).insert(GpuBuffer(2));
+    /// world.despawn(entity);
+    /// assert_eq!(world.resource::<FreedBuffers>().0, [1, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the component already has an `on_replace` value hook.
+    /// The hook will panic when run if `T` is not the type of this component.
+    pub fn on_replace_value<T: Component<Mutability = Immutable>>(
+        &mut self,
+        hook: ComponentValueHook<T>,
+    ) -> &mut Self {
+        assert!(
+            self.on_replace_value.is_none(),
+            "Component already has an on_replace value hook"
+        );
+        self.on_replace_value = Some(ErasedValueHook::new(hook));
+        self
+    }
+
+    /// Register a [`ComponentValueHook`] that will be run with the value of this component just
+    /// before it is removed from an entity. Despawning an entity counts as removing all of its
+    /// components.
+    ///
+    /// This runs after the `on_remove` hook, if any. See [`Self::on_replace_value`] for an example.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the component already has an `on_remove` value hook.
+    /// The hook will panic when run if `T` is not the type of this component.
+    pub fn on_remove_value<T: Component<Mutability = Immutable>>(
+        &mut self,
+        hook: ComponentValueHook<T>,
+    ) -> &mut Self {
+        assert!(
+            self.on_remove_value.is_none(),
+            "Component already has an on_remove value hook"
+        );
+        self.on_remove_value = Some(ErasedValueHook::new(hook));
+        self
+    }
+
     /// Attempt to register a [`ComponentHook`] that will be run when this component is added to an entity.
     ///
     /// This is a fallible version of [`Self::on_add`].
@@ -682,6 +821,49 @@ impl ComponentHooks {
     }
 }
 
+/// Runs the type-erased `hook`, which was registered as a [`ComponentValueHook<T>`].
+fn run_value_hook<T: Component<Mutability = Immutable>>(
+    hook: fn(),
+    mut world: DeferredWorld,
+    entity: Entity,
+    component_id: ComponentId,
+) {
+    let Some(info) = world.components().get_info(component_id) else {
+        return;
+    };
+    assert_eq!(
+        info.type_id(),
+        Some(TypeId::of::<T>()),
+        "A value hook for `{}` was registered on the component `{}`",
+        ShortName::of::<T>(),
+        info.name()
+    );
+    // SAFETY: the hook was registered as a `ComponentValueHook<T>`, and we checked that `T` is
+    // the type of this component.
+    let hook = unsafe { mem::transmute::<fn(), ComponentValueHook<T>>(hook) };
+
+    let world = world.as_unsafe_world_cell();
+    // SAFETY:
+    // - `T` is immutable, so no exclusive reference to it can be created through the
+    //   `DeferredWorld` while the value is borrowed.
+    // - Hooks can't make structural changes, so the value stays in place while it is borrowed.
+    let Some(value) = (unsafe {
+        world
+            .get_entity(entity)
+            .and_then(|entity| entity.get::<T>())
+    }) else {
+        return;
+    };
+    // SAFETY: the `DeferredWorld` we were given is handed on to the hook, and the only other
+    // reference into the world is the borrowed value, which can't be accessed mutably.
+    hook(
+        value,
+        unsafe { world.into_deferred() },
+        entity,
+        component_id,
+    );
+}
+
 /// Stores metadata for a type of component or resource stored in a specific [`World`].
 #[derive(Debug, Clone)]
 pub struct ComponentInfo {
@@ -724,6 +906,13 @@ impl ComponentInfo {
         self.descriptor.layout
     }
 
+    /// Returns `true` if the component was registered with
+    /// [`ComponentDescriptor::new_plain_data`], so its values are plain bytes.
+    #[inline]
+    pub fn is_plain_data(&self) -> bool {
+        self.descriptor.plain_data
+    }
+
     #[inline]
     /// Get the function which should be called to clean up values of
     /// the underlying component type. This maps to the
@@ -769,10 +958,10 @@ impl ComponentInfo {
         if self.hooks().on_insert.is_some() {
             flags.insert(ArchetypeFlags::ON_INSERT_HOOK);
         }
-        if self.hooks().on_replace.is_some() {
+        if self.hooks().on_replace.is_some() || self.hooks().on_replace_value.is_some() {
             flags.insert(ArchetypeFlags::ON_REPLACE_HOOK);
         }
-        if self.hooks().on_remove.is_some() {
+        if self.hooks().on_remove.is_some() || self.hooks().on_remove_value.is_some() {
             flags.insert(ArchetypeFlags::ON_REMOVE_HOOK);
         }
     }
@@ -787,6 +976,12 @@ impl ComponentInfo {
     pub fn required_components(&self) -> &RequiredComponents {
         &self.required_components
     }
+
+    /// Returns the ids of all components that require this component, either directly or
+    /// through a chain of other required components.
+    pub fn required_by(&self) -> &HashSet<ComponentId> {
+        &self.required_by
+    }
 }
 
 /// A value which uniquely identifies the type of a [`Component`] or [`Resource`] within a
@@ -864,6 +1059,9 @@ pub struct ComponentDescriptor {
     // None if the underlying type doesn't need to be dropped
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
     mutable: bool,
+    // SAFETY: This must only be set to "true" if any initialized bytes of the layout are a valid
+    // value of this component.
+    plain_data: bool,
 }
 
 // We need to ignore the `drop` field in our `Debug` impl
@@ -876,6 +1074,7 @@ impl Debug for ComponentDescriptor {
             .field("type_id", &self.type_id)
             .field("layout", &self.layout)
             .field("mutable", &self.mutable)
+            .field("plain_data", &self.plain_data)
             .finish()
     }
 }
@@ -901,6 +1100,7 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: T::Mutability::MUTABLE,
+            plain_data: false,
         }
     }
 
@@ -924,6 +1124,38 @@ impl ComponentDescriptor {
             layout,
             drop,
             mutable,
+            plain_data: false,
+        }
+    }
+
+    /// Create a new `ComponentDescriptor` for a component without a Rust type, whose data is
+    /// plain bytes of the given `layout`.
+    ///
+    /// Unlike [`ComponentDescriptor::new_with_layout`], this is safe to call: the component has no
+    /// drop function, so its data is simply discarded when the component is removed. This is useful
+    /// for data-driven games and script bindings that define their components at runtime.
+    ///
+    /// Any `layout.size()` initialized bytes are a valid value of the component, so it can be
+    /// accessed as bytes with [`EntityRef::get_plain_data`] and
+    /// [`EntityWorldMut::insert_plain_data`], which also lets scenes save it.
+    ///
+    /// [`EntityRef::get_plain_data`]: crate::world::EntityRef::get_plain_data
+    /// [`EntityWorldMut::insert_plain_data`]: crate::world::EntityWorldMut::insert_plain_data
+    pub fn new_plain_data(
+        name: impl Into<Cow<'static, str>>,
+        storage_type: StorageType,
+        layout: Layout,
+        mutable: bool,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            storage_type,
+            is_send_and_sync: true,
+            type_id: None,
+            layout,
+            drop: None,
+            mutable,
+            plain_data: true,
         }
     }
 
@@ -941,6 +1173,7 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: true,
+            plain_data: false,
         }
     }
 
@@ -953,7 +1186,24 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: true,
+            plain_data: false,
+        }
+    }
+
+    /// Returns this descriptor with its storage strategy replaced by `storage_type`, for example
+    /// to apply a recommendation from a `StorageReport`.
+    ///
+    /// Returns an error if this descriptor corresponds to a Rust type, whose storage strategy is
+    /// fixed by [`Component::STORAGE_TYPE`].
+    pub fn with_storage_type(
+        mut self,
+        storage_type: StorageType,
+    ) -> Result<Self, FixedStorageTypeError> {
+        if self.type_id.is_some() {
+            return Err(FixedStorageTypeError(self.name));
         }
+        self.storage_type = storage_type;
+        Ok(self)
     }
 
     /// Returns a value indicating the storage strategy for the current component.
@@ -1174,6 +1424,32 @@ impl Components {
         Components::register_component_inner(&mut self.components, storages, descriptor)
     }
 
+    /// Registers a component with the same descriptor and hooks as `info`, which may come from
+    /// another [`World`](crate::world::World). If a component of the same Rust type is already
+    /// registered, its ID is returned instead.
+    ///
+    /// Required components are not copied, as they refer to the IDs of the other world.
+    pub(crate) fn register_component_from_info(
+        &mut self,
+        storages: &mut Storages,
+        info: &ComponentInfo,
+    ) -> ComponentId {
+        let type_id = info.type_id();
+        if let Some(id) = type_id.and_then(|type_id| self.indices.get(&type_id)) {
+            return *id;
+        }
+        let id = Components::register_component_inner(
+            &mut self.components,
+            storages,
+            info.descriptor.clone(),
+        );
+        self.components[id.index()].hooks = info.hooks.clone();
+        if let Some(type_id) = type_id {
+            self.indices.insert(type_id, id);
+        }
+        id
+    }
+
     #[inline]
     fn register_component_inner(
         components: &mut Vec<ComponentInfo>,
@@ -1529,6 +1805,19 @@ impl Components {
         self.indices.get(&type_id).copied()
     }
 
+    /// Returns the [`ComponentId`] of the first registered component with the given name.
+    ///
+    /// This is mostly useful for components registered at runtime with
+    /// [`Components::register_component_with_descriptor()`], which have no [`TypeId`] to
+    /// look them up with. Names are not guaranteed to be unique, and this performs a linear search
+    /// over all registered components, so prefer storing the returned [`ComponentId`].
+    pub fn get_id_by_name(&self, name: &str) -> Option<ComponentId> {
+        self.components
+            .iter()
+            .find(|info| info.name() == name)
+            .map(ComponentInfo::id)
+    }
+
     /// Returns the [`ComponentId`] of the given [`Component`] type `T`.
     ///
     /// The returned `ComponentId` is specific to the `Components` instance
@@ -1735,6 +2024,23 @@ impl Tick {
         ticks_since_system > ticks_since_insert
     }
 
+    /// Returns how many ticks passed between `self` and `this_run`, capped at [`MAX_CHANGE_AGE`].
+    ///
+    /// Ticks older than [`MAX_CHANGE_AGE`] are clamped by
+    /// [`World::check_change_ticks`](crate::world::World::check_change_ticks), so a result of
+    /// [`MAX_CHANGE_AGE`] means "at least that old". This stays correct when the world change tick
+    /// wraps around, unlike subtracting the raw values of [`Tick::get`].
+    ///
+    /// ```
+    /// # use bevy_ecs::{change_detection::MAX_CHANGE_AGE, component::Tick};
+    /// assert_eq!(Tick::new(u32::MAX - 1).ticks_since(Tick::new(3)), 5);
+    /// assert_eq!(Tick::new(0).ticks_since(Tick::new(u32::MAX)), MAX_CHANGE_AGE);
+    /// ```
+    #[inline]
+    pub fn ticks_since(self, this_run: Tick) -> u32 {
+        this_run.relative_to(self).tick.min(MAX_CHANGE_AGE)
+    }
+
     /// Returns a change tick representing the relationship between `self` and `other`.
     #[inline]
     pub(crate) fn relative_to(self, other: Self) -> Self {
@@ -1900,6 +2206,12 @@ pub enum RequiredComponentsError {
     ArchetypeExists(ComponentId),
 }
 
+/// An error returned by [`ComponentDescriptor::with_storage_type`] when the descriptor belongs to a
+/// Rust type.
+#[derive(Error, Debug)]
+#[error("The storage type of {0} is fixed by its `Component` implementation")]
+pub struct FixedStorageTypeError(pub Cow<'static, str>);
+
 /// A Required Component constructor. See [`Component`] for details.
 #[cfg(feature = "track_location")]
 #[derive(Clone)]
@@ -2175,7 +2487,9 @@ pub fn component_clone_via_clone<C: Clone + Component>(
     ctx: &mut ComponentCloneCtx,
 ) {
     if let Some(component) = ctx.read_source_component::<C>() {
-        ctx.write_target_component(component.clone());
+        let mut component = component.clone();
+        C::map_entities(&mut component, &mut ctx.entity_mapper());
+        ctx.write_target_component(component);
     }
 }
 
@@ -2206,15 +2520,22 @@ pub fn component_clone_via_reflect(world: &mut DeferredWorld, ctx: &mut Componen
     // checked in read_source_component_reflect
     let type_id = component_info.type_id().unwrap();
     let registry = registry.read();
+    let reflect_component = registry
+        .get_type_data::<crate::reflect::ReflectComponent>(type_id)
+        .cloned();
+    let mut mapper = ctx.entity_mapper();
 
     // Try to clone using ReflectFromReflect
     if let Some(reflect_from_reflect) =
         registry.get_type_data::<bevy_reflect::ReflectFromReflect>(type_id)
     {
-        if let Some(component) =
+        if let Some(mut component) =
             reflect_from_reflect.from_reflect(source_component_reflect.as_partial_reflect())
         {
             drop(registry);
+            if let Some(reflect_component) = &reflect_component {
+                reflect_component.map_entities(&mut *component, &mut mapper);
+            }
             ctx.write_target_component_reflect(component);
             return;
         }
@@ -2226,6 +2547,9 @@ pub fn component_clone_via_reflect(world: &mut DeferredWorld, ctx: &mut Componen
         let mut component = reflect_default.default();
         component.apply(source_component_reflect.as_partial_reflect());
         drop(registry);
+        if let Some(reflect_component) = &reflect_component {
+            reflect_component.map_entities(&mut *component, &mut mapper);
+        }
         ctx.write_target_component_reflect(component);
         return;
     }
@@ -2242,6 +2566,9 @@ pub fn component_clone_via_reflect(world: &mut DeferredWorld, ctx: &mut Componen
             let mut component = reflect_from_world.from_world(world);
             assert_eq!(type_id, (*component).type_id());
             component.apply(source_component_cloned.as_partial_reflect());
+            if let Some(reflect_component) = &reflect_component {
+                reflect_component.map_entities(&mut *component, &mut mapper);
+            }
             // SAFETY:
             // - component_id is from the same world as target entity
             // - component is a valid value represented by component_id
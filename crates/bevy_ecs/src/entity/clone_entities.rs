@@ -17,7 +17,7 @@ use alloc::sync::Arc;
 use crate::{
     bundle::Bundle,
     component::{Component, ComponentCloneHandler, ComponentId, ComponentInfo, Components},
-    entity::Entity,
+    entity::{Entity, EntityMapper},
     query::DebugCheckedUnwrap,
     world::World,
 };
@@ -87,6 +87,18 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
         self.entity_cloner.target
     }
 
+    /// Returns an [`EntityMapper`] that maps the source entity to the target entity, leaving other
+    /// entities unchanged.
+    ///
+    /// The default clone handlers use it with [`Component::map_entities`], so that a cloned
+    /// component referencing the source entity references the target entity instead.
+    pub fn entity_mapper(&self) -> CloneEntityMapper {
+        CloneEntityMapper {
+            source: self.source(),
+            target: self.target(),
+        }
+    }
+
     /// Returns the [`ComponentId`] of the component being cloned.
     pub fn component_id(&self) -> ComponentId {
         self.component_id
@@ -267,6 +279,25 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
     }
 }
 
+/// An [`EntityMapper`] that maps the source entity of a clone to its target entity.
+///
+/// See [`ComponentCloneCtx::entity_mapper`].
+#[derive(Debug, Clone, Copy)]
+pub struct CloneEntityMapper {
+    source: Entity,
+    target: Entity,
+}
+
+impl EntityMapper for CloneEntityMapper {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        if entity == self.source {
+            self.target
+        } else {
+            entity
+        }
+    }
+}
+
 /// A helper struct to clone an entity. Used internally by [`EntityCloneBuilder::clone_entity`].
 pub struct EntityCloner {
     source: Entity,
@@ -668,10 +699,10 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         component::{Component, ComponentCloneHandler, ComponentDescriptor, StorageType},
-        entity::EntityCloneBuilder,
+        entity::{Entity, EntityCloneBuilder},
         world::{DeferredWorld, World},
     };
-    use alloc::vec::Vec;
+    use alloc::{vec, vec::Vec};
     use bevy_ecs_macros::require;
     use bevy_ptr::OwningPtr;
     use core::alloc::Layout;
@@ -900,6 +931,39 @@ mod tests {
         assert!(world.get::<A>(e_clone).is_some_and(|c| *c == component));
     }
 
+    #[test]
+    fn clone_entity_maps_entities() {
+        #[derive(Component, Clone, PartialEq, Eq, Debug)]
+        struct A {
+            #[entities]
+            this: Entity,
+            #[entities]
+            others: Vec<Entity>,
+            not_mapped: Entity,
+        }
+
+        let mut world = World::default();
+        let other = world.spawn_empty().id();
+        let e = world.spawn_empty().id();
+        world.entity_mut(e).insert(A {
+            this: e,
+            others: vec![other, e],
+            not_mapped: e,
+        });
+        let e_clone = world.spawn_empty().id();
+
+        EntityCloneBuilder::new(&mut world).clone_entity(e, e_clone);
+
+        assert_eq!(
+            Some(&A {
+                this: e_clone,
+                others: vec![other, e_clone],
+                not_mapped: e,
+            }),
+            world.get::<A>(e_clone)
+        );
+    }
+
     #[test]
     fn clone_entity_with_allow_filter() {
         #[derive(Component, Clone, PartialEq, Eq)]
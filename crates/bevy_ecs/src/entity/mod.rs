@@ -69,9 +69,10 @@ use crate::{
     },
     storage::{SparseSetIndex, TableId, TableRow},
 };
-use alloc::vec::Vec;
-use core::{fmt, hash::Hash, mem, num::NonZero};
+use alloc::{vec, vec::Vec};
+use core::{fmt, hash::Hash, mem, num::NonZero, ops::Range};
 use log::warn;
+use thiserror::Error;
 
 #[cfg(feature = "track_location")]
 use core::panic::Location;
@@ -494,7 +495,7 @@ pub struct ReserveEntitiesIterator<'a> {
     freelist_indices: core::slice::Iter<'a, u32>,
 
     // New Entity indices to hand out, outside the range of meta.len().
-    new_indices: core::ops::Range<u32>,
+    new_indices: Range<u32>,
 }
 
 impl<'a> Iterator for ReserveEntitiesIterator<'a> {
@@ -577,6 +578,11 @@ pub struct Entities {
     free_cursor: AtomicIdCursor,
     /// Stores the number of free entities for [`len`](Entities::len)
     len: u32,
+    /// Sorted, non-overlapping ranges of indices set aside by [`reserve_range`](Entities::reserve_range)
+    /// and [`reserve_range_at`](Entities::reserve_range_at). These are never handed out by
+    /// [`alloc`](Entities::alloc) or [`reserve_entity`](Entities::reserve_entity), and are not
+    /// returned to the freelist when freed.
+    reserved_ranges: Vec<Range<u32>>,
 }
 
 impl Entities {
@@ -586,9 +592,104 @@ impl Entities {
             pending: Vec::new(),
             free_cursor: AtomicIdCursor::new(0),
             len: 0,
+            reserved_ranges: Vec::new(),
         }
     }
 
+    /// Sets aside `count` brand new, contiguous entity indices for external allocation and returns
+    /// them.
+    ///
+    /// Reserved indices are never handed out by [`alloc`](Entities::alloc),
+    /// [`reserve_entity`](Entities::reserve_entity) or [`reserve_entities`](Entities::reserve_entities),
+    /// and go back to the reserved range instead of the freelist when their entity is despawned.
+    /// Entities with these indices can only be spawned explicitly, e.g. with
+    /// [`World::insert_or_spawn_batch`](crate::world::World::insert_or_spawn_batch).
+    ///
+    /// This lets a server hand out ids that its clients can mirror exactly by reserving the same
+    /// range with [`reserve_range_at`](Entities::reserve_range_at).
+    pub fn reserve_range(&mut self, count: u32) -> Range<u32> {
+        self.verify_flushed();
+
+        let start = u32::try_from(self.meta.len()).expect("too many entities");
+        let end = start.checked_add(count).expect("too many entities");
+        self.meta.resize(end as usize, EntityMeta::EMPTY);
+        self.insert_reserved_range(start..end);
+        start..end
+    }
+
+    /// Sets aside the given range of entity indices for external allocation, as
+    /// [`reserve_range`](Entities::reserve_range) does.
+    ///
+    /// Reserving a range up front partitions the id space: for example, a client can reserve
+    /// `0..1_000_000` for entities replicated from a server, leaving every other index to local
+    /// allocation. Indices in the range that are currently free are removed from the freelist.
+    ///
+    /// Returns an error if any index in the range is in use or already reserved.
+    pub fn reserve_range_at(&mut self, range: Range<u32>) -> Result<(), ReserveEntityRangeError> {
+        self.verify_flushed();
+
+        if range.is_empty() {
+            return Ok(());
+        }
+        if let Some(reserved) = self
+            .reserved_ranges
+            .iter()
+            .find(|reserved| reserved.start < range.end && range.start < reserved.end)
+        {
+            return Err(ReserveEntityRangeError::AlreadyReserved(reserved.clone()));
+        }
+
+        let meta_len = self.meta.len() as u32;
+        let allocated_end = range.end.min(meta_len);
+        if range.start < allocated_end {
+            let mut free = vec![false; (allocated_end - range.start) as usize];
+            for &index in &self.pending {
+                if (range.start..allocated_end).contains(&index) {
+                    free[(index - range.start) as usize] = true;
+                }
+            }
+            if let Some(offset) = free.iter().position(|free| !free) {
+                let index = range.start + offset as u32;
+                return Err(ReserveEntityRangeError::Occupied(
+                    Entity::from_raw_and_generation(index, self.meta[index as usize].generation),
+                ));
+            }
+            self.pending.retain(|index| !range.contains(index));
+        }
+        if range.end > meta_len {
+            // Indices skipped over between the end of `meta` and the range become free.
+            self.pending.extend(meta_len..range.start.max(meta_len));
+            self.meta.resize(range.end as usize, EntityMeta::EMPTY);
+        }
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+
+        self.insert_reserved_range(range);
+        Ok(())
+    }
+
+    /// Returns the ranges of entity indices set aside with [`reserve_range`](Entities::reserve_range)
+    /// and [`reserve_range_at`](Entities::reserve_range_at), sorted by their start.
+    pub fn reserved_ranges(&self) -> &[Range<u32>] {
+        &self.reserved_ranges
+    }
+
+    /// Returns true if `index` lies within a range reserved for external allocation.
+    #[inline]
+    pub fn is_reserved(&self, index: u32) -> bool {
+        !self.reserved_ranges.is_empty()
+            && self
+                .reserved_ranges
+                .iter()
+                .any(|reserved| reserved.contains(&index))
+    }
+
+    fn insert_reserved_range(&mut self, range: Range<u32>) {
+        let position = self
+            .reserved_ranges
+            .partition_point(|reserved| reserved.start < range.start);
+        self.reserved_ranges.insert(position, range);
+    }
+
     /// Reserve entity IDs concurrently.
     ///
     /// Storage for entity generation and location is lazily allocated by calling [`flush`](Entities::flush).
@@ -704,6 +805,11 @@ impl Entities {
             *self.free_cursor.get_mut() = new_free_cursor;
             self.len += 1;
             None
+        } else if self.is_reserved(entity.index())
+            && self.meta[entity.index() as usize].location.archetype_id == ArchetypeId::INVALID
+        {
+            self.len += 1;
+            None
         } else {
             Some(mem::replace(
                 &mut self.meta[entity.index() as usize].location,
@@ -743,6 +849,9 @@ impl Entities {
         } else {
             let current_meta = &self.meta[entity.index() as usize];
             if current_meta.location.archetype_id == ArchetypeId::INVALID {
+                if self.is_reserved(entity.index()) {
+                    self.len += 1;
+                }
                 AllocAtWithoutReplacement::DidNotExist
             } else if current_meta.generation == entity.generation {
                 AllocAtWithoutReplacement::Exists(current_meta.location)
@@ -776,12 +885,17 @@ impl Entities {
         }
 
         let loc = mem::replace(&mut meta.location, EntityMeta::EMPTY.location);
+        self.len -= 1;
+
+        // Reserved indices stay reserved, so they can be spawned again externally.
+        if self.is_reserved(entity.index()) {
+            return Some(loc);
+        }
 
         self.pending.push(entity.index());
 
         let new_free_cursor = self.pending.len() as IdCursor;
         *self.free_cursor.get_mut() = new_free_cursor;
-        self.len -= 1;
         Some(loc)
     }
 
@@ -813,6 +927,7 @@ impl Entities {
         self.pending.clear();
         *self.free_cursor.get_mut() = 0;
         self.len = 0;
+        self.reserved_ranges.clear();
     }
 
     /// Returns the location of an [`Entity`].
@@ -1002,6 +1117,17 @@ impl Entities {
     }
 }
 
+/// An error returned by [`Entities::reserve_range_at`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ReserveEntityRangeError {
+    /// An index in the requested range is already used by this entity.
+    #[error("The entity index is already in use by {0}")]
+    Occupied(Entity),
+    /// The requested range overlaps with this already reserved range.
+    #[error("The entity indices overlap with the already reserved range {0:?}")]
+    AlreadyReserved(Range<u32>),
+}
+
 /// Helper struct that, when printed, will write the appropriate details
 /// regarding an entity that did not exist.
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -1168,6 +1294,52 @@ mod tests {
         assert!(next_entity.generation() > entity.generation() + GENERATIONS);
     }
 
+    #[test]
+    fn reserve_range_is_never_allocated() {
+        let mut entities = Entities::new();
+        let first = entities.alloc();
+        let range = entities.reserve_range(4);
+        assert_eq!(range, 1..5);
+
+        entities.free(first);
+        let allocated: Vec<_> = (0..8).map(|_| entities.alloc().index()).collect();
+        assert!(allocated.iter().all(|index| !range.contains(index)));
+
+        let reserved = Entity::from_raw(range.start);
+        assert!(entities.alloc_at(reserved).is_none());
+        assert_eq!(entities.len(), 9);
+        entities.free(reserved);
+        assert_eq!(entities.len(), 8);
+        assert!(entities.is_reserved(reserved.index()));
+        assert!(!range.contains(&entities.alloc().index()));
+    }
+
+    #[test]
+    fn reserve_range_at() {
+        let mut entities = Entities::new();
+        let a = entities.alloc();
+        let b = entities.alloc();
+        entities.free(b);
+
+        assert_eq!(
+            entities.reserve_range_at(0..4),
+            Err(ReserveEntityRangeError::Occupied(a))
+        );
+        assert_eq!(entities.reserve_range_at(1..4), Ok(()));
+        assert_eq!(
+            entities.reserve_range_at(3..6),
+            Err(ReserveEntityRangeError::AlreadyReserved(1..4))
+        );
+        assert_eq!(entities.reserve_range_at(8..10), Ok(()));
+        assert_eq!(entities.reserved_ranges(), &[1..4, 8..10]);
+
+        // Indices skipped over by the second range are still available.
+        let allocated: Vec<_> = (0..4).map(|_| entities.alloc().index()).collect();
+        assert!(allocated
+            .iter()
+            .all(|&index| index >= 4 && index != 8 && index != 9));
+    }
+
     #[test]
     #[allow(clippy::nonminimal_bool)] // This is intentionally testing `lt` and `ge` as separate functions.
     fn entity_comparison() {
@@ -0,0 +1,212 @@
+//! Disabled entities do not show up in queries unless the query explicitly mentions them.
+//!
+//! If for example we have `Disabled` as an entity disabling component, when you add `Disabled`
+//! to an entity, the entity will only be visible to queries with a filter like
+//! [`With`]`<Disabled>`, [`Allows`]`<Disabled>` or query data like [`Has`]`<Disabled>`.
+//!
+//! This is useful for soft-disabling entities (for example when pausing a subsystem or pooling
+//! entities for reuse) without having to remove their components.
+//!
+//! ### Warnings
+//!
+//! Currently disabling an entity does not affect its hierarchy or other related entities:
+//! a disabled parent can still have enabled children.
+//!
+//! Disabling components are filtered out on an archetype level. If an entity disabling component
+//! uses [`SparseSet`](crate::component::StorageType::SparseSet) storage, every query will be forced
+//! to iterate archetypes instead of tables, which can be noticeably slower.
+//!
+//! [`With`]: crate::prelude::With
+//! [`Has`]: crate::prelude::Has
+//! [`Allows`]: crate::query::Allows
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId, Components, StorageType},
+    query::FilteredAccess,
+    system::Resource,
+    world::{FromWorld, World},
+};
+use smallvec::SmallVec;
+
+#[cfg(feature = "bevy_reflect")]
+use {crate::reflect::ReflectComponent, bevy_reflect::Reflect};
+
+/// A marker component for disabled entities.
+///
+/// Entities with this component are skipped by queries unless the query explicitly mentions
+/// [`Disabled`], see the [module docs] for more information.
+///
+/// [module docs]: crate::entity_disabling
+#[derive(Component, Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct Disabled;
+
+/// The default filters for all queries, these are used to globally exclude entities from queries.
+///
+/// Every component registered here acts as an entity disabling component: queries get an implicit
+/// [`Without`] filter for each of them, unless the query already mentions that component in its
+/// data or filter, for example through [`With`], [`Has`] or [`Allows`].
+///
+/// [`Disabled`] is registered by default.
+///
+/// [`Without`]: crate::prelude::Without
+/// [`With`]: crate::prelude::With
+/// [`Has`]: crate::prelude::Has
+/// [`Allows`]: crate::query::Allows
+#[derive(Resource, Debug)]
+pub struct DefaultQueryFilters {
+    disabling: SmallVec<[ComponentId; 4]>,
+}
+
+impl FromWorld for DefaultQueryFilters {
+    fn from_world(world: &mut World) -> Self {
+        let mut filters = DefaultQueryFilters::empty();
+        let disabled_component_id = world.register_component::<Disabled>();
+        filters.register_disabling_component(disabled_component_id);
+        filters
+    }
+}
+
+impl DefaultQueryFilters {
+    /// Creates a new, completely empty [`DefaultQueryFilters`].
+    ///
+    /// This is provided as an escape hatch; in most cases you should initialize this using [`FromWorld`],
+    /// which is automatically called when creating a new [`World`].
+    #[must_use]
+    pub fn empty() -> Self {
+        DefaultQueryFilters {
+            disabling: SmallVec::new(),
+        }
+    }
+
+    /// Adds this [`ComponentId`] to the set of entity disabling components.
+    ///
+    /// Queries created after this call will exclude entities with this component, unless they
+    /// explicitly mention it. Queries that already exist are not affected.
+    pub fn register_disabling_component(&mut self, component_id: ComponentId) {
+        if !self.disabling.contains(&component_id) {
+            self.disabling.push(component_id);
+        }
+    }
+
+    /// Get an iterator over all currently registered entity disabling components.
+    pub fn disabling_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.disabling.iter().copied()
+    }
+
+    /// Modifies the provided [`FilteredAccess`] to exclude every disabling component
+    /// that is not explicitly mentioned by it.
+    pub(crate) fn modify_access(&self, component_access: &mut FilteredAccess<ComponentId>) {
+        for component_id in self.disabling_ids() {
+            if !component_access.contains(component_id) {
+                component_access.and_without(component_id);
+            }
+        }
+    }
+
+    /// Returns `false` if any of the disabling components is not stored in tables,
+    /// since those filters can not be applied to whole tables at once.
+    pub(crate) fn is_dense(&self, components: &Components) -> bool {
+        self.disabling_ids().all(|component_id| {
+            components
+                .get_info(component_id)
+                .is_some_and(|info| info.storage_type() == StorageType::Table)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{Entity, Has, Query, With},
+        query::Allows,
+        system::RunSystemOnce,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn filters_modify_access() {
+        let mut filters = DefaultQueryFilters::empty();
+        filters.register_disabling_component(ComponentId::new(1));
+
+        // Component 1 is not mentioned, so it gets a `Without` filter.
+        let mut component_access = FilteredAccess::<ComponentId>::matches_everything();
+        component_access.add_component_read(ComponentId::new(0));
+        filters.modify_access(&mut component_access);
+        assert_eq!(
+            vec![ComponentId::new(1)],
+            component_access.without_filters().collect::<Vec<_>>()
+        );
+
+        // Component 1 is explicitly read, so no filter is added.
+        let mut component_access = FilteredAccess::<ComponentId>::matches_everything();
+        component_access.add_component_read(ComponentId::new(1));
+        filters.modify_access(&mut component_access);
+        assert_eq!(0, component_access.without_filters().count());
+
+        // Component 1 is archetypally accessed (e.g. through `Has`), so no filter is added.
+        let mut component_access = FilteredAccess::<ComponentId>::matches_everything();
+        component_access
+            .access_mut()
+            .add_archetypal(ComponentId::new(1));
+        filters.modify_access(&mut component_access);
+        assert_eq!(0, component_access.without_filters().count());
+    }
+
+    #[test]
+    fn disabled_entities_are_skipped() {
+        let mut world = World::new();
+        world.spawn_empty();
+        world.spawn(Disabled);
+
+        assert_eq!(1, world.query::<Entity>().iter(&world).count());
+        assert_eq!(
+            1,
+            world
+                .query_filtered::<Entity, With<Disabled>>()
+                .iter(&world)
+                .count()
+        );
+        assert_eq!(2, world.query::<Has<Disabled>>().iter(&world).count());
+        assert_eq!(
+            2,
+            world
+                .query_filtered::<Entity, Allows<Disabled>>()
+                .iter(&world)
+                .count()
+        );
+        assert_eq!(
+            2,
+            world
+                .run_system_once(|query: Query<Entity, Allows<Disabled>>| query.iter().count())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn custom_disabling_component() {
+        #[derive(Component)]
+        struct Pooled;
+
+        let mut world = World::new();
+        let pooled = world.register_component::<Pooled>();
+        world
+            .resource_mut::<DefaultQueryFilters>()
+            .register_disabling_component(pooled);
+
+        world.spawn_empty();
+        world.spawn(Pooled);
+        world.spawn(Disabled);
+
+        assert_eq!(1, world.query::<Entity>().iter(&world).count());
+        assert_eq!(
+            2,
+            world
+                .query_filtered::<Entity, Allows<Pooled>>()
+                .iter(&world)
+                .count()
+        );
+    }
+}
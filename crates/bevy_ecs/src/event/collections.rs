@@ -1,5 +1,8 @@
 use crate as bevy_ecs;
-use alloc::vec::Vec;
+use alloc::{
+    collections::VecDeque,
+    vec::{self, Vec},
+};
 use bevy_ecs::{
     event::{Event, EventCursor, EventId, EventInstance},
     system::Resource,
@@ -79,6 +82,8 @@ use {
 ///
 /// The buffers in [`Events`] will grow indefinitely if [`update`](Events::update) is never called.
 ///
+/// How many updates an event survives can be changed per event type with an [`EventRetention`].
+///
 /// An alternative call pattern would be to call [`update`](Events::update)
 /// manually across frames to control when events are cleared.
 /// This complicates consumption and risks ever-expanding memory usage if not cleaned up,
@@ -100,6 +105,39 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     pub(crate) events_b: EventSequence<E>,
     pub(crate) event_count: usize,
+    /// How long events are kept around, see [`EventRetention`].
+    retention: EventRetention,
+    /// For [`EventRetention::Frames`], the start event counts of the previous updates whose
+    /// events are still held in `events_a`, oldest first.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    update_starts: VecDeque<usize>,
+}
+
+/// Controls how long an [`Events`] collection keeps events before dropping them.
+///
+/// Set it with [`Events::set_retention`], or when registering the event with
+/// [`add_event_with`](https://docs.rs/bevy/*/bevy/app/struct.App.html#method.add_event_with).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq, Hash)
+)]
+pub enum EventRetention {
+    /// Events are dropped on the second [`Events::update`] after they were sent.
+    #[default]
+    DoubleBuffered,
+    /// Events are never dropped by the [`event_update_system`](super::event_update_system).
+    ///
+    /// They are only removed by calling [`Events::update`], [`Events::clear`] or
+    /// [`Events::drain`] yourself, which is useful for events that are consumed on your own schedule.
+    Manual,
+    /// Events are dropped on the `n`th [`Events::update`] after they were sent, so readers that
+    /// only run every few frames don't miss them.
+    ///
+    /// `Frames(2)` behaves like [`DoubleBuffered`](Self::DoubleBuffered), and `Frames(0)` is treated
+    /// like `Frames(1)`: events are dropped by the first update after they were sent.
+    Frames(u32),
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -109,6 +147,8 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            retention: Default::default(),
+            update_starts: Default::default(),
         }
     }
 }
@@ -193,41 +233,80 @@ impl<E: Event> Events<E> {
         }
     }
 
-    /// Swaps the event buffers and clears the oldest event buffer. In general, this should be
-    /// called once per frame/update.
+    /// Returns how long this collection keeps its events.
+    pub fn retention(&self) -> EventRetention {
+        self.retention
+    }
+
+    /// Changes how long this collection keeps its events.
+    ///
+    /// Events that are already stored are kept, and will be dropped according to the new
+    /// retention on the following updates.
+    pub fn set_retention(&mut self, retention: EventRetention) {
+        self.retention = retention;
+        self.update_starts.clear();
+    }
+
+    /// Advances the event buffers by one update and clears the events that have expired.
+    /// In general, this should be called once per frame/update.
+    ///
+    /// With the default [`EventRetention::DoubleBuffered`] this swaps the buffers and clears the
+    /// oldest one. This always advances the buffers, even with [`EventRetention::Manual`].
     ///
     /// If you need access to the events that were removed, consider using [`Events::update_drain`].
     pub fn update(&mut self) {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        self.events_b.clear();
-        self.events_b.start_event_count = self.event_count;
+        self.advance().for_each(drop);
         debug_assert_eq!(
             self.events_a.start_event_count + self.events_a.len(),
             self.events_b.start_event_count
         );
     }
 
-    /// Swaps the event buffers and drains the oldest event buffer, returning an iterator
-    /// of all events that were removed. In general, this should be called once per frame/update.
+    /// Advances the event buffers like [`Events::update`], returning an iterator of all events
+    /// that were removed. In general, this should be called once per frame/update.
     ///
     /// If you do not need to take ownership of the removed events, use [`Events::update`] instead.
     #[must_use = "If you do not need the returned events, call .update() instead."]
     pub fn update_drain(&mut self) -> impl Iterator<Item = E> + '_ {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        let iter = self.events_b.events.drain(..);
-        self.events_b.start_event_count = self.event_count;
-        debug_assert_eq!(
-            self.events_a.start_event_count + self.events_a.len(),
-            self.events_b.start_event_count
-        );
+        self.advance().map(|e| e.event)
+    }
 
-        iter.map(|e| e.event)
+    /// Moves the current events into the older buffer and returns the expired ones.
+    fn advance(&mut self) -> vec::Drain<'_, EventInstance<E>> {
+        match self.retention {
+            EventRetention::DoubleBuffered | EventRetention::Manual => {
+                core::mem::swap(&mut self.events_a, &mut self.events_b);
+                self.events_b.start_event_count = self.event_count;
+                self.events_b.events.drain(..)
+            }
+            EventRetention::Frames(frames) => {
+                self.update_starts
+                    .push_back(self.events_b.start_event_count);
+                self.events_a.events.append(&mut self.events_b.events);
+                self.events_b.start_event_count = self.event_count;
+
+                // The current update lives in `events_b`, so `events_a` holds the other `frames - 1`.
+                let retained = frames.saturating_sub(1) as usize;
+                while self.update_starts.len() > retained {
+                    self.update_starts.pop_front();
+                }
+                let start = self
+                    .update_starts
+                    .front()
+                    .copied()
+                    .unwrap_or(self.event_count);
+                let expired = start - self.events_a.start_event_count;
+                self.events_a.start_event_count = start;
+                self.events_a.events.drain(..expired)
+            }
+        }
     }
 
     #[inline]
     fn reset_start_event_count(&mut self) {
         self.events_a.start_event_count = self.event_count;
         self.events_b.start_event_count = self.event_count;
+        self.update_starts.clear();
     }
 
     /// Removes all events.
@@ -398,7 +477,11 @@ impl<E: Event> ExactSizeIterator for SendBatchIds<E> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{self as bevy_ecs, event::Events};
+    use crate::{
+        self as bevy_ecs,
+        event::{EventRetention, Events},
+    };
+    use alloc::vec::Vec;
     use bevy_ecs_macros::Event;
 
     #[test]
@@ -432,4 +515,56 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn frames_retention_keeps_events_for_n_updates() {
+        #[derive(Event, Clone, Copy, PartialEq, Debug)]
+        struct TestEvent(usize);
+
+        let mut events = Events::<TestEvent>::default();
+        events.set_retention(EventRetention::Frames(3));
+        let mut cursor = events.get_cursor();
+
+        events.send(TestEvent(0));
+        events.update();
+        events.send(TestEvent(1));
+        events.update();
+        assert_eq!(events.len(), 2);
+
+        // The first event is dropped on the third update after it was sent.
+        let dropped = events.update_drain().collect::<Vec<_>>();
+        assert_eq!(dropped, [TestEvent(0)]);
+        assert_eq!(events.oldest_event_count(), 1);
+        assert_eq!(cursor.missed_events(&events), 1);
+        assert_eq!(
+            cursor.read(&events).copied().collect::<Vec<_>>(),
+            [TestEvent(1)]
+        );
+
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn frames_retention_matches_double_buffering() {
+        #[derive(Event, Clone, Copy, PartialEq, Debug)]
+        struct TestEvent(usize);
+
+        let mut double = Events::<TestEvent>::default();
+        let mut frames = Events::<TestEvent>::default();
+        frames.set_retention(EventRetention::Frames(2));
+
+        for i in 0..5 {
+            for j in 0..i {
+                double.send(TestEvent(i * 10 + j));
+                frames.send(TestEvent(i * 10 + j));
+            }
+            assert_eq!(
+                double.update_drain().collect::<Vec<_>>(),
+                frames.update_drain().collect::<Vec<_>>()
+            );
+            assert_eq!(double.oldest_event_count(), frames.oldest_event_count());
+            assert_eq!(double.len(), frames.len());
+        }
+    }
 }
@@ -13,7 +13,7 @@ mod writer;
 pub(crate) use base::EventInstance;
 pub use base::{Event, EventId};
 pub use bevy_ecs_macros::Event;
-pub use collections::{Events, SendBatchIds};
+pub use collections::{EventRetention, Events, SendBatchIds};
 pub use event_cursor::EventCursor;
 #[cfg(feature = "multi_threaded")]
 pub use iterators::EventParIter;
@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 use bevy_ecs::{
     change_detection::{DetectChangesMut, MutUntyped},
     component::{ComponentId, Tick},
-    event::{Event, Events},
+    event::{Event, EventRetention, Events},
     system::Resource,
     world::World,
 };
@@ -11,11 +11,12 @@ use bevy_ecs::{
 #[doc(hidden)]
 struct RegisteredEvent {
     component_id: ComponentId,
-    // Required to flush the secondary buffer and drop events even if left unchanged.
+    // Required to flush the older buffers and drop events even if left unchanged.
     previously_updated: bool,
+    // Returns whether events are still stored and need further updates to be dropped.
     // SAFETY: The component ID and the function must be used to fetch the Events<T> resource
     // of the same type initialized in `register_event`, or improper type casts will occur.
-    update: unsafe fn(MutUntyped),
+    update: unsafe fn(MutUntyped) -> bool,
 }
 
 /// A registry of all of the [`Events`] in the [`World`], used by [`event_update_system`](crate::event::update::event_update_system)
@@ -56,9 +57,13 @@ impl EventRegistry {
             previously_updated: false,
             update: |ptr| {
                 // SAFETY: The resource was initialized with the type Events<T>.
-                unsafe { ptr.with_type::<Events<T>>() }
-                    .bypass_change_detection()
-                    .update();
+                let mut events = unsafe { ptr.with_type::<Events<T>>() };
+                let events = events.bypass_change_detection();
+                if events.retention() == EventRetention::Manual {
+                    return false;
+                }
+                events.update();
+                !events.is_empty()
             },
         });
     }
@@ -72,11 +77,9 @@ impl EventRegistry {
                 if registered_event.previously_updated || has_changed {
                     // SAFETY: The update function pointer is called with the resource
                     // fetched from the same component ID.
-                    unsafe { (registered_event.update)(events) };
-                    // Always set to true if the events have changed, otherwise disable running on the second invocation
-                    // to wait for more changes.
+                    // Keep updating while events are stored so they expire, otherwise wait for more changes.
                     registered_event.previously_updated =
-                        has_changed || !registered_event.previously_updated;
+                        unsafe { (registered_event.update)(events) };
                 }
             }
         }
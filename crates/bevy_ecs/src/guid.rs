@@ -0,0 +1,308 @@
+//! Provides the [`Guid`] [`Component`], a globally unique identifier for entities that stays the
+//! same across runs, and the [`GuidIndex`] resource for looking entities up by it.
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId},
+    entity::Entity,
+    system::Resource,
+    world::{DeferredWorld, FromWorld, World},
+};
+use alloc::vec::Vec;
+use bevy_utils::HashMap;
+use core::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::reflect::ReflectComponent,
+    bevy_reflect::{std_traits::ReflectDefault, Reflect},
+};
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// A globally unique identifier for an entity.
+///
+/// Unlike [`Entity`], which is only meaningful within a single [`World`] during a single run,
+/// a [`Guid`] is meant to be saved and sent around: save games and network messages can refer to
+/// entities by their [`Guid`] and find them again with the [`GuidIndex`] resource, which is kept up
+/// to date automatically.
+///
+/// [`Guid`] is immutable, so that the index can't go stale. Insert a new one to change it.
+///
+/// ```
+/// # use bevy_ecs::{guid::{Guid, GuidIndex}, prelude::*};
+/// # let mut world = World::new();
+/// let guid = Guid::from_u128(0x1234);
+/// let entity = world.spawn(guid).id();
+/// world.flush();
+///
+/// assert_eq!(world.resource::<GuidIndex>().get(guid), Some(entity));
+/// ```
+#[derive(Component, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[component(immutable, on_insert = on_insert, on_replace = on_replace)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug, PartialEq, Hash)
+)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Deserialize, Serialize)
+)]
+pub struct Guid(u128);
+
+impl Guid {
+    /// The [`Guid`] whose bits are all zero.
+    pub const NIL: Self = Self(0);
+
+    /// Creates a [`Guid`] from its bits.
+    pub const fn from_u128(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the bits of this [`Guid`].
+    pub const fn as_u128(self) -> u128 {
+        self.0
+    }
+
+    /// Creates a [`Guid`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid.as_u128())
+    }
+
+    /// Returns this [`Guid`] as a [`Uuid`].
+    pub const fn as_uuid(self) -> Uuid {
+        Uuid::from_u128(self.0)
+    }
+
+    /// Generates a new random [`Guid`], as a version 4 [`Uuid`] drawn from the operating system's
+    /// random number generator.
+    #[cfg(feature = "std")]
+    pub fn new_random() -> Self {
+        Self::from_uuid(Uuid::new_v4())
+    }
+}
+
+impl From<Uuid> for Guid {
+    fn from(uuid: Uuid) -> Self {
+        Self::from_uuid(uuid)
+    }
+}
+
+impl From<Guid> for Uuid {
+    fn from(guid: Guid) -> Self {
+        guid.as_uuid()
+    }
+}
+
+impl fmt::Display for Guid {
+    /// Formats the [`Guid`] as hyphenated hexadecimal, e.g.
+    /// `00000000-0000-0000-0000-000000001234`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_uuid().hyphenated(), f)
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Guid({self})")
+    }
+}
+
+/// The error reported when a [`Guid`] is inserted on an entity while another entity already has it.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("{guid} is already used by {existing}, so it still maps to it instead of {entity}")]
+pub struct GuidCollision {
+    /// The [`Guid`] used by both entities.
+    pub guid: Guid,
+    /// The entity the [`GuidIndex`] maps the [`Guid`] to.
+    pub existing: Entity,
+    /// The entity the [`Guid`] was inserted on.
+    pub entity: Entity,
+}
+
+/// Maps every [`Guid`] in the [`World`] to its [`Entity`].
+///
+/// This resource is created and kept up to date by the hooks of [`Guid`]. It is created the first
+/// time a [`Guid`] is inserted, once commands are applied.
+///
+/// Inserting a [`Guid`] that another entity already has is an error: it is logged as a
+/// [`GuidCollision`], and the [`Guid`] keeps mapping to the first entity. The other entities are
+/// listed by [`GuidIndex::collisions`], and one of them takes over if the first entity loses its
+/// [`Guid`].
+#[derive(Resource, Debug)]
+pub struct GuidIndex {
+    entities: HashMap<Guid, Entity>,
+    duplicates: HashMap<Guid, Vec<Entity>>,
+}
+
+impl GuidIndex {
+    /// Returns the entity with the given [`Guid`].
+    pub fn get(&self, guid: Guid) -> Option<Entity> {
+        self.entities.get(&guid).copied()
+    }
+
+    /// Returns `true` if an entity has the given [`Guid`].
+    pub fn contains(&self, guid: Guid) -> bool {
+        self.entities.contains_key(&guid)
+    }
+
+    /// Returns the number of entities with a [`Guid`].
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no entity has a [`Guid`].
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Iterates over every [`Guid`] and its [`Entity`], in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Guid, Entity)> + '_ {
+        self.entities.iter().map(|(&guid, &entity)| (guid, entity))
+    }
+
+    /// Iterates over every [`Guid`] that more than one entity has, along with the entities it
+    /// doesn't map to, in no particular order.
+    pub fn collisions(&self) -> impl Iterator<Item = (Guid, &[Entity])> + '_ {
+        self.duplicates
+            .iter()
+            .map(|(&guid, entities)| (guid, entities.as_slice()))
+    }
+
+    fn insert(&mut self, guid: Guid, entity: Entity) -> Result<(), GuidCollision> {
+        let existing = *self.entities.entry(guid).or_insert(entity);
+        if existing == entity {
+            return Ok(());
+        }
+        self.duplicates.entry(guid).or_default().push(entity);
+        Err(GuidCollision {
+            guid,
+            existing,
+            entity,
+        })
+    }
+
+    fn remove(&mut self, guid: Guid, entity: Entity) {
+        let Some(duplicates) = self.duplicates.get_mut(&guid) else {
+            if self.get(guid) == Some(entity) {
+                self.entities.remove(&guid);
+            }
+            return;
+        };
+        if self.entities.get(&guid) == Some(&entity) {
+            self.entities.insert(guid, duplicates.remove(0));
+        } else {
+            duplicates.retain(|&duplicate| duplicate != entity);
+        }
+        if duplicates.is_empty() {
+            self.duplicates.remove(&guid);
+        }
+    }
+}
+
+impl FromWorld for GuidIndex {
+    fn from_world(world: &mut World) -> Self {
+        let mut index = GuidIndex {
+            entities: HashMap::default(),
+            duplicates: HashMap::default(),
+        };
+        for (entity, &guid) in world.query::<(Entity, &Guid)>().iter(world) {
+            if let Err(error) = index.insert(guid, entity) {
+                log::error!("{error}");
+            }
+        }
+        index
+    }
+}
+
+fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let guid = *world.get::<Guid>(entity).unwrap();
+    if let Some(mut index) = world.get_resource_mut::<GuidIndex>() {
+        if let Err(error) = index.insert(guid, entity) {
+            log::error!("{error}");
+        }
+    } else {
+        // Building the index picks up this entity's `Guid`, if it still has one by then.
+        world.commands().queue(|world: &mut World| {
+            world.init_resource::<GuidIndex>();
+        });
+    }
+}
+
+fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let guid = *world.get::<Guid>(entity).unwrap();
+    if let Some(mut index) = world.get_resource_mut::<GuidIndex>() {
+        index.remove(guid, entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn index_follows_guids() {
+        let mut world = World::new();
+        let a = Guid::from_u128(1);
+        let b = Guid::from_u128(2);
+
+        let entity = world.spawn(a).id();
+        world.flush();
+        assert_eq!(world.resource::<GuidIndex>().get(a), Some(entity));
+
+        world.entity_mut(entity).insert(b);
+        let index = world.resource::<GuidIndex>();
+        assert_eq!(index.get(a), None);
+        assert_eq!(index.get(b), Some(entity));
+
+        world.despawn(entity);
+        assert!(world.resource::<GuidIndex>().is_empty());
+    }
+
+    #[test]
+    fn collisions_keep_the_first_entity() {
+        let mut world = World::new();
+        let guid = Guid::from_u128(1);
+
+        let first = world.spawn(guid).id();
+        world.flush();
+        let second = world.spawn(guid).id();
+        let index = world.resource::<GuidIndex>();
+        assert_eq!(index.get(guid), Some(first));
+        assert!(index.collisions().eq([(guid, &[second][..])]));
+
+        world.despawn(first);
+        let index = world.resource::<GuidIndex>();
+        assert_eq!(index.get(guid), Some(second));
+        assert_eq!(index.collisions().count(), 0);
+
+        world.despawn(second);
+        assert!(world.resource::<GuidIndex>().is_empty());
+    }
+
+    #[test]
+    fn random_guids_are_uuids() {
+        let a = Guid::new_random();
+        let b = Guid::new_random();
+        assert_ne!(a, b);
+        assert_ne!(a, Guid::NIL);
+        assert_eq!(a.as_uuid().get_version_num(), 4);
+        assert_eq!(Guid::from(a.as_uuid()), a);
+    }
+
+    #[test]
+    fn display() {
+        let guid = Guid::from_u128(0x0123_4567_89ab_cdef_0011_2233_4455_6677);
+        assert_eq!(format!("{guid}"), "01234567-89ab-cdef-0011-223344556677");
+    }
+}
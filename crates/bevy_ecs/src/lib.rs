@@ -32,7 +32,9 @@ pub mod bundle;
 pub mod change_detection;
 pub mod component;
 pub mod entity;
+pub mod entity_disabling;
 pub mod event;
+pub mod guid;
 pub mod identifier;
 pub mod intern;
 pub mod label;
@@ -41,6 +43,7 @@ pub mod observer;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+pub mod relationship;
 pub mod removal_detection;
 pub mod result;
 pub mod schedule;
@@ -65,9 +68,9 @@ pub mod prelude {
         event::{Event, EventMutator, EventReader, EventWriter, Events},
         name::{Name, NameOrEntity},
         observer::{CloneEntityWithObserversExt, Observer, Trigger},
-        query::{Added, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
+        query::{Added, Allows, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
         removal_detection::RemovedComponents,
-        result::{Error, Result},
+        result::{BevyError, Error, Result},
         schedule::{
             apply_deferred, common_conditions::*, ApplyDeferred, Condition, IntoSystemConfigs,
             IntoSystemSet, IntoSystemSetConfigs, Schedule, Schedules, SystemSet,
@@ -118,6 +121,7 @@ mod tests {
         change_detection::Ref,
         component::{require, Component, ComponentId, RequiredComponents, RequiredComponentsError},
         entity::Entity,
+        entity_disabling::Disabled,
         prelude::Or,
         query::{Added, Changed, FilteredAccess, QueryFilter, With, Without},
         system::Resource,
@@ -888,6 +892,44 @@ mod tests {
         // );
     }
 
+    #[test]
+    fn remove_tracking_with_values() {
+        use crate::{removal_detection::RemovedComponents, system::RunSystemOnce};
+
+        let mut world = World::new();
+        world.track_removed_values::<A>();
+        world.track_removed_values::<SparseStored>();
+
+        let a = world.spawn((SparseStored(0), A(1))).id();
+        let b = world.spawn((SparseStored(1), A(2))).id();
+        let c = world.spawn((A(3), Disabled)).id();
+        world.spawn(A(4));
+
+        world.entity_mut(a).despawn();
+        assert_eq!(Some(A(2)), world.entity_mut(b).take::<A>());
+        world.entity_mut(c).remove::<A>();
+
+        let values = world
+            .run_system_once(|mut removed: RemovedComponents<A>| {
+                removed
+                    .read_with_values()
+                    .map(|(entity, value)| (entity, *value))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(values, [(a, A(1)), (b, A(2)), (c, A(3))]);
+
+        let values = world
+            .run_system_once(|mut removed: RemovedComponents<SparseStored>| {
+                removed
+                    .read_with_values()
+                    .map(|(entity, value)| (entity, *value))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(values, [(a, SparseStored(0))]);
+    }
+
     #[test]
     fn added_tracking() {
         let mut world = World::new();
@@ -1520,6 +1562,9 @@ mod tests {
         let b_id = world.components.get_id(TypeId::of::<B>()).unwrap();
         expected.add_component_write(a_id);
         expected.add_component_read(b_id);
+        // Queries implicitly exclude disabled entities.
+        let disabled_id = world.components.component_id::<Disabled>().unwrap();
+        expected.and_without(disabled_id);
         assert!(
             query.component_access.eq(&expected),
             "ComponentId access from query fetch and query filter should be combined"
@@ -1821,6 +1866,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn batch_operations_trigger_archetype_events() {
+        use crate::{
+            archetype::{ArchetypeBatchEvent, ArchetypeBatchKind},
+            observer::Trigger,
+            system::ResMut,
+        };
+
+        #[derive(Resource, Default)]
+        struct Events(Vec<ArchetypeBatchEvent>);
+
+        let mut world = World::default();
+        world.init_resource::<Events>();
+        world.add_observer(
+            |trigger: Trigger<ArchetypeBatchEvent>, mut events: ResMut<Events>| {
+                events.0.push(*trigger.event());
+            },
+        );
+
+        let entities = world.spawn_batch((0..3).map(A)).collect::<Vec<_>>();
+        let a = world.entity(entities[0]).archetype().id();
+        let e3 = world.spawn(B(0)).id();
+        world.flush();
+        assert_eq!(
+            core::mem::take(&mut world.resource_mut::<Events>().0),
+            [ArchetypeBatchEvent {
+                kind: ArchetypeBatchKind::Spawned,
+                archetype: a,
+                count: 3,
+            }]
+        );
+
+        world.insert_batch([
+            (entities[0], B(1)),
+            (e3, B(2)),
+            (entities[1], B(3)),
+            (entities[2], B(4)),
+        ]);
+        let ab = world.entity(entities[0]).archetype().id();
+        let b = world.entity(e3).archetype().id();
+        assert_eq!(
+            world.resource::<Events>().0,
+            [
+                ArchetypeBatchEvent {
+                    kind: ArchetypeBatchKind::Inserted,
+                    archetype: ab,
+                    count: 3,
+                },
+                ArchetypeBatchEvent {
+                    kind: ArchetypeBatchKind::Inserted,
+                    archetype: b,
+                    count: 1,
+                },
+            ]
+        );
+    }
+
     #[test]
     fn insert_batch_if_new() {
         let mut world = World::default();
@@ -2623,6 +2725,41 @@ mod tests {
         assert_eq!(to_vec(required_z), vec![(b, 0), (c, 1)]);
     }
 
+    #[test]
+    fn required_components_required_by() {
+        #[derive(Component, Default)]
+        #[require(B)]
+        struct A;
+
+        #[derive(Component, Default)]
+        #[require(C)]
+        struct B;
+
+        #[derive(Component, Default)]
+        struct C;
+
+        #[derive(Component, Default)]
+        struct X;
+
+        let mut world = World::new();
+
+        let a = world.register_component::<A>();
+        let b = world.register_component::<B>();
+        let x = world.register_component::<X>();
+
+        world.register_required_components::<X, B>();
+
+        let mut required_by_c = world
+            .get_required_by::<C>()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        required_by_c.sort();
+        assert_eq!(required_by_c, vec![a, b, x]);
+        assert!(world.get_required_by_id(a).unwrap().is_empty());
+    }
+
     #[test]
     #[should_panic = "Recursive required components detected: A → B → C → B\nhelp: If this is intentional, consider merging the components."]
     fn required_components_recursion_errors() {
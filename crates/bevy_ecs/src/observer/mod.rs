@@ -84,6 +84,15 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
         self.trigger.target
     }
 
+    /// Returns the [`Entity`] that the `event` was originally triggered for, before any propagation.
+    ///
+    /// While an event bubbles along its [`Event::Traversal`], [`Trigger::target`] changes to the
+    /// entity currently being visited, whereas `original_target()` always returns the entity the
+    /// event was first sent to. When the event does not propagate, both are the same.
+    pub fn original_target(&self) -> Entity {
+        self.trigger.original_target
+    }
+
     /// Returns the components that triggered the observer, out of the
     /// components defined in `B`. Does not necessarily include all of them as
     /// `B` acts like an `OR` filter rather than an `AND` filter.
@@ -269,6 +278,9 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// The priority of the observer. Observers with a higher priority run first.
+    priority: i32,
 }
 
 impl ObserverDescriptor {
@@ -293,11 +305,36 @@ impl ObserverDescriptor {
         self
     }
 
+    /// Set the priority of the observer. See [`Observer::with_priority`].
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the priority of the observer.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Merges `descriptor` into this one.
+    ///
+    /// An observer only has a single priority, so the priority of `descriptor` replaces this one.
+    /// Merging two descriptors that were both given a non-default priority is a bug, since one of
+    /// them would be silently discarded.
     pub(crate) fn merge(&mut self, descriptor: &ObserverDescriptor) {
+        debug_assert!(
+            self.priority == 0 || descriptor.priority == 0 || self.priority == descriptor.priority,
+            "merged observer descriptors with conflicting priorities {} and {}",
+            self.priority,
+            descriptor.priority
+        );
         self.events.extend(descriptor.events.iter().copied());
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        if descriptor.priority != 0 {
+            self.priority = descriptor.priority;
+        }
     }
 }
 
@@ -312,6 +349,8 @@ pub struct ObserverTrigger {
     components: SmallVec<[ComponentId; 2]>,
     /// The entity the trigger targeted.
     pub target: Entity,
+    /// The entity the trigger originally targeted, before any propagation.
+    pub original_target: Entity,
 }
 
 impl ObserverTrigger {
@@ -321,8 +360,49 @@ impl ObserverTrigger {
     }
 }
 
-// Map between an observer entity and its runner
-type ObserverMap = EntityHashMap<ObserverRunner>;
+/// A registered observer's runner, along with its priority.
+#[derive(Clone, Copy, Debug)]
+struct RegisteredObserver {
+    runner: ObserverRunner,
+    priority: i32,
+}
+
+/// The observers of a trigger, kept sorted from the highest to the lowest priority.
+///
+/// Observers with equal priorities keep the order they were registered in.
+#[derive(Default, Clone, Debug)]
+struct ObserverMap(Vec<(Entity, RegisteredObserver)>);
+
+impl ObserverMap {
+    fn insert(&mut self, observer: Entity, registered: RegisteredObserver) {
+        self.remove(&observer);
+        let index = self
+            .0
+            .partition_point(|(_, other)| other.priority >= registered.priority);
+        self.0.insert(index, (observer, registered));
+    }
+
+    fn remove(&mut self, observer: &Entity) {
+        if let Some(index) = self.0.iter().position(|(entity, _)| entity == observer) {
+            self.0.remove(index);
+        }
+    }
+
+    /// Returns `true` if any observer in the map has a non-default priority.
+    fn is_prioritized(&self) -> bool {
+        // The map is sorted, so only the first and last priorities need checking.
+        self.0.first().is_some_and(|(_, first)| first.priority != 0)
+            || self.0.last().is_some_and(|(_, last)| last.priority != 0)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &(Entity, RegisteredObserver)> {
+        self.0.iter()
+    }
+}
 
 /// Collection of [`ObserverRunner`] for [`Observer`] registered to a particular trigger targeted at a specific component.
 #[derive(Default, Debug)]
@@ -382,6 +462,7 @@ impl Observers {
         mut world: DeferredWorld,
         event_type: ComponentId,
         target: Entity,
+        original_target: Entity,
         components: impl Iterator<Item = ComponentId> + Clone,
         data: &mut T,
         propagate: &mut bool,
@@ -399,46 +480,60 @@ impl Observers {
             (world.into_deferred(), observers)
         };
 
-        let trigger_for_components = components.clone();
-
-        let mut trigger_observer = |(&observer, runner): (&Entity, &ObserverRunner)| {
-            (runner)(
+        let mut trigger_observer = |&(observer, registered): &(Entity, RegisteredObserver)| {
+            (registered.runner)(
                 world.reborrow(),
                 ObserverTrigger {
                     observer,
                     event_type,
                     components: components.clone().collect(),
                     target,
+                    original_target,
                 },
                 data.into(),
                 propagate,
             );
         };
-        // Trigger observers listening for any kind of this trigger
-        observers.map.iter().for_each(&mut trigger_observer);
 
-        // Trigger entity observers listening for this kind of trigger
-        if target != Entity::PLACEHOLDER {
-            if let Some(map) = observers.entity_observers.get(&target) {
-                map.iter().for_each(&mut trigger_observer);
+        let for_each_map = |f: &mut dyn FnMut(&ObserverMap)| {
+            // Observers listening for any kind of this trigger
+            f(&observers.map);
+
+            // Entity observers listening for this kind of trigger
+            if target != Entity::PLACEHOLDER {
+                if let Some(map) = observers.entity_observers.get(&target) {
+                    f(map);
+                }
             }
-        }
 
-        // Trigger observers listening to this trigger targeting a specific component
-        trigger_for_components.for_each(|id| {
-            if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .map
-                    .iter()
-                    .for_each(&mut trigger_observer);
-
-                if target != Entity::PLACEHOLDER {
-                    if let Some(map) = component_observers.entity_map.get(&target) {
-                        map.iter().for_each(&mut trigger_observer);
+            // Observers listening to this trigger targeting a specific component
+            components.clone().for_each(|id| {
+                if let Some(component_observers) = observers.component_observers.get(&id) {
+                    f(&component_observers.map);
+
+                    if target != Entity::PLACEHOLDER {
+                        if let Some(map) = component_observers.entity_map.get(&target) {
+                            f(map);
+                        }
                     }
                 }
-            }
-        });
+            });
+        };
+
+        let mut prioritized = false;
+        for_each_map(&mut |map| prioritized |= map.is_prioritized());
+        if !prioritized {
+            // Every observer has the default priority, so they can run straight from their maps.
+            for_each_map(&mut |map| map.iter().for_each(&mut trigger_observer));
+            return;
+        }
+
+        // Each map is already sorted, but observers from different maps need to be interleaved.
+        // The sort is stable, so observers with equal priorities keep running in the order above.
+        let mut matching = SmallVec::<[(Entity, RegisteredObserver); 8]>::new();
+        for_each_map(&mut |map| matching.extend(map.iter().copied()));
+        matching.sort_by_key(|(_, registered)| core::cmp::Reverse(registered.priority));
+        matching.iter().for_each(trigger_observer);
     }
 
     pub(crate) fn is_archetype_cached(event_type: ComponentId) -> Option<ArchetypeFlags> {
@@ -638,17 +733,21 @@ impl World {
             (&*observer_state, &mut self.archetypes, &mut self.observers)
         };
         let descriptor = &observer_state.descriptor;
+        let registered = RegisteredObserver {
+            runner: observer_state.runner,
+            priority: descriptor.priority,
+        };
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
-                cache.map.insert(observer_entity, observer_state.runner);
+                cache.map.insert(observer_entity, registered);
             } else if descriptor.components.is_empty() {
                 // Observer is not targeting any components so register it as an entity observer
                 for &watched_entity in &observer_state.descriptor.entities {
                     let map = cache.entity_observers.entry(watched_entity).or_default();
-                    map.insert(observer_entity, observer_state.runner);
+                    map.insert(observer_entity, registered);
                 }
             } else {
                 // Register observer for each watched component
@@ -665,12 +764,12 @@ impl World {
                             });
                     if descriptor.entities.is_empty() {
                         // Register for all triggers targeting the component
-                        observers.map.insert(observer_entity, observer_state.runner);
+                        observers.map.insert(observer_entity, registered);
                     } else {
                         // Register for each watched entity
                         for &watched_entity in &descriptor.entities {
                             let map = observers.entity_map.entry(watched_entity).or_default();
-                            map.insert(observer_entity, observer_state.runner);
+                            map.insert(observer_entity, registered);
                         }
                     }
                 }
@@ -828,6 +927,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn observer_order_priority() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.spawn(
+            Observer::new(|_: Trigger<OnAdd>, mut res: ResMut<Order>| res.observed("low"))
+                .with_priority(-1),
+        );
+        world.add_observer(|_: Trigger<OnAdd>, mut res: ResMut<Order>| res.observed("default"));
+        // Component observers run after global ones unless their priority says otherwise.
+        world.spawn(
+            Observer::new(|_: Trigger<OnAdd, A>, mut res: ResMut<Order>| res.observed("high"))
+                .with_priority(5),
+        );
+        world.flush();
+
+        world.spawn(A);
+        assert_eq!(vec!["high", "default", "low"], world.resource::<Order>().0);
+    }
+
     #[test]
     fn observer_order_insert_remove() {
         let mut world = World::new();
@@ -1201,6 +1321,37 @@ mod tests {
         assert_eq!(vec!["child", "parent"], world.resource::<Order>().0);
     }
 
+    #[test]
+    fn observer_propagating_original_target() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let grandparent = world.spawn_empty().id();
+        let parent = world.spawn(Parent(grandparent)).id();
+        let child = world.spawn(Parent(parent)).id();
+
+        world.add_observer(
+            move |trigger: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                assert_eq!(trigger.original_target(), child);
+                if trigger.target() == child {
+                    res.observed("child");
+                } else if trigger.target() == parent {
+                    res.observed("parent");
+                } else if trigger.target() == grandparent {
+                    res.observed("grandparent");
+                }
+            },
+        );
+
+        world.flush();
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(
+            vec!["child", "parent", "grandparent"],
+            world.resource::<Order>().0
+        );
+    }
+
     #[test]
     fn observer_propagating_redundant_dispatch_same_entity() {
         let mut world = World::new();
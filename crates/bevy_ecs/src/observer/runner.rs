@@ -303,6 +303,33 @@ impl Observer {
         self
     }
 
+    /// Set the priority of the [`Observer`]. When an [`Event`] is triggered, observers with a higher priority
+    /// run before observers with a lower one. Observers default to a priority of `0`, and observers with
+    /// equal priorities run in an unspecified order.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # let mut world = World::default();
+    /// #[derive(Event)]
+    /// struct Damage(u32);
+    ///
+    /// // Always runs before observers with the default priority.
+    /// world.spawn(Observer::new(|mut trigger: Trigger<Damage>| {
+    ///     let capped = trigger.event().0.min(100);
+    ///     trigger.event_mut().0 = capped;
+    /// }).with_priority(10));
+    ///
+    /// world.add_observer(|trigger: Trigger<Damage>| {
+    ///     assert!(trigger.event().0 <= 100);
+    /// });
+    ///
+    /// world.trigger(Damage(500));
+    /// ```
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.descriptor.priority = priority;
+        self
+    }
+
     /// Observe the given `event`. This will cause the [`Observer`] to run whenever an event with the given [`ComponentId`]
     /// is triggered.
     /// # Safety
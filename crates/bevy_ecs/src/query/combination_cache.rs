@@ -0,0 +1,238 @@
+use crate::entity::{Entity, EntityHashSet};
+use alloc::vec::Vec;
+
+/// The `K`-sized combinations of entities matched by a query, kept up to date across runs by
+/// [`Query::iter_combinations_cached`](crate::system::Query::iter_combinations_cached).
+///
+/// Recomputing every combination each frame costs `O(n^K)`, even if the set of matched entities
+/// didn't change. This cache only adds the combinations of newly matched entities and drops the
+/// combinations of entities that stopped matching, which makes it a good fit for broad phases
+/// and similar workloads over mostly static sets of entities.
+///
+/// A cache must only ever be used with a single query, usually by storing it in a
+/// [`Local`](crate::system::Local).
+#[derive(Debug, Clone)]
+pub struct CombinationCache<const K: usize> {
+    /// The matched entities, in the order they were first seen.
+    members: Vec<Entity>,
+    member_set: EntityHashSet,
+    combinations: Vec<[Entity; K]>,
+}
+
+impl<const K: usize> Default for CombinationCache<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const K: usize> CombinationCache<K> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+            member_set: EntityHashSet::default(),
+            combinations: Vec::new(),
+        }
+    }
+
+    /// Returns the cached combinations. Each combination is listed once, in no particular order.
+    pub fn combinations(&self) -> &[[Entity; K]] {
+        &self.combinations
+    }
+
+    /// Returns the entities the combinations are built from.
+    pub fn members(&self) -> &[Entity] {
+        &self.members
+    }
+
+    /// Returns the number of cached combinations.
+    pub fn len(&self) -> usize {
+        self.combinations.len()
+    }
+
+    /// Returns `true` if there are no cached combinations.
+    pub fn is_empty(&self) -> bool {
+        self.combinations.is_empty()
+    }
+
+    /// Forgets all members and combinations.
+    pub fn clear(&mut self) {
+        self.members.clear();
+        self.member_set.clear();
+        self.combinations.clear();
+    }
+
+    /// Updates the cache to contain the combinations of exactly `entities`, which must not
+    /// contain duplicates.
+    ///
+    /// Returns `true` if the set of members changed.
+    pub fn update(&mut self, entities: impl IntoIterator<Item = Entity>) -> bool {
+        let entities: Vec<Entity> = entities.into_iter().collect();
+        let current: EntityHashSet = entities.iter().copied().collect();
+
+        let mut changed = false;
+        if self.members.iter().any(|entity| !current.contains(entity)) {
+            changed = true;
+            self.members.retain(|entity| current.contains(entity));
+            self.combinations
+                .retain(|combination| combination.iter().all(|entity| current.contains(entity)));
+        }
+
+        let first_added = self.members.len();
+        self.members.extend(
+            entities
+                .into_iter()
+                .filter(|entity| !self.member_set.contains(entity)),
+        );
+        if self.members.len() > first_added {
+            changed = true;
+            for last in first_added..self.members.len() {
+                self.push_combinations_ending_at(last);
+            }
+        }
+
+        if changed {
+            self.member_set = current;
+        }
+        changed
+    }
+
+    /// Pushes every combination whose last element is `members[last]`, with the other elements
+    /// taken from `members[..last]`. Doing this for each new member adds exactly the combinations
+    /// that contain at least one new member.
+    fn push_combinations_ending_at(&mut self, last: usize) {
+        let Some(rest) = K.checked_sub(1) else {
+            return;
+        };
+        if rest > last {
+            return;
+        }
+
+        let mut indices = [0; K];
+        for (i, index) in indices[..rest].iter_mut().enumerate() {
+            *index = i;
+        }
+        loop {
+            let mut combination = [self.members[last]; K];
+            for (entity, &index) in combination.iter_mut().zip(&indices[..rest]) {
+                *entity = self.members[index];
+            }
+            self.combinations.push(combination);
+
+            // Advance the rightmost index that hasn't reached its maximum, and reset the ones
+            // after it.
+            let mut i = rest;
+            loop {
+                if i == 0 {
+                    return;
+                }
+                i -= 1;
+                if indices[i] < last - rest + i {
+                    break;
+                }
+            }
+            indices[i] += 1;
+            let start = indices[i];
+            for (offset, index) in indices[i + 1..rest].iter_mut().enumerate() {
+                *index = start + offset + 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        schedule::Schedule,
+        system::{Local, Query, ResMut, Resource},
+        world::World,
+    };
+    use alloc::vec;
+
+    fn sorted<const K: usize>(cache: &CombinationCache<K>) -> Vec<[u32; K]> {
+        let mut combinations: Vec<_> = cache
+            .combinations()
+            .iter()
+            .map(|combination| {
+                let mut indices = combination.map(Entity::index);
+                indices.sort_unstable();
+                indices
+            })
+            .collect();
+        combinations.sort_unstable();
+        combinations
+    }
+
+    #[test]
+    fn incremental_updates() {
+        let entities: Vec<_> = (0..5).map(Entity::from_raw).collect();
+        let mut cache = CombinationCache::<2>::new();
+
+        assert!(cache.update(entities[..3].iter().copied()));
+        assert_eq!(sorted(&cache), [[0, 1], [0, 2], [1, 2]]);
+        assert!(!cache.update(entities[..3].iter().copied()));
+
+        assert!(cache.update([entities[0], entities[2], entities[3], entities[4]]));
+        assert_eq!(
+            sorted(&cache),
+            [[0, 2], [0, 3], [0, 4], [2, 3], [2, 4], [3, 4]]
+        );
+    }
+
+    #[test]
+    fn larger_combinations() {
+        let entities: Vec<_> = (0..5).map(Entity::from_raw).collect();
+        let mut cache = CombinationCache::<3>::new();
+        cache.update(entities[..2].iter().copied());
+        assert!(cache.is_empty());
+        cache.update(entities.iter().copied());
+        assert_eq!(cache.len(), 10);
+        assert_eq!(sorted(&cache)[0], [0, 1, 2]);
+        assert_eq!(sorted(&cache)[9], [2, 3, 4]);
+
+        let mut single = CombinationCache::<1>::new();
+        single.update(entities.iter().copied());
+        assert_eq!(sorted(&single), vec![[0], [1], [2], [3], [4]]);
+    }
+
+    #[test]
+    fn query_iter_combinations_cached() {
+        #[derive(Component)]
+        struct A(u32);
+
+        #[derive(Resource, Default)]
+        struct Sums(Vec<u32>);
+
+        fn sum_pairs(
+            query: Query<&A>,
+            mut cache: Local<CombinationCache<2>>,
+            mut sums: ResMut<Sums>,
+        ) {
+            let mut pairs: Vec<_> = query
+                .iter_combinations_cached(&mut cache)
+                .map(|[a, b]| a.0 + b.0)
+                .collect();
+            pairs.sort_unstable();
+            sums.0 = pairs;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Sums>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(sum_pairs);
+
+        let a = world.spawn(A(1)).id();
+        world.spawn(A(2));
+        world.spawn(A(4));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Sums>().0, [3, 5, 6]);
+
+        world.despawn(a);
+        world.spawn(A(8));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Sums>().0, [6, 10, 12]);
+    }
+}
@@ -109,6 +109,25 @@ pub enum QuerySingleError {
     MultipleEntities(&'static str),
 }
 
+/// An error that occurs when transmuting a [`Query`](crate::system::Query) or
+/// [`QueryState`](crate::query::QueryState) to another query type, via
+/// [`try_transmute_lens`](crate::system::Query::try_transmute_lens) or
+/// [`try_transmute`](crate::query::QueryState::try_transmute).
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum QueryTransmuteError {
+    /// A component used by the new query has not been registered in the world.
+    #[error("Could not create the state of {0}, please initialize all referenced components before transmuting.")]
+    UninitializedComponent(&'static str),
+    /// The new query accesses data that the original query does not.
+    #[error("Transmuted state for {new} attempts to access terms that are not allowed by original state {original}.")]
+    AccessNotAllowed {
+        /// The type name of the new query data and filter.
+        new: &'static str,
+        /// The type name of the original query data and filter.
+        original: &'static str,
+    },
+}
+
 #[cfg(test)]
 mod test {
     use crate as bevy_ecs;
@@ -331,6 +331,110 @@ unsafe impl<T: Component> QueryFilter for Without<T> {
     }
 }
 
+/// Filter that allows entities with a component `T` to be included in the query,
+/// without requiring them to have it.
+///
+/// This is only useful for entity disabling components, such as
+/// [`Disabled`](crate::entity_disabling::Disabled): mentioning a component through `Allows`
+/// opts the query out of the default filter that would otherwise exclude entities with it.
+/// For any other component, `Allows<T>` matches every entity.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::entity_disabling::Disabled;
+/// # use bevy_ecs::query::Allows;
+/// # use bevy_ecs::system::IntoSystem;
+/// # use bevy_ecs::system::Query;
+/// # use bevy_ecs::prelude::Entity;
+/// #
+/// fn count_all_entities(query: Query<Entity, Allows<Disabled>>) {
+///     println!("There are {} entities, including disabled ones", query.iter().count());
+/// }
+/// # bevy_ecs::system::assert_is_system(count_all_entities);
+/// ```
+pub struct Allows<T>(PhantomData<T>);
+
+/// SAFETY:
+/// `update_component_access` only adds an archetypal access, which does not conflict with anything.
+/// This is sound because `fetch` does not access any components.
+/// `matches_component_set` always returns `true`, and `update_component_access` does not add filters.
+unsafe impl<T: Component> WorldQuery for Allows<T> {
+    type Item<'w> = ();
+    type Fetch<'w> = ();
+    type State = ComponentId;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(_: Self::Item<'wlong>) -> Self::Item<'wshort> {}
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(_: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {}
+
+    #[inline]
+    unsafe fn init_fetch(
+        _world: UnsafeWorldCell,
+        _state: &ComponentId,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) {
+    }
+
+    // Allows<T> never filters out any entity, so it can always iterate over whole tables.
+    const IS_DENSE: bool = true;
+
+    #[inline]
+    unsafe fn set_archetype(
+        _fetch: &mut (),
+        _state: &ComponentId,
+        _archetype: &Archetype,
+        _table: &Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table(_fetch: &mut (), _state: &ComponentId, _table: &Table) {}
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+    }
+
+    #[inline]
+    fn update_component_access(&id: &ComponentId, access: &mut FilteredAccess<ComponentId>) {
+        access.access_mut().add_archetypal(id);
+    }
+
+    fn init_state(world: &mut World) -> ComponentId {
+        world.register_component::<T>()
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        components.component_id::<T>()
+    }
+
+    fn matches_component_set(
+        _state: &ComponentId,
+        _set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        true
+    }
+}
+
+// SAFETY: WorldQuery impl performs no access at all
+unsafe impl<T: Component> QueryFilter for Allows<T> {
+    const IS_ARCHETYPAL: bool = true;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        _fetch: &mut Self::Fetch<'_>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> bool {
+        true
+    }
+}
+
 /// A filter that tests if any of the given filters apply.
 ///
 /// This is useful for example if a system with multiple components in a query only wants to run
@@ -148,6 +148,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             let table = unsafe { self.tables.get(table_id).debug_checked_unwrap() };
 
             let range = range.unwrap_or(0..table.entity_count());
+            #[cfg(feature = "storage_access_stats")]
+            self.world
+                .storage_access_stats()
+                .record_iteration(&self.query_state.component_access, range.len());
             accum =
                 // SAFETY:
                 // - The fetched table matches both D and F
@@ -163,6 +167,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             let table = unsafe { self.tables.get(archetype.table_id()).debug_checked_unwrap() };
 
             let range = range.unwrap_or(0..archetype.len());
+            #[cfg(feature = "storage_access_stats")]
+            self.world
+                .storage_access_stats()
+                .record_iteration(&self.query_state.component_access, range.len());
 
             // When an archetype and its table have equal entity counts, dense iteration can be safely used.
             // this leverages cache locality to optimize performance.
@@ -1149,7 +1157,8 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Debug for QueryIter<'w, 's, D, F> {
 ///
 /// This struct is created by the [`QueryIter::sort`], [`QueryIter::sort_unstable`],
 /// [`QueryIter::sort_by`], [`QueryIter::sort_unstable_by`], [`QueryIter::sort_by_key`],
-/// [`QueryIter::sort_unstable_by_key`], and [`QueryIter::sort_by_cached_key`] methods.
+/// [`QueryIter::sort_unstable_by_key`], [`QueryIter::sort_by_cached_key`] and
+/// [`Query::iter_sorted_by_key`](crate::system::Query::iter_sorted_by_key) methods.
 pub struct QuerySortedIter<'w, 's, D: QueryData, F: QueryFilter, I>
 where
     I: Iterator<Item = Entity>,
@@ -2683,6 +2692,8 @@ struct QueryIterationCursor<'w, 's, D: QueryData, F: QueryFilter> {
     current_len: usize,
     // either table row or archetype index, depending on whether both `D`'s and `F`'s fetches are dense
     current_row: usize,
+    #[cfg(feature = "storage_access_stats")]
+    access_stats: &'w crate::storage::StorageAccessStats,
 }
 
 impl<D: QueryData, F: QueryFilter> Clone for QueryIterationCursor<'_, '_, D, F> {
@@ -2696,6 +2707,8 @@ impl<D: QueryData, F: QueryFilter> Clone for QueryIterationCursor<'_, '_, D, F>
             filter: self.filter.clone(),
             current_len: self.current_len,
             current_row: self.current_row,
+            #[cfg(feature = "storage_access_stats")]
+            access_stats: self.access_stats,
         }
     }
 }
@@ -2736,6 +2749,8 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
             is_dense: query_state.is_dense,
             current_len: 0,
             current_row: 0,
+            #[cfg(feature = "storage_access_stats")]
+            access_stats: world.storage_access_stats(),
         }
     }
 
@@ -2749,6 +2764,8 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
             storage_id_iter: self.storage_id_iter.clone(),
             current_len: self.current_len,
             current_row: self.current_row,
+            #[cfg(feature = "storage_access_stats")]
+            access_stats: self.access_stats,
         }
     }
 
@@ -2840,6 +2857,9 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
                     self.table_entities = table.entities();
                     self.current_len = table.entity_count();
                     self.current_row = 0;
+                    #[cfg(feature = "storage_access_stats")]
+                    self.access_stats
+                        .record_iteration(&query_state.component_access, self.current_len);
                 }
 
                 // SAFETY: set_table was called prior.
@@ -2889,6 +2909,9 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
                     self.archetype_entities = archetype.entities();
                     self.current_len = archetype.len();
                     self.current_row = 0;
+                    #[cfg(feature = "storage_access_stats")]
+                    self.access_stats
+                        .record_iteration(&query_state.component_access, self.current_len);
                 }
 
                 // SAFETY: set_archetype was called prior.
@@ -2968,6 +2991,63 @@ mod tests {
     #[component(storage = "SparseSet")]
     struct Sparse(usize);
 
+    #[test]
+    fn query_iter_sorted_by_key() {
+        use crate::{query::SortCache, system::Query};
+
+        #[derive(Component, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Order(u32);
+
+        let mut world = World::new();
+        let c = world.spawn(Order(3)).id();
+        let a = world.spawn(Order(1)).id();
+        let b = world.spawn(Order(2)).id();
+        let tied = world.spawn(Order(2)).id();
+
+        let mut state = world.query::<(Entity, &Order)>();
+        let mut cache = SortCache::<Order>::new();
+        let mut sorted = |world: &mut World| {
+            state.update_archetypes(world);
+            let this_run = world.change_tick();
+            // SAFETY: the query is only used for reading while `world` is not otherwise borrowed.
+            let query = unsafe {
+                Query::new(
+                    world.as_unsafe_world_cell_readonly(),
+                    &state,
+                    world.last_change_tick(),
+                    this_run,
+                )
+            };
+            let entities = query
+                .iter_sorted_by_key(&mut cache)
+                .map(|(entity, _)| entity)
+                .collect::<Vec<_>>();
+            world.increment_change_tick();
+            (entities, cache.tick)
+        };
+
+        let (entities, first_tick) = sorted(&mut world);
+        assert_eq!(entities, [a, b, tied, c]);
+
+        // Nothing changed, so the cached order is reused.
+        let (entities, tick) = sorted(&mut world);
+        assert_eq!(entities, [a, b, tied, c]);
+        assert_eq!(tick, first_tick);
+
+        // Changing a key re-sorts, keeping the previous order of equal keys.
+        world.get_mut::<Order>(c).unwrap().0 = 0;
+        world.get_mut::<Order>(tied).unwrap().0 = 2;
+        let (entities, tick) = sorted(&mut world);
+        assert_eq!(entities, [c, a, b, tied]);
+        assert_ne!(tick, first_tick);
+
+        // Membership changes re-sort as well.
+        world.despawn(a);
+        let d = world.spawn(Order(5)).id();
+        let (entities, _) = sorted(&mut world);
+        assert_eq!(entities, [c, b, tied, d]);
+    }
+
     #[allow(clippy::unnecessary_sort_by)]
     #[test]
     fn query_iter_sorts() {
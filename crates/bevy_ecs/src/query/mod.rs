@@ -2,22 +2,26 @@
 
 mod access;
 mod builder;
+mod combination_cache;
 mod error;
 mod fetch;
 mod filter;
 mod iter;
 mod par_iter;
+mod sort_cache;
 mod state;
 mod world_query;
 
 pub use access::*;
 pub use bevy_ecs_macros::{QueryData, QueryFilter};
 pub use builder::*;
+pub use combination_cache::*;
 pub use error::*;
 pub use fetch::*;
 pub use filter::*;
 pub use iter::*;
 pub use par_iter::*;
+pub use sort_cache::*;
 pub use state::*;
 pub use world_query::*;
 
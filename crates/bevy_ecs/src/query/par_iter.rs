@@ -4,6 +4,46 @@ use crate::{
 
 use super::{QueryData, QueryFilter, QueryItem, QueryState};
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+use core::cell::Cell;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+std::thread_local! {
+    /// The position in the iteration order of the query items the current thread is processing
+    /// for a parallel iteration, see [`BatchPositionGuard`].
+    static BATCH_POSITION: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+}
+
+/// Records the position in the iteration order of the query items the current thread processes
+/// for a parallel iteration, until dropped, so that [`ParallelCommands`] can apply the commands of
+/// each batch in iteration order.
+///
+/// The position is the index of the storage in [`QueryState::matched_storage_ids`], followed by
+/// the row of the first item in that storage.
+///
+/// [`ParallelCommands`]: crate::system::ParallelCommands
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+pub(crate) struct BatchPositionGuard(Option<(usize, usize)>);
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+impl BatchPositionGuard {
+    pub(crate) fn new(position: (usize, usize)) -> Self {
+        Self(BATCH_POSITION.replace(Some(position)))
+    }
+
+    /// Returns the position recorded for the current thread, if it is processing a batch.
+    pub(crate) fn current() -> Option<(usize, usize)> {
+        BATCH_POSITION.get()
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+impl Drop for BatchPositionGuard {
+    fn drop(&mut self) {
+        BATCH_POSITION.set(self.0);
+    }
+}
+
 /// A parallel iterator over query results of a [`Query`](crate::system::Query).
 ///
 /// This struct is created by the [`Query::par_iter`](crate::system::Query::par_iter) and
@@ -0,0 +1,54 @@
+use crate::{
+    component::{Component, Tick},
+    entity::Entity,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// The entities matched by a query, sorted by their `K` component and kept across runs by
+/// [`Query::iter_sorted_by_key`](crate::system::Query::iter_sorted_by_key).
+///
+/// The order is only recomputed when a `K` component of a matching entity changed or the set
+/// of matching entities changed.
+///
+/// A cache must only ever be used with a single query, usually by storing it in a
+/// [`Local`](crate::system::Local).
+pub struct SortCache<K: Component> {
+    /// The change tick at which the order was computed.
+    pub(crate) tick: Option<Tick>,
+    /// The matched entities, in the order the query iterates them.
+    pub(crate) unsorted: Vec<Entity>,
+    /// The matched entities, sorted by the key component.
+    pub(crate) sorted: Vec<Entity>,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<K: Component> Default for SortCache<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Component> SortCache<K> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self {
+            tick: None,
+            unsorted: Vec::new(),
+            sorted: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the entities in the last computed order.
+    pub fn sorted(&self) -> &[Entity] {
+        &self.sorted
+    }
+
+    /// Forgets the computed order, so that the next use sorts anew.
+    pub fn clear(&mut self) {
+        self.tick = None;
+        self.unsorted.clear();
+        self.sorted.clear();
+    }
+}
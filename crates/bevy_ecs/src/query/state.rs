@@ -3,6 +3,7 @@ use crate::{
     batching::BatchingStrategy,
     component::{ComponentId, Tick},
     entity::{Entity, EntityBorrow, EntitySet},
+    entity_disabling::DefaultQueryFilters,
     prelude::FromWorld,
     query::{
         Access, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter, QueryParIter,
@@ -21,7 +22,7 @@ use tracing::Span;
 
 use super::{
     NopWorldQuery, QueryBuilder, QueryData, QueryEntityError, QueryFilter, QueryManyIter,
-    QueryManyUniqueIter, QuerySingleError, ROQueryItem,
+    QueryManyUniqueIter, QuerySingleError, QueryTransmuteError, ROQueryItem,
 };
 
 /// An ID for either a table or an archetype. Used for Query iteration.
@@ -79,10 +80,26 @@ pub struct QueryState<D: QueryData, F: QueryFilter = ()> {
     pub(super) is_dense: bool,
     pub(crate) fetch_state: D::State,
     pub(crate) filter_state: F::State,
+    match_stats: QueryMatchStats,
     #[cfg(feature = "trace")]
     par_iter_span: Span,
 }
 
+/// Statistics about the archetype matching work done by a [`QueryState`].
+///
+/// A [`QueryState`] caches which archetypes it matches, and only checks archetypes that were
+/// created since its last update. These counters can be used to measure how much time is spent
+/// on that, for example in worlds with a very large number of archetypes.
+///
+/// See [`QueryState::match_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueryMatchStats {
+    /// The number of archetypes that were checked against this query.
+    pub archetypes_checked: u64,
+    /// The number of checked archetypes that matched this query.
+    pub archetypes_matched: u64,
+}
+
 impl<D: QueryData, F: QueryFilter> fmt::Debug for QueryState<D, F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("QueryState")
@@ -153,6 +170,11 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     pub fn matched_archetypes(&self) -> impl Iterator<Item = ArchetypeId> + '_ {
         self.matched_archetypes.ones().map(ArchetypeId::new)
     }
+
+    /// Returns statistics about the archetype matching done by this query so far.
+    pub fn match_stats(&self) -> QueryMatchStats {
+        self.match_stats
+    }
 }
 
 impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
@@ -214,7 +236,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     fn new_uninitialized(world: &mut World) -> Self {
         let fetch_state = D::init_state(world);
         let filter_state = F::init_state(world);
-        Self::from_states_uninitialized(world.id(), fetch_state, filter_state)
+        Self::from_states_uninitialized(world, fetch_state, filter_state)
     }
 
     /// Creates a new [`QueryState`] but does not populate it with the matched results from the World yet
@@ -225,7 +247,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let fetch_state = D::get_state(world.components())?;
         let filter_state = F::get_state(world.components())?;
         Some(Self::from_states_uninitialized(
-            world.id(),
+            world,
             fetch_state,
             filter_state,
         ))
@@ -236,7 +258,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// `new_archetype` and its variants must be called on all of the World's archetypes before the
     /// state can return valid query results.
     fn from_states_uninitialized(
-        world_id: WorldId,
+        world: &World,
         fetch_state: <D as WorldQuery>::State,
         filter_state: <F as WorldQuery>::State,
     ) -> Self {
@@ -255,10 +277,15 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 
         // For queries without dynamic filters the dense-ness of the query is equal to the dense-ness
         // of its static type parameters.
-        let is_dense = D::IS_DENSE && F::IS_DENSE;
+        let mut is_dense = D::IS_DENSE && F::IS_DENSE;
+
+        if let Some(default_filters) = world.get_resource::<DefaultQueryFilters>() {
+            default_filters.modify_access(&mut component_access);
+            is_dense &= default_filters.is_dense(world.components());
+        }
 
         Self {
-            world_id,
+            world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
             is_dense,
@@ -267,6 +294,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
+            match_stats: QueryMatchStats::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
@@ -282,17 +310,27 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
+        // For dynamic queries the dense-ness is given by the query builder.
+        let mut is_dense = builder.is_dense();
+
+        let mut component_access = builder.access().clone();
+
+        if let Some(default_filters) = builder.world().get_resource::<DefaultQueryFilters>() {
+            default_filters.modify_access(&mut component_access);
+            is_dense &= default_filters.is_dense(builder.world().components());
+        }
+
         let mut state = Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
-            // For dynamic queries the dense-ness is given by the query builder.
-            is_dense: builder.is_dense(),
+            is_dense,
             fetch_state,
             filter_state,
-            component_access: builder.access().clone(),
+            component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
+            match_stats: QueryMatchStats::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
@@ -440,20 +478,35 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 })
                 // select the component with the fewest archetypes
                 .min_by_key(ExactSizeIterator::len);
-            if let Some(archetypes) = potential_archetypes {
-                for archetype_id in archetypes {
-                    // exclude archetypes that have already been processed
-                    if archetype_id < &self.archetype_generation.0 {
-                        continue;
+            let new_archetypes = world.archetypes().len() - self.archetype_generation.0.index();
+            match potential_archetypes {
+                // Only visit the archetypes containing the rarest required component if there are
+                // fewer of them than new archetypes. Otherwise, just check the new archetypes.
+                Some(archetypes) if archetypes.len() < new_archetypes => {
+                    for archetype_id in archetypes {
+                        // exclude archetypes that have already been processed
+                        if archetype_id < &self.archetype_generation.0 {
+                            continue;
+                        }
+                        // SAFETY: get_potential_archetypes only returns archetype ids that are valid for the world
+                        let archetype = &world.archetypes()[*archetype_id];
+                        // SAFETY: The validate_world call ensures that the world is the same the QueryState
+                        // was initialized from.
+                        unsafe {
+                            self.new_archetype_internal(archetype);
+                        }
                     }
-                    // SAFETY: get_potential_archetypes only returns archetype ids that are valid for the world
-                    let archetype = &world.archetypes()[*archetype_id];
-                    // SAFETY: The validate_world call ensures that the world is the same the QueryState
-                    // was initialized from.
-                    unsafe {
-                        self.new_archetype_internal(archetype);
+                }
+                Some(_) => {
+                    for archetype in &world.archetypes()[self.archetype_generation..] {
+                        // SAFETY: The validate_world call ensures that the world is the same the QueryState
+                        // was initialized from.
+                        unsafe {
+                            self.new_archetype_internal(archetype);
+                        }
                     }
                 }
+                None => {}
             }
             self.archetype_generation = world.archetypes().generation();
         }
@@ -509,10 +562,12 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// # Safety
     /// `archetype` must be from the `World` this state was initialized from.
     unsafe fn new_archetype_internal(&mut self, archetype: &Archetype) -> bool {
+        self.match_stats.archetypes_checked += 1;
         if D::matches_component_set(&self.fetch_state, &|id| archetype.contains(id))
             && F::matches_component_set(&self.filter_state, &|id| archetype.contains(id))
             && self.matches_component_set(&|id| archetype.contains(id))
         {
+            self.match_stats.archetypes_matched += 1;
             let archetype_index = archetype.id().index();
             if !self.matched_archetypes.contains(archetype_index) {
                 self.matched_archetypes.grow_and_insert(archetype_index);
@@ -621,16 +676,42 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// as self but with a new type signature.
     ///
     /// Panics if `NewD` or `NewF` require accesses that this query does not have.
+    /// See [`QueryState::try_transmute_filtered`] for a version that returns an error instead.
+    #[track_caller]
     pub fn transmute_filtered<'a, NewD: QueryData, NewF: QueryFilter>(
         &self,
         world: impl Into<UnsafeWorldCell<'a>>,
     ) -> QueryState<NewD, NewF> {
+        self.try_transmute_filtered(world)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Like [`QueryState::transmute`], but returns an error instead of panicking if `NewD`
+    /// requires accesses that this query does not have.
+    pub fn try_transmute<'a, NewD: QueryData>(
+        &self,
+        world: impl Into<UnsafeWorldCell<'a>>,
+    ) -> Result<QueryState<NewD>, QueryTransmuteError> {
+        self.try_transmute_filtered::<NewD, ()>(world.into())
+    }
+
+    /// Like [`QueryState::transmute_filtered`], but returns an error instead of panicking if
+    /// `NewD` or `NewF` require accesses that this query does not have, or use components that
+    /// are not registered yet.
+    pub fn try_transmute_filtered<'a, NewD: QueryData, NewF: QueryFilter>(
+        &self,
+        world: impl Into<UnsafeWorldCell<'a>>,
+    ) -> Result<QueryState<NewD, NewF>, QueryTransmuteError> {
         let world = world.into();
         self.validate_world(world.id());
 
         let mut component_access = FilteredAccess::default();
-        let mut fetch_state = NewD::get_state(world.components()).expect("Could not create fetch_state, Please initialize all referenced components before transmuting.");
-        let filter_state = NewF::get_state(world.components()).expect("Could not create filter_state, Please initialize all referenced components before transmuting.");
+        let mut fetch_state = NewD::get_state(world.components()).ok_or(
+            QueryTransmuteError::UninitializedComponent(core::any::type_name::<NewD>()),
+        )?;
+        let filter_state = NewF::get_state(world.components()).ok_or(
+            QueryTransmuteError::UninitializedComponent(core::any::type_name::<NewF>()),
+        )?;
 
         NewD::set_access(&mut fetch_state, &self.component_access);
         NewD::update_component_access(&fetch_state, &mut component_access);
@@ -639,13 +720,14 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         NewF::update_component_access(&filter_state, &mut filter_component_access);
 
         component_access.extend(&filter_component_access);
-        assert!(
-            component_access.is_subset(&self.component_access),
-            "Transmuted state for {} attempts to access terms that are not allowed by original state {}.",
-            core::any::type_name::<(NewD, NewF)>(), core::any::type_name::<(D, F)>()
-        );
+        if !component_access.is_subset(&self.component_access) {
+            return Err(QueryTransmuteError::AccessNotAllowed {
+                new: core::any::type_name::<(NewD, NewF)>(),
+                original: core::any::type_name::<(D, F)>(),
+            });
+        }
 
-        QueryState {
+        Ok(QueryState {
             world_id: self.world_id,
             archetype_generation: self.archetype_generation,
             matched_storage_ids: self.matched_storage_ids.clone(),
@@ -655,13 +737,14 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             component_access: self.component_access.clone(),
             matched_tables: self.matched_tables.clone(),
             matched_archetypes: self.matched_archetypes.clone(),
+            match_stats: QueryMatchStats::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
                 query = core::any::type_name::<NewD>(),
                 filter = core::any::type_name::<NewF>(),
             ),
-        }
+        })
     }
 
     /// Use this to combine two queries. The data accessed will be the intersection
@@ -777,6 +860,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             component_access: joined_component_access,
             matched_tables,
             matched_archetypes,
+            match_stats: QueryMatchStats::default(),
             #[cfg(feature = "trace")]
             par_iter_span: tracing::info_span!(
                 "par_for_each",
@@ -1028,6 +1112,10 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         {
             return Err(QueryEntityError::QueryDoesNotMatch(entity, world));
         }
+        #[cfg(feature = "storage_access_stats")]
+        world
+            .storage_access_stats()
+            .record_query_get(&self.component_access);
         let archetype = world
             .archetypes()
             .get(location.archetype_id)
@@ -1659,6 +1747,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     {
         // NOTE: If you are changing query iteration code, remember to update the following places, where relevant:
         // QueryIter, QueryIterationCursor, QueryManyIter, QueryCombinationIter,QueryState::par_fold_init_unchecked_manual
+        use super::par_iter::BatchPositionGuard;
         use arrayvec::ArrayVec;
 
         bevy_tasks::ComputeTaskPool::get().scope(|scope| {
@@ -1669,7 +1758,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             let mut queue_entity_count = 0;
 
             // submit a list of storages which smaller than batch_size as single task
-            let submit_batch_queue = |queue: &mut ArrayVec<StorageId, 128>| {
+            let submit_batch_queue = |queue: &mut ArrayVec<(usize, StorageId), 128>| {
                 if queue.is_empty() {
                     return;
                 }
@@ -1681,14 +1770,15 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                     let _span = self.par_iter_span.enter();
                     let mut iter = self.iter_unchecked_manual(world, last_run, this_run);
                     let mut accum = init_accum();
-                    for storage_id in queue {
+                    for (index, storage_id) in queue {
+                        let _position = BatchPositionGuard::new((index, 0));
                         accum = iter.fold_over_storage_range(accum, &mut func, storage_id, None);
                     }
                 });
             };
 
             // submit single storage larger than batch_size
-            let submit_single = |count, storage_id: StorageId| {
+            let submit_single = |count, index, storage_id: StorageId| {
                 for offset in (0..count).step_by(batch_size) {
                     let mut func = func.clone();
                     let init_accum = init_accum.clone();
@@ -1697,6 +1787,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                     scope.spawn(async move {
                         #[cfg(feature = "trace")]
                         let _span = self.par_iter_span.enter();
+                        let _position = BatchPositionGuard::new((index, offset));
                         let accum = init_accum();
                         self.iter_unchecked_manual(world, last_run, this_run)
                             .fold_over_storage_range(accum, &mut func, storage_id, Some(batch));
@@ -1712,7 +1803,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 }
             };
 
-            for storage_id in &self.matched_storage_ids {
+            for (index, storage_id) in self.matched_storage_ids.iter().enumerate() {
                 let count = storage_entity_count(*storage_id);
 
                 // skip empty storage
@@ -1721,11 +1812,11 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 }
                 // immediately submit large storage
                 if count >= batch_size {
-                    submit_single(count, *storage_id);
+                    submit_single(count, index, *storage_id);
                     continue;
                 }
                 // merge small storage
-                batch_queue.push(*storage_id);
+                batch_queue.push((index, *storage_id));
                 queue_entity_count += count;
 
                 // submit batch_queue
@@ -1882,10 +1973,46 @@ impl<D: QueryData, F: QueryFilter> From<QueryBuilder<'_, D, F>> for QueryState<D
 mod tests {
     use crate as bevy_ecs;
     use crate::{
-        component::Component, prelude::*, query::QueryEntityError, world::FilteredEntityRef,
+        component::Component,
+        prelude::*,
+        query::{QueryEntityError, QueryTransmuteError},
+        world::FilteredEntityRef,
     };
     use alloc::vec::Vec;
 
+    #[test]
+    fn match_stats() {
+        #[derive(Component)]
+        struct A;
+
+        #[derive(Component)]
+        struct B;
+
+        #[derive(Component)]
+        struct C;
+
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, B));
+        world.spawn(B);
+
+        // Only the archetypes containing `A` are checked.
+        let mut query_state = world.query::<&A>();
+        assert_eq!(2, query_state.match_stats().archetypes_checked);
+        assert_eq!(2, query_state.match_stats().archetypes_matched);
+
+        // Up to date, nothing to check.
+        query_state.update_archetypes(&world);
+        assert_eq!(2, query_state.match_stats().archetypes_checked);
+
+        // Only the new archetypes are checked.
+        world.spawn((B, C));
+        world.spawn((A, C));
+        query_state.update_archetypes(&world);
+        assert_eq!(4, query_state.match_stats().archetypes_checked);
+        assert_eq!(3, query_state.match_stats().archetypes_matched);
+    }
+
     #[test]
     fn get_many_unchecked_manual_uniqueness() {
         let mut world = World::new();
@@ -2079,6 +2206,31 @@ mod tests {
         let _ = query_state.transmute::<&B>(&world);
     }
 
+    #[test]
+    fn try_transmute_errors() {
+        #[derive(Component)]
+        struct Unregistered;
+
+        let mut world = World::new();
+        world.spawn((A(0), B(1)));
+        let query = QueryState::<(&A, &B)>::new(&mut world);
+
+        assert!(matches!(
+            query.try_transmute::<&Unregistered>(&world),
+            Err(QueryTransmuteError::UninitializedComponent(_))
+        ));
+        assert_eq!(
+            query.try_transmute::<&mut A>(&world).map(|_| ()),
+            Err(QueryTransmuteError::AccessNotAllowed {
+                new: core::any::type_name::<(&mut A, ())>(),
+                original: core::any::type_name::<((&A, &B), ())>(),
+            })
+        );
+
+        let mut new_query = query.try_transmute::<&B>(&world).unwrap();
+        assert_eq!(new_query.single(&world).0, 1);
+    }
+
     #[test]
     #[should_panic(
         expected = "Transmuted state for ((&bevy_ecs::query::state::tests::A, &bevy_ecs::query::state::tests::B), ()) attempts to access terms that are not allowed by original state (&bevy_ecs::query::state::tests::A, ())."
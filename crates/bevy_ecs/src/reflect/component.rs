@@ -57,11 +57,11 @@
 //!
 //! [`get_type_registration`]: bevy_reflect::GetTypeRegistration::get_type_registration
 
-use super::from_reflect_with_fallback;
+use super::{from_reflect_with_fallback, map_entities::map_marked_entities};
 use crate::{
     change_detection::Mut,
     component::{ComponentId, ComponentMutability},
-    entity::Entity,
+    entity::{Entity, EntityMapper},
     prelude::Component,
     world::{
         unsafe_world_cell::UnsafeEntityCell, EntityMut, EntityWorldMut, FilteredEntityMut,
@@ -106,6 +106,11 @@ pub struct ReflectComponentFns {
     pub apply: fn(EntityMut, &dyn PartialReflect),
     /// Function pointer implementing [`ReflectComponent::apply_or_insert()`].
     pub apply_or_insert: fn(&mut EntityWorldMut, &dyn PartialReflect, &TypeRegistry),
+    /// Function pointer implementing [`ReflectComponent::apply_or_insert_mapped()`].
+    pub apply_or_insert_mapped:
+        fn(&mut EntityWorldMut, &dyn PartialReflect, &TypeRegistry, &mut dyn EntityMapper),
+    /// Function pointer implementing [`ReflectComponent::map_entities()`].
+    pub map_entities: fn(&mut dyn Reflect, &mut dyn EntityMapper),
     /// Function pointer implementing [`ReflectComponent::remove()`].
     pub remove: fn(&mut EntityWorldMut),
     /// Function pointer implementing [`ReflectComponent::contains()`].
@@ -172,6 +177,32 @@ impl ReflectComponent {
         (self.0.apply_or_insert)(entity, component, registry);
     }
 
+    /// Like [`apply_or_insert()`](Self::apply_or_insert), but also maps the [`Entity`] references
+    /// of the resulting component with [`Component::map_entities`], and those of its fields marked
+    /// with [`EntityReferences`](crate::reflect::EntityReferences).
+    ///
+    /// Unlike [`apply_or_insert()`](Self::apply_or_insert), immutable components are supported:
+    /// they are always replaced with a new value.
+    pub fn apply_or_insert_mapped(
+        &self,
+        entity: &mut EntityWorldMut,
+        component: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        mapper: &mut dyn EntityMapper,
+    ) {
+        (self.0.apply_or_insert_mapped)(entity, component, registry, mapper);
+    }
+
+    /// Maps the [`Entity`] references of the given component with [`Component::map_entities`], and
+    /// those of its fields marked with [`EntityReferences`](crate::reflect::EntityReferences).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `component` is not of this [`Component`] type.
+    pub fn map_entities(&self, component: &mut dyn Reflect, mapper: &mut dyn EntityMapper) {
+        (self.0.map_entities)(component, mapper);
+    }
+
     /// Removes this [`Component`] type from the entity. Does nothing if it doesn't exist.
     pub fn remove(&self, entity: &mut EntityWorldMut) {
         (self.0.remove)(entity);
@@ -316,6 +347,31 @@ impl<C: Component + Reflect + TypePath> FromType<C> for ReflectComponent {
                     entity.insert(component);
                 }
             },
+            apply_or_insert_mapped: |entity, reflected_component, registry, mut mapper| {
+                if C::Mutability::MUTABLE {
+                    // SAFETY: guard ensures `C` is a mutable component
+                    if let Some(mut component) = unsafe { entity.get_mut_assume_mutable::<C>() } {
+                        component.apply(reflected_component.as_partial_reflect());
+                        C::map_entities(&mut *component, &mut mapper);
+                        map_marked_entities(component.as_partial_reflect_mut(), mapper);
+                        return;
+                    }
+                }
+                let mut component = entity.world_scope(|world| {
+                    from_reflect_with_fallback::<C>(reflected_component, world, registry)
+                });
+                C::map_entities(&mut component, &mut mapper);
+                map_marked_entities(component.as_partial_reflect_mut(), mapper);
+                entity.insert(component);
+            },
+            map_entities: |reflected_component, mut mapper| {
+                let Some(component) = reflected_component.downcast_mut::<C>() else {
+                    let name = ShortName::of::<C>();
+                    panic!("Cannot call `ReflectComponent::map_entities` for component {name} on a value of another type");
+                };
+                C::map_entities(component, &mut mapper);
+                map_marked_entities(component.as_partial_reflect_mut(), mapper);
+            },
             remove: |entity| {
                 entity.remove::<C>();
             },
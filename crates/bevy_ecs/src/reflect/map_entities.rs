@@ -1,5 +1,5 @@
-use crate::entity::{EntityMapper, MapEntities};
-use bevy_reflect::{FromReflect, FromType, PartialReflect};
+use crate::entity::{Entity, EntityMapper, MapEntities};
+use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect, ReflectMut, TypeInfo};
 
 /// For a specific type of value, this maps any fields with values of type [`Entity`] to a new world.
 ///
@@ -36,3 +36,189 @@ impl<C: FromReflect + MapEntities> FromType<C> for ReflectMapEntities {
         }
     }
 }
+
+/// A custom attribute marking a reflected field of a component as holding [`Entity`] references.
+///
+/// The entities of the marked fields are mapped by [`ReflectComponent::apply_or_insert_mapped`]
+/// and [`ReflectComponent::map_entities`], for example when spawning a scene, without the
+/// component implementing [`MapEntities`]. Every [`Entity`] in a marked field is mapped with
+/// [`map_reflect_entities`], however deeply it's nested in collections.
+///
+/// A field mapped by `#[entities]` should not be marked, or its entities would be mapped twice.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, reflect::EntityReferences};
+/// # use bevy_reflect::Reflect;
+/// # use bevy_utils::HashMap;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Squad {
+///     #[reflect(@EntityReferences)]
+///     members_by_role: HashMap<String, Vec<Entity>>,
+/// }
+/// ```
+///
+/// [`ReflectComponent::apply_or_insert_mapped`]: crate::reflect::ReflectComponent::apply_or_insert_mapped
+/// [`ReflectComponent::map_entities`]: crate::reflect::ReflectComponent::map_entities
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EntityReferences;
+
+/// Maps every [`Entity`] in the reflected `value` with `mapper`.
+///
+/// The entities are found in the fields of structs, tuples and enums, the items of lists,
+/// arrays and sets, and the keys and values of maps, recursively.
+pub fn map_reflect_entities(value: &mut dyn PartialReflect, mapper: &mut dyn EntityMapper) {
+    if let Some(entity) = value.try_downcast_mut::<Entity>() {
+        *entity = mapper.map_entity(*entity);
+        return;
+    }
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                map_reflect_entities(value.field_at_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                map_reflect_entities(value.field_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                map_reflect_entities(value.field_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::List(value) => {
+            for index in 0..value.len() {
+                map_reflect_entities(value.get_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                map_reflect_entities(value.get_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::Map(value) => {
+            // Keys can't be mutated in place, so the entries are reinserted.
+            for (mut key, mut entry) in value.drain() {
+                map_reflect_entities(&mut *key, mapper);
+                map_reflect_entities(&mut *entry, mapper);
+                value.insert_boxed(key, entry);
+            }
+        }
+        ReflectMut::Set(value) => {
+            for mut item in value.drain() {
+                map_reflect_entities(&mut *item, mapper);
+                value.insert_boxed(item);
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                map_reflect_entities(value.field_at_mut(index).unwrap(), mapper);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Maps the [`Entity`] references of the fields of `value` marked with [`EntityReferences`].
+pub(crate) fn map_marked_entities(value: &mut dyn PartialReflect, mapper: &mut dyn EntityMapper) {
+    let Some(type_info) = value.get_represented_type_info() else {
+        return;
+    };
+    match (type_info, value.reflect_mut()) {
+        (TypeInfo::Struct(info), ReflectMut::Struct(value)) => {
+            for (index, field) in info.iter().enumerate() {
+                if field.has_attribute::<EntityReferences>() {
+                    if let Some(field) = value.field_at_mut(index) {
+                        map_reflect_entities(field, mapper);
+                    }
+                }
+            }
+        }
+        (TypeInfo::TupleStruct(info), ReflectMut::TupleStruct(value)) => {
+            for (index, field) in info.iter().enumerate() {
+                if field.has_attribute::<EntityReferences>() {
+                    if let Some(field) = value.field_mut(index) {
+                        map_reflect_entities(field, mapper);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bevy_reflect::std_traits::ReflectDefault;
+    use bevy_utils::{HashMap, HashSet};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Links {
+        #[reflect(@EntityReferences)]
+        by_name: HashMap<String, Vec<Entity>>,
+        #[reflect(@EntityReferences)]
+        set: HashSet<Entity>,
+        #[reflect(@EntityReferences)]
+        optional: Option<Entity>,
+        unmarked: Option<Entity>,
+    }
+
+    struct OffsetMapper;
+
+    impl EntityMapper for OffsetMapper {
+        fn map_entity(&mut self, entity: Entity) -> Entity {
+            Entity::from_raw(entity.index() + 100)
+        }
+    }
+
+    #[test]
+    fn map_marked_fields() {
+        let mut world = World::default();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Links>();
+        world.insert_resource(type_registry.clone());
+
+        let links = Links {
+            by_name: HashMap::from_iter([("a".to_string(), vec![Entity::from_raw(1)])]),
+            set: HashSet::from_iter([Entity::from_raw(2)]),
+            optional: Some(Entity::from_raw(3)),
+            unmarked: Some(Entity::from_raw(4)),
+        };
+        let entity = world.spawn_empty().id();
+        let type_registry = type_registry.read();
+        let reflect_component = type_registry
+            .get_type_data::<ReflectComponent>(core::any::TypeId::of::<Links>())
+            .unwrap();
+        reflect_component.apply_or_insert_mapped(
+            &mut world.entity_mut(entity),
+            &links,
+            &type_registry,
+            &mut OffsetMapper,
+        );
+
+        assert_eq!(
+            world.get::<Links>(entity),
+            Some(&Links {
+                by_name: HashMap::from_iter([("a".to_string(), vec![Entity::from_raw(101)])]),
+                set: HashSet::from_iter([Entity::from_raw(102)]),
+                optional: Some(Entity::from_raw(103)),
+                unmarked: Some(Entity::from_raw(4)),
+            })
+        );
+    }
+}
@@ -17,6 +17,7 @@ mod component;
 mod entity_commands;
 mod from_world;
 mod map_entities;
+mod query;
 mod resource;
 mod visit_entities;
 
@@ -24,7 +25,10 @@ pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
-pub use map_entities::ReflectMapEntities;
+pub use map_entities::{map_reflect_entities, EntityReferences, ReflectMapEntities};
+#[cfg(feature = "serialize")]
+pub use query::ReflectQueryRowSerializer;
+pub use query::{QueryDescriptor, QueryDescriptorError, ReflectQuery, ReflectQueryRow};
 pub use resource::{ReflectResource, ReflectResourceFns};
 pub use visit_entities::{ReflectVisitEntities, ReflectVisitEntitiesMut};
 
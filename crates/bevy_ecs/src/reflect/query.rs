@@ -0,0 +1,455 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_reflect::{Reflect, TypeRegistry};
+use thiserror::Error;
+
+use crate::{
+    component::ComponentId,
+    entity::Entity,
+    query::{QueryBuilder, QueryState},
+    reflect::ReflectComponent,
+    world::{FilteredEntityRef, World},
+};
+
+/// A query described as data, by the [type paths] of the components it accesses.
+///
+/// This is meant for tools that receive queries at runtime, e.g. over the network, and can't name
+/// the component types statically. [`build`](Self::build) turns it into a [`ReflectQuery`] by
+/// looking the components up in a [`TypeRegistry`], and with the `serialize` feature both the
+/// descriptor and the returned rows can be serialized.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, reflect::{QueryDescriptor, ReflectComponent}};
+/// # use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+/// #
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Dead;
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+/// registry.register::<Dead>();
+///
+/// let mut world = World::new();
+/// world.spawn(Health(10));
+/// world.spawn((Health(0), Dead));
+///
+/// let descriptor = QueryDescriptor::default()
+///     .component(Health::type_path())
+///     .without(Dead::type_path());
+/// let mut query = descriptor.build(&mut world, &registry).unwrap();
+///
+/// let rows = query.iter(&world).collect::<Vec<_>>();
+/// assert_eq!(rows.len(), 1);
+/// let (_, health) = rows[0].components[0];
+/// assert_eq!(health.downcast_ref::<Health>().unwrap().0, 10);
+/// ```
+///
+/// [type paths]: bevy_reflect::TypePath::type_path
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct QueryDescriptor {
+    /// The components every matched entity must have, and whose values are returned.
+    pub components: Vec<String>,
+    /// The components whose values are returned if the matched entity has them.
+    pub optional: Vec<String>,
+    /// The components every matched entity must have, without returning their values.
+    pub with: Vec<String>,
+    /// The components no matched entity may have.
+    pub without: Vec<String>,
+}
+
+impl QueryDescriptor {
+    /// Adds a component that is required and whose value is returned.
+    pub fn component(mut self, type_path: impl Into<String>) -> Self {
+        self.components.push(type_path.into());
+        self
+    }
+
+    /// Adds a component whose value is returned if present.
+    pub fn optional(mut self, type_path: impl Into<String>) -> Self {
+        self.optional.push(type_path.into());
+        self
+    }
+
+    /// Adds a component that is required but whose value isn't returned.
+    pub fn with(mut self, type_path: impl Into<String>) -> Self {
+        self.with.push(type_path.into());
+        self
+    }
+
+    /// Adds a component that matched entities may not have.
+    pub fn without(mut self, type_path: impl Into<String>) -> Self {
+        self.without.push(type_path.into());
+        self
+    }
+
+    /// Builds the described query for `world`, looking up its components in `registry`.
+    ///
+    /// Components that are registered in `registry` but not yet in `world` are registered in `world`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a type path isn't registered in `registry`, or if its type doesn't
+    /// have a [`ReflectComponent`] registration.
+    pub fn build(
+        &self,
+        world: &mut World,
+        registry: &TypeRegistry,
+    ) -> Result<ReflectQuery, QueryDescriptorError> {
+        let components = resolve(&self.components, world, registry)?;
+        let optional = resolve(&self.optional, world, registry)?;
+        let with = resolve(&self.with, world, registry)?;
+        let without = resolve(&self.without, world, registry)?;
+
+        let mut builder = QueryBuilder::<FilteredEntityRef<'static>>::new(world);
+        for (_, _, id) in &components {
+            builder.ref_id(*id);
+        }
+        for (_, _, id) in &optional {
+            builder.optional(|builder| {
+                builder.ref_id(*id);
+            });
+        }
+        for (_, _, id) in with {
+            builder.with_id(id);
+        }
+        for (_, _, id) in without {
+            builder.without_id(id);
+        }
+
+        Ok(ReflectQuery {
+            state: builder.build(),
+            fetched: components
+                .into_iter()
+                .chain(optional)
+                .map(|(type_path, reflect_component, _)| (type_path, reflect_component))
+                .collect(),
+        })
+    }
+}
+
+fn resolve(
+    type_paths: &[String],
+    world: &mut World,
+    registry: &TypeRegistry,
+) -> Result<Vec<(&'static str, ReflectComponent, ComponentId)>, QueryDescriptorError> {
+    type_paths
+        .iter()
+        .map(|type_path| {
+            let registration = registry
+                .get_with_type_path(type_path)
+                .ok_or_else(|| QueryDescriptorError::UnregisteredType(type_path.clone()))?;
+            let reflect_component = registration
+                .data::<ReflectComponent>()
+                .ok_or_else(|| QueryDescriptorError::NotAComponent(type_path.clone()))?
+                .clone();
+            let id = reflect_component.register_component(world);
+            Ok((registration.type_info().type_path(), reflect_component, id))
+        })
+        .collect()
+}
+
+/// An error that occurs when building a [`QueryDescriptor`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum QueryDescriptorError {
+    /// The type path isn't registered in the type registry.
+    #[error("The type `{0}` is not registered in the type registry")]
+    UnregisteredType(String),
+    /// The type is registered, but without [`ReflectComponent`].
+    #[error(
+        "The type `{0}` is not registered as a component, consider adding `#[reflect(Component)]`"
+    )]
+    NotAComponent(String),
+}
+
+/// A [`QueryState`] built from a [`QueryDescriptor`], which returns its components as reflected values.
+pub struct ReflectQuery {
+    state: QueryState<FilteredEntityRef<'static>>,
+    fetched: Vec<(&'static str, ReflectComponent)>,
+}
+
+impl ReflectQuery {
+    /// Returns the underlying [`QueryState`].
+    pub fn state(&self) -> &QueryState<FilteredEntityRef<'static>> {
+        &self.state
+    }
+
+    /// Returns the underlying [`QueryState`] mutably.
+    pub fn state_mut(&mut self) -> &mut QueryState<FilteredEntityRef<'static>> {
+        &mut self.state
+    }
+
+    /// Returns the matched entities with the values of their fetched components.
+    pub fn iter<'w, 's>(
+        &'s mut self,
+        world: &'w World,
+    ) -> impl Iterator<Item = ReflectQueryRow<'w>> + 's
+    where
+        'w: 's,
+    {
+        let fetched = &self.fetched;
+        self.state.iter(world).map(move |entity| ReflectQueryRow {
+            entity: entity.id(),
+            components: fetched
+                .iter()
+                .filter_map(|(type_path, reflect_component)| {
+                    Some((*type_path, reflect_component.reflect(entity.clone())?))
+                })
+                .collect(),
+        })
+    }
+}
+
+/// A single result of a [`ReflectQuery`].
+#[derive(Debug, Clone)]
+pub struct ReflectQueryRow<'w> {
+    /// The matched entity.
+    pub entity: Entity,
+    /// The type path and value of each fetched component the entity has, in the order they are
+    /// listed in the [`QueryDescriptor`], with required components before optional ones.
+    pub components: Vec<(&'static str, &'w dyn Reflect)>,
+}
+
+impl ReflectQueryRow<'_> {
+    /// Returns the value of the component with the given type path, if it was fetched.
+    pub fn get(&self, type_path: &str) -> Option<&dyn Reflect> {
+        self.components
+            .iter()
+            .find(|(path, _)| *path == type_path)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the type paths of the fetched components.
+    pub fn type_paths(&self) -> impl Iterator<Item = String> + '_ {
+        self.components.iter().map(|(path, _)| path.to_string())
+    }
+}
+
+#[cfg(feature = "serialize")]
+mod serialize {
+    use super::ReflectQueryRow;
+    use bevy_reflect::{serde::TypedReflectSerializer, TypeRegistry};
+    use serde::{
+        ser::{SerializeMap, SerializeStruct},
+        Serialize, Serializer,
+    };
+
+    /// Serializes a [`ReflectQueryRow`] as a struct with an `entity` field and a `components`
+    /// map from type paths to component values.
+    pub struct ReflectQueryRowSerializer<'a, 'w> {
+        row: &'a ReflectQueryRow<'w>,
+        registry: &'a TypeRegistry,
+    }
+
+    impl<'a, 'w> ReflectQueryRowSerializer<'a, 'w> {
+        /// Creates a serializer for `row`, using `registry` to serialize its component values.
+        pub fn new(row: &'a ReflectQueryRow<'w>, registry: &'a TypeRegistry) -> Self {
+            Self { row, registry }
+        }
+    }
+
+    impl Serialize for ReflectQueryRowSerializer<'_, '_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("ReflectQueryRow", 2)?;
+            state.serialize_field("entity", &self.row.entity)?;
+            state.serialize_field("components", &ComponentsSerializer(self))?;
+            state.end()
+        }
+    }
+
+    struct ComponentsSerializer<'a, 'b, 'w>(&'b ReflectQueryRowSerializer<'a, 'w>);
+
+    impl Serialize for ComponentsSerializer<'_, '_, '_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let components = &self.0.row.components;
+            let mut state = serializer.serialize_map(Some(components.len()))?;
+            for (type_path, value) in components {
+                state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::new(value.as_partial_reflect(), self.0.registry),
+                )?;
+            }
+            state.end()
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+pub use serialize::ReflectQueryRowSerializer;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        reflect::{QueryDescriptor, QueryDescriptorError, ReflectComponent},
+    };
+    use alloc::{string::ToString, vec::Vec};
+    use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct A(u32);
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct B(u32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct C;
+
+    #[derive(Reflect)]
+    struct NotAComponent;
+
+    #[test]
+    fn query_descriptor() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<A>();
+        registry.register::<B>();
+        registry.register::<C>();
+        registry.register::<NotAComponent>();
+
+        let mut world = World::new();
+        let e1 = world.spawn((A(1), B(1))).id();
+        let e2 = world.spawn(A(2)).id();
+        world.spawn((A(3), C));
+        world.spawn(B(4));
+
+        let descriptor = QueryDescriptor::default()
+            .component(A::type_path())
+            .optional(B::type_path())
+            .without(C::type_path());
+        let mut query = descriptor.build(&mut world, &registry).unwrap();
+        let mut rows = query.iter(&world).collect::<Vec<_>>();
+        rows.sort_by_key(|row| row.entity);
+
+        assert_eq!(
+            rows.iter().map(|row| row.entity).collect::<Vec<_>>(),
+            [e1, e2]
+        );
+        assert_eq!(rows[0].components.len(), 2);
+        assert_eq!(
+            rows[0].get(B::type_path()).unwrap().downcast_ref::<B>(),
+            Some(&B(1))
+        );
+        assert_eq!(
+            rows[1].type_paths().collect::<Vec<_>>(),
+            [A::type_path().to_string()]
+        );
+
+        assert_eq!(
+            QueryDescriptor::default()
+                .with("unknown::Type")
+                .build(&mut world, &registry)
+                .err(),
+            Some(QueryDescriptorError::UnregisteredType(
+                "unknown::Type".to_string()
+            ))
+        );
+        assert_eq!(
+            QueryDescriptor::default()
+                .with(NotAComponent::type_path())
+                .build(&mut world, &registry)
+                .err(),
+            Some(QueryDescriptorError::NotAComponent(
+                NotAComponent::type_path().to_string()
+            ))
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod serde_tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        reflect::{QueryDescriptor, ReflectComponent, ReflectQueryRowSerializer},
+    };
+    use alloc::vec::Vec;
+    use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+    use serde_test::{assert_ser_tokens, assert_tokens, Token};
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct A(u32);
+
+    #[test]
+    fn serialize_query_descriptor() {
+        let descriptor = QueryDescriptor::default().component("a::A").without("b::B");
+        assert_tokens(
+            &descriptor,
+            &[
+                Token::Struct {
+                    name: "QueryDescriptor",
+                    len: 4,
+                },
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Str("a::A"),
+                Token::SeqEnd,
+                Token::Str("optional"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("with"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("without"),
+                Token::Seq { len: Some(1) },
+                Token::Str("b::B"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn serialize_query_row() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<A>();
+
+        let mut world = World::new();
+        let entity = world.spawn(A(7)).id();
+
+        let mut query = QueryDescriptor::default()
+            .component(A::type_path())
+            .build(&mut world, &registry)
+            .unwrap();
+        let rows = query.iter(&world).collect::<Vec<_>>();
+        assert_ser_tokens(
+            &ReflectQueryRowSerializer::new(&rows[0], &registry),
+            &[
+                Token::Struct {
+                    name: "ReflectQueryRow",
+                    len: 2,
+                },
+                Token::Str("entity"),
+                Token::U64(entity.to_bits()),
+                Token::Str("components"),
+                Token::Map { len: Some(1) },
+                Token::Str(A::type_path()),
+                Token::NewtypeStruct { name: "A" },
+                Token::U32(7),
+                Token::MapEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Relationships between entities, with an automatically maintained reverse index.
+//!
+//! A relationship is made of two components:
+//!
+//! - A [`Relationship`] component, which lives on the "source" entity and stores the [`Entity`]
+//!   it points to, for example `Likes(bob)`.
+//! - A [`RelationshipTarget`] component, which lives on the "target" entity and stores every
+//!   entity that currently points to it, for example `LikedBy(vec![alice, carol])`.
+//!
+//! Only the [`Relationship`] side should be written to by users. Its component hooks keep the
+//! [`RelationshipTarget`] in sync: inserting or replacing the relationship adds or removes the
+//! source from the target's collection, and removing the [`RelationshipTarget`] (including by
+//! despawning the target) removes the [`Relationship`] from all of its sources.
+//!
+//! Both sides can be derived with the [`Component`] derive:
+//!
+//! ```
+//! # use bevy_ecs::{prelude::*, relationship::RelationshipTarget};
+//! #[derive(Component)]
+//! #[relationship(relationship_target = LikedBy)]
+//! struct Likes(Entity);
+//!
+//! #[derive(Component)]
+//! #[relationship_target(relationship = Likes)]
+//! struct LikedBy(Vec<Entity>);
+//!
+//! let mut world = World::new();
+//! let bob = world.spawn_empty().id();
+//! let alice = world.spawn(Likes(bob)).id();
+//! world.flush();
+//!
+//! assert_eq!(&[alice], world.get::<LikedBy>(bob).unwrap().collection().as_slice());
+//! ```
+//!
+//! [`Relationship`] components are immutable, so the only way to change a relationship is to
+//! insert a new value, which keeps the reverse index correct.
+
+use crate::{
+    component::{Component, ComponentId, Immutable, Mutable},
+    entity::Entity,
+    world::{DeferredWorld, World},
+};
+use alloc::{vec, vec::Vec};
+use log::warn;
+
+/// A [`Component`] on a "source" entity that points to a "target" entity.
+///
+/// The target entity automatically receives a [`Relationship::RelationshipTarget`] component
+/// listing every source pointing at it. See the [module docs](crate::relationship) for details.
+///
+/// When implementing this manually, [`Relationship::on_insert`] and [`Relationship::on_replace`]
+/// must be registered as the component's `on_insert` and `on_replace` hooks.
+pub trait Relationship: Component<Mutability = Immutable> + Sized {
+    /// The [`Component`] added to the target entities of this relationship.
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] this relationship points to.
+    fn get(&self) -> Entity;
+
+    /// Creates this relationship pointing to the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that adds the source to its target's collection.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if target_entity == entity {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+
+        if let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+            if let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            {
+                relationship_target.collection_mut_risky().push(entity);
+                return;
+            }
+        } else {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+
+        // The target does not have a collection yet, and inserting one is a structural change.
+        // Other sources may be related to the same target before the command is applied, so the
+        // command re-checks everything against the world at that point.
+        world.commands().queue(move |world: &mut World| {
+            let still_related = world
+                .get::<Self>(entity)
+                .is_some_and(|relationship| relationship.get() == target_entity);
+            if !still_related {
+                return;
+            }
+            let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+                return;
+            };
+            if let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            {
+                if !relationship_target.collection().contains(&entity) {
+                    relationship_target.collection_mut_risky().push(entity);
+                }
+            } else {
+                target_entity_mut.insert(
+                    <Self::RelationshipTarget as RelationshipTarget>::from_collection_risky(vec![
+                        entity,
+                    ]),
+                );
+            }
+        });
+    }
+
+    /// The `on_replace` component hook that removes the source from its target's collection.
+    ///
+    /// If the collection becomes empty, the [`RelationshipTarget`] is removed from the target.
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+            return;
+        };
+        let Some(mut relationship_target) = target_entity_mut.get_mut::<Self::RelationshipTarget>()
+        else {
+            return;
+        };
+        relationship_target
+            .collection_mut_risky()
+            .retain(|source| *source != entity);
+        if relationship_target.is_empty() {
+            world.commands().queue(move |world: &mut World| {
+                let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+                    return;
+                };
+                if target_entity_mut
+                    .get::<Self::RelationshipTarget>()
+                    .is_some_and(RelationshipTarget::is_empty)
+                {
+                    target_entity_mut.remove::<Self::RelationshipTarget>();
+                }
+            });
+        }
+    }
+}
+
+/// A [`Component`] on a "target" entity listing every source entity whose
+/// [`RelationshipTarget::Relationship`] points to it.
+///
+/// This component is maintained by the hooks of its [`Relationship`] and should not be inserted
+/// or mutated directly. See the [module docs](crate::relationship) for details.
+///
+/// When implementing this manually, [`RelationshipTarget::on_replace`] must be registered as the
+/// component's `on_replace` hook.
+pub trait RelationshipTarget: Component<Mutability = Mutable> + Sized {
+    /// The [`Relationship`] that populates this collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// Returns the entities currently related to this target.
+    fn collection(&self) -> &Vec<Entity>;
+
+    /// Returns a mutable reference to the related entities.
+    ///
+    /// Modifying the collection directly will desynchronize it from the [`Relationship`]
+    /// components on the source entities.
+    fn collection_mut_risky(&mut self) -> &mut Vec<Entity>;
+
+    /// Creates this component from a collection of related entities.
+    ///
+    /// The collection must match the [`Relationship`] components on the source entities.
+    fn from_collection_risky(collection: Vec<Entity>) -> Self;
+
+    /// Iterates over the entities currently related to this target.
+    #[inline]
+    fn iter(&self) -> impl DoubleEndedIterator<Item = Entity> + ExactSizeIterator + '_ {
+        self.collection().iter().copied()
+    }
+
+    /// Returns the number of entities currently related to this target.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns `true` if no entities are related to this target.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_replace` component hook that removes the [`Relationship`] from every source when
+    /// this component is removed, replaced or its entity is despawned.
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources = world
+            .entity(entity)
+            .get::<Self>()
+            .unwrap()
+            .collection()
+            .clone();
+        world.commands().queue(move |world: &mut World| {
+            for source in sources {
+                let Ok(mut source_mut) = world.get_entity_mut(source) else {
+                    continue;
+                };
+                if source_mut
+                    .get::<Self::Relationship>()
+                    .is_some_and(|relationship| relationship.get() == entity)
+                {
+                    source_mut.remove::<Self::Relationship>();
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::Entity,
+        relationship::{Relationship, RelationshipTarget},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[relationship(relationship_target = LikedBy)]
+    struct Likes(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Likes)]
+    struct LikedBy(Vec<Entity>);
+
+    fn liked_by(world: &World, entity: Entity) -> Option<Vec<Entity>> {
+        world
+            .get::<LikedBy>(entity)
+            .map(|liked_by| liked_by.iter().collect())
+    }
+
+    #[test]
+    fn relationship_updates_target() {
+        let mut world = World::new();
+        let bob = world.spawn_empty().id();
+        let alice = world.spawn(Likes(bob)).id();
+        let carol = world.spawn(Likes(bob)).id();
+        world.flush();
+
+        assert_eq!(Some(vec![alice, carol]), liked_by(&world, bob));
+        assert_eq!(bob, world.get::<Likes>(alice).unwrap().get());
+
+        // Retargeting moves the source to the new target's collection.
+        let dave = world.spawn_empty().id();
+        world.entity_mut(alice).insert(Likes(dave));
+        world.flush();
+        assert_eq!(Some(vec![carol]), liked_by(&world, bob));
+        assert_eq!(Some(vec![alice]), liked_by(&world, dave));
+
+        // Removing the last source removes the target component.
+        world.entity_mut(carol).remove::<Likes>();
+        world.flush();
+        assert_eq!(None, liked_by(&world, bob));
+    }
+
+    #[test]
+    fn despawn_updates_relationships() {
+        let mut world = World::new();
+        let bob = world.spawn_empty().id();
+        let alice = world.spawn(Likes(bob)).id();
+        let carol = world.spawn(Likes(bob)).id();
+        world.flush();
+
+        world.despawn(alice);
+        world.flush();
+        assert_eq!(Some(vec![carol]), liked_by(&world, bob));
+
+        world.despawn(bob);
+        world.flush();
+        assert!(world.get::<Likes>(carol).is_none());
+    }
+
+    #[test]
+    fn invalid_relationships_are_removed() {
+        let mut world = World::new();
+        let alice = world.spawn_empty().id();
+        world.entity_mut(alice).insert(Likes(alice));
+        world.flush();
+        assert!(world.get::<Likes>(alice).is_none());
+        assert_eq!(None, liked_by(&world, alice));
+
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let bob = world.spawn(Likes(missing)).id();
+        world.flush();
+        assert!(world.get::<Likes>(bob).is_none());
+    }
+}
@@ -4,11 +4,14 @@ use crate::{
     self as bevy_ecs,
     component::{Component, ComponentId, ComponentIdFor, Tick},
     entity::Entity,
-    event::{Event, EventCursor, EventId, EventIterator, EventIteratorWithId, Events},
+    event::{
+        Event, EventCursor, EventId, EventIterator, EventIteratorWithId, EventRegistry, Events,
+    },
+    observer::Trigger,
     prelude::Local,
     storage::SparseSet,
-    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
-    world::{unsafe_world_cell::UnsafeWorldCell, World},
+    system::{ReadOnlySystemParam, Res, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, OnRemove, World},
 };
 
 use derive_more::derive::Into;
@@ -30,6 +33,18 @@ use core::{
 #[cfg_attr(feature = "bevy_reflect", reflect(Debug))]
 pub struct RemovedComponentEntity(Entity);
 
+/// The value of a `T` component at the moment it was removed from an entity.
+///
+/// These are only recorded for components tracked with [`World::track_removed_values`],
+/// and can be read with [`RemovedComponents::read_with_values`].
+#[derive(Event, Debug, Clone)]
+pub struct RemovedComponentValue<T: Component> {
+    /// The entity the component was removed from.
+    pub entity: Entity,
+    /// A clone of the removed component.
+    pub value: T,
+}
+
 /// Wrapper around a [`EventCursor<RemovedComponentEntity>`] so that we
 /// can differentiate events between components.
 #[derive(Debug)]
@@ -109,10 +124,9 @@ impl RemovedComponentEvents {
 ///
 /// This acts effectively the same as an [`EventReader`](crate::event::EventReader).
 ///
-/// Note that this does not allow you to see which data existed before removal.
-/// If you need this, you will need to track the component data value on your own,
-/// using a regularly scheduled system that requests `Query<(Entity, &T), Changed<T>>`
-/// and stores the data somewhere safe to later cross-reference.
+/// Note that by default this does not allow you to see which data existed before removal.
+/// If you need this, opt in with [`World::track_removed_values`] and use
+/// [`RemovedComponents::read_with_values`].
 ///
 /// If you are using `bevy_ecs` as a standalone crate,
 /// note that the `RemovedComponents` list will not be automatically cleared for you,
@@ -143,6 +157,8 @@ pub struct RemovedComponents<'w, 's, T: Component> {
     component_id: ComponentIdFor<'s, T>,
     reader: Local<'s, RemovedComponentReader<T>>,
     event_sets: &'w RemovedComponentEvents,
+    values: Option<Res<'w, Events<RemovedComponentValue<T>>>>,
+    value_reader: Local<'s, EventCursor<RemovedComponentValue<T>>>,
 }
 
 /// Iterator over entities that had a specific component removed.
@@ -223,6 +239,22 @@ impl<'w, 's, T: Component> RemovedComponents<'w, 's, T> {
             .map(map_id_events)
     }
 
+    /// Iterates over the removed values this [`RemovedComponents`] has not seen yet, along with
+    /// the entity they were removed from.
+    ///
+    /// This only yields values for components tracked with [`World::track_removed_values`].
+    /// The values are read with a separate counter, so this does not consume the events
+    /// returned by [`read`](Self::read) and vice versa.
+    pub fn read_with_values(&mut self) -> impl Iterator<Item = (Entity, &T)> + '_ {
+        let reader = &mut *self.value_reader;
+        self.values
+            .as_deref()
+            .map(|events| reader.read(events))
+            .into_iter()
+            .flatten()
+            .map(|removed| (removed.entity, &removed.value))
+    }
+
     /// Determines the number of removal events available to be read from this [`RemovedComponents`] without consuming any.
     pub fn len(&self) -> usize {
         self.events()
@@ -247,6 +279,50 @@ impl<'w, 's, T: Component> RemovedComponents<'w, 's, T> {
     }
 }
 
+impl World {
+    /// Records a clone of every `T` component removed from an entity, including on despawn,
+    /// so that it can be read with [`RemovedComponents::read_with_values`].
+    ///
+    /// This is useful for cleanup systems that need the old data, for example a handle to
+    /// release, which is gone by the time they run.
+    ///
+    /// The values are stored as [`RemovedComponentValue<T>`] events and are buffered like any
+    /// other event registered in the [`EventRegistry`]. Calling this more than once has no
+    /// additional effect.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::RunSystemOnce;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Handle(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.track_removed_values::<Handle>();
+    /// let entity = world.spawn(Handle(7)).id();
+    /// world.despawn(entity);
+    ///
+    /// world.run_system_once(move |mut removed: RemovedComponents<Handle>| {
+    ///     let values: Vec<_> = removed.read_with_values().collect();
+    ///     assert_eq!(values, [(entity, &Handle(7))]);
+    /// });
+    /// ```
+    pub fn track_removed_values<T: Component + Clone>(&mut self) {
+        if self.contains_resource::<Events<RemovedComponentValue<T>>>() {
+            return;
+        }
+        EventRegistry::register_event::<RemovedComponentValue<T>>(self);
+        self.add_observer(|trigger: Trigger<OnRemove, T>, mut world: DeferredWorld| {
+            let entity = trigger.target();
+            let Some(value) = world.entity(entity).get::<T>().cloned() else {
+                return;
+            };
+            world
+                .resource_mut::<Events<RemovedComponentValue<T>>>()
+                .send(RemovedComponentValue { entity, value });
+        });
+    }
+}
+
 // SAFETY: Only reads World removed component events
 unsafe impl<'a> ReadOnlySystemParam for &'a RemovedComponentEvents {}
 
@@ -1,9 +1,297 @@
 //! Contains error and result helpers for use in fallible systems.
+//!
+//! Systems that return a [`Result`] can use `?` instead of unwrapping. When such a system returns
+//! an error, the [`Schedule`](crate::schedule::Schedule) running it hands the error to a
+//! [`SystemErrorHandler`], which decides what happens next. The handler of a schedule is chosen,
+//! in order of priority, by:
+//!
+//! 1. [`Schedule::set_error_handler`](crate::schedule::Schedule::set_error_handler),
+//! 2. the [`DefaultSystemErrorHandler`] resource,
+//! 3. falling back to [`panic`].
+//!
+//! This module provides handlers that [`panic`], [`ignore`] the error, or log it at a given
+//! level ([`error`], [`warn`], [`info`], [`debug`], [`trace`]). Logging and ignoring handlers keep
+//! running the rest of the schedule.
+//!
+//! Panics in systems can be handled the same way, to keep a long-running app alive when a single
+//! system fails: if [`Schedule::set_catch_panics`](crate::schedule::Schedule::set_catch_panics) or
+//! the [`CatchSystemPanics`] resource enables it, a panic is caught and passed to the handler as a
+//! [`SystemPanic`]. This requires the `std` feature.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::result;
+//! #[derive(Resource)]
+//! struct Config(String);
+//!
+//! fn parse_config(config: Res<Config>) -> Result<(), BevyError> {
+//!     let value: u32 = config.0.parse()?;
+//!     assert_eq!(value, 3);
+//!     Ok(())
+//! }
+//!
+//! let mut world = World::new();
+//! world.insert_resource(Config("not a number".into()));
+//! world.insert_resource(result::DefaultSystemErrorHandler::new(result::warn));
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(parse_config);
+//! // Logs a warning instead of panicking.
+//! schedule.run(&mut world);
+//! ```
 
-use alloc::boxed::Box;
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{any::Any, fmt};
+
+use crate::{component::Tick, system::Resource};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+use crate as bevy_ecs;
 
 /// A dynamic error type for use in fallible systems.
 pub type Error = Box<dyn core::error::Error + Send + Sync + 'static>;
 
+/// An alias of [`Error`], for systems written as `fn system(...) -> Result<(), BevyError>`.
+pub type BevyError = Error;
+
 /// A result type for use in fallible systems.
 pub type Result<T = (), E = Error> = core::result::Result<T, E>;
+
+/// Information about the system that returned an error, passed to a [`SystemErrorHandler`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemErrorContext {
+    /// The name of the system that failed.
+    pub name: Cow<'static, str>,
+    /// The last tick the system was run at.
+    pub last_run: Tick,
+}
+
+/// Handles errors returned by systems. See the [module docs](self).
+///
+/// Any function or closure taking an [`Error`] and a [`SystemErrorContext`] can be converted into
+/// a handler, so a handler can capture state, for example to collect the errors it receives.
+#[derive(Clone)]
+pub struct SystemErrorHandler(HandlerKind);
+
+#[derive(Clone)]
+enum HandlerKind {
+    Fn(fn(Error, SystemErrorContext)),
+    Closure(Arc<dyn Fn(Error, SystemErrorContext) + Send + Sync>),
+}
+
+impl SystemErrorHandler {
+    /// Creates a handler that calls `handler` with each error.
+    pub fn new(handler: impl Fn(Error, SystemErrorContext) + Send + Sync + 'static) -> Self {
+        // `portable-atomic-util` `Arc` is not able to coerce an unsized type like
+        // `std::sync::Arc` can. Creating a `Box` first does the coercion.
+        let boxed: Box<dyn Fn(Error, SystemErrorContext) + Send + Sync> = Box::new(handler);
+        Self(HandlerKind::Closure(Arc::from(boxed)))
+    }
+
+    /// Creates a handler from a function, such as the ones in this module.
+    pub const fn from_fn(handler: fn(Error, SystemErrorContext)) -> Self {
+        Self(HandlerKind::Fn(handler))
+    }
+
+    /// Passes `error`, returned by the system described by `context`, to the handler.
+    pub fn handle(&self, error: Error, context: SystemErrorContext) {
+        match &self.0 {
+            HandlerKind::Fn(handler) => handler(error, context),
+            HandlerKind::Closure(handler) => handler(error, context),
+        }
+    }
+}
+
+impl<F: Fn(Error, SystemErrorContext) + Send + Sync + 'static> From<F> for SystemErrorHandler {
+    fn from(handler: F) -> Self {
+        Self::new(handler)
+    }
+}
+
+impl fmt::Debug for SystemErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SystemErrorHandler").finish_non_exhaustive()
+    }
+}
+
+/// The [`SystemErrorHandler`] used by every [`Schedule`](crate::schedule::Schedule) that doesn't
+/// have its own handler set.
+#[derive(Resource, Debug, Clone)]
+pub struct DefaultSystemErrorHandler(pub SystemErrorHandler);
+
+impl DefaultSystemErrorHandler {
+    /// Creates the resource from any function or closure that can handle errors.
+    pub fn new(handler: impl Fn(Error, SystemErrorContext) + Send + Sync + 'static) -> Self {
+        Self(SystemErrorHandler::new(handler))
+    }
+}
+
+impl Default for DefaultSystemErrorHandler {
+    fn default() -> Self {
+        Self(SystemErrorHandler::from_fn(panic))
+    }
+}
+
+/// Whether every [`Schedule`](crate::schedule::Schedule) that doesn't set it itself catches the
+/// panics of its systems and passes them to its [`SystemErrorHandler`] as a [`SystemPanic`].
+///
+/// Panics are not caught by default.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct CatchSystemPanics(pub bool);
+
+/// The error passed to a [`SystemErrorHandler`] when a system panics, if its schedule catches
+/// panics. See [`CatchSystemPanics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemPanic {
+    message: String,
+}
+
+impl SystemPanic {
+    /// Creates the error from the payload of a panic.
+    pub fn new(payload: Box<dyn Any + Send>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        };
+        Self { message }
+    }
+
+    /// Returns the message the system panicked with.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for SystemPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "system panicked: {}", self.message)
+    }
+}
+
+impl core::error::Error for SystemPanic {}
+
+macro_rules! log_handlers {
+    ($($level:ident),*) => {
+        $(
+            #[doc = concat!("Logs the error with [`log::", stringify!($level), "`] and keeps running the schedule.")]
+            pub fn $level(error: Error, context: SystemErrorContext) {
+                log::$level!("Encountered an error in system `{}`: {:?}", context.name, error);
+            }
+        )*
+    };
+}
+
+log_handlers!(error, warn, info, debug, trace);
+
+/// Panics with the error. This is the default [`SystemErrorHandler`].
+pub fn panic(error: Error, context: SystemErrorContext) {
+    panic!(
+        "Encountered an error in system `{}`: {:?}",
+        context.name, error
+    );
+}
+
+/// Silently ignores the error and keeps running the schedule.
+pub fn ignore(_: Error, _: SystemErrorContext) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        schedule::{ExecutorKind, IntoSystemConfigs, Schedule},
+        system::ResMut,
+        world::World,
+    };
+    use alloc::string::String;
+
+    #[derive(Resource, Default)]
+    struct Ran(usize);
+
+    fn fail() -> Result<(), BevyError> {
+        Err("fail".into())
+    }
+
+    fn count(mut ran: ResMut<Ran>) -> Result {
+        ran.0 += 1;
+        Ok(())
+    }
+
+    fn run_with(configure: impl FnOnce(&mut World, &mut Schedule)) {
+        let mut world = World::new();
+        world.init_resource::<Ran>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems((fail, count).chain());
+        configure(&mut world, &mut schedule);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Ran>().0, 1);
+    }
+
+    #[test]
+    fn default_error_handler_resource() {
+        run_with(|world, _| {
+            world.insert_resource(DefaultSystemErrorHandler::new(warn));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Encountered an error in system")]
+    fn panics_by_default() {
+        run_with(|_, _| {});
+    }
+
+    #[test]
+    fn handler_receives_context() {
+        fn check_context(error: Error, context: SystemErrorContext) {
+            assert_eq!(String::from("fail"), alloc::format!("{error}"));
+            assert!(context.name.ends_with("fail"));
+        }
+
+        run_with(|_, schedule| {
+            schedule.set_error_handler(check_context);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn catches_panics() {
+        fn panic_system() {
+            panic!("boom");
+        }
+
+        fn check_panic(error: Error, context: SystemErrorContext) {
+            let panic = error.downcast_ref::<SystemPanic>().unwrap();
+            assert_eq!(panic.message(), "boom");
+            assert!(context.name.ends_with("panic_system"));
+        }
+
+        for executor in [
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::Simple,
+            ExecutorKind::MultiThreaded,
+        ] {
+            let mut world = World::new();
+            world.init_resource::<Ran>();
+            world.insert_resource(CatchSystemPanics(true));
+            let mut schedule = Schedule::default();
+            schedule
+                .set_executor_kind(executor)
+                .set_error_handler(check_panic)
+                .add_systems((panic_system, count).chain());
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<Ran>().0, 1);
+        }
+    }
+}
@@ -397,7 +397,8 @@ pub mod common_conditions {
     use crate::{
         change_detection::DetectChanges,
         event::{Event, EventReader},
-        prelude::{Component, Query, With},
+        prelude::{Added, Changed, Component, Query, With},
+        query::QueryFilter,
         removal_detection::RemovedComponents,
         system::{In, IntoSystem, Local, Res, Resource, System, SystemInput},
     };
@@ -848,6 +849,9 @@ pub mod common_conditions {
     /// A [`Condition`]-satisfying system that returns `true`
     /// if there are any new events of the given type since it was last called.
     ///
+    /// Like with any run condition, a system skipped this way isn't matched against the archetypes
+    /// created since it last ran, so systems that only react to rare events cost almost nothing.
+    ///
     /// # Example
     ///
     /// ```
@@ -926,6 +930,107 @@ pub mod common_conditions {
         !query.is_empty()
     }
 
+    /// A [`Condition`]-satisfying system that returns `true`
+    /// if a component of the given type was added to any entity since the condition last ran.
+    ///
+    /// Use [`any_match_filter`] to react to several component types at once.
+    pub fn any_component_added<T: Component>(query: Query<(), Added<T>>) -> bool {
+        !query.is_empty()
+    }
+
+    /// A [`Condition`]-satisfying system that returns `true`
+    /// if a component of the given type was added or changed on any entity since the condition last ran.
+    ///
+    /// Use [`any_match_filter`] to react to several component types at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource, Default)]
+    /// # struct Counter(u8);
+    /// # let mut app = Schedule::default();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// app.add_systems(
+    ///     my_system.run_if(any_component_changed::<MyComponent>),
+    /// );
+    ///
+    /// #[derive(Component)]
+    /// struct MyComponent(u32);
+    ///
+    /// fn my_system(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// // No `MyComponent` has changed yet, so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// let entity = world.spawn(MyComponent(0)).id();
+    ///
+    /// // A `MyComponent` was added, so `my_system` will run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    ///
+    /// // Nothing changed since the last run, so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    ///
+    /// world.get_mut::<MyComponent>(entity).unwrap().0 = 1;
+    ///
+    /// // The component was mutated, so `my_system` will run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 2);
+    /// ```
+    pub fn any_component_changed<T: Component>(query: Query<(), Changed<T>>) -> bool {
+        !query.is_empty()
+    }
+
+    /// A [`Condition`]-satisfying system that returns `true`
+    /// if any entity matches the query filter `F`.
+    ///
+    /// This is useful with change detection filters, for example
+    /// `any_match_filter::<Or<(Changed<A>, Changed<B>)>>` only runs a system
+    /// when an `A` or a `B` changed anywhere since the condition last ran.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource, Default)]
+    /// # struct Counter(u8);
+    /// # let mut app = Schedule::default();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// app.add_systems(
+    ///     my_system.run_if(any_match_filter::<Or<(Changed<A>, Changed<B>)>>),
+    /// );
+    ///
+    /// #[derive(Component)]
+    /// struct A;
+    ///
+    /// #[derive(Component)]
+    /// struct B;
+    ///
+    /// fn my_system(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// // Neither an `A` nor a `B` changed, so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// world.spawn(B);
+    ///
+    /// // A `B` was added, so `my_system` will run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    /// ```
+    pub fn any_match_filter<F: QueryFilter>(query: Query<(), F>) -> bool {
+        !query.is_empty()
+    }
+
     /// A [`Condition`]-satisfying system that returns `true`
     /// if there are any entity with a component of the given type removed.
     pub fn any_component_removed<T: Component>(mut removals: RemovedComponents<T>) -> bool {
@@ -1257,8 +1362,9 @@ mod tests {
     use super::{common_conditions::*, Condition};
     use crate as bevy_ecs;
     use crate::{
-        change_detection::ResMut,
+        change_detection::{DetectChangesMut, ResMut},
         component::Component,
+        query::{Changed, Or},
         schedule::{IntoSystemConfigs, Schedule},
         system::Local,
         world::World,
@@ -1374,9 +1480,81 @@ mod tests {
     #[derive(Component)]
     struct TestComponent;
 
+    #[derive(Component)]
+    struct OtherComponent;
+
     #[derive(Event)]
     struct TestEvent;
 
+    #[test]
+    fn on_event_condition() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<crate::event::Events<TestEvent>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(increment_counter.run_if(on_event::<TestEvent>));
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 0);
+
+        world.send_event(TestEvent);
+        world.send_event(TestEvent);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        // The events were already read.
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn component_change_conditions() {
+        #[derive(Resource, Default)]
+        struct Runs {
+            added: usize,
+            changed: usize,
+            any_changed: usize,
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Runs>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            (|mut runs: ResMut<Runs>| runs.added += 1).run_if(any_component_added::<TestComponent>),
+            (|mut runs: ResMut<Runs>| runs.changed += 1)
+                .run_if(any_component_changed::<TestComponent>),
+            (|mut runs: ResMut<Runs>| runs.any_changed += 1)
+                .run_if(any_match_filter::<Or<(Changed<TestComponent>, Changed<OtherComponent>)>>),
+        ));
+
+        schedule.run(&mut world);
+        let runs = world.resource::<Runs>();
+        assert_eq!((runs.added, runs.changed, runs.any_changed), (0, 0, 0));
+
+        let entity = world.spawn(TestComponent).id();
+        schedule.run(&mut world);
+        let runs = world.resource::<Runs>();
+        assert_eq!((runs.added, runs.changed, runs.any_changed), (1, 1, 1));
+
+        // Nothing changed since the last run.
+        schedule.run(&mut world);
+        let runs = world.resource::<Runs>();
+        assert_eq!((runs.added, runs.changed, runs.any_changed), (1, 1, 1));
+
+        world
+            .get_mut::<TestComponent>(entity)
+            .unwrap()
+            .set_changed();
+        schedule.run(&mut world);
+        let runs = world.resource::<Runs>();
+        assert_eq!((runs.added, runs.changed, runs.any_changed), (1, 2, 2));
+
+        world.spawn(OtherComponent);
+        schedule.run(&mut world);
+        let runs = world.resource::<Runs>();
+        assert_eq!((runs.added, runs.changed, runs.any_changed), (1, 2, 3));
+    }
+
     #[derive(Resource)]
     struct TestResource(());
 
@@ -1396,6 +1574,10 @@ mod tests {
                 .distributive_run_if(resource_removed::<TestResource>)
                 .distributive_run_if(on_event::<TestEvent>)
                 .distributive_run_if(any_with_component::<TestComponent>)
+                .distributive_run_if(any_component_added::<TestComponent>)
+                .distributive_run_if(any_component_changed::<TestComponent>)
+                .distributive_run_if(any_component_removed::<TestComponent>)
+                .distributive_run_if(any_match_filter::<Changed<TestComponent>>)
                 .distributive_run_if(not(run_once)),
         );
     }
@@ -12,6 +12,12 @@ use crate::{
     system::{BoxedSystem, InfallibleSystemWrapper, IntoSystem, ScheduleSystem, System},
 };
 
+#[cfg(feature = "std")]
+use {
+    crate::system::PinnedSystem,
+    alloc::borrow::{Cow, ToOwned},
+};
+
 fn new_condition<M>(condition: impl Condition<M>) -> BoxedCondition {
     let condition_system = IntoSystem::into_system(condition);
     assert!(
@@ -82,6 +88,29 @@ impl SystemConfigs {
             conditions: Vec::new(),
         })
     }
+
+    #[cfg(feature = "std")]
+    fn pin_to_thread_inner(self, thread: &str) -> Self {
+        match self {
+            Self::NodeConfig(config) => Self::NodeConfig(SystemConfig {
+                node: Box::new(PinnedSystem::new(config.node, thread.to_owned())),
+                graph_info: config.graph_info,
+                conditions: config.conditions,
+            }),
+            Self::Configs {
+                configs,
+                collective_conditions,
+                chained,
+            } => Self::Configs {
+                configs: configs
+                    .into_iter()
+                    .map(|config| config.pin_to_thread_inner(thread))
+                    .collect(),
+                collective_conditions,
+                chained,
+            },
+        }
+    }
 }
 
 impl<T> NodeConfigs<T> {
@@ -446,6 +475,28 @@ where
     fn chain_ignore_deferred(self) -> SystemConfigs {
         self.into_configs().chain_ignore_deferred()
     }
+
+    /// Always run these systems on the OS thread named `thread`, spawning it if needed.
+    ///
+    /// Use this for work that must stay on a single thread, such as audio, OS IPC or a driver
+    /// thread. The multi-threaded executor keeps running other systems while pinned ones run, and
+    /// pinned systems may access `NonSend` resources that were inserted on their thread.
+    /// See [`PinnedThread`](crate::schedule::PinnedThread) for more details.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # let mut schedule = Schedule::default();
+    /// fn poll_audio_device() {}
+    /// fn mix_audio() {}
+    ///
+    /// schedule.add_systems((poll_audio_device, mix_audio).chain().pin_to_thread("audio"));
+    /// # let mut world = World::new();
+    /// # schedule.run(&mut world);
+    /// ```
+    #[cfg(feature = "std")]
+    fn pin_to_thread(self, thread: impl Into<Cow<'static, str>>) -> SystemConfigs {
+        self.into_configs().pin_to_thread(thread)
+    }
 }
 
 impl IntoSystemConfigs<()> for SystemConfigs {
@@ -517,6 +568,12 @@ impl IntoSystemConfigs<()> for SystemConfigs {
     fn chain_ignore_deferred(self) -> Self {
         self.chain_ignore_deferred_inner()
     }
+
+    #[cfg(feature = "std")]
+    fn pin_to_thread(self, thread: impl Into<Cow<'static, str>>) -> Self {
+        let thread: Cow<'static, str> = thread.into();
+        self.pin_to_thread_inner(&thread)
+    }
 }
 
 /// Marker component to allow for conflicting implementations of [`IntoSystemConfigs`]
@@ -0,0 +1,159 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bevy_utils::{HashMap, Instant};
+use core::time::Duration;
+use fixedbitset::FixedBitSet;
+use log::warn;
+
+use crate::{
+    schedule::{ExecutorKind, SingleThreadedExecutor, SystemExecutor, SystemSchedule},
+    world::World,
+};
+
+use super::single_threaded::run_system;
+
+/// A system run that took longer than its budget, passed to the overrun handler of a
+/// [`BudgetedExecutor`].
+#[derive(Debug, Clone, Copy)]
+pub struct BudgetOverrun<'a> {
+    /// The name of the system.
+    pub name: &'a str,
+    /// How long the system took to run.
+    pub elapsed: Duration,
+    /// The budget the system exceeded.
+    pub budget: Duration,
+}
+
+/// Runs the schedule on a single thread, like [`SingleThreadedExecutor`], and checks every system
+/// run against a time budget.
+///
+/// Budgets are set per system name (see [`System::name`](crate::system::System::name)), with an
+/// optional default for all other systems. Whenever a system exceeds its budget, the overrun handler is
+/// called. By default it logs a warning; install a handler that panics to strictly enforce the budgets.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, schedule::BudgetedExecutor};
+/// # use core::time::Duration;
+/// fn physics() {}
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(physics);
+/// schedule.set_executor(
+///     BudgetedExecutor::new()
+///         .with_default_budget(Duration::from_millis(1))
+///         .with_budget(core::any::type_name_of_val(&physics), Duration::from_millis(4))
+///         .with_overrun_handler(|overrun| panic!("{} blew its budget", overrun.name)),
+/// );
+/// schedule.run(&mut World::new());
+/// ```
+pub struct BudgetedExecutor {
+    inner: SingleThreadedExecutor,
+    default_budget: Option<Duration>,
+    budgets: HashMap<String, Duration>,
+    /// The budget of each system, indexed like [`SystemSchedule::systems`].
+    system_budgets: Vec<Option<Duration>>,
+    on_overrun: Box<dyn FnMut(BudgetOverrun) + Send + Sync>,
+}
+
+impl Default for BudgetedExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BudgetedExecutor {
+    /// Creates a new budgeted executor without any budgets.
+    pub fn new() -> Self {
+        Self {
+            inner: SingleThreadedExecutor::new(),
+            default_budget: None,
+            budgets: HashMap::default(),
+            system_budgets: Vec::new(),
+            on_overrun: Box::new(|overrun| {
+                warn!(
+                    "System `{}` took {:?}, exceeding its budget of {:?}",
+                    overrun.name, overrun.elapsed, overrun.budget
+                );
+            }),
+        }
+    }
+
+    /// Sets the budget of every system that doesn't have its own budget.
+    pub fn with_default_budget(mut self, budget: Duration) -> Self {
+        self.default_budget = Some(budget);
+        self
+    }
+
+    /// Sets the budget of the system called `name`.
+    pub fn with_budget(mut self, name: impl Into<String>, budget: Duration) -> Self {
+        self.budgets.insert(name.into(), budget);
+        self
+    }
+
+    /// Sets the function called whenever a system exceeds its budget.
+    pub fn with_overrun_handler(
+        mut self,
+        on_overrun: impl FnMut(BudgetOverrun) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_overrun = Box::new(on_overrun);
+        self
+    }
+}
+
+impl SystemExecutor for BudgetedExecutor {
+    fn kind(&self) -> ExecutorKind {
+        ExecutorKind::Budgeted
+    }
+
+    fn init(&mut self, schedule: &SystemSchedule) {
+        self.inner.init(schedule);
+        self.system_budgets = schedule
+            .systems
+            .iter()
+            .map(|system| {
+                self.budgets
+                    .get(&*system.name())
+                    .copied()
+                    .or(self.default_budget)
+            })
+            .collect();
+    }
+
+    fn run(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        world: &mut World,
+        skip_systems: Option<&FixedBitSet>,
+    ) {
+        let order = 0..schedule.systems.len();
+        let system_budgets = &self.system_budgets;
+        let on_overrun = &mut self.on_overrun;
+        let error_handler = schedule.error_handler.clone();
+        let catch_panics = schedule.catch_panics;
+        self.inner.run_in_order(
+            schedule,
+            world,
+            skip_systems,
+            order,
+            |index, system, world| {
+                let Some(budget) = system_budgets[index] else {
+                    run_system(system, world, &error_handler, catch_panics);
+                    return;
+                };
+                let start = Instant::now();
+                run_system(system, world, &error_handler, catch_panics);
+                let elapsed = start.elapsed();
+                if elapsed > budget {
+                    on_overrun(BudgetOverrun {
+                        name: &system.name(),
+                        elapsed,
+                        budget,
+                    });
+                }
+            },
+        );
+    }
+
+    fn set_apply_final_deferred(&mut self, apply_final_deferred: bool) {
+        self.inner.set_apply_final_deferred(apply_final_deferred);
+    }
+}
@@ -0,0 +1,105 @@
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    schedule::{ExecutorKind, SingleThreadedExecutor, SystemExecutor, SystemSchedule},
+    world::World,
+};
+
+use super::single_threaded::run_system;
+
+/// Runs the schedule on a single thread, in a fixed topological order.
+///
+/// Systems run after all of their dependencies. Among the systems that are ready to run, the one
+/// with the lowest name (see [`System::name`](crate::system::System::name)) runs first, and only
+/// systems with identical names fall back to the order they were added in.
+///
+/// Unlike with [`SingleThreadedExecutor`], the order therefore doesn't change when independent systems
+/// are added in a different order, e.g. because plugins were registered differently. This is what
+/// lockstep multiplayer and replays need to get identical results on every machine.
+pub struct DeterministicExecutor {
+    inner: SingleThreadedExecutor,
+    /// The indices of the systems, in the order they are run.
+    order: Vec<usize>,
+}
+
+impl Default for DeterministicExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeterministicExecutor {
+    /// Creates a new deterministic executor for use in a [`Schedule`].
+    ///
+    /// [`Schedule`]: crate::schedule::Schedule
+    pub const fn new() -> Self {
+        Self {
+            inner: SingleThreadedExecutor::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Returns the order systems are run in, as indices into [`SystemSchedule::systems`].
+    ///
+    /// This is empty until the schedule has been initialized.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+}
+
+impl SystemExecutor for DeterministicExecutor {
+    fn kind(&self) -> ExecutorKind {
+        ExecutorKind::Deterministic
+    }
+
+    fn init(&mut self, schedule: &SystemSchedule) {
+        self.inner.init(schedule);
+
+        let mut remaining = schedule.system_dependencies.clone();
+        let key = |index: usize| {
+            (
+                schedule.systems[index].name().to_string(),
+                schedule.system_ids[index].index(),
+                index,
+            )
+        };
+        let mut ready = remaining
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count == 0)
+            .map(|(index, _)| key(index))
+            .collect::<BTreeSet<_>>();
+
+        self.order.clear();
+        while let Some((_, _, index)) = ready.pop_first() {
+            self.order.push(index);
+            for &dependent in &schedule.system_dependents[index] {
+                remaining[dependent] -= 1;
+                if remaining[dependent] == 0 {
+                    ready.insert(key(dependent));
+                }
+            }
+        }
+        debug_assert_eq!(self.order.len(), schedule.systems.len());
+    }
+
+    fn run(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        world: &mut World,
+        skip_systems: Option<&FixedBitSet>,
+    ) {
+        let order = self.order.iter().copied();
+        let error_handler = schedule.error_handler.clone();
+        let catch_panics = schedule.catch_panics;
+        self.inner
+            .run_in_order(schedule, world, skip_systems, order, |_, system, world| {
+                run_system(system, world, &error_handler, catch_panics);
+            });
+    }
+
+    fn set_apply_final_deferred(&mut self, apply_final_deferred: bool) {
+        self.inner.set_apply_final_deferred(apply_final_deferred);
+    }
+}
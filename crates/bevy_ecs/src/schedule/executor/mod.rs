@@ -1,15 +1,31 @@
 #[cfg(feature = "std")]
+mod budgeted;
+mod deterministic;
+#[cfg(feature = "std")]
 mod multi_threaded;
+#[cfg(feature = "std")]
+mod pinned_thread;
 mod simple;
 mod single_threaded;
 
 use alloc::{borrow::Cow, vec, vec::Vec};
 use core::any::TypeId;
 
-pub use self::{simple::SimpleExecutor, single_threaded::SingleThreadedExecutor};
+pub use self::{
+    deterministic::DeterministicExecutor, simple::SimpleExecutor,
+    single_threaded::SingleThreadedExecutor,
+};
+
+#[cfg(feature = "std")]
+pub use self::budgeted::{BudgetOverrun, BudgetedExecutor};
+
+#[cfg(feature = "std")]
+pub use self::multi_threaded::{
+    ExecutorProfiler, ExecutorProfilerHook, MainThreadExecutor, MultiThreadedExecutor, SystemRun,
+};
 
 #[cfg(feature = "std")]
-pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
+pub use self::pinned_thread::{PinnedThread, PinnedThreads};
 
 use fixedbitset::FixedBitSet;
 
@@ -18,22 +34,35 @@ use crate::{
     component::{ComponentId, Tick},
     prelude::{IntoSystemSet, SystemSet},
     query::Access,
-    result::Result,
+    result::{self, Result, SystemErrorContext, SystemErrorHandler},
     schedule::{BoxedCondition, InternedSystemSet, NodeId, SystemTypeSet},
     system::{ScheduleSystem, System, SystemIn},
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
 };
 
 /// Types that can run a [`SystemSchedule`] on a [`World`].
-pub(super) trait SystemExecutor: Send + Sync {
+///
+/// Implement this to replace the executor of a [`Schedule`](super::Schedule) with
+/// [`Schedule::set_executor`](super::Schedule::set_executor).
+pub trait SystemExecutor: Send + Sync {
+    /// Returns the kind of this executor, or [`ExecutorKind::Custom`] for executors
+    /// defined outside of `bevy_ecs`.
     fn kind(&self) -> ExecutorKind;
+    /// Called whenever the [`SystemSchedule`] has been rebuilt, before it is next run.
     fn init(&mut self, schedule: &SystemSchedule);
+    /// Runs the systems in `schedule`, along with their run conditions.
+    ///
+    /// Systems in `skip_systems` must not be run, e.g. because of stepping or disabled system sets,
+    /// but must still be treated as completed for the purpose of ordering.
+    /// [`ApplyDeferred`] systems must apply the buffers of the systems that ran before them
+    /// (see [`is_apply_deferred`]).
     fn run(
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
         skip_systems: Option<&FixedBitSet>,
     );
+    /// Sets whether system buffers are applied once all systems have run.
     fn set_apply_final_deferred(&mut self, value: bool);
 }
 
@@ -57,6 +86,18 @@ pub enum ExecutorKind {
     #[cfg(feature = "std")]
     #[cfg_attr(all(not(target_arch = "wasm32"), feature = "multi_threaded"), default)]
     MultiThreaded,
+    /// Runs the schedule on a single thread, in a topological order that only depends on the
+    /// ordering constraints and system names, not on the order systems were added in.
+    ///
+    /// See [`DeterministicExecutor`].
+    Deterministic,
+    /// Runs the schedule on a single thread and reports systems that exceed their time budget.
+    ///
+    /// See [`BudgetedExecutor`].
+    #[cfg(feature = "std")]
+    Budgeted,
+    /// A user-provided executor, installed with [`Schedule::set_executor`](super::Schedule::set_executor).
+    Custom,
 }
 
 /// Holds systems and conditions of a [`Schedule`](super::Schedule) sorted in topological order
@@ -64,7 +105,6 @@ pub enum ExecutorKind {
 ///
 /// Since the arrays are sorted in the same order, elements are referenced by their index.
 /// [`FixedBitSet`] is used as a smaller, more efficient substitute of `HashSet<usize>`.
-#[derive(Default)]
 pub struct SystemSchedule {
     /// List of system node ids.
     pub(super) system_ids: Vec<NodeId>,
@@ -74,17 +114,9 @@ pub struct SystemSchedule {
     pub(super) system_conditions: Vec<Vec<BoxedCondition>>,
     /// Indexed by system node id.
     /// Number of systems that the system immediately depends on.
-    #[cfg_attr(
-        not(feature = "std"),
-        expect(dead_code, reason = "currently only used with the std feature")
-    )]
     pub(super) system_dependencies: Vec<usize>,
     /// Indexed by system node id.
     /// List of systems that immediately depend on the system.
-    #[cfg_attr(
-        not(feature = "std"),
-        expect(dead_code, reason = "currently only used with the std feature")
-    )]
     pub(super) system_dependents: Vec<Vec<usize>>,
     /// Indexed by system node id.
     /// List of sets containing the system that have conditions
@@ -98,6 +130,16 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Handles errors returned by the systems.
+    pub(super) error_handler: SystemErrorHandler,
+    /// Whether panics in the systems are caught and passed to `error_handler`.
+    pub(super) catch_panics: bool,
+}
+
+impl Default for SystemSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SystemSchedule {
@@ -113,8 +155,75 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            error_handler: SystemErrorHandler::from_fn(result::panic),
+            catch_panics: false,
         }
     }
+
+    /// Returns the [`SystemErrorHandler`] that errors returned by the systems should be passed to.
+    pub fn error_handler(&self) -> &SystemErrorHandler {
+        &self.error_handler
+    }
+
+    /// Returns `true` if panics in the systems should be caught and passed to the
+    /// [`error_handler`](Self::error_handler) as a [`SystemPanic`](result::SystemPanic).
+    pub fn catch_panics(&self) -> bool {
+        self.catch_panics
+    }
+
+    /// Returns the node ids of the systems, in topological order.
+    pub fn system_ids(&self) -> &[NodeId] {
+        &self.system_ids
+    }
+
+    /// Returns the systems, in the same order as [`SystemSchedule::system_ids`].
+    pub fn systems(&self) -> &[ScheduleSystem] {
+        &self.systems
+    }
+
+    /// Returns the systems mutably, in the same order as [`SystemSchedule::system_ids`].
+    pub fn systems_mut(&mut self) -> &mut [ScheduleSystem] {
+        &mut self.systems
+    }
+
+    /// Returns the run conditions of each system, indexed like [`SystemSchedule::systems`].
+    pub fn system_conditions_mut(&mut self) -> &mut [Vec<BoxedCondition>] {
+        &mut self.system_conditions
+    }
+
+    /// Returns the number of systems each system directly depends on, indexed like
+    /// [`SystemSchedule::systems`].
+    pub fn system_dependencies(&self) -> &[usize] {
+        &self.system_dependencies
+    }
+
+    /// Returns the indices of the systems that directly depend on each system, indexed like
+    /// [`SystemSchedule::systems`].
+    pub fn system_dependents(&self) -> &[Vec<usize>] {
+        &self.system_dependents
+    }
+
+    /// Returns the node ids of the system sets that have run conditions.
+    pub fn set_ids(&self) -> &[NodeId] {
+        &self.set_ids
+    }
+
+    /// Returns the run conditions of each system set, indexed like [`SystemSchedule::set_ids`].
+    pub fn set_conditions_mut(&mut self) -> &mut [Vec<BoxedCondition>] {
+        &mut self.set_conditions
+    }
+
+    /// Returns, for each system, the indices of the sets with run conditions that contain it.
+    pub fn sets_with_conditions_of_systems(&self) -> &[FixedBitSet] {
+        &self.sets_with_conditions_of_systems
+    }
+
+    /// Returns, for each set with run conditions, the indices of the systems it contains.
+    ///
+    /// If a set's conditions aren't met, all of these systems are skipped.
+    pub fn systems_in_sets_with_conditions(&self) -> &[FixedBitSet] {
+        &self.systems_in_sets_with_conditions
+    }
 }
 
 /// See [`ApplyDeferred`].
@@ -153,7 +262,7 @@ pub const apply_deferred: ApplyDeferred = ApplyDeferred;
 pub struct ApplyDeferred;
 
 /// Returns `true` if the [`System`] is an instance of [`ApplyDeferred`].
-pub(super) fn is_apply_deferred(system: &ScheduleSystem) -> bool {
+pub fn is_apply_deferred(system: &ScheduleSystem) -> bool {
     system.type_id() == TypeId::of::<ApplyDeferred>()
 }
 
@@ -249,6 +358,48 @@ impl IntoSystemSet<()> for ApplyDeferred {
     }
 }
 
+/// Passes an error returned by `system` on to `error_handler`.
+pub(super) fn handle_system_error(
+    system: &ScheduleSystem,
+    error: result::Error,
+    error_handler: &SystemErrorHandler,
+) {
+    error_handler.handle(
+        error,
+        SystemErrorContext {
+            name: system.name(),
+            last_run: system.get_last_run(),
+        },
+    );
+}
+
+/// Runs `f` on `system`. If `catch_panics` is set, a panic is passed on to `error_handler` as a
+/// [`SystemPanic`](result::SystemPanic) instead of unwinding.
+pub(super) fn run_isolated(
+    system: &mut ScheduleSystem,
+    catch_panics: bool,
+    error_handler: &SystemErrorHandler,
+    f: impl FnOnce(&mut ScheduleSystem),
+) {
+    #[cfg(feature = "std")]
+    if catch_panics {
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| f(&mut *system)));
+        if let Err(payload) = result {
+            handle_system_error(
+                system,
+                alloc::boxed::Box::new(result::SystemPanic::new(payload)),
+                error_handler,
+            );
+        }
+        return;
+    }
+
+    #[cfg(not(feature = "std"))]
+    let _ = (catch_panics, error_handler);
+
+    f(system);
+}
+
 /// These functions hide the bottom of the callstack from `RUST_BACKTRACE=1` (assuming the default panic handler is used).
 ///
 /// The full callstack will still be visible with `RUST_BACKTRACE=full`.
@@ -311,9 +462,10 @@ mod tests {
         self as bevy_ecs,
         prelude::{IntoSystemConfigs, IntoSystemSetConfigs, Resource, Schedule, SystemSet},
         schedule::ExecutorKind,
-        system::{Commands, Res, WithParamWarnPolicy},
+        system::{Commands, Res, ResMut, WithParamWarnPolicy},
         world::World,
     };
+    use alloc::{string::ToString, vec, vec::Vec};
 
     #[derive(Resource)]
     struct R1;
@@ -321,12 +473,39 @@ mod tests {
     #[derive(Resource)]
     struct R2;
 
-    const EXECUTORS: [ExecutorKind; 3] = [
+    const EXECUTORS: [ExecutorKind; 5] = [
         ExecutorKind::Simple,
         ExecutorKind::SingleThreaded,
         ExecutorKind::MultiThreaded,
+        ExecutorKind::Deterministic,
+        ExecutorKind::Budgeted,
     ];
 
+    #[test]
+    fn error_handler_keeps_running_schedule() {
+        #[derive(Resource, Default)]
+        struct Ran(usize);
+
+        for executor in EXECUTORS {
+            let mut world = World::new();
+            world.init_resource::<Ran>();
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(executor);
+            schedule.set_error_handler(crate::result::ignore);
+            schedule.add_systems(
+                (
+                    || -> crate::result::Result { Err("fail".into()) },
+                    |mut ran: ResMut<Ran>| ran.0 += 1,
+                    |_: &mut World| -> crate::result::Result { Err("fail".into()) },
+                    |mut ran: ResMut<Ran>| ran.0 += 1,
+                )
+                    .chain(),
+            );
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<Ran>().0, 2, "{executor:?}");
+        }
+    }
+
     #[test]
     fn invalid_system_param_skips() {
         for executor in EXECUTORS {
@@ -386,4 +565,171 @@ mod tests {
         assert!(world.get_resource::<R1>().is_none());
         assert!(world.get_resource::<R2>().is_none());
     }
+
+    #[test]
+    fn skipped_systems_are_not_matched_against_new_archetypes() {
+        use crate::{
+            component::Component,
+            event::{Event, Events},
+            prelude::{on_event, Query},
+        };
+
+        #[derive(Component)]
+        struct A;
+
+        #[derive(Event)]
+        struct E;
+
+        for executor in EXECUTORS {
+            let mut world = World::new();
+            world.init_resource::<Events<E>>();
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(executor);
+            schedule.add_systems((|_: Query<&A>| {}).run_if(on_event::<E>));
+            schedule.run(&mut world);
+
+            let entity = world.spawn(A).id();
+            let archetype_component_id = world
+                .archetypes()
+                .get(world.entity(entity).location().archetype_id)
+                .unwrap()
+                .get_archetype_component_id(world.component_id::<A>().unwrap())
+                .unwrap();
+            let has_read = |schedule: &Schedule| {
+                let (_, system) = schedule.systems().unwrap().next().unwrap();
+                system
+                    .archetype_component_access()
+                    .has_component_read(archetype_component_id)
+            };
+
+            schedule.run(&mut world);
+            assert!(!has_read(&schedule), "{executor:?}");
+
+            world.send_event(E);
+            schedule.run(&mut world);
+            assert!(has_read(&schedule), "{executor:?}");
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<&'static str>);
+
+    fn b(mut order: ResMut<Order>) {
+        order.0.push("b");
+    }
+
+    fn c(mut order: ResMut<Order>) {
+        order.0.push("c");
+    }
+
+    fn a(mut order: ResMut<Order>) {
+        order.0.push("a");
+    }
+
+    fn z(mut order: ResMut<Order>) {
+        order.0.push("z");
+    }
+
+    #[test]
+    fn deterministic_executor_order_ignores_insertion_order() {
+        let run = |add: fn(&mut Schedule)| {
+            let mut world = World::new();
+            world.init_resource::<Order>();
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(ExecutorKind::Deterministic);
+            add(&mut schedule);
+            schedule.run(&mut world);
+            world.remove_resource::<Order>().unwrap().0
+        };
+
+        let expected = vec!["a", "b", "z", "c"];
+        assert_eq!(
+            run(|schedule| {
+                schedule.add_systems((c.after(z), z, b, a));
+            }),
+            expected
+        );
+        assert_eq!(
+            run(|schedule| {
+                schedule.add_systems((a, b, z, c.after(z)));
+            }),
+            expected
+        );
+    }
+
+    #[test]
+    fn budgeted_executor_reports_overruns() {
+        use super::{BudgetOverrun, BudgetedExecutor};
+        use alloc::sync::Arc;
+        use core::time::Duration;
+        use std::sync::Mutex;
+
+        fn slow() {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        fn fast() {}
+
+        let overruns = Arc::new(Mutex::new(Vec::new()));
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((slow, fast));
+        schedule.set_executor({
+            let overruns = overruns.clone();
+            BudgetedExecutor::new()
+                .with_budget(core::any::type_name_of_val(&slow), Duration::from_millis(1))
+                .with_budget(core::any::type_name_of_val(&fast), Duration::from_secs(1))
+                .with_overrun_handler(move |overrun: BudgetOverrun| {
+                    assert!(overrun.elapsed > overrun.budget);
+                    overruns.lock().unwrap().push(overrun.name.to_string());
+                })
+        });
+        assert_eq!(schedule.get_executor_kind(), ExecutorKind::Budgeted);
+        schedule.run(&mut world);
+
+        assert_eq!(
+            *overruns.lock().unwrap(),
+            [core::any::type_name_of_val(&slow).to_string()]
+        );
+    }
+
+    #[test]
+    fn custom_executor() {
+        use super::{SingleThreadedExecutor, SystemExecutor, SystemSchedule};
+        use fixedbitset::FixedBitSet;
+
+        /// Runs the schedule twice per run.
+        struct Twice(SingleThreadedExecutor);
+
+        impl SystemExecutor for Twice {
+            fn kind(&self) -> ExecutorKind {
+                ExecutorKind::Custom
+            }
+            fn init(&mut self, schedule: &SystemSchedule) {
+                self.0.init(schedule);
+            }
+            fn run(
+                &mut self,
+                schedule: &mut SystemSchedule,
+                world: &mut World,
+                skip_systems: Option<&FixedBitSet>,
+            ) {
+                self.0.run(schedule, world, skip_systems);
+                self.0.run(schedule, world, skip_systems);
+            }
+            fn set_apply_final_deferred(&mut self, value: bool) {
+                self.0.set_apply_final_deferred(value);
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(a);
+        schedule.set_executor(Twice(SingleThreadedExecutor::new()));
+        assert_eq!(schedule.get_executor_kind(), ExecutorKind::Custom);
+        // Setting the same kind again keeps the custom executor.
+        schedule.set_executor_kind(ExecutorKind::Custom);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, ["a", "a"]);
+    }
 }
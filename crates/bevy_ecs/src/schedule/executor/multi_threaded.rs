@@ -1,12 +1,13 @@
 use alloc::{boxed::Box, vec::Vec};
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
-use bevy_utils::{default, syncunsafecell::SyncUnsafeCell};
+use bevy_utils::{default, syncunsafecell::SyncUnsafeCell, Instant};
 use concurrent_queue::ConcurrentQueue;
 use core::{any::Any, panic::AssertUnwindSafe};
 use fixedbitset::FixedBitSet;
 use std::{
     eprintln,
     sync::{Mutex, MutexGuard},
+    thread::ThreadId,
 };
 
 #[cfg(feature = "trace")]
@@ -22,14 +23,18 @@ use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
     query::Access,
+    result::SystemErrorHandler,
     schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
     system::ScheduleSystem,
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
 
+#[cfg(feature = "multi_threaded")]
+use {crate::schedule::PinnedThread, bevy_tasks::block_on};
+
 use crate as bevy_ecs;
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, handle_system_error, run_isolated};
 
 /// Borrowed data used by the [`MultiThreadedExecutor`].
 struct Environment<'env, 'sys> {
@@ -37,6 +42,9 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<ScheduleSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    profiler: Option<Arc<dyn ExecutorProfiler>>,
+    error_handler: SystemErrorHandler,
+    catch_panics: bool,
 }
 
 struct Conditions<'a> {
@@ -54,6 +62,8 @@ impl<'env, 'sys> Environment<'env, 'sys> {
     ) -> Self {
         Environment {
             executor,
+            error_handler: schedule.error_handler.clone(),
+            catch_panics: schedule.catch_panics,
             systems: SyncUnsafeCell::from_mut(schedule.systems.as_mut_slice()).as_slice_of_cells(),
             conditions: SyncUnsafeCell::new(Conditions {
                 system_conditions: &mut schedule.system_conditions,
@@ -61,6 +71,9 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 sets_with_conditions_of_systems: &schedule.sets_with_conditions_of_systems,
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
+            profiler: world
+                .get_resource::<ExecutorProfilerHook>()
+                .map(|hook| hook.0.clone()),
             world_cell: world.as_unsafe_world_cell(),
         }
     }
@@ -77,6 +90,9 @@ struct SystemTaskMetadata {
     is_send: bool,
     /// Is `true` if the system is exclusive.
     is_exclusive: bool,
+    /// The thread the system is pinned to, if any.
+    #[cfg(feature = "multi_threaded")]
+    pinned_thread: Option<Arc<PinnedThread>>,
 }
 
 /// The result of running a system that is sent across a channel.
@@ -124,6 +140,8 @@ pub struct ExecutorState {
     running_systems: FixedBitSet,
     /// Systems that got skipped.
     skipped_systems: FixedBitSet,
+    /// Systems whose conditions have been met, but that are waiting for conflicting systems to finish.
+    evaluated_systems: FixedBitSet,
     /// Systems whose conditions have been evaluated and were run or skipped.
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
@@ -165,6 +183,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         state.running_systems = FixedBitSet::with_capacity(sys_count);
         state.completed_systems = FixedBitSet::with_capacity(sys_count);
         state.skipped_systems = FixedBitSet::with_capacity(sys_count);
+        state.evaluated_systems = FixedBitSet::with_capacity(sys_count);
         state.unapplied_systems = FixedBitSet::with_capacity(sys_count);
 
         state.system_task_metadata = Vec::with_capacity(sys_count);
@@ -172,8 +191,13 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.system_task_metadata.push(SystemTaskMetadata {
                 archetype_component_access: default(),
                 dependents: schedule.system_dependents[index].clone(),
-                is_send: schedule.systems[index].is_send(),
+                // Pinned systems are handed over to their thread, so any thread can start them.
+                is_send: schedule.systems[index].is_send()
+                    || (cfg!(feature = "multi_threaded")
+                        && schedule.systems[index].pinned_thread().is_some()),
                 is_exclusive: schedule.systems[index].is_exclusive(),
+                #[cfg(feature = "multi_threaded")]
+                pinned_thread: schedule.systems[index].pinned_thread().cloned(),
             });
             if schedule.system_dependencies[index] == 0 {
                 self.starting_systems.insert(index);
@@ -187,7 +211,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
+        skip_systems: Option<&FixedBitSet>,
     ) {
         let state = self.state.get_mut().unwrap();
         // reset counts
@@ -200,10 +224,9 @@ impl SystemExecutor for MultiThreadedExecutor {
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
 
-        // If stepping is enabled, make sure we skip those systems that should
-        // not be run.
-        #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        // Make sure we skip those systems that should not be run, because of
+        // stepping or disabled system sets.
+        if let Some(skipped_systems) = skip_systems {
             debug_assert_eq!(skipped_systems.len(), state.completed_systems.len());
             // mark skipped systems as completed
             state.completed_systems |= skipped_systems;
@@ -261,6 +284,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         state.active_access.clear();
         state.evaluated_sets.clear();
         state.skipped_systems.clear();
+        state.evaluated_systems.clear();
         state.completed_systems.clear();
     }
 
@@ -270,6 +294,27 @@ impl SystemExecutor for MultiThreadedExecutor {
 }
 
 impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
+    /// Runs `run` on `system`, reporting the run to the [`ExecutorProfiler`] if there is one.
+    fn run_profiled<R>(
+        &self,
+        system: &mut ScheduleSystem,
+        run: impl FnOnce(&mut ScheduleSystem) -> R,
+    ) -> R {
+        let Some(profiler) = &self.environment.profiler else {
+            return run(system);
+        };
+        let begin = Instant::now();
+        let result = run(system);
+        let end = Instant::now();
+        profiler.system_run(SystemRun {
+            name: &system.name(),
+            thread: std::thread::current().id(),
+            begin,
+            end,
+        });
+        result
+    }
+
     fn system_completed(
         &self,
         system_index: usize,
@@ -352,6 +397,7 @@ impl ExecutorState {
             ready_systems_copy: FixedBitSet::new(),
             running_systems: FixedBitSet::new(),
             skipped_systems: FixedBitSet::new(),
+            evaluated_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
         }
@@ -402,9 +448,8 @@ impl ExecutorState {
                 // Therefore, no other reference to this system exists and there is no aliasing.
                 let system = unsafe { &mut *context.environment.systems[system_index].get() };
 
-                if !self.can_run(
+                if !self.can_evaluate_conditions(
                     system_index,
-                    system,
                     conditions,
                     context.environment.world_cell,
                 ) {
@@ -415,21 +460,37 @@ impl ExecutorState {
                     continue;
                 }
 
+                if !self.evaluated_systems.contains(system_index) {
+                    // SAFETY: `can_evaluate_conditions` returned true, which means that:
+                    // - It must have called `update_archetype_component_access` for each run condition.
+                    // - There can be no systems running whose accesses would conflict with any conditions.
+                    if unsafe {
+                        !self.should_run(system_index, conditions, context.environment.world_cell)
+                    } {
+                        self.ready_systems.remove(system_index);
+                        self.skip_system_and_signal_dependents(system_index);
+                        // signal_dependents may have set more systems to ready.
+                        check_for_new_ready_systems = true;
+                        continue;
+                    }
+                    self.evaluated_systems.insert(system_index);
+                }
+
+                // The system is only matched against new archetypes once its conditions are met,
+                // so that skipping it, e.g. with `on_event`, costs nothing but the conditions.
+                if !self.can_run(system_index, system, context.environment.world_cell) {
+                    continue;
+                }
+
                 self.ready_systems.remove(system_index);
 
-                // SAFETY: `can_run` returned true, which means that:
-                // - It must have called `update_archetype_component_access` for each run condition.
-                // - There can be no systems running whose accesses would conflict with any conditions.
-                if unsafe {
-                    !self.should_run(
-                        system_index,
-                        system,
-                        conditions,
-                        context.environment.world_cell,
-                    )
-                } {
+                // SAFETY:
+                // - The caller ensures that `world` has permission to read any data
+                //   required by the system.
+                // - `can_run` has been called, which calls `update_archetype_component_access` with this system.
+                if unsafe { !system.validate_param_unsafe(context.environment.world_cell) } {
+                    self.skipped_systems.insert(system_index);
                     self.skip_system_and_signal_dependents(system_index);
-                    // signal_dependents may have set more systems to ready.
                     check_for_new_ready_systems = true;
                     continue;
                 }
@@ -438,7 +499,7 @@ impl ExecutorState {
                 self.num_running_systems += 1;
 
                 if self.system_task_metadata[system_index].is_exclusive {
-                    // SAFETY: `can_run` returned true for this system,
+                    // SAFETY: `can_evaluate_conditions` returned true for this system,
                     // which means no systems are currently borrowed.
                     unsafe {
                         self.spawn_exclusive_system_task(context, system_index);
@@ -461,10 +522,11 @@ impl ExecutorState {
         self.ready_systems_copy = ready_systems;
     }
 
-    fn can_run(
+    /// Returns `true` if the system could run right now, and its run conditions and those of its sets
+    /// can be evaluated without conflicting with the running systems.
+    fn can_evaluate_conditions(
         &mut self,
         system_index: usize,
-        system: &mut ScheduleSystem,
         conditions: &mut Conditions,
         world: UnsafeWorldCell,
     ) -> bool {
@@ -477,6 +539,10 @@ impl ExecutorState {
             return false;
         }
 
+        if self.evaluated_systems.contains(system_index) {
+            return true;
+        }
+
         // TODO: an earlier out if world's archetypes did not change
         for set_idx in conditions.sets_with_conditions_of_systems[system_index]
             .difference(&self.evaluated_sets)
@@ -502,20 +568,28 @@ impl ExecutorState {
             }
         }
 
-        if !self.skipped_systems.contains(system_index) {
-            system.update_archetype_component_access(world);
-            if !system
-                .archetype_component_access()
-                .is_compatible(&self.active_access)
-            {
-                return false;
-            }
+        true
+    }
 
-            self.system_task_metadata[system_index]
-                .archetype_component_access
-                .clone_from(system.archetype_component_access());
+    /// Returns `true` if the system can run without conflicting with the running systems.
+    fn can_run(
+        &mut self,
+        system_index: usize,
+        system: &mut ScheduleSystem,
+        world: UnsafeWorldCell,
+    ) -> bool {
+        system.update_archetype_component_access(world);
+        if !system
+            .archetype_component_access()
+            .is_compatible(&self.active_access)
+        {
+            return false;
         }
 
+        self.system_task_metadata[system_index]
+            .archetype_component_access
+            .clone_from(system.archetype_component_access());
+
         true
     }
 
@@ -524,11 +598,10 @@ impl ExecutorState {
     ///   the system's conditions: this includes conditions for the system
     ///   itself, and conditions for any of the system's sets.
     /// * `update_archetype_component` must have been called with `world`
-    ///   for the system as well as system and system set's run conditions.
+    ///   for the system and system set's run conditions.
     unsafe fn should_run(
         &mut self,
         system_index: usize,
-        system: &mut ScheduleSystem,
         conditions: &mut Conditions,
         world: UnsafeWorldCell,
     ) -> bool {
@@ -572,18 +645,6 @@ impl ExecutorState {
 
         should_run &= system_conditions_met;
 
-        if should_run {
-            // SAFETY:
-            // - The caller ensures that `world` has permission to read any data
-            //   required by the system.
-            // - `update_archetype_component_access` has been called for system.
-            let valid_params = unsafe { system.validate_param_unsafe(world) };
-            if !valid_params {
-                self.skipped_systems.insert(system_index);
-            }
-            should_run &= valid_params;
-        }
-
         should_run
     }
 
@@ -602,31 +663,49 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
-            let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                // SAFETY:
-                // - The caller ensures that we have permission to
-                // access the world data used by the system.
-                // - `update_archetype_component_access` has been called.
-                unsafe {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run_unsafe(
+            let res = context.run_profiled(system, |system| {
+                std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    let environment = context.environment;
+                    run_isolated(
                         system,
-                        context.environment.world_cell,
-                    ) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
-                        );
-                    };
-                };
-            }));
+                        environment.catch_panics,
+                        &environment.error_handler,
+                        |system| {
+                            // SAFETY:
+                            // - The caller ensures that we have permission to
+                            // access the world data used by the system.
+                            // - `update_archetype_component_access` has been called.
+                            let result = unsafe {
+                                __rust_begin_short_backtrace::run_unsafe(
+                                    system,
+                                    environment.world_cell,
+                                )
+                            };
+                            if let Err(err) = result {
+                                handle_system_error(system, err, &environment.error_handler);
+                            }
+                        },
+                    );
+                }))
+            });
             context.system_completed(system_index, res, system);
         };
 
         self.active_access
             .extend(&system_meta.archetype_component_access);
 
+        // Without `multi_threaded`, pinned systems block the executor until they have run on
+        // their thread.
+        #[cfg(feature = "multi_threaded")]
+        if let Some(thread) = system_meta.pinned_thread.clone() {
+            context.scope.spawn(async move {
+                // SAFETY: the dispatched job only borrows data that outlives the scope, and the
+                // scope doesn't end before this task, which waits for the job, has completed.
+                unsafe { thread.dispatch(move || block_on(task)) }.await;
+            });
+            return;
+        }
+
         if system_meta.is_send {
             context.scope.spawn(task);
         } else {
@@ -648,32 +727,55 @@ impl ExecutorState {
             let unapplied_systems = self.unapplied_systems.clone();
             self.unapplied_systems.clear();
             let task = async move {
-                // SAFETY: `can_run` returned true for this system, which means
+                // SAFETY: `can_evaluate_conditions` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
-                let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
+                let res = context.run_profiled(system, |_| {
+                    apply_deferred(&unapplied_systems, context.environment.systems, world)
+                });
                 context.system_completed(system_index, res, system);
             };
 
             context.scope.spawn_on_scope(task);
         } else {
             let task = async move {
-                // SAFETY: `can_run` returned true for this system, which means
+                // SAFETY: `can_evaluate_conditions` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
-                let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
+                let res = context.run_profiled(system, |system| {
+                    std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        let environment = context.environment;
+                        run_isolated(
+                            system,
+                            environment.catch_panics,
+                            &environment.error_handler,
+                            |system| {
+                                if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
+                                    handle_system_error(system, err, &environment.error_handler);
+                                }
+                            },
                         );
-                    };
-                }));
+                    }))
+                });
                 context.system_completed(system_index, res, system);
             };
 
+            #[cfg(feature = "multi_threaded")]
+            if let Some(thread) = self.system_task_metadata[system_index]
+                .pinned_thread
+                .clone()
+            {
+                context.scope.spawn(async move {
+                    // SAFETY: the dispatched job only borrows data that outlives the scope, and
+                    // the scope doesn't end before this task, which waits for the job, has
+                    // completed.
+                    unsafe { thread.dispatch(move || block_on(task)) }.await;
+                });
+                // The system doesn't run on the local thread, so only exclusivity is tracked.
+                self.exclusive_running = true;
+                return;
+            }
+
             context.scope.spawn_on_scope(task);
         }
 
@@ -796,15 +898,73 @@ impl MainThreadExecutor {
     }
 }
 
+/// A hook that receives timing information for every system run by the [`MultiThreadedExecutor`].
+///
+/// Insert an [`ExecutorProfilerHook`] resource to install it. This lets you pipe system timings into
+/// your own profiler or enforce a frame budget without depending on the `trace` feature.
+///
+/// The hook is called from whichever thread ran the system, right after it finished, so
+/// implementations should be cheap and must be thread-safe.
+pub trait ExecutorProfiler: Send + Sync + 'static {
+    /// Called once for every system run, including systems that panicked.
+    fn system_run(&self, run: SystemRun<'_>);
+}
+
+/// Timing information about a single system run, passed to [`ExecutorProfiler::system_run`].
+#[derive(Debug, Clone, Copy)]
+pub struct SystemRun<'a> {
+    /// The name of the system.
+    pub name: &'a str,
+    /// The thread the system ran on.
+    pub thread: ThreadId,
+    /// When the system started running.
+    pub begin: Instant,
+    /// When the system finished running.
+    pub end: Instant,
+}
+
+/// [`Resource`] holding the [`ExecutorProfiler`] that the [`MultiThreadedExecutor`] reports system runs to.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, schedule::{ExecutorKind, ExecutorProfiler, ExecutorProfilerHook, SystemRun}};
+/// # use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+/// #[derive(Default)]
+/// struct CountRuns(AtomicUsize);
+///
+/// impl ExecutorProfiler for CountRuns {
+///     fn system_run(&self, run: SystemRun<'_>) {
+///         assert!(run.end >= run.begin);
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let profiler = Arc::new(CountRuns::default());
+/// let mut world = World::new();
+/// world.insert_resource(ExecutorProfilerHook(profiler.clone()));
+///
+/// let mut schedule = Schedule::default();
+/// schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+/// schedule.add_systems((|| {}, || {}));
+/// schedule.run(&mut world);
+/// assert_eq!(profiler.0.load(Ordering::Relaxed), 2);
+/// ```
+#[derive(Resource, Clone)]
+pub struct ExecutorProfilerHook(pub Arc<dyn ExecutorProfiler>);
+
 #[cfg(test)]
 mod tests {
+    use super::{Arc, ExecutorProfiler, ExecutorProfilerHook, Mutex, SystemRun};
     use crate::{
         self as bevy_ecs,
         prelude::Resource,
         schedule::{ExecutorKind, IntoSystemConfigs, Schedule},
-        system::Commands,
+        system::{Commands, ResMut},
         world::World,
     };
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
 
     #[derive(Resource)]
     struct R;
@@ -828,6 +988,65 @@ mod tests {
         assert!(world.get_resource::<R>().is_some());
     }
 
+    #[test]
+    fn profiler_hook_receives_system_runs() {
+        #[derive(Default)]
+        struct RecordNames(Mutex<Vec<String>>);
+
+        impl ExecutorProfiler for RecordNames {
+            fn system_run(&self, run: SystemRun<'_>) {
+                assert!(run.begin <= run.end);
+                self.0.lock().unwrap().push(run.name.to_string());
+            }
+        }
+
+        fn parallel() {}
+        fn exclusive(_: &mut World) {}
+
+        let profiler = Arc::new(RecordNames::default());
+        let mut world = World::new();
+        world.insert_resource(ExecutorProfilerHook(profiler.clone()));
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_systems((parallel, exclusive).chain());
+        schedule.run(&mut world);
+
+        let names = profiler.0.lock().unwrap();
+        assert_eq!(names.len(), 2);
+        assert!(names[0].ends_with("parallel"));
+        assert!(names[1].ends_with("exclusive"));
+    }
+
+    #[test]
+    fn pinned_systems_run_on_their_thread() {
+        #[derive(Resource, Default)]
+        struct ThreadNames(Vec<Option<String>>);
+
+        fn thread_name() -> Option<String> {
+            std::thread::current().name().map(ToString::to_string)
+        }
+
+        let mut world = World::new();
+        world.init_resource::<ThreadNames>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_systems(
+            (
+                |mut names: ResMut<ThreadNames>| names.0.push(thread_name()),
+                |world: &mut World| world.resource_mut::<ThreadNames>().0.push(thread_name()),
+            )
+                .chain()
+                .pin_to_thread("bevy_ecs_multi_threaded_pinned_test"),
+        );
+        schedule.run(&mut world);
+
+        let expected = Some("bevy_ecs_multi_threaded_pinned_test".to_string());
+        assert_eq!(
+            world.resource::<ThreadNames>().0,
+            [expected.clone(), expected]
+        );
+    }
+
     /// Regression test for a weird bug flagged by MIRI in
     /// `spawn_exclusive_system_task`, related to a `&mut World` being captured
     /// inside an `async` block and somehow remaining alive even after its last use.
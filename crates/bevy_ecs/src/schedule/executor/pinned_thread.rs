@@ -0,0 +1,300 @@
+use crate::{self as bevy_ecs, system::Resource};
+use alloc::{borrow::ToOwned, boxed::Box, string::String};
+use bevy_utils::HashMap;
+use core::{
+    future::Future,
+    mem,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    sync::{mpsc, Condvar, Mutex, MutexGuard, PoisonError},
+    thread::{self, JoinHandle, ThreadId},
+};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// The [`PinnedThread`]s of a [`World`](crate::world::World), spawned on first use by the systems
+/// pinned to them with
+/// [`IntoSystemConfigs::pin_to_thread`](crate::schedule::IntoSystemConfigs::pin_to_thread).
+///
+/// The threads are shut down when the pool is dropped along with its world, or by
+/// [`PinnedThreads::shutdown`].
+#[derive(Resource, Default)]
+pub struct PinnedThreads {
+    threads: HashMap<String, Arc<PinnedThread>>,
+}
+
+impl PinnedThreads {
+    /// Returns the thread named `name`, spawning it if it doesn't exist yet.
+    pub fn get_or_spawn(&mut self, name: &str) -> Arc<PinnedThread> {
+        self.threads
+            .entry(name.to_owned())
+            .or_insert_with(|| Arc::new(PinnedThread::spawn(name)))
+            .clone()
+    }
+
+    /// Returns the thread named `name`, if it was spawned.
+    pub fn get(&self, name: &str) -> Option<&Arc<PinnedThread>> {
+        self.threads.get(name)
+    }
+
+    /// Shuts down all the threads of the pool, once they have run the work already sent to them.
+    ///
+    /// Systems still pinned to these threads panic when they next run.
+    pub fn shutdown(&mut self) {
+        for (_, thread) in self.threads.drain() {
+            thread.shutdown();
+        }
+    }
+}
+
+impl Drop for PinnedThreads {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// A named OS thread that systems can be pinned to with
+/// [`IntoSystemConfigs::pin_to_thread`](crate::schedule::IntoSystemConfigs::pin_to_thread).
+///
+/// Work that has to stay on one thread, such as talking to an audio device, OS IPC or a GPU
+/// driver thread, can live inside normal schedules this way: executors hand pinned systems over
+/// to their thread and wait for them to finish. Pinned systems may use `!Send` resources, as long
+/// as those were inserted on the same thread, e.g. by a pinned exclusive system.
+///
+/// Threads are owned by the [`PinnedThreads`] resource of the world the pinned systems were
+/// initialized with.
+pub struct PinnedThread {
+    name: String,
+    thread_id: ThreadId,
+    sender: Mutex<Option<mpsc::Sender<Job>>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl PinnedThread {
+    fn spawn(name: &str) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let handle = thread::Builder::new()
+            .name(name.to_owned())
+            .spawn(move || {
+                for job in receiver {
+                    job();
+                }
+            })
+            .unwrap_or_else(|error| panic!("Failed to spawn pinned thread `{name}`: {error}"));
+        PinnedThread {
+            name: name.to_owned(),
+            thread_id: handle.thread().id(),
+            sender: Mutex::new(Some(sender)),
+            handle: Mutex::new(Some(handle)),
+        }
+    }
+
+    /// Returns the name of the thread.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`ThreadId`] of the thread.
+    pub fn thread_id(&self) -> ThreadId {
+        self.thread_id
+    }
+
+    /// Returns `true` if this is the thread the caller is running on.
+    pub fn is_current(&self) -> bool {
+        thread::current().id() == self.thread_id
+    }
+
+    /// Shuts the thread down once it has run the work already sent to it, and waits for it to
+    /// exit, unless called from the thread itself.
+    pub fn shutdown(&self) {
+        lock(&self.sender).take();
+        let handle = lock(&self.handle).take();
+        if let Some(handle) = handle.filter(|_| !self.is_current()) {
+            // Panics of the jobs were already reported by the thread.
+            let _ = handle.join();
+        }
+    }
+
+    /// Runs `f` on this thread, blocking until it returns. Panics in `f` are resumed on the
+    /// calling thread.
+    ///
+    /// If the caller is already running on this thread, `f` is called directly.
+    pub fn run<R: Send>(&self, f: impl FnOnce() -> R + Send) -> R {
+        if self.is_current() {
+            return f();
+        }
+        let mut result = None;
+        let job = || result = Some(std::panic::catch_unwind(AssertUnwindSafe(f)));
+        // SAFETY: we block until the job has completed, so everything it borrows outlives it.
+        unsafe { self.dispatch(job) }.wait();
+        match result.expect("pinned thread job did not complete") {
+            Ok(value) => value,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+
+    /// Sends `job` to this thread, returning a [`PinnedTask`] that completes once `job` has run.
+    ///
+    /// Panics in `job` are not caught, and shut the thread down.
+    ///
+    /// # Safety
+    ///
+    /// Everything borrowed by `job` must outlive the returned [`PinnedTask`]. Dropping the task
+    /// blocks until `job` has completed, so leaking it (e.g. with [`mem::forget`]) is the only way
+    /// to violate this.
+    pub(crate) unsafe fn dispatch<'a>(&self, job: impl FnOnce() + Send + 'a) -> PinnedTask {
+        let signal = Arc::new(CompletionSignal::default());
+        let completion = signal.clone();
+        let job: Box<dyn FnOnce() + Send + 'a> = Box::new(move || {
+            // Signal completion even if `job` unwinds, so waiting tasks don't hang.
+            let _complete_on_drop = CompleteOnDrop(completion);
+            job();
+        });
+        // SAFETY: the caller guarantees that `job` doesn't outlive its borrows, and the returned
+        // task waits for the job to complete before it is dropped.
+        let job = unsafe { mem::transmute::<Box<dyn FnOnce() + Send + 'a>, Job>(job) };
+        let sent = lock(&self.sender)
+            .as_ref()
+            .is_some_and(|sender| sender.send(job).is_ok());
+        if !sent {
+            panic!("Pinned thread `{}` has shut down", self.name);
+        }
+        PinnedTask { signal }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[derive(Default)]
+struct CompletionSignal {
+    state: Mutex<CompletionState>,
+    condvar: Condvar,
+}
+
+#[derive(Default)]
+struct CompletionState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+impl CompletionSignal {
+    fn lock(&self) -> MutexGuard<'_, CompletionState> {
+        lock(&self.state)
+    }
+
+    fn complete(&self) {
+        let mut state = self.lock();
+        state.done = true;
+        let waker = state.waker.take();
+        drop(state);
+        self.condvar.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+struct CompleteOnDrop(Arc<CompletionSignal>);
+
+impl Drop for CompleteOnDrop {
+    fn drop(&mut self) {
+        self.0.complete();
+    }
+}
+
+/// A job handed to a [`PinnedThread`]. Await it, or call [`PinnedTask::wait`], to wait until the
+/// job has run.
+///
+/// Dropping the task blocks until the job has run.
+pub(crate) struct PinnedTask {
+    signal: Arc<CompletionSignal>,
+}
+
+impl PinnedTask {
+    /// Blocks the calling thread until the job has run.
+    pub(crate) fn wait(self) {
+        // The wait happens in `Drop`.
+    }
+}
+
+impl Future for PinnedTask {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.signal.lock();
+        if state.done {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for PinnedTask {
+    fn drop(&mut self) {
+        let mut state = self.signal.lock();
+        while !state.done {
+            state = self
+                .signal
+                .condvar
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn run_on_named_thread() {
+        let mut threads = PinnedThreads::default();
+        let thread = threads.get_or_spawn("bevy_ecs_pinned_thread_test");
+        assert!(Arc::ptr_eq(
+            &thread,
+            &threads.get_or_spawn("bevy_ecs_pinned_thread_test")
+        ));
+
+        let counter = AtomicUsize::new(0);
+        let (name, id) = thread.run(|| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            let current = thread::current();
+            (current.name().map(ToOwned::to_owned), current.id())
+        });
+        assert_eq!(name.as_deref(), Some("bevy_ecs_pinned_thread_test"));
+        assert_eq!(id, thread.thread_id());
+        assert_ne!(id, thread::current().id());
+        assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+        // Shutting the pool down joins its threads.
+        threads.shutdown();
+        assert!(threads.get("bevy_ecs_pinned_thread_test").is_none());
+        assert!(lock(&thread.handle).is_none());
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| thread.run(|| {})));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "pinned panic")]
+    fn run_resumes_panics() {
+        PinnedThreads::default()
+            .get_or_spawn("bevy_ecs_pinned_thread_panic_test")
+            .run(|| {
+                panic!("pinned panic");
+            });
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
     world::World,
 };
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, handle_system_error, run_isolated};
 
 /// A variant of [`SingleThreadedExecutor`](crate::schedule::SingleThreadedExecutor) that calls
 /// [`apply_deferred`](crate::system::System::apply_deferred) immediately after running each system.
@@ -42,16 +42,17 @@ impl SystemExecutor for SimpleExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
+        skip_systems: Option<&FixedBitSet>,
     ) {
-        // If stepping is enabled, make sure we skip those systems that should
-        // not be run.
-        #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        // Make sure we skip those systems that should not be run, because of
+        // stepping or disabled system sets.
+        if let Some(skipped_systems) = skip_systems {
             // mark skipped systems as completed
             self.completed_systems |= skipped_systems;
         }
 
+        let error_handler = schedule.error_handler.clone();
+        let catch_panics = schedule.catch_panics;
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -104,14 +105,11 @@ impl SystemExecutor for SimpleExecutor {
             }
 
             let f = AssertUnwindSafe(|| {
-                // TODO: implement an error-handling API instead of panicking.
-                if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                    panic!(
-                        "Encountered an error in system `{}`: {:?}",
-                        &*system.name(),
-                        err
-                    );
-                }
+                run_isolated(system, catch_panics, &error_handler, |system| {
+                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
+                        handle_system_error(system, err, &error_handler);
+                    }
+                });
             });
 
             #[cfg(feature = "std")]
@@ -8,11 +8,13 @@ use tracing::info_span;
 use std::eprintln;
 
 use crate::{
+    result::SystemErrorHandler,
     schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    system::ScheduleSystem,
     world::World,
 };
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, handle_system_error, run_isolated};
 
 /// Runs the schedule using a single thread.
 ///
@@ -48,17 +50,54 @@ impl SystemExecutor for SingleThreadedExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
+        skip_systems: Option<&FixedBitSet>,
     ) {
-        // If stepping is enabled, make sure we skip those systems that should
-        // not be run.
-        #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        let order = 0..schedule.systems.len();
+        let error_handler = schedule.error_handler.clone();
+        let catch_panics = schedule.catch_panics;
+        self.run_in_order(schedule, world, skip_systems, order, |_, system, world| {
+            run_system(system, world, &error_handler, catch_panics);
+        });
+    }
+
+    fn set_apply_final_deferred(&mut self, apply_final_deferred: bool) {
+        self.apply_final_deferred = apply_final_deferred;
+    }
+}
+
+impl SingleThreadedExecutor {
+    /// Creates a new single-threaded executor for use in a [`Schedule`].
+    ///
+    /// [`Schedule`]: crate::schedule::Schedule
+    pub const fn new() -> Self {
+        Self {
+            evaluated_sets: FixedBitSet::new(),
+            completed_systems: FixedBitSet::new(),
+            unapplied_systems: FixedBitSet::new(),
+            apply_final_deferred: true,
+        }
+    }
+
+    /// Runs the systems of `schedule` in the given `order`, which must be a topological order
+    /// containing every system index exactly once.
+    ///
+    /// `run` is called to run each system whose conditions are met, with the system's index.
+    pub(super) fn run_in_order(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        world: &mut World,
+        skip_systems: Option<&FixedBitSet>,
+        order: impl IntoIterator<Item = usize>,
+        mut run: impl FnMut(usize, &mut ScheduleSystem, &mut World),
+    ) {
+        // Make sure we skip those systems that should not be run, because of
+        // stepping or disabled system sets.
+        if let Some(skipped_systems) = skip_systems {
             // mark skipped systems as completed
             self.completed_systems |= skipped_systems;
         }
 
-        for system_index in 0..schedule.systems.len() {
+        for system_index in order {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
             #[cfg(feature = "trace")]
@@ -110,47 +149,7 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
-            let f = AssertUnwindSafe(|| {
-                if system.is_exclusive() {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
-                        );
-                    }
-                } else {
-                    // Use run_unsafe to avoid immediately applying deferred buffers
-                    let world = world.as_unsafe_world_cell();
-                    system.update_archetype_component_access(world);
-                    // SAFETY: We have exclusive, single-threaded access to the world and
-                    // update_archetype_component_access is being called immediately before this.
-                    unsafe {
-                        // TODO: implement an error-handling API instead of panicking.
-                        if let Err(err) = __rust_begin_short_backtrace::run_unsafe(system, world) {
-                            panic!(
-                                "Encountered an error in system `{}`: {:?}",
-                                &*system.name(),
-                                err
-                            );
-                        }
-                    };
-                }
-            });
-
-            #[cfg(feature = "std")]
-            {
-                if let Err(payload) = std::panic::catch_unwind(f) {
-                    eprintln!("Encountered a panic in system `{}`!", &*system.name());
-                    std::panic::resume_unwind(payload);
-                }
-            }
-
-            #[cfg(not(feature = "std"))]
-            {
-                (f)();
-            }
+            run(system_index, system, world);
 
             self.unapplied_systems.insert(system_index);
         }
@@ -162,24 +161,6 @@ impl SystemExecutor for SingleThreadedExecutor {
         self.completed_systems.clear();
     }
 
-    fn set_apply_final_deferred(&mut self, apply_final_deferred: bool) {
-        self.apply_final_deferred = apply_final_deferred;
-    }
-}
-
-impl SingleThreadedExecutor {
-    /// Creates a new single-threaded executor for use in a [`Schedule`].
-    ///
-    /// [`Schedule`]: crate::schedule::Schedule
-    pub const fn new() -> Self {
-        Self {
-            evaluated_sets: FixedBitSet::new(),
-            completed_systems: FixedBitSet::new(),
-            unapplied_systems: FixedBitSet::new(),
-            apply_final_deferred: true,
-        }
-    }
-
     fn apply_deferred(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
         for system_index in self.unapplied_systems.ones() {
             let system = &mut schedule.systems[system_index];
@@ -190,6 +171,49 @@ impl SingleThreadedExecutor {
     }
 }
 
+/// Runs a single system that isn't [`ApplyDeferred`](super::ApplyDeferred) on the current thread,
+/// without applying its buffers. Errors returned by the system are passed to `error_handler`, and
+/// so are its panics if `catch_panics` is set.
+pub(super) fn run_system(
+    system: &mut ScheduleSystem,
+    world: &mut World,
+    error_handler: &SystemErrorHandler,
+    catch_panics: bool,
+) {
+    let f = AssertUnwindSafe(|| {
+        run_isolated(system, catch_panics, error_handler, |system| {
+            if system.is_exclusive() {
+                if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
+                    handle_system_error(system, err, error_handler);
+                }
+            } else {
+                // Use run_unsafe to avoid immediately applying deferred buffers
+                let world = world.as_unsafe_world_cell();
+                system.update_archetype_component_access(world);
+                // SAFETY: We have exclusive, single-threaded access to the world and
+                // update_archetype_component_access is being called immediately before this.
+                let result = unsafe { __rust_begin_short_backtrace::run_unsafe(system, world) };
+                if let Err(err) = result {
+                    handle_system_error(system, err, error_handler);
+                }
+            }
+        });
+    });
+
+    #[cfg(feature = "std")]
+    {
+        if let Err(payload) = std::panic::catch_unwind(f) {
+            eprintln!("Encountered a panic in system `{}`!", &*system.name());
+            std::panic::resume_unwind(payload);
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        (f)();
+    }
+}
+
 fn evaluate_and_fold_conditions(conditions: &mut [BoxedCondition], world: &mut World) -> bool {
     // not short-circuiting is intentional
     #[allow(clippy::unnecessary_fold)]
@@ -0,0 +1,316 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_utils::HashMap;
+use core::fmt::Write;
+
+use crate::{
+    component::ComponentId,
+    schedule::{BoxedCondition, NodeId, ScheduleGraph, SystemSchedule},
+};
+
+/// A snapshot of a [`Schedule`](super::Schedule)'s graph, returned by
+/// [`Schedule::export_graph`](super::Schedule::export_graph).
+///
+/// Unlike [`ScheduleGraph`], this is a plain data model that doesn't borrow the schedule: nodes are
+/// referred to by their index in [`nodes`](Self::nodes), systems come before sets, and both are in
+/// the order they were added to the schedule. This makes it suitable for asserting on ordering
+/// constraints in tests and for feeding external tooling, e.g. through
+/// [`to_dot`](Self::to_dot) or [`to_json`](Self::to_json).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportedScheduleGraph {
+    /// The name of the schedule's label.
+    pub label: String,
+    /// All systems and system sets in the schedule.
+    pub nodes: Vec<ExportedNode>,
+    /// Edges from a system set to each of its direct members.
+    pub hierarchy: Vec<ExportedEdge>,
+    /// Edges from a system or set to a system or set that has to run after it.
+    pub dependencies: Vec<ExportedEdge>,
+    /// Pairs of systems with conflicting access and no ordering between them.
+    ///
+    /// This is only filled in once the schedule has been initialized.
+    pub ambiguities: Vec<ExportedAmbiguity>,
+    /// The systems in the order the executor considers them, as indices into [`nodes`](Self::nodes).
+    ///
+    /// This is only filled in once the schedule has been initialized.
+    pub execution_order: Vec<usize>,
+}
+
+/// The kind of an [`ExportedNode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportedNodeKind {
+    /// A system.
+    System,
+    /// A system set.
+    SystemSet,
+}
+
+/// A system or system set in an [`ExportedScheduleGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedNode {
+    /// Whether this node is a system or a system set.
+    pub kind: ExportedNodeKind,
+    /// The name of the system or set.
+    pub name: String,
+    /// The names of the run conditions attached directly to this node.
+    pub conditions: Vec<String>,
+}
+
+/// A directed edge between two nodes of an [`ExportedScheduleGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExportedEdge {
+    /// The index of the source node.
+    pub from: usize,
+    /// The index of the target node.
+    pub to: usize,
+}
+
+/// Two systems of an [`ExportedScheduleGraph`] that may run in either order despite conflicting access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedAmbiguity {
+    /// The index of the first system.
+    pub a: usize,
+    /// The index of the second system.
+    pub b: usize,
+    /// The components both systems access in a conflicting way.
+    ///
+    /// If this is empty, the systems conflict on [`World`](crate::world::World) access.
+    pub components: Vec<ComponentId>,
+}
+
+impl ExportedScheduleGraph {
+    pub(super) fn new(label: String, graph: &ScheduleGraph, executable: &SystemSchedule) -> Self {
+        let mut indices = HashMap::<NodeId, usize>::default();
+        let mut nodes = Vec::new();
+
+        // Once the schedule is built, its systems and conditions live in the executable schedule.
+        let mut systems = graph
+            .systems()
+            .chain(
+                executable
+                    .system_ids
+                    .iter()
+                    .zip(&executable.systems)
+                    .zip(&executable.system_conditions)
+                    .map(|((&id, system), conditions)| (id, system, conditions.as_slice())),
+            )
+            .collect::<Vec<_>>();
+        systems.sort_by_key(|(id, ..)| id.index());
+        for (id, system, conditions) in systems {
+            indices.insert(id, nodes.len());
+            nodes.push(ExportedNode {
+                kind: ExportedNodeKind::System,
+                name: system.name().to_string(),
+                conditions: condition_names(conditions).collect(),
+            });
+        }
+
+        let built_set_conditions = executable
+            .set_ids
+            .iter()
+            .copied()
+            .zip(executable.set_conditions.iter().map(Vec::as_slice))
+            .collect::<HashMap<_, _>>();
+        let mut sets = graph.system_sets().collect::<Vec<_>>();
+        sets.sort_by_key(|(id, ..)| id.index());
+        for (id, _, conditions) in sets {
+            indices.insert(id, nodes.len());
+            let built_conditions = built_set_conditions.get(&id).copied().unwrap_or_default();
+            nodes.push(ExportedNode {
+                kind: ExportedNodeKind::SystemSet,
+                name: graph.get_node_name_inner(&id, false),
+                conditions: condition_names(conditions)
+                    .chain(condition_names(built_conditions))
+                    .collect(),
+            });
+        }
+
+        let edges = |dag: &super::Dag| {
+            let mut edges = dag
+                .graph()
+                .all_edges()
+                .filter_map(|(from, to)| {
+                    Some(ExportedEdge {
+                        from: *indices.get(&from)?,
+                        to: *indices.get(&to)?,
+                    })
+                })
+                .collect::<Vec<_>>();
+            edges.sort_by_key(|edge| (edge.from, edge.to));
+            edges
+        };
+        let hierarchy = edges(graph.hierarchy());
+        let dependencies = edges(graph.dependency());
+
+        let ambiguities = graph
+            .conflicting_systems()
+            .iter()
+            .filter_map(|(a, b, components)| {
+                Some(ExportedAmbiguity {
+                    a: *indices.get(a)?,
+                    b: *indices.get(b)?,
+                    components: components.clone(),
+                })
+            })
+            .collect();
+        let execution_order = executable
+            .system_ids
+            .iter()
+            .filter_map(|id| indices.get(id).copied())
+            .collect();
+
+        Self {
+            label,
+            nodes,
+            hierarchy,
+            dependencies,
+            ambiguities,
+            execution_order,
+        }
+    }
+
+    /// Returns the index of the first node with the given name, if any.
+    ///
+    /// System names are their full type path, e.g. `my_crate::my_system`.
+    pub fn node_index(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.name == name)
+    }
+
+    /// Returns `true` if there is an explicit dependency edge from node `before` to node `after`.
+    pub fn has_dependency(&self, before: usize, after: usize) -> bool {
+        self.dependencies.contains(&ExportedEdge {
+            from: before,
+            to: after,
+        })
+    }
+
+    /// Renders the graph in the Graphviz dot format.
+    ///
+    /// Systems are drawn as boxes and sets as ellipses, annotated with their run conditions.
+    /// Set membership is drawn as dotted edges, dependencies as solid edges and ambiguities as
+    /// dashed, undirected red edges.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "digraph {} {{", quote(&self.label));
+        for (index, node) in self.nodes.iter().enumerate() {
+            let shape = match node.kind {
+                ExportedNodeKind::System => "box",
+                ExportedNodeKind::SystemSet => "ellipse",
+            };
+            let mut label = node.name.clone();
+            for condition in &node.conditions {
+                label.push_str("\nif ");
+                label.push_str(condition);
+            }
+            let _ = writeln!(out, "  n{index} [label={}, shape={shape}];", quote(&label));
+        }
+        for edge in &self.hierarchy {
+            let _ = writeln!(out, "  n{} -> n{} [style=dotted];", edge.from, edge.to);
+        }
+        for edge in &self.dependencies {
+            let _ = writeln!(out, "  n{} -> n{};", edge.from, edge.to);
+        }
+        for ambiguity in &self.ambiguities {
+            let _ = writeln!(
+                out,
+                "  n{} -> n{} [dir=none, style=dashed, color=red];",
+                ambiguity.a, ambiguity.b
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Renders the graph as a JSON object with the same shape as this struct.
+    ///
+    /// Node kinds are written as `"system"` or `"set"`, and component ids as their index.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        let _ = write!(out, "{{\"label\":{},\"nodes\":[", quote(&self.label));
+        for (i, node) in self.nodes.iter().enumerate() {
+            let kind = match node.kind {
+                ExportedNodeKind::System => "system",
+                ExportedNodeKind::SystemSet => "set",
+            };
+            let conditions = node
+                .conditions
+                .iter()
+                .map(|c| quote(c))
+                .collect::<Vec<_>>()
+                .join(",");
+            let _ = write!(
+                out,
+                "{}{{\"kind\":\"{kind}\",\"name\":{},\"conditions\":[{conditions}]}}",
+                if i == 0 { "" } else { "," },
+                quote(&node.name)
+            );
+        }
+        let edges = |edges: &[ExportedEdge]| {
+            edges
+                .iter()
+                .map(|edge| format!("{{\"from\":{},\"to\":{}}}", edge.from, edge.to))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let ambiguities = self
+            .ambiguities
+            .iter()
+            .map(|ambiguity| {
+                let components = ambiguity
+                    .components
+                    .iter()
+                    .map(|id| id.index().to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!(
+                    "{{\"a\":{},\"b\":{},\"components\":[{components}]}}",
+                    ambiguity.a, ambiguity.b
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let execution_order = self
+            .execution_order
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        let _ = write!(
+            out,
+            "],\"hierarchy\":[{}],\"dependencies\":[{}],\"ambiguities\":[{ambiguities}],\"execution_order\":[{execution_order}]}}",
+            edges(&self.hierarchy),
+            edges(&self.dependencies),
+        );
+        out
+    }
+}
+
+fn condition_names(conditions: &[BoxedCondition]) -> impl Iterator<Item = String> + '_ {
+    conditions
+        .iter()
+        .map(|condition| condition.name().to_string())
+}
+
+/// Quotes and escapes `s` as a string literal that is valid in both JSON and dot.
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
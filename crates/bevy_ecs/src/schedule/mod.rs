@@ -3,6 +3,7 @@
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod graph;
 #[allow(clippy::module_inception)]
 mod schedule;
@@ -10,7 +11,7 @@ mod set;
 mod stepping;
 
 use self::graph::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{condition::*, config::*, executor::*, export::*, schedule::*, set::*};
 
 pub use self::graph::NodeId;
 
@@ -1225,5 +1226,17 @@ mod tests {
         fn multi_threaded_executor() {
             assert_executor_supports_stepping!(ExecutorKind::MultiThreaded);
         }
+
+        /// verify the [`DeterministicExecutor`] supports stepping
+        #[test]
+        fn deterministic_executor() {
+            assert_executor_supports_stepping!(ExecutorKind::Deterministic);
+        }
+
+        /// verify the [`BudgetedExecutor`] supports stepping
+        #[test]
+        fn budgeted_executor() {
+            assert_executor_supports_stepping!(ExecutorKind::Budgeted);
+        }
     }
 }
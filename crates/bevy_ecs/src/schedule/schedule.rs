@@ -19,7 +19,7 @@ use crate::{
     self as bevy_ecs,
     component::{ComponentId, Components, Tick},
     prelude::Component,
-    result::Result,
+    result::{self, CatchSystemPanics, DefaultSystemErrorHandler, Result, SystemErrorHandler},
     schedule::*,
     system::{IntoSystem, Resource, ScheduleSystem},
     world::World,
@@ -27,6 +27,12 @@ use crate::{
 
 use crate::{query::AccessConflicts, storage::SparseSetIndex};
 pub use stepping::Stepping;
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicUsize, Ordering};
 use Direction::{Incoming, Outgoing};
 
 /// Resource that stores [`Schedule`]s mapped to [`ScheduleLabel`]s excluding the current running [`Schedule`].
@@ -204,12 +210,155 @@ impl Schedules {
     }
 }
 
+/// Resource that lists the [`SystemSet`]s whose systems are currently skipped.
+///
+/// Unlike run conditions, which are evaluated every time a schedule runs, disabled sets are
+/// resolved once per change: every [`Schedule`] computes which of its systems belong to a
+/// disabled set, and the executor then skips those systems (and their run conditions) entirely.
+///
+/// Changes take effect the next time a schedule starts running, so toggling a set from one of
+/// its own schedule's systems only affects the following run. Only actual changes to the disabled
+/// sets invalidate the systems computed by the schedules: accessing the resource mutably doesn't.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::DisabledSystemSets;
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Gameplay;
+///
+/// fn toggle_pause(mut disabled: ResMut<DisabledSystemSets>) {
+///     let paused = disabled.is_disabled(Gameplay);
+///     disabled.set_enabled(Gameplay, paused);
+/// }
+/// # bevy_ecs::system::assert_is_system(toggle_pause);
+/// ```
+#[derive(Resource, Debug)]
+pub struct DisabledSystemSets {
+    sets: HashSet<InternedSystemSet>,
+    /// Identifies the current contents of `sets`, unique across all instances.
+    generation: usize,
+}
+
+/// The next [`DisabledSystemSets::generation`].
+static NEXT_DISABLED_SETS_GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+impl Default for DisabledSystemSets {
+    fn default() -> Self {
+        Self {
+            sets: HashSet::default(),
+            generation: Self::next_generation(),
+        }
+    }
+}
+
+impl DisabledSystemSets {
+    /// Disables `set`, skipping all systems in it.
+    pub fn disable(&mut self, set: impl SystemSet) -> &mut Self {
+        if self.sets.insert(set.intern()) {
+            self.generation = Self::next_generation();
+        }
+        self
+    }
+
+    /// Enables `set` again, if it was disabled.
+    ///
+    /// Note that a system is still skipped if any other set containing it is disabled.
+    pub fn enable(&mut self, set: impl SystemSet) -> &mut Self {
+        if self.sets.remove(&set.intern()) {
+            self.generation = Self::next_generation();
+        }
+        self
+    }
+
+    /// Enables or disables `set`.
+    pub fn set_enabled(&mut self, set: impl SystemSet, enabled: bool) -> &mut Self {
+        if enabled {
+            self.enable(set)
+        } else {
+            self.disable(set)
+        }
+    }
+
+    /// Returns `true` if `set` is disabled.
+    pub fn is_disabled(&self, set: impl SystemSet) -> bool {
+        self.sets.contains(&set.intern())
+    }
+
+    /// Returns an iterator over the disabled sets.
+    pub fn iter(&self) -> impl Iterator<Item = InternedSystemSet> + '_ {
+        self.sets.iter().copied()
+    }
+
+    fn next_generation() -> usize {
+        // We use `Relaxed` here since this atomic only needs to be consistent with itself
+        NEXT_DISABLED_SETS_GENERATION.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// Records how long the schedules of the world took to run, while it exists in the world.
+///
+/// A schedule run by another one, like the schedules of `bevy_app`'s `Main`, is timed separately
+/// and also counts towards the time of the schedule running it.
+#[cfg(feature = "std")]
+#[derive(Resource, Default, Debug, Clone)]
+pub struct ScheduleTimings {
+    timings: HashMap<InternedScheduleLabel, ScheduleTiming>,
+}
+
+/// How long a schedule took to run, see [`ScheduleTimings`].
+#[cfg(feature = "std")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleTiming {
+    /// The number of runs of the schedule.
+    pub runs: u32,
+    /// The total time of these runs.
+    pub total: core::time::Duration,
+}
+
+#[cfg(feature = "std")]
+impl ScheduleTiming {
+    /// Returns the average time of a run, or zero if the schedule didn't run.
+    pub fn average(&self) -> core::time::Duration {
+        self.total.checked_div(self.runs).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "std")]
+impl ScheduleTimings {
+    /// Returns the timing of the schedule `label`, if it ran.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&ScheduleTiming> {
+        self.timings.get(&label.intern())
+    }
+
+    /// Returns an iterator over the schedules that ran and their timings.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &ScheduleTiming)> {
+        self.timings.iter().map(|(label, timing)| (*label, timing))
+    }
+
+    /// Clears the recorded timings.
+    pub fn clear(&mut self) {
+        self.timings.clear();
+    }
+
+    fn record(&mut self, label: InternedScheduleLabel, elapsed: core::time::Duration) {
+        let timing = self.timings.entry(label).or_default();
+        timing.runs += 1;
+        timing.total += elapsed;
+    }
+}
+
 fn make_executor(kind: ExecutorKind) -> Box<dyn SystemExecutor> {
     match kind {
         ExecutorKind::Simple => Box::new(SimpleExecutor::new()),
         ExecutorKind::SingleThreaded => Box::new(SingleThreadedExecutor::new()),
         #[cfg(feature = "std")]
         ExecutorKind::MultiThreaded => Box::new(MultiThreadedExecutor::new()),
+        ExecutorKind::Deterministic => Box::new(DeterministicExecutor::new()),
+        #[cfg(feature = "std")]
+        ExecutorKind::Budgeted => Box::new(BudgetedExecutor::new()),
+        ExecutorKind::Custom => {
+            panic!("custom executors must be installed with `Schedule::set_executor`")
+        }
     }
 }
 
@@ -268,6 +417,11 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    /// The systems skipped because of [`DisabledSystemSets`], along with the generation of the
+    /// disabled sets they were computed for.
+    disabled_systems: Option<(usize, FixedBitSet)>,
+    error_handler: Option<SystemErrorHandler>,
+    catch_panics: Option<bool>,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -292,6 +446,9 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            disabled_systems: None,
+            error_handler: None,
+            catch_panics: None,
         }
     }
 
@@ -361,6 +518,11 @@ impl Schedule {
     }
 
     /// Sets the schedule's execution strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `executor` is [`ExecutorKind::Custom`] and the current executor isn't custom.
+    /// Use [`Schedule::set_executor`] to install a custom executor instead.
     pub fn set_executor_kind(&mut self, executor: ExecutorKind) -> &mut Self {
         if executor != self.executor.kind() {
             self.executor = make_executor(executor);
@@ -369,6 +531,35 @@ impl Schedule {
         self
     }
 
+    /// Sets the [`SystemErrorHandler`] that errors returned by systems in this schedule are
+    /// passed to, overriding the [`DefaultSystemErrorHandler`] resource.
+    ///
+    /// See the [`result`](crate::result) module for the available handlers.
+    pub fn set_error_handler(&mut self, error_handler: impl Into<SystemErrorHandler>) -> &mut Self {
+        self.error_handler = Some(error_handler.into());
+        self
+    }
+
+    /// Sets whether panics in systems of this schedule are caught and passed to its
+    /// [`SystemErrorHandler`] as a [`SystemPanic`](crate::result::SystemPanic), overriding the
+    /// [`CatchSystemPanics`] resource.
+    ///
+    /// Catching panics requires the `std` feature.
+    pub fn set_catch_panics(&mut self, catch_panics: bool) -> &mut Self {
+        self.catch_panics = Some(catch_panics);
+        self
+    }
+
+    /// Replaces the schedule's executor with `executor`.
+    ///
+    /// This can be one of the executors shipped with `bevy_ecs`, configured beyond what
+    /// [`Schedule::set_executor_kind`] allows, or your own implementation of [`SystemExecutor`].
+    pub fn set_executor(&mut self, executor: impl SystemExecutor + 'static) -> &mut Self {
+        self.executor = Box::new(executor);
+        self.executor_initialized = false;
+        self
+    }
+
     /// Set whether the schedule applies deferred system buffers on final time or not. This is a catch-all
     /// in case a system uses commands but was not explicitly ordered before an instance of
     /// [`ApplyDeferred`]. By default this
@@ -379,27 +570,104 @@ impl Schedule {
     }
 
     /// Runs all systems in this schedule on the `world`, using its current execution strategy.
+    ///
+    /// If the world has a [`ScheduleTimings`] resource, the time the run took is recorded in it.
     pub fn run(&mut self, world: &mut World) {
         #[cfg(feature = "trace")]
         let _span = info_span!("schedule", name = ?self.label).entered();
 
+        #[cfg(feature = "std")]
+        let start = world
+            .contains_resource::<ScheduleTimings>()
+            .then(bevy_utils::Instant::now);
+
         world.check_change_ticks();
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        self.update_disabled_systems(world);
+        self.executable.error_handler = self
+            .error_handler
+            .clone()
+            .or_else(|| {
+                world
+                    .get_resource::<DefaultSystemErrorHandler>()
+                    .map(|handler| handler.0.clone())
+            })
+            .unwrap_or_else(|| SystemErrorHandler::from_fn(result::panic));
+        self.executable.catch_panics = self
+            .catch_panics
+            .or_else(|| {
+                world
+                    .get_resource::<CatchSystemPanics>()
+                    .map(|catch| catch.0)
+            })
+            .unwrap_or(false);
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor.run(&mut self.executable, world, None);
+        self.executor.run(
+            &mut self.executable,
+            world,
+            self.disabled_systems.as_ref().map(|(_, disabled)| disabled),
+        );
 
         #[cfg(feature = "bevy_debug_stepping")]
         {
-            let skip_systems = match world.get_resource_mut::<Stepping>() {
+            let mut skip_systems = match world.get_resource_mut::<Stepping>() {
                 None => None,
                 Some(mut stepping) => stepping.skipped_systems(self),
             };
 
+            if let Some((_, disabled)) = &self.disabled_systems {
+                match &mut skip_systems {
+                    Some(skip_systems) => skip_systems.union_with(disabled),
+                    None => skip_systems = Some(disabled.clone()),
+                }
+            }
+
             self.executor
                 .run(&mut self.executable, world, skip_systems.as_ref());
         }
+
+        #[cfg(feature = "std")]
+        if let Some(start) = start {
+            if let Some(mut timings) = world.get_resource_mut::<ScheduleTimings>() {
+                timings.record(self.label, start.elapsed());
+            }
+        }
+    }
+
+    /// Recomputes which systems are skipped because of [`DisabledSystemSets`],
+    /// if the disabled sets or the schedule changed since the last run.
+    fn update_disabled_systems(&mut self, world: &World) {
+        let Some(disabled_sets) = world
+            .get_resource::<DisabledSystemSets>()
+            .filter(|disabled_sets| !disabled_sets.sets.is_empty())
+        else {
+            self.disabled_systems = None;
+            return;
+        };
+
+        let generation = disabled_sets.generation;
+        if self
+            .disabled_systems
+            .as_ref()
+            .is_some_and(|(last_generation, _)| *last_generation == generation)
+        {
+            return;
+        }
+
+        let system_ids = &self.executable.system_ids;
+        let mut disabled = FixedBitSet::with_capacity(system_ids.len());
+        for (index, &id) in system_ids.iter().enumerate() {
+            if disabled_sets
+                .iter()
+                .any(|set| self.graph.set_contains_node(set, id))
+            {
+                disabled.insert(index);
+            }
+        }
+        self.disabled_systems = Some((generation, disabled));
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -421,6 +689,7 @@ impl Schedule {
             )?;
             self.graph.changed = false;
             self.executor_initialized = false;
+            self.disabled_systems = None;
         }
 
         if !self.executor_initialized {
@@ -436,6 +705,30 @@ impl Schedule {
         &self.graph
     }
 
+    /// Returns a snapshot of the schedule's systems, sets, ordering constraints and ambiguities
+    /// as an [`ExportedScheduleGraph`].
+    ///
+    /// Ambiguities and the execution order are only known once the schedule has been initialized,
+    /// e.g. by running it or calling [`Schedule::initialize`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// fn first() {}
+    /// fn second() {}
+    ///
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems((first, second).chain());
+    ///
+    /// let graph = schedule.export_graph();
+    /// let first = graph.node_index(core::any::type_name_of_val(&first)).unwrap();
+    /// let second = graph.node_index(core::any::type_name_of_val(&second)).unwrap();
+    /// assert!(graph.has_dependency(first, second));
+    /// println!("{}", graph.to_dot());
+    /// ```
+    pub fn export_graph(&self) -> ExportedScheduleGraph {
+        ExportedScheduleGraph::new(format!("{:?}", self.label), &self.graph, &self.executable)
+    }
+
     /// Returns a mutable reference to the [`ScheduleGraph`].
     pub fn graph_mut(&mut self) -> &mut ScheduleGraph {
         &mut self.graph
@@ -661,6 +954,20 @@ impl ScheduleGraph {
         self.system_set_ids.contains_key(&set.intern())
     }
 
+    /// Returns `true` if the system or system set at `id` is contained in `set`, either directly
+    /// or through any number of nested sets. Otherwise, returns `false`.
+    pub fn set_contains_node(&self, set: InternedSystemSet, id: NodeId) -> bool {
+        let Some(&set_id) = self.system_set_ids.get(&set) else {
+            return false;
+        };
+        let mut found = false;
+        self.traverse_sets_containing_node(id, &mut |node| {
+            found |= node == set_id;
+            !found
+        });
+        found
+    }
+
     /// Returns the system at the given [`NodeId`].
     ///
     /// Panics if it doesn't exist.
@@ -1489,6 +1796,8 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            error_handler: SystemErrorHandler::from_fn(result::panic),
+            catch_panics: false,
         }
     }
 
@@ -1584,7 +1893,7 @@ impl ScheduleGraph {
     }
 
     #[inline]
-    fn get_node_name_inner(&self, id: &NodeId, report_sets: bool) -> String {
+    pub(super) fn get_node_name_inner(&self, id: &NodeId, report_sets: bool) -> String {
         let name = match id {
             NodeId::System(_) => {
                 let name = self.systems[id.index()].get().unwrap().name().to_string();
@@ -2056,14 +2365,14 @@ mod tests {
         self as bevy_ecs,
         prelude::{Res, Resource},
         schedule::{
-            tests::ResMut, IntoSystemConfigs, IntoSystemSetConfigs, Schedule,
+            tests::ResMut, IntoSystemConfigs, IntoSystemSet, IntoSystemSetConfigs, Schedule,
             ScheduleBuildSettings, SystemSet,
         },
         system::Commands,
         world::World,
     };
 
-    use super::Schedules;
+    use super::{DisabledSystemSets, Schedules};
 
     #[derive(Resource)]
     struct Resource1;
@@ -2071,6 +2380,54 @@ mod tests {
     #[derive(Resource)]
     struct Resource2;
 
+    #[test]
+    fn export_graph() {
+        use super::{ExportedEdge, ExportedNodeKind};
+
+        #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+        struct Set;
+
+        fn a(_: ResMut<Resource1>) {}
+        fn b(_: ResMut<Resource1>) {}
+        fn c() {}
+
+        let mut world = World::new();
+        world.insert_resource(Resource1);
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Set.run_if(|| true));
+        schedule.add_systems(((a, b).in_set(Set), c.after(Set)));
+
+        let graph = schedule.export_graph();
+        assert!(graph.ambiguities.is_empty());
+        assert!(graph.execution_order.is_empty());
+        let index = |name: &str| {
+            graph
+                .nodes
+                .iter()
+                .position(|node| node.name.ends_with(name))
+                .unwrap()
+        };
+        let (a, b, c, set) = (index("::a"), index("::b"), index("::c"), index("Set"));
+        assert_eq!(graph.nodes[set].kind, ExportedNodeKind::SystemSet);
+        assert_eq!(graph.nodes[set].conditions.len(), 1);
+        assert!(graph.has_dependency(set, c));
+        assert!(graph.hierarchy.contains(&ExportedEdge { from: set, to: a }));
+        assert!(graph.hierarchy.contains(&ExportedEdge { from: set, to: b }));
+
+        schedule.initialize(&mut world).unwrap();
+        let graph = schedule.export_graph();
+        assert_eq!(graph.execution_order.len(), 3);
+        assert_eq!(graph.ambiguities.len(), 1);
+        let ambiguity = &graph.ambiguities[0];
+        assert_eq!(
+            (ambiguity.a.min(ambiguity.b), ambiguity.a.max(ambiguity.b)),
+            (a, b)
+        );
+
+        assert!(graph.to_dot().starts_with("digraph \"DefaultSchedule\" {"));
+        assert!(graph.to_json().contains("\"kind\":\"set\""));
+    }
+
     // regression test for https://github.com/bevyengine/bevy/issues/9114
     #[test]
     fn ambiguous_with_not_breaking_run_conditions() {
@@ -2089,6 +2446,87 @@ mod tests {
         schedule.run(&mut world);
     }
 
+    #[test]
+    fn disabled_system_sets_are_skipped() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Outer;
+
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Inner;
+
+        #[derive(Resource, Default)]
+        struct Counter(u32);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn count_ten(mut counter: ResMut<Counter>) {
+            counter.0 += 10;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<DisabledSystemSets>();
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Inner.in_set(Outer));
+        schedule.add_systems((count.in_set(Inner), count_ten));
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 11);
+
+        // Disabling the outer set also skips systems in nested sets.
+        world.resource_mut::<DisabledSystemSets>().disable(Outer);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 21);
+
+        // Systems can be disabled individually through their system type set.
+        world
+            .resource_mut::<DisabledSystemSets>()
+            .enable(Outer)
+            .disable(count_ten.into_system_set());
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 22);
+
+        world
+            .resource_mut::<DisabledSystemSets>()
+            .enable(count_ten.into_system_set());
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 33);
+
+        // Only actual changes invalidate the skipped systems.
+        world.resource_mut::<DisabledSystemSets>().disable(Inner);
+        schedule.run(&mut world);
+        let generation = schedule
+            .disabled_systems
+            .as_ref()
+            .map(|(generation, _)| *generation);
+        world.resource_mut::<DisabledSystemSets>().disable(Inner);
+        schedule.run(&mut world);
+        assert_eq!(
+            schedule
+                .disabled_systems
+                .as_ref()
+                .map(|(generation, _)| *generation),
+            generation
+        );
+        assert_eq!(world.resource::<Counter>().0, 53);
+
+        // Replacing the resource is a change too, even with the same sets.
+        let mut replacement = DisabledSystemSets::default();
+        replacement.disable(Inner);
+        world.insert_resource(replacement);
+        schedule.run(&mut world);
+        assert_ne!(
+            schedule
+                .disabled_systems
+                .as_ref()
+                .map(|(generation, _)| *generation),
+            generation
+        );
+        assert_eq!(world.resource::<Counter>().0, 63);
+    }
+
     #[test]
     fn inserts_a_sync_point() {
         let mut schedule = Schedule::default();
@@ -1,9 +1,11 @@
 use crate::{
-    schedule::{InternedScheduleLabel, NodeId, Schedule, ScheduleLabel},
+    schedule::{
+        InternedScheduleLabel, InternedSystemSet, NodeId, Schedule, ScheduleLabel, SystemSet,
+    },
     system::{IntoSystem, ResMut, Resource},
 };
 use alloc::vec::Vec;
-use bevy_utils::{HashMap, TypeIdMap};
+use bevy_utils::HashMap;
 use core::any::TypeId;
 use fixedbitset::FixedBitSet;
 use log::{info, warn};
@@ -64,10 +66,12 @@ struct Cursor {
     pub system: usize,
 }
 
-// Two methods of referring to Systems, via TypeId, or per-Schedule NodeId
+// Three methods of referring to Systems, via TypeId, per-Schedule NodeId, or
+// every system within a SystemSet
 enum SystemIdentifier {
     Type(TypeId),
     Node(NodeId),
+    Set(InternedSystemSet),
 }
 
 /// Updates to [`Stepping.schedule_states`] that will be applied at the start
@@ -292,6 +296,46 @@ impl Stepping {
         self
     }
 
+    /// Ensure all systems in this [`SystemSet`] always run when stepping is
+    /// enabled
+    ///
+    /// Note: this applies to systems in nested sets as well.  Behaviors are
+    /// applied in the order they are set, so this overrides the behaviors set
+    /// before for these systems, and is overridden by the ones set after, for
+    /// individual systems or other sets.
+    pub fn always_run_set(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl SystemSet,
+    ) -> &mut Self {
+        self.updates.push(Update::SetBehavior(
+            schedule.intern(),
+            SystemIdentifier::Set(set.intern()),
+            SystemBehavior::AlwaysRun,
+        ));
+        self
+    }
+
+    /// Ensure no system in this [`SystemSet`] runs when stepping is enabled,
+    /// skipping the whole set while stepping through the schedule
+    ///
+    /// Note: this applies to systems in nested sets as well.  Behaviors are
+    /// applied in the order they are set, so this overrides the behaviors set
+    /// before for these systems, and is overridden by the ones set after, for
+    /// individual systems or other sets.
+    pub fn never_run_set(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl SystemSet,
+    ) -> &mut Self {
+        self.updates.push(Update::SetBehavior(
+            schedule.intern(),
+            SystemIdentifier::Set(set.intern()),
+            SystemBehavior::NeverRun,
+        ));
+        self
+    }
+
     /// Add a breakpoint for system
     pub fn set_breakpoint<Marker>(
         &mut self,
@@ -354,6 +398,15 @@ impl Stepping {
         self
     }
 
+    /// Clear any behavior set for the systems in this [`SystemSet`]
+    pub fn clear_set(&mut self, schedule: impl ScheduleLabel, set: impl SystemSet) -> &mut Self {
+        self.updates.push(Update::ClearBehavior(
+            schedule.intern(),
+            SystemIdentifier::Set(set.intern()),
+        ));
+        self
+    }
+
     /// clear a breakpoint for system instance
     pub fn clear_node(&mut self, schedule: impl ScheduleLabel, node: NodeId) -> &mut Self {
         self.updates.push(Update::ClearBehavior(
@@ -608,9 +661,9 @@ struct ScheduleState {
     /// [`NodeId`]s to the caller.
     node_ids: Vec<NodeId>,
 
-    /// changes to system behavior that should be applied the next time
-    /// [`ScheduleState::skipped_systems()`] is called
-    behavior_updates: TypeIdMap<Option<SystemBehavior>>,
+    /// changes to system behavior that should be applied, in order, the next
+    /// time [`ScheduleState::skipped_systems()`] is called
+    behavior_updates: Vec<(SystemIdentifier, Option<SystemBehavior>)>,
 
     /// This field contains the first steppable system in the schedule.
     first: Option<usize>,
@@ -618,33 +671,20 @@ struct ScheduleState {
 
 impl ScheduleState {
     // set the stepping behavior for a system in this schedule
+    //
+    // Behaviors are indexed by NodeId, but we cannot map a system TypeId or a
+    // SystemSet to NodeIds without the `Schedule`.  So queue this update to be
+    // processed the next time `skipped_systems()` is called.  NodeId updates
+    // are queued as well, so that all updates are applied in order.
     fn set_behavior(&mut self, system: SystemIdentifier, behavior: SystemBehavior) {
         self.first = None;
-        match system {
-            SystemIdentifier::Node(node_id) => {
-                self.behaviors.insert(node_id, behavior);
-            }
-            // Behaviors are indexed by NodeId, but we cannot map a system
-            // TypeId to a NodeId without the `Schedule`.  So queue this update
-            // to be processed the next time `skipped_systems()` is called.
-            SystemIdentifier::Type(type_id) => {
-                self.behavior_updates.insert(type_id, Some(behavior));
-            }
-        }
+        self.behavior_updates.push((system, Some(behavior)));
     }
 
     // clear the stepping behavior for a system in this schedule
     fn clear_behavior(&mut self, system: SystemIdentifier) {
         self.first = None;
-        match system {
-            SystemIdentifier::Node(node_id) => {
-                self.behaviors.remove(&node_id);
-            }
-            // queue TypeId updates to be processed later when we have Schedule
-            SystemIdentifier::Type(type_id) => {
-                self.behavior_updates.insert(type_id, None);
-            }
-        }
+        self.behavior_updates.push((system, None));
     }
 
     // clear all system behaviors
@@ -654,27 +694,37 @@ impl ScheduleState {
         self.first = None;
     }
 
-    // apply system behavior updates by looking up the node id of the system in
-    // the schedule, and updating `systems`
+    // apply system behavior updates by looking up the node ids of the systems
+    // in the schedule, and updating `systems`
+    //
+    // Updates are applied in the order they were requested, so the latest
+    // update wins, whether it targets a system or a set containing it.
     fn apply_behavior_updates(&mut self, schedule: &Schedule) {
-        // Systems may be present multiple times within a schedule, so we
-        // iterate through all systems in the schedule, and check our behavior
-        // updates for the system TypeId.
-        // PERF: If we add a way to efficiently query schedule systems by their TypeId, we could remove the full
-        // system scan here
-        for (node_id, system) in schedule.systems().unwrap() {
-            let behavior = self.behavior_updates.get(&system.type_id());
-            match behavior {
-                None => continue,
-                Some(None) => {
-                    self.behaviors.remove(&node_id);
+        for (system, behavior) in core::mem::take(&mut self.behavior_updates) {
+            // Systems may be present multiple times within a schedule, so we
+            // iterate through all systems in the schedule, and check whether
+            // the update targets them.
+            // PERF: If we add a way to efficiently query schedule systems by their TypeId, we could remove the full
+            // system scan here
+            for (node_id, schedule_system) in schedule.systems().unwrap() {
+                let targeted = match &system {
+                    SystemIdentifier::Node(id) => *id == node_id,
+                    SystemIdentifier::Type(type_id) => schedule_system.type_id() == *type_id,
+                    SystemIdentifier::Set(set) => schedule.graph().set_contains_node(*set, node_id),
+                };
+                if !targeted {
+                    continue;
                 }
-                Some(Some(behavior)) => {
-                    self.behaviors.insert(node_id, *behavior);
+                match behavior {
+                    None => {
+                        self.behaviors.remove(&node_id);
+                    }
+                    Some(behavior) => {
+                        self.behaviors.insert(node_id, behavior);
+                    }
                 }
             }
         }
-        self.behavior_updates.clear();
 
         #[cfg(test)]
         debug!("apply_updates(): {:?}", self.behaviors);
@@ -1205,6 +1255,101 @@ mod tests {
         assert_schedule_runs!(&schedule, &mut stepping, first_system, second_system);
     }
 
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSet;
+
+    fn setup_with_set() -> (Schedule, World) {
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems((first_system, (second_system, third_system).in_set(TestSet)).chain());
+        schedule.initialize(&mut world).unwrap();
+        (schedule, world)
+    }
+
+    #[test]
+    fn never_run_set() {
+        let (schedule, _world) = setup_with_set();
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .never_run_set(TestSchedule, TestSet)
+            .continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+
+        // skipped sets are stepped over, so stepping wraps around to the
+        // first system again
+        stepping.step_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+        stepping.step_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+    }
+
+    #[test]
+    fn always_run_set() {
+        let (schedule, _world) = setup_with_set();
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .always_run_set(TestSchedule, TestSet);
+        assert_schedule_runs!(&schedule, &mut stepping, second_system, third_system);
+    }
+
+    #[test]
+    fn set_and_system_behaviors_apply_in_order() {
+        let (schedule, _world) = setup_with_set();
+
+        // The system behavior is set after the set behavior, and wins.
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .never_run_set(TestSchedule, TestSet)
+            .always_run(TestSchedule, second_system);
+        assert_schedule_runs!(&schedule, &mut stepping, second_system);
+
+        // The set behavior is set after the system behavior, and wins.
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .always_run(TestSchedule, second_system)
+            .never_run_set(TestSchedule, TestSet)
+            .continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+
+        // Updates from earlier frames are overridden the same way.
+        stepping.always_run(TestSchedule, third_system);
+        stepping.continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system, third_system);
+    }
+
+    #[test]
+    fn clear_set() {
+        let (schedule, _world) = setup_with_set();
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .never_run_set(TestSchedule, TestSet)
+            .continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+
+        stepping.clear_set(TestSchedule, TestSet);
+        stepping.continue_frame();
+        assert_schedule_runs!(
+            &schedule,
+            &mut stepping,
+            first_system,
+            second_system,
+            third_system
+        );
+    }
+
     #[test]
     fn clear_schedule() {
         let (schedule, _world) = setup();
@@ -0,0 +1,324 @@
+use crate::{
+    component::{ComponentId, Components, StorageType},
+    query::FilteredAccess,
+};
+use alloc::{borrow::Cow, vec::Vec};
+use bevy_utils::HashMap;
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicBool, Ordering};
+
+/// How often a single component was accessed in each of the ways that matter when choosing
+/// its [`StorageType`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentAccessStats {
+    /// The number of entities visited by query iteration while reading or writing this component.
+    pub iterated: u64,
+    /// The number of times this component was fetched for a single entity, through
+    /// [`Query::get`](crate::system::Query::get) or [`EntityRef::get`](crate::world::EntityRef::get)
+    /// and friends.
+    pub random_accesses: u64,
+    /// The number of times this component was added to an entity.
+    pub added: u64,
+    /// The number of times this component was removed from an entity.
+    pub removed: u64,
+}
+
+impl ComponentAccessStats {
+    /// The number of structural changes (additions and removals) recorded for this component.
+    #[inline]
+    pub fn churn(&self) -> u64 {
+        self.added + self.removed
+    }
+
+    /// The [`StorageType`] that best fits these access patterns.
+    ///
+    /// [`StorageType::SparseSet`] is recommended once there is at least one addition or removal
+    /// for every [`SPARSE_SET_CHURN_RATIO`] entities iterated, since moving an entity between
+    /// tables then costs more than the slower iteration of a sparse set.
+    pub fn recommended_storage(&self) -> StorageType {
+        let churn = self.churn();
+        if churn > 0 && churn.saturating_mul(SPARSE_SET_CHURN_RATIO) >= self.iterated {
+            StorageType::SparseSet
+        } else {
+            StorageType::Table
+        }
+    }
+}
+
+/// The number of iterated entities a single addition or removal is considered to be worth when
+/// recommending a [`StorageType`]. See [`ComponentAccessStats::recommended_storage`].
+pub const SPARSE_SET_CHURN_RATIO: u64 = 8;
+
+/// Collects per-component [`ComponentAccessStats`] for a [`World`](crate::world::World).
+///
+/// Only available with the `storage_access_stats` feature. Even then, collection is disabled by
+/// default, as it adds bookkeeping to query iteration, random access and
+/// structural changes. Enable it with [`StorageAccessStats::set_enabled`], run your app for a
+/// representative amount of time, and then inspect [`World::storage_report`] to see which
+/// components would benefit from a different [`StorageType`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Stunned;
+///
+/// let mut world = World::new();
+/// world.storage_access_stats().set_enabled(true);
+///
+/// let entity = world.spawn_empty().id();
+/// for _ in 0..10 {
+///     world.entity_mut(entity).insert(Stunned);
+///     world.entity_mut(entity).remove::<Stunned>();
+/// }
+///
+/// let report = world.storage_report();
+/// let stunned = report.get(world.component_id::<Stunned>().unwrap()).unwrap();
+/// assert_eq!(stunned.stats.added, 10);
+/// assert!(stunned.should_switch());
+/// ```
+///
+/// [`World::storage_report`]: crate::world::World::storage_report
+#[derive(Debug, Default)]
+pub struct StorageAccessStats {
+    enabled: AtomicBool,
+    stats: spin::Mutex<HashMap<ComponentId, ComponentAccessStats>>,
+}
+
+impl StorageAccessStats {
+    /// Returns `true` if access statistics are currently being collected.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Starts or stops collecting access statistics. Already collected statistics are kept.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns the statistics collected for the component `id`, if it has been accessed at all.
+    pub fn get(&self, id: ComponentId) -> Option<ComponentAccessStats> {
+        self.stats.lock().get(&id).copied()
+    }
+
+    /// Returns a snapshot of the statistics of every accessed component.
+    pub fn snapshot(&self) -> Vec<(ComponentId, ComponentAccessStats)> {
+        let mut stats: Vec<_> = self
+            .stats
+            .lock()
+            .iter()
+            .map(|(id, stats)| (*id, *stats))
+            .collect();
+        stats.sort_unstable_by_key(|(id, _)| *id);
+        stats
+    }
+
+    /// Discards all collected statistics.
+    pub fn reset(&self) {
+        self.stats.lock().clear();
+    }
+
+    /// Builds a [`StorageReport`] out of the collected statistics.
+    pub fn report(&self, components: &Components) -> StorageReport {
+        let entries = self
+            .snapshot()
+            .into_iter()
+            .filter_map(|(id, stats)| {
+                let info = components.get_info(id)?;
+                Some(StorageRecommendation {
+                    component: id,
+                    name: Cow::Owned(info.name().into()),
+                    current: info.storage_type(),
+                    recommended: stats.recommended_storage(),
+                    stats,
+                })
+            })
+            .collect();
+        StorageReport { entries }
+    }
+
+    #[inline]
+    fn record(&self, id: ComponentId, f: impl FnOnce(&mut ComponentAccessStats)) {
+        if self.is_enabled() {
+            f(self.stats.lock().entry(id).or_default());
+        }
+    }
+
+    #[inline]
+    fn record_query(
+        &self,
+        access: &FilteredAccess<ComponentId>,
+        f: impl Fn(&mut ComponentAccessStats),
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+        let (ids, inverted) = access.access().component_reads_and_writes();
+        // Queries that access every component (such as `EntityRef`) don't tell us anything useful
+        // about individual components.
+        if inverted {
+            return;
+        }
+        let mut stats = self.stats.lock();
+        for id in ids {
+            f(stats.entry(id).or_default());
+        }
+    }
+
+    /// Records that `count` entities were iterated by a query with the given access.
+    #[inline]
+    pub(crate) fn record_iteration(&self, access: &FilteredAccess<ComponentId>, count: usize) {
+        if count > 0 {
+            self.record_query(access, |stats| stats.iterated += count as u64);
+        }
+    }
+
+    /// Records that a query with the given access fetched a single entity.
+    #[inline]
+    pub(crate) fn record_query_get(&self, access: &FilteredAccess<ComponentId>) {
+        self.record_query(access, |stats| stats.random_accesses += 1);
+    }
+
+    /// Records a random access to the component `id` of a single entity.
+    #[inline]
+    pub(crate) fn record_random_access(&self, id: ComponentId) {
+        self.record(id, |stats| stats.random_accesses += 1);
+    }
+
+    /// Records an addition of the component `id` to an entity.
+    #[inline]
+    pub(crate) fn record_add(&self, id: ComponentId) {
+        self.record(id, |stats| stats.added += 1);
+    }
+
+    /// Records a removal of the component `id` from an entity.
+    #[inline]
+    pub(crate) fn record_remove(&self, id: ComponentId) {
+        self.record(id, |stats| stats.removed += 1);
+    }
+}
+
+/// The storage recommendation for a single component, as part of a [`StorageReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageRecommendation {
+    /// The component this recommendation is for.
+    pub component: ComponentId,
+    /// The name of the component.
+    pub name: Cow<'static, str>,
+    /// The [`StorageType`] the component currently uses.
+    pub current: StorageType,
+    /// The [`StorageType`] that fits the recorded access patterns best.
+    pub recommended: StorageType,
+    /// The statistics the recommendation is based on.
+    pub stats: ComponentAccessStats,
+}
+
+impl StorageRecommendation {
+    /// Returns `true` if the component would benefit from switching to the recommended storage.
+    #[inline]
+    pub fn should_switch(&self) -> bool {
+        self.current != self.recommended
+    }
+}
+
+/// A report recommending a [`StorageType`] for every component accessed while
+/// [`StorageAccessStats`] were being collected.
+///
+/// Components with a Rust type choose their storage with `#[component(storage = "SparseSet")]`.
+/// Components registered at runtime can apply a recommendation through
+/// [`ComponentDescriptor::with_storage_type`](crate::component::ComponentDescriptor::with_storage_type).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageReport {
+    entries: Vec<StorageRecommendation>,
+}
+
+impl StorageReport {
+    /// Returns the recommendation for the component `id`, if it was accessed.
+    pub fn get(&self, id: ComponentId) -> Option<&StorageRecommendation> {
+        self.entries.iter().find(|entry| entry.component == id)
+    }
+
+    /// Iterates over the recommendations for all accessed components, ordered by [`ComponentId`].
+    pub fn iter(&self) -> impl Iterator<Item = &StorageRecommendation> + '_ {
+        self.entries.iter()
+    }
+
+    /// Iterates over the components whose current storage differs from the recommended one.
+    pub fn mismatched(&self) -> impl Iterator<Item = &StorageRecommendation> + '_ {
+        self.entries.iter().filter(|entry| entry.should_switch())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_ecs, component::Component, world::World};
+
+    #[derive(Component)]
+    struct Position(#[expect(dead_code, reason = "only used for its storage")] u32);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn disabled_by_default() {
+        let mut world = World::new();
+        world.spawn(Position(0));
+        world.query::<&Position>().iter(&world).for_each(drop);
+        assert!(world.storage_access_stats().snapshot().is_empty());
+    }
+
+    #[test]
+    fn records_accesses() {
+        let mut world = World::new();
+        world.storage_access_stats().set_enabled(true);
+        let entities: Vec<_> = (0..4).map(|i| world.spawn(Position(i)).id()).collect();
+
+        let mut query = world.query::<&Position>();
+        assert_eq!(query.iter(&world).count(), 4);
+        query.iter(&world).for_each(drop);
+        query.get(&world, entities[0]).unwrap();
+        world.get::<Position>(entities[1]).unwrap();
+        world.entity_mut(entities[2]).remove::<Position>();
+
+        let id = world.component_id::<Position>().unwrap();
+        let stats = world.storage_access_stats().get(id).unwrap();
+        assert_eq!(
+            stats,
+            ComponentAccessStats {
+                iterated: 8,
+                random_accesses: 2,
+                added: 4,
+                removed: 1,
+            }
+        );
+        assert_eq!(stats.recommended_storage(), StorageType::Table);
+    }
+
+    #[test]
+    fn recommends_sparse_set_for_churn() {
+        let mut world = World::new();
+        world.storage_access_stats().set_enabled(true);
+        let entity = world.spawn(Position(0)).id();
+        for _ in 0..4 {
+            world.entity_mut(entity).insert(Marker);
+            world.query::<&Marker>().iter(&world).for_each(drop);
+            world.entity_mut(entity).remove::<Marker>();
+        }
+
+        let report = world.storage_report();
+        let marker = report.get(world.component_id::<Marker>().unwrap()).unwrap();
+        assert_eq!(marker.current, StorageType::Table);
+        assert_eq!(marker.recommended, StorageType::SparseSet);
+        assert!(report
+            .mismatched()
+            .any(|entry| entry.component == marker.component));
+
+        world.storage_access_stats().reset();
+        assert!(world.storage_report().iter().next().is_none());
+    }
+}
@@ -20,6 +20,8 @@
 //! [`World`]: crate::world::World
 //! [`World::storages`]: crate::world::World::storages
 
+#[cfg(feature = "storage_access_stats")]
+mod access_stats;
 mod blob_array;
 mod blob_vec;
 mod resource;
@@ -27,6 +29,8 @@ mod sparse_set;
 mod table;
 mod thin_array_ptr;
 
+#[cfg(feature = "storage_access_stats")]
+pub use access_stats::*;
 pub use resource::*;
 pub use sparse_set::*;
 pub use table::*;
@@ -300,9 +300,9 @@ impl<const SEND: bool> ResourceData<SEND> {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        self.added_ticks.get_mut().check_tick(change_tick);
-        self.changed_ticks.get_mut().check_tick(change_tick);
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) -> usize {
+        self.added_ticks.get_mut().check_tick(change_tick) as usize
+            + self.changed_ticks.get_mut().check_tick(change_tick) as usize
     }
 }
 
@@ -398,9 +398,10 @@ impl<const SEND: bool> Resources<SEND> {
         })
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for info in self.resources.values_mut() {
-            info.check_change_ticks(change_tick);
-        }
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) -> usize {
+        self.resources
+            .values_mut()
+            .map(|info| info.check_change_ticks(change_tick))
+            .sum()
     }
 }
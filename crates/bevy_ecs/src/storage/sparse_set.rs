@@ -360,8 +360,8 @@ impl ComponentSparseSet {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        self.dense.check_change_ticks(change_tick);
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) -> usize {
+        self.dense.check_change_ticks(change_tick)
     }
 }
 
@@ -650,10 +650,11 @@ impl SparseSets {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for set in self.sets.values_mut() {
-            set.check_change_ticks(change_tick);
-        }
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) -> usize {
+        self.sets
+            .values_mut()
+            .map(|set| set.check_change_ticks(change_tick))
+            .sum()
     }
 }
 
@@ -227,26 +227,28 @@ impl ThinColumn {
             .initialize_unchecked(dst_row.as_usize(), changed_by);
     }
 
-    /// Call [`Tick::check_tick`] on all of the ticks stored in this column.
+    /// Call [`Tick::check_tick`] on all of the ticks stored in this column, and returns how many were clamped.
     ///
     /// # Safety
     /// `len` is the actual length of this column
     #[inline]
-    pub(crate) unsafe fn check_change_ticks(&mut self, len: usize, change_tick: Tick) {
+    pub(crate) unsafe fn check_change_ticks(&mut self, len: usize, change_tick: Tick) -> usize {
+        let mut clamped = 0;
         for i in 0..len {
             // SAFETY:
             // - `i` < `len`
             // we have a mutable reference to `self`
-            unsafe { self.added_ticks.get_unchecked_mut(i) }
+            clamped += unsafe { self.added_ticks.get_unchecked_mut(i) }
                 .get_mut()
-                .check_tick(change_tick);
+                .check_tick(change_tick) as usize;
             // SAFETY:
             // - `i` < `len`
             // we have a mutable reference to `self`
-            unsafe { self.changed_ticks.get_unchecked_mut(i) }
+            clamped += unsafe { self.changed_ticks.get_unchecked_mut(i) }
                 .get_mut()
-                .check_tick(change_tick);
+                .check_tick(change_tick) as usize;
         }
+        clamped
     }
 
     /// Clear all the components from this column.
@@ -649,13 +651,13 @@ impl Column {
     }
 
     #[inline]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for component_ticks in &mut self.added_ticks {
-            component_ticks.get_mut().check_tick(change_tick);
-        }
-        for component_ticks in &mut self.changed_ticks {
-            component_ticks.get_mut().check_tick(change_tick);
-        }
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) -> usize {
+        self.added_ticks
+            .iter_mut()
+            .chain(&mut self.changed_ticks)
+            .map(|component_ticks| component_ticks.get_mut().check_tick(change_tick))
+            .filter(|clamped| *clamped)
+            .count()
     }
 
     /// Fetches the calling location that last changed the value at `row`.
@@ -611,13 +611,14 @@ impl Table {
         self.entities.is_empty()
     }
 
-    /// Call [`Tick::check_tick`] on all of the ticks in the [`Table`]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+    /// Call [`Tick::check_tick`] on all of the ticks in the [`Table`], and returns how many were clamped.
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) -> usize {
         let len = self.entity_count();
-        for col in self.columns.values_mut() {
+        self.columns
+            .values_mut()
             // SAFETY: `len` is the actual length of the column
-            unsafe { col.check_change_ticks(len, change_tick) };
-        }
+            .map(|col| unsafe { col.check_change_ticks(len, change_tick) })
+            .sum()
     }
 
     /// Iterates over the [`ThinColumn`]s of the [`Table`].
@@ -776,10 +777,11 @@ impl Tables {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for table in &mut self.tables {
-            table.check_change_ticks(change_tick);
-        }
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) -> usize {
+        self.tables
+            .iter_mut()
+            .map(|table| table.check_change_ticks(change_tick))
+            .sum()
     }
 }
 
@@ -98,6 +98,51 @@ pub trait Command<Marker = ()>: Send + 'static {
     }
 }
 
+/// A [`Command`] that can absorb a command of the same type queued right after it.
+///
+/// Commands queued with [`Commands::queue_merged`](crate::system::Commands::queue_merged)
+/// are merged into the previously queued command when it was also queued that way with the same
+/// type, so that a single command does the work of several. For example, adding children to the
+/// same parent one by one can be merged into a single command adding all of them at once.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, system::MergeCommand, world::CommandQueue};
+/// #[derive(Resource, Default)]
+/// struct Log(Vec<Vec<u32>>);
+///
+/// struct Push(Vec<u32>);
+///
+/// impl Command for Push {
+///     fn apply(self, world: &mut World) -> Result {
+///         world.resource_mut::<Log>().0.push(self.0);
+///         Ok(())
+///     }
+/// }
+///
+/// impl MergeCommand for Push {
+///     fn merge(&mut self, next: Self) -> Option<Self> {
+///         self.0.extend(next.0);
+///         None
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Log>();
+/// let mut queue = CommandQueue::default();
+/// let mut commands = Commands::new(&mut queue, &world);
+/// commands.queue_merged(Push(vec![1]));
+/// commands.queue_merged(Push(vec![2]));
+/// queue.apply(&mut world);
+/// assert_eq!(world.resource::<Log>().0, vec![vec![1, 2]]);
+/// ```
+pub trait MergeCommand: Command + Sized {
+    /// Merges `next`, which was queued right after `self`, into `self`.
+    ///
+    /// Returns `next` back if the two commands can't be merged, in which case it is queued
+    /// after `self` as usual.
+    fn merge(&mut self, next: Self) -> Option<Self>;
+}
+
 impl<F> Command for F
 where
     F: FnOnce(&mut World) + Send + 'static,
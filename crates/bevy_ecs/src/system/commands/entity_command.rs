@@ -4,14 +4,14 @@
 //! It also contains functions that return closures for use with
 //! [`EntityCommands`](crate::system::EntityCommands).
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec, vec::Vec};
 use log::info;
 
 #[cfg(feature = "track_location")]
 use core::panic::Location;
 
 use crate::{
-    bundle::{Bundle, InsertMode},
+    bundle::{Bundle, ErasedBundle, InsertMode},
     component::{Component, ComponentId, ComponentInfo},
     entity::{Entity, EntityCloneBuilder},
     event::Event,
@@ -176,6 +176,80 @@ pub fn insert(bundle: impl Bundle) -> impl EntityCommand<World> {
     }
 }
 
+/// The [`Command`] queued by [`EntityCommands::insert_merged`](crate::system::EntityCommands::insert_merged),
+/// which [`CommandQueue`](crate::world::CommandQueue) can merge with the inserts queued right
+/// after it on the same entity into an [`InsertBundles`] command.
+pub(crate) struct InsertBundle<B> {
+    pub(crate) entity: Entity,
+    pub(crate) bundle: B,
+    #[cfg(feature = "track_location")]
+    pub(crate) caller: &'static Location<'static>,
+}
+
+impl<B: Bundle> InsertBundle<B> {
+    pub(crate) fn into_bundles(self) -> InsertBundles {
+        InsertBundles {
+            entity: self.entity,
+            bundles: vec![Box::new(self.bundle)],
+            #[cfg(feature = "track_location")]
+            caller: self.caller,
+        }
+    }
+}
+
+impl<B: Bundle> Command for InsertBundle<B> {
+    fn apply(self, world: &mut World) -> Result {
+        let InsertBundle {
+            entity,
+            bundle,
+            #[cfg(feature = "track_location")]
+            caller,
+        } = self;
+        let command = move |mut entity: EntityWorldMut| {
+            entity.insert_with_caller(
+                bundle,
+                InsertMode::Replace,
+                #[cfg(feature = "track_location")]
+                caller,
+            );
+        };
+        command
+            .with_entity(entity)
+            .with_error_handling(None)
+            .apply(world)
+    }
+}
+
+/// Several [`InsertBundle`] commands on the same entity, applied with a single archetype move.
+pub(crate) struct InsertBundles {
+    pub(crate) entity: Entity,
+    pub(crate) bundles: Vec<Box<dyn ErasedBundle>>,
+    #[cfg(feature = "track_location")]
+    pub(crate) caller: &'static Location<'static>,
+}
+
+impl Command for InsertBundles {
+    fn apply(self, world: &mut World) -> Result {
+        let InsertBundles {
+            entity,
+            bundles,
+            #[cfg(feature = "track_location")]
+            caller,
+        } = self;
+        let command = move |mut entity: EntityWorldMut| {
+            entity.insert_bundles_with_caller(
+                bundles,
+                #[cfg(feature = "track_location")]
+                caller,
+            );
+        };
+        command
+            .with_entity(entity)
+            .with_error_handling(None)
+            .apply(world)
+    }
+}
+
 /// An [`EntityCommand`] that adds the components in a [`Bundle`] to an entity,
 /// except for any that were already present.
 #[track_caller]
@@ -6,7 +6,7 @@ pub mod error_handler;
 #[cfg(feature = "std")]
 mod parallel_scope;
 
-pub use command::Command;
+pub use command::{Command, MergeCommand};
 pub use entity_command::EntityCommand;
 pub use error::CommandError;
 
@@ -311,10 +311,11 @@ impl<'w, 's> Commands<'w, 's> {
     /// Take all commands from `other` and append them to `self`, leaving `other` empty
     pub fn append(&mut self, other: &mut CommandQueue) {
         match &mut self.queue {
-            InternalQueue::CommandQueue(queue) => queue.bytes.append(&mut other.bytes),
+            InternalQueue::CommandQueue(queue) => queue.append(other),
             InternalQueue::RawCommandQueue(queue) => {
                 // SAFETY: Pointers in `RawCommandQueue` are never null
                 unsafe { queue.bytes.as_mut() }.append(&mut other.bytes);
+                other.merge_slot = None;
             }
         }
     }
@@ -624,6 +625,43 @@ impl<'w, 's> Commands<'w, 's> {
         }
     }
 
+    /// Pushes a [`MergeCommand`] to the command queue, merging it into the previous command if
+    /// that one was also queued with this method, with the same type.
+    ///
+    /// Like [`Commands::queue`], errors returned by the command are ignored.
+    ///
+    /// Commands are only merged when this [`Commands`] writes to a [`CommandQueue`], like the
+    /// [`Commands`] system parameter, and not to the world's own queue, like [`World::commands`].
+    pub fn queue_merged<C: MergeCommand>(&mut self, command: C) {
+        match &mut self.queue {
+            InternalQueue::CommandQueue(queue) => queue.push_merged(command),
+            InternalQueue::RawCommandQueue(queue) => {
+                // SAFETY: `RawCommandQueue` is only every constructed in `Commands::new_raw_from_entities`
+                // where the caller of that has ensured that `queue` outlives `self`
+                unsafe {
+                    queue.push(command);
+                }
+            }
+        }
+    }
+
+    /// Queues inserting `bundle` on `entity`, merging it with other inserts queued right before on
+    /// the same entity when possible.
+    #[track_caller]
+    fn queue_insert(&mut self, entity: Entity, bundle: impl Bundle) {
+        match &mut self.queue {
+            InternalQueue::CommandQueue(queue) if self.error_handler_override.is_none() => {
+                queue.push_insert(
+                    entity,
+                    bundle,
+                    #[cfg(feature = "track_location")]
+                    Location::caller(),
+                );
+            }
+            _ => self.queue_fallible(entity_command::insert(bundle).with_entity(entity)),
+        }
+    }
+
     /// Pushes a generic [`Command`] to the command queue with error handling.
     ///
     /// The command can be:
@@ -1331,6 +1369,44 @@ impl<'a> EntityCommands<'a> {
         self.queue(entity_command::insert(bundle))
     }
 
+    /// Adds a [`Bundle`] of components to the entity, like [`Self::insert`], merging it with the
+    /// inserts queued right before on the same entity with this method.
+    ///
+    /// Merged inserts move the entity to its final archetype at once, instead of going through
+    /// the archetypes in between. Components present in several of the merged bundles only have
+    /// their last value inserted, and hooks and observers run once for all components, after
+    /// every bundle has been inserted.
+    ///
+    /// Inserts are only merged when the [`Commands`] write to a [`CommandQueue`] without an
+    /// error handler override, like the [`Commands`] system parameter.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the associated entity does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// #[derive(Component)]
+    /// struct Strength(u32);
+    ///
+    /// fn add_stats_system(mut commands: Commands) {
+    ///     commands
+    ///         .spawn_empty()
+    ///         .insert_merged(Health(100))
+    ///         .insert_merged(Strength(40));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(add_stats_system);
+    /// ```
+    #[track_caller]
+    pub fn insert_merged(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.commands.queue_insert(self.entity, bundle);
+        self
+    }
+
     /// Similar to [`Self::insert`] but will only insert if the predicate returns true.
     /// This is useful for chaining method calls.
     ///
@@ -2574,15 +2650,15 @@ mod tests {
 
         fn nothing() {}
 
-        assert!(world.iter_resources().count() == 0);
+        let resources = world.iter_resources().count();
         let id = world.register_system_cached(nothing);
-        assert!(world.iter_resources().count() == 1);
+        assert_eq!(world.iter_resources().count(), resources + 1);
         assert!(world.get_entity(id.entity).is_ok());
 
         let mut commands = Commands::new(&mut queue, &world);
         commands.unregister_system_cached(nothing);
         queue.apply(&mut world);
-        assert!(world.iter_resources().count() == 0);
+        assert_eq!(world.iter_resources().count(), resources);
         assert!(world.get_entity(id.entity).is_err());
     }
 
@@ -2613,4 +2689,98 @@ mod tests {
         assert!(world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    #[test]
+    fn parallel_commands_ordered() {
+        use crate::system::{ParallelCommands, RunSystemOnce};
+        use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+        ComputeTaskPool::get_or_init(TaskPool::default);
+
+        #[derive(Resource, Default)]
+        struct Log(Vec<u64>);
+
+        let mut world = World::default();
+        world.init_resource::<Log>();
+        world
+            .run_system_once(|par_commands: ParallelCommands| {
+                ComputeTaskPool::get().scope(|scope| {
+                    for order in (0..32).rev() {
+                        let par_commands = &par_commands;
+                        scope.spawn(async move {
+                            par_commands.command_scope_ordered(order, |mut commands| {
+                                commands.queue(move |world: &mut World| {
+                                    world.resource_mut::<Log>().0.push(order);
+                                });
+                            });
+                        });
+                    }
+                });
+                par_commands.command_scope(|mut commands| {
+                    commands.queue(|world: &mut World| world.resource_mut::<Log>().0.push(100));
+                });
+            })
+            .unwrap();
+
+        let mut expected = vec![100];
+        expected.extend(0..32);
+        assert_eq!(world.resource::<Log>().0, expected);
+    }
+
+    #[test]
+    fn parallel_commands_deterministic() {
+        use crate::{
+            batching::BatchingStrategy,
+            entity::Entity,
+            query::With,
+            system::{ParallelCommands, ParallelCommandsConfig, Query, RunSystemOnce},
+        };
+        use bevy_tasks::{ComputeTaskPool, TaskPoolBuilder};
+
+        ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(4).build());
+
+        #[derive(Resource, Default)]
+        struct Log(Vec<Entity>);
+
+        let mut world = World::default();
+        world.init_resource::<Log>();
+        world.insert_resource(ParallelCommandsConfig {
+            deterministic: true,
+        });
+        for i in 0..100 {
+            // Spread the entities over several archetypes of different sizes.
+            let mut entity = world.spawn(W(i));
+            if i % 3 == 0 {
+                entity.insert(W(i as u32));
+            }
+            if i % 7 == 0 {
+                entity.insert(W(i as u8));
+            }
+        }
+
+        world
+            .run_system_once(
+                |query: Query<(Entity, &W<i32>)>, par_commands: ParallelCommands| {
+                    query
+                        .par_iter()
+                        .batching_strategy(BatchingStrategy::fixed(4))
+                        .for_each(|(entity, _)| {
+                            // Give other threads a chance to pick up the remaining batches.
+                            std::thread::sleep(core::time::Duration::from_micros(100));
+                            par_commands.command_scope(|mut commands| {
+                                commands.queue(move |world: &mut World| {
+                                    world.resource_mut::<Log>().0.push(entity);
+                                });
+                            });
+                        });
+                },
+            )
+            .unwrap();
+
+        let expected: Vec<_> = world
+            .query_filtered::<Entity, With<W<i32>>>()
+            .iter(&world)
+            .collect();
+        assert_eq!(world.resource::<Log>().0, expected);
+    }
 }
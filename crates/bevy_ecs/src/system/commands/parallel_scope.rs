@@ -1,10 +1,11 @@
+use alloc::vec::Vec;
 use bevy_utils::Parallel;
 
 use crate::{
     self as bevy_ecs,
     entity::Entities,
     prelude::World,
-    system::{Deferred, SystemBuffer, SystemMeta, SystemParam},
+    system::{Deferred, Res, Resource, SystemBuffer, SystemMeta, SystemParam},
 };
 
 use super::{CommandQueue, Commands};
@@ -12,6 +13,23 @@ use super::{CommandQueue, Commands};
 #[derive(Default)]
 struct ParallelCommandQueue {
     thread_queues: Parallel<CommandQueue>,
+    batch_queues: Parallel<Vec<((usize, usize), CommandQueue)>>,
+    ordered_queues: Parallel<Vec<(u64, CommandQueue)>>,
+}
+
+/// Configures how the commands recorded by [`ParallelCommands`] are applied.
+///
+/// Insert this resource to change the behavior of every [`ParallelCommands`] in the world.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallelCommandsConfig {
+    /// If `true`, the commands recorded by [`ParallelCommands::command_scope`] during a
+    /// [`Query::par_iter`](crate::system::Query::par_iter) are applied in the order the query
+    /// would have been iterated sequentially, regardless of how the batches were scheduled.
+    ///
+    /// Scopes that run outside of a parallel query iteration are still applied in an unspecified
+    /// order, before those from query iteration; use [`ParallelCommands::command_scope_ordered`]
+    /// to order them. This makes applying commands slightly slower, so it is disabled by default.
+    pub deterministic: bool,
 }
 
 /// An alternative to [`Commands`] that can be used in parallel contexts, such as those
@@ -22,6 +40,9 @@ struct ParallelCommandQueue {
 /// [`Commands::spawn_batch`] for better performance.
 ///
 /// Note: Because command application order will depend on how many threads are ran, non-commutative commands may result in non-deterministic results.
+/// Enable [`ParallelCommandsConfig::deterministic`] to apply commands from a parallel query
+/// iteration in iteration order, or use [`ParallelCommands::command_scope_ordered`] to pick
+/// the order explicitly.
 ///
 /// Example:
 /// ```
@@ -49,6 +70,7 @@ struct ParallelCommandQueue {
 pub struct ParallelCommands<'w, 's> {
     state: Deferred<'s, ParallelCommandQueue>,
     entities: &'w Entities,
+    config: Option<Res<'w, ParallelCommandsConfig>>,
 }
 
 impl SystemBuffer for ParallelCommandQueue {
@@ -59,6 +81,33 @@ impl SystemBuffer for ParallelCommandQueue {
         for cq in self.thread_queues.iter_mut() {
             cq.apply(world);
         }
+
+        let mut batches: Vec<_> = self
+            .batch_queues
+            .iter_mut()
+            .flat_map(|queues| queues.iter_mut())
+            .collect();
+        batches.sort_by_key(|(position, _)| *position);
+        for (_, cq) in batches {
+            cq.apply(world);
+        }
+        for queues in self.batch_queues.iter_mut() {
+            queues.clear();
+        }
+
+        let mut ordered: Vec<_> = self
+            .ordered_queues
+            .iter_mut()
+            .flat_map(|queues| queues.iter_mut())
+            .collect();
+        // Stable, so that scopes with the same order from the same thread keep their relative order.
+        ordered.sort_by_key(|(order, _)| *order);
+        for (_, cq) in ordered {
+            cq.apply(world);
+        }
+        for queues in self.ordered_queues.iter_mut() {
+            queues.clear();
+        }
     }
 }
 
@@ -67,9 +116,71 @@ impl<'w, 's> ParallelCommands<'w, 's> {
     ///
     /// For an example, see the type-level documentation for [`ParallelCommands`].
     pub fn command_scope<R>(&self, f: impl FnOnce(Commands) -> R) -> R {
+        if self
+            .config
+            .as_ref()
+            .is_some_and(|config| config.deterministic)
+        {
+            #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+            if let Some(position) = crate::query::BatchPositionGuard::current() {
+                return self.state.batch_queues.scope(|queues| {
+                    // Scopes from the same batch share a queue, keeping their relative order.
+                    if queues.last().is_none_or(|(last, _)| *last != position) {
+                        queues.push((position, CommandQueue::default()));
+                    }
+                    let (_, queue) = queues.last_mut().unwrap();
+                    f(Commands::new_from_entities(queue, self.entities))
+                });
+            }
+        }
         self.state.thread_queues.scope(|queue| {
             let commands = Commands::new_from_entities(queue, self.entities);
             f(commands)
         })
     }
+
+    /// Temporarily provides access to [`Commands`] whose commands are applied in a
+    /// deterministic order, regardless of which thread ran the scope.
+    ///
+    /// When the commands are applied, the scopes are sorted by `order`, for example the index
+    /// of the task or batch, or [`Entity::to_bits`](crate::entity::Entity::to_bits) of the
+    /// entity being processed. Scopes with the same `order` should come from the same task,
+    /// as their relative order is only preserved within a thread.
+    /// Commands from [`ParallelCommands::command_scope`] are applied before ordered ones,
+    /// even when [`ParallelCommandsConfig::deterministic`] is enabled.
+    ///
+    /// Note that [`Commands::spawn`] reserves entity ids when the command is recorded,
+    /// so the ids themselves still depend on thread scheduling. To get deterministic ids,
+    /// spawn entities from a command instead, e.g. with [`Commands::queue`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// #[derive(Event)]
+    /// struct Died(Entity);
+    ///
+    /// fn report_deaths(query: Query<(Entity, &Health)>, par_commands: ParallelCommands) {
+    ///     query.par_iter().for_each(|(entity, health)| {
+    ///         if health.0 == 0 {
+    ///             // Events are sent in the same order on every run.
+    ///             par_commands.command_scope_ordered(entity.to_bits(), |mut commands| {
+    ///                 commands.send_event(Died(entity));
+    ///             });
+    ///         }
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(report_deaths);
+    /// ```
+    pub fn command_scope_ordered<R>(&self, order: u64, f: impl FnOnce(Commands) -> R) -> R {
+        let mut queue = CommandQueue::default();
+        let result = f(Commands::new_from_entities(&mut queue, self.entities));
+        if !queue.is_empty() {
+            self.state
+                .ordered_queues
+                .scope(|queues| queues.push((order, queue)));
+        }
+        result
+    }
 }
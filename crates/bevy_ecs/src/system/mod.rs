@@ -128,6 +128,8 @@ mod exclusive_system_param;
 mod function_system;
 mod input;
 mod observer_system;
+#[cfg(feature = "std")]
+mod pinned_system;
 mod query;
 mod schedule_system;
 #[allow(clippy::module_inception)]
@@ -147,6 +149,8 @@ pub use exclusive_system_param::*;
 pub use function_system::*;
 pub use input::*;
 pub use observer_system::*;
+#[cfg(feature = "std")]
+pub use pinned_system::*;
 pub use query::*;
 pub use schedule_system::*;
 pub use system::*;
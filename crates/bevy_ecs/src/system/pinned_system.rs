@@ -0,0 +1,168 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+use crate::{
+    archetype::ArchetypeComponentId,
+    component::{ComponentId, Tick},
+    query::Access,
+    result::Result,
+    schedule::{InternedSystemSet, PinnedThread, PinnedThreads},
+    system::{input::SystemIn, ScheduleSystem, System},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
+};
+
+/// A [`System`] that always runs on a named [`PinnedThread`].
+///
+/// This is created by [`IntoSystemConfigs::pin_to_thread`](crate::schedule::IntoSystemConfigs::pin_to_thread).
+/// The multi-threaded executor hands it to its thread without blocking a worker, while other
+/// executors block until it has run there. The thread is taken from the [`PinnedThreads`] of the
+/// world the system is initialized with.
+pub struct PinnedSystem {
+    system: ScheduleSystem,
+    thread_name: Cow<'static, str>,
+    thread: Option<Arc<PinnedThread>>,
+}
+
+impl PinnedSystem {
+    /// Creates a [`PinnedSystem`] that runs `system` on the thread named `thread_name`.
+    pub fn new(system: ScheduleSystem, thread_name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            system,
+            thread_name: thread_name.into(),
+            thread: None,
+        }
+    }
+
+    /// Returns the name of the thread the system runs on.
+    pub fn thread_name(&self) -> &str {
+        &self.thread_name
+    }
+
+    fn thread(&self) -> Arc<PinnedThread> {
+        self.thread.clone().unwrap_or_else(|| {
+            panic!(
+                "PinnedSystem `{}` must be initialized before it is run",
+                self.system.name()
+            )
+        })
+    }
+}
+
+impl System for PinnedSystem {
+    type In = ();
+    type Out = Result;
+
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        self.system.name()
+    }
+
+    #[inline]
+    fn type_id(&self) -> core::any::TypeId {
+        self.system.type_id()
+    }
+
+    #[inline]
+    fn component_access(&self) -> &Access<ComponentId> {
+        self.system.component_access()
+    }
+
+    #[inline]
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        self.system.archetype_component_access()
+    }
+
+    #[inline]
+    fn is_send(&self) -> bool {
+        self.system.is_send()
+    }
+
+    #[inline]
+    fn is_exclusive(&self) -> bool {
+        self.system.is_exclusive()
+    }
+
+    #[inline]
+    fn pinned_thread(&self) -> Option<&Arc<PinnedThread>> {
+        self.thread.as_ref()
+    }
+
+    #[inline]
+    fn has_deferred(&self) -> bool {
+        self.system.has_deferred()
+    }
+
+    unsafe fn run_unsafe(
+        &mut self,
+        input: SystemIn<'_, Self>,
+        world: UnsafeWorldCell,
+    ) -> Self::Out {
+        let thread = self.thread();
+        let system = &mut self.system;
+        // SAFETY: the caller upholds the requirements of `run_unsafe`, and `PinnedThread::run`
+        // blocks until the system has finished running.
+        thread.run(move || unsafe { system.run_unsafe(input, world) })
+    }
+
+    fn run(&mut self, input: SystemIn<'_, Self>, world: &mut World) -> Self::Out {
+        let thread = self.thread();
+        let system = &mut self.system;
+        thread.run(move || system.run(input, world))
+    }
+
+    #[inline]
+    fn apply_deferred(&mut self, world: &mut World) {
+        self.system.apply_deferred(world);
+    }
+
+    #[inline]
+    fn queue_deferred(&mut self, world: DeferredWorld) {
+        self.system.queue_deferred(world);
+    }
+
+    #[inline]
+    unsafe fn validate_param_unsafe(&mut self, world: UnsafeWorldCell) -> bool {
+        // SAFETY: Delegate to other `System` implementations.
+        unsafe { self.system.validate_param_unsafe(world) }
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        if self.thread.is_none() {
+            let thread = world
+                .get_resource_or_init::<PinnedThreads>()
+                .get_or_spawn(&self.thread_name);
+            self.thread = Some(thread);
+        }
+        self.system.initialize(world);
+    }
+
+    #[inline]
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell) {
+        self.system.update_archetype_component_access(world);
+    }
+
+    #[inline]
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.system.check_change_tick(change_tick);
+    }
+
+    #[inline]
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        self.system.default_system_sets()
+    }
+
+    #[inline]
+    fn get_last_run(&self) -> Tick {
+        self.system.get_last_run()
+    }
+
+    #[inline]
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.system.set_last_run(last_run);
+    }
+}
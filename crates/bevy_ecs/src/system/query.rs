@@ -1,17 +1,22 @@
 use crate::{
     batching::BatchingStrategy,
-    component::Tick,
-    entity::{Entity, EntityBorrow, EntitySet},
+    change_detection::{DetectChanges, Ref},
+    component::{Component, Tick},
+    entity::{Entity, EntityBorrow, EntityHashMap, EntitySet},
     query::{
-        QueryCombinationIter, QueryData, QueryEntityError, QueryFilter, QueryIter, QueryManyIter,
-        QueryManyUniqueIter, QueryParIter, QuerySingleError, QueryState, ROQueryItem,
-        ReadOnlyQueryData,
+        CombinationCache, QueryCombinationIter, QueryData, QueryEntityError, QueryFilter,
+        QueryIter, QueryManyIter, QueryManyUniqueIter, QueryParIter, QuerySingleError,
+        QuerySortedIter, QueryState, QueryTransmuteError, ROQueryItem, ReadOnlyQueryData,
+        SortCache,
     },
     world::unsafe_world_cell::UnsafeWorldCell,
 };
+use alloc::vec::Vec;
 use core::{
+    iter,
     marker::PhantomData,
     ops::{Deref, DerefMut},
+    slice,
 };
 
 /// [System parameter] that provides selective access to the [`Component`] data stored in a [`World`].
@@ -523,6 +528,112 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the read-only query items, sorted by the key component `K`.
+    ///
+    /// Entities with equal keys keep their relative order from the previous sort.
+    ///
+    /// Unlike [`QueryIter::sort`], the order is kept in `cache` across system runs, and is only
+    /// recomputed when a `K` component of a matching entity changed or the set of matching
+    /// entities changed. Detecting this still visits every matching entity, but skips fetching
+    /// and comparing the keys.
+    ///
+    /// `cache` must only ever be used with this query.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the query does not have read access to `K`,
+    /// see [`transmute_lens`](Self::transmute_lens).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::query::SortCache;
+    /// #
+    /// #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct TurnOrder(u32);
+    ///
+    /// # #[derive(Component)]
+    /// # struct Name(&'static str);
+    /// fn take_turns(query: Query<(&Name, &TurnOrder)>, mut order: Local<SortCache<TurnOrder>>) {
+    ///     for (name, _) in query.iter_sorted_by_key(&mut order) {
+    ///         println!("{}'s turn!", name.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(take_turns);
+    /// ```
+    ///
+    /// [`QueryIter::sort`]: crate::query::QueryIter::sort
+    pub fn iter_sorted_by_key<'a, K: Component + Ord>(
+        &'a self,
+        cache: &'a mut SortCache<K>,
+    ) -> QuerySortedIter<'a, 's, D::ReadOnly, F, iter::Copied<slice::Iter<'a, Entity>>> {
+        let lens_state = self
+            .state
+            .transmute_filtered::<(Entity, Ref<K>), F>(self.world);
+        // SAFETY:
+        // - `self.world` has permission to read `K`, as checked by `transmute_filtered`.
+        // - The lens is read-only and dropped before any query items are returned.
+        let lens = || unsafe {
+            lens_state.iter_unchecked_manual(self.world, self.last_run, self.this_run)
+        };
+
+        let mut is_valid = false;
+        if let Some(tick) = cache.tick {
+            let mut count = 0;
+            is_valid = true;
+            for (entity, key) in lens() {
+                if cache.unsorted.get(count) != Some(&entity)
+                    || key.last_changed().is_newer_than(tick, self.this_run)
+                {
+                    is_valid = false;
+                    break;
+                }
+                count += 1;
+            }
+            is_valid &= count == cache.unsorted.len();
+        }
+
+        if !is_valid {
+            let mut keyed: Vec<_> = lens().collect();
+            cache.unsorted.clear();
+            cache
+                .unsorted
+                .extend(keyed.iter().map(|(entity, _)| *entity));
+            // Sort by the previous order first, so that equal keys stay where they were.
+            if !cache.sorted.is_empty() {
+                let previous: EntityHashMap<usize> = cache
+                    .sorted
+                    .iter()
+                    .enumerate()
+                    .map(|(index, entity)| (*entity, index))
+                    .collect();
+                keyed
+                    .sort_by_key(|(entity, _)| previous.get(entity).copied().unwrap_or(usize::MAX));
+            }
+            keyed.sort_by(|(_, a), (_, b)| a.cmp(b));
+            cache.sorted.clear();
+            cache
+                .sorted
+                .extend(keyed.into_iter().map(|(entity, _)| entity));
+            cache.tick = Some(self.this_run);
+        }
+
+        // SAFETY:
+        // - `self.world` has permission to access the required components.
+        // - The query is read-only, so it can be aliased even if it was originally mutable.
+        // - The sorted entities are unique, since they were collected from a query.
+        unsafe {
+            QuerySortedIter::new(
+                self.world,
+                self.state.as_readonly(),
+                cache.sorted.iter().copied(),
+                self.last_run,
+                self.this_run,
+            )
+        }
+    }
+
     /// Returns a [`QueryCombinationIter`] over all combinations of `K` read-only query items without repetition.
     ///
     /// This iterator is always guaranteed to return results from each unique pair of matching entities.
@@ -594,6 +705,55 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over all combinations of `K` read-only query items without
+    /// repetition, like [`iter_combinations`](Self::iter_combinations), but keeps the list of
+    /// combinations in `cache` across runs.
+    ///
+    /// The cache is only updated incrementally when entities start or stop matching the query,
+    /// so iterating is `O(n + c)` for `n` matching entities and `c` combinations, instead of
+    /// building every combination anew. This helps with mostly static sets of entities, such as
+    /// the candidate pairs of a collision broad phase.
+    ///
+    /// `cache` must only ever be used with this query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::query::CombinationCache;
+    /// # #[derive(Component)]
+    /// # struct Collider;
+    /// #
+    /// fn broad_phase(query: Query<&Collider>, mut pairs: Local<CombinationCache<2>>) {
+    ///     for [a, b] in query.iter_combinations_cached(&mut pairs) {
+    ///         // ...
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(broad_phase);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`CombinationCache::update`] to keep a cache up to date without fetching query items.
+    pub fn iter_combinations_cached<'a, const K: usize>(
+        &'a self,
+        cache: &'a mut CombinationCache<K>,
+    ) -> impl Iterator<Item = [ROQueryItem<'a, D>; K]> + 'a {
+        let entities = self.state.transmute_filtered::<Entity, F>(self.world);
+        // SAFETY:
+        // - `self.world` has permission to access the required components.
+        // - The lens only reads entities, which never conflicts with the query.
+        cache.update(unsafe {
+            entities.iter_unchecked_manual(self.world, self.last_run, self.this_run)
+        });
+        // Shorten the lifetimes of the query, so that the returned iterator only captures `'a`.
+        let query: &'a Query<'a, 'a, D, F> = self;
+        cache
+            .combinations()
+            .iter()
+            .filter_map(move |combination| query.get_many(*combination).ok())
+    }
+
     /// Returns an [`Iterator`] over the read-only query items generated from an [`Entity`] list.
     ///
     /// Items are returned in the order of the list of entities, and may not be unique if the input
@@ -1636,6 +1796,40 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         self.transmute_lens_filtered::<NewD, ()>()
     }
 
+    /// Like [`Self::transmute_lens`], but returns an error instead of panicking if `NewD` is not
+    /// a valid transmute of this query.
+    ///
+    /// This allows checking whether a transmute is valid when the types are only known generically.
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, query::QueryTransmuteError};
+    /// #[derive(Component)]
+    /// struct A(usize);
+    ///
+    /// #[derive(Component)]
+    /// struct B(usize);
+    ///
+    /// fn system(mut query: Query<(&mut A, &B)>) {
+    ///     // A read-only view of a subset of the components is allowed...
+    ///     assert!(query.try_transmute_lens::<&A>().is_ok());
+    ///     // ...but gaining write access is not.
+    ///     assert!(matches!(
+    ///         query.try_transmute_lens::<&mut B>(),
+    ///         Err(QueryTransmuteError::AccessNotAllowed { .. })
+    ///     ));
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((A(0), B(0)));
+    /// # let mut schedule = Schedule::default();
+    /// # schedule.add_systems(system);
+    /// # schedule.run(&mut world);
+    /// ```
+    pub fn try_transmute_lens<NewD: QueryData>(
+        &mut self,
+    ) -> Result<QueryLens<'_, NewD>, QueryTransmuteError> {
+        self.try_transmute_lens_filtered::<NewD, ()>()
+    }
+
     /// Equivalent to [`Self::transmute_lens`] but also includes a [`QueryFilter`] type.
     ///
     /// Note that the lens will iterate the same tables and archetypes as the original query. This means that
@@ -1646,13 +1840,25 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
     pub fn transmute_lens_filtered<NewD: QueryData, NewF: QueryFilter>(
         &mut self,
     ) -> QueryLens<'_, NewD, NewF> {
-        let state = self.state.transmute_filtered::<NewD, NewF>(self.world);
-        QueryLens {
+        self.try_transmute_lens_filtered()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Equivalent to [`Self::try_transmute_lens`] but also includes a [`QueryFilter`] type.
+    ///
+    /// See [`Self::transmute_lens_filtered`] for how the filter is evaluated.
+    pub fn try_transmute_lens_filtered<NewD: QueryData, NewF: QueryFilter>(
+        &mut self,
+    ) -> Result<QueryLens<'_, NewD, NewF>, QueryTransmuteError> {
+        let state = self
+            .state
+            .try_transmute_filtered::<NewD, NewF>(self.world)?;
+        Ok(QueryLens {
             world: self.world,
             state,
             last_run: self.last_run,
             this_run: self.this_run,
-        }
+        })
     }
 
     /// Gets a [`QueryLens`] with the same accesses as the existing query
@@ -30,6 +30,11 @@ impl<S: System<In = (), Out = ()>> System for InfallibleSystemWrapper<S> {
         self.0.name()
     }
 
+    #[inline]
+    fn type_id(&self) -> core::any::TypeId {
+        self.0.type_id()
+    }
+
     #[inline]
     fn component_access(&self) -> &Access<ComponentId> {
         self.0.component_access()
@@ -14,6 +14,15 @@ use crate::{
 use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 use core::any::TypeId;
 
+#[cfg(feature = "std")]
+use crate::schedule::PinnedThread;
+
+#[cfg(all(feature = "std", feature = "portable-atomic"))]
+use portable_atomic_util::Arc;
+
+#[cfg(all(feature = "std", not(feature = "portable-atomic")))]
+use alloc::sync::Arc;
+
 use super::IntoSystem;
 
 /// An ECS system that can be added to a [`Schedule`](crate::schedule::Schedule)
@@ -50,6 +59,14 @@ pub trait System: Send + Sync + 'static {
     /// Returns true if the system must be run exclusively.
     fn is_exclusive(&self) -> bool;
 
+    /// Returns the [`PinnedThread`](crate::schedule::PinnedThread) this system must run on, if it
+    /// was pinned to one with [`pin_to_thread`](crate::schedule::IntoSystemConfigs::pin_to_thread)
+    /// and initialized.
+    #[cfg(feature = "std")]
+    fn pinned_thread(&self) -> Option<&Arc<PinnedThread>> {
+        None
+    }
+
     /// Returns true if system has deferred buffers.
     fn has_deferred(&self) -> bool;
 
@@ -7,11 +7,15 @@ use crate::{
     system::{input::SystemInput, BoxedSystem, IntoSystem, System},
     world::World,
 };
-use alloc::boxed::Box;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+};
 use bevy_ecs_macros::{require, Component, Resource};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
-use core::marker::PhantomData;
+use bevy_utils::HashMap;
+use core::{any::TypeId, marker::PhantomData};
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
@@ -129,6 +133,48 @@ impl<I: SystemInput, O> core::fmt::Debug for SystemId<I, O> {
 #[derive(Resource)]
 pub struct CachedSystemId<S: System>(pub SystemId<S::In, S::Out>);
 
+/// A registry of [`SystemId`]s keyed by name, so one-shot systems can be looked up and run
+/// without holding on to their ids, for example from tooling or scripting layers.
+///
+/// This resource is inserted by [`World::register_system_named`].
+#[derive(Resource, Default)]
+pub struct NamedSystemIds {
+    ids: HashMap<Cow<'static, str>, NamedSystemId>,
+}
+
+/// The untyped [`SystemId`] of a named system, along with the type of its input and output.
+struct NamedSystemId {
+    entity: Entity,
+    signature: TypeId,
+}
+
+impl NamedSystemIds {
+    /// Returns the [`SystemId`] registered under `name`.
+    ///
+    /// Returns `None` if no system was registered under `name`, or if the system's
+    /// input and output types do not match `I` and `O`.
+    pub fn get<I, O>(&self, name: &str) -> Option<SystemId<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        self.ids
+            .get(name)
+            .filter(|id| id.signature == TypeId::of::<RegisteredSystem<I, O>>())
+            .map(|id| SystemId::from_entity(id.entity))
+    }
+
+    /// Returns `true` if a system was registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.ids.contains_key(name)
+    }
+
+    /// Iterates over the names of all registered systems, along with the [`Entity`] holding each system.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.ids.iter().map(|(name, id)| (name.as_ref(), id.entity))
+    }
+}
+
 impl World {
     /// Registers a system and returns a [`SystemId`] so it can later be called by [`World::run_system`].
     ///
@@ -456,6 +502,112 @@ impl World {
     }
 }
 
+impl World {
+    /// Registers a system under the given `name` and returns its [`SystemId`].
+    ///
+    /// The name is stored in the [`NamedSystemIds`] resource, so the system can later be looked up
+    /// with [`World::named_system_id`] or run with [`World::run_system_named`], without keeping its
+    /// [`SystemId`] around.
+    ///
+    /// If a system was already registered under `name`, it is unregistered and replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// fn double(In(value): In<u32>) -> u32 {
+    ///     value * 2
+    /// }
+    ///
+    /// let mut world = World::default();
+    /// world.register_system_named("double", double);
+    /// assert_eq!(world.run_system_named_with::<In<u32>, u32>("double", 21).unwrap(), 42);
+    /// ```
+    pub fn register_system_named<I, O, M>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self.register_system(system);
+        let previous = self.get_resource_or_init::<NamedSystemIds>().ids.insert(
+            name.into(),
+            NamedSystemId {
+                entity: id.entity,
+                signature: TypeId::of::<RegisteredSystem<I, O>>(),
+            },
+        );
+        if let Some(previous) = previous {
+            self.despawn(previous.entity);
+        }
+        id
+    }
+
+    /// Returns the [`SystemId`] of the system registered under `name` with [`World::register_system_named`].
+    ///
+    /// Returns `None` if there is no such system, or if its input and output types are not `I` and `O`.
+    pub fn named_system_id<I, O>(&self, name: &str) -> Option<SystemId<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        self.get_resource::<NamedSystemIds>()?.get(name)
+    }
+
+    /// Removes the system registered under `name` and returns it.
+    ///
+    /// See [`World::register_system_named`] for more information.
+    pub fn unregister_system_named<I, O>(
+        &mut self,
+        name: &str,
+    ) -> Result<RemovedSystem<I, O>, RegisteredSystemError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self.named_system_id::<I, O>(name).ok_or_else(|| {
+            RegisteredSystemError::SystemNameNotRegistered(name.to_owned().into())
+        })?;
+        let removed = self.unregister_system(id)?;
+        self.resource_mut::<NamedSystemIds>().ids.remove(name);
+        Ok(removed)
+    }
+
+    /// Runs the system registered under `name`.
+    ///
+    /// See [`World::register_system_named`] for more information.
+    pub fn run_system_named<O: 'static>(
+        &mut self,
+        name: &str,
+    ) -> Result<O, RegisteredSystemError<(), O>> {
+        self.run_system_named_with(name, ())
+    }
+
+    /// Runs the system registered under `name`, providing an input value.
+    ///
+    /// Returns [`RegisteredSystemError::SystemNameNotRegistered`] if there is no system with that name,
+    /// or if its input and output types are not `I` and `O`.
+    ///
+    /// See [`World::register_system_named`] for more information.
+    pub fn run_system_named_with<I, O>(
+        &mut self,
+        name: &str,
+        input: I::Inner<'_>,
+    ) -> Result<O, RegisteredSystemError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self.named_system_id::<I, O>(name).ok_or_else(|| {
+            RegisteredSystemError::SystemNameNotRegistered(name.to_owned().into())
+        })?;
+        self.run_system_with(id, input)
+    }
+}
+
 /// An operation with stored systems failed.
 #[derive(Error)]
 pub enum RegisteredSystemError<I: SystemInput = (), O = ()> {
@@ -469,6 +621,11 @@ pub enum RegisteredSystemError<I: SystemInput = (), O = ()> {
     /// Did you forget to register it?
     #[error("Cached system was not found")]
     SystemNotCached,
+    /// A system was looked up by name, but no system with that name and signature was found.
+    ///
+    /// Did you forget to register it, or use the wrong input or output type?
+    #[error("No system named `{0}` with a matching signature was registered")]
+    SystemNameNotRegistered(Cow<'static, str>),
     /// A system tried to run itself recursively.
     #[error("System {0:?} tried to run itself recursively")]
     Recursive(SystemId<I, O>),
@@ -489,6 +646,10 @@ impl<I: SystemInput, O> core::fmt::Debug for RegisteredSystemError<I, O> {
                 f.debug_tuple("SystemIdNotRegistered").field(arg0).finish()
             }
             Self::SystemNotCached => write!(f, "SystemNotCached"),
+            Self::SystemNameNotRegistered(arg0) => f
+                .debug_tuple("SystemNameNotRegistered")
+                .field(arg0)
+                .finish(),
             Self::Recursive(arg0) => f.debug_tuple("Recursive").field(arg0).finish(),
             Self::SelfRemove(arg0) => f.debug_tuple("SelfRemove").field(arg0).finish(),
             Self::InvalidParams(arg0) => f.debug_tuple("InvalidParams").field(arg0).finish(),
@@ -756,6 +917,47 @@ mod tests {
         assert!(matches!(output, Ok(8)));
     }
 
+    #[test]
+    fn named_system() {
+        use crate::system::{NamedSystemIds, RegisteredSystemError};
+
+        fn add(In(i): In<i32>, mut counter: ResMut<Counter>) -> i32 {
+            counter.0 += 1;
+            i + 1
+        }
+
+        fn four() -> i32 {
+            4
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+
+        let id = world.register_system_named("add", add);
+        assert_eq!(world.named_system_id::<In<i32>, i32>("add"), Some(id));
+        assert_eq!(world.named_system_id::<(), i32>("add"), None);
+        let output = world.run_system_named_with::<In<i32>, i32>("add", 1);
+        assert!(matches!(output, Ok(2)));
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        let output = world.run_system_named::<i32>("add");
+        assert!(matches!(
+            output,
+            Err(RegisteredSystemError::SystemNameNotRegistered(name)) if name == "add",
+        ));
+
+        // Registering under the same name replaces the previous system.
+        let new = world.register_system_named("add", four);
+        let output = world.run_system_named::<i32>("add");
+        assert!(matches!(output, Ok(4)));
+        assert!(world.get_entity(id.entity()).is_err());
+
+        let removed = world.unregister_system_named::<(), i32>("add");
+        assert!(removed.is_ok());
+        assert!(world.get_entity(new.entity()).is_err());
+        assert!(!world.resource::<NamedSystemIds>().contains("add"));
+    }
+
     #[test]
     fn system_with_input_ref() {
         fn with_ref(InRef(input): InRef<u8>, mut counter: ResMut<Counter>) {
@@ -1,6 +1,10 @@
-use crate::system::{SystemBuffer, SystemMeta};
+use crate::system::{
+    entity_command::{InsertBundle, InsertBundles},
+    MergeCommand, SystemBuffer, SystemMeta,
+};
 
 use core::{
+    any::TypeId,
     fmt::Debug,
     marker::PhantomData,
     mem::{size_of, MaybeUninit},
@@ -12,7 +16,7 @@ use alloc::{boxed::Box, vec::Vec};
 use bevy_ptr::{OwningPtr, Unaligned};
 use log::warn;
 
-use crate::{system::Command, world::World};
+use crate::{bundle::Bundle, entity::Entity, system::Command, world::World};
 
 use super::DeferredWorld;
 
@@ -43,6 +47,29 @@ pub struct CommandQueue {
     pub(crate) bytes: Vec<MaybeUninit<u8>>,
     pub(crate) cursor: usize,
     pub(crate) panic_recovery: Vec<MaybeUninit<u8>>,
+    // The last command pushed, if the next one may be merged into it.
+    pub(crate) merge_slot: Option<MergeSlot>,
+}
+
+/// A command at the end of a [`CommandQueue`] that the next pushed command may be merged into.
+pub(crate) struct MergeSlot {
+    /// Offset of the command's `CommandMeta` in `bytes`.
+    start: usize,
+    /// Length of `bytes` right after the command was pushed, which any later push changes.
+    end: usize,
+    kind: MergeKind,
+}
+
+#[derive(Clone, Copy)]
+enum MergeKind {
+    /// A [`MergeCommand`] of this type.
+    Command(TypeId),
+    /// An [`InsertBundle`] or [`InsertBundles`] command on this entity, which `take` moves out of
+    /// the queue as [`InsertBundles`].
+    Insert {
+        entity: Entity,
+        take: unsafe fn(OwningPtr<'_, Unaligned>) -> InsertBundles,
+    },
 }
 
 /// Wraps pointers to a [`CommandQueue`], used internally to avoid stacked borrow rules when
@@ -87,10 +114,116 @@ impl CommandQueue {
         }
     }
 
+    /// Push a [`MergeCommand`] onto the queue, merging it into the last command if that one was
+    /// also pushed with this method, with the same type.
+    pub fn push_merged<C: MergeCommand>(&mut self, command: C) {
+        let type_id = TypeId::of::<C>();
+        match self.last_mergeable() {
+            Some(&MergeSlot {
+                start,
+                kind: MergeKind::Command(last),
+                ..
+            }) if last == type_id => {
+                // SAFETY: The last command of the queue was pushed by this method with the type `C`.
+                let mut last = unsafe { self.pop_last(start, |ptr| ptr.read_unaligned::<C>()) };
+                let rejected = last.merge(command);
+                self.push_mergeable(last, MergeKind::Command(type_id));
+                if let Some(next) = rejected {
+                    self.push_mergeable(next, MergeKind::Command(type_id));
+                }
+            }
+            _ => self.push_mergeable(command, MergeKind::Command(type_id)),
+        }
+    }
+
+    /// Push a command inserting `bundle` on `entity` onto the queue. If the last command also
+    /// inserts on `entity`, both are merged into a single [`InsertBundles`] command, which moves
+    /// the entity to its new archetype only once.
+    pub(crate) fn push_insert<B: Bundle>(
+        &mut self,
+        entity: Entity,
+        bundle: B,
+        #[cfg(feature = "track_location")] caller: &'static core::panic::Location<'static>,
+    ) {
+        match self.last_mergeable() {
+            Some(&MergeSlot {
+                start,
+                kind: MergeKind::Insert { entity: last, take },
+                ..
+            }) if last == entity => {
+                // SAFETY: The last command of the queue was pushed by this method, with the type
+                // `take` was created for.
+                let mut command = unsafe { self.pop_last(start, take) };
+                command.bundles.push(Box::new(bundle));
+                let kind = MergeKind::Insert {
+                    entity,
+                    // SAFETY: The caller ensures `ptr` points to an `InsertBundles`.
+                    take: |ptr| unsafe { ptr.read_unaligned::<InsertBundles>() },
+                };
+                self.push_mergeable(command, kind);
+            }
+            _ => {
+                let kind = MergeKind::Insert {
+                    entity,
+                    // SAFETY: The caller ensures `ptr` points to an `InsertBundle<B>`.
+                    take: |ptr| unsafe { ptr.read_unaligned::<InsertBundle<B>>() }.into_bundles(),
+                };
+                let command = InsertBundle {
+                    entity,
+                    bundle,
+                    #[cfg(feature = "track_location")]
+                    caller,
+                };
+                self.push_mergeable(command, kind);
+            }
+        }
+    }
+
+    /// Returns the last command of the queue, if it can be merged into.
+    fn last_mergeable(&self) -> Option<&MergeSlot> {
+        let slot = self.merge_slot.as_ref()?;
+        (slot.end == self.bytes.len() && slot.start >= self.cursor).then_some(slot)
+    }
+
+    fn push_mergeable<C: Command>(&mut self, command: C, kind: MergeKind) {
+        let start = self.bytes.len();
+        self.push(command);
+        self.merge_slot = Some(MergeSlot {
+            start,
+            end: self.bytes.len(),
+            kind,
+        });
+    }
+
+    /// Moves the last command out of the queue with `take`.
+    ///
+    /// # Safety
+    ///
+    /// The last command of the queue must start at `start`, and `take` must read it with its type.
+    unsafe fn pop_last<T>(
+        &mut self,
+        start: usize,
+        take: unsafe fn(OwningPtr<'_, Unaligned>) -> T,
+    ) -> T {
+        self.merge_slot = None;
+        // SAFETY: The caller ensures a command starts at `start`, so it is followed by its value.
+        let command = unsafe {
+            let ptr = self
+                .bytes
+                .as_mut_ptr()
+                .add(start + size_of::<CommandMeta>());
+            take(OwningPtr::new(NonNull::new_unchecked(ptr.cast())))
+        };
+        // SAFETY: The command was moved out above, and `start` is within the queue.
+        unsafe { self.bytes.set_len(start) };
+        command
+    }
+
     /// Execute the queued [`Command`]s in the world after applying any commands in the world's internal queue.
     /// This clears the queue.
     #[inline]
     pub fn apply(&mut self, world: &mut World) {
+        self.merge_slot = None;
         // flush the previously queued entities
         world.flush_entities();
 
@@ -106,6 +239,7 @@ impl CommandQueue {
     /// Take all commands from `other` and append them to `self`, leaving `other` empty
     pub fn append(&mut self, other: &mut CommandQueue) {
         self.bytes.append(&mut other.bytes);
+        other.merge_slot = None;
     }
 
     /// Returns false if there are any commands in the queue
@@ -356,7 +490,7 @@ impl SystemBuffer for CommandQueue {
 mod test {
     use super::*;
     use crate::{self as bevy_ecs, result::Result, system::Resource};
-    use alloc::{borrow::ToOwned, string::String, sync::Arc};
+    use alloc::{borrow::ToOwned, string::String, sync::Arc, vec, vec::Vec};
     use core::{
         panic::AssertUnwindSafe,
         sync::atomic::{AtomicU32, Ordering},
@@ -533,6 +667,92 @@ mod test {
         assert_is_send(SpawnCommand);
     }
 
+    #[derive(Resource, Default)]
+    struct Merged(Vec<Vec<u32>>);
+
+    struct MergeIfEven(Vec<u32>);
+
+    impl Command for MergeIfEven {
+        fn apply(self, world: &mut World) -> Result {
+            world.resource_mut::<Merged>().0.push(self.0);
+            Ok(())
+        }
+    }
+
+    impl MergeCommand for MergeIfEven {
+        fn merge(&mut self, next: Self) -> Option<Self> {
+            if next.0.iter().any(|value| value % 2 == 1) {
+                return Some(next);
+            }
+            self.0.extend(next.0);
+            None
+        }
+    }
+
+    #[test]
+    fn test_command_queue_push_merged() {
+        let mut world = World::new();
+        world.init_resource::<Merged>();
+        let mut queue = CommandQueue::default();
+        queue.push_merged(MergeIfEven(vec![0]));
+        queue.push_merged(MergeIfEven(vec![2]));
+        queue.push_merged(MergeIfEven(vec![3]));
+        queue.push_merged(MergeIfEven(vec![4]));
+        // Only adjacent commands are merged.
+        queue.push(|_: &mut World| {});
+        queue.push_merged(MergeIfEven(vec![6]));
+        queue.apply(&mut world);
+        queue.push_merged(MergeIfEven(vec![8]));
+        queue.apply(&mut world);
+
+        assert_eq!(
+            world.resource::<Merged>().0,
+            vec![vec![0, 2], vec![3, 4], vec![6], vec![8]]
+        );
+    }
+
+    #[test]
+    fn test_command_queue_merges_inserts() {
+        use crate::{component::Component, system::Commands};
+
+        #[derive(Component)]
+        struct A(DropCheck);
+
+        #[derive(Component, PartialEq, Debug)]
+        struct B(u32);
+
+        #[derive(Component, PartialEq, Debug)]
+        struct C(u32);
+
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let (replaced, replaced_drops) = DropCheck::new();
+        let (kept, kept_drops) = DropCheck::new();
+        let archetypes = world.archetypes().len();
+
+        let mut commands = Commands::new(&mut queue, &world);
+        let entity = commands
+            .spawn_empty()
+            .insert_merged(A(replaced))
+            .insert_merged(B(1))
+            .id();
+        commands.entity(entity).insert_merged((A(kept), B(2)));
+        let other = commands.spawn_empty().insert_merged(C(0)).id();
+        commands.entity(other).insert_merged(B(0));
+        queue.apply(&mut world);
+
+        assert_eq!(replaced_drops.load(Ordering::Relaxed), 1);
+        assert_eq!(kept_drops.load(Ordering::Relaxed), 0);
+        assert!(Arc::ptr_eq(
+            &world.get::<A>(entity).unwrap().0 .0,
+            &kept_drops
+        ));
+        assert_eq!(world.get::<B>(entity), Some(&B(2)));
+        assert_eq!(world.get::<C>(other), Some(&C(0)));
+        // Only the final archetypes were created, without the intermediate ones.
+        assert_eq!(world.archetypes().len(), archetypes + 2);
+    }
+
     #[allow(dead_code)]
     struct CommandWithPadding(u8, u16);
     impl Command for CommandWithPadding {
@@ -504,8 +504,16 @@ impl<'w> DeferredWorld<'w> {
         entity: Entity,
         targets: impl Iterator<Item = ComponentId>,
     ) {
-        if archetype.has_add_hook() {
+        #[cfg(feature = "storage_access_stats")]
+        let access_stats = self.world.storage_access_stats();
+        #[cfg(feature = "storage_access_stats")]
+        let has_add_hook = archetype.has_add_hook() || access_stats.is_enabled();
+        #[cfg(not(feature = "storage_access_stats"))]
+        let has_add_hook = archetype.has_add_hook();
+        if has_add_hook {
             for component_id in targets {
+                #[cfg(feature = "storage_access_stats")]
+                access_stats.record_add(component_id);
                 // SAFETY: Caller ensures that these components exist
                 let hooks = unsafe { self.components().get_info_unchecked(component_id) }.hooks();
                 if let Some(hook) = hooks.on_add {
@@ -555,6 +563,9 @@ impl<'w> DeferredWorld<'w> {
                 if let Some(hook) = hooks.on_replace {
                     hook(DeferredWorld { world: self.world }, entity, component_id);
                 }
+                if let Some(hook) = hooks.on_replace_value {
+                    hook.run(DeferredWorld { world: self.world }, entity, component_id);
+                }
             }
         }
     }
@@ -570,13 +581,24 @@ impl<'w> DeferredWorld<'w> {
         entity: Entity,
         targets: impl Iterator<Item = ComponentId>,
     ) {
-        if archetype.has_remove_hook() {
+        #[cfg(feature = "storage_access_stats")]
+        let access_stats = self.world.storage_access_stats();
+        #[cfg(feature = "storage_access_stats")]
+        let has_remove_hook = archetype.has_remove_hook() || access_stats.is_enabled();
+        #[cfg(not(feature = "storage_access_stats"))]
+        let has_remove_hook = archetype.has_remove_hook();
+        if has_remove_hook {
             for component_id in targets {
+                #[cfg(feature = "storage_access_stats")]
+                access_stats.record_remove(component_id);
                 // SAFETY: Caller ensures that these components exist
                 let hooks = unsafe { self.components().get_info_unchecked(component_id) }.hooks();
                 if let Some(hook) = hooks.on_remove {
                     hook(DeferredWorld { world: self.world }, entity, component_id);
                 }
+                if let Some(hook) = hooks.on_remove_value {
+                    hook.run(DeferredWorld { world: self.world }, entity, component_id);
+                }
             }
         }
     }
@@ -596,6 +618,7 @@ impl<'w> DeferredWorld<'w> {
             self.reborrow(),
             event,
             target,
+            target,
             components,
             &mut (),
             &mut false,
@@ -617,11 +640,13 @@ impl<'w> DeferredWorld<'w> {
     ) where
         T: Traversal<E>,
     {
+        let original_target = target;
         loop {
             Observers::invoke::<_>(
                 self.reborrow(),
                 event,
                 target,
+                original_target,
                 components.iter().copied(),
                 data,
                 &mut propagate,
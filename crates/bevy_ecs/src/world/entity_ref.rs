@@ -1,6 +1,8 @@
 use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
-    bundle::{Bundle, BundleId, BundleInfo, BundleInserter, DynamicBundle, InsertMode},
+    bundle::{
+        Bundle, BundleId, BundleInfo, BundleInserter, DynamicBundle, ErasedBundle, InsertMode,
+    },
     change_detection::MutUntyped,
     component::{Component, ComponentId, ComponentTicks, Components, Mutable, StorageType},
     entity::{
@@ -8,13 +10,13 @@ use crate::{
     },
     event::Event,
     observer::Observer,
-    query::{Access, ReadOnlyQueryData},
+    query::{Access, DebugCheckedUnwrap, ReadOnlyQueryData},
     removal_detection::RemovedComponentEvents,
     storage::Storages,
     system::IntoObserverSystem,
     world::{error::EntityComponentError, DeferredWorld, Mut, World},
 };
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec, vec::Vec};
 use bevy_ptr::{OwningPtr, Ptr};
 use bevy_utils::{HashMap, HashSet};
 #[cfg(feature = "track_location")]
@@ -24,7 +26,8 @@ use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
     marker::PhantomData,
-    mem::MaybeUninit,
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr::NonNull,
 };
 use thiserror::Error;
 
@@ -270,6 +273,22 @@ impl<'w> EntityRef<'w> {
         unsafe { component_ids.fetch_ref(self.cell) }
     }
 
+    /// Returns the bytes of the plain-data component with the given [`ComponentId`], if the
+    /// entity has it.
+    ///
+    /// Returns `None` if the component wasn't registered with
+    /// [`ComponentDescriptor::new_plain_data`](crate::component::ComponentDescriptor::new_plain_data).
+    pub fn get_plain_data(&self, component_id: ComponentId) -> Option<&'w [u8]> {
+        let info = self.cell.world().components().get_info(component_id)?;
+        if !info.is_plain_data() {
+            return None;
+        }
+        let size = info.layout().size();
+        let ptr = self.get_by_id(component_id).ok()?;
+        // SAFETY: the values of plain-data components are `size` initialized bytes.
+        Some(unsafe { core::slice::from_raw_parts(ptr.as_ptr(), size) })
+    }
+
     /// Returns read-only components for the current entity that match the query `Q`.
     ///
     /// # Panics
@@ -287,6 +306,99 @@ impl<'w> EntityRef<'w> {
         unsafe { self.cell.get_components::<Q>() }
     }
 
+    /// Returns an owned copy of the bundle `B` taken from this entity's components,
+    /// or `None` if the entity does not have every component of `B`.
+    ///
+    /// The entity is left untouched. This is useful for copy/paste in editors or for
+    /// recording undo state. See [`EntityRef::extract_dynamic`] for a reflection-based
+    /// alternative that works with components which aren't known at compile time.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Health(u32);
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Name(&'static str);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn((Health(10), Name("goblin"))).id();
+    ///
+    /// let snapshot = world.entity(entity).extract::<(Health, Name)>();
+    /// assert_eq!(snapshot, Some((Health(10), Name("goblin"))));
+    /// assert!(world.entity(entity).contains::<Health>());
+    /// ```
+    pub fn extract<B: Bundle + Clone>(&self) -> Option<B> {
+        let mut ptrs = Vec::new();
+        let mut has_all = true;
+        B::get_component_ids(self.cell.world().components(), &mut |id| match id
+            .and_then(|id| self.get_by_id(id).ok())
+        {
+            Some(ptr) => ptrs.push(ptr),
+            None => has_all = false,
+        });
+        if !has_all {
+            return None;
+        }
+
+        let mut ptrs = ptrs.into_iter();
+        // SAFETY:
+        // - `get_component_ids` yields components in the same order as `from_components` consumes
+        //   them, so every pointer matches the type it is read as.
+        // - The bundle is a bitwise copy of the entity's components. It is only accessed through a
+        //   shared reference to clone it and is never dropped, so the components are only read.
+        let copy = unsafe {
+            ManuallyDrop::new(B::from_components(&mut ptrs, &mut |ptrs| {
+                ptrs.next().debug_checked_unwrap().assert_unique().promote()
+            }))
+        };
+        Some(B::clone(&copy))
+    }
+
+    /// Returns owned, reflected copies of the components in `component_ids` that this entity has.
+    ///
+    /// Components that are missing from the entity, or whose type isn't registered in `registry`
+    /// with [`ReflectComponent`], are skipped. The returned values can be applied back onto an
+    /// entity with [`ReflectComponent::apply_or_insert`].
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Health>();
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Health(10)).id();
+    ///
+    /// let entity_ref = world.entity(entity);
+    /// let snapshot = entity_ref.extract_dynamic(&registry, entity_ref.archetype().components());
+    /// assert_eq!(snapshot.len(), 1);
+    /// assert!(snapshot[0].represents::<Health>());
+    /// ```
+    ///
+    /// [`ReflectComponent`]: crate::reflect::ReflectComponent
+    /// [`ReflectComponent::apply_or_insert`]: crate::reflect::ReflectComponent::apply_or_insert
+    #[cfg(feature = "bevy_reflect")]
+    pub fn extract_dynamic(
+        &self,
+        registry: &bevy_reflect::TypeRegistry,
+        component_ids: impl IntoIterator<Item = ComponentId>,
+    ) -> Vec<Box<dyn bevy_reflect::PartialReflect>> {
+        let components = self.cell.world().components();
+        component_ids
+            .into_iter()
+            .filter_map(|id| {
+                let type_id = components.get_info(id)?.type_id()?;
+                let reflect_component =
+                    registry.get_type_data::<crate::reflect::ReflectComponent>(type_id)?;
+                Some(reflect_component.reflect(*self)?.clone_value())
+            })
+            .collect()
+    }
+
     /// Returns the source code location from which this entity has been spawned.
     #[cfg(feature = "track_location")]
     pub fn spawned_by(&self) -> &'static Location<'static> {
@@ -1536,6 +1648,95 @@ impl<'w> EntityWorldMut<'w> {
         self
     }
 
+    /// Inserts several bundles with a single archetype move, replacing existing components.
+    ///
+    /// This has the same result as inserting the bundles one after another, except that
+    /// components present in several bundles only have their last value inserted, and hooks
+    /// and observers run once for all components, after every bundle has been inserted.
+    pub(crate) fn insert_bundles_with_caller(
+        &mut self,
+        bundles: Vec<Box<dyn ErasedBundle>>,
+        #[cfg(feature = "track_location")] caller: &'static Location,
+    ) -> &mut Self {
+        struct ErasedBundles {
+            bundles: Vec<Box<dyn ErasedBundle>>,
+            // For each component of `bundles`, `None` if it is inserted, or the drop function
+            // of a value replaced by a later bundle.
+            replaced: Vec<Option<Option<unsafe fn(OwningPtr<'_>)>>>,
+        }
+
+        impl DynamicBundle for ErasedBundles {
+            fn get_components(self, func: &mut impl FnMut(StorageType, OwningPtr<'_>)) {
+                let mut replaced = self.replaced.into_iter();
+                for bundle in self.bundles {
+                    bundle.get_components(&mut |storage_type, ptr| {
+                        match replaced.next() {
+                            // SAFETY: `ptr` is a value of the component this drop function belongs to.
+                            Some(Some(Some(drop))) => unsafe { drop(ptr) },
+                            Some(Some(None)) => {}
+                            _ => func(storage_type, ptr),
+                        }
+                    });
+                }
+            }
+        }
+
+        self.assert_not_despawned();
+        let mut component_ids = Vec::new();
+        for bundle in &bundles {
+            bundle.register_component_ids(self.world, &mut component_ids);
+        }
+        let mut inserted = Vec::with_capacity(component_ids.len());
+        let replaced = component_ids
+            .iter()
+            .enumerate()
+            .map(|(index, component_id)| {
+                if component_ids[index + 1..].contains(component_id) {
+                    Some(
+                        self.world
+                            .components
+                            .get_info(*component_id)
+                            .unwrap()
+                            .drop(),
+                    )
+                } else {
+                    inserted.push(*component_id);
+                    None
+                }
+            })
+            .collect();
+
+        let change_tick = self.world.change_tick();
+        let bundle_id = self
+            .world
+            .bundles
+            .init_dynamic_info(&self.world.components, &inserted);
+        // SAFETY: We just created this bundle
+        let mut bundle_inserter = unsafe {
+            BundleInserter::new_with_id(
+                self.world,
+                self.location.archetype_id,
+                bundle_id,
+                change_tick,
+            )
+        };
+        // SAFETY: location matches current entity. `ErasedBundles` passes the values of
+        // `inserted`, in order, and drops the others.
+        self.location = unsafe {
+            bundle_inserter.insert(
+                self.entity,
+                self.location,
+                ErasedBundles { bundles, replaced },
+                InsertMode::Replace,
+                #[cfg(feature = "track_location")]
+                caller,
+            )
+        };
+        self.world.flush();
+        self.update_location();
+        self
+    }
+
     /// Inserts a dynamic [`Component`] into the entity.
     ///
     /// This will overwrite any previous value(s) of the same component type.
@@ -1583,6 +1784,50 @@ impl<'w> EntityWorldMut<'w> {
         self
     }
 
+    /// Inserts the plain-data component with the given [`ComponentId`] into the entity, with
+    /// `data` as its bytes.
+    ///
+    /// This will overwrite any previous value of the same component.
+    ///
+    /// # Panics
+    ///
+    /// If the component wasn't registered in this world with
+    /// [`ComponentDescriptor::new_plain_data`](crate::component::ComponentDescriptor::new_plain_data),
+    /// or if the length of `data` isn't the size of its layout.
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    #[track_caller]
+    pub fn insert_plain_data(&mut self, component_id: ComponentId, data: &[u8]) -> &mut Self {
+        let info = self
+            .world
+            .components()
+            .get_info(component_id)
+            .filter(|info| info.is_plain_data())
+            .unwrap_or_else(|| {
+                panic!("{component_id:?} is not a plain-data component of this world")
+            });
+        let layout = info.layout();
+        assert_eq!(
+            data.len(),
+            layout.size(),
+            "The data of the plain-data component `{}` has the wrong size",
+            info.name()
+        );
+
+        // Copy the data to a properly aligned location.
+        let mut buffer = vec![0u8; layout.size() + layout.align()];
+        let offset = buffer.as_ptr().align_offset(layout.align());
+        let aligned = &mut buffer[offset..offset + layout.size()];
+        aligned.copy_from_slice(data);
+        // SAFETY:
+        // - `aligned` is valid for writes and aligned for the layout of the component.
+        // - The component is plain data, so any initialized bytes are a valid value of it.
+        // - The value has no drop function, so the copy left in `buffer` can be discarded.
+        unsafe {
+            let component = OwningPtr::new(NonNull::from(aligned).cast());
+            self.insert_by_id(component_id, component)
+        }
+    }
+
     /// Inserts a dynamic [`Bundle`] into the entity.
     ///
     /// This will overwrite any previous value(s) of the same component type.
@@ -1737,6 +1982,121 @@ impl<'w> EntityWorldMut<'w> {
         Some(result)
     }
 
+    /// Removes all components from the entity without dropping them, passing their
+    /// [`ComponentId`]s and values to `f` in the same order.
+    ///
+    /// Hooks and observers run as if the components were removed. `f` is responsible for taking
+    /// ownership of the values, which are otherwise leaked.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub(crate) fn take_all_with(&mut self, f: impl FnOnce(&[ComponentId], Vec<OwningPtr<'_>>)) {
+        self.assert_not_despawned();
+        let component_ids: Vec<ComponentId> = self.archetype().components().collect();
+        let world = &mut self.world;
+        let bundle_id = world
+            .bundles
+            .init_dynamic_info(&world.components, &component_ids);
+        // SAFETY: the `BundleInfo` for this `bundle_id` is initialized above
+        let bundle_info = unsafe { world.bundles.get_unchecked(bundle_id) };
+        let old_location = self.location;
+        // SAFETY: `archetype_id` exists because it is referenced in the old `EntityLocation` which is valid,
+        // and the entity has every component of the bundle, since it was built from its archetype
+        let new_archetype_id = unsafe {
+            bundle_info
+                .remove_bundle_from_archetype(
+                    &mut world.archetypes,
+                    &mut world.storages,
+                    &world.components,
+                    &world.observers,
+                    old_location.archetype_id,
+                    false,
+                )
+                .debug_checked_unwrap()
+        };
+
+        if new_archetype_id == old_location.archetype_id {
+            f(&[], Vec::new());
+            return;
+        }
+
+        let entity = self.entity;
+        // SAFETY: Archetypes and Bundles cannot be mutably aliased through DeferredWorld
+        let (old_archetype, bundle_info, mut deferred_world) = unsafe {
+            let bundle_info: *const BundleInfo = bundle_info;
+            let world = world.as_unsafe_world_cell();
+            (
+                &world.archetypes()[old_location.archetype_id],
+                &*bundle_info,
+                world.into_deferred(),
+            )
+        };
+
+        // SAFETY: all bundle components exist in World
+        unsafe {
+            trigger_on_replace_and_on_remove_hooks_and_observers(
+                &mut deferred_world,
+                old_archetype,
+                entity,
+                bundle_info,
+            );
+        }
+
+        let archetypes = &mut world.archetypes;
+        let storages = &mut world.storages;
+        let components = &world.components;
+        let entities = &mut world.entities;
+        let removed_components = &mut world.removed_components;
+
+        let values = component_ids
+            .iter()
+            .map(|&component_id| {
+                // SAFETY:
+                // - entity location is valid
+                // - table row is removed below, without dropping the contents
+                // - `components` comes from the same world as `storages`
+                let value = unsafe {
+                    take_component(
+                        storages,
+                        components,
+                        removed_components,
+                        component_id,
+                        entity,
+                        old_location,
+                    )
+                };
+                value.as_ptr()
+            })
+            .collect::<Vec<_>>();
+        let values = values
+            .into_iter()
+            .map(|ptr| {
+                // SAFETY: Each pointer was taken from a different table column or sparse set, and
+                // stays valid until the table row is removed below.
+                unsafe { OwningPtr::new(NonNull::new_unchecked(ptr)) }
+            })
+            .collect();
+        f(&component_ids, values);
+
+        // SAFETY: the new archetype has a subset of the components of the old one, and the taken
+        // values are forgotten instead of dropped
+        unsafe {
+            Self::move_entity_from_remove::<false>(
+                entity,
+                &mut self.location,
+                old_location.archetype_id,
+                old_location,
+                entities,
+                archetypes,
+                storages,
+                new_archetype_id,
+            );
+        }
+        self.world.flush();
+        self.update_location();
+    }
+
     /// # Safety
     ///
     /// `new_archetype_id` must have the same or a subset of the components
@@ -4140,7 +4500,11 @@ unsafe impl DynamicComponentFetch for &'_ HashSet<ComponentId> {
 
 #[cfg(test)]
 mod tests {
-    use alloc::{vec, vec::Vec};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
     use bevy_ptr::{OwningPtr, Ptr};
     use core::panic::AssertUnwindSafe;
 
@@ -4184,6 +4548,31 @@ mod tests {
         assert_eq!(test_component.0, 42);
     }
 
+    #[test]
+    fn entity_ref_extract() {
+        #[derive(Component, Clone, Debug, PartialEq)]
+        struct Label(String);
+
+        let mut world = World::new();
+        let entity = world
+            .spawn((Label("label".to_string()), TestComponent2(7)))
+            .id();
+
+        let extracted = world
+            .entity(entity)
+            .extract::<(TestComponent2, Label)>()
+            .unwrap();
+        assert_eq!(extracted, (TestComponent2(7), Label("label".to_string())));
+        assert!(world.entity(entity).extract::<TestComponent>().is_none());
+        assert!(world
+            .entity(entity)
+            .extract::<(Label, TestComponent)>()
+            .is_none());
+
+        drop(extracted);
+        world.despawn(entity);
+    }
+
     #[test]
     fn entity_mut_get_by_id() {
         let mut world = World::new();
@@ -1,5 +1,6 @@
 //! Contains error types returned by bevy's schedule.
 
+use alloc::string::String;
 use thiserror::Error;
 
 use crate::{
@@ -15,6 +16,45 @@ use crate::{
 #[error("The schedule with the label {0:?} was not found.")]
 pub struct TryRunScheduleError(pub InternedScheduleLabel);
 
+/// An error that occurs when creating a resource registered with [`World::init_resource_lazy`].
+///
+/// [`World::init_resource_lazy`]: crate::world::World::init_resource_lazy
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum LazyResourceError {
+    /// The resource does not exist and was not registered lazily.
+    #[error("The resource {0} does not exist and was not registered with `init_resource_lazy`.")]
+    NotRegistered(String),
+    /// A dependency of the resource does not exist and was not registered lazily.
+    #[error("The resource {resource} depends on {dependency}, which does not exist and was not registered with `init_resource_lazy`.")]
+    MissingDependency {
+        /// The name of the resource being created.
+        resource: String,
+        /// The name of the missing dependency.
+        dependency: String,
+    },
+    /// The dependencies of the resource form a cycle, formatted as `A -> B -> A`.
+    #[error("The dependencies of lazily initialized resources form a cycle: {0}")]
+    DependencyCycle(String),
+}
+
+/// An error that occurs when moving entities to another world with [`World::move_entities_to`].
+///
+/// No entity is moved when this error is returned.
+///
+/// [`World::move_entities_to`]: crate::world::World::move_entities_to
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MoveEntitiesError {
+    /// The entity with the given ID does not exist.
+    #[error("The entity with ID {0} does not exist.")]
+    NoSuchEntity(Entity),
+    /// The entity with the given ID was given more than once.
+    #[error("The entity with ID {0} was given more than once.")]
+    DuplicateEntity(Entity),
+    /// The component with the given name has no Rust type, so it has no counterpart in the other world.
+    #[error("The component {0} was registered without a Rust type and cannot be moved to another world.")]
+    DynamicComponent(String),
+}
+
 /// An error that occurs when dynamically retrieving components from an entity.
 #[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EntityComponentError {
@@ -0,0 +1,321 @@
+use alloc::{string::String, vec::Vec};
+use bevy_utils::HashMap;
+use variadics_please::all_tuples;
+
+use crate::{
+    component::ComponentId,
+    system::Resource,
+    world::{error::LazyResourceError, FromWorld, World},
+};
+
+/// Creates a resource from the [`World`] after the resources it depends on have been created.
+///
+/// Resources registered with [`World::init_resource_lazy`] are only constructed when they are
+/// first needed. Before calling [`FromWorldWith::from_world_with`], every resource listed in
+/// [`FromWorldWith::Dependencies`] is initialized first (lazily registered ones included), so
+/// the order in which resources are registered does not matter.
+///
+/// [`FromWorldWith`] is automatically implemented, with no dependencies, for any type
+/// implementing [`FromWorld`].
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, world::FromWorldWith};
+/// #[derive(Resource)]
+/// struct Config(u32);
+///
+/// impl Default for Config {
+///     fn default() -> Self {
+///         Config(4)
+///     }
+/// }
+///
+/// #[derive(Resource)]
+/// struct Pool(Vec<u8>);
+///
+/// impl FromWorldWith for Pool {
+///     type Dependencies = (Config,);
+///
+///     fn from_world_with(world: &mut World) -> Self {
+///         Pool(vec![0; world.resource::<Config>().0 as usize])
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// // Registration order doesn't matter: `Config` is created before `Pool`.
+/// world.init_resource_lazy::<Pool>();
+/// world.init_resource_lazy::<Config>();
+///
+/// world.init_lazy_resource::<Pool>().unwrap();
+/// assert_eq!(world.resource::<Pool>().0.len(), 4);
+/// ```
+pub trait FromWorldWith: Sized {
+    /// The resources that must exist before this value is created.
+    type Dependencies: ResourceDependencies;
+
+    /// Creates `Self` using data from the given [`World`].
+    ///
+    /// All [`FromWorldWith::Dependencies`] are present in the world when this is called.
+    fn from_world_with(world: &mut World) -> Self;
+}
+
+impl<T: FromWorld> FromWorldWith for T {
+    type Dependencies = ();
+
+    fn from_world_with(world: &mut World) -> Self {
+        T::from_world(world)
+    }
+}
+
+/// A set of [`Resource`] types, used as [`FromWorldWith::Dependencies`].
+///
+/// This is implemented for tuples of resources, like `(A, B)`, and the empty tuple `()`.
+pub trait ResourceDependencies {
+    /// Registers every resource of this set and pushes their [`ComponentId`]s to `ids`.
+    fn register_resource_ids(world: &mut World, ids: &mut Vec<ComponentId>);
+}
+
+macro_rules! impl_resource_dependencies {
+    ($(#[$meta:meta])* $($resource: ident),*) => {
+        $(#[$meta])*
+        impl<$($resource: Resource),*> ResourceDependencies for ($($resource,)*) {
+            #[allow(unused_variables)]
+            fn register_resource_ids(world: &mut World, ids: &mut Vec<ComponentId>) {
+                $(ids.push(world.components.register_resource::<$resource>());)*
+            }
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_resource_dependencies,
+    0,
+    15,
+    R
+);
+
+#[derive(Clone, Copy)]
+struct LazyResource {
+    dependencies: fn(&mut World, &mut Vec<ComponentId>),
+    init: fn(&mut World),
+}
+
+/// The resources registered with [`World::init_resource_lazy`] that have not been created yet.
+#[derive(Default)]
+pub(crate) struct LazyResources {
+    registered: HashMap<ComponentId, LazyResource>,
+}
+
+impl World {
+    /// Registers a resource to be created from the world the first time it is needed,
+    /// and returns the [`ComponentId`] created for it.
+    ///
+    /// The resource is created:
+    /// - when a system accessing it through [`Res`](crate::system::Res) or
+    ///   [`ResMut`](crate::system::ResMut) is initialized,
+    /// - when it is listed as a dependency of another lazy resource being created,
+    /// - or when [`World::init_lazy_resource`] is called.
+    ///
+    /// Resources listed in [`FromWorldWith::Dependencies`] are always created first.
+    ///
+    /// If the resource already exists, nothing happens.
+    pub fn init_resource_lazy<R: Resource + FromWorldWith>(&mut self) -> ComponentId {
+        let component_id = self.components.register_resource::<R>();
+        if !self.contains_resource_by_id(component_id) {
+            self.lazy_resources.registered.insert(
+                component_id,
+                LazyResource {
+                    dependencies: R::Dependencies::register_resource_ids,
+                    init: |world| {
+                        let value = R::from_world_with(world);
+                        world.insert_resource(value);
+                    },
+                },
+            );
+        }
+        component_id
+    }
+
+    /// Creates the lazily registered resource `R`, and all of its dependencies, if it does not
+    /// exist yet.
+    ///
+    /// Returns an error if `R` is missing and was not registered with
+    /// [`World::init_resource_lazy`], if one of its dependencies cannot be created, or if its
+    /// dependencies form a cycle.
+    pub fn init_lazy_resource<R: Resource>(&mut self) -> Result<ComponentId, LazyResourceError> {
+        let component_id = self.components.register_resource::<R>();
+        self.init_lazy_resource_by_id(component_id)?;
+        Ok(component_id)
+    }
+
+    /// Creates the lazily registered resource with the given [`ComponentId`], and all of its
+    /// dependencies, if it does not exist yet.
+    ///
+    /// See [`World::init_lazy_resource`] for the possible errors.
+    pub fn init_lazy_resource_by_id(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Result<(), LazyResourceError> {
+        self.init_lazy_resource_recursive(component_id, &mut Vec::new())
+    }
+
+    /// Creates the resource if it is missing and was registered lazily.
+    ///
+    /// Used by resource system parameters, which panic on invalid dependencies.
+    pub(crate) fn init_lazy_resource_if_registered(&mut self, component_id: ComponentId) {
+        if self.lazy_resources.registered.contains_key(&component_id)
+            && !self.contains_resource_by_id(component_id)
+        {
+            if let Err(err) = self.init_lazy_resource_by_id(component_id) {
+                panic!("{err}");
+            }
+        }
+    }
+
+    fn init_lazy_resource_recursive(
+        &mut self,
+        component_id: ComponentId,
+        stack: &mut Vec<ComponentId>,
+    ) -> Result<(), LazyResourceError> {
+        if self.contains_resource_by_id(component_id) {
+            return Ok(());
+        }
+        if let Some(position) = stack.iter().position(|id| *id == component_id) {
+            let mut path: Vec<String> = stack[position..]
+                .iter()
+                .map(|id| self.resource_name(*id))
+                .collect();
+            path.push(self.resource_name(component_id));
+            return Err(LazyResourceError::DependencyCycle(path.join(" -> ")));
+        }
+        let Some(lazy) = self.lazy_resources.registered.get(&component_id).copied() else {
+            return Err(LazyResourceError::NotRegistered(
+                self.resource_name(component_id),
+            ));
+        };
+
+        let mut dependencies = Vec::new();
+        (lazy.dependencies)(self, &mut dependencies);
+        stack.push(component_id);
+        for dependency in dependencies {
+            self.init_lazy_resource_recursive(dependency, stack)
+                .map_err(|err| match err {
+                    LazyResourceError::NotRegistered(missing) => {
+                        LazyResourceError::MissingDependency {
+                            resource: self.resource_name(component_id),
+                            dependency: missing,
+                        }
+                    }
+                    err => err,
+                })?;
+        }
+        stack.pop();
+
+        (lazy.init)(self);
+        self.lazy_resources.registered.remove(&component_id);
+        Ok(())
+    }
+
+    fn resource_name(&self, component_id: ComponentId) -> String {
+        self.components
+            .get_name(component_id)
+            .map_or_else(|| alloc::format!("{component_id:?}"), Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromWorldWith;
+    use crate::{
+        self as bevy_ecs, prelude::*, system::RunSystemOnce, world::error::LazyResourceError,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<&'static str>);
+
+    #[derive(Resource)]
+    struct A;
+
+    impl FromWorldWith for A {
+        type Dependencies = (Order, B);
+
+        fn from_world_with(world: &mut World) -> Self {
+            world.resource_mut::<Order>().0.push("A");
+            A
+        }
+    }
+
+    #[derive(Resource)]
+    struct B;
+
+    impl FromWorldWith for B {
+        type Dependencies = (Order,);
+
+        fn from_world_with(world: &mut World) -> Self {
+            world.resource_mut::<Order>().0.push("B");
+            B
+        }
+    }
+
+    #[test]
+    fn lazy_resources_init_dependencies_first() {
+        let mut world = World::new();
+        world.init_resource_lazy::<A>();
+        world.init_resource_lazy::<B>();
+        world.init_resource_lazy::<Order>();
+        assert!(!world.contains_resource::<A>());
+        assert!(!world.contains_resource::<Order>());
+
+        world.run_system_once(|_: Res<A>| {}).unwrap();
+        assert!(world.contains_resource::<B>());
+        assert_eq!(world.resource::<Order>().0, vec!["B", "A"]);
+
+        // Already existing resources are not registered again.
+        world.init_resource_lazy::<B>();
+        world.init_lazy_resource::<B>().unwrap();
+        assert_eq!(world.resource::<Order>().0, vec!["B", "A"]);
+    }
+
+    #[test]
+    fn lazy_resource_errors() {
+        #[derive(Resource)]
+        struct C;
+
+        impl FromWorldWith for C {
+            type Dependencies = (D,);
+
+            fn from_world_with(_: &mut World) -> Self {
+                C
+            }
+        }
+
+        #[derive(Resource)]
+        struct D;
+
+        impl FromWorldWith for D {
+            type Dependencies = (C,);
+
+            fn from_world_with(_: &mut World) -> Self {
+                D
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource_lazy::<A>();
+        world.init_resource_lazy::<B>();
+        assert!(matches!(
+            world.init_lazy_resource::<A>(),
+            Err(LazyResourceError::MissingDependency { .. })
+        ));
+
+        world.init_resource_lazy::<C>();
+        world.init_resource_lazy::<D>();
+        let Err(LazyResourceError::DependencyCycle(cycle)) = world.init_lazy_resource::<C>() else {
+            panic!("expected a dependency cycle");
+        };
+        assert!(cycle.ends_with("lazy_resource_errors::C"));
+        assert_eq!(cycle.matches(" -> ").count(), 2);
+        assert!(!world.contains_resource::<C>());
+    }
+}
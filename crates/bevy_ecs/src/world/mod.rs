@@ -8,6 +8,8 @@ mod entity_ref;
 pub mod error;
 mod filtered_resource;
 mod identifier;
+mod lazy_resource;
+mod move_entities;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -15,7 +17,8 @@ pub mod unsafe_world_cell;
 pub mod reflect;
 
 pub use crate::{
-    change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
+    change_detection::{ChangeTicksClamped, Mut, Ref, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE},
+    entity_disabling::DefaultQueryFilters,
     world::command_queue::CommandQueue,
 };
 pub use component_constants::*;
@@ -27,10 +30,11 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+pub use lazy_resource::{FromWorldWith, ResourceDependencies};
 pub use spawn_batch::*;
 
 use crate::{
-    archetype::{ArchetypeId, ArchetypeRow, Archetypes},
+    archetype::{ArchetypeBatchCounts, ArchetypeBatchKind, ArchetypeId, ArchetypeRow, Archetypes},
     bundle::{Bundle, BundleInfo, BundleInserter, BundleSpawner, Bundles, InsertMode},
     change_detection::{MutUntyped, TicksMut},
     component::{
@@ -38,7 +42,9 @@ use crate::{
         ComponentInfo, ComponentTicks, Components, Mutable, RequiredComponents,
         RequiredComponentsError, Tick,
     },
-    entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    entity::{
+        AllocAtWithoutReplacement, Entities, Entity, EntityLocation, ReserveEntityRangeError,
+    },
     event::{Event, EventId, Events, SendBatchIds},
     observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryFilter, QueryState},
@@ -54,8 +60,9 @@ use crate::{
 };
 use alloc::{boxed::Box, vec::Vec};
 use bevy_ptr::{OwningPtr, Ptr};
-use core::{any::TypeId, fmt};
-use log::warn;
+use bevy_utils::HashSet;
+use core::{any::TypeId, fmt, ops::Range};
+use log::{debug, warn};
 
 #[cfg(not(feature = "portable-atomic"))]
 use core::sync::atomic::{AtomicU32, Ordering};
@@ -83,6 +90,27 @@ use unsafe_world_cell::{UnsafeEntityCell, UnsafeWorldCell};
 /// To mutate different parts of the world simultaneously,
 /// use [`World::resource_scope`] or [`SystemState`](crate::system::SystemState).
 ///
+/// A `&World` can be shared with other threads, such as asset bakers or AI planners, for example
+/// with [`std::thread::scope`]. The borrow guarantees that no structural change or mutation
+/// happens until these threads are done, and non-send resources can't be read from them.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Cost(u32);
+///
+/// let mut world = World::new();
+/// world.spawn_batch((0..100).map(Cost));
+///
+/// let mut state = world.query::<&Cost>();
+/// let world = &world;
+/// let total: u32 = std::thread::scope(|scope| {
+///     let planner = scope.spawn(|| state.iter(world).map(|cost| cost.0).sum::<u32>());
+///     planner.join().unwrap()
+/// });
+/// assert_eq!(total, 4950);
+/// ```
+///
 /// ## Resources
 ///
 /// Worlds can also store [`Resource`]s,
@@ -101,8 +129,12 @@ pub struct World {
     pub(crate) change_tick: AtomicU32,
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
+    pub(crate) change_tick_check_period: u32,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
+    pub(crate) lazy_resources: lazy_resource::LazyResources,
+    #[cfg(feature = "storage_access_stats")]
+    pub(crate) storage_access_stats: crate::storage::StorageAccessStats,
 }
 
 impl Default for World {
@@ -121,8 +153,12 @@ impl Default for World {
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
+            change_tick_check_period: CHECK_TICK_THRESHOLD,
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
+            lazy_resources: Default::default(),
+            #[cfg(feature = "storage_access_stats")]
+            storage_access_stats: Default::default(),
         };
         world.bootstrap();
         world
@@ -151,6 +187,8 @@ impl World {
         assert_eq!(ON_INSERT, self.register_component::<OnInsert>());
         assert_eq!(ON_REPLACE, self.register_component::<OnReplace>());
         assert_eq!(ON_REMOVE, self.register_component::<OnRemove>());
+        // This sets up `Disabled` as a disabling component, via the FromWorld impl
+        self.init_resource::<DefaultQueryFilters>();
     }
     /// Creates a new empty [`World`].
     ///
@@ -216,6 +254,27 @@ impl World {
         &self.storages
     }
 
+    /// Retrieves this world's [`StorageAccessStats`], which can be enabled to record how each
+    /// component is accessed.
+    ///
+    /// [`StorageAccessStats`]: crate::storage::StorageAccessStats
+    #[cfg(feature = "storage_access_stats")]
+    #[inline]
+    pub fn storage_access_stats(&self) -> &crate::storage::StorageAccessStats {
+        &self.storage_access_stats
+    }
+
+    /// Builds a [`StorageReport`] recommending a [`StorageType`] for every component accessed
+    /// since [`StorageAccessStats`] were enabled.
+    ///
+    /// [`StorageAccessStats`]: crate::storage::StorageAccessStats
+    /// [`StorageReport`]: crate::storage::StorageReport
+    /// [`StorageType`]: crate::component::StorageType
+    #[cfg(feature = "storage_access_stats")]
+    pub fn storage_report(&self) -> crate::storage::StorageReport {
+        self.storage_access_stats.report(&self.components)
+    }
+
     /// Retrieves this world's [`Bundles`] collection.
     #[inline]
     pub fn bundles(&self) -> &Bundles {
@@ -501,6 +560,22 @@ impl World {
         Some(component_info.required_components())
     }
 
+    /// Retrieves the ids of the components that require the component of type `C`, if it exists.
+    ///
+    /// This is the inverse of [`World::get_required_components`], and includes components that
+    /// require `C` indirectly through another required component.
+    pub fn get_required_by<C: Component>(&self) -> Option<&HashSet<ComponentId>> {
+        let id = self.components().component_id::<C>()?;
+        let component_info = self.components().get_info(id)?;
+        Some(component_info.required_by())
+    }
+
+    /// Retrieves the ids of the components that require the component of the given [`ComponentId`], if it exists.
+    pub fn get_required_by_id(&self, id: ComponentId) -> Option<&HashSet<ComponentId>> {
+        let component_info = self.components().get_info(id)?;
+        Some(component_info.required_by())
+    }
+
     /// Registers a new [`Component`] type and returns the [`ComponentId`] created for it.
     ///
     /// This method differs from [`World::register_component`] in that it uses a [`ComponentDescriptor`]
@@ -1153,6 +1228,74 @@ impl World {
         )
     }
 
+    /// Spawns `count` entities, with bundles built by calling `f` with the index of each entity in
+    /// the batch and the [`Entity`] it will be spawned as. This lets bundles refer to their own
+    /// entity, or to their neighbors in the batch.
+    ///
+    /// Returns the spawned entities, as a range of indices if they are contiguous. This is the
+    /// case unless despawned entities are reused.
+    ///
+    /// If `f` panics, the entities reserved for the batch are spawned without any components.
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, entity::Entity, world::World};
+    ///
+    /// #[derive(Component)]
+    /// struct Next(Option<Entity>);
+    ///
+    /// let mut world = World::new();
+    /// let spawned = world.spawn_batch_with(3, |index, entity| {
+    ///     // The entities of a fresh world are contiguous.
+    ///     Next((index < 2).then(|| Entity::from_bits(entity.to_bits() + 1)))
+    /// });
+    ///
+    /// assert_eq!(spawned.len(), 3);
+    /// let first = spawned.get(0).unwrap();
+    /// assert_eq!(world.get::<Next>(first).unwrap().0, spawned.get(1));
+    /// ```
+    #[track_caller]
+    pub fn spawn_batch_with<B: Bundle>(
+        &mut self,
+        count: u32,
+        mut f: impl FnMut(u32, Entity) -> B,
+    ) -> SpawnedEntities {
+        self.flush();
+
+        let entities: Vec<Entity> = self.entities.reserve_entities(count).collect();
+        // Build every bundle before spawning, so that if `f` panics, the reserved entities are
+        // simply flushed as empty entities.
+        let bundles: Vec<B> = entities
+            .iter()
+            .zip(0..)
+            .map(|(&entity, index)| f(index, entity))
+            .collect();
+        self.entities.flush_as_invalid();
+
+        let change_tick = self.change_tick();
+        let mut spawner = BundleSpawner::new::<B>(self, change_tick);
+        spawner.reserve_storage(entities.len());
+        for (&entity, bundle) in entities.iter().zip(bundles) {
+            // SAFETY: `entity` was flushed as invalid and is not spawned yet, and `bundle` matches
+            // the spawner's bundle type.
+            unsafe {
+                spawner.spawn_non_existent(
+                    entity,
+                    bundle,
+                    #[cfg(feature = "track_location")]
+                    Location::caller(),
+                );
+            }
+        }
+        if !entities.is_empty() {
+            // SAFETY: `spawner` is dropped right after flushing below.
+            unsafe { spawner.trigger_batch_spawned(entities.len()) };
+        }
+        // SAFETY: `spawner` is dropped right after this call.
+        unsafe { spawner.flush_commands() };
+
+        SpawnedEntities::from_entities(entities)
+    }
+
     /// Retrieves a reference to the given `entity`'s [`Component`] of the given type.
     /// Returns `None` if the `entity` does not have a [`Component`] of the given type.
     /// ```
@@ -1927,6 +2070,78 @@ impl World {
         }
     }
 
+    /// Gets a mutable reference to the resource of the given type, together with mutable access
+    /// to the components of one or more entities.
+    ///
+    /// This is useful in exclusive systems that need to mutate entities based on a resource,
+    /// without cloning data or using [`World::resource_scope`].
+    ///
+    /// The entities are fetched like [`World::get_entity_mut`], except that a single [`Entity`]
+    /// returns an [`EntityMut`] instead of an [`EntityWorldMut`], since structural changes
+    /// could invalidate the resource reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist, if any of the entities do not exist,
+    /// or if the same entity is requested multiple times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Damage(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Damage(3));
+    /// let a = world.spawn(Health(10)).id();
+    /// let b = world.spawn(Health(5)).id();
+    ///
+    /// let (mut damage, [mut a_mut, mut b_mut]) = world.resource_and_entity_mut::<Damage, _>([a, b]);
+    /// a_mut.get_mut::<Health>().unwrap().0 -= damage.0;
+    /// b_mut.get_mut::<Health>().unwrap().0 -= damage.0;
+    /// damage.0 += 1;
+    ///
+    /// assert_eq!(world.get::<Health>(a).unwrap().0, 7);
+    /// assert_eq!(world.get::<Health>(b).unwrap().0, 2);
+    /// assert_eq!(world.resource::<Damage>().0, 4);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn resource_and_entity_mut<R: Resource, F: WorldEntityFetch>(
+        &mut self,
+        entities: F,
+    ) -> (Mut<'_, R>, F::DeferredMut<'_>) {
+        #[inline(never)]
+        #[cold]
+        #[track_caller]
+        fn panic_on_err(e: EntityFetchError) -> ! {
+            panic!("{e}");
+        }
+
+        let cell = self.as_unsafe_world_cell();
+        // SAFETY: `&mut self` gives mutable access to the entire world. The returned entity
+        // references can only access component data, which is stored separately from resources.
+        let Some(resource) = (unsafe { cell.get_resource_mut::<R>() }) else {
+            panic!(
+                "Requested resource {} does not exist in the `World`.
+                Did you forget to add it using `app.insert_resource` / `app.init_resource`?
+                Resources are also implicitly added via `app.add_event`,
+                and can be added by plugins.",
+                core::any::type_name::<R>()
+            );
+        };
+        // SAFETY: see above. The deferred references can not make structural changes,
+        // so they can not invalidate the resource reference.
+        match unsafe { entities.fetch_deferred_mut(cell) } {
+            Ok(fetched) => (resource, fetched),
+            Err(e) => panic_on_err(e),
+        }
+    }
+
     /// Gets a reference to the resource of the given type if it exists
     #[inline]
     pub fn get_resource<R: Resource>(&self) -> Option<&R> {
@@ -2151,6 +2366,47 @@ impl World {
         unsafe { self.as_unsafe_world_cell().get_non_send_resource_mut() }
     }
 
+    /// Sets aside `count` new entity indices that this world will never allocate on its own.
+    ///
+    /// Entities with reserved indices are spawned explicitly with [`World::insert_or_spawn_batch`].
+    /// This is useful for replication: a server reserves a range and sends it to its clients, which
+    /// mirror it with [`World::reserve_entity_range_at`] so that the same [`Entity`] refers to the
+    /// same object everywhere. See [`Entities::reserve_range`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// let mut server = World::new();
+    /// let range = server.reserve_entity_range(16);
+    ///
+    /// let mut client = World::new();
+    /// client.reserve_entity_range_at(range.clone()).unwrap();
+    ///
+    /// let player = Entity::from_raw(range.start);
+    /// server.insert_or_spawn_batch([(player, Player)]).unwrap();
+    /// client.insert_or_spawn_batch([(player, Player)]).unwrap();
+    ///
+    /// // Local allocation never hands out reserved indices.
+    /// assert!(!range.contains(&client.spawn_empty().id().index()));
+    /// ```
+    pub fn reserve_entity_range(&mut self, count: u32) -> Range<u32> {
+        self.flush();
+        self.entities.reserve_range(count)
+    }
+
+    /// Sets aside the given range of entity indices so that this world never allocates them on
+    /// its own, for example to partition the id space between entities replicated from a server
+    /// and locally spawned ones. See [`Entities::reserve_range_at`].
+    pub fn reserve_entity_range_at(
+        &mut self,
+        range: Range<u32>,
+    ) -> Result<(), ReserveEntityRangeError> {
+        self.flush();
+        self.entities.reserve_range_at(range)
+    }
+
     /// For a given batch of ([`Entity`], [`Bundle`]) pairs, either spawns each [`Entity`] with the given
     /// bundle (if the entity does not exist), or inserts the [`Bundle`] (if the entity already exists).
     /// This is faster than doing equivalent operations one-by-one.
@@ -2413,6 +2669,7 @@ impl World {
         }
 
         let mut batch = iter.into_iter();
+        let mut batch_counts = ArchetypeBatchCounts::default();
 
         if let Some((first_entity, first_bundle)) = batch.next() {
             if let Some(first_location) = self.entities().get(first_entity) {
@@ -2429,7 +2686,7 @@ impl World {
                     archetype_id: first_location.archetype_id,
                 };
                 // SAFETY: `entity` is valid, `location` matches entity, bundle matches inserter
-                unsafe {
+                let new_location = unsafe {
                     cache.inserter.insert(
                         first_entity,
                         first_location,
@@ -2439,6 +2696,7 @@ impl World {
                         caller,
                     )
                 };
+                batch_counts.add(new_location.archetype_id);
 
                 for (entity, bundle) in batch {
                     if let Some(location) = cache.inserter.entities().get(entity) {
@@ -2457,7 +2715,7 @@ impl World {
                             }
                         }
                         // SAFETY: `entity` is valid, `location` matches entity, bundle matches inserter
-                        unsafe {
+                        let new_location = unsafe {
                             cache.inserter.insert(
                                 entity,
                                 location,
@@ -2467,6 +2725,7 @@ impl World {
                                 caller,
                             )
                         };
+                        batch_counts.add(new_location.archetype_id);
                     } else {
                         panic!("error[B0003]: Could not insert a bundle (of type `{}`) for entity {entity}, which {}. See: https://bevyengine.org/learn/errors/b0003", core::any::type_name::<B>(), self.entities.entity_does_not_exist_error_details_message(entity));
                     }
@@ -2475,6 +2734,8 @@ impl World {
                 panic!("error[B0003]: Could not insert a bundle (of type `{}`) for entity {first_entity}, which {}. See: https://bevyengine.org/learn/errors/b0003", core::any::type_name::<B>(), self.entities.entity_does_not_exist_error_details_message(first_entity));
             }
         }
+
+        batch_counts.trigger(self, ArchetypeBatchKind::Inserted);
     }
 
     /// For a given batch of ([`Entity`], [`Bundle`]) pairs,
@@ -2564,6 +2825,7 @@ impl World {
         }
 
         let mut batch = iter.into_iter();
+        let mut batch_counts = ArchetypeBatchCounts::default();
 
         if let Some((first_entity, first_bundle)) = batch.next() {
             if let Some(first_location) = self.entities().get(first_entity) {
@@ -2580,7 +2842,7 @@ impl World {
                     archetype_id: first_location.archetype_id,
                 };
                 // SAFETY: `entity` is valid, `location` matches entity, bundle matches inserter
-                unsafe {
+                let new_location = unsafe {
                     cache.inserter.insert(
                         first_entity,
                         first_location,
@@ -2590,6 +2852,7 @@ impl World {
                         caller,
                     )
                 };
+                batch_counts.add(new_location.archetype_id);
 
                 for (entity, bundle) in batch {
                     if let Some(location) = cache.inserter.entities().get(entity) {
@@ -2608,7 +2871,7 @@ impl World {
                             }
                         }
                         // SAFETY: `entity` is valid, `location` matches entity, bundle matches inserter
-                        unsafe {
+                        let new_location = unsafe {
                             cache.inserter.insert(
                                 entity,
                                 location,
@@ -2618,10 +2881,13 @@ impl World {
                                 caller,
                             )
                         };
+                        batch_counts.add(new_location.archetype_id);
                     }
                 }
             }
         }
+
+        batch_counts.trigger(self, ArchetypeBatchKind::Inserted);
     }
 
     /// Temporarily removes the requested resource from this [`World`], runs custom user code,
@@ -3046,15 +3312,51 @@ impl World {
         f(guard.world)
     }
 
+    /// Returns the minimum number of world tick increments between two passes of
+    /// [`World::check_change_ticks`].
+    ///
+    /// Defaults to [`CHECK_TICK_THRESHOLD`].
+    #[inline]
+    pub fn change_tick_check_period(&self) -> u32 {
+        self.change_tick_check_period
+    }
+
+    /// Sets the minimum number of world tick increments between two passes of
+    /// [`World::check_change_ticks`].
+    ///
+    /// Checking more often spreads the cost of scanning all change ticks, and reports
+    /// [`ChangeTicksClamped`] sooner, but never changes which changes are detected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is greater than [`CHECK_TICK_THRESHOLD`], since change ticks could then
+    /// overflow before being checked.
+    pub fn set_change_tick_check_period(&mut self, period: u32) {
+        assert!(
+            period <= CHECK_TICK_THRESHOLD,
+            "The change tick check period must be at most {CHECK_TICK_THRESHOLD}, got {period}"
+        );
+        self.change_tick_check_period = period;
+    }
+
+    /// Returns the world change tick at which [`World::check_change_ticks`] last scanned
+    /// the change ticks.
+    #[inline]
+    pub fn last_check_tick(&self) -> Tick {
+        self.last_check_tick
+    }
+
     /// Iterates all component change ticks and clamps any older than [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE).
     /// This prevents overflow and thus prevents false positives.
     ///
-    /// **Note:** Does nothing if the [`World`] counter has not been incremented at least [`CHECK_TICK_THRESHOLD`]
-    /// times since the previous pass.
+    /// If any component or resource ticks were clamped, [`ChangeTicksClamped`] is triggered.
+    ///
+    /// **Note:** Does nothing if the [`World`] counter has not been incremented at least
+    /// [`World::change_tick_check_period`] times since the previous pass.
     // TODO: benchmark and optimize
     pub fn check_change_ticks(&mut self) {
         let change_tick = self.change_tick();
-        if change_tick.relative_to(self.last_check_tick).get() < CHECK_TICK_THRESHOLD {
+        if change_tick.relative_to(self.last_check_tick).get() < self.change_tick_check_period {
             return;
         }
 
@@ -3067,16 +3369,20 @@ impl World {
 
         #[cfg(feature = "trace")]
         let _span = tracing::info_span!("check component ticks").entered();
-        tables.check_change_ticks(change_tick);
-        sparse_sets.check_change_ticks(change_tick);
-        resources.check_change_ticks(change_tick);
-        non_send_resources.check_change_ticks(change_tick);
+        let count = tables.check_change_ticks(change_tick)
+            + sparse_sets.check_change_ticks(change_tick)
+            + resources.check_change_ticks(change_tick)
+            + non_send_resources.check_change_ticks(change_tick);
 
         if let Some(mut schedules) = self.get_resource_mut::<Schedules>() {
             schedules.check_change_ticks(change_tick);
         }
 
         self.last_check_tick = change_tick;
+        if count > 0 {
+            debug!("Clamped {count} change ticks older than {MAX_CHANGE_AGE} ticks.");
+            self.trigger(ChangeTicksClamped { change_tick, count });
+        }
     }
 
     /// Runs both [`clear_entities`](Self::clear_entities) and [`clear_resources`](Self::clear_resources),
@@ -3202,6 +3508,7 @@ impl World {
     /// # struct B(u32);
     /// #
     /// # let mut world = World::new();
+    /// # world.remove_resource::<bevy_ecs::entity_disabling::DefaultQueryFilters>();
     /// # world.insert_resource(A(1));
     /// # world.insert_resource(B(2));
     /// let mut total = 0;
@@ -3669,7 +3976,8 @@ mod tests {
     use crate::{
         change_detection::DetectChangesMut,
         component::{ComponentDescriptor, ComponentInfo, StorageType},
-        entity::EntityHashSet,
+        entity::{Entity, EntityHashSet},
+        entity_disabling::DefaultQueryFilters,
         ptr::OwningPtr,
         system::Resource,
         world::error::EntityFetchError,
@@ -3859,6 +4167,8 @@ mod tests {
     #[test]
     fn iter_resources() {
         let mut world = World::new();
+        // Remove the default resources so that only the test resources are iterated.
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
@@ -3885,6 +4195,8 @@ mod tests {
     #[test]
     fn iter_resources_mut() {
         let mut world = World::new();
+        // Remove the default resources so that only the test resources are iterated.
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
@@ -3999,6 +4311,66 @@ mod tests {
         assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 1);
     }
 
+    #[test]
+    fn dynamic_plain_data_component() {
+        let mut world = World::new();
+
+        let descriptor = ComponentDescriptor::new_plain_data(
+            "Dynamic Position",
+            StorageType::Table,
+            core::alloc::Layout::new::<[f32; 2]>(),
+            true,
+        );
+        let component_id = world.register_component_with_descriptor(descriptor);
+        assert_eq!(
+            world.components().get_id_by_name("Dynamic Position"),
+            Some(component_id)
+        );
+        assert_eq!(world.components().get_id_by_name("Missing"), None);
+
+        let mut entity = world.spawn_empty();
+        OwningPtr::make([1.0f32, 2.0], |ptr| {
+            // SAFETY: value is valid for the component layout
+            unsafe {
+                entity.insert_by_id(component_id, ptr);
+            }
+        });
+        let entity = entity.id();
+
+        // SAFETY: [f32; 2] is the correct type for the component
+        let data = unsafe {
+            world
+                .entity(entity)
+                .get_by_id(component_id)
+                .unwrap()
+                .deref::<[f32; 2]>()
+        };
+        assert_eq!(*data, [1.0, 2.0]);
+
+        let bytes = world.entity(entity).get_plain_data(component_id).unwrap();
+        assert_eq!(bytes.len(), size_of::<[f32; 2]>());
+        let bytes = bytes.to_vec();
+        let other = world
+            .spawn_empty()
+            .insert_plain_data(component_id, &bytes)
+            .id();
+        assert_eq!(
+            world.entity(other).get_plain_data(component_id),
+            Some(&bytes[..])
+        );
+
+        #[derive(Component)]
+        struct Typed;
+
+        let typed_id = world.register_component::<Typed>();
+        world.entity_mut(other).insert(Typed);
+        assert_eq!(world.entity(other).get_plain_data(typed_id), None);
+
+        world.entity_mut(entity).remove_by_id(component_id);
+        assert!(world.entity(entity).get_by_id(component_id).is_err());
+        assert_eq!(world.entity(entity).get_plain_data(component_id), None);
+    }
+
     #[derive(Resource)]
     struct TestFromWorld(u32);
     impl FromWorld for TestFromWorld {
@@ -4219,6 +4591,32 @@ mod tests {
         assert_eq!(world.entity(b2).get(), Some(&B(4)));
     }
 
+    #[test]
+    fn spawn_batch_with() {
+        #[derive(Component, PartialEq, Debug)]
+        struct Owner(Entity, u32);
+
+        let mut world = World::new();
+        let spawned = world.spawn_batch_with(3, |index, entity| Owner(entity, index));
+        assert!(spawned.index_range().is_some());
+        for (index, entity) in spawned.iter().enumerate() {
+            assert_eq!(
+                world.get::<Owner>(entity),
+                Some(&Owner(entity, index as u32))
+            );
+        }
+
+        let despawned = spawned.get(1).unwrap();
+        world.despawn(despawned);
+        let spawned = world.spawn_batch_with(2, |index, entity| Owner(entity, index));
+        assert!(spawned.index_range().is_none());
+        let spawned = spawned.into_vec();
+        assert_eq!(spawned[0].index(), despawned.index());
+        assert_eq!(world.get::<Owner>(spawned[1]), Some(&Owner(spawned[1], 1)));
+
+        assert!(world.spawn_batch_with(0, |_, _| Foo).is_empty());
+    }
+
     #[test]
     fn spawn_empty_bundle() {
         let mut world = World::new();
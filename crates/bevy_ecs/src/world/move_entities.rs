@@ -0,0 +1,328 @@
+use alloc::vec::Vec;
+
+use crate::{
+    component::ComponentId,
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    world::{error::MoveEntitiesError, World},
+};
+
+#[cfg(feature = "bevy_reflect")]
+use crate::{
+    entity::SceneEntityMapper,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+};
+
+impl World {
+    /// Moves `entity`, with all of its components, from this world to `other`, and returns the
+    /// [`Entity`] it was given in `other`.
+    ///
+    /// See [`World::move_entities_to`] for details.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, PartialEq, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut loading_world = World::new();
+    /// let mut world = World::new();
+    /// let entity = loading_world.spawn(Health(10)).id();
+    ///
+    /// let moved = loading_world.move_entity_to(&mut world, entity).unwrap();
+    /// assert!(loading_world.get_entity(entity).is_err());
+    /// assert_eq!(world.get::<Health>(moved), Some(&Health(10)));
+    /// ```
+    pub fn move_entity_to(
+        &mut self,
+        other: &mut World,
+        entity: Entity,
+    ) -> Result<Entity, MoveEntitiesError> {
+        let entity_map = self.move_entities_to(other, &[entity])?;
+        Ok(entity_map[&entity])
+    }
+
+    /// Moves `entities`, with all of their components, from this world to `other`, and returns
+    /// a map from each moved entity to the [`Entity`] it was given in `other`.
+    ///
+    /// Component values are moved as they are, without cloning or serializing them. Components
+    /// that aren't registered in `other` yet are registered with the same hooks, but without
+    /// their required components.
+    ///
+    /// The moved components are removed from this world first, running their `on_replace` and
+    /// `on_remove` hooks and observers, then inserted into `other`, running their `on_add` and
+    /// `on_insert` hooks and observers.
+    ///
+    /// With the `bevy_reflect` feature, [`Entity`] references inside components registered with
+    /// [`ReflectMapEntities`] or [`ReflectComponent`] in the [`AppTypeRegistry`] of `other` (or of
+    /// this world, if `other` has none) are remapped before insertion, the latter through
+    /// [`Component::map_entities`](crate::component::Component::map_entities). References to
+    /// moved entities point to their new counterparts, and other references point to entities
+    /// that never exist in `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, without moving anything, if an entity does not exist or is given twice,
+    /// or if it has a component without a Rust type.
+    pub fn move_entities_to(
+        &mut self,
+        other: &mut World,
+        entities: &[Entity],
+    ) -> Result<EntityHashMap<Entity>, MoveEntitiesError> {
+        self.flush();
+        self.check_move_entities(entities)?;
+
+        let mut entity_map = EntityHashMap::default();
+        for &entity in entities {
+            entity_map.insert(entity, other.spawn_empty().id());
+        }
+
+        #[cfg(feature = "bevy_reflect")]
+        let registry = other
+            .get_resource::<AppTypeRegistry>()
+            .or_else(|| self.get_resource::<AppTypeRegistry>())
+            .cloned();
+        // The mapper also maps the references to entities that aren't moved, which must not end
+        // up in the returned map.
+        #[cfg(feature = "bevy_reflect")]
+        let mut mapper_map = entity_map.clone();
+
+        for &entity in entities {
+            let target = entity_map[&entity];
+            // Hooks of previously moved entities may have despawned this one through commands.
+            let Ok(mut source) = self.get_entity_mut(entity) else {
+                continue;
+            };
+            let target_ids: Vec<ComponentId> = source
+                .archetype()
+                .components()
+                .map(|component_id| {
+                    let info = source.world().components.get_info(component_id).unwrap();
+                    other
+                        .components
+                        .register_component_from_info(&mut other.storages, info)
+                })
+                .collect();
+
+            source.take_all_with(|_, values| {
+                #[cfg(feature = "bevy_reflect")]
+                let mut values = values;
+                #[cfg(feature = "bevy_reflect")]
+                if let Some(registry) = &registry {
+                    let registry = registry.read();
+                    for (component_id, value) in target_ids.iter().zip(values.iter_mut()) {
+                        let Some(registration) = other
+                            .components
+                            .get_info(*component_id)
+                            .and_then(|info| registry.get(info.type_id()?))
+                        else {
+                            continue;
+                        };
+                        let Some(from_ptr) = registration.data::<bevy_reflect::ReflectFromPtr>()
+                        else {
+                            continue;
+                        };
+                        // SAFETY: `value` is a component of the type of `registration`, since
+                        // both worlds index components with this `TypeId` by the same type.
+                        let reflected = unsafe { from_ptr.as_reflect_mut(value.as_mut()) };
+                        if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                            SceneEntityMapper::world_scope(&mut mapper_map, other, |_, mapper| {
+                                map_entities
+                                    .map_entities(reflected.as_partial_reflect_mut(), mapper);
+                            });
+                        } else if let Some(reflect_component) =
+                            registration.data::<ReflectComponent>()
+                        {
+                            SceneEntityMapper::world_scope(&mut mapper_map, other, |_, mapper| {
+                                reflect_component.map_entities(reflected, mapper);
+                            });
+                        }
+                    }
+                }
+
+                if !target_ids.is_empty() {
+                    // SAFETY: `target_ids` were registered in `other` with the same descriptors as
+                    // the taken components, in the same order.
+                    unsafe {
+                        other
+                            .entity_mut(target)
+                            .insert_by_ids(&target_ids, values.into_iter());
+                    }
+                }
+            });
+            source.despawn();
+        }
+        Ok(entity_map)
+    }
+
+    /// Checks that `entities` can be moved to another world with [`World::move_entities_to`],
+    /// without changing anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error [`World::move_entities_to`] would return.
+    pub fn check_move_entities(&self, entities: &[Entity]) -> Result<(), MoveEntitiesError> {
+        let mut seen = EntityHashSet::default();
+        for &entity in entities {
+            let Ok(entity_ref) = self.get_entity(entity) else {
+                return Err(MoveEntitiesError::NoSuchEntity(entity));
+            };
+            if !seen.insert(entity) {
+                return Err(MoveEntitiesError::DuplicateEntity(entity));
+            }
+            for component_id in entity_ref.archetype().components() {
+                let info = self.components.get_info(component_id).unwrap();
+                if info.type_id().is_none() {
+                    return Err(MoveEntitiesError::DynamicComponent(info.name().into()));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::{Component, ComponentId},
+        entity::{Entity, EntityMapper, MapEntities},
+        world::{error::MoveEntitiesError, DeferredWorld, World},
+    };
+    use alloc::{vec, vec::Vec};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Name(&'static str);
+
+    #[derive(Component, Debug, PartialEq)]
+    #[component(storage = "SparseSet")]
+    struct Sparse(Vec<u32>);
+
+    #[test]
+    fn move_entities_between_worlds() {
+        let mut world = World::new();
+        let mut other = World::new();
+        other.spawn(Sparse(vec![0]));
+        let a = world.spawn((Name("a"), Sparse(vec![1, 2]))).id();
+        let b = world.spawn(Name("b")).id();
+        let c = world.spawn_empty().id();
+        let stays = world.spawn(Name("stays")).id();
+
+        let entity_map = world.move_entities_to(&mut other, &[a, b, c]).unwrap();
+        assert_eq!(3, entity_map.len());
+        for entity in [a, b, c] {
+            assert!(world.get_entity(entity).is_err());
+        }
+        assert_eq!(Some(&Name("stays")), world.get::<Name>(stays));
+        assert_eq!(Some(&Name("a")), other.get::<Name>(entity_map[&a]));
+        assert_eq!(
+            Some(&Sparse(vec![1, 2])),
+            other.get::<Sparse>(entity_map[&a])
+        );
+        assert_eq!(Some(&Name("b")), other.get::<Name>(entity_map[&b]));
+        assert_eq!(
+            0,
+            other.entity(entity_map[&c]).archetype().component_count()
+        );
+
+        // The component uses the id registered by type in the other world.
+        let mut query = other.query::<&Name>();
+        assert_eq!(2, query.iter(&other).count());
+    }
+
+    #[test]
+    fn move_entities_errors() {
+        let mut world = World::new();
+        let mut other = World::new();
+        let a = world.spawn(Name("a")).id();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        assert_eq!(
+            Err(MoveEntitiesError::NoSuchEntity(missing)),
+            world.move_entities_to(&mut other, &[a, missing])
+        );
+        assert_eq!(
+            Err(MoveEntitiesError::DuplicateEntity(a)),
+            world.move_entities_to(&mut other, &[a, a])
+        );
+        assert!(world.get_entity(a).is_ok());
+        assert_eq!(0, other.entities().len());
+    }
+
+    #[test]
+    fn move_entity_runs_hooks_without_dropping() {
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+        static REMOVES: AtomicUsize = AtomicUsize::new(0);
+        static ADDS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Component)]
+        #[component(on_add = on_add, on_remove = on_remove)]
+        struct Tracked;
+
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        fn on_add(_: DeferredWorld, _: Entity, _: ComponentId) {
+            ADDS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_remove(_: DeferredWorld, _: Entity, _: ComponentId) {
+            REMOVES.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        let mut other = World::new();
+        let entity = world.spawn(Tracked).id();
+        let moved = world.move_entity_to(&mut other, entity).unwrap();
+        assert_eq!(2, ADDS.load(Ordering::Relaxed));
+        assert_eq!(1, REMOVES.load(Ordering::Relaxed));
+        assert_eq!(0, DROPS.load(Ordering::Relaxed));
+
+        other.despawn(moved);
+        assert_eq!(1, DROPS.load(Ordering::Relaxed));
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn move_entities_maps_entities() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component, MapEntities)]
+        struct Target(Entity);
+
+        impl MapEntities for Target {
+            fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+                self.0 = entity_mapper.map_entity(self.0);
+            }
+        }
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Derived(#[entities] Entity);
+
+        let mut world = World::new();
+        let mut other = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Target>();
+        registry.write().register::<Derived>();
+        other.insert_resource(registry);
+
+        let outside = world.spawn_empty().id();
+        let a = world.spawn_empty().id();
+        let b = world.spawn((Target(a), Derived(a))).id();
+        world.entity_mut(a).insert(Target(outside));
+
+        let entity_map = world.move_entities_to(&mut other, &[a, b]).unwrap();
+        assert_eq!(2, entity_map.len());
+        let (new_a, new_b) = (entity_map[&a], entity_map[&b]);
+        assert_eq!(new_a, other.get::<Target>(new_b).unwrap().0);
+        assert_eq!(new_a, other.get::<Derived>(new_b).unwrap().0);
+        let dangling = other.get::<Target>(new_a).unwrap().0;
+        assert!(other.get_entity(dangling).is_err());
+    }
+}
@@ -3,9 +3,10 @@ use crate::{
     entity::{Entity, EntitySetIterator},
     world::World,
 };
-use core::iter::FusedIterator;
+use alloc::vec::Vec;
 #[cfg(feature = "track_location")]
 use core::panic::Location;
+use core::{iter::FusedIterator, ops::Range};
 
 /// An iterator that spawns a series of entities and returns the [ID](Entity) of
 /// each spawned entity.
@@ -18,6 +19,7 @@ where
 {
     inner: I,
     spawner: BundleSpawner<'w>,
+    count: usize,
     #[cfg(feature = "track_location")]
     caller: &'static Location<'static>,
 }
@@ -50,6 +52,7 @@ where
         Self {
             inner: iter,
             spawner,
+            count: 0,
             #[cfg(feature = "track_location")]
             caller,
         }
@@ -64,6 +67,10 @@ where
     fn drop(&mut self) {
         // Iterate through self in order to spawn remaining bundles.
         for _ in &mut *self {}
+        if self.count > 0 {
+            // SAFETY: `self.spawner` will be dropped immediately after flushing below.
+            unsafe { self.spawner.trigger_batch_spawned(self.count) };
+        }
         // Apply any commands from those operations.
         // SAFETY: `self.spawner` will be dropped immediately after this call.
         unsafe { self.spawner.flush_commands() };
@@ -79,6 +86,7 @@ where
 
     fn next(&mut self) -> Option<Entity> {
         let bundle = self.inner.next()?;
+        self.count += 1;
         // SAFETY: bundle matches spawner type
         unsafe {
             Some(self.spawner.spawn(
@@ -118,3 +126,82 @@ where
     T: Bundle,
 {
 }
+
+/// The entities spawned by [`World::spawn_batch_with`], in the order they were spawned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpawnedEntities {
+    /// The entities have consecutive indices, starting at `first`, and share its generation.
+    Contiguous {
+        /// The first spawned entity.
+        first: Entity,
+        /// The number of spawned entities.
+        len: u32,
+    },
+    /// Some of the entities reuse the indices of despawned entities, so they are listed one by one.
+    Scattered(Vec<Entity>),
+}
+
+impl SpawnedEntities {
+    pub(crate) fn from_entities(entities: Vec<Entity>) -> Self {
+        let Some(&first) = entities.first() else {
+            return Self::Scattered(entities);
+        };
+        let contiguous = entities
+            .iter()
+            .zip(0..)
+            .all(|(entity, offset)| entity.to_bits() == first.to_bits() + offset);
+        if contiguous {
+            Self::Contiguous {
+                first,
+                len: entities.len() as u32,
+            }
+        } else {
+            Self::Scattered(entities)
+        }
+    }
+
+    /// Returns the number of spawned entities.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Contiguous { len, .. } => *len as usize,
+            Self::Scattered(entities) => entities.len(),
+        }
+    }
+
+    /// Returns `true` if no entities were spawned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `index`-th spawned entity.
+    pub fn get(&self, index: usize) -> Option<Entity> {
+        match self {
+            Self::Contiguous { first, len } => {
+                // The index is stored in the low bits, and all of these indices were allocated.
+                (index < *len as usize).then(|| Entity::from_bits(first.to_bits() + index as u64))
+            }
+            Self::Scattered(entities) => entities.get(index).copied(),
+        }
+    }
+
+    /// Returns the range of entity indices, if the entities are contiguous.
+    pub fn index_range(&self) -> Option<Range<u32>> {
+        match self {
+            Self::Contiguous { first, len } => Some(first.index()..first.index() + len),
+            Self::Scattered(_) => None,
+        }
+    }
+
+    /// Iterates over the spawned entities, in the order they were spawned.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        (0..self.len()).map(|index| self.get(index).unwrap())
+    }
+
+    /// Collects the spawned entities into a [`Vec`].
+    pub fn into_vec(self) -> Vec<Entity> {
+        match self {
+            Self::Contiguous { .. } => self.iter().collect(),
+            Self::Scattered(entities) => entities,
+        }
+    }
+}
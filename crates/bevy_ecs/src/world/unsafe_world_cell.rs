@@ -249,6 +249,15 @@ impl<'w> UnsafeWorldCell<'w> {
         &unsafe { self.world_metadata() }.components
     }
 
+    /// Retrieves this world's [`StorageAccessStats`](crate::storage::StorageAccessStats).
+    #[cfg(feature = "storage_access_stats")]
+    #[inline]
+    pub fn storage_access_stats(self) -> &'w crate::storage::StorageAccessStats {
+        // SAFETY:
+        // - we only access world metadata
+        &unsafe { self.world_metadata() }.storage_access_stats
+    }
+
     /// Retrieves this world's collection of [removed components](RemovedComponentEvents).
     pub fn removed_components(self) -> &'w RemovedComponentEvents {
         // SAFETY:
@@ -1103,6 +1112,10 @@ unsafe fn get_component(
     entity: Entity,
     location: EntityLocation,
 ) -> Option<Ptr<'_>> {
+    #[cfg(feature = "storage_access_stats")]
+    world
+        .storage_access_stats()
+        .record_random_access(component_id);
     // SAFETY: component_id exists and is therefore valid
     match storage_type {
         StorageType::Table => {
@@ -1130,6 +1143,10 @@ unsafe fn get_component_and_ticks(
     entity: Entity,
     location: EntityLocation,
 ) -> Option<(Ptr<'_>, TickCells<'_>, MaybeUnsafeCellLocation<'_>)> {
+    #[cfg(feature = "storage_access_stats")]
+    world
+        .storage_access_stats()
+        .record_random_access(component_id);
     match storage_type {
         StorageType::Table => {
             let table = world.fetch_table(location)?;
@@ -0,0 +1,101 @@
+use crate::{GltfExtras, GltfMaterialExtras};
+use bevy_ecs::{
+    prelude::*,
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
+use bevy_reflect::serde::TypedReflectDeserializer;
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+use tracing::warn;
+
+/// Inserts the components described by the [`GltfExtras`] and [`GltfMaterialExtras`] of spawned
+/// glTF entities, see [`GltfPlugin::with_extras_as_components`](crate::GltfPlugin::with_extras_as_components).
+pub(crate) fn insert_extras_components(
+    nodes: Query<(Entity, &GltfExtras), Added<GltfExtras>>,
+    materials: Query<(Entity, &GltfMaterialExtras), Added<GltfMaterialExtras>>,
+    mut commands: Commands,
+) {
+    let extras = nodes
+        .iter()
+        .map(|(entity, extras)| (entity, &extras.value))
+        .chain(
+            materials
+                .iter()
+                .map(|(entity, extras)| (entity, &extras.value)),
+        );
+    for (entity, extras) in extras {
+        let extras = extras.clone();
+        commands
+            .entity(entity)
+            .queue(move |entity: Entity, world: &mut World| {
+                insert_components(world, entity, &extras);
+            });
+    }
+}
+
+/// Inserts the registered reflected components named by the keys of the JSON object `extras` on
+/// `entity`, deserialized from their values. Keys that don't name a component are ignored.
+fn insert_components(world: &mut World, entity: Entity, extras: &str) {
+    let Ok(Value::Object(properties)) = serde_json::from_str::<Value>(extras) else {
+        return;
+    };
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let Ok(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+
+    for (key, value) in properties {
+        let Some(registration) = registry
+            .get_with_type_path(&key)
+            .or_else(|| registry.get_with_short_type_path(&key))
+        else {
+            continue;
+        };
+        let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+            continue;
+        };
+        match TypedReflectDeserializer::new(registration, &registry).deserialize(value) {
+            Ok(component) => reflect_component.insert(&mut entity, &*component, &registry),
+            Err(error) => warn!(
+                "Failed to deserialize the glTF extra `{key}` of {} into a component: {error}",
+                entity.id()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        max: u32,
+    }
+
+    #[test]
+    fn insert_registered_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+
+        let entity = world.spawn_empty().id();
+        insert_components(
+            &mut world,
+            entity,
+            r#"{"Health": {"max": 100}, "blender_property": 1.5}"#,
+        );
+        assert_eq!(world.get::<Health>(entity), Some(&Health { max: 100 }));
+
+        let entity = world.spawn_empty().id();
+        insert_components(&mut world, entity, r#"{"Health": "invalid"}"#);
+        insert_components(&mut world, entity, "not json");
+        assert!(world.get::<Health>(entity).is_none());
+    }
+}
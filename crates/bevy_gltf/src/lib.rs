@@ -96,13 +96,19 @@ extern crate alloc;
 use bevy_animation::AnimationClip;
 use bevy_utils::HashMap;
 
+mod extras;
 mod loader;
+mod scene_instance;
+mod selection;
+mod skeleton;
 mod vertex_attributes;
 pub use loader::*;
+pub use scene_instance::*;
+pub use skeleton::*;
 
 use bevy_app::prelude::*;
 use bevy_asset::{Asset, AssetApp, AssetPath, Handle};
-use bevy_ecs::{prelude::Component, reflect::ReflectComponent};
+use bevy_ecs::{prelude::Component, reflect::ReflectComponent, schedule::IntoSystemConfigs};
 use bevy_image::CompressedImageFormats;
 use bevy_pbr::StandardMaterial;
 use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypePath};
@@ -110,20 +116,21 @@ use bevy_render::{
     mesh::{skinning::SkinnedMeshInverseBindposes, Mesh, MeshVertexAttribute},
     renderer::RenderDevice,
 };
-use bevy_scene::Scene;
+use bevy_scene::{scene_spawner_system, Scene};
 
 /// The glTF prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Gltf, GltfAssetLabel, GltfExtras};
+    pub use crate::{Gltf, GltfAssetLabel, GltfExtras, GltfSceneInstance};
 }
 
 /// Adds support for glTF file loading to the app.
 #[derive(Default)]
 pub struct GltfPlugin {
     custom_vertex_attributes: HashMap<Box<str>, MeshVertexAttribute>,
+    extras_as_components: bool,
 }
 
 impl GltfPlugin {
@@ -140,6 +147,18 @@ impl GltfPlugin {
         self.custom_vertex_attributes.insert(name.into(), attribute);
         self
     }
+
+    /// Turn the extras of glTF nodes and materials into components of the spawned entities.
+    ///
+    /// The extras are expected to be a JSON object: each key naming a registered [`Component`]
+    /// that is reflected with `#[reflect(Component)]`, by type path or short type path, is
+    /// deserialized from its value and inserted on the entities spawned for the node, or for the
+    /// primitives using the material. Other keys are ignored. This lets metadata authored in tools
+    /// like Blender, such as `{"Health": {"max": 100}}`, become ECS data when the scene spawns.
+    pub fn with_extras_as_components(mut self) -> Self {
+        self.extras_as_components = true;
+        self
+    }
 }
 
 impl Plugin for GltfPlugin {
@@ -149,12 +168,22 @@ impl Plugin for GltfPlugin {
             .register_type::<GltfMeshExtras>()
             .register_type::<GltfMaterialExtras>()
             .register_type::<GltfMaterialName>()
+            .register_type::<GltfSceneInstance>()
             .init_asset::<Gltf>()
             .init_asset::<GltfNode>()
             .init_asset::<GltfPrimitive>()
             .init_asset::<GltfMesh>()
             .init_asset::<GltfSkin>()
-            .preregister_asset_loader::<GltfLoader>(&["gltf", "glb"]);
+            .init_asset::<Skeleton>()
+            .preregister_asset_loader::<GltfLoader>(&["gltf", "glb"])
+            .add_observer(copy_scene_instance_to_parent);
+
+        if self.extras_as_components {
+            app.add_systems(
+                SpawnScene,
+                extras::insert_extras_components.after(scene_spawner_system),
+            );
+        }
     }
 
     fn finish(&self, app: &mut App) {
@@ -192,6 +221,10 @@ pub struct Gltf {
     pub skins: Vec<Handle<GltfSkin>>,
     /// Named skins loaded from the glTF file.
     pub named_skins: HashMap<Box<str>, Handle<GltfSkin>>,
+    /// The skeletons of the skins loaded from the glTF file.
+    pub skeletons: Vec<Handle<Skeleton>>,
+    /// The skeletons of the named skins loaded from the glTF file.
+    pub named_skeletons: HashMap<Box<str>, Handle<Skeleton>>,
     /// Default scene to be displayed.
     pub default_scene: Option<Handle<Scene>>,
     /// All animations loaded from the glTF file.
@@ -529,6 +562,8 @@ pub enum GltfAssetLabel {
     Skin(usize),
     /// `Skin{}/InverseBindMatrices`: glTF mesh skin matrices as Bevy `SkinnedMeshInverseBindposes`
     InverseBindMatrices(usize),
+    /// `Skin{}/Skeleton`: joint hierarchy of a glTF mesh skin as a `Skeleton`
+    Skeleton(usize),
 }
 
 impl core::fmt::Display for GltfAssetLabel {
@@ -561,6 +596,7 @@ impl core::fmt::Display for GltfAssetLabel {
             GltfAssetLabel::InverseBindMatrices(index) => {
                 f.write_str(&format!("Skin{index}/InverseBindMatrices"))
             }
+            GltfAssetLabel::Skeleton(index) => f.write_str(&format!("Skin{index}/Skeleton")),
         }
     }
 }
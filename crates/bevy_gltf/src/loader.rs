@@ -1,6 +1,7 @@
 use crate::{
-    vertex_attributes::convert_attribute, Gltf, GltfAssetLabel, GltfExtras, GltfMaterialExtras,
-    GltfMaterialName, GltfMeshExtras, GltfNode, GltfSceneExtras, GltfSkin,
+    selection::GltfSelection, vertex_attributes::convert_attribute, Gltf, GltfAssetLabel,
+    GltfExtras, GltfMaterialExtras, GltfMaterialName, GltfMeshExtras, GltfNode, GltfSceneExtras,
+    GltfSceneInstance, GltfSkin, Skeleton, SkeletonJoint,
 };
 
 use alloc::collections::VecDeque;
@@ -162,6 +163,14 @@ pub struct GltfLoaderSettings {
     pub load_lights: bool,
     /// If true, the loader will include the root of the gltf root node.
     pub include_source: bool,
+    /// If set, only the items with these labels or names, and the items they depend on, are
+    /// loaded, for instance `["Mesh3", "Animation7", "Level1"]`.
+    ///
+    /// Labels are matched by their first segment, so `Scene0/Node2` selects the whole scene. A
+    /// scene brings its nodes, a node its children, mesh and skin, a mesh its materials and a
+    /// material its textures, but the other items of the file are neither decoded nor read: the
+    /// [`Gltf`] collections only contain the loaded items.
+    pub load_only: Option<Vec<String>>,
 }
 
 impl Default for GltfLoaderSettings {
@@ -172,6 +181,7 @@ impl Default for GltfLoaderSettings {
             load_cameras: true,
             load_lights: true,
             include_source: false,
+            load_only: None,
         }
     }
 }
@@ -213,7 +223,13 @@ async fn load_gltf<'a, 'b, 'c>(
             "Gltf file name invalid",
         ))))?
         .to_string();
-    let buffer_data = load_buffers(&gltf, load_context).await?;
+    let selection = settings
+        .load_only
+        .as_deref()
+        .map_or_else(GltfSelection::all, |labels| {
+            GltfSelection::new(&gltf.document, labels)
+        });
+    let buffer_data = load_buffers(&gltf, &selection, load_context).await?;
 
     let mut linear_textures = <HashSet<_>>::default();
 
@@ -279,6 +295,15 @@ async fn load_gltf<'a, 'b, 'c>(
         let mut named_animations = <HashMap<_, _>>::default();
         let mut animation_roots = <HashSet<_>>::default();
         for animation in gltf.animations() {
+            if !selection.animation(animation.index()) {
+                // The scenes still need to know which of their nodes are animated.
+                for channel in animation.channels() {
+                    if let Some((root_index, _)) = paths.get(&channel.target().node().index()) {
+                        animation_roots.insert(*root_index);
+                    }
+                }
+                continue;
+            }
             let mut animation_clip = AnimationClip::default();
             for channel in animation.channels() {
                 let node = channel.target().node();
@@ -557,7 +582,10 @@ async fn load_gltf<'a, 'b, 'c>(
     // that the material's load context would no longer track those images as dependencies.
     let mut _texture_handles = Vec::new();
     if gltf.textures().len() == 1 || cfg!(target_arch = "wasm32") {
-        for texture in gltf.textures() {
+        for texture in gltf
+            .textures()
+            .filter(|texture| selection.texture(texture.index()))
+        {
             let parent_path = load_context.path().parent().unwrap();
             let image = load_image(
                 texture,
@@ -574,7 +602,10 @@ async fn load_gltf<'a, 'b, 'c>(
         #[cfg(not(target_arch = "wasm32"))]
         IoTaskPool::get()
             .scope(|scope| {
-                gltf.textures().for_each(|gltf_texture| {
+                let textures = gltf
+                    .textures()
+                    .filter(|texture| selection.texture(texture.index()));
+                textures.for_each(|gltf_texture| {
                     let parent_path = load_context.path().parent().unwrap();
                     let linear_textures = &linear_textures;
                     let buffer_data = &buffer_data;
@@ -602,20 +633,24 @@ async fn load_gltf<'a, 'b, 'c>(
             });
     }
 
-    let mut materials = vec![];
+    let mut materials = <HashMap<_, _>>::default();
     let mut named_materials = <HashMap<_, _>>::default();
     // Only include materials in the output if they're set to be retained in the MAIN_WORLD and/or RENDER_WORLD by the load_materials flag
     if !settings.load_materials.is_empty() {
         // NOTE: materials must be loaded after textures because image load() calls will happen before load_with_settings, preventing is_srgb from being set properly
-        for material in gltf.materials() {
+        for (index, material) in gltf
+            .materials()
+            .filter_map(|material| Some((material.index()?, material)))
+            .filter(|(index, _)| selection.material(*index))
+        {
             let handle = load_material(&material, load_context, &gltf.document, false);
             if let Some(name) = material.name() {
                 named_materials.insert(name.into(), handle.clone());
             }
-            materials.push(handle);
+            materials.insert(index, handle);
         }
     }
-    let mut meshes = vec![];
+    let mut meshes = <HashMap<_, _>>::default();
     let mut named_meshes = <HashMap<_, _>>::default();
     let mut meshes_on_skinned_nodes = <HashSet<_>>::default();
     let mut meshes_on_non_skinned_nodes = <HashSet<_>>::default();
@@ -629,6 +664,9 @@ async fn load_gltf<'a, 'b, 'c>(
         }
     }
     for gltf_mesh in gltf.meshes() {
+        if !selection.mesh(gltf_mesh.index()) {
+            continue;
+        }
         let mut primitives = vec![];
         for primitive in gltf_mesh.primitives() {
             let primitive_label = GltfAssetLabel::Primitive {
@@ -748,7 +786,7 @@ async fn load_gltf<'a, 'b, 'c>(
                 primitive
                     .material()
                     .index()
-                    .and_then(|i| materials.get(i).cloned()),
+                    .and_then(|i| materials.get(&i).cloned()),
                 get_gltf_extras(primitive.extras()),
                 get_gltf_extras(primitive.material().extras()),
             ));
@@ -761,31 +799,62 @@ async fn load_gltf<'a, 'b, 'c>(
         if let Some(name) = gltf_mesh.name() {
             named_meshes.insert(name.into(), handle.clone());
         }
-        meshes.push(handle);
+        meshes.insert(gltf_mesh.index(), handle);
     }
 
-    let skinned_mesh_inverse_bindposes: Vec<_> = gltf
-        .skins()
-        .map(|gltf_skin| {
-            let reader = gltf_skin.reader(|buffer| Some(&buffer_data[buffer.index()]));
-            let local_to_bone_bind_matrices: Vec<Mat4> = reader
-                .read_inverse_bind_matrices()
-                .unwrap()
-                .map(|mat| Mat4::from_cols_array_2d(&mat))
-                .collect();
-
-            load_context.add_labeled_asset(
-                inverse_bind_matrices_label(&gltf_skin),
-                SkinnedMeshInverseBindposes::from(local_to_bone_bind_matrices),
-            )
+    let node_parents: HashMap<usize, usize> = gltf
+        .nodes()
+        .flat_map(|node| {
+            node.children()
+                .map(move |child| (child.index(), node.index()))
         })
         .collect();
+    let mut skinned_mesh_inverse_bindposes = <HashMap<_, _>>::default();
+    let mut skeletons = vec![];
+    let mut named_skeletons = <HashMap<_, _>>::default();
+    for gltf_skin in gltf.skins() {
+        if !selection.skin(gltf_skin.index()) {
+            continue;
+        }
+        let reader = gltf_skin.reader(|buffer| Some(&buffer_data[buffer.index()]));
+        let local_to_bone_bind_matrices: Vec<Mat4> = reader
+            .read_inverse_bind_matrices()
+            .unwrap()
+            .map(|mat| Mat4::from_cols_array_2d(&mat))
+            .collect();
+
+        #[cfg_attr(not(feature = "bevy_animation"), allow(unused_mut))]
+        let mut skeleton = load_skeleton(&gltf_skin, &local_to_bone_bind_matrices, &node_parents);
+        #[cfg(feature = "bevy_animation")]
+        for (joint, node) in skeleton.joints.iter_mut().zip(gltf_skin.joints()) {
+            joint.target_id = paths
+                .get(&node.index())
+                .map(|(_, path)| AnimationTargetId::from_names(path.iter()));
+        }
+        let handle = load_context.add_labeled_asset(
+            GltfAssetLabel::Skeleton(gltf_skin.index()).to_string(),
+            skeleton,
+        );
+        skeletons.push(handle.clone());
+        if let Some(name) = gltf_skin.name() {
+            named_skeletons.insert(name.into(), handle);
+        }
+
+        let handle = load_context.add_labeled_asset(
+            inverse_bind_matrices_label(&gltf_skin),
+            SkinnedMeshInverseBindposes::from(local_to_bone_bind_matrices),
+        );
+        skinned_mesh_inverse_bindposes.insert(gltf_skin.index(), handle);
+    }
 
     let mut nodes = HashMap::<usize, Handle<GltfNode>>::default();
     let mut named_nodes = <HashMap<_, _>>::default();
     let mut skins = vec![];
     let mut named_skins = <HashMap<_, _>>::default();
     for node in GltfTreeIterator::try_new(&gltf)? {
+        if !selection.node(node.index()) {
+            continue;
+        }
         let skin = node.skin().map(|skin| {
             let joints = skin
                 .joints()
@@ -795,7 +864,7 @@ async fn load_gltf<'a, 'b, 'c>(
             let gltf_skin = GltfSkin::new(
                 &skin,
                 joints,
-                skinned_mesh_inverse_bindposes[skin.index()].clone(),
+                skinned_mesh_inverse_bindposes[&skin.index()].clone(),
                 get_gltf_extras(skin.extras()),
             );
 
@@ -817,7 +886,7 @@ async fn load_gltf<'a, 'b, 'c>(
         let mesh = node
             .mesh()
             .map(|mesh| mesh.index())
-            .and_then(|i| meshes.get(i).cloned());
+            .and_then(|i| meshes.get(&i).cloned());
 
         let gltf_node = GltfNode::new(
             &node,
@@ -838,17 +907,14 @@ async fn load_gltf<'a, 'b, 'c>(
         }
     }
 
-    let mut nodes_to_sort = nodes.into_iter().collect::<Vec<_>>();
-    nodes_to_sort.sort_by_key(|(i, _)| *i);
-    let nodes = nodes_to_sort
-        .into_iter()
-        .map(|(_, resolved)| resolved)
-        .collect();
+    let nodes = in_index_order(nodes);
 
+    let mut default_scene = None;
     let mut scenes = vec![];
     let mut named_scenes = <HashMap<_, _>>::default();
     let mut active_camera_found = false;
-    for scene in gltf.scenes() {
+    let node_names: Vec<_> = gltf.nodes().map(|node| node.name()).collect();
+    for scene in gltf.scenes().filter(|scene| selection.scene(scene.index())) {
         let mut err = None;
         let mut world = World::default();
         let mut node_index_to_entity_map = <HashMap<_, _>>::default();
@@ -883,6 +949,14 @@ async fn load_gltf<'a, 'b, 'c>(
             })
             .id();
 
+        let mut instance = GltfSceneInstance::default();
+        let mut node_entities: Vec<_> = node_index_to_entity_map.iter().collect();
+        node_entities.sort_by_key(|(index, _)| **index);
+        for (&index, &entity) in node_entities {
+            instance.insert(index, node_names[index], entity);
+        }
+        world.entity_mut(world_root_id).insert(instance);
+
         if let Some(extras) = scene.extras().as_ref() {
             world.entity_mut(world_root_id).insert(GltfSceneExtras {
                 value: extras.get().to_string(),
@@ -915,7 +989,7 @@ async fn load_gltf<'a, 'b, 'c>(
                 .collect();
 
             entity.insert(SkinnedMesh {
-                inverse_bindposes: skinned_mesh_inverse_bindposes[skin_index].clone(),
+                inverse_bindposes: skinned_mesh_inverse_bindposes[&skin_index].clone(),
                 joints: joint_entities,
             });
         }
@@ -925,21 +999,26 @@ async fn load_gltf<'a, 'b, 'c>(
         if let Some(name) = scene.name() {
             named_scenes.insert(name.into(), scene_handle.clone());
         }
+        if gltf
+            .default_scene()
+            .is_some_and(|default| default.index() == scene.index())
+        {
+            default_scene = Some(scene_handle.clone());
+        }
         scenes.push(scene_handle);
     }
 
     Ok(Gltf {
-        default_scene: gltf
-            .default_scene()
-            .and_then(|scene| scenes.get(scene.index()))
-            .cloned(),
+        default_scene,
         scenes,
         named_scenes,
-        meshes,
+        meshes: in_index_order(meshes),
         named_meshes,
         skins,
         named_skins,
-        materials,
+        skeletons,
+        named_skeletons,
+        materials: in_index_order(materials),
         named_materials,
         nodes,
         named_nodes,
@@ -955,6 +1034,13 @@ async fn load_gltf<'a, 'b, 'c>(
     })
 }
 
+/// Returns the values of `items`, ordered by their glTF index.
+fn in_index_order<T>(items: HashMap<usize, T>) -> Vec<T> {
+    let mut items: Vec<_> = items.into_iter().collect();
+    items.sort_by_key(|(index, _)| *index);
+    items.into_iter().map(|(_, item)| item).collect()
+}
+
 fn get_gltf_extras(extras: &json::Extras) -> Option<GltfExtras> {
     extras.as_ref().map(|extras| GltfExtras {
         value: extras.get().to_string(),
@@ -984,6 +1070,50 @@ fn node_transform(node: &Node) -> Transform {
     }
 }
 
+/// Builds the [`Skeleton`] of `skin`, whose joints have the inverse bindposes
+/// `inverse_bindposes`. `node_parents` maps the nodes of the file to their parent.
+fn load_skeleton(
+    skin: &gltf::Skin,
+    inverse_bindposes: &[Mat4],
+    node_parents: &HashMap<usize, usize>,
+) -> Skeleton {
+    let joint_indices: HashMap<usize, usize> = skin
+        .joints()
+        .enumerate()
+        .map(|(index, node)| (node.index(), index))
+        .collect();
+    let joints = skin
+        .joints()
+        .enumerate()
+        .map(|(index, node)| {
+            // The parent joint is the closest ancestor node that is a joint of the skin.
+            let mut parent = node_parents.get(&node.index());
+            while let Some(node) = parent {
+                if joint_indices.contains_key(node) {
+                    break;
+                }
+                parent = node_parents.get(node);
+            }
+            SkeletonJoint {
+                name: node_name(&node),
+                parent: parent.map(|node| joint_indices[node]),
+                rest_pose: node_transform(&node),
+                inverse_bindpose: inverse_bindposes.get(index).copied().unwrap_or_default(),
+                #[cfg(feature = "bevy_animation")]
+                target_id: None,
+            }
+        })
+        .collect();
+
+    Skeleton {
+        name: skin
+            .name()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("Skeleton{}", skin.index())),
+        joints,
+    }
+}
+
 fn node_name(node: &Node) -> Name {
     let name = node
         .name()
@@ -1829,12 +1959,17 @@ fn alpha_mode(material: &Material) -> AlphaMode {
 /// Loads the raw glTF buffer data for a specific glTF file.
 async fn load_buffers(
     gltf: &gltf::Gltf,
+    selection: &GltfSelection,
     load_context: &mut LoadContext<'_>,
 ) -> Result<Vec<Vec<u8>>, GltfError> {
     const VALID_MIME_TYPES: &[&str] = &["application/octet-stream", "application/gltf-buffer"];
 
     let mut buffer_data = Vec::new();
     for buffer in gltf.buffers() {
+        if !selection.buffer(buffer.index()) {
+            buffer_data.push(Vec::new());
+            continue;
+        }
         match buffer.source() {
             gltf::buffer::Source::Uri(uri) => {
                 let uri = percent_encoding::percent_decode_str(uri)
@@ -2255,7 +2390,10 @@ fn material_needs_tangents(material: &Material) -> bool {
 mod test {
     use std::path::Path;
 
-    use crate::{Gltf, GltfAssetLabel, GltfNode, GltfSkin};
+    use crate::{
+        Gltf, GltfAssetLabel, GltfLoaderSettings, GltfNode, GltfSceneInstance, GltfSkin,
+        RetargetMapBuilder, Skeleton,
+    };
     use bevy_app::{App, TaskPoolPlugin};
     use bevy_asset::{
         io::{
@@ -2264,10 +2402,10 @@ mod test {
         },
         AssetApp, AssetPlugin, AssetServer, Assets, Handle, LoadState,
     };
-    use bevy_ecs::{system::Resource, world::World};
+    use bevy_ecs::{name::Name, system::Resource, world::World};
     use bevy_log::LogPlugin;
     use bevy_render::mesh::{skinning::SkinnedMeshInverseBindposes, MeshPlugin};
-    use bevy_scene::ScenePlugin;
+    use bevy_scene::{Scene, ScenePlugin};
 
     fn test_app(dir: Dir) -> App {
         let mut app = App::new();
@@ -2305,6 +2443,14 @@ mod test {
     }
 
     fn load_gltf_into_app(gltf_path: &str, gltf: &str) -> App {
+        load_gltf_into_app_with_settings(gltf_path, gltf, |_| {})
+    }
+
+    fn load_gltf_into_app_with_settings(
+        gltf_path: &str,
+        gltf: &str,
+        settings: impl Fn(&mut GltfLoaderSettings) + Send + Sync + 'static,
+    ) -> App {
         #[expect(unused)]
         #[derive(Resource)]
         struct GltfHandle(Handle<Gltf>);
@@ -2314,7 +2460,7 @@ mod test {
         let mut app = test_app(dir);
         app.update();
         let asset_server = app.world().resource::<AssetServer>().clone();
-        let handle: Handle<Gltf> = asset_server.load(gltf_path.to_string());
+        let handle: Handle<Gltf> = asset_server.load_with_settings(gltf_path.to_string(), settings);
         let handle_id = handle.id();
         app.insert_resource(GltfHandle(handle));
         app.update();
@@ -2368,6 +2514,110 @@ mod test {
         assert_eq!(gltf_node.asset_label(), GltfAssetLabel::Node(0));
     }
 
+    #[test]
+    fn scene_instance() {
+        let gltf_path = "test.gltf";
+        let app = load_gltf_into_app(
+            gltf_path,
+            r#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "nodes": [
+        {
+            "name": "Door",
+            "children": [1]
+        },
+        {
+            "name": "Hinge_Door_01"
+        }
+    ],
+    "scene": 0,
+    "scenes": [{ "nodes": [0] }]
+}
+"#,
+        );
+        let asset_server = app.world().resource::<AssetServer>();
+        let handle = asset_server.load(GltfAssetLabel::Scene(0).from_asset(gltf_path));
+        let scene = app
+            .world()
+            .resource::<Assets<Scene>>()
+            .get(&handle)
+            .unwrap();
+        let mut instances = scene.world.try_query::<&GltfSceneInstance>().unwrap();
+        let instance = instances.single(&scene.world);
+
+        let hinge = instance.get_by_name("Hinge_Door_01").unwrap();
+        assert_eq!(instance.get_by_index(1), Some(hinge));
+        assert_eq!(
+            scene.world.get::<Name>(hinge).map(Name::as_str),
+            Some("Hinge_Door_01")
+        );
+        let door = instance.get_by_index(0).unwrap();
+        assert_eq!(
+            scene.world.get::<Name>(door).map(Name::as_str),
+            Some("Door")
+        );
+        assert_eq!(instance.iter_names().count(), 2);
+    }
+
+    #[test]
+    fn load_only() {
+        let gltf_path = "test.gltf";
+        let gltf = r#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "nodes": [
+        {
+            "name": "Hall",
+            "children": [1]
+        },
+        {
+            "name": "Lamp"
+        },
+        {
+            "name": "Cellar"
+        }
+    ],
+    "scene": 0,
+    "scenes": [{ "nodes": [0] }, { "name": "Basement", "nodes": [2] }]
+}
+"#;
+        let app = load_gltf_into_app_with_settings(gltf_path, gltf, |settings| {
+            settings.load_only = Some(vec!["Basement".to_string()]);
+        });
+        let loaded = app
+            .world()
+            .resource::<Assets<Gltf>>()
+            .iter()
+            .next()
+            .unwrap()
+            .1;
+        assert_eq!(loaded.scenes.len(), 1);
+        assert!(loaded.named_scenes.contains_key("Basement"));
+        assert!(loaded.default_scene.is_none());
+        assert_eq!(loaded.nodes.len(), 1);
+        assert!(loaded.named_nodes.contains_key("Cellar"));
+
+        let app = load_gltf_into_app_with_settings(gltf_path, gltf, |settings| {
+            settings.load_only = Some(vec!["Node0".to_string()]);
+        });
+        let loaded = app
+            .world()
+            .resource::<Assets<Gltf>>()
+            .iter()
+            .next()
+            .unwrap()
+            .1;
+        assert!(loaded.scenes.is_empty());
+        assert_eq!(loaded.nodes.len(), 2);
+        assert!(loaded.named_nodes.contains_key("Hall"));
+        assert!(loaded.named_nodes.contains_key("Lamp"));
+    }
+
     #[test]
     fn node_hierarchy_no_hierarchy() {
         let gltf_path = "test.gltf";
@@ -2681,4 +2931,101 @@ mod test {
         assert_eq!(skinned_node.children.len(), 2);
         assert_eq!(skinned_node.skin.as_ref(), Some(&gltf_root.skins[0]));
     }
+
+    #[test]
+    fn skeleton() {
+        let gltf_path = "test.gltf";
+        let app = load_gltf_into_app(
+            gltf_path,
+            r#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "nodes": [
+        {
+            "name": "skinned",
+            "skin": 0,
+            "children": [1]
+        },
+        {
+            "name": "hips",
+            "children": [2]
+        },
+        {
+            "name": "spine",
+            "translation": [0.0, 1.0, 0.0]
+        }
+    ],
+    "skins": [
+        {
+            "name": "rig",
+            "inverseBindMatrices": 0,
+            "joints": [2, 1]
+        }
+    ],
+    "buffers": [
+        {
+            "uri" : "data:application/gltf-buffer;base64,AACAPwAAAAAAAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAAAAAAAAAAAAgD8AAAAAAAAAAAAAAAAAAAAAAACAPwAAgD8AAAAAAAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAIC/AAAAAAAAgD8=",
+            "byteLength" : 128
+        }
+    ],
+    "bufferViews": [
+        {
+            "buffer": 0,
+            "byteLength": 128
+        }
+    ],
+    "accessors": [
+        {
+            "bufferView" : 0,
+            "componentType" : 5126,
+            "count" : 2,
+            "type" : "MAT4"
+        }
+    ],
+    "scene": 0,
+    "scenes": [{ "nodes": [0] }]
+}
+"#,
+        );
+        let asset_server = app.world().resource::<AssetServer>();
+        let handle = asset_server.load(gltf_path);
+        let gltf_root = app.world().resource::<Assets<Gltf>>().get(&handle).unwrap();
+        let skeletons = app.world().resource::<Assets<Skeleton>>();
+        let skeleton = skeletons.get(&gltf_root.named_skeletons["rig"]).unwrap();
+
+        assert_eq!(skeleton.name, "rig");
+        assert_eq!(skeleton.joint_index("spine"), Some(0));
+        assert_eq!(skeleton.joint_index("hips"), Some(1));
+        assert_eq!(skeleton.joints[0].parent, Some(1));
+        assert_eq!(skeleton.roots().collect::<Vec<_>>(), [1]);
+        assert_eq!(skeleton.children(1).collect::<Vec<_>>(), [0]);
+        assert_eq!(skeleton.joints[0].rest_pose.translation.y, 1.0);
+
+        let mut mixamo = skeleton.clone();
+        mixamo.joints[0].name = Name::new("mixamorig:Spine");
+        mixamo.joints[1].name = Name::new("mixamorig:Pelvis");
+        let map = RetargetMapBuilder::new(&mixamo, skeleton)
+            .strip_prefix("mixamorig:")
+            .ignore_case()
+            .build();
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(0, 0)]);
+        assert_eq!(map.unmatched().collect::<Vec<_>>(), [1]);
+
+        let map = RetargetMapBuilder::new(&mixamo, skeleton)
+            .strip_prefix("mixamorig:")
+            .ignore_case()
+            .with_joint("mixamorig:Pelvis", "hips")
+            .build();
+        assert_eq!(map.target_joint(1), Some(1));
+
+        // Explicit matches are normalized too, whatever the order of the builder calls.
+        let map = RetargetMapBuilder::new(&mixamo, skeleton)
+            .with_joint("PELVIS", "Hips")
+            .strip_prefix("mixamorig:")
+            .ignore_case()
+            .build();
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(0, 0), (1, 1)]);
+    }
 }
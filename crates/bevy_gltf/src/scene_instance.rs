@@ -0,0 +1,116 @@
+use bevy_ecs::{
+    entity::{Entity, VisitEntities, VisitEntitiesMut},
+    prelude::*,
+    reflect::{
+        ReflectComponent, ReflectMapEntities, ReflectVisitEntities, ReflectVisitEntitiesMut,
+    },
+};
+use bevy_hierarchy::Children;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_scene::SceneInstanceReady;
+use bevy_utils::HashMap;
+
+/// The entities spawned for the nodes of a glTF scene, by node name and by node index.
+///
+/// This is inserted on the root entity of each glTF [`Scene`](bevy_scene::Scene). When the scene
+/// is spawned as a child of an entity, for instance with a [`SceneRoot`](bevy_scene::SceneRoot),
+/// it's also copied to that entity once the scene is ready, so that specific nodes can be found
+/// without traversing the hierarchy comparing [`Name`](bevy_ecs::name::Name)s.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_gltf::GltfSceneInstance;
+/// fn open_doors(doors: Query<&GltfSceneInstance, Added<GltfSceneInstance>>) {
+///     for instance in &doors {
+///         if let Some(hinge) = instance.get_by_name("Hinge_Door_01") {
+///             // Rotate `hinge`.
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(
+    Component,
+    MapEntities,
+    VisitEntities,
+    VisitEntitiesMut,
+    Default,
+    Debug
+)]
+pub struct GltfSceneInstance {
+    by_name: HashMap<String, Entity>,
+    by_index: HashMap<usize, Entity>,
+}
+
+impl GltfSceneInstance {
+    /// Records that `entity` was spawned for the node at `index`, named `name`.
+    ///
+    /// If several nodes have the same name, the first recorded one is kept.
+    pub(crate) fn insert(&mut self, index: usize, name: Option<&str>, entity: Entity) {
+        self.by_index.insert(index, entity);
+        if let Some(name) = name {
+            self.by_name.entry(name.to_string()).or_insert(entity);
+        }
+    }
+
+    /// Returns the entity spawned for the node named `name`.
+    ///
+    /// If several nodes have this name, this is the one with the lowest index.
+    pub fn get_by_name(&self, name: &str) -> Option<Entity> {
+        self.by_name.get(name).copied()
+    }
+
+    /// Returns the entity spawned for the node at `index` in the glTF file.
+    pub fn get_by_index(&self, index: usize) -> Option<Entity> {
+        self.by_index.get(&index).copied()
+    }
+
+    /// Returns an iterator over the names of the nodes and their entities.
+    pub fn iter_names(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.by_name
+            .iter()
+            .map(|(name, &entity)| (name.as_str(), entity))
+    }
+
+    /// Returns an iterator over the indices of the nodes and their entities.
+    pub fn iter_indices(&self) -> impl Iterator<Item = (usize, Entity)> + '_ {
+        self.by_index
+            .iter()
+            .map(|(&index, &entity)| (index, entity))
+    }
+}
+
+impl VisitEntities for GltfSceneInstance {
+    fn visit_entities<F: FnMut(Entity)>(&self, mut f: F) {
+        self.by_name.values().copied().for_each(&mut f);
+        self.by_index.values().copied().for_each(f);
+    }
+}
+
+impl VisitEntitiesMut for GltfSceneInstance {
+    fn visit_entities_mut<F: FnMut(&mut Entity)>(&mut self, mut f: F) {
+        self.by_name.values_mut().for_each(&mut f);
+        self.by_index.values_mut().for_each(f);
+    }
+}
+
+/// Copies the [`GltfSceneInstance`] of a spawned glTF scene to the entity it was spawned as a
+/// child of.
+pub(crate) fn copy_scene_instance_to_parent(
+    trigger: Trigger<SceneInstanceReady>,
+    children: Query<&Children>,
+    instances: Query<&GltfSceneInstance>,
+    mut commands: Commands,
+) {
+    let parent = trigger.target();
+    let Ok(children) = children.get(parent) else {
+        return;
+    };
+    if let Some(instance) = children
+        .iter()
+        .rev()
+        .find_map(|&child| instances.get(child).ok())
+    {
+        commands.entity(parent).insert(instance.clone());
+    }
+}
@@ -0,0 +1,261 @@
+use bevy_utils::HashSet;
+use gltf::{accessor::Accessor, image::Source, Document, Node};
+use serde_json::Value;
+
+/// The items of a glTF file to load, see [`GltfLoaderSettings::load_only`](crate::GltfLoaderSettings::load_only).
+///
+/// Selecting an item also selects the items it needs: a scene needs its nodes, a node its
+/// children, mesh and skin, a skin its joints, a mesh the materials of its primitives, and a
+/// material its textures. The buffers are the ones read by the selected items.
+#[derive(Default)]
+pub(crate) struct GltfSelection {
+    all: bool,
+    scenes: HashSet<usize>,
+    nodes: HashSet<usize>,
+    meshes: HashSet<usize>,
+    materials: HashSet<usize>,
+    textures: HashSet<usize>,
+    skins: HashSet<usize>,
+    animations: HashSet<usize>,
+    buffers: HashSet<usize>,
+}
+
+impl GltfSelection {
+    /// Selects the whole file.
+    pub(crate) fn all() -> Self {
+        Self {
+            all: true,
+            ..Default::default()
+        }
+    }
+
+    /// Selects the items named by `selectors`, which are either labels like `Mesh3` or
+    /// `Scene0/Node1`, of which only the first segment is used, or names of items.
+    pub(crate) fn new(document: &Document, selectors: &[String]) -> Self {
+        let mut selection = Self::default();
+        let mut nodes = Vec::new();
+        for selector in selectors {
+            match parse_label(selector) {
+                Some(("Scene", index)) => {
+                    selection.scenes.insert(index);
+                }
+                Some(("Node", index)) => nodes.push(index),
+                Some(("Mesh", index)) => {
+                    selection.meshes.insert(index);
+                }
+                Some(("Material", index)) => {
+                    selection.materials.insert(index);
+                }
+                Some(("Texture", index)) => {
+                    selection.textures.insert(index);
+                }
+                Some(("Skin", index)) => {
+                    selection.skins.insert(index);
+                }
+                Some(("Animation", index)) => {
+                    selection.animations.insert(index);
+                }
+                _ => {}
+            }
+
+            let name = Some(selector.as_str());
+            let named =
+                |item_name: Option<&str>, index: usize| (item_name == name).then_some(index);
+            selection
+                .scenes
+                .extend(document.scenes().filter_map(|s| named(s.name(), s.index())));
+            nodes.extend(document.nodes().filter_map(|n| named(n.name(), n.index())));
+            selection
+                .meshes
+                .extend(document.meshes().filter_map(|m| named(m.name(), m.index())));
+            selection.materials.extend(
+                document
+                    .materials()
+                    .filter_map(|m| m.index().and_then(|index| named(m.name(), index))),
+            );
+            selection
+                .skins
+                .extend(document.skins().filter_map(|s| named(s.name(), s.index())));
+            selection.animations.extend(
+                document
+                    .animations()
+                    .filter_map(|a| named(a.name(), a.index())),
+            );
+        }
+
+        for scene in document.scenes() {
+            if selection.scenes.contains(&scene.index()) {
+                nodes.extend(scene.nodes().map(|node| node.index()));
+            }
+        }
+        for skin in document.skins() {
+            if selection.skins.contains(&skin.index()) {
+                nodes.extend(skin.joints().map(|joint| joint.index()));
+            }
+        }
+        let all_nodes: Vec<Node> = document.nodes().collect();
+        while let Some(index) = nodes.pop() {
+            let Some(node) = all_nodes.get(index) else {
+                continue;
+            };
+            if !selection.nodes.insert(index) {
+                continue;
+            }
+            nodes.extend(node.children().map(|child| child.index()));
+            if let Some(mesh) = node.mesh() {
+                selection.meshes.insert(mesh.index());
+            }
+            if let Some(skin) = node.skin() {
+                if selection.skins.insert(skin.index()) {
+                    nodes.extend(skin.joints().map(|joint| joint.index()));
+                }
+            }
+        }
+
+        for mesh in document.meshes() {
+            if !selection.meshes.contains(&mesh.index()) {
+                continue;
+            }
+            for primitive in mesh.primitives() {
+                if let Some(material) = primitive.material().index() {
+                    selection.materials.insert(material);
+                }
+                let morph_targets = primitive.morph_targets().flat_map(|target| {
+                    [target.positions(), target.normals(), target.tangents()]
+                        .into_iter()
+                        .flatten()
+                });
+                for accessor in primitive
+                    .attributes()
+                    .map(|(_, accessor)| accessor)
+                    .chain(primitive.indices())
+                    .chain(morph_targets)
+                {
+                    selection.insert_buffers(&accessor);
+                }
+            }
+        }
+        for (index, material) in document.as_json().materials.iter().enumerate() {
+            if selection.materials.contains(&index) {
+                if let Ok(material) = serde_json::to_value(material) {
+                    insert_texture_indices(&material, &mut selection.textures);
+                }
+            }
+        }
+        for texture in document.textures() {
+            if selection.textures.contains(&texture.index()) {
+                if let Source::View { view, .. } = texture.source().source() {
+                    selection.buffers.insert(view.buffer().index());
+                }
+            }
+        }
+        for skin in document.skins() {
+            if selection.skins.contains(&skin.index()) {
+                if let Some(accessor) = skin.inverse_bind_matrices() {
+                    selection.insert_buffers(&accessor);
+                }
+            }
+        }
+        for animation in document.animations() {
+            if selection.animations.contains(&animation.index()) {
+                for channel in animation.channels() {
+                    selection.insert_buffers(&channel.sampler().input());
+                    selection.insert_buffers(&channel.sampler().output());
+                }
+            }
+        }
+
+        selection
+    }
+
+    fn insert_buffers(&mut self, accessor: &Accessor) {
+        if let Some(view) = accessor.view() {
+            self.buffers.insert(view.buffer().index());
+        }
+        if let Some(sparse) = accessor.sparse() {
+            self.buffers
+                .insert(sparse.indices().view().buffer().index());
+            self.buffers.insert(sparse.values().view().buffer().index());
+        }
+    }
+
+    pub(crate) fn scene(&self, index: usize) -> bool {
+        self.all || self.scenes.contains(&index)
+    }
+
+    pub(crate) fn node(&self, index: usize) -> bool {
+        self.all || self.nodes.contains(&index)
+    }
+
+    pub(crate) fn mesh(&self, index: usize) -> bool {
+        self.all || self.meshes.contains(&index)
+    }
+
+    pub(crate) fn material(&self, index: usize) -> bool {
+        self.all || self.materials.contains(&index)
+    }
+
+    pub(crate) fn texture(&self, index: usize) -> bool {
+        self.all || self.textures.contains(&index)
+    }
+
+    pub(crate) fn skin(&self, index: usize) -> bool {
+        self.all || self.skins.contains(&index)
+    }
+
+    #[cfg_attr(not(feature = "bevy_animation"), allow(dead_code))]
+    pub(crate) fn animation(&self, index: usize) -> bool {
+        self.all || self.animations.contains(&index)
+    }
+
+    pub(crate) fn buffer(&self, index: usize) -> bool {
+        self.all || self.buffers.contains(&index)
+    }
+}
+
+/// Splits the first segment of a label like `Mesh3/Primitive0` into its kind and index.
+fn parse_label(label: &str) -> Option<(&str, usize)> {
+    let segment = label.split('/').next()?;
+    let digits_start = segment.find(|c: char| c.is_ascii_digit())?;
+    let (kind, rest) = segment.split_at(digits_start);
+    let digits_end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    Some((kind, rest[..digits_end].parse().ok()?))
+}
+
+/// Inserts the indices of the textures referenced by the `*Texture` properties of a material,
+/// including the ones of its extensions.
+fn insert_texture_indices(value: &Value, textures: &mut HashSet<usize>) {
+    match value {
+        Value::Object(properties) => {
+            for (key, value) in properties {
+                if key.ends_with("Texture") {
+                    if let Some(index) = value.get("index").and_then(Value::as_u64) {
+                        textures.insert(index as usize);
+                    }
+                }
+                insert_texture_indices(value, textures);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                insert_texture_indices(value, textures);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_label;
+
+    #[test]
+    fn parse_labels() {
+        assert_eq!(parse_label("Mesh3"), Some(("Mesh", 3)));
+        assert_eq!(parse_label("Scene0/Node12"), Some(("Scene", 0)));
+        assert_eq!(parse_label("Material1 (inverted)"), Some(("Material", 1)));
+        assert_eq!(parse_label("DefaultMaterial"), None);
+    }
+}
@@ -0,0 +1,246 @@
+#[cfg(feature = "bevy_animation")]
+use bevy_animation::{AnimationClip, AnimationTargetId};
+use bevy_asset::Asset;
+use bevy_ecs::name::Name;
+use bevy_math::Mat4;
+use bevy_reflect::TypePath;
+use bevy_transform::prelude::Transform;
+use bevy_utils::HashMap;
+
+/// The joint hierarchy of a glTF skin, with the names and rest poses of its joints.
+///
+/// Unlike [`GltfSkin`](crate::GltfSkin), whose joints are the nodes of the glTF file, this describes
+/// the rig on its own, to build a [`RetargetMap`] binding the animations of one rig to another.
+///
+/// See the [`GltfAssetLabel::Skeleton`](crate::GltfAssetLabel::Skeleton) label.
+#[derive(Asset, Debug, Clone, TypePath)]
+pub struct Skeleton {
+    /// Computed name for the skeleton - the name of its skin, or a generated name from its index.
+    pub name: String,
+    /// The joints of the skeleton, in the order of the joints of the skin. Parents may come after
+    /// their children.
+    pub joints: Vec<SkeletonJoint>,
+}
+
+/// A joint of a [`Skeleton`].
+#[derive(Debug, Clone)]
+pub struct SkeletonJoint {
+    /// The name of the joint node, or a generated name from its index.
+    pub name: Name,
+    /// The index of the parent joint in [`Skeleton::joints`], `None` for root joints.
+    pub parent: Option<usize>,
+    /// The transform of the joint relative to its parent node, in the rest pose.
+    pub rest_pose: Transform,
+    /// The inverse of the global transform of the joint when the mesh was bound to the skeleton.
+    pub inverse_bindpose: Mat4,
+    /// The id of the joint in the [`AnimationClip`]s of the glTF file, `None` if part of its
+    /// hierarchy is missing a name.
+    #[cfg(feature = "bevy_animation")]
+    pub target_id: Option<AnimationTargetId>,
+}
+
+impl Skeleton {
+    /// Returns the index of the joint named `name`.
+    pub fn joint_index(&self, name: &str) -> Option<usize> {
+        self.joints
+            .iter()
+            .position(|joint| joint.name.as_str() == name)
+    }
+
+    /// Returns the joint named `name`.
+    pub fn joint(&self, name: &str) -> Option<&SkeletonJoint> {
+        self.joints.iter().find(|joint| joint.name.as_str() == name)
+    }
+
+    /// Returns an iterator over the indices of the joints without a parent joint.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.joints
+            .iter()
+            .enumerate()
+            .filter(|(_, joint)| joint.parent.is_none())
+            .map(|(index, _)| index)
+    }
+
+    /// Returns an iterator over the indices of the children of the joint `index`.
+    pub fn children(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.joints
+            .iter()
+            .enumerate()
+            .filter(move |(_, joint)| joint.parent == Some(index))
+            .map(|(index, _)| index)
+    }
+}
+
+/// Builds a [`RetargetMap`] matching the joints of a source [`Skeleton`] with the joints of a target
+/// [`Skeleton`] by name.
+///
+/// ```
+/// # use bevy_gltf::{RetargetMapBuilder, Skeleton};
+/// # fn retarget(mixamo: &Skeleton, character: &Skeleton) {
+/// let map = RetargetMapBuilder::new(mixamo, character)
+///     .strip_prefix("mixamorig:")
+///     .ignore_case()
+///     .with_joint("Hips", "pelvis")
+///     .build();
+/// # }
+/// ```
+pub struct RetargetMapBuilder<'a> {
+    source: &'a Skeleton,
+    target: &'a Skeleton,
+    prefixes: Vec<String>,
+    ignore_case: bool,
+    joints: HashMap<String, String>,
+}
+
+impl<'a> RetargetMapBuilder<'a> {
+    /// Creates a builder matching the joints of `source` with the joints of `target` that have the
+    /// same name.
+    pub fn new(source: &'a Skeleton, target: &'a Skeleton) -> Self {
+        Self {
+            source,
+            target,
+            prefixes: Vec::new(),
+            ignore_case: false,
+            joints: HashMap::default(),
+        }
+    }
+
+    /// Ignores `prefix` at the start of joint names, like the `mixamorig:` prefix of the rigs
+    /// exported by Mixamo.
+    pub fn strip_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Ignores the case of joint names.
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Matches the source joint named `source` with the target joint named `target`, whatever
+    /// their names.
+    ///
+    /// Both names are compared like the other joint names, without the
+    /// [stripped prefixes](Self::strip_prefix) and [ignoring the case](Self::ignore_case) if set.
+    pub fn with_joint(mut self, source: impl Into<String>, target: impl Into<String>) -> Self {
+        self.joints.insert(source.into(), target.into());
+        self
+    }
+
+    /// Builds the map. Joints without a match are left out of it.
+    pub fn build(self) -> RetargetMap {
+        let mut targets = <HashMap<_, _>>::default();
+        for (index, joint) in self.target.joints.iter().enumerate() {
+            targets.entry(self.normalize(&joint.name)).or_insert(index);
+        }
+        let explicit: HashMap<String, String> = self
+            .joints
+            .iter()
+            .map(|(source, target)| (self.normalize(source), self.normalize(target)))
+            .collect();
+
+        let joints: Vec<Option<usize>> = self
+            .source
+            .joints
+            .iter()
+            .map(|joint| {
+                let name = self.normalize(&joint.name);
+                let target = explicit.get(&name).unwrap_or(&name);
+                targets.get(target).copied()
+            })
+            .collect();
+
+        #[cfg(feature = "bevy_animation")]
+        let target_ids = self
+            .source
+            .joints
+            .iter()
+            .zip(&joints)
+            .filter_map(|(joint, target)| {
+                let target_id = target.and_then(|target| self.target.joints[target].target_id);
+                Some((joint.target_id?, target_id))
+            })
+            .collect();
+
+        RetargetMap {
+            joints,
+            #[cfg(feature = "bevy_animation")]
+            target_ids,
+        }
+    }
+
+    fn normalize(&self, name: &str) -> String {
+        let name = self
+            .prefixes
+            .iter()
+            .find_map(|prefix| name.strip_prefix(prefix.as_str()))
+            .unwrap_or(name);
+        if self.ignore_case {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        }
+    }
+}
+
+/// Matches the joints of a source [`Skeleton`] with the joints of a target [`Skeleton`], to play
+/// the animations of the source rig on the target rig. Built with a [`RetargetMapBuilder`].
+///
+/// Only the targets of the animation curves are remapped: the rigs must have compatible rest poses
+/// and joint orientations for the animations to look right.
+#[derive(Debug, Clone, Default)]
+pub struct RetargetMap {
+    joints: Vec<Option<usize>>,
+    /// The ids of the source joints in animations, with the ids of their matches.
+    #[cfg(feature = "bevy_animation")]
+    target_ids: HashMap<AnimationTargetId, Option<AnimationTargetId>>,
+}
+
+impl RetargetMap {
+    /// Returns the index of the target joint matching the source joint `source`.
+    pub fn target_joint(&self, source: usize) -> Option<usize> {
+        self.joints.get(source).copied().flatten()
+    }
+
+    /// Returns an iterator over the indices of the matched source and target joints.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.joints
+            .iter()
+            .enumerate()
+            .filter_map(|(source, target)| Some((source, (*target)?)))
+    }
+
+    /// Returns an iterator over the indices of the source joints without a match.
+    pub fn unmatched(&self) -> impl Iterator<Item = usize> + '_ {
+        self.joints
+            .iter()
+            .enumerate()
+            .filter(|(_, target)| target.is_none())
+            .map(|(source, _)| source)
+    }
+
+    /// Returns a copy of `clip`, an animation of the source rig, whose curves animate the matching
+    /// joints of the target rig instead.
+    ///
+    /// The curves of the source joints without a match are left out, the curves of the other
+    /// targets are kept.
+    #[cfg(feature = "bevy_animation")]
+    pub fn retarget_clip(&self, clip: &AnimationClip) -> AnimationClip {
+        let mut retargeted = clip.clone();
+        let curves = core::mem::take(retargeted.curves_mut());
+        for (id, curves) in curves {
+            let id = match self.target_ids.get(&id) {
+                Some(Some(target)) => *target,
+                Some(None) => continue,
+                None => id,
+            };
+            retargeted
+                .curves_mut()
+                .entry(id)
+                .or_default()
+                .extend(curves);
+        }
+        retargeted
+    }
+}
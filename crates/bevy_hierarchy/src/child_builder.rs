@@ -3,7 +3,8 @@ use bevy_ecs::{
     bundle::Bundle,
     entity::Entity,
     event::Events,
-    system::{Command, Commands, EntityCommands},
+    result::Result,
+    system::{Command, Commands, EntityCommands, MergeCommand},
     world::{EntityWorldMut, World},
 };
 use smallvec::{smallvec, SmallVec};
@@ -149,6 +150,32 @@ fn remove_children(parent: Entity, children: &[Entity], world: &mut World) {
     }
 }
 
+/// Command adding `children` to `parent`, queued with [`Commands::queue_merged`] so that
+/// children added to the same parent one after another are added at once.
+struct AddChildren {
+    parent: Entity,
+    children: SmallVec<[Entity; 8]>,
+}
+
+impl Command for AddChildren {
+    fn apply(self, world: &mut World) -> Result {
+        world.entity_mut(self.parent).add_children(&self.children);
+        Ok(())
+    }
+}
+
+impl MergeCommand for AddChildren {
+    fn merge(&mut self, next: Self) -> Option<Self> {
+        if next.parent != self.parent {
+            return Some(next);
+        }
+        // Children added again move to the end, like with separate commands.
+        self.children.retain(|child| !next.children.contains(child));
+        self.children.extend(next.children);
+        None
+    }
+}
+
 /// Struct for building children entities and adding them to a parent entity.
 ///
 /// # Example
@@ -351,9 +378,9 @@ impl BuildChildren for EntityCommands<'_> {
         if children.contains(&parent) {
             panic!("Entity cannot be a child of itself.");
         }
-        self.queue(move |entity: Entity, world: &mut World| {
-            world.entity_mut(entity).add_children(&children);
-        })
+        self.commands()
+            .queue_merged(AddChildren { parent, children });
+        self
     }
 
     fn with_child<B: Bundle>(&mut self, bundle: B) -> &mut Self {
@@ -368,10 +395,10 @@ impl BuildChildren for EntityCommands<'_> {
         if children.contains(&parent) {
             panic!("Cannot add entity as a child of itself.");
         }
-        let children = SmallVec::<[Entity; 8]>::from_slice(children);
-        self.queue(move |entity: Entity, world: &mut World| {
-            world.entity_mut(entity).add_children(&children);
-        })
+        let children = SmallVec::from_slice(children);
+        self.commands()
+            .queue_merged(AddChildren { parent, children });
+        self
     }
 
     fn insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self {
@@ -397,9 +424,10 @@ impl BuildChildren for EntityCommands<'_> {
         if child == parent {
             panic!("Cannot add entity as a child of itself.");
         }
-        self.queue(move |entity: Entity, world: &mut World| {
-            world.entity_mut(entity).add_child(child);
-        })
+        let children = smallvec![child];
+        self.commands()
+            .queue_merged(AddChildren { parent, children });
+        self
     }
 
     fn clear_children(&mut self) -> &mut Self {
@@ -1186,6 +1214,27 @@ mod tests {
         assert_eq!(**children, [child]);
     }
 
+    #[test]
+    fn add_child_commands_merged() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let [a, b, c, parent, other] = core::array::from_fn(|_| world.spawn_empty().id());
+
+        let mut commands = Commands::new(&mut queue, &world);
+        commands
+            .entity(parent)
+            .add_child(a)
+            .add_children(&[b, c])
+            .add_child(a);
+        commands.entity(other).add_child(c);
+        queue.apply(&mut world);
+
+        assert_eq!(**world.get::<Children>(parent).unwrap(), [b, a]);
+        assert_eq!(**world.get::<Children>(other).unwrap(), [c]);
+        assert_eq!(world.get::<Parent>(a).unwrap().get(), parent);
+        assert_eq!(world.get::<Parent>(c).unwrap().get(), other);
+    }
+
     #[test]
     fn add_children_does_not_insert_empty_children() {
         let mut world = World::new();
@@ -2,11 +2,13 @@ use crate::{
     components::{Children, Parent},
     BuildChildren,
 };
+use alloc::vec;
 use bevy_ecs::{
+    archetype::{ArchetypeBatchCounts, ArchetypeBatchKind},
     component::ComponentCloneHandler,
     entity::{ComponentCloneCtx, Entity, EntityCloneBuilder},
     system::EntityCommands,
-    world::{DeferredWorld, EntityWorldMut, World},
+    world::{error::MoveEntitiesError, DeferredWorld, EntityWorldMut, World},
 };
 use log::debug;
 
@@ -20,32 +22,74 @@ pub fn despawn_with_children_recursive(world: &mut World, entity: Entity, warn:
     }
 
     // then despawn the entity and all of its children
-    despawn_with_children_recursive_inner(world, entity, warn);
+    let mut counts = ArchetypeBatchCounts::default();
+    despawn_with_children_recursive_inner(world, entity, warn, &mut counts);
+    counts.trigger(world, ArchetypeBatchKind::Despawned);
 }
 
 // Should only be called by `despawn_with_children_recursive` and `despawn_children_recursive`!
-fn despawn_with_children_recursive_inner(world: &mut World, entity: Entity, warn: bool) {
+fn despawn_with_children_recursive_inner(
+    world: &mut World,
+    entity: Entity,
+    warn: bool,
+    counts: &mut ArchetypeBatchCounts,
+) {
     if let Some(mut children) = world.get_mut::<Children>(entity) {
         for e in core::mem::take(&mut children.0) {
-            despawn_with_children_recursive_inner(world, e, warn);
+            despawn_with_children_recursive_inner(world, e, warn, counts);
         }
     }
 
-    if warn {
-        if !world.despawn(entity) {
-            debug!("Failed to despawn entity {}", entity);
-        }
-    } else if !world.try_despawn(entity) {
-        debug!("Failed to despawn entity {}", entity);
+    let archetype = world
+        .entities()
+        .get(entity)
+        .map(|location| location.archetype_id);
+    let despawned = if warn {
+        world.despawn(entity)
+    } else {
+        world.try_despawn(entity)
+    };
+    match archetype {
+        Some(archetype) if despawned => counts.add(archetype),
+        _ => debug!("Failed to despawn entity {}", entity),
     }
 }
 
 fn despawn_children_recursive(world: &mut World, entity: Entity, warn: bool) {
     if let Some(children) = world.entity_mut(entity).take::<Children>() {
+        let mut counts = ArchetypeBatchCounts::default();
         for e in children.0 {
-            despawn_with_children_recursive_inner(world, e, warn);
+            despawn_with_children_recursive_inner(world, e, warn, &mut counts);
+        }
+        counts.trigger(world, ArchetypeBatchKind::Despawned);
+    }
+}
+
+/// Function for moving an entity and all its children to another [`World`], returning the entity
+/// it was given in `other`.
+///
+/// The entity is removed from its parent first. [`Parent`] and [`Children`] are kept pointing at
+/// the moved entities as long as they are registered in the `AppTypeRegistry` of either world,
+/// which is done by the `HierarchyPlugin`. See [`World::move_entities_to`] for details.
+pub fn move_with_children_recursive(
+    world: &mut World,
+    other: &mut World,
+    entity: Entity,
+) -> Result<Entity, MoveEntitiesError> {
+    let mut entities = vec![entity];
+    let mut index = 0;
+    while let Some(&current) = entities.get(index) {
+        if let Some(children) = world.get::<Children>(current) {
+            entities.extend_from_slice(children);
         }
+        index += 1;
     }
+    // Nothing is changed if the entities can't be moved.
+    world.check_move_entities(&entities)?;
+
+    world.entity_mut(entity).remove_parent();
+    let entity_map = world.move_entities_to(other, &entities)?;
+    Ok(entity_map[&entity])
 }
 
 /// Trait that holds functions for despawning recursively down the transform hierarchy
@@ -249,12 +293,14 @@ fn component_clone_parent(world: &mut DeferredWorld, ctx: &mut ComponentCloneCtx
 mod tests {
     use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
     use bevy_ecs::{
+        archetype::{ArchetypeBatchEvent, ArchetypeBatchKind},
         component::Component,
-        system::{error_handler, Commands},
+        observer::Trigger,
+        system::{error_handler, Commands, ResMut, Resource},
         world::{CommandQueue, World},
     };
 
-    use super::DespawnRecursiveExt;
+    use super::{move_with_children_recursive, DespawnRecursiveExt};
     use crate::{
         child_builder::{BuildChildren, ChildBuild},
         components::Children,
@@ -343,6 +389,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn despawn_recursive_triggers_batch_events() {
+        #[derive(Resource, Default)]
+        struct Events(Vec<ArchetypeBatchEvent>);
+
+        let mut world = World::default();
+        world.init_resource::<Events>();
+        world.add_observer(
+            |trigger: Trigger<ArchetypeBatchEvent>, mut events: ResMut<Events>| {
+                events.0.push(*trigger.event());
+            },
+        );
+
+        let parent = world.spawn(Idx(0)).id();
+        let children = (1..4)
+            .map(|i| world.spawn(Idx(i)).set_parent(parent).id())
+            .collect::<Vec<_>>();
+        let parent_archetype = world.entity(parent).archetype().id();
+        let child_archetype = world.entity(children[0]).archetype().id();
+
+        world.entity_mut(parent).despawn_recursive();
+
+        assert_eq!(
+            world.resource::<Events>().0,
+            [
+                ArchetypeBatchEvent {
+                    kind: ArchetypeBatchKind::Despawned,
+                    archetype: child_archetype,
+                    count: 3,
+                },
+                ArchetypeBatchEvent {
+                    kind: ArchetypeBatchKind::Despawned,
+                    archetype: parent_archetype,
+                    count: 1,
+                },
+            ]
+        );
+    }
+
     #[test]
     fn despawn_descendants() {
         let mut world = World::default();
@@ -479,4 +564,47 @@ mod tests {
             .get::<Children>()
             .is_some_and(|c| c.contains(&child_clone)));
     }
+
+    #[cfg(feature = "reflect")]
+    #[test]
+    fn move_with_children() {
+        use crate::components::Parent;
+        use bevy_ecs::{reflect::AppTypeRegistry, world::error::MoveEntitiesError};
+
+        let mut world = World::default();
+        let mut other = World::default();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Parent>();
+        registry.write().register::<Children>();
+        world.insert_resource(registry);
+
+        let root = world.spawn(Idx(0)).id();
+        let parent = world.spawn(Idx(1)).set_parent(root).id();
+        let child = world.spawn(Idx(2)).set_parent(parent).id();
+
+        // A malformed hierarchy can't be moved, and is left untouched.
+        world
+            .entity_mut(parent)
+            .insert(Children::from_entities(&[child, child]));
+        assert_eq!(
+            Err(MoveEntitiesError::DuplicateEntity(child)),
+            move_with_children_recursive(&mut world, &mut other, parent)
+        );
+        assert_eq!(root, world.get::<Parent>(parent).unwrap().get());
+        assert_eq!(&[parent], &**world.get::<Children>(root).unwrap());
+        world
+            .entity_mut(parent)
+            .insert(Children::from_entities(&[child]));
+
+        let moved = move_with_children_recursive(&mut world, &mut other, parent).unwrap();
+        assert!(world.get_entity(parent).is_err());
+        assert!(world.get_entity(child).is_err());
+        assert!(world.get::<Children>(root).is_none());
+
+        assert!(other.get::<Parent>(moved).is_none());
+        assert_eq!(Some(&Idx(1)), other.get::<Idx>(moved));
+        let moved_child = other.get::<Children>(moved).unwrap()[0];
+        assert_eq!(Some(&Idx(2)), other.get::<Idx>(moved_child));
+        assert_eq!(moved, other.get::<Parent>(moved_child).unwrap().get());
+    }
 }
@@ -75,6 +75,11 @@ pub use valid_parent_check_plugin::*;
 mod query_extension;
 pub use query_extension::*;
 
+#[cfg(feature = "reflect")]
+mod reflect_clone;
+#[cfg(feature = "reflect")]
+pub use reflect_clone::*;
+
 /// The hierarchy prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
@@ -0,0 +1,209 @@
+use crate::components::{Children, Parent};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap, EntityMapper},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+    system::EntityCommands,
+    world::{EntityWorldMut, World},
+};
+use bevy_reflect::PartialReflect;
+use core::any::TypeId;
+
+/// Function for cloning an entity and all its descendants through reflection.
+///
+/// Every component whose type is registered with [`ReflectComponent`] in the [`AppTypeRegistry`]
+/// is cloned, the other components are skipped. The entities referenced by the cloned components
+/// are mapped with [`ReflectMapEntities`] if it's registered, and with
+/// [`Component::map_entities`](bevy_ecs::component::Component::map_entities) otherwise: references
+/// to the cloned entities point to their clones, and references to other entities are kept.
+///
+/// The clone of `entity` is returned. It has no parent, while the clones of its descendants keep
+/// the hierarchy of the originals.
+///
+/// # Panics
+///
+/// Panics if `entity` doesn't exist, or if the world has no [`AppTypeRegistry`].
+pub fn clone_with_children_recursive(world: &mut World, entity: Entity) -> Entity {
+    let clone = world.spawn_empty().id();
+    clone_with_children_recursive_into(world, entity, clone);
+    clone
+}
+
+fn clone_with_children_recursive_into(world: &mut World, entity: Entity, clone: Entity) {
+    let mut entity_map = EntityHashMap::default();
+    entity_map.insert(entity, clone);
+    let mut sources = Vec::new();
+    let mut stack = vec![entity];
+    while let Some(source) = stack.pop() {
+        sources.push(source);
+        let children = world
+            .get::<Children>(source)
+            .map(|children| children.to_vec())
+            .unwrap_or_default();
+        for child in children {
+            if !entity_map.contains_key(&child) {
+                entity_map.insert(child, world.spawn_empty().id());
+                stack.push(child);
+            }
+        }
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    for source in sources {
+        let source_ref = world.entity(source);
+        // Cloned first, as the clone is accessed mutably.
+        let components: Vec<(_, _, Box<dyn PartialReflect>)> = source_ref
+            .archetype()
+            .components()
+            .filter_map(|component_id| world.components().get_info(component_id)?.type_id())
+            // The clone of the root is detached from the parent of the original.
+            .filter(|&type_id| source != entity || type_id != TypeId::of::<Parent>())
+            .filter_map(|type_id| {
+                let registration = type_registry.get(type_id)?;
+                let reflect_component = registration.data::<ReflectComponent>()?;
+                let value = reflect_component.reflect(source_ref)?.clone_value();
+                Some((
+                    reflect_component,
+                    registration.data::<ReflectMapEntities>(),
+                    value,
+                ))
+            })
+            .collect();
+
+        let mut target = world.entity_mut(entity_map[&source]);
+        let mut mapper = CloneMapper(&entity_map);
+        for (reflect_component, map_entities, mut value) in components {
+            if let Some(map_entities) = map_entities {
+                map_entities.map_entities(value.as_partial_reflect_mut(), &mut mapper);
+                reflect_component.apply_or_insert(
+                    &mut target,
+                    value.as_partial_reflect(),
+                    &type_registry,
+                );
+            } else {
+                reflect_component.apply_or_insert_mapped(
+                    &mut target,
+                    value.as_partial_reflect(),
+                    &type_registry,
+                    &mut mapper,
+                );
+            }
+        }
+    }
+}
+
+/// Maps the cloned entities to their clones, and keeps the other entities.
+struct CloneMapper<'a>(&'a EntityHashMap<Entity>);
+
+impl EntityMapper for CloneMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Trait that holds functions for cloning entities recursively down the hierarchy through
+/// reflection.
+pub trait CloneRecursiveReflectExt {
+    /// Clones the entity and its descendants through reflection, and returns the clone of the
+    /// entity.
+    ///
+    /// See [`clone_with_children_recursive`].
+    fn clone_recursive_reflect(&mut self) -> Entity;
+}
+
+impl CloneRecursiveReflectExt for EntityCommands<'_> {
+    /// Reserves the clone of the entity, whose components are cloned when the command is applied.
+    fn clone_recursive_reflect(&mut self) -> Entity {
+        let clone = self.commands().spawn_empty().id();
+        self.queue(move |entity: Entity, world: &mut World| {
+            #[cfg(feature = "trace")]
+            let _span = tracing::info_span!(
+                "command",
+                name = "CloneRecursiveReflect",
+                entity = tracing::field::debug(entity)
+            )
+            .entered();
+            clone_with_children_recursive_into(world, entity, clone);
+        });
+        clone
+    }
+}
+
+impl CloneRecursiveReflectExt for EntityWorldMut<'_> {
+    fn clone_recursive_reflect(&mut self) -> Entity {
+        let entity = self.id();
+        self.world_scope(|world| clone_with_children_recursive(world, entity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildChildren;
+    use bevy_ecs::{component::Component, reflect::ReflectComponent};
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Target(#[entities] Entity);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Value(u32);
+
+    #[derive(Component)]
+    struct NotReflected;
+
+    #[test]
+    fn clone_recursive_reflect() {
+        let mut world = World::default();
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+            registry.register::<Target>();
+            registry.register::<Value>();
+        }
+        world.insert_resource(type_registry);
+
+        let outside = world.spawn_empty().id();
+        let parent = world.spawn_empty().id();
+        let root = world
+            .spawn((Value(0), NotReflected))
+            .set_parent(parent)
+            .id();
+        let child = world.spawn((Value(1), Target(root))).set_parent(root).id();
+        let grandchild = world
+            .spawn((Value(2), Target(outside)))
+            .set_parent(child)
+            .id();
+        world.entity_mut(root).insert(Target(grandchild));
+
+        let root_clone = world.entity_mut(root).clone_recursive_reflect();
+
+        let clone = world.entity(root_clone);
+        assert_eq!(clone.get::<Value>(), Some(&Value(0)));
+        assert!(!clone.contains::<NotReflected>());
+        assert!(!clone.contains::<Parent>());
+        assert_eq!(world.get::<Children>(parent).unwrap().len(), 1);
+
+        let child_clone = clone.get::<Children>().unwrap()[0];
+        assert_ne!(child_clone, child);
+        assert_eq!(world.get::<Value>(child_clone), Some(&Value(1)));
+        assert_eq!(world.get::<Target>(child_clone), Some(&Target(root_clone)));
+        assert_eq!(world.get::<Parent>(child_clone).unwrap().get(), root_clone);
+
+        let grandchild_clone = world.get::<Children>(child_clone).unwrap()[0];
+        assert_ne!(grandchild_clone, grandchild);
+        assert_eq!(
+            world.get::<Target>(grandchild_clone),
+            Some(&Target(outside))
+        );
+        assert_eq!(
+            world.get::<Target>(root_clone),
+            Some(&Target(grandchild_clone))
+        );
+    }
+}
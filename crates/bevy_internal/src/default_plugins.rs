@@ -9,6 +9,7 @@ plugin_group! {
         bevy_diagnostic:::FrameCountPlugin,
         bevy_time:::TimePlugin,
         bevy_transform:::TransformPlugin,
+        bevy_transform::constraints:::TransformConstraintPlugin,
         bevy_hierarchy:::HierarchyPlugin,
         bevy_diagnostic:::DiagnosticsPlugin,
         bevy_input:::InputPlugin,
@@ -133,3 +134,45 @@ plugin_group! {
     ///     Duration::from_secs_f64(1.0 / 60.0),
     /// ))).run();
 }
+
+plugin_group! {
+    /// This plugin group will add the plugins for a headless *Bevy* application, like a dedicated
+    /// server:
+    pub struct ServerPlugins {
+        bevy_app:::PanicHandlerPlugin,
+        bevy_log:::LogPlugin,
+        bevy_app:::TaskPoolPlugin,
+        bevy_diagnostic:::FrameCountPlugin,
+        bevy_time:::TimePlugin,
+        bevy_transform:::TransformPlugin,
+        bevy_transform::constraints:::TransformConstraintPlugin,
+        bevy_hierarchy:::HierarchyPlugin,
+        bevy_diagnostic:::DiagnosticsPlugin,
+        bevy_app:::ScheduleRunnerPlugin,
+        #[custom(cfg(any(unix, windows)))]
+        bevy_app:::TerminalCtrlCHandlerPlugin,
+        #[cfg(feature = "bevy_asset")]
+        bevy_asset:::AssetPlugin,
+        #[cfg(feature = "bevy_scene")]
+        bevy_scene:::ScenePlugin,
+        #[cfg(feature = "bevy_state")]
+        bevy_state::app:::StatesPlugin,
+        #[doc(hidden)]
+        :IgnoreAmbiguitiesPlugin,
+    }
+    /// Unlike [`DefaultPlugins`], it doesn't open windows, render, play audio or read input devices,
+    /// whatever the enabled *Cargo* *features*.
+    ///
+    /// Like [`MinimalPlugins`], its [schedule runner (`ScheduleRunnerPlugin`)](crate::app::ScheduleRunnerPlugin)
+    /// runs the app as fast as possible by default. A dedicated server usually runs at a fixed
+    /// rate instead, which can be set using [`run_loop`](crate::app::ScheduleRunnerPlugin::run_loop).
+    /// # Example:
+    /// ```rust, no_run
+    /// # use std::time::Duration;
+    /// # use bevy_app::{App, PluginGroup, ScheduleRunnerPlugin};
+    /// # use bevy_internal::ServerPlugins;
+    /// App::new().add_plugins(ServerPlugins.set(ScheduleRunnerPlugin::run_loop(
+    ///     // Run 30 times per second.
+    ///     Duration::from_secs_f64(1.0 / 30.0),
+    /// ))).run();
+}
@@ -2,7 +2,7 @@
 pub use crate::{
     app::prelude::*, ecs::prelude::*, hierarchy::prelude::*, input::prelude::*, log::prelude::*,
     math::prelude::*, reflect::prelude::*, time::prelude::*, transform::prelude::*,
-    utils::prelude::*, DefaultPlugins, MinimalPlugins,
+    utils::prelude::*, DefaultPlugins, MinimalPlugins, ServerPlugins,
 };
 
 #[doc(hidden)]
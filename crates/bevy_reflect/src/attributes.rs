@@ -1,4 +1,4 @@
-use crate::Reflect;
+use crate::{self as bevy_reflect, Reflect};
 use alloc::boxed::Box;
 use bevy_utils::TypeIdMap;
 use core::{
@@ -175,6 +175,80 @@ macro_rules! impl_custom_attribute_methods {
 
 pub(crate) use impl_custom_attribute_methods;
 
+/// A custom attribute describing a field or type to the user, such as the hover text of an
+/// inspector widget.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{attributes::{Category, NumericRange, Step, Tooltip}, Reflect, Typed, TypeInfo};
+/// #[derive(Reflect)]
+/// struct Light {
+///     #[reflect(@Tooltip("Luminous intensity, in candela"))]
+///     #[reflect(@Category("Lighting"))]
+///     #[reflect(@NumericRange::new(0.0, 10_000.0), @Step(10.0))]
+///     intensity: f32,
+/// }
+///
+/// let TypeInfo::Struct(info) = Light::type_info() else {
+///     panic!("expected struct info");
+/// };
+/// let field = info.field("intensity").unwrap();
+/// assert_eq!(field.get_attribute::<Category>(), Some(&Category("Lighting")));
+/// assert_eq!(field.get_attribute::<NumericRange>().unwrap().clamp(-1.0), 0.0);
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct Tooltip(pub &'static str);
+
+/// A custom attribute grouping fields or types under a heading, such as a foldable section of an
+/// inspector.
+///
+/// See [`Tooltip`] for an example.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct Category(pub &'static str);
+
+/// A custom attribute bounding the values of a numeric field, such as the ends of a slider.
+///
+/// The bounds are inclusive. They are stored as `f64` whatever the type of the field,
+/// so that inspectors can handle every numeric type alike.
+///
+/// See [`Tooltip`] for an example.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Debug, PartialEq)]
+pub struct NumericRange {
+    /// The smallest allowed value.
+    pub min: f64,
+    /// The largest allowed value.
+    pub max: f64,
+}
+
+impl NumericRange {
+    /// Creates a range from `min` to `max`, inclusive.
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `true` if `value` is within the range.
+    pub fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    /// Returns `value` restricted to the range.
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+}
+
+/// A custom attribute giving the increment of a numeric field, such as the amount a drag or a
+/// spin button changes it by.
+///
+/// See [`Tooltip`] for an example.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Debug, PartialEq)]
+pub struct Step(pub f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -444,4 +518,31 @@ mod tests {
         let field = info.field("value").unwrap();
         assert!(field.get_attribute::<bool>().unwrap());
     }
+
+    #[test]
+    fn should_derive_inspector_attributes() {
+        #[derive(Reflect)]
+        #[reflect(@Category("Combat"))]
+        struct Health {
+            #[reflect(@super::Tooltip("Hit points left"))]
+            #[reflect(@NumericRange::new(0.0, 100.0), @Step(0.5))]
+            value: f32,
+        }
+
+        let TypeInfo::Struct(info) = Health::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(info.get_attribute::<Category>(), Some(&Category("Combat")));
+
+        let field = info.field("value").unwrap();
+        assert_eq!(
+            field.get_attribute::<super::Tooltip>(),
+            Some(&super::Tooltip("Hit points left"))
+        );
+        assert_eq!(field.get_attribute::<Step>(), Some(&Step(0.5)));
+        let range = field.get_attribute::<NumericRange>().unwrap();
+        assert!(range.contains(100.0));
+        assert!(!range.contains(100.5));
+        assert_eq!(range.clamp(-3.0), 0.0);
+    }
 }
@@ -0,0 +1,432 @@
+use crate::{ApplyError, PartialReflect, ReflectKind, ReflectMut, ReflectRef, TypeInfo};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+
+/// The changes that turn a reflected value into another, as computed by
+/// [`PartialReflect::diff`] and applied by [`PartialReflect::apply_patch`].
+///
+/// A patch only contains what changed: the changed fields of structs, tuple structs and tuples,
+/// the changed elements of lists and the elements removed from or pushed to their end, and the
+/// changed, inserted and removed entries of maps, and the inserted and removed values of sets.
+/// Other values are replaced as a whole when they
+/// differ, as are values whose type or shape changed. This keeps patches small enough for
+/// networking delta-compression and editor undo stacks.
+///
+/// Values are compared with [`PartialReflect::reflect_partial_eq`], so values that don't support
+/// comparison are always considered changed.
+///
+/// ```
+/// # use bevy_reflect::{PartialReflect, Reflect, ReflectPatch};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+/// }
+///
+/// let mut before = Player { name: "Ferris".into(), health: 10 };
+/// let after = Player { name: "Ferris".into(), health: 3 };
+///
+/// let patch = before.diff(&after);
+/// assert!(matches!(&patch, ReflectPatch::Struct(fields) if fields.len() == 1));
+/// before.apply_patch(&patch).unwrap();
+/// assert_eq!(before, after);
+/// ```
+#[derive(Debug)]
+pub enum ReflectPatch {
+    /// The value didn't change.
+    Unchanged,
+    /// The value is replaced by another.
+    Replace(Box<dyn PartialReflect>),
+    /// Fields of a [`Struct`](crate::Struct) changed, by name.
+    Struct(Vec<(String, ReflectPatch)>),
+    /// Fields of a [`TupleStruct`](crate::TupleStruct) changed, by index.
+    TupleStruct(Vec<(usize, ReflectPatch)>),
+    /// Fields of a [`Tuple`](crate::Tuple) changed, by index.
+    Tuple(Vec<(usize, ReflectPatch)>),
+    /// A [`List`](crate::List) changed.
+    List {
+        /// The changed elements, by index.
+        changed: Vec<(usize, ReflectPatch)>,
+        /// The number of elements removed from the end, after the elements are changed.
+        removed: usize,
+        /// The elements pushed to the end, after the elements are removed.
+        pushed: Vec<Box<dyn PartialReflect>>,
+    },
+    /// A [`Map`](crate::Map) changed.
+    Map {
+        /// The changed values, by key.
+        changed: Vec<(Box<dyn PartialReflect>, ReflectPatch)>,
+        /// The inserted entries.
+        inserted: Vec<(Box<dyn PartialReflect>, Box<dyn PartialReflect>)>,
+        /// The keys of the removed entries.
+        removed: Vec<Box<dyn PartialReflect>>,
+    },
+    /// A [`Set`](crate::Set) changed.
+    Set {
+        /// The inserted values.
+        inserted: Vec<Box<dyn PartialReflect>>,
+        /// The removed values.
+        removed: Vec<Box<dyn PartialReflect>>,
+    },
+}
+
+impl ReflectPatch {
+    /// Returns `true` if this patch doesn't change anything.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, ReflectPatch::Unchanged)
+    }
+}
+
+/// Computes the patch that turns `from` into `to`.
+///
+/// See [`PartialReflect::diff`].
+pub(crate) fn diff(from: &dyn PartialReflect, to: &dyn PartialReflect) -> ReflectPatch {
+    let from_type = from.get_represented_type_info().map(TypeInfo::type_id);
+    let to_type = to.get_represented_type_info().map(TypeInfo::type_id);
+    if from_type != to_type {
+        return ReflectPatch::Replace(to.clone_value());
+    }
+
+    let patch = match (from.reflect_ref(), to.reflect_ref()) {
+        (ReflectRef::Struct(from), ReflectRef::Struct(to)) => {
+            if from.field_len() != to.field_len() {
+                return ReflectPatch::Replace(to.clone_value());
+            }
+            let mut fields = Vec::new();
+            for (index, to_field) in to.iter_fields().enumerate() {
+                let name = to.name_at(index).unwrap_or_default();
+                let Some(from_field) = from.field(name) else {
+                    return ReflectPatch::Replace(to.clone_value());
+                };
+                let patch = diff(from_field, to_field);
+                if !patch.is_unchanged() {
+                    fields.push((String::from(name), patch));
+                }
+            }
+            (!fields.is_empty()).then_some(ReflectPatch::Struct(fields))
+        }
+        (ReflectRef::TupleStruct(from), ReflectRef::TupleStruct(to)) => {
+            if from.field_len() != to.field_len() {
+                return ReflectPatch::Replace(to.clone_value());
+            }
+            let fields = diff_fields(from.iter_fields().zip(to.iter_fields()));
+            (!fields.is_empty()).then_some(ReflectPatch::TupleStruct(fields))
+        }
+        (ReflectRef::Tuple(from), ReflectRef::Tuple(to)) => {
+            if from.field_len() != to.field_len() {
+                return ReflectPatch::Replace(to.clone_value());
+            }
+            let fields = diff_fields(from.iter_fields().zip(to.iter_fields()));
+            (!fields.is_empty()).then_some(ReflectPatch::Tuple(fields))
+        }
+        (ReflectRef::List(from), ReflectRef::List(to)) => {
+            let changed = diff_fields(from.iter().zip(to.iter()));
+            let removed = from.len().saturating_sub(to.len());
+            let pushed: Vec<_> = to
+                .iter()
+                .skip(from.len())
+                .map(PartialReflect::clone_value)
+                .collect();
+            (!changed.is_empty() || removed > 0 || !pushed.is_empty()).then_some(
+                ReflectPatch::List {
+                    changed,
+                    removed,
+                    pushed,
+                },
+            )
+        }
+        (ReflectRef::Map(from), ReflectRef::Map(to)) => {
+            let mut changed = Vec::new();
+            let mut inserted = Vec::new();
+            for (key, to_value) in to.iter() {
+                match from.get(key) {
+                    Some(from_value) => {
+                        let patch = diff(from_value, to_value);
+                        if !patch.is_unchanged() {
+                            changed.push((key.clone_value(), patch));
+                        }
+                    }
+                    None => inserted.push((key.clone_value(), to_value.clone_value())),
+                }
+            }
+            let removed: Vec<_> = from
+                .iter()
+                .filter(|(key, _)| to.get(*key).is_none())
+                .map(|(key, _)| key.clone_value())
+                .collect();
+            (!changed.is_empty() || !inserted.is_empty() || !removed.is_empty()).then_some(
+                ReflectPatch::Map {
+                    changed,
+                    inserted,
+                    removed,
+                },
+            )
+        }
+        (ReflectRef::Set(from), ReflectRef::Set(to)) => {
+            let inserted: Vec<_> = to
+                .iter()
+                .filter(|value| !from.contains(*value))
+                .map(PartialReflect::clone_value)
+                .collect();
+            let removed: Vec<_> = from
+                .iter()
+                .filter(|value| !to.contains(*value))
+                .map(PartialReflect::clone_value)
+                .collect();
+            (!inserted.is_empty() || !removed.is_empty())
+                .then_some(ReflectPatch::Set { inserted, removed })
+        }
+        _ => {
+            if from.reflect_partial_eq(to) == Some(true) {
+                None
+            } else {
+                return ReflectPatch::Replace(to.clone_value());
+            }
+        }
+    };
+    patch.unwrap_or(ReflectPatch::Unchanged)
+}
+
+/// Computes the patches of the changed fields or elements, by index.
+fn diff_fields<'a>(
+    fields: impl Iterator<Item = (&'a dyn PartialReflect, &'a dyn PartialReflect)>,
+) -> Vec<(usize, ReflectPatch)> {
+    fields
+        .map(|(from, to)| diff(from, to))
+        .enumerate()
+        .filter(|(_, patch)| !patch.is_unchanged())
+        .collect()
+}
+
+/// Applies `patch` to `value`.
+///
+/// See [`PartialReflect::apply_patch`].
+pub(crate) fn apply_patch(
+    value: &mut dyn PartialReflect,
+    patch: &ReflectPatch,
+) -> Result<(), ApplyError> {
+    match patch {
+        ReflectPatch::Unchanged => return Ok(()),
+        ReflectPatch::Replace(replacement) => return value.try_apply(replacement.as_ref()),
+        _ => {}
+    }
+
+    let kind = value.reflect_kind();
+    match (value.reflect_mut(), patch) {
+        (ReflectMut::Struct(value), ReflectPatch::Struct(fields)) => {
+            for (name, patch) in fields {
+                let field = value
+                    .field_mut(name)
+                    .ok_or_else(|| missing(format!("field `{name}`")))?;
+                apply_patch(field, patch)?;
+            }
+        }
+        (ReflectMut::TupleStruct(value), ReflectPatch::TupleStruct(fields)) => {
+            for (index, patch) in fields {
+                let field = value
+                    .field_mut(*index)
+                    .ok_or_else(|| missing(format!("field {index}")))?;
+                apply_patch(field, patch)?;
+            }
+        }
+        (ReflectMut::Tuple(value), ReflectPatch::Tuple(fields)) => {
+            for (index, patch) in fields {
+                let field = value
+                    .field_mut(*index)
+                    .ok_or_else(|| missing(format!("field {index}")))?;
+                apply_patch(field, patch)?;
+            }
+        }
+        (
+            ReflectMut::List(value),
+            ReflectPatch::List {
+                changed,
+                removed,
+                pushed,
+            },
+        ) => {
+            for (index, patch) in changed {
+                let element = value
+                    .get_mut(*index)
+                    .ok_or_else(|| missing(format!("element {index}")))?;
+                apply_patch(element, patch)?;
+            }
+            for _ in 0..*removed {
+                value
+                    .pop()
+                    .ok_or_else(|| missing(String::from("element to remove")))?;
+            }
+            for element in pushed {
+                value.push(element.clone_value());
+            }
+        }
+        (
+            ReflectMut::Map(value),
+            ReflectPatch::Map {
+                changed,
+                inserted,
+                removed,
+            },
+        ) => {
+            for (key, patch) in changed {
+                let entry = value
+                    .get_mut(key.as_ref())
+                    .ok_or_else(|| missing(format!("key {key:?}")))?;
+                apply_patch(entry, patch)?;
+            }
+            for key in removed {
+                value
+                    .remove(key.as_ref())
+                    .ok_or_else(|| missing(format!("key {key:?}")))?;
+            }
+            for (key, entry) in inserted {
+                value.insert_boxed(key.clone_value(), entry.clone_value());
+            }
+        }
+        (ReflectMut::Set(value), ReflectPatch::Set { inserted, removed }) => {
+            for element in removed {
+                if !value.remove(element.as_ref()) {
+                    return Err(missing(format!("value {element:?}")));
+                }
+            }
+            for element in inserted {
+                value.insert_boxed(element.clone_value());
+            }
+        }
+        (_, patch) => {
+            return Err(ApplyError::MismatchedKinds {
+                from_kind: patch_kind(patch),
+                to_kind: kind,
+            })
+        }
+    }
+    Ok(())
+}
+
+fn missing(target: String) -> ApplyError {
+    ApplyError::MissingPatchTarget {
+        target: target.into_boxed_str(),
+    }
+}
+
+fn patch_kind(patch: &ReflectPatch) -> ReflectKind {
+    match patch {
+        ReflectPatch::Struct(_) => ReflectKind::Struct,
+        ReflectPatch::TupleStruct(_) => ReflectKind::TupleStruct,
+        ReflectPatch::Tuple(_) => ReflectKind::Tuple,
+        ReflectPatch::List { .. } => ReflectKind::List,
+        ReflectPatch::Map { .. } => ReflectKind::Map,
+        ReflectPatch::Set { .. } => ReflectKind::Set,
+        ReflectPatch::Unchanged | ReflectPatch::Replace(_) => {
+            unreachable!("patches that don't depend on the kind are applied first")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_reflect, PartialReflect, Reflect, ReflectPatch};
+    use alloc::{string::String, vec, vec::Vec};
+    use bevy_utils::{HashMap, HashSet};
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Stats {
+        health: u32,
+        speed: f32,
+    }
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Player {
+        name: String,
+        stats: Stats,
+        inventory: Vec<u32>,
+        flags: HashMap<String, bool>,
+        tags: HashSet<String>,
+    }
+
+    fn player() -> Player {
+        let mut flags = HashMap::default();
+        flags.insert(String::from("visible"), true);
+        flags.insert(String::from("invincible"), false);
+        let mut tags = HashSet::default();
+        tags.insert(String::from("hero"));
+        tags.insert(String::from("flying"));
+        Player {
+            name: String::from("Ferris"),
+            stats: Stats {
+                health: 10,
+                speed: 1.0,
+            },
+            inventory: vec![1, 2, 3],
+            flags,
+            tags,
+        }
+    }
+
+    #[test]
+    fn diff_and_apply_patch() {
+        let before = player();
+        assert!(before.diff(&before.clone()).is_unchanged());
+
+        let mut after = player();
+        after.stats.health = 3;
+        after.inventory = vec![1, 5];
+        after.flags.remove("invincible");
+        after.flags.insert(String::from("visible"), false);
+        after.flags.insert(String::from("stunned"), true);
+        after.tags.remove("flying");
+        after.tags.insert(String::from("swimming"));
+
+        let patch = before.diff(&after);
+        let ReflectPatch::Struct(fields) = &patch else {
+            panic!("expected a struct patch, got {patch:?}");
+        };
+        let names: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["stats", "inventory", "flags", "tags"]);
+        assert!(matches!(
+            &fields[0].1,
+            ReflectPatch::Struct(stats) if stats.len() == 1 && stats[0].0 == "health"
+        ));
+        assert!(matches!(
+            &fields[1].1,
+            ReflectPatch::List { changed, removed: 1, pushed } if changed.len() == 1 && pushed.is_empty()
+        ));
+        assert!(matches!(
+            &fields[2].1,
+            ReflectPatch::Map { changed, inserted, removed }
+                if changed.len() == 1 && inserted.len() == 1 && removed.len() == 1
+        ));
+        assert!(matches!(
+            &fields[3].1,
+            ReflectPatch::Set { inserted, removed } if inserted.len() == 1 && removed.len() == 1
+        ));
+
+        let mut patched = before.clone();
+        patched.apply_patch(&patch).unwrap();
+        assert_eq!(patched, after);
+
+        // Growing a list pushes elements.
+        let mut grown = after.clone();
+        grown.inventory.extend([7, 8]);
+        let mut patched = after.clone();
+        patched.apply_patch(&after.diff(&grown)).unwrap();
+        assert_eq!(patched, grown);
+
+        // Removing the values of a set removes them from the patched set.
+        let mut shrunk = after.clone();
+        shrunk.tags.clear();
+        let mut patched = after.clone();
+        patched.apply_patch(&after.diff(&shrunk)).unwrap();
+        assert_eq!(patched, shrunk);
+    }
+
+    #[test]
+    fn apply_patch_to_mismatched_value() {
+        let before = player();
+        let mut after = player();
+        after.name = String::from("Corro");
+        let patch = before.diff(&after);
+
+        let mut stats = before.stats.clone();
+        assert!(stats.apply_patch(&patch).is_err());
+    }
+}
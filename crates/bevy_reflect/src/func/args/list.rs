@@ -8,6 +8,7 @@ use crate::{
 use alloc::{
     boxed::Box,
     collections::vec_deque::{Iter, VecDeque},
+    vec::Vec,
 };
 
 /// A list of arguments that can be passed to a [`DynamicFunction`] or [`DynamicFunctionMut`].
@@ -302,10 +303,23 @@ impl<'a> ArgList<'a> {
     }
 }
 
+impl<'a> FromIterator<ArgValue<'a>> for ArgList<'a> {
+    fn from_iter<I: IntoIterator<Item = ArgValue<'a>>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::push_arg)
+    }
+}
+
+/// Creates a list of owned arguments, such as the values received from a script runtime.
+impl From<Vec<Box<dyn PartialReflect>>> for ArgList<'_> {
+    fn from(args: Vec<Box<dyn PartialReflect>>) -> Self {
+        args.into_iter().map(ArgValue::Owned).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::string::String;
+    use alloc::{string::String, vec};
 
     #[test]
     fn should_push_arguments_in_order() {
@@ -415,4 +429,14 @@ mod tests {
         assert!(args.list[2].value().reflect_partial_eq(&123).unwrap());
         assert_eq!(args.list[2].index(), 2);
     }
+
+    #[test]
+    fn should_create_list_of_owned_arguments() {
+        let values: Vec<Box<dyn PartialReflect>> = vec![Box::new(123_i32), Box::new(456_i32)];
+        let mut args = ArgList::from(values);
+
+        assert_eq!(args.len(), 2);
+        assert_eq!(args.take_owned::<i32>().unwrap(), 123);
+        assert_eq!(args.take_owned::<i32>().unwrap(), 456);
+    }
 }
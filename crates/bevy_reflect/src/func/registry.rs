@@ -1,11 +1,12 @@
-use alloc::{borrow::Cow, sync::Arc};
+use alloc::{borrow::Cow, format, sync::Arc};
 use core::fmt::Debug;
 use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use bevy_utils::HashMap;
 
-use crate::func::{
-    ArgList, DynamicFunction, FunctionRegistrationError, FunctionResult, IntoFunction,
+use crate::{
+    func::{ArgList, DynamicFunction, FunctionRegistrationError, FunctionResult, IntoFunction},
+    TypePath,
 };
 
 /// A registry of [reflected functions].
@@ -284,6 +285,53 @@ impl FunctionRegistry {
         }
     }
 
+    /// Attempts to register the given function as a method of `T` with the given name.
+    ///
+    /// A method is a function whose first argument is its receiver, a `T` taken by value,
+    /// reference, or mutable reference.
+    /// It is registered under the name `"<type path of T>::<name>"`,
+    /// so that it can be looked up with [`get_method`] and called with [`call_method`].
+    ///
+    /// If a registered function with the same name already exists,
+    /// it will not be registered again and an error will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_reflect::{func::{ArgList, FunctionRegistry}, Reflect};
+    /// #[derive(Reflect)]
+    /// struct Health(f32);
+    ///
+    /// impl Health {
+    ///     fn damage(&mut self, amount: f32) -> f32 {
+    ///         self.0 -= amount;
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let mut registry = FunctionRegistry::default();
+    /// registry.register_method::<Health, _, _>("damage", Health::damage).unwrap();
+    ///
+    /// let mut health = Health(100.0);
+    /// let args = ArgList::new().push_mut(&mut health).push_owned(25.0_f32);
+    /// let value = registry.call_method("damage", args).unwrap().unwrap().unwrap_owned();
+    /// assert_eq!(value.try_take::<f32>().unwrap(), 75.0);
+    /// ```
+    ///
+    /// [`get_method`]: Self::get_method
+    /// [`call_method`]: Self::call_method
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: &str,
+        function: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        T: TypePath,
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        self.register_with_name(method_name(T::type_path(), name), function)
+    }
+
     /// Calls the function with the given [name] and [args].
     ///
     /// Returns `None` if no function with the given name is registered.
@@ -303,6 +351,31 @@ impl FunctionRegistry {
         self.functions.get(name)
     }
 
+    /// Calls the method with the given name of the type of the receiver, the first of the [args].
+    ///
+    /// The receiver may also be a dynamic value, in which case the method of the type it
+    /// represents is called.
+    ///
+    /// Returns `None` if there are no arguments, or if no such method is registered.
+    /// Otherwise, returns the result of the method call.
+    ///
+    /// See [`register_method`] for an example.
+    ///
+    /// [args]: ArgList
+    /// [`register_method`]: Self::register_method
+    pub fn call_method<'a>(&self, name: &str, args: ArgList<'a>) -> Option<FunctionResult<'a>> {
+        let receiver = args.iter().next()?.value().get_represented_type_info()?;
+        let func = self.get_method(receiver.type_path(), name)?;
+        Some(func.call(args))
+    }
+
+    /// Get a reference to the method with the given name of the type with the given [type path].
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_method(&self, type_path: &str, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.get(&method_name(type_path, name))
+    }
+
     /// Returns `true` if a function with the given [name] is registered.
     ///
     /// [name]: DynamicFunction::name
@@ -326,6 +399,11 @@ impl FunctionRegistry {
     }
 }
 
+/// Returns the name a method is registered under in a [`FunctionRegistry`].
+fn method_name(type_path: &str, name: &str) -> Cow<'static, str> {
+    Cow::Owned(format!("{type_path}::{name}"))
+}
+
 impl Debug for FunctionRegistry {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_set().entries(self.functions.values()).finish()
@@ -510,6 +588,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn should_register_and_call_method() {
+        use crate::{self as bevy_reflect, Reflect};
+
+        #[derive(Reflect)]
+        struct Counter(i32);
+
+        impl Counter {
+            fn add(&mut self, amount: i32) -> i32 {
+                self.0 += amount;
+                self.0
+            }
+        }
+
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register_method::<Counter, _, _>("add", Counter::add)
+            .unwrap();
+        assert!(registry.contains(&format!("{}::add", Counter::type_path())));
+        assert!(registry.get_method(Counter::type_path(), "add").is_some());
+
+        let mut counter = Counter(1);
+        let args = ArgList::new().push_mut(&mut counter).push_owned(2_i32);
+        let value = registry
+            .call_method("add", args)
+            .unwrap()
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(value.try_downcast_ref::<i32>(), Some(&3));
+        assert_eq!(counter.0, 3);
+
+        let args = ArgList::new().push_owned(2_i32);
+        assert!(registry.call_method("add", args).is_none());
+    }
+
     #[test]
     fn should_debug_function_registry() {
         fn foo() -> i32 {
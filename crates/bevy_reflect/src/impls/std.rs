@@ -1296,6 +1296,11 @@ where
         registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
         registration
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<K>();
+        registry.register::<V>();
+    }
 }
 
 impl<K, V> FromReflect for ::alloc::collections::BTreeMap<K, V>
@@ -570,6 +570,7 @@ extern crate std;
 extern crate alloc;
 
 mod array;
+mod diff;
 mod fields;
 mod from_reflect;
 #[cfg(feature = "functions")]
@@ -634,6 +635,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -2827,6 +2829,52 @@ bevy_reflect::tests::Test {
         );
     }
 
+    #[test]
+    fn should_register_and_round_trip_remote_type() {
+        mod external_crate {
+            #[derive(Debug, PartialEq)]
+            pub struct TheirType {
+                pub value: u32,
+            }
+        }
+
+        #[reflect_remote(external_crate::TheirType)]
+        struct MyType {
+            pub value: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register_remote::<MyType>();
+
+        let registration = registry
+            .get_remote(TypeId::of::<external_crate::TheirType>())
+            .unwrap();
+        assert_eq!(registration.type_id(), TypeId::of::<MyType>());
+        let wrapper = registration.data::<ReflectRemoteWrapper>().unwrap();
+        assert_eq!(
+            wrapper.remote_type_id(),
+            TypeId::of::<external_crate::TheirType>()
+        );
+        assert!(wrapper.as_reflect(&123_u32).is_none());
+
+        let value = external_crate::TheirType { value: 123 };
+        let reflected = wrapper.as_reflect(&value).unwrap();
+        let serializer = ReflectSerializer::new(reflected.as_partial_reflect(), &registry);
+        let serialized = ron::to_string(&serializer).unwrap();
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = registration
+            .data::<ReflectFromReflect>()
+            .unwrap()
+            .from_reflect(deserialized.as_partial_reflect())
+            .unwrap();
+        let remote = wrapper.into_remote(deserialized).unwrap();
+        assert_eq!(remote.downcast_ref(), Some(&value));
+    }
+
     #[test]
     fn should_reflect_nested_remote_type() {
         mod external_crate {
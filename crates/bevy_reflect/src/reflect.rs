@@ -1,7 +1,8 @@
 use crate::{
-    array_debug, enum_debug, list_debug, map_debug, set_debug, struct_debug, tuple_debug,
+    array_debug, diff, enum_debug, list_debug, map_debug, set_debug, struct_debug, tuple_debug,
     tuple_struct_debug, DynamicTypePath, DynamicTyped, OpaqueInfo, ReflectKind,
-    ReflectKindMismatchError, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, Typed,
+    ReflectKindMismatchError, ReflectMut, ReflectOwned, ReflectPatch, ReflectRef, TypeInfo,
+    TypePath, Typed,
 };
 use alloc::boxed::Box;
 use core::{
@@ -47,6 +48,10 @@ pub enum ApplyError {
         enum_name: Box<str>,
         variant_name: Box<str>,
     },
+
+    #[error("the patched value has no {target}")]
+    /// A [`ReflectPatch`] changed a field, element or entry that the patched value doesn't have.
+    MissingPatchTarget { target: Box<str> },
 }
 
 impl From<ReflectKindMismatchError> for ApplyError {
@@ -270,6 +275,24 @@ where
         }
     }
 
+    /// Returns the [`ReflectPatch`] that turns this value into `value`.
+    ///
+    /// Only the fields, elements and entries that differ are part of the patch: see
+    /// [`ReflectPatch`].
+    fn diff(&self, value: &dyn PartialReflect) -> ReflectPatch {
+        diff(self.as_partial_reflect(), value)
+    }
+
+    /// Applies a [`ReflectPatch`] computed by [`diff`](PartialReflect::diff) to this value.
+    ///
+    /// # Handling Errors
+    ///
+    /// This function may leave `self` in a partially mutated state if a error was encountered on the way.
+    /// consider maintaining a cloned instance of this data you can switch to if a error is encountered.
+    fn apply_patch(&mut self, patch: &ReflectPatch) -> Result<(), ApplyError> {
+        diff::apply_patch(self.as_partial_reflect_mut(), patch)
+    }
+
     /// Indicates whether or not this type is a _dynamic_ type.
     ///
     /// Dynamic types include the ones built-in to this [crate],
@@ -1,4 +1,6 @@
-use crate::Reflect;
+use crate::{FromType, Reflect};
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
 
 /// Marks a type as a [reflectable] wrapper for a remote type.
 ///
@@ -62,3 +64,65 @@ pub trait ReflectRemote: Reflect {
     /// Converts the remote type into this wrapper.
     fn into_wrapper(remote: Self::Remote) -> Self;
 }
+
+/// Type data for converting between a remote type and its [`ReflectRemote`] wrapper at runtime.
+///
+/// This lets a remote value be reflected, and so serialized or edited, when only its [`TypeId`]
+/// is known. It is inserted by [`TypeRegistry::register_remote`], which also makes the
+/// registration of the wrapper available from the remote type with [`TypeRegistry::get_remote`].
+///
+/// [`TypeRegistry::register_remote`]: crate::TypeRegistry::register_remote
+/// [`TypeRegistry::get_remote`]: crate::TypeRegistry::get_remote
+#[derive(Clone)]
+pub struct ReflectRemoteWrapper {
+    remote_type_id: TypeId,
+    as_reflect: fn(&dyn Any) -> Option<&dyn Reflect>,
+    as_reflect_mut: fn(&mut dyn Any) -> Option<&mut dyn Reflect>,
+    into_remote: fn(Box<dyn Reflect>) -> Result<Box<dyn Any>, Box<dyn Reflect>>,
+}
+
+impl ReflectRemoteWrapper {
+    /// The [`TypeId`] of the remote type.
+    pub fn remote_type_id(&self) -> TypeId {
+        self.remote_type_id
+    }
+
+    /// Returns the remote value as a reflected wrapper, or `None` if it's not of the remote type.
+    pub fn as_reflect<'a>(&self, remote: &'a dyn Any) -> Option<&'a dyn Reflect> {
+        (self.as_reflect)(remote)
+    }
+
+    /// Returns the remote value as a mutable reflected wrapper, or `None` if it's not of the
+    /// remote type.
+    pub fn as_reflect_mut<'a>(&self, remote: &'a mut dyn Any) -> Option<&'a mut dyn Reflect> {
+        (self.as_reflect_mut)(remote)
+    }
+
+    /// Converts a boxed wrapper into its boxed remote value.
+    ///
+    /// Returns the wrapper back if it's not of the wrapper type.
+    pub fn into_remote(&self, wrapper: Box<dyn Reflect>) -> Result<Box<dyn Any>, Box<dyn Reflect>> {
+        (self.into_remote)(wrapper)
+    }
+}
+
+impl<W: ReflectRemote> FromType<W> for ReflectRemoteWrapper
+where
+    W::Remote: Any,
+{
+    fn from_type() -> Self {
+        Self {
+            remote_type_id: TypeId::of::<W::Remote>(),
+            as_reflect: |remote| {
+                let remote = remote.downcast_ref::<W::Remote>()?;
+                Some(W::as_wrapper(remote))
+            },
+            as_reflect_mut: |remote| {
+                let remote = remote.downcast_mut::<W::Remote>()?;
+                Some(W::as_wrapper_mut(remote))
+            },
+            // Reflected remote wrappers downcast to the remote type itself.
+            into_remote: |wrapper| Ok(wrapper.downcast::<W::Remote>()?),
+        }
+    }
+}
@@ -1,12 +1,13 @@
 #[cfg(feature = "debug_stack")]
 use crate::serde::de::error_utils::TYPE_INFO_STACK;
-use crate::serde::{ReflectDeserializeWithRegistry, SerializationData};
+use crate::serde::{ReflectDeserializeWithRegistry, ReflectMigrate, SerializationData};
 use crate::{
     serde::{
         de::{
             arrays::ArrayVisitor, enums::EnumVisitor, error_utils::make_custom_error,
-            lists::ListVisitor, maps::MapVisitor, options::OptionVisitor, sets::SetVisitor,
-            structs::StructVisitor, tuple_structs::TupleStructVisitor, tuples::TupleVisitor,
+            lists::ListVisitor, maps::MapVisitor, migrate::MigrateVisitor, options::OptionVisitor,
+            sets::SetVisitor, structs::StructVisitor, tuple_structs::TupleStructVisitor,
+            tuples::TupleVisitor,
         },
         TypeRegistrationDeserializer,
     },
@@ -273,6 +274,8 @@ pub struct TypedReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    /// Whether the version tag of a type with [`ReflectMigrate`] was already read.
+    pub(super) skip_migrate: bool,
 }
 
 impl<'a> TypedReflectDeserializer<'a, ()> {
@@ -290,6 +293,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            skip_migrate: false,
         }
     }
 
@@ -308,6 +312,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            skip_migrate: false,
         }
     }
 }
@@ -331,6 +336,7 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor: Some(processor),
+            skip_migrate: false,
         }
     }
 
@@ -344,6 +350,7 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor,
+            skip_migrate: false,
         }
     }
 }
@@ -376,6 +383,23 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
 
             let type_path = self.registration.type_info().type_path();
 
+            // Types with `ReflectMigrate` are tagged with the version they were serialized with
+            if let Some(migrate) = self
+                .registration
+                .data::<ReflectMigrate>()
+                .filter(|_| !self.skip_migrate)
+            {
+                return deserializer.deserialize_tuple(
+                    2,
+                    MigrateVisitor {
+                        migrate,
+                        registration: self.registration,
+                        registry: self.registry,
+                        processor: self.processor,
+                    },
+                );
+            }
+
             // Handle both Value case and types that have a custom `ReflectDeserialize`
             if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
                 let value = deserialize_reflect.deserialize(deserializer)?;
@@ -0,0 +1,73 @@
+use crate::{
+    serde::{de::error_utils::make_custom_error, ReflectMigrate, TypedReflectDeserializer},
+    PartialReflect, TypeRegistration, TypeRegistry,
+};
+use alloc::boxed::Box;
+use core::{fmt, fmt::Formatter};
+use serde::de::{SeqAccess, Visitor};
+
+use super::ReflectDeserializerProcessor;
+
+/// A [`Visitor`] for deserializing the versioned data of a type with [`ReflectMigrate`].
+pub(super) struct MigrateVisitor<'a, P> {
+    pub migrate: &'a ReflectMigrate,
+    pub registration: &'a TypeRegistration,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for MigrateVisitor<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("versioned reflected value of type ")?;
+        formatter.write_str(self.registration.type_info().type_path())
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let type_path = self.registration.type_info().type_path();
+        let version: u32 = seq
+            .next_element()?
+            .ok_or_else(|| make_custom_error(format_args!("missing version of `{type_path}`")))?;
+
+        if version == self.migrate.version() {
+            let mut de = TypedReflectDeserializer::new_internal(
+                self.registration,
+                self.registry,
+                self.processor,
+            );
+            de.skip_migrate = true;
+            return seq
+                .next_element_seed(de)?
+                .ok_or_else(|| make_custom_error(format_args!("missing value of `{type_path}`")));
+        }
+
+        let schema = self.migrate.schema_type(version).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "no migration of `{type_path}` from version {version} to version {}",
+                self.migrate.version()
+            ))
+        })?;
+        let schema_registration = self.registry.get(schema).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "no registration found for version {version} of `{type_path}`"
+            ))
+        })?;
+        let de = TypedReflectDeserializer::new_internal(
+            schema_registration,
+            self.registry,
+            self.processor,
+        );
+        let value = seq
+            .next_element_seed(de)?
+            .ok_or_else(|| make_custom_error(format_args!("missing value of `{type_path}`")))?;
+        self.migrate.migrate(version, &*value).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "failed to migrate `{type_path}` from version {version}"
+            ))
+        })
+    }
+}
@@ -11,6 +11,7 @@ mod error_utils;
 mod helpers;
 mod lists;
 mod maps;
+mod migrate;
 mod options;
 mod processor;
 mod registration_utils;
@@ -183,6 +183,74 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn should_migrate_versioned_data() {
+        #[derive(Reflect)]
+        struct HealthV0(u8);
+
+        #[derive(Reflect)]
+        struct HealthV1(f32);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Health {
+            current: f32,
+            max: f32,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Player {
+            health: Health,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        ReflectMigrate::new(2)
+            .with_migration(0, |old: HealthV0| HealthV1(f32::from(old.0)))
+            .with_migration(1, |old: HealthV1| Health {
+                current: old.0,
+                max: 100.0,
+            })
+            .register::<Health>(&mut registry);
+
+        let player = Player {
+            health: Health {
+                current: 10.0,
+                max: 20.0,
+            },
+        };
+        let serializer = TypedReflectSerializer::new(&player, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(serialized, "(health:(2,(current:10.0,max:20.0)))");
+
+        let deserialize = |input: &str| {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            TypedReflectDeserializer::of::<Player>(&registry)
+                .deserialize(&mut deserializer)
+                .map(|value| Player::from_reflect(&*value).unwrap())
+        };
+        assert_eq!(deserialize(&serialized).unwrap(), player);
+        assert_eq!(
+            deserialize("(health:(1,(50.0)))").unwrap(),
+            Player {
+                health: Health {
+                    current: 50.0,
+                    max: 100.0,
+                },
+            }
+        );
+        // Version 0 is migrated to version 1, and then to the current version.
+        assert_eq!(
+            deserialize("(health:(0,(25)))").unwrap(),
+            Player {
+                health: Health {
+                    current: 25.0,
+                    max: 100.0,
+                },
+            }
+        );
+        assert!(deserialize("(health:(3,(50.0)))").is_err());
+    }
+
     mod type_data {
         use super::*;
         use crate::from_reflect::FromReflect;
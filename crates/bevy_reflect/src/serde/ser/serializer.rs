@@ -1,11 +1,14 @@
 #[cfg(feature = "debug_stack")]
 use crate::serde::ser::error_utils::TYPE_INFO_STACK;
 use crate::{
-    serde::ser::{
-        arrays::ArraySerializer, custom_serialization::try_custom_serialize, enums::EnumSerializer,
-        error_utils::make_custom_error, lists::ListSerializer, maps::MapSerializer,
-        sets::SetSerializer, structs::StructSerializer, tuple_structs::TupleStructSerializer,
-        tuples::TupleSerializer,
+    serde::{
+        ser::{
+            arrays::ArraySerializer, custom_serialization::try_custom_serialize,
+            enums::EnumSerializer, error_utils::make_custom_error, lists::ListSerializer,
+            maps::MapSerializer, sets::SetSerializer, structs::StructSerializer,
+            tuple_structs::TupleStructSerializer, tuples::TupleSerializer,
+        },
+        ReflectMigrate,
     },
     PartialReflect, ReflectRef, TypeRegistry,
 };
@@ -172,6 +175,8 @@ pub struct TypedReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    /// Whether the version tag of a type with [`ReflectMigrate`] was already written.
+    skip_migrate: bool,
 }
 
 impl<'a> TypedReflectSerializer<'a, ()> {
@@ -189,6 +194,7 @@ impl<'a> TypedReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            skip_migrate: false,
         }
     }
 }
@@ -212,6 +218,7 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            skip_migrate: false,
         }
     }
 
@@ -225,6 +232,7 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
             value,
             registry,
             processor,
+            skip_migrate: false,
         }
     }
 }
@@ -257,6 +265,28 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
             serializer
         };
 
+        // Tag types that have `ReflectMigrate` with their current version
+        if let Some(migrate) = self
+            .value
+            .get_represented_type_info()
+            .and_then(|info| {
+                self.registry
+                    .get_type_data::<ReflectMigrate>(info.type_id())
+            })
+            .filter(|_| !self.skip_migrate)
+        {
+            let untagged = TypedReflectSerializer {
+                skip_migrate: true,
+                ..*self
+            };
+            let output = (migrate.version(), untagged).serialize(serializer);
+
+            #[cfg(feature = "debug_stack")]
+            TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
+
+            return output;
+        }
+
         // Handle both Value case and types that have a custom `Serialize`
         let (serializer, error) = match try_custom_serialize(self.value, self.registry, serializer)
         {
@@ -1,6 +1,7 @@
-use crate::Reflect;
-use alloc::boxed::Box;
+use crate::{FromReflect, GetTypeRegistration, PartialReflect, Reflect, TypeRegistry};
+use alloc::{boxed::Box, sync::Arc};
 use bevy_utils::{hashbrown::hash_map::Iter, HashMap};
+use core::any::TypeId;
 
 /// Contains data relevant to the automatic reflect powered (de)serialization of a type.
 #[derive(Debug, Clone)]
@@ -134,3 +135,177 @@ impl SkippedField {
         (self.default_fn)()
     }
 }
+
+type MigrateFn = dyn Fn(&dyn PartialReflect) -> Option<Box<dyn PartialReflect>> + Send + Sync;
+
+/// Type data tagging the serialized data of a type with a version, along with migrations that
+/// turn data serialized with an older version of the type into the current one.
+///
+/// [`TypedReflectSerializer`] serializes a type with this type data as a tuple of its current
+/// version and its value.
+/// [`TypedReflectDeserializer`] reads the version first: data of the current version is
+/// deserialized as usual, while data of an older version is deserialized as the type given to
+/// [`with_migration`] for that version, and then converted by the migration.
+/// This keeps saved data loading after the layout of the type changes.
+///
+/// Migrations can be chained: a migration may return the type read for a later version, which is
+/// then migrated from that version in turn, so each new version only needs a migration from the
+/// previous one.
+///
+/// As the version is part of the serialized data, this should be registered from the first
+/// version of a type whose layout may change.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{serde::{ReflectMigrate, TypedReflectDeserializer}, FromReflect, Reflect, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// // The layout `Health` had in version 1, kept around to read old data.
+/// #[derive(Reflect)]
+/// struct HealthV1(f32);
+///
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// ReflectMigrate::new(2)
+///     .with_migration(1, |old: HealthV1| Health {
+///         current: old.0,
+///         max: 100.0,
+///     })
+///     .register::<Health>(&mut registry);
+///
+/// let mut deserializer = ron::Deserializer::from_str("(1, (50.0))").unwrap();
+/// let value = TypedReflectDeserializer::of::<Health>(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// let health = Health::from_reflect(&*value).unwrap();
+/// assert_eq!(health, Health { current: 50.0, max: 100.0 });
+/// ```
+///
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [`with_migration`]: Self::with_migration
+#[derive(Clone)]
+pub struct ReflectMigrate {
+    version: u32,
+    migrations: HashMap<u32, Migration>,
+}
+
+#[derive(Clone)]
+struct Migration {
+    schema: TypeId,
+    /// The type returned by the migration.
+    target: TypeId,
+    register_schema: fn(&mut TypeRegistry),
+    migrate: Arc<MigrateFn>,
+}
+
+impl ReflectMigrate {
+    /// Creates a [`ReflectMigrate`] for the given current `version`, without any migrations.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: HashMap::default(),
+        }
+    }
+
+    /// Returns the current version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Adds a migration from data serialized with version `from_version`, which is deserialized
+    /// as an `Old`.
+    ///
+    /// `New` is either the type itself, or the `Old` type of a later version, whose migration is
+    /// then applied to the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from_version` isn't older than the current version.
+    pub fn with_migration<Old, New>(
+        mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> Self
+    where
+        Old: FromReflect + GetTypeRegistration,
+        New: Reflect,
+    {
+        assert!(
+            from_version < self.version,
+            "cannot migrate from version {from_version} to older version {}",
+            self.version
+        );
+        self.migrations.insert(
+            from_version,
+            Migration {
+                schema: TypeId::of::<Old>(),
+                target: TypeId::of::<New>(),
+                register_schema: TypeRegistry::register::<Old>,
+                migrate: Arc::new(
+                    move |value: &dyn PartialReflect| -> Option<Box<dyn PartialReflect>> {
+                        let old = Old::from_reflect(value)?;
+                        Some(Box::new(migrate(old)))
+                    },
+                ),
+            },
+        );
+        self
+    }
+
+    /// Returns the type data serialized with `from_version` is deserialized as, if it can be
+    /// migrated.
+    pub fn schema_type(&self, from_version: u32) -> Option<TypeId> {
+        self.migrations
+            .get(&from_version)
+            .map(|migration| migration.schema)
+    }
+
+    /// Migrates `value`, deserialized as the [`schema_type`](Self::schema_type) of
+    /// `from_version`, to the current version of the type, chaining the migrations of the later
+    /// versions as needed.
+    ///
+    /// Returns `None` if there is no migration from `from_version`, or `value` isn't of its
+    /// schema type.
+    pub fn migrate(
+        &self,
+        from_version: u32,
+        value: &dyn PartialReflect,
+    ) -> Option<Box<dyn PartialReflect>> {
+        let mut migration = self.migrations.get(&from_version)?;
+        let mut value = (migration.migrate)(value)?;
+        // Versions only go up, so this ends.
+        let mut version = from_version;
+        while let Some((&next_version, next)) = self
+            .migrations
+            .iter()
+            .filter(|(&next_version, next)| {
+                next_version > version && next.schema == migration.target
+            })
+            .min_by_key(|(&next_version, _)| next_version)
+        {
+            value = (next.migrate)(value.as_partial_reflect())?;
+            version = next_version;
+            migration = next;
+        }
+        Some(value)
+    }
+
+    /// Registers `T` and the schema types of all migrations in `registry`, and inserts this type
+    /// data for `T`.
+    pub fn register<T: GetTypeRegistration>(self, registry: &mut TypeRegistry) {
+        registry.register::<T>();
+        for migration in self.migrations.values() {
+            (migration.register_schema)(registry);
+        }
+        registry
+            .get_mut(TypeId::of::<T>())
+            .expect("type was just registered")
+            .insert(self);
+    }
+}
@@ -1,10 +1,13 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::Serializable, FromReflect, Reflect, ReflectRemote, ReflectRemoteWrapper, TypeInfo,
+    TypePath, Typed,
+};
 use alloc::sync::Arc;
 use alloc::{boxed::Box, string::String};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet, TypeIdMap};
 use core::{
-    any::TypeId,
+    any::{Any, TypeId},
     fmt::Debug,
     ops::{Deref, DerefMut},
 };
@@ -34,6 +37,7 @@ pub struct TypeRegistry {
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    remote_to_wrapper: TypeIdMap<TypeId>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -96,6 +100,7 @@ impl TypeRegistry {
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            remote_to_wrapper: Default::default(),
         }
     }
 
@@ -281,6 +286,61 @@ impl TypeRegistry {
         self.registrations.contains_key(&type_id)
     }
 
+    /// Registers the [remote wrapper] `W`, along with its [`ReflectRemoteWrapper`] type data.
+    ///
+    /// The registration of `W` can then be retrieved from the [`TypeId`] of its remote type with
+    /// [`get_remote`], so that values of the remote type can be reflected and serialized through
+    /// `W` without referring to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::any::TypeId;
+    /// # use bevy_reflect::{reflect_remote, ReflectRemoteWrapper, TypeRegistry};
+    /// mod some_lib {
+    ///     pub struct TheirType {
+    ///         pub value: u32,
+    ///     }
+    /// }
+    ///
+    /// #[reflect_remote(some_lib::TheirType)]
+    /// struct MyType {
+    ///     pub value: u32,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register_remote::<MyType>();
+    ///
+    /// let registration = registry.get_remote(TypeId::of::<some_lib::TheirType>()).unwrap();
+    /// let wrapper = registration.data::<ReflectRemoteWrapper>().unwrap();
+    /// let value = some_lib::TheirType { value: 123 };
+    /// assert!(wrapper.as_reflect(&value).is_some());
+    /// ```
+    ///
+    /// [remote wrapper]: ReflectRemote
+    /// [`get_remote`]: Self::get_remote
+    pub fn register_remote<W>(&mut self)
+    where
+        W: ReflectRemote + GetTypeRegistration + TypePath,
+        W::Remote: Any,
+    {
+        self.register::<W>();
+        self.register_type_data::<W, ReflectRemoteWrapper>();
+        self.remote_to_wrapper
+            .insert(TypeId::of::<W::Remote>(), TypeId::of::<W>());
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the [remote wrapper] registered with
+    /// [`register_remote`] for the remote type with the given [`TypeId`].
+    ///
+    /// If no wrapper was registered for the remote type, returns `None`.
+    ///
+    /// [remote wrapper]: ReflectRemote
+    /// [`register_remote`]: Self::register_remote
+    pub fn get_remote(&self, remote_type_id: TypeId) -> Option<&TypeRegistration> {
+        self.get(*self.remote_to_wrapper.get(&remote_type_id)?)
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [`TypeId`].
     ///
@@ -15,8 +15,9 @@ use bevy_ecs::{
 };
 use bevy_hierarchy::BuildChildren as _;
 use bevy_reflect::{
+    attributes::CustomAttributes,
     prelude::ReflectDefault,
-    serde::{ReflectSerializer, TypedReflectDeserializer},
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
     NamedField, OpaqueInfo, PartialReflect, ReflectDeserialize, ReflectSerialize, TypeInfo,
     TypeRegistration, TypeRegistry, VariantInfo,
 };
@@ -846,10 +847,23 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     };
 
     let types = world.resource::<AppTypeRegistry>();
-    let types = types.read();
-    let schemas = types
+    let schemas = export_registry_schema(&types.read(), &filter);
+
+    serde_json::to_value(schemas).map_err(BrpError::internal)
+}
+
+/// Exports the schemas of the types of `type_registry` that pass `filter`, by type path.
+///
+/// This is the result of a `bevy/registry/schema` request. It can also be called directly, for
+/// example to write the schemas to a file for external editors that build property grids for the
+/// reflected types without connecting to the app.
+pub fn export_registry_schema(
+    type_registry: &TypeRegistry,
+    filter: &BrpJsonSchemaQueryFilter,
+) -> HashMap<String, JsonSchemaBevyType> {
+    type_registry
         .iter()
-        .map(export_type)
+        .map(|reg| export_type(reg, type_registry))
         .filter(|(_, schema)| {
             if let Some(crate_name) = &schema.crate_name {
                 if !filter.with_crates.is_empty()
@@ -884,13 +898,14 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
 
             true
         })
-        .collect::<HashMap<String, JsonSchemaBevyType>>();
-
-    serde_json::to_value(schemas).map_err(BrpError::internal)
+        .collect::<HashMap<String, JsonSchemaBevyType>>()
 }
 
 /// Exports schema info for a given type
-fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
+fn export_type(
+    reg: &TypeRegistration,
+    type_registry: &TypeRegistry,
+) -> (String, JsonSchemaBevyType) {
     let t = reg.type_info();
     let binding = t.type_path_table();
 
@@ -904,11 +919,16 @@ fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
         module_path: binding.module_path().map(str::to_owned),
         ..Default::default()
     };
+    #[cfg(feature = "documentation")]
+    {
+        typed_schema.description = t.docs().map(str::to_owned);
+    }
     match t {
         TypeInfo::Struct(info) => {
+            typed_schema.attributes = export_attributes(info.custom_attributes(), type_registry);
             typed_schema.properties = info
                 .iter()
-                .map(|field| (field.name().to_owned(), field.ty().ref_type()))
+                .map(|field| (field.name().to_owned(), export_field(field, type_registry)))
                 .collect::<HashMap<_, _>>();
             typed_schema.required = info
                 .iter()
@@ -921,6 +941,7 @@ fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
         }
         TypeInfo::Enum(info) => {
             typed_schema.kind = SchemaKind::Enum;
+            typed_schema.attributes = export_attributes(info.custom_attributes(), type_registry);
 
             let simple = info
                 .iter()
@@ -977,6 +998,7 @@ fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
         TypeInfo::TupleStruct(info) => {
             typed_schema.schema_type = SchemaType::Array;
             typed_schema.kind = SchemaKind::TupleStruct;
+            typed_schema.attributes = export_attributes(info.custom_attributes(), type_registry);
             typed_schema.prefix_items = info
                 .iter()
                 .map(SchemaJsonReference::ref_type)
@@ -1022,6 +1044,40 @@ fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
     (t.type_path().to_owned(), typed_schema)
 }
 
+/// Exports the schema of a struct field, with its docs and custom attributes.
+fn export_field(field: &NamedField, type_registry: &TypeRegistry) -> Value {
+    let mut schema = field.ty().ref_type();
+    #[cfg(feature = "documentation")]
+    if let Some(docs) = field.docs() {
+        schema["description"] = docs.into();
+    }
+    let attributes = export_attributes(field.custom_attributes(), type_registry);
+    if !attributes.is_empty() {
+        schema["attributes"] = json!(attributes);
+    }
+    schema
+}
+
+/// Exports custom attributes by type path.
+///
+/// The attributes are serialized through reflection when their type is registered, and exported
+/// as their [`Debug`] representation otherwise.
+fn export_attributes(
+    attributes: &CustomAttributes,
+    type_registry: &TypeRegistry,
+) -> HashMap<String, Value> {
+    attributes
+        .iter()
+        .map(|(_, attribute)| {
+            let serializer =
+                TypedReflectSerializer::new(attribute.as_partial_reflect(), type_registry);
+            let value = serde_json::to_value(serializer)
+                .unwrap_or_else(|_| Value::String(format!("{attribute:?}")));
+            (attribute.reflect_type_path().to_owned(), value)
+        })
+        .collect()
+}
+
 fn get_registrered_reflect_types(reg: &TypeRegistration) -> Vec<String> {
     // Vec could be moved to allow registering more types by game maker.
     let registered_reflect_types: [(TypeId, &str); 5] = [
@@ -1062,6 +1118,14 @@ pub struct JsonSchemaBevyType {
     pub reflect_types: Vec<String>,
     /// Bevy specific field, [`TypeInfo`] type mapping.
     pub kind: SchemaKind,
+    /// The documentation of the type, provided when the `documentation` feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    /// Bevy specific field, the [custom attributes] of the type by type path.
+    ///
+    /// [custom attributes]: bevy_reflect::attributes::CustomAttributes
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub attributes: HashMap<String, Value>,
     /// Bevy specific field, provided when [`SchemaKind`] `kind` field is equal to [`SchemaKind::Map`].
     ///
     /// It contains type info of key of the Map.
@@ -1424,7 +1488,7 @@ mod tests {
     }
     use super::*;
     use bevy_ecs::{component::Component, system::Resource};
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{Reflect, TypePath};
 
     #[test]
     fn serialization_tests() {
@@ -1467,7 +1531,7 @@ mod tests {
             .get(TypeId::of::<Foo>())
             .expect("SHOULD BE REGISTERED")
             .clone();
-        let (_, schema) = export_type(&foo_registration);
+        let (_, schema) = export_type(&foo_registration, &type_registry);
         println!("{}", &serde_json::to_string_pretty(&schema).unwrap());
 
         assert!(
@@ -1513,7 +1577,7 @@ mod tests {
             .get(TypeId::of::<EnumComponent>())
             .expect("SHOULD BE REGISTERED")
             .clone();
-        let (_, schema) = export_type(&foo_registration);
+        let (_, schema) = export_type(&foo_registration, &type_registry);
         assert!(
             schema.reflect_types.contains(&"Component".to_owned()),
             "Should be a component"
@@ -1548,7 +1612,7 @@ mod tests {
             .get(TypeId::of::<EnumComponent>())
             .expect("SHOULD BE REGISTERED")
             .clone();
-        let (_, schema) = export_type(&foo_registration);
+        let (_, schema) = export_type(&foo_registration, &type_registry);
         assert!(
             !schema.reflect_types.contains(&"Component".to_owned()),
             "Should not be a component"
@@ -1577,7 +1641,7 @@ mod tests {
             .get(TypeId::of::<TupleStructType>())
             .expect("SHOULD BE REGISTERED")
             .clone();
-        let (_, schema) = export_type(&foo_registration);
+        let (_, schema) = export_type(&foo_registration, &type_registry);
         println!("{}", &serde_json::to_string_pretty(&schema).unwrap());
         assert!(
             schema.reflect_types.contains(&"Component".to_owned()),
@@ -1609,7 +1673,7 @@ mod tests {
             .get(TypeId::of::<Foo>())
             .expect("SHOULD BE REGISTERED")
             .clone();
-        let (_, schema) = export_type(&foo_registration);
+        let (_, schema) = export_type(&foo_registration, &type_registry);
         let schema_as_value = serde_json::to_value(&schema).expect("Should serialize");
         let value = json!({
           "shortPath": "Foo",
@@ -1636,4 +1700,50 @@ mod tests {
         });
         assert_eq!(schema_as_value, value);
     }
+
+    #[test]
+    fn reflect_export_attributes() {
+        #[derive(Reflect, Debug)]
+        struct Tooltip(String);
+
+        #[derive(Reflect, Component, Default)]
+        #[reflect(Component)]
+        #[reflect(@Tooltip("A health bar".to_owned()))]
+        struct Health {
+            #[reflect(@0.0..=100.0_f32)]
+            value: f32,
+        }
+
+        #[derive(Reflect, Resource, Default)]
+        #[reflect(Resource)]
+        struct Score(u32);
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<Health>();
+            register.register::<Tooltip>();
+            register.register::<Score>();
+        }
+        let type_registry = atr.read();
+        let filter = BrpJsonSchemaQueryFilter {
+            with_crates: vec!["bevy_remote".to_owned()],
+            type_limit: JsonSchemaTypeLimit {
+                with: vec!["Component".to_owned()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let schemas = export_registry_schema(&type_registry, &filter);
+        assert_eq!(schemas.len(), 1, "Should only export the component");
+
+        let schema = &schemas[Health::type_path()];
+        assert_eq!(
+            schema.attributes.get(Tooltip::type_path()),
+            Some(&json!("A health bar"))
+        );
+        assert!(schema.properties["value"]["attributes"]
+            .get("core::ops::RangeInclusive<f32>")
+            .is_some());
+    }
 }
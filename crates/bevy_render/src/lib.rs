@@ -103,7 +103,6 @@ use alloc::sync::Arc;
 use bevy_app::{App, AppLabel, Plugin, SubApp};
 use bevy_asset::{load_internal_asset, AssetApp, AssetServer, Handle};
 use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
-use core::ops::{Deref, DerefMut};
 use std::sync::Mutex;
 use tracing::debug;
 
@@ -222,22 +221,7 @@ pub struct ExtractSchedule;
 /// This resource is only available during [`ExtractSchedule`] and not
 /// during command application of that schedule.
 /// See [`Extract`] for more details.
-#[derive(Resource, Default)]
-pub struct MainWorld(World);
-
-impl Deref for MainWorld {
-    type Target = World;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl DerefMut for MainWorld {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
+pub use bevy_app::MainWorld;
 
 pub mod graph {
     use crate::render_graph::RenderLabel;
@@ -441,12 +425,12 @@ fn extract(main_world: &mut World, render_world: &mut World) {
     // temporarily add the app world to the render world as a resource
     let scratch_world = main_world.remove_resource::<ScratchMainWorld>().unwrap();
     let inserted_world = core::mem::replace(main_world, scratch_world.0);
-    render_world.insert_resource(MainWorld(inserted_world));
+    render_world.insert_resource(MainWorld::new(inserted_world));
     render_world.run_schedule(ExtractSchedule);
 
     // move the app world back, as if nothing happened.
     let inserted_world = render_world.remove_resource::<MainWorld>().unwrap();
-    let scratch_world = core::mem::replace(main_world, inserted_world.0);
+    let scratch_world = core::mem::replace(main_world, inserted_world.into_inner());
     main_world.insert_resource(ScratchMainWorld(scratch_world));
 }
 
@@ -3,7 +3,7 @@ use crate::{
 };
 use bevy_app::{App, Plugin, SubApp};
 pub use bevy_asset::RenderAssetUsages;
-use bevy_asset::{Asset, AssetEvent, AssetId, Assets};
+use bevy_asset::{Asset, AssetApp, AssetEvent, AssetId, AssetMemoryUsage, Assets};
 use bevy_ecs::{
     prelude::{Commands, EventReader, IntoSystemConfigs, ResMut, Resource},
     schedule::{SystemConfigs, SystemSet},
@@ -111,7 +111,8 @@ impl<A: RenderAsset, AFTER: RenderAssetDependency + 'static> Plugin
     for RenderAssetPlugin<A, AFTER>
 {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CachedExtractRenderAssetSystemState<A>>();
+        app.init_resource::<CachedExtractRenderAssetSystemState<A>>()
+            .register_asset_memory_usage(render_asset_memory_usage::<A>);
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
                 .init_resource::<ExtractedAssets<A>>()
@@ -129,6 +130,17 @@ impl<A: RenderAsset, AFTER: RenderAssetDependency + 'static> Plugin
     }
 }
 
+/// Measures the memory used by a [`RenderAsset::SourceAsset`]: the [`RenderAsset::byte_len`] it
+/// takes on the CPU until it's extracted, and on the GPU when it's used in the render world.
+fn render_asset_memory_usage<A: RenderAsset>(source_asset: &A::SourceAsset) -> AssetMemoryUsage {
+    let byte_len = A::byte_len(source_asset).unwrap_or(0);
+    let in_render_world = A::asset_usage(source_asset).contains(RenderAssetUsages::RENDER_WORLD);
+    AssetMemoryUsage {
+        cpu: size_of::<A::SourceAsset>() + byte_len,
+        gpu: if in_render_world { byte_len } else { 0 },
+    }
+}
+
 // helper to allow specifying dependencies between render assets
 pub trait RenderAssetDependency {
     fn register_system(render_app: &mut SubApp, system: SystemConfigs);
@@ -16,7 +16,8 @@ use crate::{
     settings::{WgpuSettings, WgpuSettingsPriority},
     view::{ExtractedWindows, ViewTarget},
 };
-use alloc::sync::Arc;
+use alloc::{borrow::Cow, sync::Arc};
+use bevy_app::Capability;
 use bevy_ecs::{prelude::*, system::SystemState};
 use bevy_time::TimeSender;
 use bevy_utils::Instant;
@@ -186,6 +187,41 @@ const GPU_NOT_FOUND_ERROR_MESSAGE: &str = if cfg!(target_os = "linux") {
     "Unable to find a GPU! Make sure you have installed required drivers!"
 };
 
+/// The [`Capability`] of rendering, missing when no GPU adapter is found for the backends
+/// allowed by the default [`WgpuSettings`], which can be set through environment variables.
+///
+/// This can be [required](bevy_app::PluginGroupBuilder::require) by the rendering plugins to
+/// leave them out on machines without a GPU, like servers and CI runners. On the web, the adapter
+/// can't be requested synchronously, so the capability is assumed to be available.
+pub struct GpuAdapterCapability;
+
+impl Capability for GpuAdapterCapability {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("gpu_adapter")
+    }
+
+    fn detect(&self) -> bool {
+        let settings = WgpuSettings::default();
+        let Some(backends) = settings.backends else {
+            return false;
+        };
+        if cfg!(target_arch = "wasm32") {
+            return true;
+        }
+        let instance = Instance::new(wgpu::InstanceDescriptor {
+            backends,
+            dx12_shader_compiler: settings.dx12_shader_compiler,
+            flags: settings.instance_flags,
+            gles_minor_version: settings.gles3_minor_version,
+        });
+        futures_lite::future::block_on(instance.request_adapter(&RequestAdapterOptions {
+            power_preference: settings.power_preference,
+            ..Default::default()
+        }))
+        .is_some()
+    }
+}
+
 /// Initializes the renderer by retrieving and preparing the GPU instance, device and queue
 /// for the specified backend.
 pub async fn initialize_renderer(
@@ -1,10 +1,11 @@
-use bevy_asset::Handle;
+use bevy_asset::{AssetPath, Handle};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     component::{require, Component},
+    entity::Entity,
     prelude::ReflectComponent,
 };
-use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_reflect::{prelude::ReflectDefault, PartialReflect, Reflect, TypePath};
 use bevy_transform::components::Transform;
 use derive_more::derive::From;
 
@@ -28,3 +29,127 @@ pub struct SceneRoot(pub Handle<Scene>);
 #[require(Transform)]
 #[cfg_attr(feature = "bevy_render", require(Visibility))]
 pub struct DynamicSceneRoot(pub Handle<DynamicScene>);
+
+/// References another [`DynamicScene`] asset by path, to be spawned as a child of this entity.
+///
+/// This composes scenes out of other scenes, for example to build a level out of reusable prefab
+/// scenes. Unlike [`DynamicSceneRoot`], which holds a handle, the reference can be saved in a
+/// scene file.
+///
+/// References are resolved by the [`SceneSpawner`](crate::SceneSpawner) as soon as the entity is
+/// spawned, and the referenced scene is spawned in the same frame if it is already loaded,
+/// together with the scenes it references in turn. Once spawned, the entity has a
+/// [`SceneInstance`](crate::SceneInstance) component. A scene referencing itself, directly or
+/// through other scenes, isn't spawned again below itself.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Debug)]
+#[require(Transform)]
+#[cfg_attr(feature = "bevy_render", require(Visibility))]
+pub struct SceneReference {
+    /// The path of the referenced scene.
+    pub path: AssetPath<'static>,
+    /// The handle to the referenced scene, set once the reference is resolved.
+    #[reflect(ignore)]
+    pub(crate) handle: Option<Handle<DynamicScene>>,
+}
+
+impl SceneReference {
+    /// Creates a reference to the scene at `path`.
+    pub fn new(path: impl Into<AssetPath<'static>>) -> Self {
+        Self {
+            path: path.into(),
+            handle: None,
+        }
+    }
+}
+
+/// Overrides values of the scene instance spawned on this entity, from a [`SceneRoot`],
+/// [`DynamicSceneRoot`] or [`SceneReference`].
+///
+/// This tweaks a single instance of a scene, like the color of one prop, without forking the
+/// scene asset. The overrides are applied by the [`SceneSpawner`](crate::SceneSpawner) right
+/// after the instance is spawned, before [`SceneInstanceReady`](crate::SceneInstanceReady) is
+/// triggered, and applied again each time the instance is patched after its scene is reloaded.
+/// Changing the overrides of an instance that is already spawned doesn't affect it until then.
+///
+/// Overrides that can't be applied, because their entity, component or path doesn't exist in the
+/// instance, are skipped with a warning.
+#[derive(Component, Default)]
+pub struct InstanceOverrides {
+    overrides: Vec<InstanceOverride>,
+}
+
+/// A single override of [`InstanceOverrides`].
+pub struct InstanceOverride {
+    /// The overridden entity, as identified in the scene asset rather than in the world.
+    pub entity: Entity,
+    /// The type path of the overridden component.
+    pub component: String,
+    /// The [reflection path](bevy_reflect::GetPath) of the overridden field in the component, or
+    /// an empty path to override the whole component.
+    pub path: String,
+    /// The value applied to the overridden field.
+    pub value: Box<dyn PartialReflect>,
+}
+
+impl InstanceOverrides {
+    /// Returns these overrides with the field at `path` of the component `C` of the scene entity
+    /// `entity` overridden by `value`.
+    #[must_use]
+    pub fn with<C: Component + TypePath>(
+        mut self,
+        entity: Entity,
+        path: impl Into<String>,
+        value: impl PartialReflect,
+    ) -> Self {
+        self.set::<C>(entity, path, value);
+        self
+    }
+
+    /// Overrides the field at `path` of the component `C` of the scene entity `entity` by
+    /// `value`.
+    pub fn set<C: Component + TypePath>(
+        &mut self,
+        entity: Entity,
+        path: impl Into<String>,
+        value: impl PartialReflect,
+    ) {
+        self.insert(InstanceOverride {
+            entity,
+            component: C::type_path().to_string(),
+            path: path.into(),
+            value: Box::new(value),
+        });
+    }
+
+    /// Adds an override, replacing the one of the same field, if any.
+    pub fn insert(&mut self, instance_override: InstanceOverride) {
+        match self.overrides.iter_mut().find(|existing| {
+            existing.entity == instance_override.entity
+                && existing.component == instance_override.component
+                && existing.path == instance_override.path
+        }) {
+            Some(existing) => *existing = instance_override,
+            None => self.overrides.push(instance_override),
+        }
+    }
+
+    /// Removes the override of the field at `path` of the component with the type path
+    /// `component` of the scene entity `entity`, returning it if it existed.
+    pub fn remove(
+        &mut self,
+        entity: Entity,
+        component: &str,
+        path: &str,
+    ) -> Option<InstanceOverride> {
+        let index = self.overrides.iter().position(|existing| {
+            existing.entity == entity && existing.component == component && existing.path == path
+        })?;
+        Some(self.overrides.remove(index))
+    }
+
+    /// Returns an iterator over the overrides.
+    pub fn iter(&self) -> impl Iterator<Item = &InstanceOverride> {
+        self.overrides.iter()
+    }
+}
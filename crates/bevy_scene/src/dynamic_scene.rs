@@ -1,4 +1,4 @@
-use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
+use crate::{ron, DynamicSceneBuilder, PlainDataComponents, Scene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
@@ -6,7 +6,7 @@ use bevy_ecs::{
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use bevy_reflect::{FromReflect, PartialReflect, TypePath, TypeRegistry};
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
@@ -82,45 +82,28 @@ impl DynamicScene {
             let entity = *entity_map
                 .get(&scene_entity.entity)
                 .expect("should have previously spawned an empty entity");
-
-            // Apply/ add each component to the given entity.
-            for component in &scene_entity.components {
-                let mut component = component.clone_value();
-                let type_info = component.get_represented_type_info().ok_or_else(|| {
-                    SceneSpawnError::NoRepresentedType {
-                        type_path: component.reflect_type_path().to_string(),
-                    }
-                })?;
-                let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                    SceneSpawnError::UnregisteredButReflectedType {
-                        type_path: type_info.type_path().to_string(),
-                    }
-                })?;
-                let reflect_component =
-                    registration.data::<ReflectComponent>().ok_or_else(|| {
-                        SceneSpawnError::UnregisteredComponent {
-                            type_path: type_info.type_path().to_string(),
-                        }
-                    })?;
-
-                // If this component references entities in the scene, update
-                // them to the entities in the world.
-                if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
-                    SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
-                        map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
-                    });
-                }
-
-                reflect_component.apply_or_insert(
-                    &mut world.entity_mut(entity),
-                    component.as_partial_reflect(),
-                    &type_registry,
-                );
-            }
+            write_components(
+                world,
+                entity,
+                &scene_entity.components,
+                entity_map,
+                &type_registry,
+            )?;
         }
 
         // Insert resources after all entities have been added to the world.
         // This ensures the entities are available for the resources to reference during mapping.
+        self.write_resources(world, entity_map, &type_registry)
+    }
+
+    /// Writes the resources of this scene to `world`, mapping the entities they reference with
+    /// `entity_map`.
+    pub(crate) fn write_resources(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
         for resource in &self.resources {
             let mut resource = resource.clone_value();
             let type_info = resource.get_represented_type_info().ok_or_else(|| {
@@ -149,7 +132,7 @@ impl DynamicScene {
 
             // If the world already contains an instance of the given resource
             // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), &type_registry);
+            reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), type_registry);
         }
 
         Ok(())
@@ -183,6 +166,65 @@ impl DynamicScene {
     }
 }
 
+/// Applies or adds each of `components` to `entity`, mapping the entities they reference with
+/// `entity_map`.
+pub(crate) fn write_components<'a>(
+    world: &mut World,
+    entity: Entity,
+    components: impl IntoIterator<Item = &'a Box<dyn PartialReflect>>,
+    entity_map: &mut EntityHashMap<Entity>,
+    type_registry: &TypeRegistry,
+) -> Result<(), SceneSpawnError> {
+    for component in components {
+        let mut component = component.clone_value();
+        let type_info = component.get_represented_type_info().ok_or_else(|| {
+            SceneSpawnError::NoRepresentedType {
+                type_path: component.reflect_type_path().to_string(),
+            }
+        })?;
+        if type_info.is::<PlainDataComponents>() {
+            if let Some(plain_data) = PlainDataComponents::from_reflect(component.as_ref()) {
+                plain_data.write_to_entity(&mut world.entity_mut(entity))?;
+            }
+            continue;
+        }
+        let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+            SceneSpawnError::UnregisteredButReflectedType {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+        let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+            SceneSpawnError::UnregisteredComponent {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+
+        // If this component references entities in the scene, update
+        // them to the entities in the world. Components without a registered
+        // `ReflectMapEntities` are mapped through `Component::map_entities`.
+        if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+            SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
+            });
+            reflect_component.apply_or_insert(
+                &mut world.entity_mut(entity),
+                component.as_partial_reflect(),
+                type_registry,
+            );
+        } else {
+            SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+                reflect_component.apply_or_insert_mapped(
+                    &mut world.entity_mut(entity),
+                    component.as_partial_reflect(),
+                    type_registry,
+                    mapper,
+                );
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Serialize a given Rust data structure into rust object notation (ron).
 #[cfg(feature = "serialize")]
 pub fn serialize_ron<S>(serialize: S) -> Result<String, ron::Error>
@@ -377,4 +419,51 @@ mod tests {
             .write_to_world(&mut dst_world, &mut Default::default())
             .unwrap();
     }
+
+    #[test]
+    fn components_with_entity_fields_are_mapped() {
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Holder {
+            #[entities]
+            single: Entity,
+            #[entities]
+            many: Vec<Entity>,
+        }
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        #[component(immutable)]
+        struct Pointer(#[entities] Entity);
+
+        let reg = AppTypeRegistry::default();
+        {
+            let mut reg_write = reg.write();
+            reg_write.register::<Holder>();
+            reg_write.register::<Pointer>();
+        }
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(reg.clone());
+        let a = scene_world.spawn_empty().id();
+        let b = scene_world.spawn(Pointer(a)).id();
+        scene_world.entity_mut(a).insert(Holder {
+            single: b,
+            many: vec![a, b],
+        });
+        let scene = DynamicScene::from_world(&scene_world);
+
+        let mut dst_world = World::new();
+        dst_world.insert_resource(reg);
+        let mut entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+
+        let (dst_a, dst_b) = (entity_map[&a], entity_map[&b]);
+        let holder = dst_world.get::<Holder>(dst_a).unwrap();
+        assert_eq!(dst_b, holder.single);
+        assert_eq!(vec![dst_a, dst_b], holder.many);
+        assert_eq!(dst_a, dst_world.get::<Pointer>(dst_b).unwrap().0);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{DynamicEntity, DynamicScene, SceneFilter};
+use crate::{DynamicEntity, DynamicScene, PlainDataComponents, SceneFilter};
 use alloc::collections::BTreeMap;
 use bevy_ecs::{
     component::{Component, ComponentId},
@@ -7,6 +7,7 @@ use bevy_ecs::{
     system::Resource,
     world::World,
 };
+use bevy_hierarchy::{Children, Parent};
 use bevy_reflect::{PartialReflect, ReflectFromReflect};
 use bevy_utils::default;
 
@@ -220,6 +221,40 @@ impl<'w> DynamicSceneBuilder<'w> {
         self.extract_entities(core::iter::once(entity))
     }
 
+    /// Extract an entity and all its descendants from the builder's [`World`].
+    ///
+    /// The hierarchy is extracted through the [`Parent`] and [`Children`] components, so it is
+    /// restored when the scene is spawned, as long as these components are registered and pass
+    /// the filter. The [`Parent`] of `root` is left out unless it was extracted too, so that
+    /// `root` is spawned as a root entity. This is useful to save a selection as a prefab.
+    ///
+    /// Re-extracting an entity that was already extracted will have no effect.
+    #[must_use]
+    pub fn extract_entity_recursive(self, root: Entity) -> Self {
+        let world = self.original_world;
+        let mut entities = Vec::new();
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            entities.push(entity);
+            if let Some(children) = world.get::<Children>(entity) {
+                stack.extend(children.iter().rev());
+            }
+        }
+
+        let mut builder = self.extract_entities(entities.into_iter());
+        let parent_outside_scene = world
+            .get::<Parent>(root)
+            .is_some_and(|parent| !builder.extracted_scene.contains_key(&parent.get()));
+        if parent_outside_scene {
+            if let Some(entry) = builder.extracted_scene.get_mut(&root) {
+                entry
+                    .components
+                    .retain(|component| !component.represents::<Parent>());
+            }
+        }
+        builder
+    }
+
     /// Despawns all entities with no components.
     ///
     /// These were likely created because none of their components were present in the provided type registry upon extraction.
@@ -314,6 +349,13 @@ impl<'w> DynamicSceneBuilder<'w> {
                 };
                 extract_and_push();
             }
+
+            if !self.component_filter.is_denied::<PlainDataComponents>() {
+                let plain_data = PlainDataComponents::from_entity(self.original_world, entity);
+                if !plain_data.0.is_empty() {
+                    entry.components.push(Box::new(plain_data));
+                }
+            }
             self.extracted_scene.insert(entity, entry);
         }
 
@@ -398,6 +440,7 @@ mod tests {
         world::World,
     };
 
+    use bevy_hierarchy::{BuildChildren, Children, Parent};
     use bevy_reflect::Reflect;
 
     use super::DynamicSceneBuilder;
@@ -510,6 +553,44 @@ mod tests {
         assert_eq!(entity_d, entities.next().map(|e| e.entity).unwrap());
     }
 
+    #[test]
+    fn extract_entity_recursive() {
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<ComponentA>();
+            register.register::<Parent>();
+            register.register::<Children>();
+        }
+        world.insert_resource(atr);
+
+        let outside = world.spawn_empty().id();
+        let root = world.spawn(ComponentA).set_parent(outside).id();
+        let child = world.spawn(ComponentA).set_parent(root).id();
+        let grandchild = world.spawn(ComponentA).set_parent(child).id();
+        world.spawn(ComponentA).set_parent(outside);
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity_recursive(root)
+            .build();
+
+        let entities: Vec<_> = scene.entities.iter().map(|entity| entity.entity).collect();
+        assert_eq!(entities, vec![root, child, grandchild]);
+        assert!(!scene.entities[0]
+            .components
+            .iter()
+            .any(|component| component.represents::<Parent>()));
+
+        let mut entity_map = Default::default();
+        scene.write_to_world(&mut world, &mut entity_map).unwrap();
+        let (new_root, new_child) = (entity_map[&root], entity_map[&child]);
+        assert!(world.get::<Parent>(new_root).is_none());
+        assert_eq!(world.get::<Parent>(new_child).unwrap().get(), new_root);
+        assert_eq!(world.get::<Children>(new_root).unwrap()[..], [new_child]);
+    }
+
     #[test]
     fn extract_query() {
         let mut world = World::default();
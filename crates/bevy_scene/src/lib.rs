@@ -16,10 +16,16 @@ extern crate alloc;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod plain_data;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+mod scene_params;
+mod scene_patch;
+#[cfg(feature = "serialize")]
+mod scene_saver;
 mod scene_spawner;
+mod scene_validation;
 
 #[cfg(feature = "serialize")]
 pub mod serde;
@@ -31,10 +37,16 @@ use bevy_ecs::schedule::IntoSystemConfigs;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use plain_data::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_params::*;
+pub use scene_patch::*;
+#[cfg(feature = "serialize")]
+pub use scene_saver::*;
 pub use scene_spawner::*;
+pub use scene_validation::*;
 
 /// The scene prelude.
 ///
@@ -42,13 +54,13 @@ pub use scene_spawner::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        DynamicScene, DynamicSceneBuilder, DynamicSceneRoot, Scene, SceneFilter, SceneRoot,
-        SceneSpawner,
+        DynamicScene, DynamicSceneBuilder, DynamicSceneRoot, InstanceOverrides, Scene, SceneFilter,
+        SceneReference, SceneRoot, SceneSpawner,
     };
 }
 
 use bevy_app::prelude::*;
-use bevy_asset::AssetApp;
+use bevy_asset::{AssetApp, Handle};
 
 /// Plugin that provides scene functionality to an [`App`].
 #[derive(Default)]
@@ -63,7 +75,14 @@ impl Plugin for ScenePlugin {
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
-            .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
+            .register_type::<SceneReference>()
+            .register_type::<SceneParameters>()
+            .register_type::<PlainDataComponents>()
+            .add_event::<SceneSpawnProgress>()
+            .add_event::<SceneSaved>()
+            .init_resource::<SceneSaveTasks>()
+            .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain())
+            .add_systems(PostUpdate, poll_scene_save_tasks);
 
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
@@ -84,6 +103,27 @@ impl Plugin for ScenePlugin {
                 }
             });
 
+        // Register component hooks for SceneReference
+        app.world_mut()
+            .register_component_hooks::<SceneReference>()
+            .on_remove(|mut world, entity, _| {
+                let Some(reference) = world.get::<SceneReference>(entity) else {
+                    return;
+                };
+                let id = reference.handle.as_ref().map(Handle::id);
+                if let Some(&SceneInstance(scene_instance)) = world.get::<SceneInstance>(entity) {
+                    let Some(mut scene_spawner) = world.get_resource_mut::<SceneSpawner>() else {
+                        return;
+                    };
+                    if let Some(instance_ids) =
+                        id.and_then(|id| scene_spawner.spawned_dynamic_scenes.get_mut(&id))
+                    {
+                        instance_ids.remove(&scene_instance);
+                    }
+                    scene_spawner.despawn_instance(scene_instance);
+                }
+            });
+
         // Register component hooks for SceneRoot
         app.world_mut()
             .register_component_hooks::<SceneRoot>()
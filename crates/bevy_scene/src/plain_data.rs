@@ -0,0 +1,70 @@
+use crate::SceneSpawnError;
+use alloc::collections::BTreeMap;
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    world::{EntityWorldMut, World},
+};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+
+/// The components of an entity that were registered at runtime with
+/// [`ComponentDescriptor::new_plain_data`](bevy_ecs::component::ComponentDescriptor::new_plain_data),
+/// as their bytes keyed by component name.
+///
+/// These components have no Rust type to reflect, so a [`DynamicScene`](crate::DynamicScene)
+/// stores them in this value instead, which is saved like any other component. When the scene is
+/// written to a world, each component is looked up by name, and must have been registered in that
+/// world with the same layout.
+///
+/// Extracting them can be prevented by denying this type in the
+/// [`DynamicSceneBuilder`](crate::DynamicSceneBuilder)'s component filter.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct PlainDataComponents(pub BTreeMap<String, Vec<u8>>);
+
+impl PlainDataComponents {
+    /// Collects the plain-data components of `entity`.
+    pub fn from_entity(world: &World, entity: Entity) -> Self {
+        let entity = world.entity(entity);
+        let components = entity.archetype().components().filter_map(|component_id| {
+            let data = entity.get_plain_data(component_id)?;
+            let info = world.components().get_info(component_id)?;
+            Some((info.name().to_string(), data.to_vec()))
+        });
+        Self(components.collect())
+    }
+
+    /// Inserts these components into `entity`, replacing the values it already has.
+    pub fn write_to_entity(&self, entity: &mut EntityWorldMut) -> Result<(), SceneSpawnError> {
+        for (name, data) in &self.0 {
+            let component_id = plain_data_component_id(entity.world(), name, data.len())?;
+            entity.insert_plain_data(component_id, data);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the id of the plain-data component named `name` in `world`, checking that its values
+/// are `size` bytes long.
+fn plain_data_component_id(
+    world: &World,
+    name: &str,
+    size: usize,
+) -> Result<ComponentId, SceneSpawnError> {
+    let info = world
+        .components()
+        .get_id_by_name(name)
+        .and_then(|component_id| world.components().get_info(component_id))
+        .filter(|info| info.is_plain_data())
+        .ok_or_else(|| SceneSpawnError::UnregisteredPlainDataComponent {
+            name: name.to_string(),
+        })?;
+    if info.layout().size() != size {
+        return Err(SceneSpawnError::PlainDataSizeMismatch {
+            name: name.to_string(),
+            expected: info.layout().size(),
+            found: size,
+        });
+    }
+    Ok(info.id())
+}
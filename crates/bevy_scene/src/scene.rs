@@ -1,11 +1,13 @@
-use crate::{DynamicScene, SceneSpawnError};
+use crate::{DynamicEntity, DynamicScene, PlainDataComponents, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::{
     entity::{Entity, EntityHashMap, SceneEntityMapper},
+    entity_disabling::DefaultQueryFilters,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath};
+use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use core::any::TypeId;
 
 /// A composition of [`World`] objects.
 ///
@@ -47,6 +49,98 @@ impl Scene {
         Ok(Self { world: new_world })
     }
 
+    /// Converts the scene to a [`DynamicScene`], reflecting its components and resources with
+    /// `type_registry`, so that its entities can be written one at a time.
+    pub(crate) fn to_dynamic_scene(
+        &self,
+        type_registry: &TypeRegistry,
+    ) -> Result<DynamicScene, SceneSpawnError> {
+        let mut resources = Vec::new();
+        for (component_id, resource_data) in self.world.storages().resources.iter() {
+            if !resource_data.is_present() {
+                continue;
+            }
+
+            let component_info = self
+                .world
+                .components()
+                .get_info(component_id)
+                .expect("component_ids in archetypes should have ComponentInfo");
+
+            let type_id = component_info
+                .type_id()
+                .expect("reflected resources must have a type_id");
+
+            if type_id == TypeId::of::<DefaultQueryFilters>() {
+                continue;
+            }
+
+            let registration =
+                type_registry
+                    .get(type_id)
+                    .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                        std_type_name: component_info.name().to_string(),
+                    })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_path: registration.type_info().type_path().to_string(),
+                }
+            })?;
+            if let Some(resource) = reflect_resource.reflect(&self.world) {
+                resources.push(resource.clone_value());
+            }
+        }
+
+        let mut entities = Vec::new();
+        for archetype in self.world.archetypes().iter() {
+            for scene_entity in archetype.entities() {
+                let mut components: Vec<Box<dyn PartialReflect>> = Vec::new();
+                let plain_data = PlainDataComponents::from_entity(&self.world, scene_entity.id());
+                if !plain_data.0.is_empty() {
+                    components.push(Box::new(plain_data));
+                }
+
+                for component_id in archetype.components() {
+                    let component_info = self
+                        .world
+                        .components()
+                        .get_info(component_id)
+                        .expect("component_ids in archetypes should have ComponentInfo");
+                    if component_info.is_plain_data() {
+                        continue;
+                    }
+
+                    let registration = type_registry
+                        .get(component_info.type_id().unwrap())
+                        .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                            std_type_name: component_info.name().to_string(),
+                        })?;
+                    let reflect_component =
+                        registration.data::<ReflectComponent>().ok_or_else(|| {
+                            SceneSpawnError::UnregisteredComponent {
+                                type_path: registration.type_info().type_path().to_string(),
+                            }
+                        })?;
+                    if let Some(component) =
+                        reflect_component.reflect(self.world.entity(scene_entity.id()))
+                    {
+                        components.push(component.clone_value());
+                    }
+                }
+
+                entities.push(DynamicEntity {
+                    entity: scene_entity.id(),
+                    components,
+                });
+            }
+        }
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+        })
+    }
+
     /// Write the entities and their corresponding components to the given world.
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered in the
@@ -75,6 +169,12 @@ impl Scene {
                 .type_id()
                 .expect("reflected resources must have a type_id");
 
+            // The destination world already has its own query filters, which are
+            // configured per world and not part of the scene.
+            if type_id == TypeId::of::<DefaultQueryFilters>() {
+                continue;
+            }
+
             let registration =
                 type_registry
                     .get(type_id)
@@ -105,12 +205,18 @@ impl Scene {
                     .get(&scene_entity.id())
                     .expect("should have previously spawned an entity");
 
+                PlainDataComponents::from_entity(&self.world, scene_entity.id())
+                    .write_to_entity(&mut world.entity_mut(entity))?;
+
                 for component_id in archetype.components() {
                     let component_info = self
                         .world
                         .components()
                         .get_info(component_id)
                         .expect("component_ids in archetypes should have ComponentInfo");
+                    if component_info.is_plain_data() {
+                        continue;
+                    }
 
                     let registration = type_registry
                         .get(component_info.type_id().unwrap())
@@ -131,18 +237,28 @@ impl Scene {
                         continue;
                     };
 
-                    // If this component references entities in the scene,
-                    // update them to the entities in the world.
+                    // If this component references entities in the scene, update
+                    // them to the entities in the world. Components without a registered
+                    // `ReflectMapEntities` are mapped through `Component::map_entities`.
                     if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
                         SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
                             map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
                         });
+                        reflect_component.apply_or_insert(
+                            &mut world.entity_mut(entity),
+                            component.as_partial_reflect(),
+                            &type_registry,
+                        );
+                    } else {
+                        SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+                            reflect_component.apply_or_insert_mapped(
+                                &mut world.entity_mut(entity),
+                                component.as_partial_reflect(),
+                                &type_registry,
+                                mapper,
+                            );
+                        });
                     }
-                    reflect_component.apply_or_insert(
-                        &mut world.entity_mut(entity),
-                        component.as_partial_reflect(),
-                        &type_registry,
-                    );
                 }
             }
         }
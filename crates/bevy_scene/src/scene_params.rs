@@ -0,0 +1,86 @@
+use bevy_ecs::{component::Component, prelude::ReflectComponent};
+use bevy_reflect::{prelude::ReflectDefault, PartialReflect, Reflect, TypePath};
+use bevy_utils::HashMap;
+
+/// Declares the named parameters of a scene, each exposing a field of a component of this entity.
+///
+/// When the scene is spawned with [`SceneSpawner::spawn_with_params`] or
+/// [`SceneSpawner::spawn_dynamic_with_params`], the values given for these parameters are applied
+/// to the exposed fields, right after the instance is spawned and each time it is patched after
+/// its scene is reloaded. This makes variants of a prefab, like a team color or a difficulty
+/// scalar, without duplicating the scene asset.
+///
+/// As this is a component of the scene, it is saved and loaded with it. Several entities of a
+/// scene can expose fields under the same parameter name, and they all receive its value.
+///
+/// [`SceneSpawner::spawn_with_params`]: crate::SceneSpawner::spawn_with_params
+/// [`SceneSpawner::spawn_dynamic_with_params`]: crate::SceneSpawner::spawn_dynamic_with_params
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct SceneParameters {
+    /// The declared parameters.
+    pub parameters: Vec<SceneParameter>,
+}
+
+/// A parameter of a scene. See [`SceneParameters`].
+#[derive(Clone, Debug, Reflect)]
+#[reflect(Debug)]
+pub struct SceneParameter {
+    /// The name the value of the parameter is given under.
+    pub name: String,
+    /// The type path of the component with the exposed field.
+    pub component: String,
+    /// The [reflection path](bevy_reflect::GetPath) of the exposed field in the component, or an
+    /// empty path to expose the whole component.
+    pub path: String,
+}
+
+impl SceneParameters {
+    /// Returns these parameters with the parameter `name`, exposing the field at `path` of the
+    /// component `C`.
+    #[must_use]
+    pub fn with<C: Component + TypePath>(
+        mut self,
+        name: impl Into<String>,
+        path: impl Into<String>,
+    ) -> Self {
+        self.parameters.push(SceneParameter {
+            name: name.into(),
+            component: C::type_path().to_string(),
+            path: path.into(),
+        });
+        self
+    }
+}
+
+/// The values of the parameters of a scene instance, by parameter name. See [`SceneParameters`].
+#[derive(Debug, Default)]
+pub struct SceneParams {
+    values: HashMap<String, Box<dyn PartialReflect>>,
+}
+
+impl SceneParams {
+    /// Returns these values with the value of the parameter `name` set to `value`.
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, value: impl PartialReflect) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Sets the value of the parameter `name` to `value`.
+    pub fn set(&mut self, name: impl Into<String>, value: impl PartialReflect) {
+        self.values.insert(name.into(), Box::new(value));
+    }
+
+    /// Returns the value of the parameter `name`, if it was set.
+    pub fn get(&self, name: &str) -> Option<&dyn PartialReflect> {
+        self.values
+            .get(name)
+            .map(|value| value.as_partial_reflect())
+    }
+
+    /// Returns `true` if no parameter value was set.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
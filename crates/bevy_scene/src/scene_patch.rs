@@ -0,0 +1,408 @@
+use crate::{DynamicEntity, DynamicScene, SceneSpawnError};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    world::World,
+};
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy_reflect::PartialReflect;
+
+#[cfg(feature = "serialize")]
+use {
+    crate::{ron, serde::ScenePatchSerializer, serialize_ron},
+    bevy_reflect::TypeRegistry,
+};
+
+/// The changes that turn a [`DynamicScene`] into another one, computed by [`DynamicScene::diff`].
+///
+/// Entities are matched by their identifier in the scenes, and components and resources by their
+/// type. A patch can be applied to another scene with [`apply_to_scene`](Self::apply_to_scene), or
+/// to a spawned scene instance with
+/// [`SceneSpawner::apply_patch_sync`](crate::SceneSpawner::apply_patch_sync). As it only contains
+/// what changed, it is a good fit for level override files and for sending small content updates
+/// over the network.
+#[derive(Default)]
+pub struct ScenePatch {
+    /// Resources that were added or changed.
+    pub resources: Vec<Box<dyn PartialReflect>>,
+    /// Type paths of the resources that were removed.
+    pub removed_resources: Vec<String>,
+    /// Entities that were added, or whose components changed.
+    pub entities: Vec<EntityPatch>,
+    /// Identifiers of the entities that were removed.
+    pub removed_entities: Vec<Entity>,
+}
+
+/// The changes to a single entity of a [`ScenePatch`].
+pub struct EntityPatch {
+    /// The identifier of the entity in the scene.
+    pub entity: Entity,
+    /// Components that were added or changed.
+    pub components: Vec<Box<dyn PartialReflect>>,
+    /// Type paths of the components that were removed.
+    pub removed_components: Vec<String>,
+}
+
+impl DynamicScene {
+    /// Computes the [`ScenePatch`] that turns `base` into this scene.
+    ///
+    /// Values are compared with [`PartialReflect::reflect_partial_eq`]. Values of types that can't
+    /// be compared are always considered changed.
+    pub fn diff(&self, base: &DynamicScene) -> ScenePatch {
+        let (resources, removed_resources) = diff_values(&self.resources, &base.resources);
+
+        let base_entities: EntityHashMap<_> = base
+            .entities
+            .iter()
+            .map(|base_entity| (base_entity.entity, &base_entity.components[..]))
+            .collect();
+        let mut entities = Vec::new();
+        for entity in &self.entities {
+            let base_components = base_entities.get(&entity.entity).copied().unwrap_or(&[]);
+            let (components, removed_components) = diff_values(&entity.components, base_components);
+            if !components.is_empty() || !removed_components.is_empty() {
+                entities.push(EntityPatch {
+                    entity: entity.entity,
+                    components,
+                    removed_components,
+                });
+            }
+        }
+
+        let scene_entities: EntityHashSet = self
+            .entities
+            .iter()
+            .map(|scene_entity| scene_entity.entity)
+            .collect();
+        let removed_entities = base
+            .entities
+            .iter()
+            .map(|base_entity| base_entity.entity)
+            .filter(|entity| !scene_entities.contains(entity))
+            .collect();
+
+        ScenePatch {
+            resources,
+            removed_resources,
+            entities,
+            removed_entities,
+        }
+    }
+}
+
+impl ScenePatch {
+    /// Returns `true` if this patch doesn't change anything.
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+            && self.removed_resources.is_empty()
+            && self.entities.is_empty()
+            && self.removed_entities.is_empty()
+    }
+
+    /// Applies this patch to `scene`.
+    pub fn apply_to_scene(&self, scene: &mut DynamicScene) {
+        let removed_entities: EntityHashSet = self.removed_entities.iter().copied().collect();
+        scene
+            .entities
+            .retain(|entity| !removed_entities.contains(&entity.entity));
+        let mut indices: EntityHashMap<_> = scene
+            .entities
+            .iter()
+            .enumerate()
+            .map(|(index, entity)| (entity.entity, index))
+            .collect();
+        for patch in &self.entities {
+            let index = *indices.entry(patch.entity).or_insert_with(|| {
+                scene.entities.push(DynamicEntity {
+                    entity: patch.entity,
+                    components: Vec::new(),
+                });
+                scene.entities.len() - 1
+            });
+            apply_values(
+                &mut scene.entities[index].components,
+                &patch.components,
+                &patch.removed_components,
+            );
+        }
+        apply_values(
+            &mut scene.resources,
+            &self.resources,
+            &self.removed_resources,
+        );
+    }
+
+    /// Applies this patch to the entities of a spawned scene, given the mapping from the scene's
+    /// entities to the world's.
+    ///
+    /// Removed entities are despawned with their descendants, and added entities are spawned and
+    /// inserted in `entity_map`.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered in the
+    /// provided [`AppTypeRegistry`] or doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) or
+    /// [`Resource`](bevy_ecs::prelude::Resource) trait.
+    pub fn write_to_world_with(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        {
+            let type_registry = type_registry.read();
+
+            for scene_entity in &self.removed_entities {
+                let Some(entity) = entity_map.remove(scene_entity) else {
+                    continue;
+                };
+                if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                    entity_mut.remove_parent();
+                    entity_mut.despawn_recursive();
+                }
+            }
+
+            for patch in &self.entities {
+                let Some(&entity) = entity_map.get(&patch.entity) else {
+                    continue;
+                };
+                for type_path in &patch.removed_components {
+                    let reflect_component = type_registry
+                        .get_with_type_path(type_path)
+                        .ok_or_else(|| SceneSpawnError::UnregisteredButReflectedType {
+                            type_path: type_path.clone(),
+                        })?
+                        .data::<ReflectComponent>()
+                        .ok_or_else(|| SceneSpawnError::UnregisteredComponent {
+                            type_path: type_path.clone(),
+                        })?;
+                    if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                        reflect_component.remove(&mut entity_mut);
+                    }
+                }
+            }
+
+            for type_path in &self.removed_resources {
+                let reflect_resource = type_registry
+                    .get_with_type_path(type_path)
+                    .ok_or_else(|| SceneSpawnError::UnregisteredButReflectedType {
+                        type_path: type_path.clone(),
+                    })?
+                    .data::<ReflectResource>()
+                    .ok_or_else(|| SceneSpawnError::UnregisteredResource {
+                        type_path: type_path.clone(),
+                    })?;
+                reflect_resource.remove(world);
+            }
+        }
+
+        // Writing the added and changed values as a scene maps the entities they reference.
+        let changes = DynamicScene {
+            resources: self
+                .resources
+                .iter()
+                .map(|value| value.clone_value())
+                .collect(),
+            entities: self
+                .entities
+                .iter()
+                .map(|patch| DynamicEntity {
+                    entity: patch.entity,
+                    components: patch
+                        .components
+                        .iter()
+                        .map(|value| value.clone_value())
+                        .collect(),
+                })
+                .collect(),
+        };
+        changes.write_to_world_with(world, entity_map, type_registry)
+    }
+
+    /// Applies this patch to the entities of a spawned scene, using the world's
+    /// [`AppTypeRegistry`]. See [`write_to_world_with`](Self::write_to_world_with).
+    pub fn write_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        self.write_to_world_with(world, entity_map, &registry)
+    }
+
+    /// Serialize this patch into RON, the format used by [`DynamicScene::serialize`].
+    ///
+    /// It can be deserialized with [`ScenePatchDeserializer`](crate::serde::ScenePatchDeserializer).
+    #[cfg(feature = "serialize")]
+    pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(ScenePatchSerializer::new(self, registry))
+    }
+}
+
+/// Returns the type path of the type represented by `value`.
+pub(crate) fn represented_type_path(value: &dyn PartialReflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.reflect_type_path(), |info| info.type_path())
+}
+
+/// Returns the values of `values` that aren't in `base` or differ from it, and the type paths of
+/// the values of `base` that aren't in `values`.
+fn diff_values(
+    values: &[Box<dyn PartialReflect>],
+    base: &[Box<dyn PartialReflect>],
+) -> (Vec<Box<dyn PartialReflect>>, Vec<String>) {
+    let changed = values
+        .iter()
+        .filter(|value| {
+            let type_path = represented_type_path(value.as_partial_reflect());
+            !base.iter().any(|base_value| {
+                represented_type_path(base_value.as_partial_reflect()) == type_path
+                    && base_value.reflect_partial_eq(value.as_partial_reflect()) == Some(true)
+            })
+        })
+        .map(|value| value.clone_value())
+        .collect();
+    let removed = base
+        .iter()
+        .map(|base_value| represented_type_path(base_value.as_partial_reflect()))
+        .filter(|&type_path| {
+            !values
+                .iter()
+                .any(|value| represented_type_path(value.as_partial_reflect()) == type_path)
+        })
+        .map(ToString::to_string)
+        .collect();
+    (changed, removed)
+}
+
+/// Removes the values of the `removed` types from `values`, and adds or replaces the `changed`
+/// ones.
+fn apply_values(
+    values: &mut Vec<Box<dyn PartialReflect>>,
+    changed: &[Box<dyn PartialReflect>],
+    removed: &[String],
+) {
+    values.retain(|value| {
+        let type_path = represented_type_path(value.as_partial_reflect());
+        !removed.iter().any(|removed| removed == type_path)
+    });
+    for value in changed {
+        let type_path = represented_type_path(value.as_partial_reflect());
+        match values
+            .iter_mut()
+            .find(|existing| represented_type_path(existing.as_partial_reflect()) == type_path)
+        {
+            Some(existing) => *existing = value.clone_value(),
+            None => values.push(value.clone_value()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{component::Component, reflect::ReflectComponent};
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Clone, Copy, PartialEq, Debug)]
+    #[reflect(Component, PartialEq)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Clone, Copy, PartialEq, Debug)]
+    #[reflect(Component, PartialEq)]
+    struct Speed(u32);
+
+    fn registry() -> AppTypeRegistry {
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Health>();
+        registry.write().register::<Speed>();
+        registry
+    }
+
+    fn scene(entities: &[(u32, &[&dyn PartialReflect])]) -> DynamicScene {
+        DynamicScene {
+            resources: Vec::new(),
+            entities: entities
+                .iter()
+                .map(|(index, components)| DynamicEntity {
+                    entity: Entity::from_raw(*index),
+                    components: components
+                        .iter()
+                        .map(|component| component.clone_value())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn diff_and_apply() {
+        let base = scene(&[
+            (0, &[&Health(10), &Speed(1)]),
+            (1, &[&Health(5)]),
+            (2, &[&Speed(2)]),
+        ]);
+        let target = scene(&[(0, &[&Health(10), &Speed(3)]), (1, &[]), (3, &[&Health(7)])]);
+
+        let patch = target.diff(&base);
+        // Entity 0 has one changed component, entity 1 one removed component, entity 2 is
+        // removed and entity 3 is added.
+        assert_eq!(patch.entities.len(), 3);
+        assert_eq!(patch.entities[0].components.len(), 1);
+        assert_eq!(patch.entities[1].removed_components.len(), 1);
+        assert_eq!(patch.removed_entities, vec![Entity::from_raw(2)]);
+        assert!(target.diff(&target).is_empty());
+
+        let mut patched = base;
+        patch.apply_to_scene(&mut patched);
+        assert!(target.diff(&patched).is_empty());
+    }
+
+    #[test]
+    fn apply_to_spawned_instance() {
+        let registry = registry();
+        let base = scene(&[(0, &[&Health(10), &Speed(1)]), (1, &[&Health(5)])]);
+        let target = scene(&[(0, &[&Health(10), &Speed(3)]), (2, &[&Health(7)])]);
+
+        let mut world = World::new();
+        world.insert_resource(registry);
+        let mut entity_map = EntityHashMap::default();
+        base.write_to_world(&mut world, &mut entity_map).unwrap();
+        let first = entity_map[&Entity::from_raw(0)];
+        let second = entity_map[&Entity::from_raw(1)];
+
+        target
+            .diff(&base)
+            .write_to_world(&mut world, &mut entity_map)
+            .unwrap();
+
+        assert_eq!(world.get::<Speed>(first), Some(&Speed(3)));
+        assert!(world.get_entity(second).is_err());
+        let third = entity_map[&Entity::from_raw(2)];
+        assert_eq!(world.get::<Health>(third), Some(&Health(7)));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn roundtrip_ron() {
+        use crate::serde::ScenePatchDeserializer;
+        use serde::de::DeserializeSeed;
+
+        let registry = registry();
+        let base = scene(&[(0, &[&Health(10), &Speed(1)]), (1, &[&Health(5)])]);
+        let target = scene(&[(0, &[&Health(12)]), (2, &[&Speed(7)])]);
+        let patch = target.diff(&base);
+
+        let serialized = patch.serialize(&registry.read()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ScenePatchDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut patched = base;
+        deserialized.apply_to_scene(&mut patched);
+        assert!(target.diff(&patched).is_empty());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{ron, DynamicSceneBuilder, SceneFilter};
+use alloc::sync::Arc;
+use bevy_asset::{
+    io::{AssetWriterError, MissingAssetSourceError, MissingAssetWriterError},
+    AssetPath, AssetServer,
+};
+use bevy_ecs::{
+    entity::Entity,
+    event::{Event, EventWriter},
+    reflect::AppTypeRegistry,
+    result::Result,
+    system::{Command, ResMut, Resource},
+    world::World,
+};
+use bevy_tasks::{block_on, poll_once, IoTaskPool, Task};
+use thiserror::Error;
+
+/// Saves an entity and its descendants as a [`DynamicScene`](crate::DynamicScene) to an asset
+/// path, without blocking.
+///
+/// The entities are extracted with [`DynamicSceneBuilder::extract_entity_recursive`] when the
+/// command is applied, keeping the components allowed by `filter`, and the scene is serialized to
+/// RON. It is then written through the [`AssetWriter`](bevy_asset::io::AssetWriter) of the asset
+/// source of `path` on the [`IoTaskPool`]. A [`SceneSaved`] event is sent once the scene is
+/// written, or if saving it failed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_scene::SaveSceneCommand;
+/// # #[derive(Component)]
+/// # struct Level;
+/// fn save_level(mut commands: Commands, level: Single<Entity, With<Level>>) {
+///     commands.queue(SaveSceneCommand::new(*level, "levels/saved.scn.ron"));
+/// }
+/// ```
+pub struct SaveSceneCommand {
+    /// The root of the saved entities.
+    pub root: Entity,
+    /// The asset path the scene is written to.
+    pub path: AssetPath<'static>,
+    /// Filters the saved components.
+    pub filter: SceneFilter,
+}
+
+impl SaveSceneCommand {
+    /// Creates a command saving `root` and its descendants to `path`, with all their components.
+    pub fn new(root: Entity, path: impl Into<AssetPath<'static>>) -> Self {
+        Self {
+            root,
+            path: path.into(),
+            filter: SceneFilter::default(),
+        }
+    }
+
+    /// Returns this command with a new filter for the saved components.
+    #[must_use]
+    pub fn with_filter(mut self, filter: SceneFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+impl Command for SaveSceneCommand {
+    fn apply(self, world: &mut World) -> Result {
+        let SaveSceneCommand { root, path, filter } = self;
+        let serialized = if world.get_entity(root).is_err() {
+            Err(SceneSaveError::NonExistentEntity(root))
+        } else {
+            let scene = DynamicSceneBuilder::from_world(world)
+                .with_component_filter(filter)
+                .extract_entity_recursive(root)
+                .build();
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            scene
+                .serialize(&type_registry)
+                .map_err(SceneSaveError::from)
+        };
+        let serialized = match serialized {
+            Ok(serialized) => serialized,
+            Err(error) => {
+                world.send_event(SceneSaved {
+                    root,
+                    path,
+                    result: Err(Arc::new(error)),
+                });
+                return Ok(());
+            }
+        };
+
+        let asset_server = world.resource::<AssetServer>().clone();
+        let task = IoTaskPool::get().spawn(write_scene(asset_server, path.clone(), serialized));
+        world
+            .get_resource_or_init::<SceneSaveTasks>()
+            .0
+            .push((root, path, task));
+        Ok(())
+    }
+}
+
+async fn write_scene(
+    asset_server: AssetServer,
+    path: AssetPath<'static>,
+    serialized: String,
+) -> Result<(), SceneSaveError> {
+    asset_server
+        .get_source(path.source())?
+        .writer()?
+        .write_bytes(path.path(), serialized.as_bytes())
+        .await?;
+    Ok(())
+}
+
+/// Sent when the scene saved by a [`SaveSceneCommand`] is written, or failed to be.
+#[derive(Event, Clone, Debug)]
+pub struct SceneSaved {
+    /// The root of the saved entities.
+    pub root: Entity,
+    /// The asset path the scene was written to.
+    pub path: AssetPath<'static>,
+    /// Whether the scene was written.
+    pub result: Result<(), Arc<SceneSaveError>>,
+}
+
+/// An error that occurs when saving a scene with a [`SaveSceneCommand`].
+#[derive(Error, Debug)]
+pub enum SceneSaveError {
+    /// The root entity of the saved scene doesn't exist.
+    #[error("entity {0} does not exist")]
+    NonExistentEntity(Entity),
+    /// The scene couldn't be serialized.
+    #[error("failed to serialize the scene: {0}")]
+    Serialize(#[from] ron::Error),
+    /// The asset source of the path doesn't exist.
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    /// The asset source of the path can't be written to.
+    #[error(transparent)]
+    MissingAssetWriter(#[from] MissingAssetWriterError),
+    /// The scene couldn't be written.
+    #[error(transparent)]
+    Write(#[from] AssetWriterError),
+}
+
+/// The scenes being written by [`SaveSceneCommand`]s.
+#[derive(Resource, Default)]
+pub(crate) struct SceneSaveTasks(
+    Vec<(Entity, AssetPath<'static>, Task<Result<(), SceneSaveError>>)>,
+);
+
+/// Sends a [`SceneSaved`] event for each scene written by a [`SaveSceneCommand`] since the last
+/// run.
+pub(crate) fn poll_scene_save_tasks(
+    mut tasks: ResMut<SceneSaveTasks>,
+    mut events: EventWriter<SceneSaved>,
+) {
+    tasks.0.retain_mut(|(root, path, task)| {
+        let Some(result) = block_on(poll_once(task)) else {
+            return true;
+        };
+        events.send(SceneSaved {
+            root: *root,
+            path: path.clone(),
+            result: result.map_err(Arc::new),
+        });
+        false
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScenePlugin;
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin,
+    };
+    use bevy_ecs::event::Events;
+
+    #[test]
+    fn report_missing_writer() {
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::from("read_only"),
+            AssetSource::build().with_reader(|| {
+                Box::new(MemoryAssetReader {
+                    root: Dir::default(),
+                })
+            }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ));
+
+        let root = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .queue(SaveSceneCommand::new(root, "read_only://saved.scn.ron"));
+        let mut saved = Vec::new();
+        for _ in 0..100 {
+            app.update();
+            saved.extend(app.world_mut().resource_mut::<Events<SceneSaved>>().drain());
+            if !saved.is_empty() {
+                break;
+            }
+        }
+
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].root, root);
+        assert!(matches!(
+            saved[0].result.as_ref().map_err(|error| &**error),
+            Err(SceneSaveError::MissingAssetWriter(_))
+        ));
+    }
+}
@@ -1,15 +1,25 @@
-use crate::{DynamicScene, Scene};
-use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
+use crate::{
+    dynamic_scene::write_components, scene_patch::represented_type_path, DynamicEntity,
+    DynamicScene, InstanceOverrides, Scene, SceneParameters, SceneParams, ScenePatch,
+    SceneReference,
+};
+use bevy_asset::{AssetEvent, AssetId, AssetPath, AssetServer, Assets, Handle};
 use bevy_ecs::{
-    entity::{Entity, EntityHashMap},
+    component::ComponentInfo,
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    entity_disabling::Disabled,
     event::{Event, EventCursor, Events},
-    reflect::AppTypeRegistry,
+    reflect::{AppTypeRegistry, ReflectComponent},
     system::Resource,
     world::{Mut, World},
 };
-use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt, Parent};
-use bevy_reflect::Reflect;
-use bevy_utils::{HashMap, HashSet};
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt, Parent};
+use bevy_reflect::{
+    FromReflect, GetPath, PartialReflect, Reflect, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use bevy_utils::{HashMap, HashSet, Instant};
+use core::{any::TypeId, time::Duration};
+use log::warn;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -22,6 +32,9 @@ use bevy_ecs::{
 };
 /// Triggered on a scene's parent entity when [`crate::SceneInstance`] becomes ready to use.
 ///
+/// The entities of the instance can be looked up from their scene entity with
+/// [`SceneSpawner::instance_entity`] or [`SceneSpawner::instance_entities`].
+///
 /// See also [`Trigger`], [`SceneSpawner::instance_is_ready`].
 ///
 /// [`Trigger`]: bevy_ecs::observer::Trigger
@@ -32,13 +45,74 @@ pub struct SceneInstanceReady {
     pub instance_id: InstanceId,
 }
 
+/// Sent after each step of the spawn of a scene instance over several frames. See
+/// [`SceneSpawner::set_spawn_budget`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Event)]
+pub struct SceneSpawnProgress {
+    /// Instance being spawned.
+    pub instance_id: InstanceId,
+    /// The number of entities of the scene spawned so far.
+    pub spawned: usize,
+    /// The number of entities of the scene.
+    pub total: usize,
+}
+
 /// Information about a scene instance.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct InstanceInfo {
     /// Mapping of entities from the scene world to the instance world.
     pub entity_map: EntityHashMap<Entity>,
+    /// The types of the components written by the scene, for each scene entity. Components of
+    /// other types were added at runtime, and are kept when the scene is reloaded.
+    scene_components: SceneComponents,
+    /// The entity the instance was spawned as a child of.
+    parent: Option<Entity>,
+    /// Where the roots of the instance are inserted among the children of `parent`.
+    placement: ChildPlacement,
+    /// The values of the [`SceneParameters`] of the scene, applied again when it is reloaded.
+    params: SceneParams,
+}
+
+/// Where the roots of a scene instance spawned as a child are inserted among the children of its
+/// parent.
+#[derive(Debug, Default, Clone, Copy)]
+struct ChildPlacement {
+    /// The order in which the instance was requested, to keep sibling instances in that order.
+    order: u64,
+    /// The index requested for the roots, if any.
+    index: Option<usize>,
+}
+
+/// A scene instance being spawned over several frames. See [`SceneSpawner::set_spawn_budget`].
+struct StreamingSpawn {
+    scene: StreamedScene,
+    instance_id: InstanceId,
+    instance: InstanceInfo,
+    /// The time the entities of the scene are written for at each step.
+    budget: Duration,
+    /// The indices and ids of the entities of the scene in the order they are written, parents
+    /// first. `None` until the scene is loaded.
+    order: Option<Vec<(usize, Entity)>>,
+    /// The number of entities of `order` that were written.
+    written: usize,
+    /// The number of roots of the instance that were added to the children of its parent.
+    placed_roots: usize,
+    /// Whether the scene was modified since its entities started being written, which makes
+    /// `order` stale.
+    modified: bool,
 }
 
+/// The scene of a [`StreamingSpawn`].
+enum StreamedScene {
+    Dynamic(Handle<DynamicScene>),
+    /// A scene, converted to a dynamic scene once it's loaded so that its entities can be written
+    /// one at a time.
+    Real(Handle<Scene>, Option<DynamicScene>),
+}
+
+/// The types of the components of each entity of a scene.
+type SceneComponents = EntityHashMap<HashSet<TypeId>>;
+
 /// Unique id identifying a scene instance.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash)]
@@ -65,17 +139,45 @@ impl InstanceId {
 /// Deferred methods: (Scene operations will be processed when the [`scene_spawner_system`] is run)
 /// - [`spawn_dynamic`](Self::spawn_dynamic)
 /// - [`spawn_dynamic_as_child`](Self::spawn_dynamic_as_child)
+/// - [`spawn_dynamic_as_child_at`](Self::spawn_dynamic_as_child_at)
 /// - [`spawn`](Self::spawn)
 /// - [`spawn_as_child`](Self::spawn_as_child)
+/// - [`spawn_as_child_at`](Self::spawn_as_child_at)
+/// - [`spawn_dynamic_with_params`](Self::spawn_dynamic_with_params)
+/// - [`spawn_with_params`](Self::spawn_with_params)
+/// - [`spawn_dynamic_streaming`](Self::spawn_dynamic_streaming)
+/// - [`spawn_streaming`](Self::spawn_streaming)
+/// - [`set_spawn_budget`](Self::set_spawn_budget)
 /// - [`despawn`](Self::despawn)
 /// - [`despawn_instance`](Self::despawn_instance)
+///
+/// When a scene asset is modified, for example when it is hot reloaded, its spawned instances are
+/// patched rather than respawned: the entities and components that are still in the scene are
+/// updated in place and keep their ids, the ones that were removed from the scene are despawned
+/// or removed, and the components added at runtime are kept.
+///
+/// The root entities of an instance spawned as a child are added to the children of the parent in
+/// the order of their ids in the scene. Instances spawned as children of the same parent keep the
+/// order in which they were requested, even if their scenes finish loading in another order,
+/// unless they were requested at a given index.
 #[derive(Default, Resource)]
 pub struct SceneSpawner {
     pub(crate) spawned_dynamic_scenes: HashMap<AssetId<DynamicScene>, HashSet<InstanceId>>,
+    pub(crate) spawned_scenes: HashMap<AssetId<Scene>, HashSet<InstanceId>>,
     pub(crate) spawned_instances: HashMap<InstanceId, InstanceInfo>,
     scene_asset_event_reader: EventCursor<AssetEvent<DynamicScene>>,
-    dynamic_scenes_to_spawn: Vec<(Handle<DynamicScene>, InstanceId, Option<Entity>)>,
-    scenes_to_spawn: Vec<(Handle<Scene>, InstanceId, Option<Entity>)>,
+    real_scene_asset_event_reader: EventCursor<AssetEvent<Scene>>,
+    dynamic_scenes_to_spawn: Vec<(
+        Handle<DynamicScene>,
+        InstanceId,
+        Option<Entity>,
+        ChildPlacement,
+    )>,
+    scenes_to_spawn: Vec<(Handle<Scene>, InstanceId, Option<Entity>, ChildPlacement)>,
+    next_child_order: u64,
+    params_to_spawn: HashMap<InstanceId, SceneParams>,
+    budgets_to_spawn: HashMap<InstanceId, Duration>,
+    streaming_spawns: Vec<StreamingSpawn>,
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
@@ -133,26 +235,76 @@ pub enum SceneSpawnError {
         /// Id of the non-existent scene.
         id: AssetId<Scene>,
     },
+    /// Scene instance with the given id does not exist.
+    #[error("scene instance does not exist")]
+    NonExistentInstance {
+        /// Id of the non-existent scene instance.
+        id: InstanceId,
+    },
+    /// Scene contains a plain-data component that isn't registered in the world.
+    #[error("scene contains the plain-data component `{name}`, which isn't registered in the world. consider registering it with `ComponentDescriptor::new_plain_data`")]
+    UnregisteredPlainDataComponent {
+        /// Name of the unregistered component.
+        name: String,
+    },
+    /// Scene contains a plain-data component whose size doesn't match its registration.
+    #[error("scene contains {found} bytes for the plain-data component `{name}`, which is registered with a size of {expected} bytes")]
+    PlainDataSizeMismatch {
+        /// Name of the component.
+        name: String,
+        /// Size of the component as registered in the world.
+        expected: usize,
+        /// Size of the component in the scene.
+        found: usize,
+    },
 }
 
 impl SceneSpawner {
     /// Schedule the spawn of a new instance of the provided dynamic scene.
     pub fn spawn_dynamic(&mut self, id: impl Into<Handle<DynamicScene>>) -> InstanceId {
         let instance_id = InstanceId::new();
-        self.dynamic_scenes_to_spawn
-            .push((id.into(), instance_id, None));
+        self.dynamic_scenes_to_spawn.push((
+            id.into(),
+            instance_id,
+            None,
+            ChildPlacement::default(),
+        ));
         instance_id
     }
 
     /// Schedule the spawn of a new instance of the provided dynamic scene as a child of `parent`.
+    ///
+    /// The roots of the instance are added after the children of `parent`, but before the roots
+    /// of the instances requested later as children of `parent`.
     pub fn spawn_dynamic_as_child(
         &mut self,
         id: impl Into<Handle<DynamicScene>>,
         parent: Entity,
     ) -> InstanceId {
         let instance_id = InstanceId::new();
+        let placement = self.child_placement(None);
         self.dynamic_scenes_to_spawn
-            .push((id.into(), instance_id, Some(parent)));
+            .push((id.into(), instance_id, Some(parent), placement));
+        self.scenes_with_parent.push((instance_id, parent));
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene as a child of `parent`,
+    /// with its roots inserted at `index` in the [`Children`] of
+    /// `parent`.
+    ///
+    /// If `index` is greater than the number of children when the instance is spawned, the roots
+    /// are added after the children.
+    pub fn spawn_dynamic_as_child_at(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        parent: Entity,
+        index: usize,
+    ) -> InstanceId {
+        let instance_id = InstanceId::new();
+        let placement = self.child_placement(Some(index));
+        self.dynamic_scenes_to_spawn
+            .push((id.into(), instance_id, Some(parent), placement));
         self.scenes_with_parent.push((instance_id, parent));
         instance_id
     }
@@ -160,19 +312,126 @@ impl SceneSpawner {
     /// Schedule the spawn of a new instance of the provided scene.
     pub fn spawn(&mut self, id: impl Into<Handle<Scene>>) -> InstanceId {
         let instance_id = InstanceId::new();
-        self.scenes_to_spawn.push((id.into(), instance_id, None));
+        self.scenes_to_spawn
+            .push((id.into(), instance_id, None, ChildPlacement::default()));
         instance_id
     }
 
     /// Schedule the spawn of a new instance of the provided scene as a child of `parent`.
+    ///
+    /// The roots of the instance are added after the children of `parent`, but before the roots
+    /// of the instances requested later as children of `parent`.
     pub fn spawn_as_child(&mut self, id: impl Into<Handle<Scene>>, parent: Entity) -> InstanceId {
         let instance_id = InstanceId::new();
+        let placement = self.child_placement(None);
         self.scenes_to_spawn
-            .push((id.into(), instance_id, Some(parent)));
+            .push((id.into(), instance_id, Some(parent), placement));
         self.scenes_with_parent.push((instance_id, parent));
         instance_id
     }
 
+    /// Schedule the spawn of a new instance of the provided scene as a child of `parent`, with its
+    /// roots inserted at `index` in the [`Children`] of `parent`.
+    ///
+    /// If `index` is greater than the number of children when the instance is spawned, the roots
+    /// are added after the children.
+    pub fn spawn_as_child_at(
+        &mut self,
+        id: impl Into<Handle<Scene>>,
+        parent: Entity,
+        index: usize,
+    ) -> InstanceId {
+        let instance_id = InstanceId::new();
+        let placement = self.child_placement(Some(index));
+        self.scenes_to_spawn
+            .push((id.into(), instance_id, Some(parent), placement));
+        self.scenes_with_parent.push((instance_id, parent));
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene, with the values of its
+    /// [`SceneParameters`] given by `params`.
+    ///
+    /// The values are applied right after the instance is spawned, and again each time it is
+    /// patched after the scene is reloaded.
+    pub fn spawn_dynamic_with_params(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        params: SceneParams,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic(id);
+        self.params_to_spawn.insert(instance_id, params);
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided scene, with the values of its
+    /// [`SceneParameters`] given by `params`.
+    ///
+    /// The values are applied right after the instance is spawned, and again each time it is
+    /// patched after the scene is reloaded.
+    pub fn spawn_with_params(
+        &mut self,
+        id: impl Into<Handle<Scene>>,
+        params: SceneParams,
+    ) -> InstanceId {
+        let instance_id = self.spawn(id);
+        self.params_to_spawn.insert(instance_id, params);
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene over several frames,
+    /// writing its entities for at most `budget` each time the [`scene_spawner_system`] runs.
+    ///
+    /// See [`set_spawn_budget`](Self::set_spawn_budget).
+    pub fn spawn_dynamic_streaming(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        budget: Duration,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic(id);
+        self.set_spawn_budget(instance_id, budget);
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided scene over several frames, writing
+    /// its entities for at most `budget` each time the [`scene_spawner_system`] runs.
+    ///
+    /// See [`set_spawn_budget`](Self::set_spawn_budget).
+    pub fn spawn_streaming(
+        &mut self,
+        id: impl Into<Handle<Scene>>,
+        budget: Duration,
+    ) -> InstanceId {
+        let instance_id = self.spawn(id);
+        self.set_spawn_budget(instance_id, budget);
+        instance_id
+    }
+
+    /// Makes a scheduled instance spawn over several frames, writing its entities for at most
+    /// `budget` each time the [`scene_spawner_system`] runs. This works with instances scheduled
+    /// by any of the deferred spawn methods, including as a child or with parameters.
+    ///
+    /// This avoids the frame hitch of spawning a large scene at once. All the entities of the
+    /// scene are reserved [`Disabled`] when the scene is loaded, so that components can reference
+    /// entities that are written later without queries seeing empty entities. The entities are
+    /// then written parents first, each enabled and added to the children of its parent as it is
+    /// written, so that the hierarchy is consistent after every step. The [`SceneParams`] of the
+    /// instance and the [`InstanceOverrides`] of its parent are applied to each entity as it is
+    /// written. At least one entity is written at each step.
+    ///
+    /// A [`SceneSpawnProgress`] event is sent after each step, and [`SceneInstanceReady`] is
+    /// triggered once all the entities and the resources of the scene are written. If the scene
+    /// is modified while it is being spawned, its remaining entities are written at once.
+    pub fn set_spawn_budget(&mut self, instance_id: InstanceId, budget: Duration) {
+        self.budgets_to_spawn.insert(instance_id, budget);
+    }
+
+    fn child_placement(&mut self, index: Option<usize>) -> ChildPlacement {
+        let order = self.next_child_order;
+        self.next_child_order += 1;
+        ChildPlacement { order, index }
+    }
+
     /// Schedule the despawn of all instances of the provided dynamic scene.
     pub fn despawn(&mut self, id: impl Into<AssetId<DynamicScene>>) {
         self.scenes_to_despawn.push(id.into());
@@ -199,13 +458,19 @@ impl SceneSpawner {
 
     /// Immediately despawns a scene instance, removing all its entities from the world.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
+        for instance_ids in self.spawned_scenes.values_mut() {
+            instance_ids.remove(instance_id);
+        }
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
-            for &entity in instance.entity_map.values() {
-                if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
-                    entity_mut.remove_parent();
-                    entity_mut.despawn_recursive();
-                };
-            }
+            despawn_instance_entities(world, &instance);
+        }
+        if let Some(index) = self
+            .streaming_spawns
+            .iter()
+            .position(|streaming| streaming.instance_id == *instance_id)
+        {
+            let streaming = self.streaming_spawns.remove(index);
+            despawn_instance_entities(world, &streaming.instance);
         }
     }
 
@@ -215,28 +480,46 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<InstanceId, SceneSpawnError> {
-        let mut entity_map = EntityHashMap::default();
+        let mut instance = InstanceInfo::default();
         let id = id.into();
-        Self::spawn_dynamic_internal(world, id, &mut entity_map)?;
+        Self::spawn_dynamic_internal(world, id, &mut instance)?;
         let instance_id = InstanceId::new();
-        self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+        self.spawned_instances.insert(instance_id, instance);
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
         Ok(instance_id)
     }
 
+    /// Writes the dynamic scene to `instance`, patching it if it was already spawned.
     fn spawn_dynamic_internal(
         world: &mut World,
         id: AssetId<DynamicScene>,
-        entity_map: &mut EntityHashMap<Entity>,
+        instance: &mut InstanceInfo,
     ) -> Result<(), SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
             let scene = scenes
                 .get(id)
                 .ok_or(SceneSpawnError::NonExistentScene { id })?;
 
-            scene.write_to_world(world, entity_map)
+            let mut scene_components = SceneComponents::default();
+            for scene_entity in &scene.entities {
+                scene_components.insert(
+                    scene_entity.entity,
+                    scene_entity
+                        .components
+                        .iter()
+                        .filter_map(|component| component.get_represented_type_info())
+                        .map(TypeInfo::type_id)
+                        .collect(),
+                );
+            }
+            remove_stale_entities_and_components(world, instance, &scene_components);
+
+            scene.write_to_world(world, &mut instance.entity_map)?;
+            instance.scene_components = scene_components;
+            apply_scene_params(world, instance, None);
+            apply_instance_overrides(world, instance, None);
+            Ok(())
         })
     }
 
@@ -246,46 +529,73 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<Scene>>,
     ) -> Result<InstanceId, SceneSpawnError> {
-        let mut entity_map = EntityHashMap::default();
+        let mut instance = InstanceInfo::default();
         let id = id.into();
-        Self::spawn_sync_internal(world, id, &mut entity_map)?;
+        Self::spawn_sync_internal(world, id, &mut instance)?;
         let instance_id = InstanceId::new();
-        self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+        self.spawned_instances.insert(instance_id, instance);
+        let spawned = self.spawned_scenes.entry(id).or_default();
+        spawned.insert(instance_id);
         Ok(instance_id)
     }
 
+    /// Writes the scene to `instance`, patching it if it was already spawned.
     fn spawn_sync_internal(
         world: &mut World,
         id: AssetId<Scene>,
-        entity_map: &mut EntityHashMap<Entity>,
+        instance: &mut InstanceInfo,
     ) -> Result<(), SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<Scene>>| {
             let scene = scenes
                 .get(id)
                 .ok_or(SceneSpawnError::NonExistentRealScene { id })?;
 
+            let mut scene_components = SceneComponents::default();
+            for archetype in scene.world.archetypes().iter() {
+                let types: HashSet<TypeId> = archetype
+                    .components()
+                    .filter_map(|component_id| scene.world.components().get_info(component_id))
+                    .filter_map(ComponentInfo::type_id)
+                    .collect();
+                for scene_entity in archetype.entities() {
+                    scene_components.insert(scene_entity.id(), types.clone());
+                }
+            }
+            remove_stale_entities_and_components(world, instance, &scene_components);
+
             scene.write_to_world_with(
                 world,
-                entity_map,
+                &mut instance.entity_map,
                 &world.resource::<AppTypeRegistry>().clone(),
-            )
+            )?;
+            instance.scene_components = scene_components;
+            apply_scene_params(world, instance, None);
+            apply_instance_overrides(world, instance, None);
+            Ok(())
         })
     }
 
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    /// The instances are patched: see [`SceneSpawner`].
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
+        let instances_by_parent = instances_by_parent(&self.spawned_instances);
         for id in scene_ids {
             if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
                 for instance_id in spawned_instances {
                     if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
+                        Self::spawn_dynamic_internal(world, *id, instance_info)?;
+                        parent_instance_roots(
+                            world,
+                            &self.spawned_instances,
+                            &instances_by_parent,
+                            *instance_id,
+                        );
                     }
                 }
             }
@@ -293,6 +603,80 @@ impl SceneSpawner {
         Ok(())
     }
 
+    /// Like [`update_spawned_scenes`](Self::update_spawned_scenes), for [`Scene`]s.
+    pub fn update_spawned_real_scenes(
+        &mut self,
+        world: &mut World,
+        scene_ids: &[AssetId<Scene>],
+    ) -> Result<(), SceneSpawnError> {
+        let instances_by_parent = instances_by_parent(&self.spawned_instances);
+        for id in scene_ids {
+            if let Some(spawned_instances) = self.spawned_scenes.get(id) {
+                for instance_id in spawned_instances {
+                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
+                        Self::spawn_sync_internal(world, *id, instance_info)?;
+                        parent_instance_roots(
+                            world,
+                            &self.spawned_instances,
+                            &instances_by_parent,
+                            *instance_id,
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Immediately applies a [`ScenePatch`] to a spawned scene instance.
+    ///
+    /// The patched entities and components are treated as part of the scene: if the scene asset
+    /// is modified afterwards, the instance is patched to match the new scene, which undoes the
+    /// changes made by this patch.
+    pub fn apply_patch_sync(
+        &mut self,
+        world: &mut World,
+        instance_id: InstanceId,
+        patch: &ScenePatch,
+    ) -> Result<(), SceneSpawnError> {
+        let instance = self
+            .spawned_instances
+            .get_mut(&instance_id)
+            .ok_or(SceneSpawnError::NonExistentInstance { id: instance_id })?;
+        patch.write_to_world(world, &mut instance.entity_map)?;
+
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        for entity in &patch.removed_entities {
+            instance.scene_components.remove(entity);
+        }
+        for entity_patch in &patch.entities {
+            let components = instance
+                .scene_components
+                .entry(entity_patch.entity)
+                .or_default();
+            for type_path in &entity_patch.removed_components {
+                if let Some(registration) = type_registry.get_with_type_path(type_path) {
+                    components.remove(&registration.type_id());
+                }
+            }
+            components.extend(entity_patch.components.iter().filter_map(|component| {
+                type_registry
+                    .get_with_type_path(represented_type_path(component.as_partial_reflect()))
+                    .map(TypeRegistration::type_id)
+            }));
+        }
+
+        let instances_by_parent = instances_by_parent(&self.spawned_instances);
+        parent_instance_roots(
+            world,
+            &self.spawned_instances,
+            &instances_by_parent,
+            instance_id,
+        );
+        Ok(())
+    }
+
     /// Immediately despawns all scenes scheduled for despawn by despawning their instances.
     pub fn despawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_despawn = core::mem::take(&mut self.scenes_to_despawn);
@@ -316,13 +700,30 @@ impl SceneSpawner {
     pub fn spawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_spawn = core::mem::take(&mut self.dynamic_scenes_to_spawn);
 
-        for (handle, instance_id, parent) in scenes_to_spawn {
-            let mut entity_map = EntityHashMap::default();
+        for (handle, instance_id, parent, placement) in scenes_to_spawn {
+            let mut instance = InstanceInfo {
+                parent,
+                placement,
+                params: self
+                    .params_to_spawn
+                    .remove(&instance_id)
+                    .unwrap_or_default(),
+                ..Default::default()
+            };
+
+            if let Some(budget) = self.budgets_to_spawn.remove(&instance_id) {
+                self.streaming_spawns.push(StreamingSpawn::new(
+                    StreamedScene::Dynamic(handle),
+                    instance_id,
+                    instance,
+                    budget,
+                ));
+                continue;
+            }
 
-            match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
+            match Self::spawn_dynamic_internal(world, handle.id(), &mut instance) {
                 Ok(_) => {
-                    self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                    self.spawned_instances.insert(instance_id, instance);
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(handle.id())
@@ -337,8 +738,9 @@ impl SceneSpawner {
                     }
                 }
                 Err(SceneSpawnError::NonExistentScene { .. }) => {
+                    self.params_to_spawn.insert(instance_id, instance.params);
                     self.dynamic_scenes_to_spawn
-                        .push((handle, instance_id, parent));
+                        .push((handle, instance_id, parent, placement));
                 }
                 Err(err) => return Err(err),
             }
@@ -346,13 +748,32 @@ impl SceneSpawner {
 
         let scenes_to_spawn = core::mem::take(&mut self.scenes_to_spawn);
 
-        for (scene_handle, instance_id, parent) in scenes_to_spawn {
-            let mut entity_map = EntityHashMap::default();
+        for (scene_handle, instance_id, parent, placement) in scenes_to_spawn {
+            let mut instance = InstanceInfo {
+                parent,
+                placement,
+                params: self
+                    .params_to_spawn
+                    .remove(&instance_id)
+                    .unwrap_or_default(),
+                ..Default::default()
+            };
+
+            if let Some(budget) = self.budgets_to_spawn.remove(&instance_id) {
+                self.streaming_spawns.push(StreamingSpawn::new(
+                    StreamedScene::Real(scene_handle, None),
+                    instance_id,
+                    instance,
+                    budget,
+                ));
+                continue;
+            }
 
-            match Self::spawn_sync_internal(world, scene_handle.id(), &mut entity_map) {
+            match Self::spawn_sync_internal(world, scene_handle.id(), &mut instance) {
                 Ok(_) => {
-                    self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                    self.spawned_instances.insert(instance_id, instance);
+                    let spawned = self.spawned_scenes.entry(scene_handle.id()).or_default();
+                    spawned.insert(instance_id);
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -362,8 +783,9 @@ impl SceneSpawner {
                     }
                 }
                 Err(SceneSpawnError::NonExistentRealScene { .. }) => {
+                    self.params_to_spawn.insert(instance_id, instance.params);
                     self.scenes_to_spawn
-                        .push((scene_handle, instance_id, parent));
+                        .push((scene_handle, instance_id, parent, placement));
                 }
                 Err(err) => return Err(err),
             }
@@ -372,26 +794,67 @@ impl SceneSpawner {
         Ok(())
     }
 
+    /// Writes the entities of the scenes being spawned over several frames, for at most the
+    /// budget of each.
+    ///
+    /// See [`set_spawn_budget`](Self::set_spawn_budget).
+    pub fn spawn_streaming_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
+        let streaming_spawns = core::mem::take(&mut self.streaming_spawns);
+        if streaming_spawns.is_empty() {
+            return Ok(());
+        }
+
+        let instances_by_parent = instances_by_parent(&self.spawned_instances);
+        for mut streaming in streaming_spawns {
+            if !streaming.step(world, &self.spawned_instances, &instances_by_parent)? {
+                self.streaming_spawns.push(streaming);
+                continue;
+            }
+            let StreamingSpawn {
+                scene,
+                instance_id,
+                instance,
+                ..
+            } = streaming;
+            let parent = instance.parent;
+            self.spawned_instances.insert(instance_id, instance);
+            match scene {
+                StreamedScene::Dynamic(handle) => self
+                    .spawned_dynamic_scenes
+                    .entry(handle.id())
+                    .or_default()
+                    .insert(instance_id),
+                StreamedScene::Real(handle, _) => self
+                    .spawned_scenes
+                    .entry(handle.id())
+                    .or_default()
+                    .insert(instance_id),
+            };
+
+            // Scenes with parents are ready once `set_scene_instance_parent_sync()` sees them.
+            if parent.is_none() {
+                // Defer via commands otherwise SceneSpawner is not available in the observer.
+                world.commands().trigger(SceneInstanceReady { instance_id });
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
         let scenes_with_parent = core::mem::take(&mut self.scenes_with_parent);
+        if scenes_with_parent.is_empty() {
+            return;
+        }
 
+        let instances_by_parent = instances_by_parent(&self.spawned_instances);
         for (instance_id, parent) in scenes_with_parent {
-            if let Some(instance) = self.spawned_instances.get(&instance_id) {
-                for &entity in instance.entity_map.values() {
-                    // Add the `Parent` component to the scene root, and update the `Children` component of
-                    // the scene parent
-                    if !world
-                        .get_entity(entity)
-                        .ok()
-                        // This will filter only the scene root entity, as all other from the
-                        // scene have a parent
-                        // Entities that wouldn't exist anymore are also skipped
-                        // this case shouldn't happen anyway
-                        .is_none_or(|entity| entity.contains::<Parent>())
-                    {
-                        world.entity_mut(parent).add_child(entity);
-                    }
-                }
+            if self.spawned_instances.contains_key(&instance_id) {
+                parent_instance_roots(
+                    world,
+                    &self.spawned_instances,
+                    &instances_by_parent,
+                    instance_id,
+                );
 
                 // Defer via commands otherwise SceneSpawner is not available in the observer.
                 world
@@ -403,6 +866,52 @@ impl SceneSpawner {
         }
     }
 
+    /// Spawns the scenes referenced by [`SceneReference`]s that haven't been resolved yet, as
+    /// children of the referencing entities.
+    ///
+    /// Returns `true` if any reference was resolved.
+    fn resolve_scene_references(&mut self, world: &mut World) -> bool {
+        let unresolved: Vec<(Entity, AssetPath<'static>)> = world
+            .query::<(Entity, &SceneReference)>()
+            .iter(world)
+            .filter(|(_, reference)| reference.handle.is_none())
+            .map(|(entity, reference)| (entity, reference.path.clone()))
+            .collect();
+        if unresolved.is_empty() {
+            return false;
+        }
+        let Some(asset_server) = world.get_resource::<AssetServer>().cloned() else {
+            return false;
+        };
+
+        for (entity, path) in unresolved {
+            let handle = asset_server.load::<DynamicScene>(path.clone());
+            let mut is_cycle = false;
+            let mut current = entity;
+            while let Some(parent) = world.get::<Parent>(current) {
+                current = parent.get();
+                if world
+                    .get::<SceneReference>(current)
+                    .is_some_and(|reference| reference.path == path)
+                {
+                    is_cycle = true;
+                    break;
+                }
+            }
+            if let Some(mut reference) = world.get_mut::<SceneReference>(entity) {
+                reference.handle = Some(handle.clone());
+            }
+            if is_cycle {
+                warn!("Scene `{path}` references itself, it won't be spawned again below itself");
+                continue;
+            }
+
+            let instance_id = self.spawn_dynamic_as_child(handle, entity);
+            world.entity_mut(entity).insert(SceneInstance(instance_id));
+        }
+        true
+    }
+
     /// Check that an scene instance spawned previously is ready to use
     pub fn instance_is_ready(&self, instance_id: InstanceId) -> bool {
         self.spawned_instances.contains_key(&instance_id)
@@ -423,6 +932,552 @@ impl SceneSpawner {
             .flatten()
             .copied()
     }
+
+    /// Returns the mapping from the entities of the scene to the entities of an instance, once
+    /// it's spawned.
+    ///
+    /// This lets post-spawn fixup code, like an observer of [`SceneInstanceReady`], find specific
+    /// entities of the scene without searching them by [`Name`](bevy_ecs::name::Name).
+    pub fn instance_entities(&self, instance_id: InstanceId) -> Option<&EntityHashMap<Entity>> {
+        self.spawned_instances
+            .get(&instance_id)
+            .map(|instance| &instance.entity_map)
+    }
+
+    /// Returns the entity spawned for the scene entity `scene_entity` in an instance, once it's
+    /// spawned. See [`instance_entities`](Self::instance_entities).
+    pub fn instance_entity(&self, instance_id: InstanceId, scene_entity: Entity) -> Option<Entity> {
+        self.instance_entities(instance_id)?
+            .get(&scene_entity)
+            .copied()
+    }
+}
+
+/// Groups the instances spawned as children of an entity by that entity.
+fn instances_by_parent(
+    instances: &HashMap<InstanceId, InstanceInfo>,
+) -> EntityHashMap<Vec<InstanceId>> {
+    let mut instances_by_parent = EntityHashMap::<Vec<InstanceId>>::default();
+    for (&instance_id, instance) in instances {
+        if let Some(parent) = instance.parent {
+            instances_by_parent
+                .entry(parent)
+                .or_default()
+                .push(instance_id);
+        }
+    }
+    instances_by_parent
+}
+
+/// Adds the root entities of the instance `instance_id` as children of the entity it was spawned
+/// as a child of, if any, at the position given by its [`ChildPlacement`].
+///
+/// `instances_by_parent` is built by [`instances_by_parent`] from `instances`.
+fn parent_instance_roots(
+    world: &mut World,
+    instances: &HashMap<InstanceId, InstanceInfo>,
+    instances_by_parent: &EntityHashMap<Vec<InstanceId>>,
+    instance_id: InstanceId,
+) {
+    let Some(instance) = instances.get(&instance_id) else {
+        return;
+    };
+    let Some(parent) = instance.parent else {
+        return;
+    };
+    if world.get_entity(parent).is_err() {
+        return;
+    }
+
+    // Only the scene roots don't have a parent yet, as all the other entities from the scene are
+    // children of a scene entity. Entities that don't exist anymore are skipped too.
+    let mut roots: Vec<_> = instance
+        .entity_map
+        .iter()
+        .filter(|(_, &entity)| {
+            world
+                .get_entity(entity)
+                .is_ok_and(|entity| !entity.contains::<Parent>())
+        })
+        .map(|(&scene_entity, &entity)| (scene_entity, entity))
+        .collect();
+    if roots.is_empty() {
+        return;
+    }
+    roots.sort_unstable_by_key(|&(scene_entity, _)| scene_entity);
+    let roots: Vec<_> = roots.into_iter().map(|(_, entity)| entity).collect();
+    insert_instance_roots(world, instances, instances_by_parent, instance, &roots, 0);
+}
+
+/// Inserts `roots`, root entities of `instance`, among the children of the entity it was spawned
+/// as a child of, at the position given by its [`ChildPlacement`]. `placed` roots of the instance
+/// were already inserted before them.
+///
+/// `instances_by_parent` is built by [`instances_by_parent`] from `instances`, which doesn't need
+/// to contain `instance`.
+fn insert_instance_roots(
+    world: &mut World,
+    instances: &HashMap<InstanceId, InstanceInfo>,
+    instances_by_parent: &EntityHashMap<Vec<InstanceId>>,
+    instance: &InstanceInfo,
+    roots: &[Entity],
+    placed: usize,
+) {
+    let Some(parent) = instance.parent else {
+        return;
+    };
+    if roots.is_empty() || world.get_entity(parent).is_err() {
+        return;
+    }
+
+    let children = world
+        .get::<Children>(parent)
+        .map_or(&[][..], |children| &**children);
+    let index = match instance.placement.index {
+        Some(index) => (index + placed).min(children.len()),
+        None => {
+            // Keep the instances requested later, but spawned earlier, after this one.
+            let later_siblings: EntityHashSet = instances_by_parent
+                .get(&parent)
+                .into_iter()
+                .flatten()
+                .filter_map(|sibling_id| instances.get(sibling_id))
+                .filter(|sibling| {
+                    sibling.placement.index.is_none()
+                        && sibling.placement.order > instance.placement.order
+                })
+                .flat_map(|sibling| sibling.entity_map.values().copied())
+                .collect();
+            children
+                .iter()
+                .position(|child| later_siblings.contains(child))
+                .unwrap_or(children.len())
+        }
+    };
+    world.entity_mut(parent).insert_children(index, roots);
+}
+
+impl StreamingSpawn {
+    fn new(
+        scene: StreamedScene,
+        instance_id: InstanceId,
+        instance: InstanceInfo,
+        budget: Duration,
+    ) -> Self {
+        Self {
+            scene,
+            instance_id,
+            instance,
+            budget,
+            order: None,
+            written: 0,
+            placed_roots: 0,
+            modified: false,
+        }
+    }
+
+    /// Writes entities of the scene for at most the budget, and returns `true` once the whole
+    /// scene is written.
+    ///
+    /// `instances` and `instances_by_parent` are used to place the roots of the instance among
+    /// the instances spawned as children of the same parent.
+    fn step(
+        &mut self,
+        world: &mut World,
+        instances: &HashMap<InstanceId, InstanceInfo>,
+        instances_by_parent: &EntityHashMap<Vec<InstanceId>>,
+    ) -> Result<bool, SceneSpawnError> {
+        if self.modified {
+            // The entities that weren't written yet are despawned if they are no longer in the
+            // scene, and written with the others otherwise.
+            let order = self.order.as_deref().unwrap_or_default();
+            for &(_, scene_entity) in &order[self.written..] {
+                self.instance
+                    .scene_components
+                    .entry(scene_entity)
+                    .or_default();
+                let entity = self.instance.entity_map[&scene_entity];
+                if let Ok(mut entity) = world.get_entity_mut(entity) {
+                    entity.remove::<Disabled>();
+                }
+            }
+            match &self.scene {
+                StreamedScene::Dynamic(handle) => {
+                    SceneSpawner::spawn_dynamic_internal(world, handle.id(), &mut self.instance)?;
+                }
+                StreamedScene::Real(handle, _) => {
+                    SceneSpawner::spawn_sync_internal(world, handle.id(), &mut self.instance)?;
+                }
+            }
+            let total = self.instance.scene_components.len();
+            world.send_event(SceneSpawnProgress {
+                instance_id: self.instance_id,
+                spawned: total,
+                total,
+            });
+            return Ok(true);
+        }
+
+        match &mut self.scene {
+            StreamedScene::Dynamic(handle) => {
+                let id = handle.id();
+                world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                    let Some(scene) = scenes.get(id) else {
+                        return Ok(false);
+                    };
+                    self.write(world, scene, instances, instances_by_parent)
+                })
+            }
+            StreamedScene::Real(handle, converted) => {
+                let scene = match converted.take() {
+                    Some(scene) => scene,
+                    None => {
+                        let Some(scene) = world.resource::<Assets<Scene>>().get(handle.id()) else {
+                            return Ok(false);
+                        };
+                        let type_registry = world.resource::<AppTypeRegistry>().read();
+                        scene.to_dynamic_scene(&type_registry)?
+                    }
+                };
+                let done = self.write(world, &scene, instances, instances_by_parent);
+                if let StreamedScene::Real(_, converted) = &mut self.scene {
+                    *converted = Some(scene);
+                }
+                done
+            }
+        }
+    }
+
+    /// Writes entities of `scene` for at most the budget, and returns `true` once the whole scene
+    /// is written.
+    fn write(
+        &mut self,
+        world: &mut World,
+        scene: &DynamicScene,
+        instances: &HashMap<InstanceId, InstanceInfo>,
+        instances_by_parent: &EntityHashMap<Vec<InstanceId>>,
+    ) -> Result<bool, SceneSpawnError> {
+        let start = Instant::now();
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let instance = &mut self.instance;
+        let order = self.order.get_or_insert_with(|| {
+            for scene_entity in &scene.entities {
+                instance
+                    .entity_map
+                    .entry(scene_entity.entity)
+                    .or_insert_with(|| world.spawn(Disabled).id());
+            }
+            hierarchy_order(scene)
+        });
+
+        let mut written_entities = EntityHashSet::default();
+        let mut roots = Vec::new();
+        while let Some(&(index, _)) = order.get(self.written) {
+            let scene_entity = &scene.entities[index];
+            let entity = instance.entity_map[&scene_entity.entity];
+            world.entity_mut(entity).remove::<Disabled>();
+            // The hierarchy is built as the entities are written rather than from the scene,
+            // whose `Children` reference entities that aren't written yet.
+            write_components(
+                world,
+                entity,
+                scene_entity.components.iter().filter(|component| {
+                    !is_component::<Parent>(component.as_partial_reflect())
+                        && !is_component::<Children>(component.as_partial_reflect())
+                }),
+                &mut instance.entity_map,
+                &type_registry,
+            )?;
+            match scene_parent(scene_entity)
+                .filter(|parent| instance.scene_components.contains_key(parent))
+            {
+                Some(parent) => {
+                    let parent = instance.entity_map[&parent];
+                    world.entity_mut(parent).add_child(entity);
+                }
+                None => roots.push(entity),
+            }
+            instance.scene_components.insert(
+                scene_entity.entity,
+                scene_entity
+                    .components
+                    .iter()
+                    .filter_map(|component| component.get_represented_type_info())
+                    .map(TypeInfo::type_id)
+                    .collect(),
+            );
+            written_entities.insert(scene_entity.entity);
+            self.written += 1;
+            if start.elapsed() >= self.budget {
+                break;
+            }
+        }
+
+        insert_instance_roots(
+            world,
+            instances,
+            instances_by_parent,
+            instance,
+            &roots,
+            self.placed_roots,
+        );
+        self.placed_roots += roots.len();
+        apply_scene_params(world, instance, Some(&written_entities));
+        apply_instance_overrides(world, instance, Some(&written_entities));
+
+        let done = self.written == order.len();
+        if done {
+            // Children were added in the order they were written: the `Children` of the scene
+            // restore their order.
+            for scene_entity in &scene.entities {
+                write_components(
+                    world,
+                    instance.entity_map[&scene_entity.entity],
+                    scene_entity.components.iter().filter(|component| {
+                        is_component::<Children>(component.as_partial_reflect())
+                    }),
+                    &mut instance.entity_map,
+                    &type_registry,
+                )?;
+            }
+            scene.write_resources(world, &mut instance.entity_map, &type_registry)?;
+        }
+        world.send_event(SceneSpawnProgress {
+            instance_id: self.instance_id,
+            spawned: self.written,
+            total: order.len(),
+        });
+        Ok(done)
+    }
+}
+
+/// Returns the indices and ids of the entities of `scene`, parents before their children.
+///
+/// Entities whose parent isn't in the scene are roots. Siblings keep the order of the scene.
+fn hierarchy_order(scene: &DynamicScene) -> Vec<(usize, Entity)> {
+    let scene_entities: EntityHashSet = scene
+        .entities
+        .iter()
+        .map(|scene_entity| scene_entity.entity)
+        .collect();
+    let mut children = EntityHashMap::<Vec<usize>>::default();
+    let mut roots = Vec::new();
+    for (index, scene_entity) in scene.entities.iter().enumerate() {
+        match scene_parent(scene_entity).filter(|parent| scene_entities.contains(parent)) {
+            Some(parent) => children.entry(parent).or_default().push(index),
+            None => roots.push(index),
+        }
+    }
+
+    let mut order = Vec::with_capacity(scene.entities.len());
+    let mut stack: Vec<usize> = roots.into_iter().rev().collect();
+    loop {
+        while let Some(index) = stack.pop() {
+            let entity = scene.entities[index].entity;
+            order.push((index, entity));
+            if let Some(children) = children.remove(&entity) {
+                stack.extend(children.into_iter().rev());
+            }
+        }
+        // Entities in a parent cycle can't be reached from a root.
+        let Some(&entity) = children.keys().next() else {
+            break;
+        };
+        stack.extend(children.remove(&entity).unwrap().into_iter().rev());
+    }
+    order
+}
+
+/// Returns the parent of `scene_entity` in the scene, if it has a [`Parent`] component.
+fn scene_parent(scene_entity: &DynamicEntity) -> Option<Entity> {
+    scene_entity
+        .components
+        .iter()
+        .find(|component| is_component::<Parent>(component.as_partial_reflect()))
+        .and_then(|component| Parent::from_reflect(component.as_partial_reflect()))
+        .map(|parent| parent.get())
+}
+
+/// Returns `true` if `component` represents a `C`.
+fn is_component<C: Component>(component: &dyn PartialReflect) -> bool {
+    component
+        .get_represented_type_info()
+        .is_some_and(|type_info| type_info.type_id() == TypeId::of::<C>())
+}
+
+/// Despawns the entities of `instance`, with their descendants.
+fn despawn_instance_entities(world: &mut World, instance: &InstanceInfo) {
+    for &entity in instance.entity_map.values() {
+        if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+            entity_mut.remove_parent();
+            entity_mut.despawn_recursive();
+        };
+    }
+}
+
+/// Applies the [`InstanceOverrides`] of the entity `instance` was spawned as a child of, if any.
+///
+/// If `scene_entities` is given, only the overrides of these scene entities are applied.
+fn apply_instance_overrides(
+    world: &mut World,
+    instance: &InstanceInfo,
+    scene_entities: Option<&EntityHashSet>,
+) {
+    let Some(overrides) = instance
+        .parent
+        .and_then(|parent| world.get::<InstanceOverrides>(parent))
+    else {
+        return;
+    };
+    // Cloned, as the overridden entities are accessed mutably.
+    let overrides: Vec<_> = overrides
+        .iter()
+        .filter(|instance_override| {
+            scene_entities
+                .is_none_or(|scene_entities| scene_entities.contains(&instance_override.entity))
+        })
+        .map(|instance_override| {
+            (
+                instance_override.entity,
+                instance_override.component.clone(),
+                instance_override.path.clone(),
+                instance_override.value.clone_value(),
+            )
+        })
+        .collect();
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    for (scene_entity, component, path, value) in overrides {
+        let Some(&entity) = instance.entity_map.get(&scene_entity) else {
+            warn!("Skipping the override of {scene_entity}, which isn't in the scene instance");
+            continue;
+        };
+        if let Err(error) = apply_at_path(
+            world,
+            &type_registry,
+            entity,
+            &component,
+            &path,
+            value.as_ref(),
+        ) {
+            warn!("Skipping the override of `{component}` on {scene_entity}: {error}");
+        }
+    }
+}
+
+/// Applies the values of the [`SceneParams`] of `instance` to the fields exposed by the
+/// [`SceneParameters`] of its entities.
+///
+/// If `scene_entities` is given, only the entities spawned for these scene entities are updated.
+fn apply_scene_params(
+    world: &mut World,
+    instance: &InstanceInfo,
+    scene_entities: Option<&EntityHashSet>,
+) {
+    if instance.params.is_empty() {
+        return;
+    }
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let entities = instance
+        .entity_map
+        .iter()
+        .filter(|(scene_entity, _)| {
+            scene_entities.is_none_or(|scene_entities| scene_entities.contains(*scene_entity))
+        })
+        .map(|(_, &entity)| entity);
+    for entity in entities {
+        // Cloned, as the entity is accessed mutably.
+        let Some(parameters) = world.get::<SceneParameters>(entity).cloned() else {
+            continue;
+        };
+        for parameter in parameters.parameters {
+            let Some(value) = instance.params.get(&parameter.name) else {
+                continue;
+            };
+            if let Err(error) = apply_at_path(
+                world,
+                &type_registry,
+                entity,
+                &parameter.component,
+                &parameter.path,
+                value,
+            ) {
+                warn!("Skipping the scene parameter `{}`: {error}", parameter.name);
+            }
+        }
+    }
+}
+
+/// Applies `value` to the field at the reflection `path` of the component of `entity` with the
+/// type path `component`, or to the whole component if `path` is empty.
+fn apply_at_path(
+    world: &mut World,
+    type_registry: &TypeRegistry,
+    entity: Entity,
+    component: &str,
+    path: &str,
+    value: &dyn PartialReflect,
+) -> Result<(), String> {
+    let entity_mut = world
+        .get_entity_mut(entity)
+        .map_err(|_| format!("{entity} doesn't exist"))?;
+    let reflect_component = type_registry
+        .get_with_type_path(component)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .ok_or_else(|| format!("`{component}` isn't a registered component"))?;
+    let mut reflected = reflect_component
+        .reflect_mut(entity_mut)
+        .ok_or_else(|| format!("{entity} doesn't have `{component}`"))?;
+    let target = if path.is_empty() {
+        reflected.as_partial_reflect_mut()
+    } else {
+        reflected
+            .reflect_path_mut(path)
+            .map_err(|error| format!("`{path}`: {error}"))?
+    };
+    target
+        .try_apply(value)
+        .map_err(|error| format!("`{path}`: {error}"))
+}
+
+/// Despawns the entities of `instance` that are no longer in the scene, and removes the components
+/// that the scene no longer has, according to `scene_components`.
+///
+/// Components that weren't written by the scene are left untouched.
+fn remove_stale_entities_and_components(
+    world: &mut World,
+    instance: &mut InstanceInfo,
+    scene_components: &SceneComponents,
+) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let previous_components = &instance.scene_components;
+    instance.entity_map.retain(|scene_entity, &mut entity| {
+        // The map can also contain entities referenced by the scene without being part of it.
+        let Some(previous) = previous_components.get(scene_entity) else {
+            return true;
+        };
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return false;
+        };
+        let Some(current) = scene_components.get(scene_entity) else {
+            entity_mut.remove_parent();
+            entity_mut.despawn_recursive();
+            return false;
+        };
+        for type_id in previous.difference(current) {
+            if let Some(reflect_component) = type_registry
+                .get(*type_id)
+                .and_then(|registration| registration.data::<ReflectComponent>())
+            {
+                reflect_component.remove(&mut entity_mut);
+            }
+        }
+        true
+    });
 }
 
 /// System that handles scheduled scene instance spawning and despawning through a [`SceneSpawner`].
@@ -443,10 +1498,24 @@ pub fn scene_spawner_system(world: &mut World) {
             });
         scene_spawner
             .dynamic_scenes_to_spawn
-            .retain(|(_, instance, _)| !dead_instances.contains(instance));
+            .retain(|(_, instance, ..)| !dead_instances.contains(instance));
         scene_spawner
             .scenes_to_spawn
-            .retain(|(_, instance, _)| !dead_instances.contains(instance));
+            .retain(|(_, instance, ..)| !dead_instances.contains(instance));
+        scene_spawner
+            .params_to_spawn
+            .retain(|instance, _| !dead_instances.contains(instance));
+        scene_spawner
+            .budgets_to_spawn
+            .retain(|instance, _| !dead_instances.contains(instance));
+        let (dead_streaming_spawns, streaming_spawns): (Vec<_>, Vec<_>) =
+            core::mem::take(&mut scene_spawner.streaming_spawns)
+                .into_iter()
+                .partition(|streaming| dead_instances.contains(&streaming.instance_id));
+        scene_spawner.streaming_spawns = streaming_spawns;
+        for streaming in dead_streaming_spawns {
+            despawn_instance_entities(world, &streaming.instance);
+        }
 
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
@@ -460,6 +1529,34 @@ pub fn scene_spawner_system(world: &mut World) {
                 if scene_spawner.spawned_dynamic_scenes.contains_key(id) {
                     updated_spawned_scenes.push(*id);
                 }
+                for streaming in &mut scene_spawner.streaming_spawns {
+                    if let StreamedScene::Dynamic(handle) = &streaming.scene {
+                        if handle.id() == *id && streaming.order.is_some() {
+                            streaming.modified = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        let scene_asset_events = world.resource::<Events<AssetEvent<Scene>>>();
+
+        let mut updated_spawned_real_scenes = Vec::new();
+        for event in scene_spawner
+            .real_scene_asset_event_reader
+            .read(scene_asset_events)
+        {
+            if let AssetEvent::Modified { id } = event {
+                if scene_spawner.spawned_scenes.contains_key(id) {
+                    updated_spawned_real_scenes.push(*id);
+                }
+                for streaming in &mut scene_spawner.streaming_spawns {
+                    if let StreamedScene::Real(handle, _) = &streaming.scene {
+                        if handle.id() == *id && streaming.order.is_some() {
+                            streaming.modified = true;
+                        }
+                    }
+                }
             }
         }
 
@@ -471,7 +1568,22 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .update_spawned_scenes(world, &updated_spawned_scenes)
             .unwrap();
+        scene_spawner
+            .update_spawned_real_scenes(world, &updated_spawned_real_scenes)
+            .unwrap();
+        scene_spawner
+            .spawn_streaming_scenes(world)
+            .unwrap_or_else(|err| panic!("{}", err));
         scene_spawner.set_scene_instance_parent_sync(world);
+
+        // Spawn the scenes referenced by the scenes that were just spawned, and then the ones
+        // they reference in turn, as far as they are loaded.
+        while scene_spawner.resolve_scene_references(world) {
+            scene_spawner
+                .spawn_queued_scenes(world)
+                .unwrap_or_else(|err| panic!("{}", err));
+            scene_spawner.set_scene_instance_parent_sync(world);
+        }
     });
 }
 
@@ -529,14 +1641,15 @@ mod tests {
     use crate::{DynamicSceneBuilder, DynamicSceneRoot, ScenePlugin};
 
     use super::*;
-    use crate::{DynamicScene, SceneSpawner};
-    use bevy_app::ScheduleRunnerPlugin;
+    use crate::{DynamicEntity, DynamicScene, SceneReference, SceneSpawner};
+    use bevy_app::{ScheduleRunnerPlugin, TaskPoolPlugin};
     use bevy_asset::Assets;
     use bevy_ecs::{
         entity::Entity,
         prelude::{AppTypeRegistry, World},
     };
     use bevy_hierarchy::{Children, HierarchyPlugin};
+    use bevy_reflect::PartialReflect;
 
     #[derive(Component, Reflect, Default)]
     #[reflect(Component)]
@@ -658,6 +1771,464 @@ mod tests {
     #[reflect(Component)]
     struct ComponentF;
 
+    /// Adds a dynamic scene with the given entities as the asset at `path`.
+    fn add_scene_at_path(
+        app: &mut App,
+        path: &'static str,
+        entities: Vec<DynamicEntity>,
+    ) -> Handle<DynamicScene> {
+        let handle = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<DynamicScene>(path);
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(
+                &handle,
+                DynamicScene {
+                    resources: Vec::new(),
+                    entities,
+                },
+            );
+        handle
+    }
+
+    fn scene_entity(index: u32, component: impl PartialReflect) -> DynamicEntity {
+        DynamicEntity {
+            entity: Entity::from_raw(index),
+            components: vec![Box::new(component)],
+        }
+    }
+
+    #[test]
+    fn spawn_nested_scenes() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<A>();
+        let _leaf = add_scene_at_path(&mut app, "leaf.scn.ron", vec![scene_entity(0, A(2))]);
+        let _prefab = add_scene_at_path(
+            &mut app,
+            "prefab.scn.ron",
+            vec![scene_entity(0, SceneReference::new("leaf.scn.ron"))],
+        );
+
+        let root = app
+            .world_mut()
+            .spawn(SceneReference::new("prefab.scn.ron"))
+            .id();
+        app.update();
+
+        // Both levels are spawned in the same frame.
+        let (leaf, &a) = app.world_mut().query::<(Entity, &A)>().single(app.world());
+        assert_eq!(a, A(2));
+        let prefab = app.world().get::<Parent>(leaf).unwrap().get();
+        assert!(app.world().entity(prefab).contains::<SceneReference>());
+        assert_eq!(app.world().get::<Parent>(prefab).unwrap().get(), root);
+
+        // Despawning the referencing entity despawns the nested instances.
+        app.world_mut().entity_mut(root).despawn_recursive();
+        app.update();
+        assert!(app
+            .world()
+            .resource::<SceneSpawner>()
+            .spawned_instances
+            .is_empty());
+    }
+
+    #[test]
+    fn scene_reference_cycle() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ));
+        let _cycle = add_scene_at_path(
+            &mut app,
+            "cycle.scn.ron",
+            vec![scene_entity(0, SceneReference::new("cycle.scn.ron"))],
+        );
+
+        app.world_mut().spawn(SceneReference::new("cycle.scn.ron"));
+        app.update();
+
+        // The root entity, and the entity of the scene, whose reference isn't spawned again.
+        let references = app
+            .world_mut()
+            .query::<&SceneReference>()
+            .iter(app.world())
+            .count();
+        assert_eq!(references, 2);
+    }
+
+    #[test]
+    fn reload_patches_instance() {
+        #[derive(Component)]
+        struct AddedAtRuntime;
+
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>()
+            .register_type::<ComponentF>();
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let kept = scene_world.spawn((A(1), ComponentF)).id();
+        let removed = scene_world.spawn(A(2)).id();
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene::from_world(&scene_world));
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(scene.clone());
+        app.update();
+
+        let entity_map =
+            &app.world().resource::<SceneSpawner>().spawned_instances[&instance_id].entity_map;
+        let (kept_entity, removed_entity) = (entity_map[&kept], entity_map[&removed]);
+        app.world_mut()
+            .entity_mut(kept_entity)
+            .insert(AddedAtRuntime);
+
+        // Change a component, remove another one, and remove an entity.
+        scene_world
+            .entity_mut(kept)
+            .insert(A(3))
+            .remove::<ComponentF>();
+        scene_world.despawn(removed);
+        *app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&scene)
+            .unwrap() = DynamicScene::from_world(&scene_world);
+        // The asset event is sent at the end of the first update.
+        app.update();
+        app.update();
+
+        let kept = app.world().entity(kept_entity);
+        assert_eq!(kept.get::<A>(), Some(&A(3)));
+        assert!(!kept.contains::<ComponentF>());
+        assert!(kept.contains::<AddedAtRuntime>());
+        assert!(app.world().get_entity(removed_entity).is_err());
+    }
+
+    #[test]
+    fn overrides_survive_reload() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>();
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let overridden = scene_world.spawn(A(1)).id();
+        let other = scene_world.spawn(A(2)).id();
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene::from_world(&scene_world));
+        let root = app
+            .world_mut()
+            .spawn((
+                DynamicSceneRoot(scene.clone()),
+                InstanceOverrides::default().with::<A>(overridden, ".0", 10_usize),
+            ))
+            .id();
+        app.update();
+
+        let instance_id = **app.world().get::<SceneInstance>(root).unwrap();
+        let entity_map =
+            &app.world().resource::<SceneSpawner>().spawned_instances[&instance_id].entity_map;
+        let (overridden_entity, other_entity) = (entity_map[&overridden], entity_map[&other]);
+        assert_eq!(app.world().get::<A>(overridden_entity), Some(&A(10)));
+        assert_eq!(app.world().get::<A>(other_entity), Some(&A(2)));
+
+        scene_world.entity_mut(overridden).insert(A(3));
+        scene_world.entity_mut(other).insert(A(4));
+        *app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&scene)
+            .unwrap() = DynamicScene::from_world(&scene_world);
+        // The asset event is sent at the end of the first update.
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().get::<A>(overridden_entity), Some(&A(10)));
+        assert_eq!(app.world().get::<A>(other_entity), Some(&A(4)));
+    }
+
+    #[test]
+    fn spawn_with_params() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>();
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let exposed = scene_world
+            .spawn((
+                A(1),
+                SceneParameters::default().with::<A>("difficulty", ".0"),
+            ))
+            .id();
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene::from_world(&scene_world));
+
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        let default_id = scene_spawner.spawn_dynamic(scene.clone());
+        let hard_id = scene_spawner.spawn_dynamic_with_params(
+            scene.clone(),
+            SceneParams::default().with("difficulty", 3_usize),
+        );
+        app.update();
+
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        let default_entity = scene_spawner.instance_entity(default_id, exposed).unwrap();
+        let hard_entity = scene_spawner.instance_entity(hard_id, exposed).unwrap();
+        assert_eq!(app.world().get::<A>(default_entity), Some(&A(1)));
+        assert_eq!(app.world().get::<A>(hard_entity), Some(&A(3)));
+
+        // The value is applied again after the scene is reloaded.
+        scene_world.entity_mut(exposed).insert(A(2));
+        *app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&scene)
+            .unwrap() = DynamicScene::from_world(&scene_world);
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().get::<A>(default_entity), Some(&A(2)));
+        assert_eq!(app.world().get::<A>(hard_entity), Some(&A(3)));
+    }
+
+    #[test]
+    fn spawn_streaming() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), HierarchyPlugin, ScenePlugin))
+            .register_type::<A>();
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let root = scene_world.spawn(A(0)).id();
+        for index in 1..4 {
+            scene_world.spawn(A(index)).set_parent(root);
+        }
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene::from_world(&scene_world));
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic_streaming(scene, Duration::ZERO);
+
+        // With no budget, a single entity is written at each step.
+        for spawned in 1..=4 {
+            app.update();
+            let progress: Vec<_> = app
+                .world_mut()
+                .resource_mut::<Events<SceneSpawnProgress>>()
+                .drain()
+                .collect();
+            assert_eq!(
+                progress,
+                vec![SceneSpawnProgress {
+                    instance_id,
+                    spawned,
+                    total: 4
+                }]
+            );
+
+            let world = app.world_mut();
+            let written: Vec<(Entity, usize)> = world
+                .query::<(Entity, &A)>()
+                .iter(world)
+                .map(|(entity, a)| (entity, a.0))
+                .collect();
+            assert_eq!(written.len(), spawned);
+            // The entities that weren't written yet are reserved disabled.
+            let reserved = world
+                .query_filtered::<Entity, With<Disabled>>()
+                .iter(world)
+                .count();
+            assert_eq!(reserved, 4 - spawned);
+            let (root_entity, _) = written.iter().find(|(_, a)| *a == 0).unwrap();
+            let children = world
+                .get::<Children>(*root_entity)
+                .map_or(0, |children| children.len());
+            assert_eq!(children, spawned - 1);
+            for (entity, _) in &written {
+                if let Some(parent) = world.get::<Parent>(*entity) {
+                    assert_eq!(parent.get(), *root_entity);
+                }
+            }
+        }
+
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(scene_spawner.instance_is_ready(instance_id));
+        let root_entity = scene_spawner.instance_entity(instance_id, root).unwrap();
+        let scene_children: Vec<Entity> = scene_world.get::<Children>(root).unwrap().to_vec();
+        let children: Vec<Entity> = app
+            .world()
+            .get::<Children>(root_entity)
+            .unwrap()
+            .iter()
+            .map(|&child| {
+                let (&scene_child, _) = scene_spawner
+                    .instance_entities(instance_id)
+                    .unwrap()
+                    .iter()
+                    .find(|(_, entity)| **entity == child)
+                    .unwrap();
+                scene_child
+            })
+            .collect();
+        assert_eq!(children, scene_children);
+    }
+
+    #[test]
+    fn spawn_streaming_with_params_and_overrides() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), HierarchyPlugin, ScenePlugin))
+            .register_type::<A>();
+
+        let mut scene_world = World::new();
+        let exposed = scene_world
+            .spawn((
+                A(1),
+                SceneParameters::default().with::<A>("difficulty", ".0"),
+            ))
+            .id();
+        let overridden = scene_world.spawn(A(2)).id();
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<Scene>>()
+            .add(Scene::new(scene_world));
+        let parent = app
+            .world_mut()
+            .spawn(InstanceOverrides::default().with::<A>(overridden, ".0", 10_usize))
+            .id();
+        let sibling = app.world_mut().spawn_empty().set_parent(parent).id();
+
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        let with_params = scene_spawner.spawn_with_params(
+            scene.clone(),
+            SceneParams::default().with("difficulty", 3_usize),
+        );
+        scene_spawner.set_spawn_budget(with_params, Duration::ZERO);
+        let as_child = scene_spawner.spawn_as_child_at(scene, parent, 0);
+        scene_spawner.set_spawn_budget(as_child, Duration::ZERO);
+
+        // The values are applied to each entity as soon as it's written.
+        app.update();
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(!scene_spawner.instance_is_ready(with_params));
+        let world = app.world_mut();
+        let mut values: Vec<usize> = world.query::<&A>().iter(world).map(|a| a.0).collect();
+        values.sort_unstable();
+        assert_eq!(values, [1, 3]);
+
+        app.update();
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(scene_spawner.instance_is_ready(with_params));
+        assert!(scene_spawner.instance_is_ready(as_child));
+        let hard_entity = scene_spawner.instance_entity(with_params, exposed).unwrap();
+        let overridden_entity = scene_spawner.instance_entity(as_child, overridden).unwrap();
+        let exposed_entity = scene_spawner.instance_entity(as_child, exposed).unwrap();
+        assert_eq!(app.world().get::<A>(hard_entity), Some(&A(3)));
+        assert_eq!(app.world().get::<A>(overridden_entity), Some(&A(10)));
+        assert_eq!(app.world().get::<A>(exposed_entity), Some(&A(1)));
+
+        // The roots of the instance were inserted at the requested index as they were written.
+        let children: Vec<Entity> = app.world().get::<Children>(parent).unwrap().to_vec();
+        assert_eq!(children.len(), 3);
+        assert_eq!(children[2], sibling);
+    }
+
+    #[test]
+    fn spawn_as_child_order() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>();
+
+        let scene = |value| DynamicScene {
+            resources: Vec::new(),
+            entities: vec![scene_entity(0, A(value))],
+        };
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let first = scenes.reserve_handle();
+        let second = scenes.add(scene(2));
+        let third = scenes.add(scene(3));
+        let parent = app.world_mut().spawn_empty().id();
+
+        // The first scene isn't loaded yet, so the second one is spawned first.
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        scene_spawner.spawn_dynamic_as_child(first.clone(), parent);
+        scene_spawner.spawn_dynamic_as_child(second, parent);
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic_as_child_at(third, parent, 0);
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&first, scene(1));
+        app.update();
+
+        let children = app.world().get::<Children>(parent).unwrap();
+        let values: Vec<_> = children
+            .iter()
+            .map(|&child| app.world().get::<A>(child).unwrap().0)
+            .collect();
+        assert_eq!(values, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn instance_entities_on_ready() {
+        #[derive(Component)]
+        struct FixedUp;
+
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>();
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene {
+                resources: Vec::new(),
+                entities: vec![scene_entity(0, A(1)), scene_entity(1, A(2))],
+            });
+        app.world_mut().add_observer(
+            |trigger: Trigger<SceneInstanceReady>,
+             scene_spawner: Res<SceneSpawner>,
+             mut commands: Commands| {
+                let entity = scene_spawner
+                    .instance_entity(trigger.event().instance_id, Entity::from_raw(1))
+                    .unwrap();
+                commands.entity(entity).insert(FixedUp);
+            },
+        );
+        let root = app.world_mut().spawn(DynamicSceneRoot(scene)).id();
+        app.update();
+
+        let instance_id = **app.world().get::<SceneInstance>(root).unwrap();
+        let entity_map = app
+            .world()
+            .resource::<SceneSpawner>()
+            .instance_entities(instance_id)
+            .unwrap();
+        assert_eq!(entity_map.len(), 2);
+        let fixed_up = entity_map[&Entity::from_raw(1)];
+        assert_eq!(app.world().get::<A>(fixed_up), Some(&A(2)));
+        assert!(app.world().entity(fixed_up).contains::<FixedUp>());
+    }
+
     #[derive(Resource, Default)]
     struct TriggerCount(u32);
 
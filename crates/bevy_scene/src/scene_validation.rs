@@ -0,0 +1,356 @@
+use crate::{scene_patch::represented_type_path, DynamicScene, PlainDataComponents};
+use bevy_ecs::{
+    entity::{Entity, EntityHashSet, EntityMapper},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectResource},
+};
+use bevy_reflect::{
+    PartialReflect, ReflectFromReflect, ReflectRef, TypeRegistration, TypeRegistry,
+};
+use bevy_utils::HashSet;
+use thiserror::Error;
+
+/// A problem found in a [`DynamicScene`] by [`DynamicScene::validate`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SceneIssue {
+    /// An entity appears several times in the scene.
+    #[error("entity {entity} appears several times in the scene")]
+    DuplicateEntity {
+        /// The duplicated entity.
+        entity: Entity,
+    },
+    /// An entity has several components of the same type.
+    #[error("entity {entity} has several `{type_path}` components")]
+    DuplicateComponent {
+        /// The entity with the duplicated component.
+        entity: Entity,
+        /// The type of the duplicated component.
+        type_path: String,
+    },
+    /// The scene has several resources of the same type.
+    #[error("the scene has several `{type_path}` resources")]
+    DuplicateResource {
+        /// The type of the duplicated resource.
+        type_path: String,
+    },
+    /// A component of an entity isn't registered in the type registry.
+    #[error(
+        "entity {entity} has the component `{type_path}`, which isn't registered. \
+        consider registering the type using `app.register_type::<T>()`"
+    )]
+    UnregisteredComponentType {
+        /// The entity with the unregistered component.
+        entity: Entity,
+        /// The type of the unregistered component.
+        type_path: String,
+    },
+    /// A resource isn't registered in the type registry.
+    #[error(
+        "the scene has the resource `{type_path}`, which isn't registered. \
+        consider registering the type using `app.register_type::<T>()`"
+    )]
+    UnregisteredResourceType {
+        /// The type of the unregistered resource.
+        type_path: String,
+    },
+    /// A component is registered, but doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) trait.
+    #[error(
+        "entity {entity} has the component `{type_path}`, which doesn't reflect `Component`. \
+        consider adding `#[reflect(Component)]` to your type"
+    )]
+    NotAComponent {
+        /// The entity with the component.
+        entity: Entity,
+        /// The type of the component.
+        type_path: String,
+    },
+    /// A resource is registered, but doesn't reflect the
+    /// [`Resource`](bevy_ecs::prelude::Resource) trait.
+    #[error(
+        "the scene has the resource `{type_path}`, which doesn't reflect `Resource`. \
+        consider adding `#[reflect(Resource)]` to your type"
+    )]
+    NotAResource {
+        /// The type of the resource.
+        type_path: String,
+    },
+    /// A component references an entity that isn't in the scene. When spawned, the reference
+    /// points to an entity that doesn't exist.
+    #[error(
+        "`{type_path}` on entity {entity} references entity {reference}, which isn't in the scene"
+    )]
+    DanglingEntityReference {
+        /// The entity with the component.
+        entity: Entity,
+        /// The type of the component.
+        type_path: String,
+        /// The referenced entity.
+        reference: Entity,
+    },
+    /// A component contains entities, but its type can't map them to the spawned entities.
+    #[error(
+        "`{type_path}` on entity {entity} contains entities that can't be mapped when spawning. \
+        consider adding `#[reflect(MapEntities)]` to your type"
+    )]
+    UnmappableEntityReference {
+        /// The entity with the component.
+        entity: Entity,
+        /// The type of the component.
+        type_path: String,
+    },
+}
+
+impl DynamicScene {
+    /// Checks this scene for problems that would make spawning it fail or spawn something else
+    /// than expected, using the types registered in `type_registry`.
+    ///
+    /// This reports unregistered component and resource types, duplicated entities, components
+    /// and resources, and entity references that can't be mapped to the spawned entities. It
+    /// is meant to let content pipelines fail fast, before a scene is partially spawned. An empty
+    /// list means that no issue was found.
+    pub fn validate(&self, type_registry: &TypeRegistry) -> Vec<SceneIssue> {
+        let mut issues = Vec::new();
+
+        let mut entities = EntityHashSet::default();
+        for scene_entity in &self.entities {
+            if !entities.insert(scene_entity.entity) {
+                issues.push(SceneIssue::DuplicateEntity {
+                    entity: scene_entity.entity,
+                });
+            }
+        }
+
+        for scene_entity in &self.entities {
+            let entity = scene_entity.entity;
+            let mut type_paths = HashSet::<&str>::default();
+            for component in &scene_entity.components {
+                let type_path = represented_type_path(component.as_partial_reflect());
+                if !type_paths.insert(type_path) {
+                    issues.push(SceneIssue::DuplicateComponent {
+                        entity,
+                        type_path: type_path.to_string(),
+                    });
+                }
+
+                let Some(registration) =
+                    registration(component.as_partial_reflect(), type_registry)
+                else {
+                    issues.push(SceneIssue::UnregisteredComponentType {
+                        entity,
+                        type_path: type_path.to_string(),
+                    });
+                    continue;
+                };
+                if registration.type_info().is::<PlainDataComponents>() {
+                    continue;
+                }
+                let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+                    issues.push(SceneIssue::NotAComponent {
+                        entity,
+                        type_path: type_path.to_string(),
+                    });
+                    continue;
+                };
+
+                if contained_entities(component.as_partial_reflect()) == 0 {
+                    continue;
+                }
+                let Some(references) = mapped_entities(
+                    component.as_partial_reflect(),
+                    registration,
+                    reflect_component,
+                )
+                .filter(|references| !references.is_empty()) else {
+                    issues.push(SceneIssue::UnmappableEntityReference {
+                        entity,
+                        type_path: type_path.to_string(),
+                    });
+                    continue;
+                };
+                for reference in references {
+                    if reference != Entity::PLACEHOLDER && !entities.contains(&reference) {
+                        issues.push(SceneIssue::DanglingEntityReference {
+                            entity,
+                            type_path: type_path.to_string(),
+                            reference,
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut type_paths = HashSet::<&str>::default();
+        for resource in &self.resources {
+            let type_path = represented_type_path(resource.as_partial_reflect());
+            if !type_paths.insert(type_path) {
+                issues.push(SceneIssue::DuplicateResource {
+                    type_path: type_path.to_string(),
+                });
+            }
+            match registration(resource.as_partial_reflect(), type_registry) {
+                None => issues.push(SceneIssue::UnregisteredResourceType {
+                    type_path: type_path.to_string(),
+                }),
+                Some(registration) if registration.data::<ReflectResource>().is_none() => {
+                    issues.push(SceneIssue::NotAResource {
+                        type_path: type_path.to_string(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        issues
+    }
+}
+
+fn registration<'a>(
+    value: &dyn PartialReflect,
+    type_registry: &'a TypeRegistry,
+) -> Option<&'a TypeRegistration> {
+    let type_info = value.get_represented_type_info()?;
+    type_registry.get(type_info.type_id())
+}
+
+/// Records the entities it maps, without changing them.
+struct EntityRecorder(Vec<Entity>);
+
+impl EntityMapper for EntityRecorder {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.push(entity);
+        entity
+    }
+}
+
+/// Returns the entities the component maps when it's spawned, or `None` if it can't be mapped.
+fn mapped_entities(
+    component: &dyn PartialReflect,
+    registration: &TypeRegistration,
+    reflect_component: &ReflectComponent,
+) -> Option<Vec<Entity>> {
+    let mut recorder = EntityRecorder(Vec::new());
+    // Spawning maps entities the same way: with `ReflectMapEntities` if registered, and with
+    // `Component::map_entities` otherwise.
+    if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+        let mut component = component.clone_value();
+        map_entities.map_entities(component.as_partial_reflect_mut(), &mut recorder);
+    } else {
+        let mut component = registration
+            .data::<ReflectFromReflect>()?
+            .from_reflect(component)?;
+        reflect_component.map_entities(component.as_mut(), &mut recorder);
+    }
+    Some(recorder.0)
+}
+
+/// Counts the [`Entity`] values contained in `value`.
+fn contained_entities(value: &dyn PartialReflect) -> usize {
+    if value.try_downcast_ref::<Entity>().is_some() {
+        return 1;
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().map(contained_entities).sum(),
+        ReflectRef::TupleStruct(value) => value.iter_fields().map(contained_entities).sum(),
+        ReflectRef::Tuple(value) => value.iter_fields().map(contained_entities).sum(),
+        ReflectRef::List(value) => value.iter().map(contained_entities).sum(),
+        ReflectRef::Array(value) => value.iter().map(contained_entities).sum(),
+        ReflectRef::Map(value) => value
+            .iter()
+            .map(|(key, value)| contained_entities(key) + contained_entities(value))
+            .sum(),
+        ReflectRef::Set(value) => value.iter().map(contained_entities).sum(),
+        ReflectRef::Enum(value) => value
+            .iter_fields()
+            .map(|field| contained_entities(field.value()))
+            .sum(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicEntity;
+    use bevy_ecs::{
+        component::Component,
+        entity::{VisitEntities, VisitEntitiesMut},
+        system::Resource,
+    };
+    use bevy_reflect::{Reflect, TypePath};
+
+    #[derive(Component, Reflect, Clone, Copy, VisitEntities, VisitEntitiesMut)]
+    #[reflect(Component, MapEntities)]
+    struct Target(Entity);
+
+    #[derive(Component, Reflect, Clone, Copy)]
+    #[reflect(Component)]
+    struct UnmappedTarget(Entity);
+
+    #[derive(Component, Reflect, Clone, Copy)]
+    #[reflect(Component)]
+    struct Unregistered;
+
+    #[derive(Resource, Reflect, Clone, Copy)]
+    struct NotAResource;
+
+    fn entity(index: u32, components: Vec<Box<dyn PartialReflect>>) -> DynamicEntity {
+        DynamicEntity {
+            entity: Entity::from_raw(index),
+            components,
+        }
+    }
+
+    #[test]
+    fn reports_issues() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Target>();
+        registry.register::<UnmappedTarget>();
+        registry.register::<NotAResource>();
+
+        let (first, second, missing) = (
+            Entity::from_raw(0),
+            Entity::from_raw(1),
+            Entity::from_raw(7),
+        );
+        let valid = DynamicScene {
+            resources: Vec::new(),
+            entities: vec![
+                entity(0, vec![Box::new(Target(second))]),
+                entity(1, vec![Box::new(Target(first))]),
+            ],
+        };
+        assert!(valid.validate(&registry).is_empty());
+
+        let scene = DynamicScene {
+            resources: vec![Box::new(NotAResource)],
+            entities: vec![
+                entity(0, vec![Box::new(Target(missing)), Box::new(Unregistered)]),
+                entity(0, vec![Box::new(UnmappedTarget(first))]),
+            ],
+        };
+        let issues = scene.validate(&registry);
+        let type_path = |type_path: &str| type_path.to_string();
+        assert_eq!(
+            issues,
+            vec![
+                SceneIssue::DuplicateEntity { entity: first },
+                SceneIssue::DanglingEntityReference {
+                    entity: first,
+                    type_path: type_path(Target::type_path()),
+                    reference: missing,
+                },
+                SceneIssue::UnregisteredComponentType {
+                    entity: first,
+                    type_path: type_path(Unregistered::type_path()),
+                },
+                SceneIssue::UnmappableEntityReference {
+                    entity: first,
+                    type_path: type_path(UnmappedTarget::type_path()),
+                },
+                SceneIssue::NotAResource {
+                    type_path: type_path(NotAResource::type_path()),
+                },
+            ]
+        );
+    }
+}
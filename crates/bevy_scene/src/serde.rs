@@ -1,6 +1,6 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicScene, EntityPatch, ScenePatch};
 use bevy_ecs::entity::Entity;
 use bevy_reflect::{
     serde::{
@@ -29,6 +29,18 @@ pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized scene patch struct type.
+pub const PATCH_STRUCT: &str = "ScenePatch";
+/// Name of the serialized removed resources field in a scene patch struct.
+pub const PATCH_REMOVED_RESOURCES: &str = "removed_resources";
+/// Name of the serialized removed entities field in a scene patch struct.
+pub const PATCH_REMOVED_ENTITIES: &str = "removed_entities";
+
+/// Name of the serialized entity patch struct type.
+pub const ENTITY_PATCH_STRUCT: &str = "EntityPatch";
+/// Name of the serialized removed components field in an entity patch struct.
+pub const ENTITY_PATCH_FIELD_REMOVED_COMPONENTS: &str = "removed_components";
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -507,22 +519,406 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+/// Serializer for a [`ScenePatch`].
+///
+/// The resources and components are serialized like in a scene, see [`SceneSerializer`], and the
+/// removed resources and components as lists of type paths.
+pub struct ScenePatchSerializer<'a> {
+    /// The patch to serialize.
+    pub patch: &'a ScenePatch,
+    /// The type registry containing the types present in the patch.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ScenePatchSerializer<'a> {
+    /// Create a new serializer from a [`ScenePatch`] and an associated [`TypeRegistry`].
+    pub fn new(patch: &'a ScenePatch, registry: &'a TypeRegistry) -> Self {
+        ScenePatchSerializer { patch, registry }
+    }
+}
+
+impl<'a> Serialize for ScenePatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(PATCH_STRUCT, 4)?;
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &SceneMapSerializer {
+                entries: &self.patch.resources,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(PATCH_REMOVED_RESOURCES, &self.patch.removed_resources)?;
+        state.serialize_field(
+            SCENE_ENTITIES,
+            &EntityPatchesSerializer {
+                entities: &self.patch.entities,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(PATCH_REMOVED_ENTITIES, &self.patch.removed_entities)?;
+        state.end()
+    }
+}
+
+/// Handles serialization of multiple entity patches as a map of entity id to serialized patch.
+pub struct EntityPatchesSerializer<'a> {
+    /// The entity patches to serialize.
+    pub entities: &'a [EntityPatch],
+    /// Type registry in which the component types used by the patches are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityPatchesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for patch in self.entities {
+            state.serialize_entry(
+                &patch.entity,
+                &EntityPatchSerializer {
+                    patch,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles entity patch serialization.
+pub struct EntityPatchSerializer<'a> {
+    /// The entity patch to serialize.
+    pub patch: &'a EntityPatch,
+    /// Type registry in which the component types used by the patch are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityPatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(ENTITY_PATCH_STRUCT, 2)?;
+        state.serialize_field(
+            ENTITY_FIELD_COMPONENTS,
+            &SceneMapSerializer {
+                entries: &self.patch.components,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            ENTITY_PATCH_FIELD_REMOVED_COMPONENTS,
+            &self.patch.removed_components,
+        )?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ScenePatchField {
+    Resources,
+    RemovedResources,
+    Entities,
+    RemovedEntities,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum EntityPatchField {
+    Components,
+    RemovedComponents,
+}
+
+/// Handles scene patch deserialization.
+///
+/// When deserializing from a self-describing format like RON, fields can be omitted, in which case
+/// they are empty. This keeps hand-written patches short.
+pub struct ScenePatchDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the patch to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePatchDeserializer<'a> {
+    type Value = ScenePatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            PATCH_STRUCT,
+            &[
+                SCENE_RESOURCES,
+                PATCH_REMOVED_RESOURCES,
+                SCENE_ENTITIES,
+                PATCH_REMOVED_ENTITIES,
+            ],
+            ScenePatchVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct ScenePatchVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ScenePatchVisitor<'a> {
+    type Value = ScenePatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene patch struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let resources = seq
+            .next_element_seed(SceneMapDeserializer {
+                registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+        let removed_resources = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(PATCH_REMOVED_RESOURCES))?;
+        let entities = seq
+            .next_element_seed(EntityPatchesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        let removed_entities = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(PATCH_REMOVED_ENTITIES))?;
+
+        Ok(ScenePatch {
+            resources,
+            removed_resources,
+            entities,
+            removed_entities,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut resources = None;
+        let mut removed_resources = None;
+        let mut entities = None;
+        let mut removed_entities = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ScenePatchField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+                ScenePatchField::RemovedResources => {
+                    if removed_resources.is_some() {
+                        return Err(Error::duplicate_field(PATCH_REMOVED_RESOURCES));
+                    }
+                    removed_resources = Some(map.next_value()?);
+                }
+                ScenePatchField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                    }
+                    entities = Some(map.next_value_seed(EntityPatchesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+                ScenePatchField::RemovedEntities => {
+                    if removed_entities.is_some() {
+                        return Err(Error::duplicate_field(PATCH_REMOVED_ENTITIES));
+                    }
+                    removed_entities = Some(map.next_value()?);
+                }
+            }
+        }
+
+        Ok(ScenePatch {
+            resources: resources.unwrap_or_default(),
+            removed_resources: removed_resources.unwrap_or_default(),
+            entities: entities.unwrap_or_default(),
+            removed_entities: removed_entities.unwrap_or_default(),
+        })
+    }
+}
+
+/// Handles deserialization for a collection of entity patches.
+pub struct EntityPatchesDeserializer<'a> {
+    /// Type registry in which the component types used by the patches to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchesDeserializer<'a> {
+    type Value = Vec<EntityPatch>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(EntityPatchesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct EntityPatchesVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for EntityPatchesVisitor<'a> {
+    type Value = Vec<EntityPatch>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entity patches")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let patch = map.next_value_seed(EntityPatchDeserializer {
+                entity,
+                type_registry: self.type_registry,
+            })?;
+            entities.push(patch);
+        }
+
+        Ok(entities)
+    }
+}
+
+/// Handle deserialization of an entity patch.
+pub struct EntityPatchDeserializer<'a> {
+    /// Id of the patched entity.
+    pub entity: Entity,
+    /// Type registry in which the component types used by the patch to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchDeserializer<'a> {
+    type Value = EntityPatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ENTITY_PATCH_STRUCT,
+            &[
+                ENTITY_FIELD_COMPONENTS,
+                ENTITY_PATCH_FIELD_REMOVED_COMPONENTS,
+            ],
+            EntityPatchVisitor {
+                entity: self.entity,
+                registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct EntityPatchVisitor<'a> {
+    entity: Entity,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for EntityPatchVisitor<'a> {
+    type Value = EntityPatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity patch struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let components = seq
+            .next_element_seed(SceneMapDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        let removed_components = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(ENTITY_PATCH_FIELD_REMOVED_COMPONENTS))?;
+
+        Ok(EntityPatch {
+            entity: self.entity,
+            components,
+            removed_components,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components = None;
+        let mut removed_components = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                EntityPatchField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+                    components = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+                EntityPatchField::RemovedComponents => {
+                    if removed_components.is_some() {
+                        return Err(Error::duplicate_field(
+                            ENTITY_PATCH_FIELD_REMOVED_COMPONENTS,
+                        ));
+                    }
+                    removed_components = Some(map.next_value()?);
+                }
+            }
+        }
+
+        Ok(EntityPatch {
+            entity: self.entity,
+            components: components.unwrap_or_default(),
+            removed_components: removed_components.unwrap_or_default(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         ron,
         serde::{SceneDeserializer, SceneSerializer},
-        DynamicScene, DynamicSceneBuilder,
+        DynamicScene, DynamicSceneBuilder, PlainDataComponents, SceneSpawnError,
     };
     use bevy_ecs::{
+        component::{ComponentDescriptor, ComponentId, StorageType},
         entity::{Entity, EntityHashMap, VisitEntities, VisitEntitiesMut},
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
         query::{With, Without},
         reflect::{AppTypeRegistry, ReflectMapEntities},
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{
+        serde::ReflectMigrate, FromReflect, Reflect, ReflectDeserialize, ReflectSerialize,
+    };
     use bincode::Options;
+    use core::alloc::Layout;
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
 
@@ -727,6 +1123,99 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_migrate_versioned_components() {
+        #[derive(Reflect)]
+        struct HealthV0(f32);
+
+        #[derive(Reflect)]
+        struct HealthV1 {
+            current: f32,
+        }
+
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[reflect(Component)]
+        struct Health {
+            current: f32,
+            max: f32,
+        }
+
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        // Version 0 is migrated to version 1, and then to the current version.
+        ReflectMigrate::new(2)
+            .with_migration(0, |old: HealthV0| HealthV1 { current: old.0 })
+            .with_migration(1, |old: HealthV1| Health {
+                current: old.current,
+                max: 100.0,
+            })
+            .register::<Health>(&mut registry.write());
+        world.insert_resource(registry);
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Health": (0, (10.0)),
+      },
+    ),
+    4294967297: (
+      components: {
+        "bevy_scene::serde::tests::Health": (1, (
+          current: 20.0,
+        )),
+      },
+    ),
+    4294967298: (
+      components: {
+        "bevy_scene::serde::tests::Health": (2, (
+          current: 30.0,
+          max: 50.0,
+        )),
+      },
+    ),
+  },
+)"#;
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let deserialize = |input: &str| {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            SceneDeserializer {
+                type_registry: &registry,
+            }
+            .deserialize(&mut deserializer)
+        };
+        let health = |scene: &DynamicScene| {
+            scene
+                .entities
+                .iter()
+                .map(|entity| Health::from_reflect(entity.components[0].as_partial_reflect()))
+                .collect::<Vec<_>>()
+        };
+        let expected = vec![
+            Some(Health {
+                current: 10.0,
+                max: 100.0,
+            }),
+            Some(Health {
+                current: 20.0,
+                max: 100.0,
+            }),
+            Some(Health {
+                current: 30.0,
+                max: 50.0,
+            }),
+        ];
+        let scene = deserialize(input).unwrap();
+        assert_eq!(health(&scene), expected);
+
+        // Scenes are saved with the current version.
+        let output = scene.serialize(&registry).unwrap();
+        assert_eq!(health(&deserialize(&output).unwrap()), expected);
+
+        assert!(deserialize(&input.replace("(1, (", "(3, (")).is_err());
+    }
+
     fn roundtrip_ron(world: &World) -> (DynamicScene, DynamicScene) {
         let scene = DynamicScene::from_world(world);
         let registry = world.resource::<AppTypeRegistry>().read();
@@ -796,6 +1285,49 @@ mod tests {
         assert_eq!(&qux, world.query::<&Qux>().single(&world));
     }
 
+    #[test]
+    fn should_roundtrip_plain_data_components() {
+        fn register_position(world: &mut World) -> ComponentId {
+            world
+                .resource::<AppTypeRegistry>()
+                .write()
+                .register::<PlainDataComponents>();
+            world.register_component_with_descriptor(ComponentDescriptor::new_plain_data(
+                "Position",
+                StorageType::Table,
+                Layout::new::<[f32; 2]>(),
+                true,
+            ))
+        }
+
+        let mut world = create_world();
+        let position = register_position(&mut world);
+        let data: Vec<u8> = [1.0f32, 2.0].iter().flat_map(|x| x.to_ne_bytes()).collect();
+        world.spawn(Foo(123)).insert_plain_data(position, &data);
+
+        let (scene, deserialized_scene) = roundtrip_ron(&world);
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        let mut dst_world = create_world();
+        assert!(matches!(
+            deserialized_scene.write_to_world(&mut dst_world, &mut EntityHashMap::default()),
+            Err(SceneSpawnError::UnregisteredPlainDataComponent { name }) if name == "Position"
+        ));
+
+        let mut dst_world = create_world();
+        let dst_position = register_position(&mut dst_world);
+        deserialized_scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+        let entity = dst_world
+            .query_filtered::<Entity, With<Foo>>()
+            .single(&dst_world);
+        assert_eq!(
+            dst_world.entity(entity).get_plain_data(dst_position),
+            Some(&data[..])
+        );
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
@@ -9,6 +9,7 @@ use bevy_color::Color;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{prelude::*, reflect::ReflectComponent};
 use bevy_hierarchy::{Children, Parent};
+use bevy_math::Vec2;
 use bevy_reflect::prelude::*;
 use bevy_utils::once;
 use cosmic_text::{Buffer, Metrics};
@@ -87,6 +88,42 @@ impl ComputedTextBlock {
     pub fn needs_rerender(&self) -> bool {
         self.needs_rerender
     }
+
+    /// Returns the horizontal position of a caret placed before the byte at `byte_index` of the
+    /// line `line` of the text, in physical pixels from the left of the text block, as of the last
+    /// layout.
+    ///
+    /// A `byte_index` past the last glyph of the line places the caret after it. Returns `None` if
+    /// the line has no glyph at or ending at `byte_index`, like an empty line.
+    pub fn caret_x(&self, line: usize, byte_index: usize) -> Option<f32> {
+        self.buffer
+            .layout_runs()
+            .filter(|run| run.line_i == line)
+            .find_map(|run| {
+                run.glyphs.iter().find_map(|glyph| {
+                    let (start, end) = if glyph.level.is_rtl() {
+                        (glyph.x + glyph.w, glyph.x)
+                    } else {
+                        (glyph.x, glyph.x + glyph.w)
+                    };
+                    if glyph.start == byte_index {
+                        Some(start)
+                    } else if glyph.end == byte_index {
+                        Some(end)
+                    } else {
+                        None
+                    }
+                })
+            })
+    }
+
+    /// Returns the line and the byte index within the line of the caret position closest to
+    /// `position`, in physical pixels from the top left of the text block, as of the last layout.
+    pub fn hit(&self, position: Vec2) -> Option<(usize, usize)> {
+        self.buffer
+            .hit(position.x, position.y)
+            .map(|cursor| (cursor.line, cursor.index))
+    }
 }
 
 impl Default for ComputedTextBlock {
@@ -0,0 +1,143 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+
+use crate::{Fixed, Time};
+
+/// The value a component `T` had at the start of the last fixed timestep.
+///
+/// Together with [`FixedInterpolationAlpha`], this lets systems running at the render rate blend
+/// between the last two fixed-step states of `T`, instead of showing the state jumping once per
+/// [`FixedUpdate`]. Add a [`FixedInterpolationPlugin<T>`] to keep it up to date: every entity
+/// with a `T` then gets a [`Previous<T>`] the next time the fixed main loop runs.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{FixedInterpolationAlpha, Previous};
+/// #[derive(Component, Clone)]
+/// struct Position(f32);
+///
+/// #[derive(Component)]
+/// struct RenderedPosition(f32);
+///
+/// fn interpolate(
+///     alpha: Res<FixedInterpolationAlpha>,
+///     mut query: Query<(&Position, &Previous<Position>, &mut RenderedPosition)>,
+/// ) {
+///     for (current, previous, mut rendered) in &mut query {
+///         rendered.0 = previous.0 .0 + (current.0 - previous.0 .0) * alpha.0;
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Previous<T: Component>(pub T);
+
+/// How far the [`Virtual`](crate::Virtual) clock is between the last fixed timestep and the next
+/// one, from `0.0` to `1.0`.
+///
+/// This is [`Time::<Fixed>::overstep_fraction`], updated right after the fixed main loop, and is
+/// meant to interpolate between a [`Previous`] value and the current one.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FixedInterpolationAlpha(pub f32);
+
+/// Tracks the [`Previous`] value of the component `T` at every fixed timestep.
+pub struct FixedInterpolationPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for FixedInterpolationPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Component + Clone> Plugin for FixedInterpolationPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedFirst,
+            update_previous::<T>.in_set(UpdatePreviousSystem),
+        );
+    }
+}
+
+/// Copies every component into its [`Previous`] value at the start of each fixed timestep,
+/// before [`FixedUpdate`] changes it.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct UpdatePreviousSystem;
+
+/// Copies the current value of every `T` into its [`Previous<T>`], inserting it if missing.
+pub fn update_previous<T: Component + Clone>(
+    mut commands: Commands,
+    mut tracked: Query<(&T, &mut Previous<T>)>,
+    untracked: Query<(Entity, &T), Without<Previous<T>>>,
+) {
+    for (current, mut previous) in &mut tracked {
+        previous.0 = current.clone();
+    }
+    for (entity, current) in &untracked {
+        commands.entity(entity).insert(Previous(current.clone()));
+    }
+}
+
+/// Updates [`FixedInterpolationAlpha`] from [`Time<Fixed>`].
+pub fn update_fixed_interpolation_alpha(
+    fixed_time: Res<Time<Fixed>>,
+    mut alpha: ResMut<FixedInterpolationAlpha>,
+) {
+    alpha.0 = fixed_time.overstep_fraction();
+}
+
+pub(crate) fn add_fixed_interpolation_alpha(app: &mut App) {
+    app.init_resource::<FixedInterpolationAlpha>().add_systems(
+        RunFixedMainLoop,
+        update_fixed_interpolation_alpha.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TimePlugin, TimeUpdateStrategy};
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Position(f32);
+
+    fn step(mut query: Query<&mut Position>) {
+        for mut position in &mut query {
+            position.0 += 1.0;
+        }
+    }
+
+    #[test]
+    fn previous_tracks_last_fixed_step() {
+        let timestep = Time::<Fixed>::default().timestep();
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, FixedInterpolationPlugin::<Position>::default()))
+            .add_systems(FixedUpdate, step)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(timestep * 3 / 2));
+        let entity = app.world_mut().spawn(Position(0.0)).id();
+
+        // The first update doesn't advance time.
+        app.update();
+        assert!(app.world().get::<Previous<Position>>(entity).is_none());
+
+        // One and a half timesteps: one fixed step runs.
+        app.update();
+        assert_eq!(app.world().get::<Position>(entity), Some(&Position(1.0)));
+        assert_eq!(
+            app.world().get::<Previous<Position>>(entity),
+            Some(&Previous(Position(0.0)))
+        );
+        let alpha = app.world().resource::<FixedInterpolationAlpha>().0;
+        assert!((alpha - 0.5).abs() < 1e-3);
+
+        // Three timesteps: two fixed steps have run.
+        app.update();
+        assert_eq!(app.world().get::<Position>(entity), Some(&Position(3.0)));
+        assert_eq!(
+            app.world().get::<Previous<Position>>(entity),
+            Some(&Previous(Position(2.0)))
+        );
+        let alpha = app.world().resource::<FixedInterpolationAlpha>().0;
+        assert!(alpha.abs() < 1e-3);
+    }
+}
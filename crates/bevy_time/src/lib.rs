@@ -14,6 +14,7 @@
 /// Common run conditions
 pub mod common_conditions;
 mod fixed;
+mod interpolation;
 mod real;
 mod stopwatch;
 mod time;
@@ -21,6 +22,7 @@ mod timer;
 mod virt;
 
 pub use fixed::*;
+pub use interpolation::*;
 pub use real::*;
 pub use stopwatch::*;
 pub use time::*;
@@ -35,7 +37,7 @@ pub mod prelude {
     pub use crate::{Fixed, Real, Time, Timer, TimerMode, Virtual};
 }
 
-use bevy_app::{prelude::*, RunFixedMainLoop};
+use bevy_app::{prelude::*, RunFixedMainLoop, TickDelta};
 use bevy_ecs::{
     event::{event_update_system, signal_event_update_system, EventRegistry, ShouldUpdateEvents},
     prelude::*,
@@ -82,6 +84,7 @@ impl Plugin for TimePlugin {
             RunFixedMainLoop,
             run_fixed_main_schedule.in_set(RunFixedMainLoopSystem::FixedMainLoop),
         );
+        add_fixed_interpolation_alpha(app);
 
         // Ensure the events are not dropped until `FixedMain` systems can observe them
         app.add_systems(FixedPostUpdate, signal_event_update_system);
@@ -128,11 +131,15 @@ pub fn create_time_channels() -> (TimeSender, TimeReceiver) {
 
 /// The system used to update the [`Time`] used by app logic. If there is a render world the time is
 /// sent from there to this system through channels. Otherwise the time is updated in this system.
+///
+/// When the app is driven by an [`AppTicker`](bevy_app::AppTicker), the time is advanced by the
+/// [`TickDelta`] of each tick instead.
 pub fn time_system(
     mut real_time: ResMut<Time<Real>>,
     mut virtual_time: ResMut<Time<Virtual>>,
     mut time: ResMut<Time>,
     update_strategy: Res<TimeUpdateStrategy>,
+    tick_delta: Option<Res<TickDelta>>,
     time_recv: Option<Res<TimeReceiver>>,
     mut has_received_time: Local<bool>,
 ) {
@@ -151,6 +158,12 @@ pub fn time_system(
         Instant::now()
     };
 
+    if let Some(tick_delta) = tick_delta {
+        real_time.update_with_duration(tick_delta.0);
+        update_virtual_time(&mut time, &mut virtual_time, &real_time);
+        return;
+    }
+
     match update_strategy.as_ref() {
         TimeUpdateStrategy::Automatic => real_time.update_with_instant(new_time),
         TimeUpdateStrategy::ManualInstant(instant) => real_time.update_with_instant(*instant),
@@ -1,13 +1,21 @@
-//! Extension to [`EntityCommands`] to modify `bevy_hierarchy` hierarchies
-//! while preserving [`GlobalTransform`].
+//! Extensions to [`EntityCommands`] to modify `bevy_hierarchy` hierarchies
+//! while preserving [`GlobalTransform`], and to set an entity's [`Transform`] from
+//! world-space values.
+//! Also extends [`ChildBuild`] to spawn children at a world-space position, and [`Commands`] to
+//! bake the transforms of static subtrees.
 
-use crate::prelude::{GlobalTransform, Transform};
+use crate::prelude::{GlobalTransform, StaticTransformTree, Transform, TransformParent};
 use bevy_ecs::{
+    bundle::Bundle,
+    change_detection::DetectChangesMut,
     entity::Entity,
-    system::EntityCommands,
+    system::{Commands, EntityCommands},
     world::{EntityWorldMut, World},
 };
-use bevy_hierarchy::BuildChildren;
+use bevy_hierarchy::{
+    BuildChildren, ChildBuild, ChildBuilder, Children, Parent, WorldChildBuilder,
+};
+use bevy_math::{Quat, Vec3};
 
 /// Collection of methods similar to [`BuildChildren`], but preserving each
 /// entity's [`GlobalTransform`].
@@ -87,3 +95,408 @@ impl BuildChildrenTransformExt for EntityWorldMut<'_> {
         self
     }
 }
+
+/// Methods to place an entity in world space, by computing the local [`Transform`] that results in
+/// the requested [`GlobalTransform`] given the entity's ancestors.
+///
+/// The [`GlobalTransform`] of the parent is computed from the [`Transform`]s of the entity's
+/// ancestors when the command is applied, so it takes changes made since the last transform
+/// propagation into account. The [`GlobalTransform`] of the entity itself is updated right away.
+///
+/// Note that when used on [`EntityCommands`], the transform updates will only execute
+/// the next time commands are applied
+/// (during [`ApplyDeferred`](bevy_ecs::schedule::ApplyDeferred)).
+///
+/// The ancestors' transforms are expected to be non-degenerate and without shearing, or the
+/// resulting [`Transform`] will be invalid.
+pub trait WorldTransformExt {
+    /// Moves this entity to `translation` in world space, keeping its local rotation and scale.
+    fn set_world_translation(&mut self, translation: Vec3) -> &mut Self;
+
+    /// Rotates this entity to `rotation` in world space, keeping its world translation and scale.
+    fn set_world_rotation(&mut self, rotation: Quat) -> &mut Self;
+
+    /// Scales this entity to `scale` in world space, keeping its world translation and rotation.
+    fn set_world_scale(&mut self, scale: Vec3) -> &mut Self;
+
+    /// Updates this entity's [`Transform`] so that its [`GlobalTransform`] becomes
+    /// `global_transform`.
+    fn set_global_transform(&mut self, global_transform: GlobalTransform) -> &mut Self;
+}
+
+impl WorldTransformExt for EntityCommands<'_> {
+    fn set_world_translation(&mut self, translation: Vec3) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.set_world_translation(translation);
+        })
+    }
+
+    fn set_world_rotation(&mut self, rotation: Quat) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.set_world_rotation(rotation);
+        })
+    }
+
+    fn set_world_scale(&mut self, scale: Vec3) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.set_world_scale(scale);
+        })
+    }
+
+    fn set_global_transform(&mut self, global_transform: GlobalTransform) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.set_global_transform(global_transform);
+        })
+    }
+}
+
+impl WorldTransformExt for EntityWorldMut<'_> {
+    fn set_world_translation(&mut self, translation: Vec3) -> &mut Self {
+        update_world_transform(self, |parent, local| {
+            // Only the translation changes, so avoid decomposing the whole transform.
+            local.translation = match parent {
+                Some(parent) => parent.affine().inverse().transform_point3(translation),
+                None => translation,
+            };
+        })
+    }
+
+    fn set_world_rotation(&mut self, rotation: Quat) -> &mut Self {
+        update_world_transform(self, |parent, local| {
+            let mut global = global_of(parent, *local).compute_transform();
+            global.rotation = rotation;
+            *local = local_of(parent, global.into());
+        })
+    }
+
+    fn set_world_scale(&mut self, scale: Vec3) -> &mut Self {
+        update_world_transform(self, |parent, local| {
+            let mut global = global_of(parent, *local).compute_transform();
+            global.scale = scale;
+            *local = local_of(parent, global.into());
+        })
+    }
+
+    fn set_global_transform(&mut self, global_transform: GlobalTransform) -> &mut Self {
+        update_world_transform(self, |parent, local| {
+            *local = local_of(parent, global_transform);
+        })
+    }
+}
+
+/// Methods to spawn children of [`ChildBuild`] builders in world space.
+pub trait ChildBuildTransformExt: ChildBuild {
+    /// Spawns a child with the given bundle, with the [`Transform`] that places it at
+    /// `global_transform` relative to the builder's parent.
+    ///
+    /// The [`GlobalTransform`] of the parent is computed from the [`Transform`]s of its ancestors
+    /// when the child is spawned, and the child's [`GlobalTransform`] is set right away, so the
+    /// child appears in the right place even if the parent moved since the last transform
+    /// propagation. Any [`Transform`] or [`GlobalTransform`] in `bundle` is overwritten.
+    ///
+    /// Note that when used on [`ChildBuilder`], the transform is only computed the next time
+    /// commands are applied (during [`ApplyDeferred`](bevy_ecs::schedule::ApplyDeferred)).
+    fn spawn_at_world(
+        &mut self,
+        bundle: impl Bundle,
+        global_transform: GlobalTransform,
+    ) -> Self::SpawnOutput<'_>;
+}
+
+impl ChildBuildTransformExt for ChildBuilder<'_> {
+    fn spawn_at_world(
+        &mut self,
+        bundle: impl Bundle,
+        global_transform: GlobalTransform,
+    ) -> EntityCommands {
+        // The `Parent` is only inserted once the builder is done, so pass the parent along.
+        let parent = self.parent_entity();
+        let mut entity = self.spawn(bundle);
+        entity.queue(move |mut entity: EntityWorldMut| {
+            place_at_world(&mut entity, parent, global_transform);
+        });
+        entity
+    }
+}
+
+impl ChildBuildTransformExt for WorldChildBuilder<'_> {
+    fn spawn_at_world(
+        &mut self,
+        bundle: impl Bundle,
+        global_transform: GlobalTransform,
+    ) -> EntityWorldMut {
+        let parent = self.parent_entity();
+        let mut entity = self.spawn(bundle);
+        place_at_world(&mut entity, parent, global_transform);
+        entity
+    }
+}
+
+/// Inserts the [`Transform`] that places `entity` at `global_transform` relative to `parent`, and
+/// that [`GlobalTransform`].
+fn place_at_world(entity: &mut EntityWorldMut, parent: Entity, global_transform: GlobalTransform) {
+    let parent = entity.world_scope(|world| compute_global_transform(world, parent));
+    entity.insert((local_of(parent, global_transform), global_transform));
+}
+
+/// Methods to bake the [`GlobalTransform`]s of subtrees that never move, see
+/// [`StaticTransformTree`].
+///
+/// Note that when used on [`Commands`], the transform updates will only execute
+/// the next time commands are applied
+/// (during [`ApplyDeferred`](bevy_ecs::schedule::ApplyDeferred)).
+pub trait BakeTransformsExt {
+    /// Computes the final [`GlobalTransform`]s of `root` and its descendants, and inserts a
+    /// [`StaticTransformTree`] on `root` so that transform propagation skips them from now on.
+    fn bake_transforms(&mut self, root: Entity) -> &mut Self;
+
+    /// Removes the [`StaticTransformTree`] from `root`, so that the [`GlobalTransform`]s of the
+    /// subtree are updated again by the next transform propagation.
+    fn invalidate_baked_transforms(&mut self, root: Entity) -> &mut Self;
+}
+
+impl BakeTransformsExt for Commands<'_, '_> {
+    fn bake_transforms(&mut self, root: Entity) -> &mut Self {
+        self.queue(move |world: &mut World| {
+            world.bake_transforms(root);
+        });
+        self
+    }
+
+    fn invalidate_baked_transforms(&mut self, root: Entity) -> &mut Self {
+        self.queue(move |world: &mut World| {
+            world.invalidate_baked_transforms(root);
+        });
+        self
+    }
+}
+
+impl BakeTransformsExt for World {
+    fn bake_transforms(&mut self, root: Entity) -> &mut Self {
+        let Some(global_transform) = compute_global_transform(self, root) else {
+            return self;
+        };
+        bake_recursive(self, root, global_transform);
+        self.entity_mut(root).insert(StaticTransformTree);
+        self
+    }
+
+    fn invalidate_baked_transforms(&mut self, root: Entity) -> &mut Self {
+        let Ok(mut root) = self.get_entity_mut(root) else {
+            return self;
+        };
+        root.remove::<StaticTransformTree>();
+        // Propagation only updates the `GlobalTransform`s of the subtree if something changed.
+        if let Some(mut transform) = root.get_mut::<Transform>() {
+            transform.set_changed();
+        }
+        self
+    }
+}
+
+/// Sets the [`GlobalTransform`] of `entity` and recomputes the ones of its descendants, skipping
+/// the subtrees with a [`TransformParent`] like propagation does.
+fn bake_recursive(world: &mut World, entity: Entity, global_transform: GlobalTransform) {
+    if let Some(mut global) = world.get_mut::<GlobalTransform>(entity) {
+        *global = global_transform;
+    }
+    let Some(children) = world
+        .get::<Children>(entity)
+        .map(|children| children.to_vec())
+    else {
+        return;
+    };
+    for child in children {
+        if world.get::<TransformParent>(child).is_some() {
+            continue;
+        }
+        if let Some(&transform) = world.get::<Transform>(child) {
+            bake_recursive(world, child, global_transform.mul_transform(transform));
+        }
+    }
+}
+
+/// Computes the [`GlobalTransform`] of `entity` from its [`Transform`] and the ones of its
+/// ancestors, or `None` if it doesn't have a [`Transform`].
+fn compute_global_transform(world: &World, entity: Entity) -> Option<GlobalTransform> {
+    let mut global_transform = GlobalTransform::from(*world.get::<Transform>(entity)?);
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        current = parent.get();
+        // Transform propagation stops at ancestors without a `Transform`.
+        let Some(transform) = world.get::<Transform>(current) else {
+            break;
+        };
+        global_transform = *transform * global_transform;
+    }
+    Some(global_transform)
+}
+
+fn global_of(parent: Option<GlobalTransform>, local: Transform) -> GlobalTransform {
+    match parent {
+        Some(parent) => parent.mul_transform(local),
+        None => local.into(),
+    }
+}
+
+fn local_of(parent: Option<GlobalTransform>, global: GlobalTransform) -> Transform {
+    match parent {
+        Some(parent) => global.reparented_to(&parent),
+        None => global.compute_transform(),
+    }
+}
+
+/// Calls `update` with the up-to-date [`GlobalTransform`] of the entity's parent, if it has one,
+/// and the entity's [`Transform`], then syncs the entity's [`GlobalTransform`].
+fn update_world_transform<'a, 'w>(
+    entity: &'a mut EntityWorldMut<'w>,
+    update: impl FnOnce(Option<GlobalTransform>, &mut Transform),
+) -> &'a mut EntityWorldMut<'w> {
+    let id = entity.id();
+    entity.world_scope(|world| {
+        let parent = world
+            .get::<Parent>(id)
+            .and_then(|parent| compute_global_transform(world, parent.get()));
+        let Some(mut transform) = world.get_mut::<Transform>(id) else {
+            return;
+        };
+        update(parent, &mut transform);
+        let global_transform = global_of(parent, *transform);
+        if let Some(mut global) = world.get_mut::<GlobalTransform>(id) {
+            *global = global_transform;
+        }
+    });
+    entity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransformPlugin;
+    use approx::assert_abs_diff_eq;
+    use bevy_app::App;
+    use bevy_ecs::world::CommandQueue;
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+    use core::f32::consts::FRAC_PI_2;
+
+    fn spawn_child(world: &mut World) -> Entity {
+        let parent = world
+            .spawn(
+                Transform::from_xyz(1.0, 2.0, 3.0)
+                    .with_rotation(Quat::from_rotation_y(FRAC_PI_2))
+                    .with_scale(Vec3::splat(2.0)),
+            )
+            .id();
+        let child = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+        world.entity_mut(parent).add_child(child);
+        child
+    }
+
+    #[test]
+    fn set_world_translation() {
+        let mut world = World::new();
+        let child = spawn_child(&mut world);
+
+        world
+            .entity_mut(child)
+            .set_world_translation(Vec3::new(5.0, 6.0, 7.0));
+
+        let global = world.get::<GlobalTransform>(child).unwrap();
+        assert_abs_diff_eq!(
+            global.translation(),
+            Vec3::new(5.0, 6.0, 7.0),
+            epsilon = 1e-5
+        );
+        // The parent has a 90 degree rotation around Y and a scale of 2.
+        let local = world.get::<Transform>(child).unwrap();
+        assert_abs_diff_eq!(local.translation, Vec3::new(-2.0, 2.0, 2.0), epsilon = 1e-5);
+        assert_eq!(local.rotation, Quat::IDENTITY);
+    }
+
+    #[test]
+    fn spawn_at_world() {
+        let mut world = World::new();
+        let parent = spawn_child(&mut world);
+        let global_transform = GlobalTransform::from_xyz(5.0, 6.0, 7.0);
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let mut from_commands = Entity::PLACEHOLDER;
+        commands.entity(parent).with_children(|builder| {
+            from_commands = builder.spawn_at_world((), global_transform).id();
+        });
+        queue.apply(&mut world);
+
+        let mut from_world = Entity::PLACEHOLDER;
+        world.entity_mut(parent).with_children(|builder| {
+            from_world = builder.spawn_at_world((), global_transform).id();
+        });
+
+        for child in [from_commands, from_world] {
+            assert_eq!(world.get::<Parent>(child).unwrap().get(), parent);
+            assert_eq!(
+                *world.get::<GlobalTransform>(child).unwrap(),
+                global_transform
+            );
+            let global = compute_global_transform(&world, child).unwrap();
+            assert_abs_diff_eq!(
+                global.translation(),
+                Vec3::new(5.0, 6.0, 7.0),
+                epsilon = 1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn bake_transforms() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let world = app.world_mut();
+        let root = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+        let child = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+        world.entity_mut(root).add_child(child);
+
+        // Baking computes the `GlobalTransform`s right away.
+        world.bake_transforms(root);
+        let child_translation = |app: &App| {
+            app.world()
+                .get::<GlobalTransform>(child)
+                .unwrap()
+                .translation()
+        };
+        assert_eq!(child_translation(&app), Vec3::new(2.0, 0.0, 0.0));
+
+        app.world_mut()
+            .get_mut::<Transform>(child)
+            .unwrap()
+            .translation
+            .x = 5.0;
+        app.update();
+        assert_eq!(child_translation(&app), Vec3::new(2.0, 0.0, 0.0));
+
+        app.world_mut().invalidate_baked_transforms(root);
+        app.update();
+        assert_eq!(child_translation(&app), Vec3::new(6.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn set_world_rotation_and_scale() {
+        let mut world = World::new();
+        let child = spawn_child(&mut world);
+
+        world
+            .entity_mut(child)
+            .set_world_rotation(Quat::IDENTITY)
+            .set_world_scale(Vec3::ONE);
+
+        let global = world.get::<GlobalTransform>(child).unwrap();
+        let (scale, rotation, translation) = global.to_scale_rotation_translation();
+        assert_abs_diff_eq!(scale, Vec3::ONE, epsilon = 1e-5);
+        assert!(rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        // The child keeps its position in world space.
+        assert_abs_diff_eq!(translation, Vec3::new(1.0, 2.0, 1.0), epsilon = 1e-5);
+        let local = world.get::<Transform>(child).unwrap();
+        assert_abs_diff_eq!(local.scale, Vec3::splat(0.5), epsilon = 1e-5);
+    }
+}
@@ -3,6 +3,7 @@ use core::ops::Mul;
 use super::Transform;
 use bevy_math::{ops, Affine3A, Dir3, Isometry3d, Mat4, Quat, Vec3, Vec3A};
 use derive_more::derive::From;
+use thiserror::Error;
 
 #[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -199,6 +200,48 @@ impl GlobalTransform {
         self.0.to_scale_rotation_translation()
     }
 
+    /// Extracts `scale`, `rotation` and `translation` from `self`, checking that the transform can
+    /// be decomposed into them.
+    ///
+    /// Returns an error if the transform is degenerate, or if its [`shear`](Self::shear) is above
+    /// [`GlobalTransform::SHEAR_TOLERANCE`].
+    #[inline]
+    pub fn to_scale_rotation_translation_checked(
+        &self,
+    ) -> Result<(Vec3, Quat, Vec3), DecompositionError> {
+        let matrix = self.0.matrix3;
+        let determinant = matrix.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return Err(DecompositionError::Degenerate);
+        }
+        let shear = self.shear();
+        if shear > Self::SHEAR_TOLERANCE {
+            return Err(DecompositionError::Shear(shear));
+        }
+        Ok(self.0.to_scale_rotation_translation())
+    }
+
+    /// The [`shear`](Self::shear) above which
+    /// [`to_scale_rotation_translation_checked`](Self::to_scale_rotation_translation_checked)
+    /// considers a transform sheared.
+    pub const SHEAR_TOLERANCE: f32 = 1e-4;
+
+    /// Measures how much the transform shears space, as the largest cosine of the angle between two
+    /// of its axes.
+    ///
+    /// This is `0.0` for transforms made of a scale, a rotation and a translation, which keep the
+    /// axes perpendicular. Shear appears when a child is rotated relative to a parent with a
+    /// non-uniform scale. It is `NaN` for degenerate transforms.
+    #[inline]
+    pub fn shear(&self) -> f32 {
+        let x = self.0.matrix3.x_axis.normalize();
+        let y = self.0.matrix3.y_axis.normalize();
+        let z = self.0.matrix3.z_axis.normalize();
+        ops::abs(x.dot(y))
+            .max(ops::abs(y.dot(z)))
+            .max(ops::abs(z.dot(x)))
+    }
+
     impl_local_axis!(right, left, X);
     impl_local_axis!(up, down, Y);
     impl_local_axis!(back, forward, Z);
@@ -297,6 +340,19 @@ impl GlobalTransform {
     }
 }
 
+/// Error returned by [`GlobalTransform::to_scale_rotation_translation_checked`].
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+pub enum DecompositionError {
+    /// The transform has a zero or non-finite scale.
+    #[error("The transform has a zero or non-finite scale")]
+    Degenerate,
+    /// The transform has shear, which a scale, rotation and translation can't represent.
+    #[error(
+        "The transform has a shear of {0}, which a scale, rotation and translation can't represent"
+    )]
+    Shear(f32),
+}
+
 impl Default for GlobalTransform {
     fn default() -> Self {
         Self::IDENTITY
@@ -411,4 +467,26 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn checked_decomposition() {
+        let parent = GlobalTransform::from(Transform::from_scale(Vec3::new(1.0, 4.0, 1.0)));
+        let child = Transform::from_rotation(Quat::from_rotation_z(0.5));
+        assert!(GlobalTransform::from(child)
+            .to_scale_rotation_translation_checked()
+            .is_ok());
+
+        let sheared = parent * child;
+        assert!(sheared.shear() > GlobalTransform::SHEAR_TOLERANCE);
+        assert!(matches!(
+            sheared.to_scale_rotation_translation_checked(),
+            Err(DecompositionError::Shear(_))
+        ));
+
+        let flat = GlobalTransform::from(Transform::from_scale(Vec3::new(1.0, 0.0, 1.0)));
+        assert_eq!(
+            flat.to_scale_rotation_translation_checked(),
+            Err(DecompositionError::Degenerate)
+        );
+    }
 }
@@ -1,5 +1,15 @@
 mod global_transform;
+#[cfg(feature = "bevy-support")]
+mod static_transform_tree;
 mod transform;
+mod transform_2d;
+#[cfg(feature = "bevy-support")]
+mod transform_parent;
 
 pub use global_transform::*;
+#[cfg(feature = "bevy-support")]
+pub use static_transform_tree::*;
 pub use transform::*;
+pub use transform_2d::*;
+#[cfg(feature = "bevy-support")]
+pub use transform_parent::*;
@@ -0,0 +1,24 @@
+use bevy_ecs::component::Component;
+
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+/// Marks the root of a subtree whose [`GlobalTransform`](super::GlobalTransform)s have been baked,
+/// and are no longer updated by transform propagation.
+///
+/// This is inserted by [`bake_transforms`](crate::commands::BakeTransformsExt::bake_transforms),
+/// which computes the final [`GlobalTransform`](super::GlobalTransform)s of the subtree once.
+/// Skipping large decorative parts of a scene saves the cost of checking them for changes every
+/// frame. Changes to the [`Transform`](super::Transform)s in the subtree, or to the ancestors of
+/// its root, are ignored until it is invalidated with
+/// [`invalidate_baked_transforms`](crate::commands::BakeTransformsExt::invalidate_baked_transforms).
+///
+/// Entities in the subtree with a [`TransformParent`](super::TransformParent) are still updated
+/// every frame.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct StaticTransformTree;
@@ -0,0 +1,325 @@
+use super::{GlobalTransform, Transform};
+use bevy_math::{Affine2, Affine3A, Mat2, Quat, Rot2, Vec2, Vec3A};
+use core::ops::Mul;
+
+#[cfg(feature = "bevy-support")]
+use bevy_ecs::{component::Component, prelude::require};
+
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+/// Describe the position of a 2d entity. If the entity has a parent, the position is relative
+/// to its parent position.
+///
+/// [`Transform2d`] is an opt-in alternative to [`Transform`](super::Transform) for pure 2d games:
+/// its propagation only composes 2d affine transforms, skipping the quaternion and 3d matrix work
+/// of [`Transform`](super::Transform). An entity should have one or the other, but not both.
+///
+/// [`GlobalTransform2d`] is updated from [`Transform2d`] by
+/// [`propagate_transforms_2d`](crate::systems::propagate_transforms_2d), and then converted to the
+/// [`GlobalTransform`] that rendering extracts by
+/// [`sync_global_transforms_2d`](crate::systems::sync_global_transforms_2d). Both run in the system
+/// set [`TransformPropagate`](crate::TransformSystem::TransformPropagate).
+///
+/// [`Transform2d`] takes precedence over [`Transform`](super::Transform): the
+/// [`Transform`](super::Transform) of an entity that has both is overwritten with its
+/// [`Transform2d`], and the 3d propagation skips it.
+///
+/// Hierarchies can mix [`Transform2d`] and [`Transform`](super::Transform) entities. A
+/// [`Transform2d`] entity whose parent has a [`Transform`](super::Transform) is positioned relative
+/// to the projection of its parent on the XY plane, see
+/// [`GlobalTransform2d::from`](GlobalTransform2d#impl-From<GlobalTransform>-for-GlobalTransform2d).
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy-support",
+    derive(Component),
+    require(GlobalTransform2d, GlobalTransform)
+)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+#[cfg_attr(
+    all(feature = "bevy_reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Transform2d {
+    /// Position of the entity.
+    pub translation: Vec2,
+    /// Rotation of the entity.
+    pub rotation: Rot2,
+    /// Scale of the entity.
+    pub scale: Vec2,
+    /// Depth of the entity, used for z-ordering. It is added to the depth of the parent.
+    pub z: f32,
+}
+
+impl Transform2d {
+    /// An identity [`Transform2d`] with no translation, rotation, and a scale of 1 on all axes.
+    pub const IDENTITY: Self = Transform2d {
+        translation: Vec2::ZERO,
+        rotation: Rot2::IDENTITY,
+        scale: Vec2::ONE,
+        z: 0.0,
+    };
+
+    /// Creates a new [`Transform2d`] at the position `(x, y)`, with a depth of `z`.
+    #[inline]
+    pub const fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        Transform2d {
+            translation: Vec2::new(x, y),
+            z,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`Transform2d`] with `translation`.
+    #[inline]
+    pub const fn from_translation(translation: Vec2) -> Self {
+        Transform2d {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`Transform2d`] with `rotation`.
+    #[inline]
+    pub const fn from_rotation(rotation: Rot2) -> Self {
+        Transform2d {
+            rotation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`Transform2d`] with `scale`.
+    #[inline]
+    pub const fn from_scale(scale: Vec2) -> Self {
+        Transform2d {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns this [`Transform2d`] with a new translation.
+    #[inline]
+    #[must_use]
+    pub const fn with_translation(mut self, translation: Vec2) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns this [`Transform2d`] with a new rotation.
+    #[inline]
+    #[must_use]
+    pub const fn with_rotation(mut self, rotation: Rot2) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns this [`Transform2d`] with a new scale.
+    #[inline]
+    #[must_use]
+    pub const fn with_scale(mut self, scale: Vec2) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns this [`Transform2d`] with a new depth.
+    #[inline]
+    #[must_use]
+    pub const fn with_z(mut self, z: f32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// Computes the 2d affine transformation matrix of this transform, ignoring the depth.
+    #[inline]
+    pub fn compute_affine(&self) -> Affine2 {
+        // Built from the cosine and sine directly, to avoid computing the angle.
+        let Rot2 { cos, sin } = self.rotation;
+        Affine2 {
+            matrix2: Mat2::from_cols(
+                Vec2::new(cos, sin) * self.scale.x,
+                Vec2::new(-sin, cos) * self.scale.y,
+            ),
+            translation: self.translation,
+        }
+    }
+}
+
+impl Default for Transform2d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform2d> for Transform {
+    fn from(transform: Transform2d) -> Self {
+        Transform {
+            translation: transform.translation.extend(transform.z),
+            rotation: Quat::from_rotation_z(transform.rotation.as_radians()),
+            scale: transform.scale.extend(1.0),
+        }
+    }
+}
+
+/// The 2d transformation from entity-local coordinates to worldspace coordinates, computed from
+/// [`Transform2d`].
+///
+/// You cannot directly mutate [`GlobalTransform2d`]; instead, change the entity's
+/// [`Transform2d`]. Its [`GlobalTransform`] is kept in sync with it, to be used by rendering.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy-support", derive(Component))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+#[cfg_attr(
+    all(feature = "bevy_reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GlobalTransform2d {
+    affine: Affine2,
+    z: f32,
+}
+
+impl GlobalTransform2d {
+    /// An identity [`GlobalTransform2d`] that maps all points in space to themselves.
+    pub const IDENTITY: Self = Self {
+        affine: Affine2::IDENTITY,
+        z: 0.0,
+    };
+
+    /// Returns the 2d affine transformation matrix.
+    #[inline]
+    pub fn affine(&self) -> Affine2 {
+        self.affine
+    }
+
+    /// Returns the position in worldspace.
+    #[inline]
+    pub fn translation(&self) -> Vec2 {
+        self.affine.translation
+    }
+
+    /// Returns the depth in worldspace.
+    #[inline]
+    pub fn z(&self) -> f32 {
+        self.z
+    }
+
+    /// Transforms the given point from local space to worldspace.
+    #[inline]
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.affine.transform_point2(point)
+    }
+
+    /// Multiplies `self` with `transform` component by component, returning the resulting
+    /// [`GlobalTransform2d`].
+    #[inline]
+    #[must_use]
+    pub fn mul_transform(&self, transform: Transform2d) -> Self {
+        Self {
+            affine: self.affine * transform.compute_affine(),
+            z: self.z + transform.z,
+        }
+    }
+}
+
+impl Default for GlobalTransform2d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform2d> for GlobalTransform2d {
+    fn from(transform: Transform2d) -> Self {
+        Self {
+            affine: transform.compute_affine(),
+            z: transform.z,
+        }
+    }
+}
+
+/// Projects a [`GlobalTransform`] on the XY plane, keeping its depth: rotations out of the plane
+/// are lost.
+impl From<GlobalTransform> for GlobalTransform2d {
+    fn from(transform: GlobalTransform) -> Self {
+        let affine = transform.affine();
+        Self {
+            affine: Affine2 {
+                matrix2: Mat2::from_cols(
+                    affine.matrix3.x_axis.truncate(),
+                    affine.matrix3.y_axis.truncate(),
+                ),
+                translation: affine.translation.truncate(),
+            },
+            z: affine.translation.z,
+        }
+    }
+}
+
+impl From<GlobalTransform2d> for GlobalTransform {
+    fn from(transform: GlobalTransform2d) -> Self {
+        let matrix2 = transform.affine.matrix2;
+        let translation = transform.affine.translation;
+        GlobalTransform::from(Affine3A::from_cols(
+            matrix2.x_axis.extend(0.0).into(),
+            matrix2.y_axis.extend(0.0).into(),
+            Vec3A::Z,
+            translation.extend(transform.z).into(),
+        ))
+    }
+}
+
+impl Mul<Transform2d> for GlobalTransform2d {
+    type Output = GlobalTransform2d;
+
+    #[inline]
+    fn mul(self, transform: Transform2d) -> Self::Output {
+        self.mul_transform(transform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use bevy_math::Vec3;
+    use core::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn matches_3d_transform() {
+        let parent = Transform2d::from_xyz(1.0, 2.0, 3.0)
+            .with_rotation(Rot2::radians(FRAC_PI_2))
+            .with_scale(Vec2::new(2.0, 3.0));
+        let child = Transform2d::from_xyz(4.0, 5.0, 1.0).with_rotation(Rot2::radians(0.5));
+        let global_2d = GlobalTransform::from(GlobalTransform2d::from(parent) * child);
+
+        let to_3d = Transform::from;
+        let global_3d = GlobalTransform::from(to_3d(parent)) * to_3d(child);
+
+        assert_relative_eq!(
+            global_2d.compute_matrix(),
+            global_3d.compute_matrix(),
+            epsilon = 1e-5
+        );
+        assert_eq!(global_2d.translation().z, 4.0);
+        let projected = GlobalTransform2d::from(global_2d);
+        assert_relative_eq!(
+            projected.translation(),
+            (GlobalTransform2d::from(parent) * child).translation(),
+            epsilon = 1e-5
+        );
+        assert_eq!(projected.z(), 4.0);
+        assert_relative_eq!(
+            global_2d.transform_point(Vec3::X),
+            global_3d.transform_point(Vec3::X),
+            epsilon = 1e-5
+        );
+    }
+}
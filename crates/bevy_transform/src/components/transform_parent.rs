@@ -0,0 +1,65 @@
+use bevy_ecs::{
+    component::Component,
+    entity::{Entity, VisitEntities, VisitEntitiesMut},
+    world::{FromWorld, World},
+};
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    bevy_ecs::reflect::{
+        ReflectComponent, ReflectFromWorld, ReflectMapEntities, ReflectVisitEntities,
+        ReflectVisitEntitiesMut,
+    },
+    bevy_reflect::Reflect,
+};
+
+/// Overrides which entity's [`GlobalTransform`](super::GlobalTransform) is used as the parent
+/// when computing the [`GlobalTransform`](super::GlobalTransform) of this entity.
+///
+/// By default, an entity's [`Transform`](super::Transform) is relative to its
+/// [`Parent`](bevy_hierarchy::Parent). With a [`TransformParent`], it is relative to the given
+/// entity instead, while the entity stays where it is in the hierarchy. This lets the logical
+/// hierarchy differ from the spatial one: an item can be owned by an inventory entity while
+/// following the hand bone that holds it.
+///
+/// The descendants of this entity are still positioned relative to it, unless they have a
+/// [`TransformParent`] themselves. If the target entity doesn't exist or has no
+/// [`GlobalTransform`](super::GlobalTransform), the [`Transform`](super::Transform) is used as is,
+/// like for an entity without a parent. Transform parents forming a cycle don't panic, but the
+/// [`GlobalTransform`](super::GlobalTransform)s in the cycle lag a frame behind.
+///
+/// Entities with a [`TransformParent`] are updated every frame by
+/// [`propagate_transform_parents`](crate::systems::propagate_transform_parents), after the rest of
+/// the transform propagation.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, VisitEntities, VisitEntitiesMut)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(
+        Component,
+        MapEntities,
+        VisitEntities,
+        VisitEntitiesMut,
+        PartialEq,
+        Debug,
+        FromWorld
+    )
+)]
+pub struct TransformParent(pub Entity);
+
+impl TransformParent {
+    /// Gets the [`Entity`] ID of the transform parent.
+    #[inline]
+    pub fn get(&self) -> Entity {
+        self.0
+    }
+}
+
+// Reflection creates an instance to apply the deserialized data onto, so `TransformParent` needs
+// a placeholder value, like `Parent`.
+impl FromWorld for TransformParent {
+    #[inline]
+    fn from_world(_world: &mut World) -> Self {
+        TransformParent(Entity::PLACEHOLDER)
+    }
+}
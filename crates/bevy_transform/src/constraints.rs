@@ -0,0 +1,503 @@
+use crate::{
+    components::{GlobalTransform, StaticTransformTree, Transform, Transform2d, TransformParent},
+    plugins::TransformSystem,
+    systems::propagate_children,
+};
+use alloc::vec::Vec;
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut, Ref},
+    component::Component,
+    entity::{Entity, VisitEntities, VisitEntitiesMut},
+    query::{Or, With, Without},
+    schedule::{IntoSystemConfigs, SystemSet},
+    system::{Local, ParamSet, Query},
+    world::{FromWorld, World},
+};
+use bevy_hierarchy::{Children, HierarchyQueryExt, Parent};
+use bevy_math::Vec3;
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    bevy_ecs::reflect::{
+        ReflectComponent, ReflectFromWorld, ReflectMapEntities, ReflectVisitEntities,
+        ReflectVisitEntitiesMut,
+    },
+    bevy_reflect::prelude::*,
+};
+
+/// Rotates this entity so that its forward direction points towards the given entity, keeping
+/// its local up direction as close as possible to [`Vec3::Y`].
+///
+/// Like every constraint, this overrides the [`GlobalTransform`] computed by transform propagation.
+/// See [`TransformConstraintPlugin`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, VisitEntities, VisitEntitiesMut)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(
+        Component,
+        MapEntities,
+        VisitEntities,
+        VisitEntitiesMut,
+        PartialEq,
+        Debug,
+        FromWorld
+    )
+)]
+pub struct LookAt(pub Entity);
+
+impl FromWorld for LookAt {
+    #[inline]
+    fn from_world(_world: &mut World) -> Self {
+        LookAt(Entity::PLACEHOLDER)
+    }
+}
+
+/// Rotates this entity to face the same way as the [`BillboardCamera`], so that it always faces
+/// the screen.
+///
+/// If there are several [`BillboardCamera`]s, an arbitrary one is used. See
+/// [`TransformConstraintPlugin`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct Billboard;
+
+/// Marks the camera that [`Billboard`] entities face.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct BillboardCamera;
+
+/// Moves this entity towards the [`GlobalTransform`] of `source`.
+///
+/// With a `weight` of `1.0` the entity takes the translation, rotation and scale of `source`, and
+/// with a `weight` of `0.0` it keeps its own. See [`TransformConstraintPlugin`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, VisitEntities, VisitEntitiesMut)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(
+        Component,
+        MapEntities,
+        VisitEntities,
+        VisitEntitiesMut,
+        PartialEq,
+        Debug,
+        FromWorld
+    )
+)]
+pub struct CopyTransform {
+    /// The entity whose [`GlobalTransform`] is copied.
+    pub source: Entity,
+    /// How much of the transform of `source` is copied, from `0.0` to `1.0`.
+    #[visit_entities(ignore)]
+    pub weight: f32,
+}
+
+impl CopyTransform {
+    /// Creates a [`CopyTransform`] that fully copies the transform of `source`.
+    pub fn new(source: Entity) -> Self {
+        Self {
+            source,
+            weight: 1.0,
+        }
+    }
+
+    /// Returns this [`CopyTransform`] with a new weight.
+    #[must_use]
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl FromWorld for CopyTransform {
+    #[inline]
+    fn from_world(_world: &mut World) -> Self {
+        CopyTransform::new(Entity::PLACEHOLDER)
+    }
+}
+
+/// Evaluates the [`CopyTransform`], [`LookAt`] and [`Billboard`] constraints.
+///
+/// Constraints run in [`PostUpdate`], after transform propagation, and override the
+/// [`GlobalTransform`] of the constrained entities, whose descendants are then moved along with
+/// them. The [`Transform`] of the constrained entities is left untouched. Like transform
+/// propagation, the descendants are only updated when something changed, and large subtrees are
+/// split across threads with the `multi_threaded` feature.
+///
+/// The unconstrained transform of an entity with a [`TransformParent`] is relative to its
+/// transform parent. Descendants with a [`TransformParent`] aren't moved along with their
+/// constrained ancestor, but entities whose transform parent is a constrained entity, or one of
+/// its descendants, are.
+///
+/// Constrained entities are evaluated from the top of the hierarchy down, so that a constrained
+/// entity sees the constrained [`GlobalTransform`] of its ancestors. An entity with several
+/// constraints applies them in this order: [`CopyTransform`], [`LookAt`], and then [`Billboard`].
+/// Targets are read as they are when the constrained entity is evaluated.
+#[derive(Default)]
+pub struct TransformConstraintPlugin;
+
+impl Plugin for TransformConstraintPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<LookAt>()
+            .register_type::<Billboard>()
+            .register_type::<BillboardCamera>()
+            .register_type::<CopyTransform>();
+
+        app.add_systems(
+            PostUpdate,
+            apply_transform_constraints
+                .in_set(TransformConstraintSystem)
+                .after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Label for the system evaluating transform constraints, which runs in [`PostUpdate`] after
+/// [`TransformSystem::TransformPropagate`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct TransformConstraintSystem;
+
+/// The descendants of constrained entities, propagated like in
+/// [`propagate_transforms`](crate::systems::propagate_transforms).
+type DescendantQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Ref<'static, Transform>,
+        &'static mut GlobalTransform,
+        Option<&'static Children>,
+    ),
+    (
+        With<Parent>,
+        Without<TransformParent>,
+        Without<StaticTransformTree>,
+        Without<Transform2d>,
+    ),
+>;
+
+/// Evaluates the transform constraints. See [`TransformConstraintPlugin`].
+pub fn apply_transform_constraints(
+    constrained_query: Query<
+        (
+            Entity,
+            Option<&CopyTransform>,
+            Option<&LookAt>,
+            Option<&Billboard>,
+            Option<&TransformParent>,
+        ),
+        Or<(With<CopyTransform>, With<LookAt>, With<Billboard>)>,
+    >,
+    follower_query: Query<
+        (Entity, &TransformParent),
+        (Without<CopyTransform>, Without<LookAt>, Without<Billboard>),
+    >,
+    camera_query: Query<Entity, With<BillboardCamera>>,
+    mut transform_queries: ParamSet<(Query<(&Transform, &mut GlobalTransform)>, DescendantQuery)>,
+    parent_query: Query<&Parent>,
+    propagation_parent_query: Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
+    children_query: Query<&Children>,
+    mut ordered: Local<Vec<(usize, Entity)>>,
+) {
+    // Without constrained entities, there are also no followers to update.
+    if constrained_query.is_empty() {
+        return;
+    }
+
+    ordered.clear();
+    ordered.extend(constrained_query.iter().map(|(entity, ..)| {
+        let depth = parent_query.iter_ancestors(entity).count();
+        (depth, entity)
+    }));
+    ordered.sort_unstable();
+
+    let camera = camera_query.iter().next();
+    for &(_, entity) in ordered.iter() {
+        let Ok((_, copy_transform, look_at, billboard, transform_parent)) =
+            constrained_query.get(entity)
+        else {
+            continue;
+        };
+        let mut transform_query = transform_queries.p0();
+        let Ok((&transform, _)) = transform_query.get(entity) else {
+            continue;
+        };
+        // Start over from the unconstrained transform, as the stored `GlobalTransform` may have
+        // been constrained last frame.
+        let parent = match transform_parent {
+            Some(&TransformParent(target)) => global_transform_of(&transform_query, target),
+            None => parent_query
+                .get(entity)
+                .ok()
+                .and_then(|parent| global_transform_of(&transform_query, parent.get())),
+        };
+        let mut global = match parent {
+            Some(parent) => parent.mul_transform(transform),
+            None => GlobalTransform::from(transform),
+        }
+        .compute_transform();
+
+        if let Some(copy_transform) = copy_transform {
+            if let Some(source) = global_transform_of(&transform_query, copy_transform.source) {
+                let source = source.compute_transform();
+                let weight = copy_transform.weight;
+                global.translation = global.translation.lerp(source.translation, weight);
+                global.rotation = global.rotation.slerp(source.rotation, weight);
+                global.scale = global.scale.lerp(source.scale, weight);
+            }
+        }
+        if let Some(&LookAt(target)) = look_at {
+            if let Some(target) = global_transform_of(&transform_query, target) {
+                global.look_at(target.translation(), Vec3::Y);
+            }
+        }
+        if billboard.is_some() {
+            if let Some(camera) =
+                camera.and_then(|camera| global_transform_of(&transform_query, camera))
+            {
+                global.rotation = camera.compute_transform().rotation;
+            }
+        }
+
+        let global = GlobalTransform::from(global);
+        let Ok((_, mut global_transform)) = transform_query.get_mut(entity) else {
+            continue;
+        };
+        // Transform propagation may have reset the constrained `GlobalTransform` this frame, along
+        // with the descendants.
+        let reset = global_transform.is_changed();
+        let changed = global_transform.set_if_neq(global) || reset;
+        propagate_descendants(
+            &transform_queries.p1(),
+            &propagation_parent_query,
+            &children_query,
+            entity,
+            &global,
+            changed,
+        );
+    }
+
+    // Transform parents are propagated before the constraints, so the entities following a
+    // constrained subtree need to be updated again.
+    for (entity, &TransformParent(target)) in &follower_query {
+        let follows_constrained = core::iter::once(target)
+            .chain(parent_query.iter_ancestors(target))
+            .any(|ancestor| constrained_query.contains(ancestor));
+        if !follows_constrained {
+            continue;
+        }
+        let mut transform_query = transform_queries.p0();
+        let Some(parent) = global_transform_of(&transform_query, target) else {
+            continue;
+        };
+        let Ok((transform, mut global_transform)) = transform_query.get_mut(entity) else {
+            continue;
+        };
+        let global = parent.mul_transform(*transform);
+        if global_transform.set_if_neq(global) {
+            propagate_descendants(
+                &transform_queries.p1(),
+                &propagation_parent_query,
+                &children_query,
+                entity,
+                &global,
+                true,
+            );
+        }
+    }
+}
+
+fn global_transform_of(
+    transform_query: &Query<(&Transform, &mut GlobalTransform)>,
+    entity: Entity,
+) -> Option<GlobalTransform> {
+    transform_query
+        .get(entity)
+        .ok()
+        .map(|(_, global_transform)| *global_transform)
+}
+
+/// Recomputes the [`GlobalTransform`] of the descendants of `entity`, whose [`GlobalTransform`] is
+/// `global_transform`, if `changed` or if their own [`Transform`] changed.
+fn propagate_descendants(
+    descendant_query: &DescendantQuery,
+    parent_query: &Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
+    children_query: &Query<&Children>,
+    entity: Entity,
+    global_transform: &GlobalTransform,
+    changed: bool,
+) {
+    let Ok(children) = children_query.get(entity) else {
+        return;
+    };
+    #[expect(
+        unsafe_code,
+        reason = "`propagate_children()` is unsafe due to its use of `Query::get_unchecked()`."
+    )]
+    // SAFETY:
+    // - `descendant_query` isn't fetched anywhere else while the descendants are propagated.
+    // - Each descendant is checked to have `entity` as its parent before it is fetched, so a
+    //   malformed hierarchy panics instead of aliasing.
+    unsafe {
+        propagate_children(
+            global_transform,
+            descendant_query,
+            parent_query,
+            entity,
+            children,
+            changed,
+            0,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransformPlugin;
+    use approx::assert_abs_diff_eq;
+    use bevy_hierarchy::BuildChildren;
+    use bevy_math::Quat;
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+    fn app() -> App {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut app = App::new();
+        app.add_plugins((TransformPlugin, TransformConstraintPlugin));
+        app
+    }
+
+    #[test]
+    fn look_at_moves_descendants() {
+        let mut app = app();
+        let world = app.world_mut();
+        let target = world.spawn(Transform::from_xyz(0.0, 0.0, -10.0)).id();
+        let turret = world
+            .spawn((Transform::from_xyz(0.0, 0.0, 0.0), LookAt(target)))
+            .id();
+        let barrel = world.spawn(Transform::from_xyz(0.0, 0.0, -1.0)).id();
+        world.entity_mut(turret).add_child(barrel);
+        app.update();
+
+        // Already facing the target.
+        let barrel_translation = |app: &App| {
+            app.world()
+                .get::<GlobalTransform>(barrel)
+                .unwrap()
+                .translation()
+        };
+        assert_abs_diff_eq!(barrel_translation(&app), Vec3::NEG_Z, epsilon = 1e-5);
+
+        app.world_mut()
+            .get_mut::<Transform>(target)
+            .unwrap()
+            .translation = Vec3::new(10.0, 0.0, 0.0);
+        app.update();
+        assert_abs_diff_eq!(barrel_translation(&app), Vec3::X, epsilon = 1e-5);
+        assert_eq!(
+            app.world().get::<Transform>(turret).unwrap().rotation,
+            Quat::IDENTITY,
+            "constraints leave the transform untouched"
+        );
+
+        // Nothing changed, so the descendants aren't written again.
+        let last_changed = |app: &App| {
+            app.world()
+                .entity(barrel)
+                .get_ref::<GlobalTransform>()
+                .unwrap()
+                .last_changed()
+        };
+        let before = last_changed(&app);
+        app.update();
+        assert_eq!(last_changed(&app), before);
+        assert_abs_diff_eq!(barrel_translation(&app), Vec3::X, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn constraints_respect_transform_parents() {
+        let mut app = app();
+        let world = app.world_mut();
+        let target = world.spawn(Transform::from_xyz(10.0, 0.0, 0.0)).id();
+        let anchor = world.spawn(Transform::from_xyz(0.0, 5.0, 0.0)).id();
+        let turret = world
+            .spawn((Transform::from_xyz(0.0, 0.0, 0.0), LookAt(target)))
+            .id();
+        // Positioned relative to the anchor, despite being a child of the turret.
+        let detached = world
+            .spawn((Transform::from_xyz(0.0, 0.0, -1.0), TransformParent(anchor)))
+            .id();
+        world.entity_mut(turret).add_child(detached);
+        // Follows the constrained turret, without being in its hierarchy.
+        let follower = world
+            .spawn((Transform::from_xyz(0.0, 0.0, -1.0), TransformParent(turret)))
+            .id();
+        app.update();
+
+        let translation = |entity| {
+            app.world()
+                .get::<GlobalTransform>(entity)
+                .unwrap()
+                .translation()
+        };
+        assert_abs_diff_eq!(
+            translation(detached),
+            Vec3::new(0.0, 5.0, -1.0),
+            epsilon = 1e-5
+        );
+        assert_abs_diff_eq!(translation(follower), Vec3::X, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn copy_transform_with_weight() {
+        let mut app = app();
+        let world = app.world_mut();
+        let source = world.spawn(Transform::from_xyz(4.0, 0.0, 0.0)).id();
+        let follower = world
+            .spawn((
+                Transform::default(),
+                CopyTransform::new(source).with_weight(0.5),
+            ))
+            .id();
+
+        // Evaluating the constraint again doesn't drift.
+        app.update();
+        app.update();
+        let global_transform = app.world().get::<GlobalTransform>(follower).unwrap();
+        assert_abs_diff_eq!(
+            global_transform.translation(),
+            Vec3::new(2.0, 0.0, 0.0),
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn billboard_faces_camera() {
+        let mut app = app();
+        let world = app.world_mut();
+        let rotation = Quat::from_rotation_y(1.0);
+        world.spawn((Transform::from_rotation(rotation), BillboardCamera));
+        let sprite = world
+            .spawn((Transform::from_xyz(1.0, 2.0, 3.0), Billboard))
+            .id();
+        app.update();
+
+        let (_, sprite_rotation, translation) = app
+            .world()
+            .get::<GlobalTransform>(sprite)
+            .unwrap()
+            .to_scale_rotation_translation();
+        assert!(sprite_rotation.abs_diff_eq(rotation, 1e-5));
+        assert_abs_diff_eq!(translation, Vec3::new(1.0, 2.0, 3.0), epsilon = 1e-5);
+    }
+}
@@ -6,6 +6,7 @@ use bevy_ecs::{
     system::{Query, SystemParam},
 };
 use bevy_hierarchy::{HierarchyQueryExt, Parent};
+use bevy_math::{Affine3A, Vec3};
 use thiserror::Error;
 
 use crate::components::{GlobalTransform, Transform};
@@ -46,6 +47,59 @@ impl<'w, 's> TransformHelper<'w, 's> {
 
         Ok(global_transform)
     }
+
+    /// Converts `point` from the local space of `from` to the local space of `to`.
+    ///
+    /// For example, this converts the tip of a muzzle, given relative to the muzzle entity, to the
+    /// space of the turret entity.
+    pub fn transform_point(
+        &self,
+        from: Entity,
+        to: Entity,
+        point: Vec3,
+    ) -> Result<Vec3, ComputeGlobalTransformError> {
+        Ok(self.relative_affine(from, to)?.transform_point3(point))
+    }
+
+    /// Converts `direction` from the local space of `from` to the local space of `to`.
+    ///
+    /// Only the rotation and scale of the entities are applied, so this works for any vector that
+    /// isn't a position, such as an offset or a velocity. Normalize the result if a unit vector is
+    /// needed, as scaling changes its length.
+    pub fn transform_direction(
+        &self,
+        from: Entity,
+        to: Entity,
+        direction: Vec3,
+    ) -> Result<Vec3, ComputeGlobalTransformError> {
+        Ok(self.relative_affine(from, to)?.transform_vector3(direction))
+    }
+
+    /// Converts `pose` from the local space of `from` to the local space of `to`.
+    ///
+    /// The result is the [`Transform`] that a child of `to` would need to have the same
+    /// [`GlobalTransform`] as a child of `from` with the [`Transform`] `pose`.
+    pub fn transform_pose(
+        &self,
+        from: Entity,
+        to: Entity,
+        pose: Transform,
+    ) -> Result<Transform, ComputeGlobalTransformError> {
+        let from = self.compute_global_transform(from)?;
+        let to = self.compute_global_transform(to)?;
+        Ok(from.mul_transform(pose).reparented_to(&to))
+    }
+
+    /// Computes the transformation from the local space of `from` to the local space of `to`.
+    fn relative_affine(
+        &self,
+        from: Entity,
+        to: Entity,
+    ) -> Result<Affine3A, ComputeGlobalTransformError> {
+        let from = self.compute_global_transform(from)?;
+        let to = self.compute_global_transform(to)?;
+        Ok(to.affine().inverse() * from.affine())
+    }
 }
 
 fn map_error(err: QueryEntityError, ancestor: bool) -> ComputeGlobalTransformError {
@@ -63,7 +117,8 @@ fn map_error(err: QueryEntityError, ancestor: bool) -> ComputeGlobalTransformErr
     }
 }
 
-/// Error returned by [`TransformHelper::compute_global_transform`].
+/// Error returned by [`TransformHelper::compute_global_transform`] and the conversions between
+/// entity spaces of [`TransformHelper`].
 #[derive(Debug, Error)]
 pub enum ComputeGlobalTransformError {
     /// The entity or one of its ancestors is missing the [`Transform`] component.
@@ -83,8 +138,9 @@ mod tests {
     use alloc::{vec, vec::Vec};
     use core::f32::consts::TAU;
 
+    use approx::assert_abs_diff_eq;
     use bevy_app::App;
-    use bevy_ecs::system::SystemState;
+    use bevy_ecs::{entity::Entity, system::SystemState, world::World};
     use bevy_hierarchy::BuildChildren;
     use bevy_math::{Quat, Vec3};
 
@@ -115,6 +171,51 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn convert_between_entities() {
+        let mut world = World::new();
+        let turret = world
+            .spawn(
+                Transform::from_xyz(0.0, 1.0, 0.0)
+                    .with_rotation(Quat::from_rotation_y(TAU / 4.))
+                    .with_scale(Vec3::splat(2.)),
+            )
+            .id();
+        let muzzle = world.spawn(Transform::from_xyz(0.0, 0.0, -1.0)).id();
+        world.entity_mut(turret).add_child(muzzle);
+        let target = world.spawn(Transform::from_xyz(5.0, 0.0, 0.0)).id();
+
+        let mut state = SystemState::<TransformHelper>::new(&mut world);
+        let helper = state.get(&world);
+
+        // The muzzle tip, relative to the muzzle, in the space of the turret.
+        let tip = helper
+            .transform_point(muzzle, turret, Vec3::new(0.0, 0.0, -0.5))
+            .unwrap();
+        assert_abs_diff_eq!(tip, Vec3::new(0.0, 0.0, -1.5), epsilon = 1e-5);
+
+        // The turret is rotated a quarter turn around Y and scaled by 2.
+        let direction = helper
+            .transform_direction(muzzle, target, Vec3::NEG_Z)
+            .unwrap();
+        assert_abs_diff_eq!(direction, Vec3::new(-2.0, 0.0, 0.0), epsilon = 1e-5);
+
+        let pose = helper
+            .transform_pose(muzzle, target, Transform::IDENTITY)
+            .unwrap();
+        let muzzle_global = helper.compute_global_transform(muzzle).unwrap();
+        let target_global = helper.compute_global_transform(target).unwrap();
+        assert_abs_diff_eq!(
+            target_global.mul_transform(pose).affine(),
+            muzzle_global.affine(),
+            epsilon = 1e-5
+        );
+
+        assert!(helper
+            .transform_point(muzzle, Entity::PLACEHOLDER, Vec3::ZERO)
+            .is_err());
+    }
+
     fn match_transform_propagation_systems_inner(transforms: Vec<Transform>) {
         let mut app = App::new();
         app.add_plugins(TransformPlugin);
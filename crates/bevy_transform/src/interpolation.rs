@@ -0,0 +1,219 @@
+use crate::{
+    components::Transform, constraints::TransformConstraintSystem, plugins::TransformSystem,
+};
+use bevy_app::{App, FixedFirst, FixedLast, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::Entity,
+    query::{With, Without},
+    schedule::{IntoSystemConfigs, SystemSet},
+    system::{Commands, Query, Res},
+};
+use bevy_time::{FixedInterpolationAlpha, Previous};
+
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+/// Marks an entity whose [`Transform`] is updated at a fixed rate, in
+/// [`FixedUpdate`](bevy_app::FixedUpdate), so that its [`Transform`] is interpolated between the
+/// last two fixed timesteps instead of jumping once per fixed timestep.
+///
+/// This requires the [`TransformInterpolationPlugin`]. The [`Transform`] at the start of each fixed
+/// timestep is recorded in a [`Previous<Transform>`], and the one at the end in a
+/// [`FixedTransform`]. Every frame, before transform propagation, the [`Transform`] is set to the
+/// blend of the two using the [`FixedInterpolationAlpha`], and it is restored to the
+/// [`FixedTransform`] before the next fixed timestep. As a result, the rendered entity lags at most
+/// one fixed timestep behind the simulation, and its descendants move smoothly along with it.
+///
+/// Outside of the fixed timestep schedules, the [`Transform`] of an interpolated entity holds the
+/// interpolated value: change the [`FixedTransform`] instead to move it from there, e.g. to
+/// teleport it.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct InterpolateTransform;
+
+/// The [`Transform`] of an [`InterpolateTransform`] entity at the end of the last fixed timestep.
+///
+/// This is inserted by the [`TransformInterpolationPlugin`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct FixedTransform(pub Transform);
+
+/// Interpolates the [`Transform`] of [`InterpolateTransform`] entities between fixed timesteps.
+///
+/// This requires the [`TimePlugin`](bevy_time::TimePlugin) and the
+/// [`TransformPlugin`](crate::TransformPlugin). The interpolation runs before transform
+/// propagation and the [transform constraints](crate::constraints::TransformConstraintPlugin), so
+/// constraints apply on top of the interpolated transforms.
+#[derive(Default)]
+pub struct TransformInterpolationPlugin;
+
+impl Plugin for TransformInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<InterpolateTransform>()
+            .register_type::<FixedTransform>();
+
+        app.add_systems(
+            FixedFirst,
+            record_previous_transforms.in_set(TransformInterpolationSystem::RecordPrevious),
+        )
+        .add_systems(
+            FixedLast,
+            record_fixed_transforms.in_set(TransformInterpolationSystem::RecordFixed),
+        )
+        .add_systems(
+            PostUpdate,
+            interpolate_transforms
+                .in_set(TransformInterpolationSystem::Interpolate)
+                .before(TransformSystem::TransformPropagate)
+                .before(TransformConstraintSystem),
+        );
+    }
+}
+
+/// Set enum for the systems of the [`TransformInterpolationPlugin`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum TransformInterpolationSystem {
+    /// Restores the [`FixedTransform`] of interpolated entities and records it as their
+    /// [`Previous<Transform>`] at the start of each fixed timestep, in [`FixedFirst`].
+    RecordPrevious,
+    /// Records the [`FixedTransform`] of interpolated entities at the end of each fixed timestep,
+    /// in [`FixedLast`].
+    RecordFixed,
+    /// Writes the interpolated [`Transform`], in [`PostUpdate`] before transform propagation.
+    Interpolate,
+}
+
+/// Restores the [`Transform`] of [`InterpolateTransform`] entities to their [`FixedTransform`],
+/// and copies it into their [`Previous<Transform>`], inserting it if missing.
+pub fn record_previous_transforms(
+    mut commands: Commands,
+    mut tracked: Query<
+        (
+            &mut Transform,
+            Option<&FixedTransform>,
+            &mut Previous<Transform>,
+        ),
+        With<InterpolateTransform>,
+    >,
+    untracked: Query<
+        (Entity, &Transform),
+        (With<InterpolateTransform>, Without<Previous<Transform>>),
+    >,
+) {
+    for (mut transform, fixed, mut previous) in &mut tracked {
+        if let Some(fixed) = fixed {
+            transform.set_if_neq(fixed.0);
+        }
+        previous.0 = *transform;
+    }
+    for (entity, transform) in &untracked {
+        commands.entity(entity).insert(Previous(*transform));
+    }
+}
+
+/// Copies the [`Transform`] of [`InterpolateTransform`] entities into their [`FixedTransform`],
+/// inserting it if missing.
+pub fn record_fixed_transforms(
+    mut commands: Commands,
+    mut tracked: Query<(&Transform, &mut FixedTransform), With<InterpolateTransform>>,
+    untracked: Query<(Entity, &Transform), (With<InterpolateTransform>, Without<FixedTransform>)>,
+) {
+    for (transform, mut fixed) in &mut tracked {
+        fixed.set_if_neq(FixedTransform(*transform));
+    }
+    for (entity, transform) in &untracked {
+        commands.entity(entity).insert(FixedTransform(*transform));
+    }
+}
+
+/// Sets the [`Transform`] of [`InterpolateTransform`] entities from their
+/// [`Previous<Transform>`] and [`FixedTransform`], blended by the [`FixedInterpolationAlpha`].
+///
+/// The [`Transform`] is only written when the interpolated value differs, so that entities at
+/// rest don't trigger transform propagation.
+pub fn interpolate_transforms(
+    alpha: Res<FixedInterpolationAlpha>,
+    mut query: Query<
+        (&mut Transform, &Previous<Transform>, &FixedTransform),
+        With<InterpolateTransform>,
+    >,
+) {
+    let alpha = alpha.0;
+    query
+        .par_iter_mut()
+        .for_each(|(mut transform, previous, fixed)| {
+            transform.set_if_neq(Transform {
+                translation: previous.0.translation.lerp(fixed.0.translation, alpha),
+                rotation: previous.0.rotation.slerp(fixed.0.rotation, alpha),
+                scale: previous.0.scale.lerp(fixed.0.scale, alpha),
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::GlobalTransform, TransformPlugin};
+    use bevy_app::FixedUpdate;
+    use bevy_hierarchy::BuildChildren;
+    use bevy_math::Vec3;
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+    use bevy_time::{Fixed, Time, TimePlugin, TimeUpdateStrategy};
+
+    fn step(mut query: Query<&mut Transform, With<InterpolateTransform>>) {
+        for mut transform in &mut query {
+            transform.translation.x += 1.0;
+        }
+    }
+
+    #[test]
+    fn interpolates_between_fixed_steps() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let timestep = Time::<Fixed>::default().timestep();
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin, TransformInterpolationPlugin))
+            .add_systems(FixedUpdate, step)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(timestep * 3 / 2));
+        let world = app.world_mut();
+        let entity = world
+            .spawn((Transform::default(), InterpolateTransform))
+            .id();
+        let child = world.spawn(Transform::from_xyz(0.0, 1.0, 0.0)).id();
+        world.entity_mut(entity).add_child(child);
+
+        // The first update doesn't advance time, and the second one runs a fixed step.
+        let fixed_x = |app: &App| {
+            app.world()
+                .get::<FixedTransform>(entity)
+                .unwrap()
+                .0
+                .translation
+                .x
+        };
+        app.update();
+        app.update();
+        assert_eq!(fixed_x(&app), 1.0);
+
+        // Halfway between the previous fixed step and the current one, along with the child.
+        let world = app.world();
+        let translation = |entity| world.get::<GlobalTransform>(entity).unwrap().translation();
+        assert!(translation(entity).abs_diff_eq(Vec3::new(0.5, 0.0, 0.0), 1e-3));
+        assert!(translation(child).abs_diff_eq(Vec3::new(0.5, 1.0, 0.0), 1e-3));
+
+        // The next two fixed steps start from the fixed transform, not the interpolated one.
+        app.update();
+        assert_eq!(fixed_x(&app), 3.0);
+    }
+}
@@ -16,6 +16,9 @@ extern crate alloc;
 pub mod commands;
 /// The basic components of the transform crate
 pub mod components;
+/// Constraints overriding the global transform of entities
+#[cfg(feature = "bevy-support")]
+pub mod constraints;
 
 /// Transform related traits
 pub mod traits;
@@ -32,6 +35,14 @@ pub mod helper;
 #[cfg(feature = "bevy-support")]
 pub mod systems;
 
+/// Rounding of global transforms to a fixed precision
+#[cfg(feature = "bevy-support")]
+pub mod quantization;
+
+/// Interpolation of transforms between fixed timesteps
+#[cfg(feature = "bevy_time")]
+pub mod interpolation;
+
 /// The transform prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
@@ -43,11 +54,25 @@ pub mod prelude {
     #[cfg(feature = "bevy-support")]
     #[doc(hidden)]
     pub use crate::{
-        commands::BuildChildrenTransformExt,
+        commands::{
+            BakeTransformsExt, BuildChildrenTransformExt, ChildBuildTransformExt, WorldTransformExt,
+        },
+        constraints::{
+            Billboard, BillboardCamera, CopyTransform, LookAt, TransformConstraintPlugin,
+        },
         helper::TransformHelper,
         plugins::{TransformPlugin, TransformSystem},
+        quantization::{TransformQuantization, TransformQuantizationPlugin},
         traits::TransformPoint,
     };
+
+    #[cfg(feature = "shear_detection")]
+    #[doc(hidden)]
+    pub use crate::plugins::TransformShearDetectionPlugin;
+
+    #[cfg(feature = "bevy_time")]
+    #[doc(hidden)]
+    pub use crate::interpolation::{InterpolateTransform, TransformInterpolationPlugin};
 }
 
 #[cfg(feature = "bevy-support")]
@@ -4,11 +4,19 @@ use bevy_hierarchy::ValidParentCheckPlugin;
 
 use crate::{
     components::GlobalTransform,
-    systems::{propagate_transforms, sync_simple_transforms},
+    systems::{
+        propagate_transform_parents, propagate_transforms, propagate_transforms_2d,
+        sync_global_transforms_2d, sync_simple_transforms,
+    },
 };
 
+#[cfg(feature = "shear_detection")]
+use crate::systems::detect_transform_shear;
+
 #[cfg(feature = "bevy_reflect")]
-use crate::components::Transform;
+use crate::components::{
+    GlobalTransform2d, StaticTransformTree, Transform, Transform2d, TransformParent,
+};
 
 /// Set enum for the systems relating to transform propagation
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -30,7 +38,11 @@ impl Plugin for TransformPlugin {
 
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<Transform>()
-            .register_type::<GlobalTransform>();
+            .register_type::<GlobalTransform>()
+            .register_type::<TransformParent>()
+            .register_type::<StaticTransformTree>()
+            .register_type::<Transform2d>()
+            .register_type::<GlobalTransform2d>();
 
         app.add_plugins(ValidParentCheckPlugin::<GlobalTransform>::default())
             .configure_sets(
@@ -48,6 +60,14 @@ impl Plugin for TransformPlugin {
                         // due to subtle query filtering that is not yet correctly computed in the ambiguity detector
                         .ambiguous_with(PropagateTransformsSet),
                     propagate_transforms.in_set(PropagateTransformsSet),
+                    (propagate_transforms_2d, sync_global_transforms_2d)
+                        .chain()
+                        .in_set(TransformSystem::TransformPropagate)
+                        .after(sync_simple_transforms)
+                        .after(PropagateTransformsSet),
+                    propagate_transform_parents
+                        .in_set(TransformSystem::TransformPropagate)
+                        .after(sync_global_transforms_2d),
                 ),
             )
             .configure_sets(
@@ -61,7 +81,33 @@ impl Plugin for TransformPlugin {
                         .in_set(TransformSystem::TransformPropagate)
                         .ambiguous_with(PropagateTransformsSet),
                     propagate_transforms.in_set(PropagateTransformsSet),
+                    (propagate_transforms_2d, sync_global_transforms_2d)
+                        .chain()
+                        .in_set(TransformSystem::TransformPropagate)
+                        .after(sync_simple_transforms)
+                        .after(PropagateTransformsSet),
+                    propagate_transform_parents
+                        .in_set(TransformSystem::TransformPropagate)
+                        .after(sync_global_transforms_2d),
                 ),
             );
     }
 }
+
+/// Warns about entities with a sheared [`GlobalTransform`], pointing at the hierarchy that causes
+/// it. See [`detect_transform_shear`].
+///
+/// This is a debugging aid, and isn't part of the [`TransformPlugin`].
+#[cfg(feature = "shear_detection")]
+#[derive(Default)]
+pub struct TransformShearDetectionPlugin;
+
+#[cfg(feature = "shear_detection")]
+impl Plugin for TransformShearDetectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            detect_transform_shear.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    components::GlobalTransform, constraints::TransformConstraintSystem, plugins::TransformSystem,
+};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    query::Changed,
+    schedule::{IntoSystemConfigs, SystemSet},
+    system::{Query, Res, Resource},
+};
+use bevy_math::{ops, Affine3A, Mat3A, Vec3A};
+
+/// Configures the precision [`GlobalTransform`]s are rounded to by the
+/// [`TransformQuantizationPlugin`].
+///
+/// Steps should be powers of two, such as `1.0 / 1024.0`, so that every rounded value is exactly
+/// representable as a float.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TransformQuantization {
+    /// The step translations are rounded to, in world units.
+    pub translation_step: f32,
+    /// The step the components of the rotation and scale matrix are rounded to.
+    pub basis_step: f32,
+}
+
+impl Default for TransformQuantization {
+    fn default() -> Self {
+        Self {
+            translation_step: 1.0 / 1024.0,
+            basis_step: 1.0 / 65536.0,
+        }
+    }
+}
+
+impl TransformQuantization {
+    /// Rounds `global_transform` to the configured precision.
+    pub fn quantize(&self, global_transform: &GlobalTransform) -> GlobalTransform {
+        let affine = global_transform.affine();
+        GlobalTransform::from(Affine3A {
+            matrix3: Mat3A::from_cols(
+                round_to_step(affine.matrix3.x_axis, self.basis_step),
+                round_to_step(affine.matrix3.y_axis, self.basis_step),
+                round_to_step(affine.matrix3.z_axis, self.basis_step),
+            ),
+            translation: round_to_step(affine.translation, self.translation_step),
+        })
+    }
+}
+
+fn round_to_step(value: Vec3A, step: f32) -> Vec3A {
+    Vec3A::new(
+        ops::round(value.x / step) * step,
+        ops::round(value.y / step) * step,
+        ops::round(value.z / step) * step,
+    )
+}
+
+/// Rounds every [`GlobalTransform`] to a fixed precision after transform propagation, as
+/// configured by the [`TransformQuantization`] resource.
+///
+/// Floating point results can differ slightly between platforms and compilers. Rounding them
+/// makes the [`GlobalTransform`]s read by gameplay systems match across machines, which lockstep
+/// networking and replays rely on. Values that fall close to a rounding boundary can still round
+/// differently, so this reduces divergence rather than guaranteeing bit-exact results.
+#[derive(Default)]
+pub struct TransformQuantizationPlugin {
+    /// The precision to round to. See [`TransformQuantization`].
+    pub quantization: TransformQuantization,
+}
+
+impl Plugin for TransformQuantizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.quantization).add_systems(
+            PostUpdate,
+            quantize_global_transforms
+                .in_set(TransformQuantizationSystem)
+                .after(TransformSystem::TransformPropagate)
+                .after(TransformConstraintSystem),
+        );
+    }
+}
+
+/// Label for the system rounding [`GlobalTransform`]s, which runs in [`PostUpdate`] after
+/// [`TransformSystem::TransformPropagate`] and the transform constraints.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct TransformQuantizationSystem;
+
+/// Rounds the [`GlobalTransform`]s that changed since the last run to the precision of the
+/// [`TransformQuantization`] resource.
+pub fn quantize_global_transforms(
+    quantization: Res<TransformQuantization>,
+    mut query: Query<&mut GlobalTransform, Changed<GlobalTransform>>,
+) {
+    query.par_iter_mut().for_each(|mut global_transform| {
+        let quantized = quantization.quantize(&global_transform);
+        global_transform.set_if_neq(quantized);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::Transform, TransformPlugin};
+    use bevy_math::Vec3;
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+    #[test]
+    fn quantize_after_propagation() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TransformQuantizationPlugin {
+                quantization: TransformQuantization {
+                    translation_step: 0.25,
+                    basis_step: 0.5,
+                },
+            },
+        ));
+        let entity = app
+            .world_mut()
+            .spawn(Transform::from_xyz(1.1, -2.9, 0.3).with_scale(Vec3::new(1.2, 1.0, 0.8)))
+            .id();
+        app.update();
+
+        let (scale, _, translation) = app
+            .world()
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .to_scale_rotation_translation();
+        assert_eq!(translation, Vec3::new(1.0, -3.0, 0.25));
+        assert_eq!(scale, Vec3::new(1.0, 1.0, 1.0));
+    }
+}
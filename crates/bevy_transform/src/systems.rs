@@ -1,13 +1,33 @@
-use crate::components::{GlobalTransform, Transform};
+use crate::components::{
+    GlobalTransform, GlobalTransform2d, StaticTransformTree, Transform, Transform2d,
+    TransformParent,
+};
 use alloc::vec::Vec;
 use bevy_ecs::{
-    change_detection::Ref,
+    change_detection::{DetectChangesMut, Mut, Ref},
+    entity::EntityHashSet,
     prelude::{Changed, DetectChanges, Entity, Query, With, Without},
     query::{Added, Or},
     removal_detection::RemovedComponents,
     system::{Local, ParamSet},
 };
-use bevy_hierarchy::{Children, Parent};
+use bevy_hierarchy::{Children, HierarchyQueryExt, Parent};
+
+#[cfg(feature = "multi_threaded")]
+use bevy_tasks::ComputeTaskPool;
+
+#[cfg(feature = "shear_detection")]
+use {
+    alloc::{format, string::String},
+    bevy_ecs::name::Name,
+};
+
+#[cfg(feature = "propagation_diagnostics")]
+use {
+    bevy_ecs::system::{ResMut, Resource},
+    bevy_utils::{Instant, Parallel},
+    core::time::Duration,
+};
 
 /// Update [`GlobalTransform`] component of entities that aren't in the hierarchy
 ///
@@ -20,9 +40,21 @@ pub fn sync_simple_transforms(
                 Or<(Changed<Transform>, Added<GlobalTransform>)>,
                 Without<Parent>,
                 Without<Children>,
+                Without<TransformParent>,
+                Without<StaticTransformTree>,
+                Without<Transform2d>,
+            ),
+        >,
+        Query<
+            (Ref<Transform>, &mut GlobalTransform),
+            (
+                Without<Parent>,
+                Without<Children>,
+                Without<TransformParent>,
+                Without<StaticTransformTree>,
+                Without<Transform2d>,
             ),
         >,
-        Query<(Ref<Transform>, &mut GlobalTransform), (Without<Parent>, Without<Children>)>,
     )>,
     mut orphaned: RemovedComponents<Parent>,
 ) {
@@ -43,59 +75,283 @@ pub fn sync_simple_transforms(
     }
 }
 
+/// Subtrees are split across the [`ComputeTaskPool`] at every multiple of this depth, if the
+/// entity at that depth has at least [`PARALLEL_SPLIT_MIN_CHILDREN`] children.
+#[cfg(feature = "multi_threaded")]
+const PARALLEL_SPLIT_DEPTH: usize = 4;
+
+/// The minimum number of children an entity needs for its subtree to be split across tasks.
+#[cfg(feature = "multi_threaded")]
+const PARALLEL_SPLIT_MIN_CHILDREN: usize = 64;
+
+/// The number of children propagated by each task when a subtree is split.
+#[cfg(feature = "multi_threaded")]
+const PARALLEL_SPLIT_CHUNK_SIZE: usize = 32;
+
+/// Records how long [`propagate_transforms`] spent in each root subtree of the hierarchy.
+///
+/// Insert this resource to enable recording, which otherwise has no cost. It is overwritten every
+/// time [`propagate_transforms`] runs, and only contains the subtrees that were visited: roots
+/// without [`Children`] are handled by [`sync_simple_transforms`].
+#[cfg(feature = "propagation_diagnostics")]
+#[derive(Resource, Debug, Default)]
+pub struct TransformPropagationDiagnostics {
+    subtrees: Vec<SubtreePropagation>,
+}
+
+/// The time spent propagating the transforms of a root subtree, recorded in
+/// [`TransformPropagationDiagnostics`].
+#[cfg(feature = "propagation_diagnostics")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubtreePropagation {
+    /// The root entity of the subtree.
+    pub root: Entity,
+    /// The time spent propagating the transforms of the subtree.
+    pub duration: Duration,
+}
+
+#[cfg(feature = "propagation_diagnostics")]
+impl TransformPropagationDiagnostics {
+    /// Returns the subtrees visited by the last propagation, in no particular order.
+    pub fn subtrees(&self) -> &[SubtreePropagation] {
+        &self.subtrees
+    }
+
+    /// Returns the subtree that took the longest to propagate.
+    pub fn slowest(&self) -> Option<&SubtreePropagation> {
+        self.subtrees.iter().max_by_key(|subtree| subtree.duration)
+    }
+
+    /// Returns the total time spent propagating subtrees, summed over all threads.
+    pub fn total(&self) -> Duration {
+        self.subtrees.iter().map(|subtree| subtree.duration).sum()
+    }
+}
+
 /// Update [`GlobalTransform`] component of entities based on entity hierarchy and
 /// [`Transform`] component.
 ///
+/// Root subtrees are propagated in parallel. With the `multi_threaded` feature, subtrees with many
+/// children are further split across the `ComputeTaskPool`, so that a single very large hierarchy
+/// doesn't end up on a single thread. With the `propagation_diagnostics` feature, insert a
+/// [`TransformPropagationDiagnostics`] resource to measure the time spent in each root subtree.
+///
+/// Subtrees marked with a [`StaticTransformTree`] are skipped.
+///
 /// Third party plugins should ensure that this is used in concert with [`sync_simple_transforms`].
 pub fn propagate_transforms(
     mut root_query: Query<
         (Entity, &Children, Ref<Transform>, &mut GlobalTransform),
-        Without<Parent>,
+        (
+            Without<Parent>,
+            Without<TransformParent>,
+            Without<StaticTransformTree>,
+            Without<Transform2d>,
+        ),
     >,
     mut orphaned: RemovedComponents<Parent>,
-    transform_query: Query<(Ref<Transform>, &mut GlobalTransform, Option<&Children>), With<Parent>>,
+    transform_query: Query<
+        (Ref<Transform>, &mut GlobalTransform, Option<&Children>),
+        (
+            With<Parent>,
+            Without<TransformParent>,
+            Without<StaticTransformTree>,
+            Without<Transform2d>,
+        ),
+    >,
     parent_query: Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
     mut orphaned_entities: Local<Vec<Entity>>,
+    #[cfg(feature = "propagation_diagnostics")] diagnostics: Option<
+        ResMut<TransformPropagationDiagnostics>,
+    >,
+    #[cfg(feature = "propagation_diagnostics")] mut subtree_timings: Local<
+        Parallel<Vec<SubtreePropagation>>,
+    >,
 ) {
     orphaned_entities.clear();
     orphaned_entities.extend(orphaned.read());
     orphaned_entities.sort_unstable();
+    #[cfg(feature = "propagation_diagnostics")]
+    let record_timings = diagnostics.is_some();
     root_query.par_iter_mut().for_each(
         |(entity, children, transform, mut global_transform)| {
+            #[cfg(feature = "propagation_diagnostics")]
+            let start = record_timings.then(Instant::now);
+
             let changed = transform.is_changed() || global_transform.is_added() || orphaned_entities.binary_search(&entity).is_ok();
             if changed {
                 *global_transform = GlobalTransform::from(*transform);
             }
 
-            for (child, actual_parent) in parent_query.iter_many(children) {
-                assert_eq!(
-                    actual_parent.get(), entity,
-                    "Malformed hierarchy. This probably means that your hierarchy has been improperly maintained, or contains a cycle"
+            // SAFETY:
+            // - `entity` is a root, so the entire hierarchy leading to its children is consistent.
+            // - We may operate as if all descendants are consistent, since `propagate_children` will panic before
+            //   continuing to propagate if it encounters an entity with inconsistent parentage.
+            // - Since each root entity is unique and the hierarchy is consistent and forest-like,
+            //   other root entities' `propagate_children` calls will not conflict with this one.
+            // - Since this is the only place where `transform_query` gets used, there will be no conflicting fetches elsewhere.
+            #[expect(unsafe_code, reason = "`propagate_children()` is unsafe due to its use of `Query::get_unchecked()`.")]
+            unsafe {
+                propagate_children(
+                    &global_transform,
+                    &transform_query,
+                    &parent_query,
+                    entity,
+                    children,
+                    changed,
+                    0,
                 );
-                // SAFETY:
-                // - `child` must have consistent parentage, or the above assertion would panic.
-                // Since `child` is parented to a root entity, the entire hierarchy leading to it is consistent.
-                // - We may operate as if all descendants are consistent, since `propagate_recursive` will panic before 
-                //   continuing to propagate if it encounters an entity with inconsistent parentage.
-                // - Since each root entity is unique and the hierarchy is consistent and forest-like,
-                //   other root entities' `propagate_recursive` calls will not conflict with this one.
-                // - Since this is the only place where `transform_query` gets used, there will be no conflicting fetches elsewhere.
-                #[expect(unsafe_code, reason = "`propagate_recursive()` is unsafe due to its use of `Query::get_unchecked()`.")]
-                unsafe {
-                    propagate_recursive(
-                        &global_transform,
-                        &transform_query,
-                        &parent_query,
-                        child,
-                        changed || actual_parent.is_changed(),
-                    );
-                }
+            }
+
+            #[cfg(feature = "propagation_diagnostics")]
+            if let Some(start) = start {
+                subtree_timings.borrow_local_mut().push(SubtreePropagation {
+                    root: entity,
+                    duration: start.elapsed(),
+                });
             }
         },
     );
+
+    #[cfg(feature = "propagation_diagnostics")]
+    if let Some(mut diagnostics) = diagnostics {
+        diagnostics.subtrees.clear();
+        subtree_timings.drain_into(&mut diagnostics.subtrees);
+    }
 }
 
-/// Recursively propagates the transforms for `entity` and all of its descendants.
+/// Propagates the transforms of the `children` of `entity`, at `depth` in the hierarchy, and all
+/// of their descendants.
+///
+/// With the `multi_threaded` feature, entities with many children at depths that are a multiple
+/// of `PARALLEL_SPLIT_DEPTH` have them split into chunks that are propagated by separate tasks of
+/// the `ComputeTaskPool`. Idle threads steal those tasks, which balances very large subtrees
+/// across threads.
+///
+/// # Panics
+///
+/// If `entity`'s descendants have a malformed hierarchy, this function will panic occur before propagating
+/// the transforms of any malformed entities and their descendants.
+///
+/// # Safety
+///
+/// - While this function is running, `transform_query` must not have any fetches for the descendants of
+///     `entity`.
+/// - The caller must ensure that the hierarchy leading to `entity`
+///     is well-formed and must remain as a tree or a forest. Each entity must have at most one parent.
+#[expect(
+    unsafe_code,
+    reason = "This function calls `propagate_recursive()`, which uses `Query::get_unchecked()`."
+)]
+pub(crate) unsafe fn propagate_children(
+    parent: &GlobalTransform,
+    transform_query: &Query<
+        (Ref<Transform>, &mut GlobalTransform, Option<&Children>),
+        (
+            With<Parent>,
+            Without<TransformParent>,
+            Without<StaticTransformTree>,
+            Without<Transform2d>,
+        ),
+    >,
+    parent_query: &Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
+    entity: Entity,
+    children: &[Entity],
+    changed: bool,
+    depth: usize,
+) {
+    #[cfg(feature = "multi_threaded")]
+    if depth % PARALLEL_SPLIT_DEPTH == 0 && children.len() >= PARALLEL_SPLIT_MIN_CHILDREN {
+        if let Some(task_pool) = ComputeTaskPool::try_get() {
+            let parent = *parent;
+            task_pool.scope(|scope| {
+                for chunk in children.chunks(PARALLEL_SPLIT_CHUNK_SIZE) {
+                    scope.spawn(async move {
+                        // SAFETY: The chunks are disjoint, and the caller guarantees that
+                        // `transform_query` will not be fetched for any descendants of `entity`
+                        // for as long as this scope is running.
+                        unsafe {
+                            propagate_children_serial(
+                                &parent,
+                                transform_query,
+                                parent_query,
+                                entity,
+                                chunk,
+                                changed,
+                                depth,
+                            );
+                        }
+                    });
+                }
+            });
+            return;
+        }
+    }
+    // SAFETY: Guaranteed by the caller.
+    unsafe {
+        propagate_children_serial(
+            parent,
+            transform_query,
+            parent_query,
+            entity,
+            children,
+            changed,
+            depth,
+        );
+    }
+}
+
+/// Like [`propagate_children`], without splitting `children` across tasks.
+///
+/// # Safety
+///
+/// See [`propagate_children`].
+#[expect(
+    unsafe_code,
+    reason = "This function calls `propagate_recursive()`, which uses `Query::get_unchecked()`."
+)]
+unsafe fn propagate_children_serial(
+    parent: &GlobalTransform,
+    transform_query: &Query<
+        (Ref<Transform>, &mut GlobalTransform, Option<&Children>),
+        (
+            With<Parent>,
+            Without<TransformParent>,
+            Without<StaticTransformTree>,
+            Without<Transform2d>,
+        ),
+    >,
+    parent_query: &Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
+    entity: Entity,
+    children: &[Entity],
+    changed: bool,
+    depth: usize,
+) {
+    for (child, actual_parent) in parent_query.iter_many(children) {
+        assert_eq!(
+            actual_parent.get(), entity,
+            "Malformed hierarchy. This probably means that your hierarchy has been improperly maintained, or contains a cycle"
+        );
+        // SAFETY: The caller guarantees that `transform_query` will not be fetched
+        // for any descendants of `entity`, so it is safe to call `propagate_recursive` for each child.
+        //
+        // The above assertion ensures that each child has one and only one unique parent throughout the
+        // entire hierarchy.
+        unsafe {
+            propagate_recursive(
+                parent,
+                transform_query,
+                parent_query,
+                child,
+                changed || actual_parent.is_changed(),
+                depth + 1,
+            );
+        }
+    }
+}
+
+/// Recursively propagates the transforms for `entity`, at `depth` in the hierarchy, and all of its
+/// descendants.
 ///
 /// # Panics
 ///
@@ -116,16 +372,23 @@ unsafe fn propagate_recursive(
     parent: &GlobalTransform,
     transform_query: &Query<
         (Ref<Transform>, &mut GlobalTransform, Option<&Children>),
-        With<Parent>,
+        (
+            With<Parent>,
+            Without<TransformParent>,
+            Without<StaticTransformTree>,
+            Without<Transform2d>,
+        ),
     >,
     parent_query: &Query<(Entity, Ref<Parent>), With<GlobalTransform>>,
     entity: Entity,
     mut changed: bool,
+    depth: usize,
 ) {
     let (global_matrix, children) = {
         let Ok((transform, mut global_transform, children)) =
             // SAFETY: This call cannot create aliased mutable references.
-            //   - The top level iteration parallelizes on the roots of the hierarchy.
+            //   - The top level iteration parallelizes on the roots of the hierarchy, and subtrees are
+            //     only split between disjoint sets of children.
             //   - The caller ensures that each child has one and only one unique parent throughout the entire
             //     hierarchy.
             //
@@ -162,20 +425,313 @@ unsafe fn propagate_recursive(
     };
 
     let Some(children) = children else { return };
+    // SAFETY: The caller guarantees that `transform_query` will not be fetched for `entity` or any
+    // of its descendants, and `global_matrix` is not accessed through it.
+    unsafe {
+        propagate_children(
+            global_matrix.as_ref(),
+            transform_query,
+            parent_query,
+            entity,
+            children,
+            changed,
+            depth,
+        );
+    }
+}
+
+/// Update [`GlobalTransform`] component of entities with a [`TransformParent`], and of their
+/// descendants.
+///
+/// Entities with a [`TransformParent`] are skipped by [`sync_simple_transforms`] and
+/// [`propagate_transforms`], so this must run after them. Their [`GlobalTransform`] is recomputed
+/// every time this runs, as their transform parent can be anywhere in the world.
+pub fn propagate_transform_parents(
+    attached: Query<Entity, With<TransformParent>>,
+    transform_parent_query: Query<&'static TransformParent>,
+    mut detached: RemovedComponents<TransformParent>,
+    mut transform_query: Query<(&'static Transform, &'static mut GlobalTransform)>,
+    children_query: Query<&'static Children>,
+    parent_query: Query<&'static Parent>,
+    mut updated: Local<EntityHashSet>,
+    mut in_progress: Local<EntityHashSet>,
+) {
+    updated.clear();
+    in_progress.clear();
+    let mut propagation = TransformParentPropagation {
+        transform_parent_query: &transform_parent_query,
+        transform_query: &mut transform_query,
+        children_query: &children_query,
+        parent_query: &parent_query,
+        updated: &mut updated,
+        in_progress: &mut in_progress,
+    };
+
+    // Entities that lost their `TransformParent` are positioned relative to their `Parent` again,
+    // but `propagate_transforms` only updates them once something in their hierarchy changes.
+    for entity in detached.read() {
+        if transform_parent_query.contains(entity) {
+            continue;
+        }
+        let parent = parent_query
+            .get(entity)
+            .ok()
+            .and_then(|parent| propagation.transform_query.get(parent.get()).ok())
+            .map(|(_, global_transform)| *global_transform);
+        propagation.set_global_transform(entity, parent);
+    }
+
+    for entity in &attached {
+        propagation.update(entity);
+    }
+}
+
+/// The state of [`propagate_transform_parents`]. Only the mutably borrowed query needs its own
+/// `'w` and `'s` lifetimes, the others are shortened to `'a`.
+struct TransformParentPropagation<'a, 'w, 's> {
+    transform_parent_query: &'a Query<'a, 'a, &'static TransformParent>,
+    transform_query: &'a mut Query<'w, 's, (&'static Transform, &'static mut GlobalTransform)>,
+    children_query: &'a Query<'a, 'a, &'static Children>,
+    parent_query: &'a Query<'a, 'a, &'static Parent>,
+    updated: &'a mut EntityHashSet,
+    in_progress: &'a mut EntityHashSet,
+}
+
+impl TransformParentPropagation<'_, '_, '_> {
+    /// Updates the [`GlobalTransform`] of `entity`, which has a [`TransformParent`], and its
+    /// descendants, after updating the transform parent itself if needed.
+    fn update(&mut self, entity: Entity) {
+        if self.updated.contains(&entity) || !self.in_progress.insert(entity) {
+            // Already up to date, or part of a cycle.
+            return;
+        }
+        let Ok(&TransformParent(target)) = self.transform_parent_query.get(entity) else {
+            return;
+        };
+
+        // The target is positioned relative to its closest ancestor-or-self with a
+        // `TransformParent`, which has to be updated first.
+        let mut ancestor = target;
+        loop {
+            if self.transform_parent_query.contains(ancestor) {
+                self.update(ancestor);
+                break;
+            }
+            match self.parent_query.get(ancestor) {
+                Ok(parent) => ancestor = parent.get(),
+                Err(_) => break,
+            }
+        }
+
+        self.in_progress.remove(&entity);
+        self.updated.insert(entity);
+        let parent = self
+            .transform_query
+            .get(target)
+            .ok()
+            .map(|(_, global_transform)| *global_transform);
+        self.set_global_transform(entity, parent);
+    }
+
+    /// Sets the [`GlobalTransform`] of `entity` relative to `parent`, and propagates it to the
+    /// descendants that don't have a [`TransformParent`].
+    fn set_global_transform(&mut self, entity: Entity, parent: Option<GlobalTransform>) {
+        let Ok((transform, mut global_transform)) = self.transform_query.get_mut(entity) else {
+            return;
+        };
+        let new_global_transform = match parent {
+            Some(parent) => parent.mul_transform(*transform),
+            None => GlobalTransform::from(*transform),
+        };
+        global_transform.set_if_neq(new_global_transform);
+
+        let Ok(children) = self.children_query.get(entity) else {
+            return;
+        };
+        for &child in children {
+            if !self.transform_parent_query.contains(child) {
+                self.set_global_transform(child, Some(new_global_transform));
+            }
+        }
+    }
+}
+
+/// The global transform of the parent of an entity visited by [`propagate_transforms_2d`].
+#[derive(Clone, Copy)]
+enum ParentGlobalTransform {
+    TwoD(GlobalTransform2d),
+    ThreeD(GlobalTransform),
+}
+
+/// The [`Transform2d`] entities visited by [`propagate_transforms_2d`].
+type Transform2dQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Ref<'static, Transform2d>,
+        &'static mut GlobalTransform2d,
+        Option<&'static Children>,
+    ),
+>;
+
+/// The [`Transform`] entities below [`Transform2d`] entities, visited by
+/// [`propagate_transforms_2d`].
+type Transform3dQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Ref<'static, Transform>,
+        // `Mut` rather than `&mut`, so that the read-only query gives a `Ref` to the parents.
+        Mut<'static, GlobalTransform>,
+        Option<&'static Children>,
+    ),
+    (
+        Without<Transform2d>,
+        Without<TransformParent>,
+        Without<StaticTransformTree>,
+    ),
+>;
+
+/// Update [`GlobalTransform2d`] component of entities based on entity hierarchy and
+/// [`Transform2d`] component.
+///
+/// This is the 2d counterpart of [`sync_simple_transforms`] and [`propagate_transforms`], which
+/// skip [`Transform2d`] entities. It also updates the [`GlobalTransform`] of the
+/// [`Transform`] entities below [`Transform2d`] entities, and positions the [`Transform2d`]
+/// entities below [`Transform`] entities relative to them, so it must run after those systems.
+/// Root subtrees are propagated in parallel.
+///
+/// Third party plugins should ensure that it is used in concert with [`sync_global_transforms_2d`].
+pub fn propagate_transforms_2d(
+    root_query: Query<(Entity, Option<Ref<Parent>>), With<Transform2d>>,
+    ancestor_query: Query<&Parent>,
+    is_2d_query: Query<(), With<Transform2d>>,
+    mut orphaned: RemovedComponents<Parent>,
+    transform_2d_query: Transform2dQuery,
+    transform_3d_query: Transform3dQuery,
+    parent_query: Query<(Entity, Ref<Parent>)>,
+    mut orphaned_entities: Local<Vec<Entity>>,
+) {
+    orphaned_entities.clear();
+    orphaned_entities.extend(orphaned.read());
+    orphaned_entities.sort_unstable();
+    root_query.par_iter().for_each(|(entity, parent)| {
+        let mut changed = orphaned_entities.binary_search(&entity).is_ok();
+        let mut parent_global = None;
+        if let Some(parent) = parent {
+            // Only the topmost `Transform2d` entities are roots of the 2d propagation: the ones
+            // below them are visited from there, even through `Transform` entities.
+            if ancestor_query
+                .iter_ancestors(entity)
+                .any(|ancestor| is_2d_query.contains(ancestor))
+            {
+                return;
+            }
+            changed |= parent.is_changed();
+            if let Ok((_, global_transform, _)) = transform_3d_query.get(parent.get()) {
+                changed |= global_transform.is_changed();
+                parent_global = Some(ParentGlobalTransform::ThreeD(*global_transform));
+            }
+        }
+
+        // SAFETY:
+        // - `entity` is the topmost `Transform2d` entity of its hierarchy, so other roots aren't
+        //   among its descendants, and the hierarchy leading to it is only read.
+        // - `propagate_recursive_2d` panics before continuing to propagate if it encounters an
+        //   entity with inconsistent parentage, so subtrees visited in parallel don't overlap.
+        // - `transform_2d_query` and `transform_3d_query` are only used through
+        //   `propagate_recursive_2d`, apart from reading the parent above, which has no
+        //   `Transform2d` ancestor and so isn't part of any subtree visited by this system.
+        #[expect(unsafe_code, reason = "`propagate_recursive_2d()` is unsafe due to its use of `Query::get_unchecked()`.")]
+        unsafe {
+            propagate_recursive_2d(
+                parent_global,
+                &transform_2d_query,
+                &transform_3d_query,
+                &parent_query,
+                entity,
+                changed,
+            );
+        }
+    });
+}
+
+/// Recursively propagates the 2d transforms for `entity` and all of its descendants.
+///
+/// # Panics
+///
+/// If `entity`'s descendants have a malformed hierarchy, this function will panic occur before
+/// propagating the transforms of any malformed entities and their descendants.
+///
+/// # Safety
+///
+/// - While this function is running, `transform_2d_query` and `transform_3d_query` must not have
+///     any fetches for `entity`, nor any of its descendants.
+/// - The caller must ensure that the hierarchy leading to `entity`
+///     is well-formed and must remain as a tree or a forest. Each entity must have at most one parent.
+#[expect(
+    unsafe_code,
+    reason = "This function uses `Query::get_unchecked()`, which can result in multiple mutable references if the preconditions are not met."
+)]
+unsafe fn propagate_recursive_2d(
+    parent: Option<ParentGlobalTransform>,
+    transform_2d_query: &Transform2dQuery,
+    transform_3d_query: &Transform3dQuery,
+    parent_query: &Query<(Entity, Ref<Parent>)>,
+    entity: Entity,
+    mut changed: bool,
+) {
+    // SAFETY: The caller guarantees that the queries aren't fetched for `entity` elsewhere, and
+    // the assertion below ensures that each child has one and only one unique parent.
+    let (global, children) = if let Ok((transform, mut global_transform, children)) =
+        unsafe { transform_2d_query.get_unchecked(entity) }
+    {
+        changed |= transform.is_changed() || global_transform.is_added();
+        if changed {
+            *global_transform = match parent {
+                Some(ParentGlobalTransform::TwoD(parent)) => parent.mul_transform(*transform),
+                Some(ParentGlobalTransform::ThreeD(parent)) => {
+                    GlobalTransform2d::from(parent).mul_transform(*transform)
+                }
+                None => GlobalTransform2d::from(*transform),
+            };
+        }
+        (ParentGlobalTransform::TwoD(*global_transform), children)
+    } else if let Ok((transform, mut global_transform, children)) =
+        // SAFETY: See above.
+        unsafe { transform_3d_query.get_unchecked(entity) }
+    {
+        changed |= transform.is_changed() || global_transform.is_added();
+        if changed {
+            *global_transform = match parent {
+                Some(ParentGlobalTransform::TwoD(parent)) => {
+                    GlobalTransform::from(parent).mul_transform(*transform)
+                }
+                Some(ParentGlobalTransform::ThreeD(parent)) => parent.mul_transform(*transform),
+                None => GlobalTransform::from(*transform),
+            };
+        }
+        (ParentGlobalTransform::ThreeD(*global_transform), children)
+    } else {
+        return;
+    };
+
+    let Some(children) = children else {
+        return;
+    };
     for (child, actual_parent) in parent_query.iter_many(children) {
         assert_eq!(
             actual_parent.get(), entity,
             "Malformed hierarchy. This probably means that your hierarchy has been improperly maintained, or contains a cycle"
         );
-        // SAFETY: The caller guarantees that `transform_query` will not be fetched
-        // for any descendants of `entity`, so it is safe to call `propagate_recursive` for each child.
-        //
-        // The above assertion ensures that each child has one and only one unique parent throughout the
-        // entire hierarchy.
+        // SAFETY: The caller guarantees that the queries will not be fetched for any descendants
+        // of `entity`, and the above assertion ensures that each child has one and only one
+        // unique parent throughout the entire hierarchy.
         unsafe {
-            propagate_recursive(
-                global_matrix.as_ref(),
-                transform_query,
+            propagate_recursive_2d(
+                Some(global),
+                transform_2d_query,
+                transform_3d_query,
                 parent_query,
                 child,
                 changed || actual_parent.is_changed(),
@@ -184,12 +740,101 @@ unsafe fn propagate_recursive(
     }
 }
 
+/// Update the [`GlobalTransform`] of [`Transform2d`] entities from their [`GlobalTransform2d`],
+/// so that they can be rendered like any other entity, and overwrite their [`Transform`], if
+/// they have one, with their [`Transform2d`].
+///
+/// This must run after [`propagate_transforms_2d`].
+pub fn sync_global_transforms_2d(
+    mut query: Query<
+        (
+            &Transform2d,
+            &GlobalTransform2d,
+            &mut GlobalTransform,
+            Option<&mut Transform>,
+        ),
+        Or<(
+            Changed<Transform2d>,
+            Changed<GlobalTransform2d>,
+            Changed<Transform>,
+            Added<GlobalTransform>,
+        )>,
+    >,
+) {
+    query.par_iter_mut().for_each(
+        |(transform_2d, global_transform_2d, mut global_transform, transform)| {
+            global_transform.set_if_neq(GlobalTransform::from(*global_transform_2d));
+            if let Some(mut transform) = transform {
+                transform.set_if_neq(Transform::from(*transform_2d));
+            }
+        },
+    );
+}
+
+/// Warns about entities whose [`GlobalTransform`] becomes sheared while the one of their parent
+/// isn't, which happens when an entity is rotated relative to a parent with a non-uniform scale.
+///
+/// Shear can't be represented by a [`Transform`], so decomposing such a [`GlobalTransform`] gives
+/// invalid results, and the rendered entity looks skewed. The warning lists the hierarchy leading
+/// to the entity, with the scale of each ancestor, and is only printed once per entity.
+#[cfg(feature = "shear_detection")]
+pub fn detect_transform_shear(
+    query: Query<(Entity, &GlobalTransform, &Parent), Changed<GlobalTransform>>,
+    global_transform_query: Query<&GlobalTransform>,
+    transform_query: Query<(&Transform, Option<&Name>)>,
+    parent_query: Query<&Parent>,
+    mut removed: RemovedComponents<GlobalTransform>,
+    mut already_diagnosed: Local<EntityHashSet>,
+) {
+    // Forget the entities that were despawned, or no longer have a `GlobalTransform`.
+    for entity in removed.read() {
+        already_diagnosed.remove(&entity);
+    }
+
+    for (entity, global_transform, parent) in &query {
+        if global_transform.shear() <= GlobalTransform::SHEAR_TOLERANCE
+            || already_diagnosed.contains(&entity)
+        {
+            continue;
+        }
+        let Ok(parent_global_transform) = global_transform_query.get(parent.get()) else {
+            continue;
+        };
+        let parent_shear = parent_global_transform.shear();
+        if parent_shear > GlobalTransform::SHEAR_TOLERANCE || parent_shear.is_nan() {
+            // The shear comes from further up the hierarchy, which is diagnosed there.
+            continue;
+        }
+        already_diagnosed.insert(entity);
+
+        let describe = |entity: Entity| -> String {
+            match transform_query.get(entity) {
+                Ok((transform, Some(name))) => {
+                    format!("{name} ({entity}, scale {})", transform.scale)
+                }
+                Ok((transform, None)) => format!("{entity} (scale {})", transform.scale),
+                Err(_) => format!("{entity}"),
+            }
+        };
+        let mut chain: Vec<String> = parent_query.iter_ancestors(entity).map(describe).collect();
+        chain.reverse();
+        chain.push(describe(entity));
+        log::warn!(
+            "{entity} has a sheared `GlobalTransform`, because it is rotated relative to its parent \
+            {}, whose global scale of {} is non-uniform. Hierarchy: {}",
+            parent.get(),
+            parent_global_transform.to_scale_rotation_translation().0,
+            chain.join(" -> "),
+        );
+    }
+}
+
 #[cfg(test)]
 mod test {
     use alloc::vec;
     use bevy_app::prelude::*;
     use bevy_ecs::{prelude::*, world::CommandQueue};
-    use bevy_math::{vec3, Vec3};
+    use bevy_math::{vec3, Vec2, Vec3};
     use bevy_tasks::{ComputeTaskPool, TaskPool};
 
     use crate::systems::*;
@@ -517,4 +1162,234 @@ mod test {
             *world.entity(child).get::<GlobalTransform>().unwrap()
         );
     }
+
+    #[test]
+    fn transform_parent_overrides_hierarchy() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            sync_simple_transforms,
+            propagate_transforms,
+            propagate_transform_parents
+                .after(sync_simple_transforms)
+                .after(propagate_transforms),
+        ));
+
+        let hand = world.spawn(Transform::from_xyz(0.0, 1.0, 0.0)).id();
+        let inventory = world.spawn(Transform::from_xyz(10.0, 0.0, 0.0)).id();
+        let item = world
+            .spawn((Transform::from_xyz(0.0, 0.0, 1.0), TransformParent(hand)))
+            .set_parent(inventory)
+            .id();
+        let gem = world.spawn(Transform::from_xyz(2.0, 0.0, 0.0)).id();
+        world.entity_mut(item).add_child(gem);
+        schedule.run(&mut world);
+
+        assert_eq!(
+            *world.get::<GlobalTransform>(item).unwrap(),
+            GlobalTransform::from_xyz(0.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            *world.get::<GlobalTransform>(gem).unwrap(),
+            GlobalTransform::from_xyz(2.0, 1.0, 1.0)
+        );
+
+        // The item follows the hand.
+        world.get_mut::<Transform>(hand).unwrap().translation.y = 2.0;
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(gem).unwrap(),
+            GlobalTransform::from_xyz(2.0, 2.0, 1.0)
+        );
+
+        // Without a transform parent, the item goes back to following the inventory.
+        world.entity_mut(item).remove::<TransformParent>();
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(gem).unwrap(),
+            GlobalTransform::from_xyz(12.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn did_propagate_2d() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((propagate_transforms_2d, sync_global_transforms_2d).chain());
+
+        let mut child = Entity::PLACEHOLDER;
+        let parent = world
+            .spawn(Transform2d::from_xyz(1.0, 0.0, 1.0))
+            .with_children(|parent| {
+                child = parent.spawn(Transform2d::from_xyz(0.0, 2.0, 1.0)).id();
+            })
+            .id();
+        schedule.run(&mut world);
+
+        assert_eq!(
+            world.get::<GlobalTransform2d>(child).unwrap().translation(),
+            Vec2::new(1.0, 2.0)
+        );
+        assert_eq!(
+            *world.get::<GlobalTransform>(child).unwrap(),
+            GlobalTransform::from_xyz(1.0, 2.0, 2.0)
+        );
+
+        world.get_mut::<Transform2d>(parent).unwrap().translation.x = 3.0;
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(child).unwrap(),
+            GlobalTransform::from_xyz(3.0, 2.0, 2.0)
+        );
+
+        // Orphaned entities are positioned relative to the world again.
+        world.entity_mut(child).remove_parent();
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(child).unwrap(),
+            GlobalTransform::from_xyz(0.0, 2.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn propagate_mixed_2d_3d() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                (sync_simple_transforms, propagate_transforms),
+                propagate_transforms_2d,
+                sync_global_transforms_2d,
+            )
+                .chain(),
+        );
+
+        let root = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+        // `Transform2d` takes precedence over `Transform`.
+        let child_2d = world
+            .spawn((
+                Transform2d::from_xyz(0.0, 2.0, 1.0),
+                Transform::from_xyz(9.0, 9.0, 9.0),
+            ))
+            .set_parent(root)
+            .id();
+        let grandchild_3d = world
+            .spawn(Transform::from_xyz(0.0, 0.0, 1.0))
+            .set_parent(child_2d)
+            .id();
+        let leaf_2d = world
+            .spawn(Transform2d::from_xyz(1.0, 0.0, 0.0))
+            .set_parent(grandchild_3d)
+            .id();
+        schedule.run(&mut world);
+
+        let global = |world: &World, entity| *world.get::<GlobalTransform>(entity).unwrap();
+        assert_eq!(
+            *world.get::<Transform>(child_2d).unwrap(),
+            Transform::from_xyz(0.0, 2.0, 1.0)
+        );
+        assert_eq!(
+            global(&world, child_2d),
+            GlobalTransform::from_xyz(1.0, 2.0, 1.0)
+        );
+        assert_eq!(
+            global(&world, grandchild_3d),
+            GlobalTransform::from_xyz(1.0, 2.0, 2.0)
+        );
+        assert_eq!(
+            global(&world, leaf_2d),
+            GlobalTransform::from_xyz(2.0, 2.0, 2.0)
+        );
+
+        world.get_mut::<Transform>(root).unwrap().translation.x = 5.0;
+        schedule.run(&mut world);
+        assert_eq!(
+            global(&world, child_2d),
+            GlobalTransform::from_xyz(5.0, 2.0, 1.0)
+        );
+        assert_eq!(
+            global(&world, leaf_2d),
+            GlobalTransform::from_xyz(6.0, 2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn propagate_2d_siblings_under_3d_parent() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                (sync_simple_transforms, propagate_transforms),
+                propagate_transforms_2d,
+            )
+                .chain(),
+        );
+
+        // The 2d siblings are propagated in parallel, and all read their shared parent.
+        let root = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+        let siblings: Vec<_> = (0..64)
+            .map(|i| {
+                world
+                    .spawn(Transform2d::from_xyz(0.0, i as f32, 0.0))
+                    .set_parent(root)
+                    .id()
+            })
+            .collect();
+        schedule.run(&mut world);
+
+        world.get_mut::<Transform>(root).unwrap().translation.x = 3.0;
+        schedule.run(&mut world);
+        for (i, sibling) in siblings.into_iter().enumerate() {
+            assert_eq!(
+                *world.get::<GlobalTransform2d>(sibling).unwrap(),
+                GlobalTransform2d::from(GlobalTransform::from_xyz(3.0, i as f32, 0.0))
+            );
+        }
+    }
+
+    #[test]
+    fn propagate_split_subtrees() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+        #[cfg(feature = "propagation_diagnostics")]
+        world.init_resource::<TransformPropagationDiagnostics>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+
+        // Wide enough for the children of the root to be split across tasks, with the
+        // `multi_threaded` feature.
+        let root = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+        let mut leaves = Vec::new();
+        for i in 0..128 {
+            let child = world.spawn(Transform::from_xyz(0.0, i as f32, 0.0)).id();
+            world.entity_mut(root).add_child(child);
+            let leaf = world.spawn(Transform::from_xyz(0.0, 0.0, 1.0)).id();
+            world.entity_mut(child).add_child(leaf);
+            leaves.push((i, leaf));
+        }
+        schedule.run(&mut world);
+
+        for (i, leaf) in leaves {
+            assert_eq!(
+                *world.get::<GlobalTransform>(leaf).unwrap(),
+                GlobalTransform::from_xyz(1.0, i as f32, 1.0)
+            );
+        }
+
+        #[cfg(feature = "propagation_diagnostics")]
+        {
+            let diagnostics = world.resource::<TransformPropagationDiagnostics>();
+            assert_eq!(diagnostics.subtrees().len(), 1);
+            assert_eq!(diagnostics.slowest().unwrap().root, root);
+        }
+    }
 }
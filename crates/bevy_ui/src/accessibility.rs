@@ -2,7 +2,7 @@ use crate::{
     experimental::UiChildren,
     prelude::{Button, Label},
     widget::{ImageNode, TextUiReader},
-    ComputedNode,
+    ComputedNode, UiSystem,
 };
 use bevy_a11y::AccessibilityNode;
 use bevy_app::{App, Plugin, PostUpdate};
@@ -162,9 +162,8 @@ impl Plugin for AccessibilityPlugin {
                     .after(CameraUpdateSystem)
                     // the listed systems do not affect calculated size
                     .ambiguous_with(crate::ui_stack_system),
-                button_changed,
-                image_changed,
-                label_changed,
+                // the labels are read once the text inputs updated their text
+                (button_changed, image_changed, label_changed).after(UiSystem::Prepare),
             ),
         );
     }
@@ -63,7 +63,15 @@ impl UiRect {
     }
 }
 
-pub fn from_node(node: &Node, context: &LayoutContext, ignore_border: bool) -> taffy::style::Style {
+/// Converts `node` to a taffy style. `grid_area` is the placement of the rows and columns of its
+/// [`Node::grid_area`], resolved in the areas of its parent, which overrides its own placement.
+pub fn from_node(
+    node: &Node,
+    context: &LayoutContext,
+    ignore_border: bool,
+    grid_area: Option<(GridPlacement, GridPlacement)>,
+) -> taffy::style::Style {
+    let (grid_row, grid_column) = grid_area.unwrap_or((node.grid_row, node.grid_column));
     taffy::style::Style {
         display: node.display.into(),
         box_sizing: node.box_sizing.into(),
@@ -144,8 +152,8 @@ pub fn from_node(node: &Node, context: &LayoutContext, ignore_border: bool) -> t
             .iter()
             .map(|track| track.into_taffy_track(context))
             .collect::<Vec<_>>(),
-        grid_row: node.grid_row.into(),
-        grid_column: node.grid_column.into(),
+        grid_row: grid_row.into(),
+        grid_column: grid_column.into(),
     }
 }
 
@@ -522,9 +530,11 @@ mod tests {
             ],
             grid_column: GridPlacement::start(4),
             grid_row: GridPlacement::span(3),
+            grid_template_areas: Vec::new(),
+            grid_area: None,
         };
         let viewport_values = LayoutContext::new(1.0, bevy_math::Vec2::new(800., 600.));
-        let taffy_style = from_node(&node, &viewport_values, false);
+        let taffy_style = from_node(&node, &viewport_values, false, None);
         assert_eq!(taffy_style.display, taffy::style::Display::Flex);
         assert_eq!(taffy_style.box_sizing, taffy::style::BoxSizing::ContentBox);
         assert_eq!(taffy_style.position, taffy::style::Position::Absolute);
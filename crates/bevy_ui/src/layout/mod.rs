@@ -1,7 +1,7 @@
 use crate::{
     experimental::{UiChildren, UiRootNodes},
-    BorderRadius, ComputedNode, ContentSize, DefaultUiCamera, Display, LayoutConfig, Node, Outline,
-    OverflowAxis, ScrollPosition, TargetCamera, UiScale, Val,
+    BorderRadius, ComputedNode, ContentSize, DefaultUiCamera, Display, GridPlacement, LayoutConfig,
+    Node, Outline, OverflowAxis, ScrollPosition, TargetCamera, UiLayoutOptions, UiScale, Val,
 };
 use bevy_ecs::{
     change_detection::{DetectChanges, DetectChangesMut},
@@ -86,6 +86,13 @@ pub struct UiLayoutSystemBuffers {
     interned_root_nodes: Vec<Vec<Entity>>,
     resized_windows: EntityHashSet,
     camera_layout_info: EntityHashMap<CameraLayoutInfo>,
+    grid_areas: EntityHashMap<ResolvedGridArea>,
+}
+
+/// The placement of the [`Node::grid_area`] of a node in the areas of its parent.
+struct ResolvedGridArea {
+    placement: Option<(GridPlacement, GridPlacement)>,
+    parent_changed: bool,
 }
 
 struct CameraLayoutInfo {
@@ -101,7 +108,7 @@ pub fn ui_layout_system(
     mut buffers: Local<UiLayoutSystemBuffers>,
     primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
     camera_data: (Query<(Entity, &Camera)>, DefaultUiCamera),
-    ui_scale: Res<UiScale>,
+    ui_options: (Res<UiScale>, Res<UiLayoutOptions>),
     mut scale_factor_events: EventReader<WindowScaleFactorChanged>,
     mut resize_events: EventReader<bevy_window::WindowResized>,
     mut ui_surface: ResMut<UiSurface>,
@@ -132,9 +139,11 @@ pub fn ui_layout_system(
         interned_root_nodes,
         resized_windows,
         camera_layout_info,
+        grid_areas,
     } = &mut *buffers;
 
     let (cameras, default_ui_camera) = camera_data;
+    let (ui_scale, layout_options) = ui_options;
 
     let default_camera = default_ui_camera.get();
     let camera_with_default = |target_camera: Option<&TargetCamera>| {
@@ -201,10 +210,32 @@ pub fn ui_layout_system(
         ui_surface.try_remove_node_context(entity);
     }
 
+    // Resolve the named grid areas, which are defined by the parent of each node
+    grid_areas.clear();
+    for (entity, node, _, _) in &node_query {
+        let Some(name) = node.grid_area.as_deref() else {
+            continue;
+        };
+        let Some((_, parent_node, _, _)) = ui_children
+            .get_parent(entity)
+            .and_then(|parent| node_query.get(parent).ok())
+        else {
+            continue;
+        };
+        grid_areas.insert(
+            entity,
+            ResolvedGridArea {
+                placement: parent_node.resolve_grid_area(name).ok(),
+                parent_changed: parent_node.is_changed(),
+            },
+        );
+    }
+
     // Sync Node and ContentSize to Taffy for all nodes
     node_query
         .iter_mut()
         .for_each(|(entity, node, content_size, target_camera)| {
+            let grid_area = grid_areas.get(&entity);
             if let Some(camera) =
                 camera_with_default(target_camera).and_then(|c| camera_layout_info.get(&c))
             {
@@ -212,6 +243,7 @@ pub fn ui_layout_system(
                     || !scale_factor_events.is_empty()
                     || ui_scale.is_changed()
                     || node.is_changed()
+                    || grid_area.is_some_and(|grid_area| grid_area.parent_changed)
                     || content_size
                         .as_ref()
                         .is_some_and(|c| c.is_changed() || c.measure.is_some())
@@ -221,10 +253,17 @@ pub fn ui_layout_system(
                         [camera.size.x as f32, camera.size.y as f32].into(),
                     );
                     let measure = content_size.and_then(|mut c| c.measure.take());
-                    ui_surface.upsert_node(&layout_context, entity, &node, measure);
+                    let grid_area = grid_area.and_then(|grid_area| grid_area.placement);
+                    ui_surface.upsert_node(&layout_context, entity, &node, grid_area, measure);
                 }
             } else {
-                ui_surface.upsert_node(&LayoutContext::DEFAULT, entity, &Node::default(), None);
+                ui_surface.upsert_node(
+                    &LayoutContext::DEFAULT,
+                    entity,
+                    &Node::default(),
+                    None,
+                    None,
+                );
             }
         });
     scale_factor_events.clear();
@@ -294,7 +333,7 @@ with UI components as a child of an entity without UI components, your UI layout
                 &mut commands,
                 *root,
                 &mut ui_surface,
-                true,
+                layout_options.pixel_snapping,
                 None,
                 &mut node_transform_query,
                 &ui_children,
@@ -367,6 +406,7 @@ with UI components as a child of an entity without UI components, your UI layout
 
             let content_size = Vec2::new(layout.content_size.width, layout.content_size.height);
             node.bypass_change_detection().content_size = content_size;
+            node.bypass_change_detection().pixel_snapping = use_rounding;
 
             let taffy_rect_to_border_rect = |rect: taffy::Rect<f32>| BorderRect {
                 left: rect.left,
@@ -411,6 +451,11 @@ with UI components as a child of an entity without UI components, your UI layout
                 .resolve(node.size().x, viewport_size)
                 .unwrap_or(0.)
                 .max(0.);
+
+                if use_rounding {
+                    node.outline_width = node.outline_width.round();
+                    node.outline_offset = node.outline_offset.round();
+                }
             }
 
             if transform.translation.truncate() != node_center {
@@ -472,6 +517,7 @@ mod tests {
     use taffy::TraversePartialTree;
 
     use bevy_asset::{AssetEvent, Assets};
+    use bevy_color::Color;
     use bevy_core_pipeline::core_2d::Camera2d;
     use bevy_ecs::{
         entity::Entity,
@@ -499,8 +545,11 @@ mod tests {
     };
 
     use crate::{
-        layout::ui_surface::UiSurface, prelude::*, ui_layout_system,
-        update::update_target_camera_system, ContentSize, LayoutContext,
+        layout::ui_surface::UiSurface,
+        prelude::*,
+        ui_layout_system,
+        update::{update_grid_areas_system, update_target_camera_system},
+        ContentSize, LayoutContext, UiLayoutOptions,
     };
 
     // these window dimensions are easy to convert to and from percentage values
@@ -510,6 +559,7 @@ mod tests {
     fn setup_ui_test_world() -> (World, Schedule) {
         let mut world = World::new();
         world.init_resource::<UiScale>();
+        world.init_resource::<UiLayoutOptions>();
         world.init_resource::<UiSurface>();
         world.init_resource::<Events<WindowScaleFactorChanged>>();
         world.init_resource::<Events<WindowResized>>();
@@ -1036,6 +1086,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn grid_area_places_node_in_parent_area() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+        ui_schedule.add_systems(update_grid_areas_system.before(ui_layout_system));
+
+        let grid = world
+            .spawn(Node {
+                display: Display::Grid,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                grid_template_columns: RepeatedGridTrack::flex(2, 1.),
+                grid_template_rows: RepeatedGridTrack::flex(2, 1.),
+                grid_template_areas: vec!["header header".into(), "sidebar main".into()],
+                ..default()
+            })
+            .id();
+        let item = world
+            .spawn(Node {
+                grid_area: Some("main".into()),
+                ..default()
+            })
+            .id();
+        world.entity_mut(grid).add_child(item);
+
+        let location = |world: &mut World| {
+            let mut ui_surface = world.resource_mut::<UiSurface>();
+            let layout = ui_surface.get_layout(item, true).unwrap().0;
+            (layout.location.x, layout.location.y, layout.size.width)
+        };
+
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            location(&mut world),
+            (WINDOW_WIDTH / 2., WINDOW_HEIGHT / 2., WINDOW_WIDTH / 2.)
+        );
+        // The area is only resolved for the layout, the placement of the node is untouched.
+        let node = world.get::<Node>(item).unwrap();
+        assert_eq!(node.grid_row, GridPlacement::DEFAULT);
+        assert_eq!(node.grid_column, GridPlacement::DEFAULT);
+
+        // Changing the areas of the parent moves the node.
+        world.get_mut::<Node>(grid).unwrap().grid_template_areas =
+            vec!["main main".into(), "sidebar header".into()];
+        ui_schedule.run(&mut world);
+        assert_eq!(location(&mut world), (0., 0., WINDOW_WIDTH));
+
+        // Without an area, the node is placed by its own placement again.
+        world.get_mut::<Node>(item).unwrap().grid_area = None;
+        world.get_mut::<Node>(item).unwrap().grid_column = GridPlacement::start(2);
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            location(&mut world),
+            (WINDOW_WIDTH / 2., 0., WINDOW_WIDTH / 2.)
+        );
+    }
+
     #[test]
     fn ui_node_should_be_set_to_its_content_size() {
         let (mut world, mut ui_schedule) = setup_ui_test_world();
@@ -1151,10 +1257,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ui_pixel_snapping_test() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let node = world
+            .spawn((
+                Node {
+                    width: Val::Px(10.4),
+                    height: Val::Px(20.6),
+                    ..default()
+                },
+                Outline::new(Val::Px(1.6), Val::Px(0.4), Color::WHITE),
+            ))
+            .id();
+
+        ui_schedule.run(&mut world);
+        let computed_node = world.get::<ComputedNode>(node).unwrap();
+        assert!(computed_node.pixel_snapping);
+        assert_eq!(computed_node.size, Vec2::new(10., 21.));
+        assert_eq!(computed_node.outline_width, 2.);
+        assert_eq!(computed_node.outline_offset, 0.);
+
+        world.resource_mut::<UiLayoutOptions>().pixel_snapping = false;
+        ui_schedule.run(&mut world);
+        let computed_node = world.get::<ComputedNode>(node).unwrap();
+        assert!(!computed_node.pixel_snapping);
+        assert!(
+            (computed_node.size - Vec2::new(10.4, 20.6))
+                .abs()
+                .max_element()
+                < 0.001
+        );
+        assert!((computed_node.outline_width - 1.6).abs() < 0.001);
+
+        world
+            .entity_mut(node)
+            .insert(LayoutConfig { use_rounding: true });
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            world.get::<ComputedNode>(node).unwrap().size,
+            Vec2::new(10., 21.)
+        );
+    }
+
     #[test]
     fn no_camera_ui() {
         let mut world = World::new();
         world.init_resource::<UiScale>();
+        world.init_resource::<UiLayoutOptions>();
         world.init_resource::<UiSurface>();
         world.init_resource::<Events<WindowScaleFactorChanged>>();
         world.init_resource::<Events<WindowResized>>();
@@ -1237,6 +1388,7 @@ mod tests {
                 params.root_node_entity,
                 &Node::default(),
                 None,
+                None,
             );
 
             ui_surface.compute_camera_layout(
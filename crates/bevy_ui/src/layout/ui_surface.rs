@@ -9,7 +9,10 @@ use bevy_ecs::{
 use bevy_math::{UVec2, Vec2};
 use bevy_utils::default;
 
-use crate::{layout::convert, LayoutContext, LayoutError, Measure, MeasureArgs, Node, NodeMeasure};
+use crate::{
+    layout::convert, GridPlacement, LayoutContext, LayoutError, Measure, MeasureArgs, Node,
+    NodeMeasure,
+};
 use bevy_text::CosmicFontSystem;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -65,11 +68,14 @@ impl Default for UiSurface {
 impl UiSurface {
     /// Retrieves the Taffy node associated with the given UI node entity and updates its style.
     /// If no associated Taffy node exists a new Taffy node is inserted into the Taffy layout.
+    ///
+    /// `grid_area` is the resolved placement of the [`Node::grid_area`] of the node, if any.
     pub fn upsert_node(
         &mut self,
         layout_context: &LayoutContext,
         entity: Entity,
         node: &Node,
+        grid_area: Option<(GridPlacement, GridPlacement)>,
         mut new_node_context: Option<NodeMeasure>,
     ) {
         let taffy = &mut self.taffy;
@@ -79,11 +85,14 @@ impl UiSurface {
             added = true;
             if let Some(measure) = new_node_context.take() {
                 taffy
-                    .new_leaf_with_context(convert::from_node(node, layout_context, true), measure)
+                    .new_leaf_with_context(
+                        convert::from_node(node, layout_context, true, grid_area),
+                        measure,
+                    )
                     .unwrap()
             } else {
                 taffy
-                    .new_leaf(convert::from_node(node, layout_context, false))
+                    .new_leaf(convert::from_node(node, layout_context, false, grid_area))
                     .unwrap()
             }
         });
@@ -101,7 +110,7 @@ impl UiSurface {
             taffy
                 .set_style(
                     taffy_node_id,
-                    convert::from_node(node, layout_context, has_measure),
+                    convert::from_node(node, layout_context, has_measure, grid_area),
                 )
                 .unwrap();
         }
@@ -372,14 +381,26 @@ mod tests {
         let node = Node::default();
 
         // standard upsert
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
 
         // should be inserted into taffy
         assert_eq!(ui_surface.taffy.total_node_count(), 1);
         assert!(ui_surface.entity_to_taffy.contains_key(&root_node_entity));
 
         // test duplicate insert 1
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
 
         // node count should not have increased
         assert_eq!(ui_surface.taffy.total_node_count(), 1);
@@ -396,7 +417,13 @@ mod tests {
         assert!(is_root_node_pair_valid(&ui_surface.taffy, root_node_pair));
 
         // test duplicate insert 2
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
 
         // node count should not have increased
         assert_eq!(ui_surface.taffy.total_node_count(), 2);
@@ -436,7 +463,13 @@ mod tests {
         let root_node_entity = Entity::from_raw(1);
         let node = Node::default();
 
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
 
         // assign root node to camera
         ui_surface.set_camera_children(camera_entity, [root_node_entity].into_iter());
@@ -474,7 +507,13 @@ mod tests {
         let root_node_entity = Entity::from_raw(1);
         let node = Node::default();
 
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
 
         // assign root node to camera
         ui_surface.set_camera_children(camera_entity, [root_node_entity].into_iter());
@@ -526,7 +565,13 @@ mod tests {
         let root_node_entity = Entity::from_raw(1);
         let node = Node::default();
 
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
 
         ui_surface.set_camera_children(camera_entity, [root_node_entity].into_iter());
 
@@ -572,7 +617,13 @@ mod tests {
         let root_node_entity = Entity::from_raw(1);
         let node = Node::default();
 
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
         let mut content_size = ContentSize::default();
         content_size.set(NodeMeasure::Fixed(FixedMeasure { size: Vec2::ONE }));
         let measure_func = content_size.measure.take().unwrap();
@@ -588,8 +639,20 @@ mod tests {
         let child_entity = Entity::from_raw(2);
         let node = Node::default();
 
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, child_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            child_entity,
+            &node,
+            None,
+            None,
+        );
 
         ui_surface.update_children(root_node_entity, vec![child_entity].into_iter());
 
@@ -610,8 +673,20 @@ mod tests {
         let child_entity = Entity::from_raw(2);
         let node = Node::default();
 
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, root_node_entity, &node, None);
-        ui_surface.upsert_node(&LayoutContext::TEST_CONTEXT, child_entity, &node, None);
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            root_node_entity,
+            &node,
+            None,
+            None,
+        );
+        ui_surface.upsert_node(
+            &LayoutContext::TEST_CONTEXT,
+            child_entity,
+            &node,
+            None,
+            None,
+        );
 
         let root_taffy_node = *ui_surface.entity_to_taffy.get(&root_node_entity).unwrap();
         let child_taffy = *ui_surface.entity_to_taffy.get(&child_entity).unwrap();
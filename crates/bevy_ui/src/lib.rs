@@ -15,6 +15,8 @@
 //! Spawn UI elements with [`widget::Button`], [`ImageNode`], [`Text`](prelude::Text) and [`Node`]
 //! This UI is laid out with the Flexbox and CSS Grid layout models (see <https://cssreference.io/flexbox/>)
 
+extern crate alloc;
+
 pub mod measurement;
 pub mod ui_material;
 pub mod update;
@@ -77,7 +79,7 @@ use bevy_transform::TransformSystem;
 use layout::ui_surface::UiSurface;
 use stack::ui_stack_system;
 pub use stack::UiStack;
-use update::{update_clipping_system, update_target_camera_system};
+use update::{update_clipping_system, update_grid_areas_system, update_target_camera_system};
 
 /// The basic plugin for Bevy UI
 pub struct UiPlugin {
@@ -136,6 +138,28 @@ impl Default for UiScale {
     }
 }
 
+/// Global options for the layout of the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Resource)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub struct UiLayoutOptions {
+    /// If set to true, the rects of the nodes, their outlines and the glyphs of their text are
+    /// rounded to the nearest physical pixel during layout, to avoid blurry borders and shimmering
+    /// when the scale factor or [`UiScale`] isn't an integer.
+    ///
+    /// This can be overridden for a node and its descendants with a [`LayoutConfig`].
+    ///
+    /// Defaults to true.
+    pub pixel_snapping: bool,
+}
+
+impl Default for UiLayoutOptions {
+    fn default() -> Self {
+        Self {
+            pixel_snapping: true,
+        }
+    }
+}
+
 // Marks systems that can be ambiguous with [`widget::text_system`] if the `bevy_text` feature is enabled.
 // See https://github.com/bevyengine/bevy/pull/11391 for more details.
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
@@ -148,6 +172,7 @@ impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<UiSurface>()
             .init_resource::<UiScale>()
+            .init_resource::<UiLayoutOptions>()
             .init_resource::<UiStack>()
             .register_type::<BackgroundColor>()
             .register_type::<CalculatedClip>()
@@ -163,11 +188,13 @@ impl Plugin for UiPlugin {
             .register_type::<ImageNodeSize>()
             .register_type::<UiRect>()
             .register_type::<UiScale>()
+            .register_type::<UiLayoutOptions>()
             .register_type::<BorderColor>()
             .register_type::<BorderRadius>()
             .register_type::<BoxShadow>()
             .register_type::<widget::Button>()
             .register_type::<widget::Label>()
+            .register_type::<widget::VirtualListRow>()
             .register_type::<ZIndex>()
             .register_type::<Outline>()
             .register_type::<BoxShadowSamples>()
@@ -200,6 +227,10 @@ impl Plugin for UiPlugin {
             PostUpdate,
             (
                 update_target_camera_system.in_set(UiSystem::Prepare),
+                update_grid_areas_system.in_set(UiSystem::Prepare),
+                widget::update_virtual_lists
+                    .in_set(UiSystem::Prepare)
+                    .in_set(AmbiguousWithTextSystem),
                 ui_layout_system_config,
                 ui_stack_system
                     .in_set(UiSystem::Stack)
@@ -250,12 +281,26 @@ impl Plugin for UiPlugin {
 
 fn build_text_interop(app: &mut App) {
     use crate::widget::TextNodeFlags;
+    use bevy_a11y::AccessibilitySystem;
+    use bevy_input_focus::{InputFocus, InputFocusSet};
     use bevy_text::TextLayoutInfo;
     use widget::Text;
 
     app.register_type::<TextLayoutInfo>()
         .register_type::<TextNodeFlags>()
-        .register_type::<Text>();
+        .register_type::<Text>()
+        .register_type::<widget::TextInput>()
+        .init_resource::<InputFocus>()
+        .init_resource::<widget::TextInputClipboard>()
+        .add_event::<widget::TextInputEvent>();
+
+    app.add_systems(
+        PreUpdate,
+        (widget::focus_text_inputs, widget::edit_text_inputs)
+            .chain()
+            .after(UiSystem::Focus)
+            .before(InputFocusSet::Dispatch),
+    );
 
     app.add_systems(
         PostUpdate,
@@ -282,6 +327,27 @@ fn build_text_interop(app: &mut App) {
                 .ambiguous_with(bevy_text::detect_text_needs_rerender::<bevy_text::Text2d>)
                 .ambiguous_with(bevy_text::update_text2d_layout)
                 .ambiguous_with(bevy_text::calculate_bounds_text2d),
+            widget::update_text_inputs
+                .in_set(UiSystem::Prepare)
+                .before(bevy_text::detect_text_needs_rerender::<Text>)
+                // Text2d and bevy_ui text are entirely on separate entities
+                .ambiguous_with(bevy_text::detect_text_needs_rerender::<bevy_text::Text2d>)
+                .ambiguous_with(bevy_text::update_text2d_layout),
+            (
+                widget::update_text_input_carets
+                    .after(widget::text_system)
+                    .before(update_clipping_system)
+                    // the caret doesn't care about stack index
+                    .ambiguous_with(ui_stack_system),
+                widget::update_text_input_ime
+                    .after(TransformSystem::TransformPropagate)
+                    .before(AccessibilitySystem::Update),
+            )
+                .chain()
+                .in_set(UiSystem::PostLayout)
+                // Text2d and bevy_ui text are entirely on separate entities
+                .ambiguous_with(bevy_text::detect_text_needs_rerender::<bevy_text::Text2d>)
+                .ambiguous_with(bevy_text::update_text2d_layout),
         ),
     );
 
@@ -659,8 +659,12 @@ pub fn extract_text_sections(
             continue;
         };
 
-        let transform = global_transform.affine()
+        let mut transform = global_transform.affine()
             * bevy_math::Affine3A::from_translation((-0.5 * uinode.size()).extend(0.));
+        if uinode.pixel_snapping {
+            // The glyphs are positioned on whole pixels from the top left corner of the node.
+            transform.translation = transform.translation.round();
+        }
 
         let mut color = LinearRgba::WHITE;
         let mut current_span = usize::MAX;
@@ -75,6 +75,11 @@ pub struct ComputedNode {
     ///
     /// Automatically calculated by [`super::layout::ui_layout_system`].
     pub inverse_scale_factor: f32,
+    /// Whether the rect of this node, its outline and its text are rounded to physical pixels, see
+    /// [`LayoutConfig`] and [`UiLayoutOptions`](crate::UiLayoutOptions).
+    ///
+    /// Automatically calculated by [`super::layout::ui_layout_system`].
+    pub pixel_snapping: bool,
 }
 
 impl ComputedNode {
@@ -243,6 +248,7 @@ impl ComputedNode {
         border: BorderRect::ZERO,
         padding: BorderRect::ZERO,
         inverse_scale_factor: 1.,
+        pixel_snapping: false,
     };
 }
 
@@ -619,6 +625,34 @@ pub struct Node {
     ///
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/grid-column>
     pub grid_column: GridPlacement,
+
+    /// Names the areas of a grid, with one string per row listing the name of the area of each column, separated by spaces.
+    /// A `.` marks a cell outside of any area. Each area must be a rectangle.
+    ///
+    /// Grid items are placed in an area with [`Node::grid_area`].
+    ///
+    /// ```
+    /// # use bevy_ui::{Display, Node};
+    /// let dashboard = Node {
+    ///     display: Display::Grid,
+    ///     grid_template_areas: vec![
+    ///         "header header".into(),
+    ///         "sidebar main".into(),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    /// ```
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-areas>
+    pub grid_template_areas: Vec<String>,
+
+    /// The name of the area of the [`Node::grid_template_areas`] of its parent in which a grid item is placed.
+    ///
+    /// When set, the node is laid out in the rows and columns of the area instead of its `grid_row` and `grid_column`,
+    /// which are left unchanged and used again once it's unset.
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/grid-area>
+    pub grid_area: Option<String>,
 }
 
 impl Node {
@@ -662,7 +696,42 @@ impl Node {
         grid_auto_columns: Vec::new(),
         grid_column: GridPlacement::DEFAULT,
         grid_row: GridPlacement::DEFAULT,
+        grid_template_areas: Vec::new(),
+        grid_area: None,
     };
+
+    /// Returns the placement of the rows and columns of the area `name` of the [`Node::grid_template_areas`] of this node.
+    pub fn resolve_grid_area(
+        &self,
+        name: &str,
+    ) -> Result<(GridPlacement, GridPlacement), GridAreaError> {
+        let mut rows = (usize::MAX, 0);
+        let mut columns = (usize::MAX, 0);
+        let mut cells = 0;
+        for (row, areas) in self.grid_template_areas.iter().enumerate() {
+            for (column, area) in areas.split_whitespace().enumerate() {
+                if area == name && name != "." {
+                    rows = (rows.0.min(row), rows.1.max(row));
+                    columns = (columns.0.min(column), columns.1.max(column));
+                    cells += 1;
+                }
+            }
+        }
+        if cells == 0 {
+            return Err(GridAreaError::NotFound(name.to_string()));
+        }
+
+        let row_span = rows.1 - rows.0 + 1;
+        let column_span = columns.1 - columns.0 + 1;
+        // The cells of the area fill its bounding rect only if there are as many of them.
+        if cells != row_span * column_span {
+            return Err(GridAreaError::NotRectangular(name.to_string()));
+        }
+        Ok((
+            GridPlacement::start_span(rows.0 as i16 + 1, row_span as u16),
+            GridPlacement::start_span(columns.0 as i16 + 1, column_span as u16),
+        ))
+    }
 }
 
 impl Default for Node {
@@ -1974,6 +2043,15 @@ pub enum GridPlacementError {
     InvalidZeroSpan,
 }
 
+/// Errors that occur when placing a grid item in a named area with [`Node::grid_area`]
+#[derive(Debug, Eq, PartialEq, Clone, Error)]
+pub enum GridAreaError {
+    #[error("No grid area is named \"{0}\"")]
+    NotFound(String),
+    #[error("The grid area \"{0}\" is not a rectangle")]
+    NotRectangular(String),
+}
+
 /// The background color of the node
 ///
 /// This serves as the "fill" color.
@@ -2561,6 +2639,8 @@ pub struct LayoutConfig {
     /// If set to true the coordinates for this node and its descendents will be rounded to the nearest physical pixel.
     /// This can help prevent visual artifacts like blurry images or semi-transparent edges that can occur with sub-pixel positioning.
     ///
+    /// Nodes without a `LayoutConfig` inherit this from their parent, and root nodes from [`UiLayoutOptions::pixel_snapping`](crate::UiLayoutOptions::pixel_snapping).
+    ///
     /// Defaults to true.
     pub use_rounding: bool,
 }
@@ -2573,7 +2653,7 @@ impl Default for LayoutConfig {
 
 #[cfg(test)]
 mod tests {
-    use crate::GridPlacement;
+    use crate::{GridAreaError, GridPlacement, Node};
 
     #[test]
     fn invalid_grid_placement_values() {
@@ -2600,6 +2680,53 @@ mod tests {
         assert_eq!(GridPlacement::start_span(3, 5).get_end(), None);
         assert_eq!(GridPlacement::end_span(-4, 12).get_start(), None);
     }
+
+    #[test]
+    fn resolve_grid_area() {
+        let node = Node {
+            grid_template_areas: vec![
+                "header header header".into(),
+                "sidebar main main".into(),
+                "sidebar main main".into(),
+                "footer . broken".into(),
+                "broken broken .".into(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            node.resolve_grid_area("header"),
+            Ok((
+                GridPlacement::start_span(1, 1),
+                GridPlacement::start_span(1, 3)
+            ))
+        );
+        assert_eq!(
+            node.resolve_grid_area("sidebar"),
+            Ok((
+                GridPlacement::start_span(2, 2),
+                GridPlacement::start_span(1, 1)
+            ))
+        );
+        assert_eq!(
+            node.resolve_grid_area("main"),
+            Ok((
+                GridPlacement::start_span(2, 2),
+                GridPlacement::start_span(2, 2)
+            ))
+        );
+        assert_eq!(
+            node.resolve_grid_area("broken"),
+            Err(GridAreaError::NotRectangular("broken".into()))
+        );
+        assert_eq!(
+            node.resolve_grid_area("."),
+            Err(GridAreaError::NotFound(".".into()))
+        );
+        assert_eq!(
+            node.resolve_grid_area("nav"),
+            Err(GridAreaError::NotFound("nav".into()))
+        );
+    }
 }
 
 /// Indicates that this root [`Node`] entity should be rendered to a specific camera.
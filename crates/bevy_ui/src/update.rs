@@ -7,6 +7,7 @@ use crate::{
 
 use super::ComputedNode;
 use bevy_ecs::{
+    change_detection::{DetectChanges, Ref},
     entity::Entity,
     query::{Changed, With},
     system::{Commands, Query},
@@ -15,6 +16,7 @@ use bevy_math::Rect;
 use bevy_sprite::BorderRect;
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::HashSet;
+use tracing::warn;
 
 /// Updates clipping for all nodes
 pub fn update_clipping_system(
@@ -216,3 +218,29 @@ fn update_children_target_camera(
         );
     }
 }
+
+/// Warns about the nodes with a [`Node::grid_area`] that isn't a rectangular area of the
+/// [`Node::grid_template_areas`] of their parent, which are placed by their `grid_row` and
+/// `grid_column` instead.
+///
+/// The areas themselves are resolved when the nodes are laid out.
+pub fn update_grid_areas_system(node_query: Query<(Entity, Ref<Node>)>, ui_children: UiChildren) {
+    for (entity, node) in &node_query {
+        let Some(name) = node.grid_area.as_deref() else {
+            continue;
+        };
+        let Some((_, parent_node)) = ui_children
+            .get_parent(entity)
+            .and_then(|parent| node_query.get(parent).ok())
+        else {
+            continue;
+        };
+        // Only warn once per change of the node or of its parent
+        if !node.is_changed() && !parent_node.is_changed() {
+            continue;
+        }
+        if let Err(error) = parent_node.resolve_grid_area(name) {
+            warn!("Node ({entity}) could not be placed: {error}");
+        }
+    }
+}
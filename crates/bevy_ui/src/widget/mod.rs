@@ -5,9 +5,13 @@ mod image;
 mod label;
 
 mod text;
+mod text_input;
+mod virtual_list;
 
 pub use button::*;
 pub use image::*;
 pub use label::*;
 
 pub use text::*;
+pub use text_input::*;
+pub use virtual_list::*;
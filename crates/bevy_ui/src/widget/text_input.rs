@@ -0,0 +1,879 @@
+use crate::{
+    widget::Text, BackgroundColor, ComputedNode, Display, Interaction, Node, PositionType,
+    RelativeCursorPosition, Val,
+};
+use alloc::borrow::Cow;
+use bevy_color::Color;
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    entity::Entity,
+    event::{Event, EventReader, EventWriter},
+    prelude::{require, Component},
+    query::{With, Without},
+    reflect::ReflectComponent,
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+    world::Ref,
+};
+use bevy_hierarchy::BuildChildren;
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    mouse::MouseButton,
+    ButtonInput,
+};
+use bevy_input_focus::InputFocus;
+use bevy_math::Vec2;
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_text::{ComputedTextBlock, TextColor, TextFont, TextLayout, TextLayoutInfo};
+use bevy_transform::components::GlobalTransform;
+use bevy_window::{Ime, Window};
+use core::ops::Range;
+
+/// The width of the caret of a [`TextInput`], in logical pixels.
+const CARET_WIDTH: f32 = 2.;
+
+/// A single line text field the user can edit once it has the [`InputFocus`], by clicking it.
+///
+/// The input supports moving the caret with the arrow keys, Home and End, selecting text with
+/// Shift or by dragging the mouse, and copying, cutting and pasting with Ctrl (or Cmd) and C, X
+/// and V through the [`TextInputClipboard`]. Text composed with an input method is shown at the
+/// caret, underlined, until it is committed. Every edit sends a [`TextInputEvent::Changed`], and
+/// pressing Enter sends a [`TextInputEvent::Submitted`].
+///
+/// The text is drawn with the [`TextFont`] and [`TextColor`] of the input, by a [`Text`] child
+/// spawned by the input along with its caret and selection. Set [`Node::overflow`] to
+/// [`Overflow::clip_x`](crate::Overflow::clip_x) to hide the text scrolled out of the input
+/// when it doesn't fit.
+///
+/// The keyboard events typed into the input are still seen by the rest of the app: run the systems
+/// reacting to the keyboard with [`text_input_focused`] to ignore them while the user types.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{prelude::*, widget::{TextInput, TextInputEvent}};
+/// fn spawn_name_field(mut commands: Commands) {
+///     commands.spawn((
+///         Node {
+///             width: Val::Px(200.),
+///             padding: UiRect::all(Val::Px(4.)),
+///             overflow: Overflow::clip_x(),
+///             ..Default::default()
+///         },
+///         TextInput::new("Player").with_max_chars(16),
+///     ));
+/// }
+///
+/// fn read_name(mut events: EventReader<TextInputEvent>) {
+///     for event in events.read() {
+///         if let TextInputEvent::Submitted { value, .. } = event {
+///             // start the game with the name in `value`
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(Node, TextFont, TextColor, Interaction, RelativeCursorPosition)]
+pub struct TextInput {
+    value: String,
+    cursor: usize,
+    anchor: usize,
+    #[reflect(ignore)]
+    preedit: Option<Preedit>,
+    max_chars: Option<usize>,
+    caret_color: Color,
+    selection_color: Color,
+    scroll: f32,
+    #[reflect(ignore)]
+    children: Option<TextInputChildren>,
+}
+
+/// The text being composed with an input method, shown at the caret of the input.
+#[derive(Debug, Clone)]
+struct Preedit {
+    value: String,
+    cursor: Option<(usize, usize)>,
+}
+
+/// The entities spawned by a [`TextInput`] to draw its text, selection, preedit and caret.
+#[derive(Debug, Clone, Copy)]
+struct TextInputChildren {
+    selection: Entity,
+    text: Entity,
+    preedit: Entity,
+    caret: Entity,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            cursor: 0,
+            anchor: 0,
+            preedit: None,
+            max_chars: None,
+            caret_color: Color::WHITE,
+            selection_color: Color::srgba(0.25, 0.45, 0.9, 0.6),
+            scroll: 0.,
+            children: None,
+        }
+    }
+}
+
+impl TextInput {
+    /// Creates an input with the text `value`, and the caret after it.
+    pub fn new(value: impl Into<String>) -> Self {
+        let mut input = Self::default();
+        input.set_value(value);
+        input
+    }
+
+    /// Limits the value of the input to `max_chars` characters. Text typed or pasted beyond the
+    /// limit is dropped.
+    pub fn with_max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = Some(max_chars);
+        self
+    }
+
+    /// Sets the color of the caret and of the line under the text being composed.
+    pub fn with_caret_color(mut self, color: impl Into<Color>) -> Self {
+        self.caret_color = color.into();
+        self
+    }
+
+    /// Sets the color of the background of the selected text.
+    pub fn with_selection_color(mut self, color: impl Into<Color>) -> Self {
+        self.selection_color = color.into();
+        self
+    }
+
+    /// Returns the text of the input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the text of the input and moves the caret after it. This doesn't send a
+    /// [`TextInputEvent`].
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.cursor = self.value.len();
+        self.anchor = self.cursor;
+        self.preedit = None;
+    }
+
+    /// Returns the byte index of the caret in the value.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the caret before the character at the byte index `index`, or after the last one if
+    /// `index` is past the end of the value, and clears the selection.
+    pub fn set_cursor(&mut self, index: usize) {
+        self.move_cursor(index, false);
+    }
+
+    /// Returns the byte range of the selected text, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        (self.anchor != self.cursor)
+            .then(|| self.anchor.min(self.cursor)..self.anchor.max(self.cursor))
+    }
+
+    /// Returns the selected text, empty if no text is selected.
+    pub fn selected_text(&self) -> &str {
+        self.selection().map_or("", |range| &self.value[range])
+    }
+
+    /// Selects the text in the byte range `range`, with the caret at its end.
+    pub fn select(&mut self, range: Range<usize>) {
+        self.move_cursor(range.start, false);
+        self.move_cursor(range.end, true);
+    }
+
+    /// Selects all the text of the input.
+    pub fn select_all(&mut self) {
+        self.select(0..self.value.len());
+    }
+
+    /// Returns the text being composed with an input method, if any.
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_ref().map(|preedit| preedit.value.as_str())
+    }
+
+    /// Inserts `text` at the caret, replacing the selected text, and moves the caret after it.
+    ///
+    /// Control characters, like new lines, are dropped. Returns `true` if the value changed.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let removed = self.delete_selection();
+        let mut available = usize::MAX;
+        if let Some(max_chars) = self.max_chars {
+            available = max_chars.saturating_sub(self.value.chars().count());
+        }
+        let text: String = text
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(available)
+            .collect();
+        self.value.insert_str(self.cursor, &text);
+        self.move_cursor(self.cursor + text.len(), false);
+        removed || !text.is_empty()
+    }
+
+    /// Deletes the selected text, or the character before the caret.
+    fn delete_backward(&mut self) -> bool {
+        if self.selection().is_none() {
+            self.move_cursor(self.previous_boundary(self.cursor), true);
+        }
+        self.delete_selection()
+    }
+
+    /// Deletes the selected text, or the character after the caret.
+    fn delete_forward(&mut self) -> bool {
+        if self.selection().is_none() {
+            self.move_cursor(self.next_boundary(self.cursor), true);
+        }
+        self.delete_selection()
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.selection() else {
+            return false;
+        };
+        self.value.replace_range(range.clone(), "");
+        self.move_cursor(range.start, false);
+        true
+    }
+
+    /// Moves the caret to `index`, extending the selection from its anchor if `extend` is set.
+    fn move_cursor(&mut self, index: usize, extend: bool) {
+        let mut index = index.min(self.value.len());
+        while !self.value.is_char_boundary(index) {
+            index -= 1;
+        }
+        self.cursor = index;
+        if !extend {
+            self.anchor = index;
+        }
+    }
+
+    fn move_left(&mut self, extend: bool) {
+        match self.selection() {
+            Some(range) if !extend => self.move_cursor(range.start, false),
+            _ => self.move_cursor(self.previous_boundary(self.cursor), extend),
+        }
+    }
+
+    fn move_right(&mut self, extend: bool) {
+        match self.selection() {
+            Some(range) if !extend => self.move_cursor(range.end, false),
+            _ => self.move_cursor(self.next_boundary(self.cursor), extend),
+        }
+    }
+
+    fn previous_boundary(&self, index: usize) -> usize {
+        self.value[..index]
+            .chars()
+            .next_back()
+            .map_or(index, |c| index - c.len_utf8())
+    }
+
+    fn next_boundary(&self, index: usize) -> usize {
+        self.value[index..]
+            .chars()
+            .next()
+            .map_or(index, |c| index + c.len_utf8())
+    }
+
+    fn set_preedit(&mut self, value: &str, cursor: Option<(usize, usize)>) {
+        if value.is_empty() {
+            self.preedit = None;
+            return;
+        }
+        // The composed text replaces the selection once committed.
+        self.delete_selection();
+        self.preedit = Some(Preedit {
+            value: value.to_owned(),
+            cursor,
+        });
+    }
+
+    /// Returns the text shown by the input: its value, with the text being composed at the caret.
+    fn display_text(&self) -> Cow<'_, str> {
+        match &self.preedit {
+            Some(preedit) => {
+                let mut text = self.value.clone();
+                text.insert_str(self.cursor, &preedit.value);
+                Cow::Owned(text)
+            }
+            None => Cow::Borrowed(&self.value),
+        }
+    }
+
+    /// Converts a byte index in the value to a byte index in the shown text.
+    fn display_index(&self, index: usize) -> usize {
+        match &self.preedit {
+            Some(preedit) if index > self.cursor => index + preedit.value.len(),
+            _ => index,
+        }
+    }
+
+    /// Converts a byte index in the shown text to a byte index in the value.
+    fn value_index(&self, display_index: usize) -> usize {
+        match &self.preedit {
+            Some(preedit) if display_index >= self.cursor + preedit.value.len() => {
+                display_index - preedit.value.len()
+            }
+            Some(_) => display_index.min(self.cursor),
+            None => display_index,
+        }
+    }
+
+    /// Returns the byte index of the caret in the shown text, or `None` if the input method hides
+    /// it.
+    fn display_cursor(&self) -> Option<usize> {
+        match &self.preedit {
+            Some(preedit) => preedit.cursor.map(|(start, _)| self.cursor + start),
+            None => Some(self.cursor),
+        }
+    }
+}
+
+/// An event sent when the user edits or submits a [`TextInput`].
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub enum TextInputEvent {
+    /// The user edited the text of the input.
+    Changed {
+        /// The entity of the input.
+        entity: Entity,
+        /// The new text of the input.
+        value: String,
+    },
+    /// The user pressed Enter in the input.
+    Submitted {
+        /// The entity of the input.
+        entity: Entity,
+        /// The text of the input.
+        value: String,
+    },
+}
+
+/// The clipboard the [`TextInput`]s copy text to and paste text from.
+///
+/// This clipboard is local to the app: to exchange text with other applications, copy its
+/// contents to and from the clipboard of the system, with a crate like `arboard`.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct TextInputClipboard(pub String);
+
+/// A run condition that is `true` while a [`TextInput`] has the [`InputFocus`].
+///
+/// The [`TextInput`]s don't consume the keyboard events they handle, so that gameplay systems
+/// reading the keyboard should be skipped while the user types:
+///
+/// ```
+/// # use bevy_app::{App, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::widget::text_input_focused;
+/// # fn move_player() {}
+/// App::new().add_systems(Update, move_player.run_if(not(text_input_focused)));
+/// ```
+pub fn text_input_focused(focus: Res<InputFocus>, inputs: Query<(), With<TextInput>>) -> bool {
+    focus.get().is_some_and(|entity| inputs.contains(entity))
+}
+
+/// Gives the [`InputFocus`] to the [`TextInput`]s when they're clicked and moves their caret to the
+/// clicked character, or selects text while the mouse is dragged over them.
+///
+/// Clicking anywhere else takes the focus from the focused input.
+pub fn focus_text_inputs(
+    mut focus: ResMut<InputFocus>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut inputs: Query<(
+        Entity,
+        &mut TextInput,
+        &Interaction,
+        &RelativeCursorPosition,
+        &ComputedNode,
+    )>,
+    texts: Query<&ComputedTextBlock>,
+) {
+    if !mouse.pressed(MouseButton::Left) {
+        return;
+    }
+    let just_pressed = mouse.just_pressed(MouseButton::Left);
+    let mut pressed_input = false;
+    for (entity, mut input, interaction, cursor_position, node) in &mut inputs {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        pressed_input = true;
+        if !just_pressed && focus.get() != Some(entity) {
+            continue;
+        }
+        if focus.get() != Some(entity) {
+            focus.set(entity);
+        }
+
+        let Some(normalized) = cursor_position.normalized else {
+            continue;
+        };
+        let Some(block) = input
+            .children
+            .and_then(|children| texts.get(children.text).ok())
+        else {
+            continue;
+        };
+        let inset = Vec2::new(
+            node.border().left + node.padding().left,
+            node.border().top + node.padding().top,
+        );
+        let position = normalized * node.size() - inset + Vec2::new(input.scroll, 0.);
+        if let Some((_, index)) = block.hit(position) {
+            let index = input.value_index(index);
+            input.move_cursor(index, !just_pressed);
+        }
+    }
+
+    if just_pressed && !pressed_input {
+        if let Some(focused) = focus.get() {
+            if inputs.contains(focused) {
+                focus.clear();
+            }
+        }
+    }
+}
+
+/// Applies the keyboard and input method events to the focused [`TextInput`], and sends the
+/// [`TextInputEvent`]s.
+pub fn edit_text_inputs(
+    mut focus: ResMut<InputFocus>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut ime_events: EventReader<Ime>,
+    mut inputs: Query<&mut TextInput>,
+    mut clipboard: ResMut<TextInputClipboard>,
+    mut text_input_events: EventWriter<TextInputEvent>,
+) {
+    let Some((entity, mut input)) = focus
+        .get()
+        .and_then(|entity| Some((entity, inputs.get_mut(entity).ok()?)))
+    else {
+        keyboard_events.clear();
+        ime_events.clear();
+        return;
+    };
+
+    let shortcut = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let mut changed = false;
+    let mut unfocus = false;
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() || unfocus {
+            continue;
+        }
+        // The keys are handled by the input method while text is being composed.
+        if input.preedit.is_some() {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                text_input_events.send(TextInputEvent::Submitted {
+                    entity,
+                    value: input.value.clone(),
+                });
+            }
+            Key::Escape => unfocus = true,
+            Key::Backspace => changed |= input.delete_backward(),
+            Key::Delete => changed |= input.delete_forward(),
+            Key::ArrowLeft => input.move_left(shift),
+            Key::ArrowRight => input.move_right(shift),
+            Key::Home => input.move_cursor(0, shift),
+            Key::End => {
+                let end = input.value.len();
+                input.move_cursor(end, shift);
+            }
+            Key::Character(character) if shortcut => match character.to_lowercase().as_str() {
+                "a" => input.select_all(),
+                "c" if input.selection().is_some() => {
+                    clipboard.0 = input.selected_text().to_owned();
+                }
+                "x" if input.selection().is_some() => {
+                    clipboard.0 = input.selected_text().to_owned();
+                    changed |= input.delete_selection();
+                }
+                "v" => changed |= input.insert(&clipboard.0),
+                _ => {}
+            },
+            _ => {
+                if let Some(text) = event.text.as_deref().filter(|_| !shortcut) {
+                    changed |= input.insert(text);
+                }
+            }
+        }
+    }
+
+    for event in ime_events.read() {
+        match event {
+            Ime::Preedit { value, cursor, .. } => input.set_preedit(value, *cursor),
+            Ime::Commit { value, .. } => {
+                input.preedit = None;
+                changed |= input.insert(value);
+            }
+            Ime::Disabled { .. } => input.preedit = None,
+            Ime::Enabled { .. } => {}
+        }
+    }
+
+    if changed {
+        text_input_events.send(TextInputEvent::Changed {
+            entity,
+            value: input.value.clone(),
+        });
+    }
+    if unfocus {
+        input.preedit = None;
+        focus.clear();
+    }
+}
+
+/// Spawns the children of the new [`TextInput`]s, and updates their text.
+pub fn update_text_inputs(
+    mut commands: Commands,
+    mut inputs: Query<(Entity, &mut TextInput, Ref<TextFont>, Ref<TextColor>)>,
+    mut texts: Query<(&mut Text, &mut TextFont, &mut TextColor), Without<TextInput>>,
+) {
+    for (entity, mut input, font, color) in &mut inputs {
+        let Some(children) = input.children else {
+            let absolute = || Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            };
+            let selection = commands
+                .spawn((absolute(), BackgroundColor(input.selection_color)))
+                .set_parent(entity)
+                .id();
+            let text = commands
+                .spawn((
+                    Text::new(input.display_text()),
+                    TextLayout::new_with_no_wrap(),
+                    (*font).clone(),
+                    *color,
+                    Node {
+                        flex_shrink: 0.,
+                        ..Default::default()
+                    },
+                ))
+                .set_parent(entity)
+                .id();
+            let preedit = commands
+                .spawn((absolute(), BackgroundColor(input.caret_color)))
+                .set_parent(entity)
+                .id();
+            let caret = commands
+                .spawn((absolute(), BackgroundColor(input.caret_color)))
+                .set_parent(entity)
+                .id();
+            input.children = Some(TextInputChildren {
+                selection,
+                text,
+                preedit,
+                caret,
+            });
+            continue;
+        };
+
+        let Ok((mut text, mut text_font, mut text_color)) = texts.get_mut(children.text) else {
+            continue;
+        };
+        let display_text = input.display_text();
+        if text.0 != display_text {
+            text.0 = display_text.into_owned();
+        }
+        if font.is_changed() {
+            *text_font = (*font).clone();
+        }
+        if color.is_changed() {
+            *text_color = *color;
+        }
+    }
+}
+
+/// Positions the caret, the selection and the line under the text being composed of the
+/// [`TextInput`]s, and scrolls their text to keep the caret visible.
+pub fn update_text_input_carets(
+    focus: Res<InputFocus>,
+    mut inputs: Query<(Entity, &mut TextInput, &ComputedNode)>,
+    texts: Query<(&ComputedTextBlock, &TextLayoutInfo)>,
+    mut nodes: Query<&mut Node, Without<TextInput>>,
+) {
+    for (entity, mut input, node) in &mut inputs {
+        let Some(children) = input.children else {
+            continue;
+        };
+        let Ok((block, layout)) = texts.get(children.text) else {
+            continue;
+        };
+        let focused = focus.get() == Some(entity);
+        let inverse_scale_factor = node.inverse_scale_factor();
+        let caret_width = CARET_WIDTH / inverse_scale_factor;
+        let caret_x = |index: usize| block.caret_x(0, index).unwrap_or(0.);
+
+        // Scroll the text to keep the caret inside the content box of the input.
+        let padding = node.padding();
+        let border = node.border();
+        let content_width =
+            node.size().x - padding.left - padding.right - border.left - border.right;
+        let display_cursor = input.display_cursor();
+        let mut scroll = input.scroll;
+        if let Some(x) = display_cursor.map(caret_x) {
+            if x + caret_width - scroll > content_width {
+                scroll = x + caret_width - content_width;
+            }
+            if x < scroll {
+                scroll = x;
+            }
+        }
+        scroll = scroll
+            .min(layout.size.x + caret_width - content_width)
+            .max(0.);
+        if input.scroll != scroll {
+            input.scroll = scroll;
+        }
+
+        if let Ok(mut text_node) = nodes.get_mut(children.text) {
+            let left = Val::Px(-scroll * inverse_scale_factor);
+            if text_node.left != left {
+                text_node.left = left;
+            }
+        }
+
+        // The absolutely positioned children are placed from the padding box of the input.
+        let place = |x: f32, width: f32, top: f32, height: f32, visible: bool| Node {
+            display: if visible {
+                Display::Flex
+            } else {
+                Display::None
+            },
+            position_type: PositionType::Absolute,
+            left: Val::Px((padding.left + x - scroll) * inverse_scale_factor),
+            top: Val::Px((padding.top + top) * inverse_scale_factor),
+            width: Val::Px(width * inverse_scale_factor),
+            height: Val::Px(height * inverse_scale_factor),
+            ..Default::default()
+        };
+        let height = layout.size.y;
+
+        let caret = place(
+            display_cursor.map_or(0., caret_x),
+            caret_width,
+            0.,
+            height,
+            focused && display_cursor.is_some(),
+        );
+        set_node(&mut nodes, children.caret, caret);
+
+        let selection = input.selection().map(|range| {
+            let start = caret_x(input.display_index(range.start));
+            let end = caret_x(input.display_index(range.end));
+            (start.min(end), (end - start).abs())
+        });
+        let (x, width) = selection.unwrap_or_default();
+        let selection = place(x, width, 0., height, focused && selection.is_some());
+        set_node(&mut nodes, children.selection, selection);
+
+        let preedit = input.preedit.as_ref().map(|preedit| {
+            let start = caret_x(input.cursor);
+            let end = caret_x(input.cursor + preedit.value.len());
+            (start.min(end), (end - start).abs())
+        });
+        let underline = 1. / inverse_scale_factor;
+        let (x, width) = preedit.unwrap_or_default();
+        let preedit = place(
+            x,
+            width,
+            height - underline,
+            underline,
+            focused && preedit.is_some(),
+        );
+        set_node(&mut nodes, children.preedit, preedit);
+    }
+}
+
+fn set_node(nodes: &mut Query<&mut Node, Without<TextInput>>, entity: Entity, node: Node) {
+    if let Ok(mut current) = nodes.get_mut(entity) {
+        current.set_if_neq(node);
+    }
+}
+
+/// Enables the input method of the windows while a [`TextInput`] is focused, and places its
+/// candidate box at the caret of the input.
+pub fn update_text_input_ime(
+    mut ime_enabled: Local<bool>,
+    focus: Res<InputFocus>,
+    inputs: Query<(&TextInput, &ComputedNode)>,
+    carets: Query<&GlobalTransform>,
+    mut windows: Query<&mut Window>,
+) {
+    let focused = focus.get().and_then(|entity| inputs.get(entity).ok());
+    let Some((input, node)) = focused else {
+        // Only disable the input method if it was enabled for a text input.
+        if *ime_enabled {
+            for mut window in &mut windows {
+                window.ime_enabled = false;
+            }
+            *ime_enabled = false;
+        }
+        return;
+    };
+
+    let caret_position = input
+        .children
+        .and_then(|children| carets.get(children.caret).ok())
+        .map(|transform| transform.translation().truncate() * node.inverse_scale_factor());
+    for mut window in &mut windows {
+        if !window.ime_enabled {
+            window.ime_enabled = true;
+        }
+        if let Some(position) = caret_position {
+            if window.ime_position != position {
+                window.ime_position = position;
+            }
+        }
+    }
+    *ime_enabled = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::Children;
+    use bevy_input::ButtonState;
+
+    fn key(key_code: KeyCode, logical_key: Key, text: Option<&str>) -> KeyboardInput {
+        KeyboardInput {
+            key_code,
+            logical_key,
+            state: ButtonState::Pressed,
+            text: text.map(Into::into),
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    #[test]
+    fn text_input_systems() {
+        let mut world = World::new();
+        world.init_resource::<InputFocus>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<KeyboardInput>>();
+        world.init_resource::<Events<Ime>>();
+        world.init_resource::<Events<TextInputEvent>>();
+        world.init_resource::<TextInputClipboard>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((focus_text_inputs, edit_text_inputs, update_text_inputs).chain());
+
+        let input = world
+            .spawn((TextInput::new("ab"), Interaction::Pressed))
+            .id();
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<InputFocus>().get(), Some(input));
+        assert!(world.run_system_cached(text_input_focused).unwrap());
+        // The text of the input is drawn by one of its children.
+        assert_eq!(world.get::<Children>(input).unwrap().len(), 4);
+
+        let mut keyboard_events = world.resource_mut::<Events<KeyboardInput>>();
+        keyboard_events.send(key(KeyCode::KeyC, Key::Character("c".into()), Some("c")));
+        keyboard_events.send(key(KeyCode::ArrowLeft, Key::ArrowLeft, None));
+        keyboard_events.send(key(KeyCode::Backspace, Key::Backspace, None));
+        keyboard_events.send(key(KeyCode::Enter, Key::Enter, None));
+        schedule.run(&mut world);
+        assert_eq!(world.get::<TextInput>(input).unwrap().value(), "ac");
+        let events: Vec<_> = world
+            .resource_mut::<Events<TextInputEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            events,
+            [
+                TextInputEvent::Submitted {
+                    entity: input,
+                    value: "ac".into(),
+                },
+                TextInputEvent::Changed {
+                    entity: input,
+                    value: "ac".into(),
+                },
+            ]
+        );
+        let text = world
+            .get::<TextInput>(input)
+            .unwrap()
+            .children
+            .unwrap()
+            .text;
+        assert_eq!(world.get::<Text>(text).unwrap().0, "ac");
+
+        // Escape takes the focus from the input, which then ignores the keyboard.
+        let mut keyboard_events = world.resource_mut::<Events<KeyboardInput>>();
+        keyboard_events.send(key(KeyCode::Escape, Key::Escape, None));
+        keyboard_events.send(key(KeyCode::KeyD, Key::Character("d".into()), Some("d")));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<InputFocus>().get(), None);
+        assert!(!world.run_system_cached(text_input_focused).unwrap());
+        assert_eq!(world.get::<TextInput>(input).unwrap().value(), "ac");
+    }
+
+    #[test]
+    fn text_input_editing() {
+        let mut input = TextInput::new("héllo").with_max_chars(8);
+        assert_eq!(input.cursor(), 6);
+
+        input.move_left(false);
+        input.move_left(true);
+        input.move_left(true);
+        assert_eq!(input.selected_text(), "ll");
+        assert!(input.insert("y"));
+        assert_eq!(input.value(), "héyo");
+
+        // The caret steps over the two bytes of `é`.
+        input.set_cursor(3);
+        assert!(input.delete_backward());
+        assert_eq!(input.value(), "hyo");
+        assert_eq!(input.cursor(), 1);
+        assert!(!TextInput::default().delete_forward());
+
+        // Text beyond the limit and control characters are dropped.
+        assert!(input.insert("ab\ncdefg"));
+        assert_eq!(input.value(), "habcdeyo");
+        assert!(!input.insert("z"));
+
+        input.select_all();
+        assert!(input.insert("→"));
+        assert_eq!(input.value(), "→");
+
+        // The composed text is shown at the caret, but not part of the value.
+        input.set_cursor(0);
+        input.set_preedit("にほ", Some((3, 3)));
+        assert_eq!(input.display_text(), "にほ→");
+        assert_eq!(input.display_cursor(), Some(3));
+        assert_eq!(input.display_index(3), 9);
+        assert_eq!(input.value_index(9), 3);
+        assert_eq!(input.value_index(4), 0);
+        assert_eq!(input.value(), "→");
+    }
+}
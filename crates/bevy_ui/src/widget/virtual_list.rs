@@ -0,0 +1,371 @@
+use crate::{ComputedNode, Node, PositionType, ScrollPosition, Val};
+use alloc::sync::Arc;
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{require, Component},
+    reflect::ReflectComponent,
+    system::{Commands, EntityCommands, Query},
+};
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt, Parent};
+use bevy_reflect::Reflect;
+use core::ops::Range;
+
+/// A scrolling list of rows of the same height that only spawns the rows currently visible, plus a
+/// few rows above and below, so that lists of thousands of items stay cheap to lay out and render.
+///
+/// The rows are spawned as children of the list by the row factory, which is given the index of
+/// the row in the data and the [`EntityCommands`] of the row. When the list scrolls, the rows
+/// leaving the view are reused for the rows entering it: their children are despawned, the
+/// components added by the factory are removed, and the factory is run again with the new index.
+/// The factory must not replace the [`Node`] of the row, which positions it in the list, but may add
+/// any other component or children.
+///
+/// The list scrolls vertically: set [`Node::overflow`] to [`Overflow::scroll_y`](crate::Overflow::scroll_y)
+/// and update the [`ScrollPosition`] of the list to scroll it.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_hierarchy::BuildChildren;
+/// # use bevy_ui::{prelude::*, widget::VirtualList};
+/// fn spawn_inventory(mut commands: Commands) {
+///     commands.spawn((
+///         Node {
+///             height: Val::Px(400.),
+///             overflow: Overflow::scroll_y(),
+///             ..Default::default()
+///         },
+///         VirtualList::new(10_000, 24., |index, row| {
+///             row.with_child(Text::new(format!("Item {index}")));
+///         }),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+#[require(Node, ScrollPosition)]
+pub struct VirtualList {
+    len: usize,
+    row_height: f32,
+    buffer: usize,
+    factory: Arc<dyn Fn(usize, &mut EntityCommands) + Send + Sync>,
+    rows: Vec<(usize, Entity)>,
+    spacer: Option<Entity>,
+    spacer_height: f32,
+    needs_refresh: bool,
+}
+
+impl VirtualList {
+    /// The default number of rows spawned above and below the visible rows.
+    pub const DEFAULT_BUFFER: usize = 2;
+
+    /// Creates a list of `len` rows of `row_height` logical pixels, spawned by `factory`.
+    pub fn new(
+        len: usize,
+        row_height: f32,
+        factory: impl Fn(usize, &mut EntityCommands) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            len,
+            row_height,
+            buffer: Self::DEFAULT_BUFFER,
+            factory: Arc::new(factory),
+            rows: Vec::new(),
+            spacer: None,
+            spacer_height: 0.,
+            needs_refresh: false,
+        }
+    }
+
+    /// Sets the number of rows spawned above and below the visible rows, to avoid showing missing
+    /// rows while scrolling quickly.
+    pub fn with_buffer(mut self, buffer: usize) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    /// Returns the number of rows of the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Sets the number of rows of the list, when items are added to or removed from the data.
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    /// Returns the height of the rows, in logical pixels.
+    pub fn row_height(&self) -> f32 {
+        self.row_height
+    }
+
+    /// Rebuilds all the spawned rows with the factory, when the data they show changed.
+    pub fn refresh(&mut self) {
+        self.needs_refresh = true;
+    }
+
+    /// Returns the range of indices of the spawned rows, as of the last update.
+    pub fn spawned_rows(&self) -> Range<usize> {
+        let start = self.rows.iter().map(|(index, _)| *index).min();
+        let end = self.rows.iter().map(|(index, _)| *index + 1).max();
+        start.unwrap_or(0)..end.unwrap_or(0)
+    }
+
+    /// Returns the entity of the row at `index`, if it is spawned.
+    pub fn row_entity(&self, index: usize) -> Option<Entity> {
+        self.rows
+            .iter()
+            .find(|(row_index, _)| *row_index == index)
+            .map(|(_, entity)| *entity)
+    }
+
+    /// Returns the range of the rows to spawn when the list is scrolled to `offset` and shows
+    /// `viewport_height` logical pixels.
+    fn rows_to_spawn(&self, offset: f32, viewport_height: f32) -> Range<usize> {
+        if self.row_height <= 0. {
+            return 0..0;
+        }
+        let first = (offset.max(0.) / self.row_height).floor() as usize;
+        let last = ((offset.max(0.) + viewport_height) / self.row_height).ceil() as usize;
+        let start = first.saturating_sub(self.buffer).min(self.len);
+        let end = last.saturating_add(self.buffer).min(self.len);
+        start..end
+    }
+}
+
+/// The index of a row spawned by a [`VirtualList`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct VirtualListRow(pub usize);
+
+/// Spawns, recycles and despawns the rows of the [`VirtualList`]s to match their scroll position.
+pub fn update_virtual_lists(
+    mut commands: Commands,
+    mut lists: Query<(Entity, &mut VirtualList, &ComputedNode, &ScrollPosition)>,
+) {
+    for (list_entity, mut list, node, scroll_position) in &mut lists {
+        let viewport_height = node.size().y * node.inverse_scale_factor();
+        let range = list.rows_to_spawn(scroll_position.offset_y, viewport_height);
+        if !list.needs_refresh
+            && list.rows.len() == range.len()
+            && list.spacer_height == list.len as f32 * list.row_height
+            && list.spawned_rows() == range
+        {
+            continue;
+        }
+        let list = &mut *list;
+
+        // The rows are absolutely positioned, the spacer gives the list its scrollable height.
+        let spacer_height = list.len as f32 * list.row_height;
+        let spacer_node = Node {
+            height: Val::Px(spacer_height),
+            flex_shrink: 0.,
+            ..Default::default()
+        };
+        match list.spacer {
+            Some(spacer) if list.spacer_height != spacer_height => {
+                commands.entity(spacer).insert(spacer_node);
+            }
+            Some(_) => {}
+            None => {
+                let spacer = commands.spawn(spacer_node).set_parent(list_entity).id();
+                list.spacer = Some(spacer);
+            }
+        }
+        list.spacer_height = spacer_height;
+
+        let mut spawned = vec![false; range.len()];
+        let mut recycled = Vec::new();
+        let mut rows = Vec::with_capacity(range.len());
+        for (index, entity) in list.rows.drain(..) {
+            if range.contains(&index) {
+                spawned[index - range.start] = true;
+                rows.push((index, entity));
+            } else {
+                recycled.push(entity);
+            }
+        }
+
+        if list.needs_refresh {
+            for &(index, entity) in &rows {
+                build_row(&mut commands, list, entity, index, true);
+            }
+            list.needs_refresh = false;
+        }
+
+        for index in range.clone() {
+            if spawned[index - range.start] {
+                continue;
+            }
+            let (entity, recycled) = match recycled.pop() {
+                Some(entity) => (entity, true),
+                None => (commands.spawn_empty().set_parent(list_entity).id(), false),
+            };
+            build_row(&mut commands, list, entity, index, recycled);
+            rows.push((index, entity));
+        }
+
+        for entity in recycled {
+            commands.entity(entity).despawn_recursive();
+        }
+        list.rows = rows;
+    }
+}
+
+/// Builds the row at `index` on `entity`. A `recycled` row is cleared first, so that it doesn't
+/// keep anything the factory added for its previous index.
+fn build_row(
+    commands: &mut Commands,
+    list: &VirtualList,
+    entity: Entity,
+    index: usize,
+    recycled: bool,
+) {
+    let mut row = commands.entity(entity);
+    if recycled {
+        row.despawn_descendants().retain::<Parent>();
+    }
+    row.insert((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(index as f32 * list.row_height),
+            left: Val::Px(0.),
+            right: Val::Px(0.),
+            height: Val::Px(list.row_height),
+            ..Default::default()
+        },
+        VirtualListRow(index),
+    ));
+    (list.factory)(index, &mut row);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_math::Vec2;
+
+    fn spawned_rows(world: &mut World) -> Vec<(usize, Entity)> {
+        let mut rows: Vec<_> = world
+            .query::<(Entity, &VirtualListRow)>()
+            .iter(world)
+            .map(|(entity, row)| (row.0, entity))
+            .collect();
+        rows.sort();
+        rows
+    }
+
+    #[test]
+    fn virtual_list_recycles_rows() {
+        let mut world = World::new();
+        let list = world
+            .spawn((
+                VirtualList::new(1000, 10., |_, _| {}),
+                ComputedNode {
+                    size: Vec2::splat(100.),
+                    ..ComputedNode::DEFAULT
+                },
+                ScrollPosition::from(Vec2::new(0., 250.)),
+            ))
+            .id();
+
+        world.run_system_once(update_virtual_lists).unwrap();
+        let rows = spawned_rows(&mut world);
+        let indices: Vec<_> = rows.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, (23..37).collect::<Vec<_>>());
+
+        world
+            .entity_mut(list)
+            .insert(ScrollPosition::from(Vec2::new(0., 300.)));
+        world.run_system_once(update_virtual_lists).unwrap();
+        let scrolled_rows = spawned_rows(&mut world);
+        let indices: Vec<_> = scrolled_rows.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, (28..42).collect::<Vec<_>>());
+
+        // The rows that left the view were reused for the rows that entered it.
+        let mut entities: Vec<_> = rows.iter().map(|(_, entity)| *entity).collect();
+        let mut scrolled_entities: Vec<_> =
+            scrolled_rows.iter().map(|(_, entity)| *entity).collect();
+        entities.sort();
+        scrolled_entities.sort();
+        assert_eq!(entities, scrolled_entities);
+
+        world.get_mut::<VirtualList>(list).unwrap().set_len(30);
+        world.run_system_once(update_virtual_lists).unwrap();
+        let indices: Vec<_> = spawned_rows(&mut world)
+            .iter()
+            .map(|(index, _)| *index)
+            .collect();
+        assert_eq!(indices, (28..30).collect::<Vec<_>>());
+    }
+
+    #[derive(Component)]
+    struct Even;
+
+    #[derive(Component)]
+    struct Built(usize);
+
+    #[test]
+    fn virtual_list_rebuilds_rows() {
+        let mut world = World::new();
+        let list = world
+            .spawn((
+                VirtualList::new(100, 10., |index, row| {
+                    if index % 2 == 0 {
+                        row.insert(Even);
+                    }
+                    row.with_child(Built(index));
+                })
+                .with_buffer(0),
+                ComputedNode {
+                    size: Vec2::splat(20.),
+                    ..ComputedNode::DEFAULT
+                },
+                ScrollPosition::default(),
+            ))
+            .id();
+
+        world.run_system_once(update_virtual_lists).unwrap();
+        let spacer = world.get::<VirtualList>(list).unwrap().spacer.unwrap();
+        assert_eq!(world.get::<Node>(spacer).unwrap().height, Val::Px(1000.));
+        let rows = spawned_rows(&mut world);
+        assert_eq!(rows.len(), 2);
+        assert!(world.get::<Even>(rows[0].1).is_some());
+        assert!(world.get::<Even>(rows[1].1).is_none());
+
+        // Scrolling by one row recycles the even row as an odd one, without its components.
+        world
+            .entity_mut(list)
+            .insert(ScrollPosition::from(Vec2::new(0., 10.)));
+        world.run_system_once(update_virtual_lists).unwrap();
+        let rows = spawned_rows(&mut world);
+        assert_eq!(
+            rows.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert!(world.get::<Even>(rows[0].1).is_none());
+        assert!(world.get::<Even>(rows[1].1).is_some());
+        assert_eq!(
+            world.get::<VirtualListRow>(rows[1].1),
+            Some(&VirtualListRow(2))
+        );
+
+        // Refreshing rebuilds the rows in place, and resizing the list resizes the spacer.
+        let mut virtual_list = world.get_mut::<VirtualList>(list).unwrap();
+        virtual_list.refresh();
+        virtual_list.set_len(50);
+        world.run_system_once(update_virtual_lists).unwrap();
+        assert_eq!(spawned_rows(&mut world), rows);
+        assert_eq!(world.get::<Node>(spacer).unwrap().height, Val::Px(500.));
+        let mut built: Vec<_> = world
+            .query::<&Built>()
+            .iter(&world)
+            .map(|built| built.0)
+            .collect();
+        built.sort();
+        assert_eq!(built, [1, 2]);
+    }
+}